@@ -13,6 +13,9 @@ use lsp_types::DiagnosticSeverity;
 use lsp_types::DiagnosticTag;
 use serde_json::Value;
 
+use crate::find_diagnostic_code;
+use crate::message_catalog::translate_message;
+use crate::message_code;
 use crate::Location;
 use crate::SourceLocationKey;
 
@@ -62,12 +65,14 @@ impl Diagnostic {
         tags: Vec<DiagnosticTag>,
     ) -> Self {
         Self(Box::new(DiagnosticData {
+            code: message_code(&message),
             message: Box::new(message),
             location,
             related_information: Vec::new(),
             tags,
             severity,
             data: Vec::new(),
+            safe_fix: None,
         }))
     }
 
@@ -84,13 +89,16 @@ impl Diagnostic {
         location: Location,
     ) -> Self {
         let data = message.get_data();
+        let safe_fix = message.get_safe_fix();
         Self(Box::new(DiagnosticData {
+            code: message_code(&message),
             message: Box::new(message),
             location,
             tags: Vec::new(),
             severity: DiagnosticSeverity::ERROR,
             related_information: Vec::new(),
             data,
+            safe_fix,
         }))
     }
 
@@ -139,10 +147,26 @@ impl Diagnostic {
         self
     }
 
+    /// Attaches a fix that can be applied without review, e.g. by
+    /// `relay-compiler --fix`.
+    pub fn with_safe_fix(mut self, fix: DiagnosticFix) -> Self {
+        self.0.safe_fix = Some(fix);
+        self
+    }
+
     pub fn message(&self) -> &impl DiagnosticDisplay {
         &self.0.message
     }
 
+    /// The message, translated with the installed `MessageCatalog` if it has
+    /// a template for the code of this diagnostic.
+    pub fn localized_message(&self) -> String {
+        let message = self.0.message.to_string();
+        self.error_code()
+            .and_then(|code| translate_message(code, &message))
+            .unwrap_or(message)
+    }
+
     pub fn location(&self) -> Location {
         self.0.location
     }
@@ -151,10 +175,29 @@ impl Diagnostic {
         &self.0.data
     }
 
+    pub fn safe_fix(&self) -> Option<&DiagnosticFix> {
+        self.0.safe_fix.as_ref()
+    }
+
     pub fn severity(&self) -> DiagnosticSeverity {
         self.0.severity
     }
 
+    /// The name of the kind of this diagnostic, i.e. of the variant of the
+    /// error enum of its message (e.g. `DeprecatedField`). Plain string
+    /// messages have no kind.
+    pub fn code(&self) -> Option<&'static str> {
+        self.error_code()
+            .and_then(find_diagnostic_code)
+            .map(|diagnostic_code| diagnostic_code.kind)
+    }
+
+    /// The stable code of the kind of this diagnostic (e.g. `RELAY1001`), see
+    /// `diagnostic_codes()`.
+    pub fn error_code(&self) -> Option<&'static str> {
+        self.0.code
+    }
+
     pub fn tags(&self) -> Vec<DiagnosticTag> {
         self.0.tags.clone()
     }
@@ -182,6 +225,13 @@ impl Diagnostic {
         self.0.severity = severity;
     }
 
+    /// Replace the severity with the one configured for this kind of
+    /// diagnostic, e.g. in the `severityOverrides` of a project. Unlike
+    /// `override_severity`, this may also lower it.
+    pub fn remap_severity(&mut self, severity: DiagnosticSeverity) {
+        self.0.severity = severity;
+    }
+
     pub fn related_information(&self) -> &[DiagnosticRelatedInformation] {
         &self.0.related_information
     }
@@ -231,6 +281,10 @@ struct DiagnosticData {
     /// Human readable error message.
     message: Box<dyn DiagnosticDisplay>,
 
+    /// The stable code of the kind of the message, if it's a value of an
+    /// error enum with `diagnostic_codes!`.
+    code: Option<&'static str>,
+
     /// The primary location of this diagnostic.
     location: Location,
 
@@ -246,6 +300,48 @@ struct DiagnosticData {
     /// `data` is used in the LSP protocol:
     /// @see https://microsoft.github.io/language-server-protocol/specifications/specification-current/#diagnostic
     data: Vec<Box<dyn DiagnosticDisplay>>,
+
+    /// A fix that can be applied without review.
+    safe_fix: Option<DiagnosticFix>,
+}
+
+/// A mechanical fix of a diagnostic. Fixes are described relative to the
+/// location of the diagnostic, and resolved to text edits by the callers that
+/// have the source text: the LSP quick fixes, the `batch` command and
+/// `relay-compiler --fix`.
+#[derive(fmt::Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DiagnosticFix {
+    /// Replace the text at the location of the diagnostic.
+    ReplaceWith(String),
+    /// Remove the definition of the variable named at the location of the
+    /// diagnostic, from the variables of an operation or the
+    /// `@argumentDefinitions` of a fragment.
+    RemoveVariableDefinition,
+    /// Text edits computed by the rule reporting the diagnostic, applied
+    /// together. The edits are in one source, which can be another one than
+    /// the source of the diagnostic.
+    Edits {
+        title: String,
+        edits: Vec<DiagnosticEdit>,
+    },
+}
+
+/// The replacement of the text at a location.
+#[derive(fmt::Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DiagnosticEdit {
+    pub location: Location,
+    pub replacement: String,
+}
+
+impl DiagnosticFix {
+    /// Describes the fix, e.g. as the title of a code action.
+    pub fn title(&self) -> String {
+        match self {
+            DiagnosticFix::ReplaceWith(text) => format!("Fix Error: '{}'", text),
+            DiagnosticFix::RemoveVariableDefinition => "Remove unused variable".to_string(),
+            DiagnosticFix::Edits { title, .. } => title.clone(),
+        }
+    }
 }
 
 /// Secondary locations attached to a diagnostic.
@@ -260,6 +356,12 @@ pub struct DiagnosticRelatedInformation {
 
 pub trait WithDiagnosticData {
     fn get_data(&self) -> Vec<Box<dyn DiagnosticDisplay>>;
+
+    /// The fix that can be applied without review, if any. It is usually one
+    /// of the items of `get_data`, when there is no other to choose from.
+    fn get_safe_fix(&self) -> Option<DiagnosticFix> {
+        None
+    }
 }
 
 /// Trait for diagnostic messages to allow structs that capture
@@ -0,0 +1,1207 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Stable codes of the kinds of diagnostics, e.g. `RELAY1001` for
+//! `DuplicateDefinition`. Each variant of an error enum gets its code with a
+//! `#[code(..)]` attribute in `diagnostic_codes!`; the variants may be
+//! renamed, but the code stays the same, so that it can be used in
+//! suppressions and searched for in the docs.
+//!
+//! Codes are never renumbered or reused: new kinds of diagnostics are
+//! appended to the range of their crate, `RELAY1xxx` for graphql-ir,
+//! `RELAY2xxx` for relay-transforms, `RELAY3xxx` for relay-compiler and the
+//! crates of its build (persist-query, js-config-loader), `RELAY4xxx` for
+//! graphql-syntax, `RELAY5xxx` for docblock-syntax, `RELAY6xxx` for schema,
+//! `RELAY7xxx` for schema-validate, `RELAY8xxx` for graphql-ir-validations
+//! and `RELAY9xxx` for relay-docblock. `RELAY0xxx` is left to the error
+//! enums of tests.
+
+use std::any::Any;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::fmt;
+
+use once_cell::sync::OnceCell;
+
+/// A kind of diagnostic, with its stable code.
+#[derive(Debug)]
+pub struct DiagnosticCode {
+    pub code: &'static str,
+    /// The name of the variant of the error enum. Variants of different
+    /// enums may have the same name, but not the same code.
+    pub kind: &'static str,
+    /// The message of the diagnostic, with the placeholders of its arguments,
+    /// i.e. the `#[error(..)]` message of the variant.
+    pub summary: &'static str,
+    /// A longer explanation with examples, printed by
+    /// `relay-compiler --explain`.
+    pub explanation: Option<&'static str>,
+}
+
+/// The code of a message, if it is a value of the error enum.
+type MessageCode = fn(&dyn Any) -> Option<&'static str>;
+
+/// The codes of the variants of an error enum, registered by
+/// `diagnostic_codes!`.
+#[doc(hidden)]
+pub struct DiagnosticCodes {
+    pub type_id: fn() -> TypeId,
+    pub code: MessageCode,
+    /// The code, kind and summary of each variant.
+    pub kinds: &'static [(&'static str, &'static str, &'static str)],
+}
+
+inventory::collect!(DiagnosticCodes);
+
+struct Registry {
+    codes_by_type: HashMap<TypeId, MessageCode>,
+    diagnostic_codes: Vec<DiagnosticCode>,
+}
+
+fn registry() -> &'static Registry {
+    static REGISTRY: OnceCell<Registry> = OnceCell::new();
+    REGISTRY.get_or_init(|| {
+        let mut codes_by_type = HashMap::new();
+        let mut diagnostic_codes = Vec::new();
+        for codes in inventory::iter::<DiagnosticCodes> {
+            codes_by_type.insert((codes.type_id)(), codes.code);
+            diagnostic_codes.extend(codes.kinds.iter().map(|&(code, kind, summary)| {
+                DiagnosticCode {
+                    code,
+                    kind,
+                    summary,
+                    explanation: EXPLANATIONS
+                        .iter()
+                        .find(|(explained_code, _)| *explained_code == code)
+                        .map(|(_, explanation)| *explanation),
+                }
+            }));
+        }
+        diagnostic_codes.sort_by_key(|diagnostic_code| diagnostic_code.code);
+        Registry {
+            codes_by_type,
+            diagnostic_codes,
+        }
+    })
+}
+
+/// The kinds of diagnostics of the error enums linked in the binary, sorted
+/// by code.
+pub fn diagnostic_codes() -> &'static [DiagnosticCode] {
+    &registry().diagnostic_codes
+}
+
+/// The code of a diagnostic message, if it's a value of an error enum with
+/// `diagnostic_codes!`.
+pub fn message_code<T: 'static>(message: &T) -> Option<&'static str> {
+    let code = registry().codes_by_type.get(&TypeId::of::<T>())?;
+    code(message)
+}
+
+/// The message of an error, prefixed by its code if it has one, e.g.
+/// `[RELAY3008] Failed to read file ...`, for the errors that are printed
+/// without the header of a diagnostic.
+pub fn message_with_code<T: fmt::Display + 'static>(message: &T) -> String {
+    match message_code(message) {
+        Some(code) => format!("[{}] {}", code, message),
+        None => message.to_string(),
+    }
+}
+
+/// Finds the kind of diagnostic with a code (e.g. `RELAY1001`, or `1001`) or a
+/// kind name (e.g. `DuplicateDefinition`), the first one if several enums
+/// have a variant with this name.
+pub fn find_diagnostic_code(code_or_kind: &str) -> Option<&'static DiagnosticCode> {
+    let code_or_kind = code_or_kind.trim();
+    let code = if code_or_kind.chars().all(|chr| chr.is_ascii_digit()) {
+        format!("RELAY{}", code_or_kind)
+    } else {
+        code_or_kind.to_ascii_uppercase()
+    };
+    diagnostic_codes().iter().find(|diagnostic_code| {
+        diagnostic_code.code == code || diagnostic_code.kind == code_or_kind
+    })
+}
+
+/// Defines an error enum with the code of each of its variants, e.g.:
+///
+/// ```ignore
+/// diagnostic_codes! {
+///     #[derive(Clone, Debug, Error)]
+///     pub enum ValidationMessage {
+///         #[error("Duplicate definitions for '{0}'")]
+///         #[code("RELAY1001")]
+///         DuplicateDefinition(StringKey),
+///
+///         /// Doc comments go before `#[error(..)]`.
+///         #[error("Expected an object, interface, or union, found '{0:?}'")]
+///         #[code("RELAY1002")]
+///         ExpectedCompositeType(Type),
+///     }
+/// }
+/// ```
+///
+/// The `#[code(..)]` attribute is required, so that new variants get a code.
+/// The codes and the `#[error(..)]` messages are registered for
+/// `diagnostic_codes()`, and the codes of the messages of diagnostics are
+/// looked up with `message_code()`.
+#[macro_export]
+macro_rules! diagnostic_codes {
+    (
+        $(#[$attr:meta])*
+        $vis:vis enum $message:ident {
+            $(
+                $(#[doc = $doc:literal])*
+                #[error($summary:literal $($format_args:tt)*)]
+                #[code($code:literal)]
+                $kind:ident $(( $($tuple:tt)* ))? $({ $($fields:tt)* })?
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis enum $message {
+            $(
+                $(#[doc = $doc])*
+                #[error($summary $($format_args)*)]
+                $kind $(( $($tuple)* ))? $({ $($fields)* })?
+            ),*
+        }
+
+        const _: () = {
+            fn code(message: &dyn ::std::any::Any) -> ::std::option::Option<&'static str> {
+                message.downcast_ref::<$message>().map(|message| match message {
+                    $($message::$kind { .. } => $code,)*
+                })
+            }
+
+            $crate::inventory::submit! {
+                $crate::DiagnosticCodes {
+                    type_id: ::std::any::TypeId::of::<$message>,
+                    code,
+                    kinds: &[$(($code, stringify!($kind), $summary)),*],
+                }
+            }
+        };
+    };
+}
+
+/// The longer explanations of the kinds of diagnostics, by code.
+const EXPLANATIONS: &[(&str, &str)] = &[
+    (
+        "RELAY1001",
+        r#"Two operations or fragments of a project have the same name. Operation and
+fragment names must be unique across all the files of a project, since the
+names of the generated artifacts are derived from them.
+
+Rename one of the definitions. By convention, definitions are prefixed with
+the name of their module, e.g. `UserProfile_user` in `UserProfile.js`."#,
+    ),
+    (
+        "RELAY1002",
+        r#"Fields can only be selected on objects, interfaces and unions. The type
+condition of a fragment or an inline fragment, or the type of the root of an
+operation, is a scalar, an enum or an input object.
+
+```graphql
+fragment User_name on String {   # `String` is a scalar
+  length
+}
+```
+
+Use the object, interface or union type that has the fields to select."#,
+    ),
+    (
+        "RELAY1004",
+        r#"The field returns a scalar or an enum, which doesn't have fields to select.
+
+```graphql
+fragment User_user on User {
+  name {   # `name` is a String
+    value
+  }
+}
+```
+
+Remove the selections of the field."#,
+    ),
+    (
+        "RELAY1005",
+        r#"The directive is not defined by the schema, by Relay, or by the client
+schema extensions of the project.
+
+```graphql
+query AppQuery {
+  me @cached {   # `@cached` is not defined
+    name
+  }
+}
+```
+
+Check the spelling of the directive, or define it in a client schema
+extension if it's handled by your own code."#,
+    ),
+    (
+        "RELAY1007",
+        r#"Relay derives the names of the generated artifacts and of the types of the
+operation from its name, so anonymous operations are not supported.
+
+```graphql
+query {   # no name
+  me { id }
+}
+```
+
+Name the operation after its module, e.g. `query AppQuery { ... }` in
+`App.js`."#,
+    ),
+    (
+        "RELAY1010",
+        r#"A literal value doesn't match the type of the argument, of the input field
+or of the default value of the variable it's passed to, e.g. a string for an
+`Int` or an object for a list of IDs.
+
+```graphql
+query AppQuery {
+  user(id: 4) {   # `id` is an `ID!`, the value should be a string
+    name
+  }
+}
+```
+
+Pass a value of the expected type, or a variable of this type."#,
+    ),
+    (
+        "RELAY1011",
+        r#"Enum values are written without quotes in GraphQL; a quoted value is a
+string, which is not a valid value for an enum argument.
+
+```graphql
+query AppQuery {
+  posts(orderBy: "NEWEST") {   # should be `orderBy: NEWEST`
+    id
+  }
+}
+```
+
+Remove the quotes around the enum value."#,
+    ),
+    (
+        "RELAY1013",
+        r#"An input object literal doesn't have some of the non-null fields of its type
+that don't have a default value.
+
+```graphql
+mutation AddCommentMutation {
+  addComment(input: {postId: "1"}) {   # `text: String!` is missing
+    id
+  }
+}
+```
+
+Add the missing fields to the object, or pass the whole input as a variable."#,
+    ),
+    (
+        "RELAY1017",
+        r#"The arguments of a fragment are declared either with the variable
+definitions syntax (`fragment User_user($size: Int) on User`) or with
+the `@argumentDefinitions` directive, but not both.
+
+Move all the arguments to one of the two forms."#,
+    ),
+    (
+        "RELAY1022",
+        r#"A non-null variable must be provided by the caller, so its default value
+would never be used.
+
+```graphql
+query AppQuery($first: Int! = 10) {
+  ...
+}
+```
+
+Make the variable nullable to keep the default value (`$first: Int = 10`), or
+remove the default value."#,
+    ),
+    (
+        "RELAY1024",
+        r#"A variable of a fragment without an `@argumentDefinitions` entry is a global
+variable, provided by the operations that spread the fragment. All its
+usages must be compatible with one type, which is inferred from the first
+usage.
+
+```graphql
+fragment User_user on User {
+  friends(first: $count) { ... }    # `Int`
+  avatar(size: $count) { ... }      # `String`
+}
+```
+
+Use different variables for the two arguments, or declare the variable in
+the `@argumentDefinitions` of the fragment."#,
+    ),
+    (
+        "RELAY1025",
+        r#"A variable used in an operation, or in the fragments it spreads, is not
+defined by the operation. Fragments can use the variables of the operations
+that spread them (global variables), which must then be defined by every such
+operation.
+
+```graphql
+query AppQuery {
+  node(id: $id) {   # `$id` is not defined
+    id
+  }
+}
+```
+
+Add the variable to the operation (`query AppQuery($id: ID!)`), or define it
+locally in the fragment with `@argumentDefinitions`."#,
+    ),
+    (
+        "RELAY1029",
+        r#"The type condition of the fragment and the parent type of the spread have no
+possible type in common, so the fragment would never match.
+
+```graphql
+fragment Page_page on Page {
+  name
+}
+
+fragment User_user on User {
+  ...Page_page   # a `User` is never a `Page`
+}
+```
+
+Spread the fragment where its type can occur, e.g. in an inline fragment on
+an interface or union that contains it."#,
+    ),
+    (
+        "RELAY1030",
+        r#"Every directive declares the locations where it may be used, e.g. `FIELD`
+or `FRAGMENT_SPREAD`, and is used in another one.
+
+```graphql
+fragment User_user on User @connection(key: "User_friends") {
+  ...
+}
+```
+
+Move the directive to a supported location. The locations of the Relay
+directives are listed in the docs of each directive."#,
+    ),
+    (
+        "RELAY1036",
+        r#"Relay uses the response keys `__id` and `__typename` to normalize the data in
+its store, and an alias to one of these names would overwrite them.
+
+Use another alias."#,
+    ),
+    (
+        "RELAY1037",
+        r#"The root types of the operations are never abstract, and Relay doesn't
+support selecting `__typename` on `Query`, `Mutation` or `Subscription`.
+
+Remove the `__typename` selection, the type of the root is already known."#,
+    ),
+    (
+        "RELAY1039",
+        r#"`@connection` and `@stream_connection` are used on fields that return a
+connection object, with `edges` and `pageInfo` fields, and not a list or a
+scalar.
+
+```graphql
+fragment User_friends on User {
+  friends @connection(key: "User_friends") {   # `friends` is `[User]`
+    id
+  }
+}
+```
+
+Use the directive on a field implementing the connection specification, or
+paginate the list without `@connection`."#,
+    ),
+    (
+        "RELAY1048",
+        r#"The `key` of a connection identifies its records in the store, and must be a
+literal string so that it's known at compile time.
+
+```graphql
+friends(first: 10) @connection(key: $key) {
+  ...
+}
+```
+
+Pass a string literal, by convention `<FragmentName>_<fieldName>`, e.g.
+`@connection(key: "User_friends")`."#,
+    ),
+    (
+        "RELAY1059",
+        r#"A variable of an operation is defined but never used in the operation or in
+the fragments it spreads.
+
+```graphql
+query AppQuery($id: ID!, $first: Int) {   # `$first` is never used
+  node(id: $id) {
+    id
+  }
+}
+```
+
+Remove the variable definition, or pass the variable to the field or fragment
+that should use it. `relay-compiler --fix` removes unused variables."#,
+    ),
+    (
+        "RELAY1060",
+        r#"A local variable of a fragment, defined with `@argumentDefinitions`, is never
+used in the fragment.
+
+```graphql
+fragment User_user on User
+  @argumentDefinitions(scale: {type: "Float"}) {   # `$scale` is never used
+  name
+}
+```
+
+Remove the entry from `@argumentDefinitions`, or use the variable in the
+fragment. Variables that are expected to be unused can be annotated with
+`@unusedLocalVariable_DEPRECATED`. `relay-compiler --fix` removes unused
+fragment variables."#,
+    ),
+    (
+        "RELAY1066",
+        r#"The directive isn't declared as `repeatable` in the schema, so it can only be
+used once on a field, fragment or operation.
+
+Remove the duplicate directive, or merge the arguments of the two usages."#,
+    ),
+    (
+        "RELAY1068",
+        r#"The field is marked as `@deprecated` in the schema, and may be removed from
+it. The reason of the deprecation, if any, is part of the message.
+
+Select the field that replaces it, as described in the deprecation reason."#,
+    ),
+    (
+        "RELAY1069",
+        r#"A field is selected without some of its non-null arguments that don't have a
+default value in the schema.
+
+```graphql
+# type Query { node(id: ID!): Node }
+query AppQuery {
+  node {   # missing the required `id` argument
+    id
+  }
+}
+```
+
+Pass a value or a variable for each required argument."#,
+    ),
+    (
+        "RELAY1070",
+        r#"An argument is passed twice to a field or a directive.
+
+```graphql
+user(id: "1", id: "2") {
+  name
+}
+```
+
+Remove one of the arguments."#,
+    ),
+    (
+        "RELAY1071",
+        r#"A non-null argument of a field or of a directive, without a default value,
+is not passed.
+
+```graphql
+query AppQuery {
+  node {   # `node(id: ID!)`
+    id
+  }
+}
+```
+
+Pass the argument, as a literal or a variable."#,
+    ),
+    (
+        "RELAY1072",
+        r#"The fragment declares a non-null argument without a default value in its
+`@argumentDefinitions`, and the spread doesn't pass it in `@arguments`.
+
+```graphql
+fragment User_avatar on User
+  @argumentDefinitions(size: {type: "Int!"}) {
+  avatar(size: $size)
+}
+
+fragment User_user on User {
+  ...User_avatar   # `@arguments(size: 32)` is missing
+}
+```
+
+Pass the argument with `@arguments`, or add a `defaultValue` to its
+definition."#,
+    ),
+    (
+        "RELAY1073",
+        r#"Two variables of an operation, or two arguments of a fragment, have the same
+name.
+
+Remove or rename one of them."#,
+    ),
+    (
+        "RELAY1075",
+        r#"Aliases starting with two underscores are reserved: Relay uses such keys
+(e.g. `__id`, `__typename`, `__fragments`) in the records and fragment data it
+stores.
+
+```graphql
+fragment User_user on User {
+  __name: name   # reserved alias
+}
+```
+
+Choose an alias that doesn't start with `__`."#,
+    ),
+    (
+        "RELAY1076",
+        r#"A type condition, variable type or `@argumentDefinitions` type refers to a
+type that is not defined in the schema or the client schema extensions.
+
+```graphql
+fragment User_user on Usr {   # `Usr` is not defined
+  name
+}
+```
+
+Check the spelling of the type. When there is a single close match, the
+message suggests it and `relay-compiler --fix` renames the type."#,
+    ),
+    (
+        "RELAY1077",
+        r#"The field is not defined on the parent type in the schema or the client
+schema extensions.
+
+```graphql
+# type User { id: ID! name: String }
+fragment User_user on User {
+  nmae   # did you mean `name`?
+}
+```
+
+Check the spelling of the field and the type it is selected on; fields of a
+more specific type require an inline fragment (`... on Page { url }`). When
+there is a single close match, `relay-compiler --fix` renames the field."#,
+    ),
+    (
+        "RELAY1078",
+        r#"The field returns an object, interface or union, so it must select some of
+the fields of its type.
+
+```graphql
+fragment User_user on User {
+  friends   # missing selections, e.g. `friends { name }`
+}
+```"#,
+    ),
+    (
+        "RELAY1079",
+        r#"A fragment spread refers to a fragment that is not defined in the project.
+
+```graphql
+query AppQuery {
+  me {
+    ...User_usr   # did you mean `User_user`?
+  }
+}
+```
+
+Check the spelling of the fragment and that the file defining it is part of
+the sources of the project. When there is a single close match,
+`relay-compiler --fix` renames the spread."#,
+    ),
+    (
+        "RELAY1080",
+        r#"An argument is not defined by the field or directive it is passed to.
+
+```graphql
+# type User { friends(first: Int): [User] }
+fragment User_user on User {
+  friends(frist: 10) {   # did you mean `first`?
+    name
+  }
+}
+```"#,
+    ),
+    (
+        "RELAY1081",
+        r#"The type condition of an inline fragment has no possible type in common with
+the enclosing types, so its selections would never be fetched. Unlike
+`RELAY1028`, the parent type alone would allow the condition, but the
+enclosing inline fragments and fragments have already narrowed it down.
+
+Remove the inline fragment, or move it to a place where its type can
+occur."#,
+    ),
+    (
+        "RELAY1082",
+        r#"The type of a fragment overlaps with each of the types of its parent
+selections, but no object type is possible for all of them at once, so the
+fragment can never match at runtime.
+
+```graphql
+# interface Actor, interface Named, interface Commentable
+# type User implements Actor & Named
+# type Page implements Named & Commentable
+# type Story implements Actor & Commentable
+fragment Viewer_actor on Actor {
+  ... on Named {          # User
+    ...Comments_target    # Commentable: Page or Story, never a User
+  }
+}
+```
+
+Spread the fragment in a selection of one of its possible types, or change
+the type condition of the enclosing inline fragment."#,
+    ),
+    (
+        "RELAY1083",
+        r#"The arguments of a fragment spread are passed either with the arguments
+syntax (`...User_user(size: 64)`) or with the `@arguments` directive, but not
+both.
+
+Move all the arguments to one of the two forms; `relay-compiler codemod
+migrate-fragment-arguments` rewrites the directives to the arguments syntax."#,
+    ),
+    (
+        "RELAY2001",
+        r#"A fragment spreads itself, directly or through other fragments. The
+selections of such fragments would be infinite, which is not allowed by the
+GraphQL specification.
+
+```graphql
+fragment User_user on User {
+  friends {
+    ...User_user   # spreads the fragment being defined
+  }
+}
+```
+
+Select the fields of the nested level explicitly, or use a separate fragment
+for it."#,
+    ),
+    (
+        "RELAY2039",
+        r#"The labels of `@defer` and `@stream` identify the incremental payloads of the
+response, so they must be unique across an operation and the fragments it
+includes. The label defaults to the name of the fragment or of the field,
+which repeats when the same fragment is deferred twice.
+
+Pass a unique literal `label` to one of the directives."#,
+    ),
+    (
+        "RELAY2040",
+        r#"`@stream` delivers the items of a list incrementally, so it can only be used
+on fields returning a list.
+
+Use `@defer` on a fragment to deliver the selections of another field
+later."#,
+    ),
+    (
+        "RELAY2063",
+        r#"`@relay_test_operation` generates the metadata of the types of all the
+fields of the operation, for the data mocks of tests. It makes the artifacts
+larger, so it's only allowed in the files matching the `testPathRegex` of the
+config.
+
+Move the operation to a test file, or adjust `testPathRegex`."#,
+    ),
+    (
+        "RELAY2115",
+        r#"A `@refetchable` fragment is refetched with a query generated by the
+compiler, which needs a way to fetch the object again: the `Query` type, the
+`Viewer` type, or a type implementing `Node` (or with a `@fetchable` field).
+
+Use `@refetchable` on a fragment on one of these types, or refetch the
+enclosing fragment instead."#,
+    ),
+    (
+        "RELAY2117",
+        r#"The `queryName` of `@refetchable` is the name of the generated query, and is
+used by two fragments.
+
+Give each fragment its own query name, by convention
+`<FragmentName>RefetchQuery`."#,
+    ),
+    (
+        "RELAY2133",
+        r#"`@required` needs an `action`, which tells Relay what to do when the field
+is null: `NONE` to make the parent null, `LOG` to also log it, or `THROW` to
+throw an error.
+
+```graphql
+name @required   # should be `@required(action: LOG)`
+```
+
+Add the `action` argument."#,
+    ),
+    (
+        "RELAY2134",
+        r#"The same field is selected several times in a fragment, with `@required` and
+different actions. Since the selections are merged in the response, the
+field can only have one behavior.
+
+Use the same `action` for all the selections of the field."#,
+    ),
+    (
+        "RELAY2135",
+        r#"The same field is selected several times in a fragment, with `@required` on
+some of the selections only. Since the selections are merged, Relay can't
+tell whether the field is required.
+
+Add `@required` with the same `action` to all the selections of the field,
+or remove it from all of them."#,
+    ),
+    (
+        "RELAY2138",
+        r#"All the selections of the operation or fragment are removed by
+`@include`/`@skip` directives with constant values, or by client fields,
+and sending an empty selection set to the server is invalid.
+
+Remove the conditions that are always false, or select a field that the
+server resolves."#,
+    ),
+    (
+        "RELAY2139",
+        r#"Fragments spread with `@relay(mask: false)` are inlined into their parent
+when compiling, so a cycle of them would expand forever.
+
+Remove `@relay(mask: false)` from one of the spreads of the cycle."#,
+    ),
+    (
+        "RELAY2141",
+        r#"Operation names must be unique across a project, and Relay enforces that by
+requiring them to start with the name of the module that defines them and
+to end with the kind of operation, e.g. `UserProfileQuery` in
+`UserProfile.js`.
+
+Rename the operation after its module."#,
+    ),
+    (
+        "RELAY2142",
+        r#"Fragment names must be unique across a project, and Relay enforces that by
+requiring them to start with the name of the module that defines them, e.g.
+`UserProfile_user` in `UserProfile.js`.
+
+Rename the fragment after its module."#,
+    ),
+    (
+        "RELAY2143",
+        r#"The project restricts the directories in which operations may be defined with
+the `operationDirectories` option of its config, e.g. so that only the route
+entrypoints own queries and the components only define fragments:
+
+```json
+"operationDirectories": ["src/routes"]
+```
+
+Move the operation to one of these directories, or replace it with a fragment
+that is spread in an operation of these directories."#,
+    ),
+    (
+        "RELAY2144",
+        r#"Without a type in `customScalarTypes`, the generated types of a custom scalar
+are `any` (`unknown` in TypeScript), which hides typing gaps. The project sets
+`requireCustomScalarTypes`, so every custom scalar that the documents select
+or use in the types of their variables needs a type:
+
+```json
+"customScalarTypes": {
+  "DateTime": "string",
+  "JSON": {"name": "JSONValue", "path": "../src/JSONValue"}
+}
+```"#,
+    ),
+    (
+        "RELAY2147",
+        r#"The `@include` and `@skip` conditions of a fragment definition are added to
+each spread of the fragment, so their variable must be defined where the
+fragment is spread: in the variables of an operation, or in the
+`@argumentDefinitions` or the global variables of a fragment.
+
+```graphql
+fragment Comments_story on Story @include(if: $showComments) {
+  comments { count }
+}
+
+query StoryQuery($id: ID!) {   # $showComments is missing
+  node(id: $id) { ...Comments_story }
+}
+```
+
+Define the variable, or declare the value used by the callers that don't
+define it in the `fragmentConditionDefaults` of the project config, e.g.
+`{"showComments": false}`."#,
+    ),
+    (
+        "RELAY3001",
+        r#"A `# relay-ignore-next-line` comment suppresses diagnostics that are not
+reported anymore, usually because the code they excused was fixed or moved.
+
+```graphql
+fragment User_user on User {
+  # relay-ignore-next-line RELAY1068 Waiting for the new field to ship
+  name   # `name` is not deprecated
+}
+```
+
+Remove the comment, or move it above the line of the suppressed diagnostic."#,
+    ),
+    (
+        "RELAY3002",
+        r#"A `relay-ignore-next-line` comment is malformed. Suppressions name the codes
+or kinds of the diagnostics they suppress, and the reason for the
+suppression:
+
+```graphql
+# relay-ignore-next-line RELAY1068 The field is still used by older clients
+oldField
+```
+
+Several codes are separated by commas, without spaces."#,
+    ),
+    (
+        "RELAY3003",
+        r#"Two projects of a group of `uniqueDefinitionNameGroups` define an operation or a
+fragment with the same name. The projects of a group share a namespace
+downstream, e.g. a persisted query store keyed by the names of the
+operations, where the artifacts of one project would replace the other's.
+
+```json
+{
+  "uniqueDefinitionNameGroups": [["web", "mobile"]]
+}
+```
+
+Rename one of the definitions, e.g. with a prefix of its project. The
+definitions of a source directory shared by the projects aren't duplicates."#,
+    ),
+    (
+        "RELAY3005",
+        r#"The config file was parsed, but some of its values are inconsistent with each
+other or with the file system, e.g. a source directory that doesn't exist.
+Each problem is listed below this error, with its own code.
+
+Fix the listed problems, which are explained with
+`relay-compiler --explain <code>`."#,
+    ),
+    (
+        "RELAY3013",
+        r#"The compiler uses Watchman to list and watch the files of the project when
+it's installed, and the Watchman service returned an error.
+
+Check that `watchman` runs, e.g. with `watchman version`, and that the root
+of the project isn't on an unsupported file system. The compiler can also
+crawl the files without Watchman: set `FORCE_NO_WATCHMAN=1` (watch mode
+still requires Watchman)."#,
+    ),
+    (
+        "RELAY3026",
+        r#"The saved state was written by another version of the compiler or with
+another config, so the compiler can't reuse its incremental state.
+
+Delete the saved state to build from scratch, or pass a saved state built
+with the current version and config."#,
+    ),
+    (
+        "RELAY3030",
+        r#"Every project of a multi-project config needs at least one source directory
+that maps to it in the `sources` map.
+
+```json
+{
+  "sources": {"src/app": "app"},
+  "projects": {"app": {...}, "admin": {...}}
+}
+```
+
+Add an entry for the project to `sources`, or remove the project."#,
+    ),
+    (
+        "RELAY3034",
+        r#"A project gets its server schema either from a single file with `schema` or
+from a directory of `.graphql` files with `schemaDir`.
+
+Set exactly one of the two options for the project."#,
+    ),
+    (
+        "RELAY3042",
+        r#"`obfuscateOperationNames` replaces the names of the operations in the
+generated artifacts with their persisted ids, so it requires the operations
+to be persisted.
+
+Configure `persist` for the project, or remove `obfuscateOperationNames`."#,
+    ),
+    (
+        "RELAY3046",
+        r#"The option is no longer read by the compiler, and the message says what
+replaces it.
+
+Remove the option from the config, and follow the suggested migration."#,
+    ),
+    (
+        "RELAY4023",
+        r#"A string literal is not closed on its line. Strings can't contain line
+breaks, except for block strings delimited by `"""`.
+
+```graphql
+user(name: "Jane) {   # missing `"`
+  id
+}
+```
+
+Close the string, or use a block string for multi-line text."#,
+    ),
+    (
+        "RELAY6005",
+        r#"A client schema extension uses `extend type` on a type that isn't defined in
+the server schema, or in another client schema extension.
+
+Check the spelling of the type. To define a new client type, use `type` instead
+of `extend type`."#,
+    ),
+    (
+        "RELAY6008",
+        r#"A field, an argument or a member of a union references a type that is
+defined neither in the server schema nor in the client schema extensions.
+
+Check the spelling of the type, or define it in a client schema extension."#,
+    ),
+    (
+        "RELAY6009",
+        r#"A type defines, or extends itself with, a field that already exists on it.
+
+Remove or rename one of the fields."#,
+    ),
+    (
+        "RELAY6010",
+        r#"A client schema extension adds a field that is already defined by the server
+schema, e.g. after the field was added to the server. Relay would not know
+whether to read the field from the server or from the client store.
+
+Delete the field from the client schema extension to use the server field,
+or rename the client field."#,
+    ),
+    (
+        "RELAY7011",
+        r#"An object or interface implements an interface, but doesn't define one of
+its fields.
+
+```graphql
+interface Node { id: ID! }
+type User implements Node { name: String }   # `id` is missing
+```
+
+Add the field, with a compatible type, to the implementing type."#,
+    ),
+    (
+        "RELAY8001",
+        r#"Two fields with different names have the same response key in a selection
+set, e.g. because one of them is aliased to the name of the other. Their
+values would overwrite each other in the response.
+
+```graphql
+user {
+  name: username
+  name
+}
+```
+
+Use a different alias for one of the fields."#,
+    ),
+    (
+        "RELAY8003",
+        r#"The same field is selected several times in a selection set, with different
+arguments once the arguments of the fragments are applied. The response
+can only contain one value for the field.
+
+```graphql
+user {
+  avatar(size: 32)
+  ...User_avatar   # selects `avatar(size: $size)` with `size: 64`
+}
+```
+
+Alias one of the selections, e.g. `smallAvatar: avatar(size: 32)`."#,
+    ),
+    (
+        "RELAY9001",
+        r#"A `@RelayResolver` docblock contains a field that Relay doesn't know, e.g. a
+misspelled `@rootFragment`.
+
+Check the spelling of the field. The supported docblock fields are
+described in the docs of Relay Resolvers."#,
+    ),
+    (
+        "RELAY9004",
+        r#"A `@RelayResolver` docblock doesn't contain a field it needs, e.g. the type
+that a resolver field is defined on.
+
+```js
+/**
+ * @RelayResolver
+ * @fieldName greeting
+ */
+```
+
+Add the missing field, here `@onType User`, or use the terse syntax
+`@RelayResolver User.greeting: String`."#,
+    ),
+    (
+        "RELAY9006",
+        r#"A resolver field is defined either on an object type with `@onType` or on an
+interface with `@onInterface`, but not both.
+
+Keep the docblock field matching the kind of the parent type."#,
+    ),
+];
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::fs;
+    use std::path::Path;
+
+    use thiserror::Error;
+
+    use super::*;
+
+    diagnostic_codes! {
+        #[derive(Debug, Error)]
+        enum TestMessage {
+            #[error("Duplicate test definitions for '{0}'")]
+            #[code("RELAY0001")]
+            DuplicateTestDefinition(&'static str),
+
+            #[error("Unknown test directive")]
+            #[code("RELAY0002")]
+            UnknownTestDirective,
+        }
+    }
+
+    /// The codes and kinds of the variants of the error enums of the crates,
+    /// from their `#[code(..)]` attributes.
+    fn enum_diagnostic_codes(dir: &Path, codes: &mut Vec<(String, String)>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                enum_diagnostic_codes(&path, codes);
+            } else if path
+                .extension()
+                .map_or(false, |extension| extension == "rs")
+            {
+                let content = fs::read_to_string(&path).unwrap();
+                let mut lines = content.lines().map(str::trim);
+                while let Some(line) = lines.next() {
+                    if let Some(code) = line
+                        .strip_prefix("#[code(\"")
+                        .and_then(|line| line.strip_suffix("\")]"))
+                    {
+                        let kind = lines
+                            .next()
+                            .unwrap()
+                            .split(|chr: char| !chr.is_ascii_alphanumeric() && chr != '_')
+                            .next()
+                            .unwrap();
+                        codes.push((code.to_string(), kind.to_string()));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn every_variant_has_a_unique_code() {
+        let mut codes = Vec::new();
+        enum_diagnostic_codes(
+            Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap(),
+            &mut codes,
+        );
+        assert!(codes.len() > 300);
+        let mut seen_codes = HashSet::new();
+        for (code, kind) in &codes {
+            assert!(
+                code.len() == 9
+                    && code.starts_with("RELAY")
+                    && code[5..].chars().all(|chr| chr.is_ascii_digit()),
+                "Invalid code {} of {}",
+                code,
+                kind
+            );
+            assert!(
+                code.starts_with("RELAY0") || seen_codes.insert(code),
+                "The code {} of {} is used by another variant",
+                code,
+                kind
+            );
+        }
+        for (code, _) in EXPLANATIONS {
+            assert!(
+                seen_codes.contains(&code.to_string()),
+                "The explained code {} is not the code of a variant",
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn explanations_are_sorted_by_code() {
+        assert!(
+            EXPLANATIONS
+                .windows(2)
+                .all(|explanations| explanations[0].0 < explanations[1].0)
+        );
+    }
+
+    #[test]
+    fn codes_of_messages() {
+        assert_eq!(
+            message_code(&TestMessage::DuplicateTestDefinition("Query")),
+            Some("RELAY0001")
+        );
+        assert_eq!(
+            message_code(&TestMessage::UnknownTestDirective),
+            Some("RELAY0002")
+        );
+        assert_eq!(message_code(&"Unknown test directive"), None);
+        assert_eq!(
+            message_with_code(&TestMessage::UnknownTestDirective),
+            "[RELAY0002] Unknown test directive"
+        );
+        assert_eq!(
+            message_with_code(&"Unknown test directive"),
+            "Unknown test directive"
+        );
+    }
+
+    #[test]
+    fn find_by_code_or_kind() {
+        let by_code = find_diagnostic_code("RELAY0001").unwrap();
+        assert_eq!(by_code.kind, "DuplicateTestDefinition");
+        assert_eq!(by_code.summary, "Duplicate test definitions for '{0}'");
+        assert!(std::ptr::eq(
+            by_code,
+            find_diagnostic_code("relay0001").unwrap()
+        ));
+        assert!(std::ptr::eq(by_code, find_diagnostic_code("0001").unwrap()));
+        assert!(std::ptr::eq(
+            by_code,
+            find_diagnostic_code("DuplicateTestDefinition").unwrap()
+        ));
+        assert!(find_diagnostic_code("RELAY0000").is_none());
+    }
+}
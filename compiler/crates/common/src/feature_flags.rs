@@ -73,9 +73,19 @@ pub struct FeatureFlags {
     #[serde(default)]
     pub enable_fragment_aliases: FeatureFlag,
 
+    /// Enable `@include` and `@skip` on fragment definitions, the conditions
+    /// are pushed down to the spreads of the fragments.
+    #[serde(default)]
+    pub enable_fragment_conditions: FeatureFlag,
+
     /// Print queries in compact form
     #[serde(default)]
     pub compact_query_text: FeatureFlag,
+
+    /// Hoist selections shared by all inline fragments of an abstract
+    /// selection out of the inline fragments in normalization ASTs.
+    #[serde(default)]
+    pub merge_inline_fragments: FeatureFlag,
 }
 
 #[derive(Debug, Deserialize, Clone, Serialize)]
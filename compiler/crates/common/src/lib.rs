@@ -12,8 +12,10 @@
 mod console_logger;
 mod diagnostic;
 mod diagnostic_check;
+mod diagnostic_codes;
 mod feature_flags;
 mod location;
+mod message_catalog;
 mod named_item;
 mod perf_logger;
 mod pointer_address;
@@ -29,6 +31,8 @@ pub use diagnostic::diagnostics_result;
 pub use diagnostic::get_diagnostics_data;
 pub use diagnostic::Diagnostic;
 pub use diagnostic::DiagnosticDisplay;
+pub use diagnostic::DiagnosticEdit;
+pub use diagnostic::DiagnosticFix;
 pub use diagnostic::DiagnosticRelatedInformation;
 pub(crate) use diagnostic::Diagnostics;
 pub use diagnostic::DiagnosticsResult;
@@ -38,11 +42,23 @@ pub use diagnostic_check::escalate_and_check;
 pub use diagnostic_check::CriticalDiagnostics;
 pub use diagnostic_check::DiagnosticCheck;
 pub use diagnostic_check::StableDiagnostics;
+pub use diagnostic_codes::diagnostic_codes;
+pub use diagnostic_codes::find_diagnostic_code;
+pub use diagnostic_codes::message_code;
+pub use diagnostic_codes::message_with_code;
+pub use diagnostic_codes::DiagnosticCode;
+pub use diagnostic_codes::DiagnosticCodes;
 pub use feature_flags::FeatureFlag;
 pub use feature_flags::FeatureFlags;
+#[doc(hidden)]
+pub use inventory;
 pub use location::Location;
 pub use location::SourceLocationKey;
 pub use location::WithLocation;
+pub use message_catalog::localize_message;
+pub use message_catalog::set_message_catalog;
+pub use message_catalog::JsonMessageCatalog;
+pub use message_catalog::MessageCatalog;
 pub use lsp_types::DiagnosticSeverity;
 pub use lsp_types::DiagnosticTag;
 pub use named_item::ArgumentName;
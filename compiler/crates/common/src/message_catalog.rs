@@ -0,0 +1,282 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Translated messages of diagnostics. The English templates of the messages
+//! are the summaries of `diagnostic_codes()`; a `MessageCatalog` provides a
+//! template for some codes, with the same placeholders, e.g.
+//! `"RELAY1001": "Définitions en double de '{0}'"`.
+//!
+//! The arguments of a message are recovered by matching its English text
+//! against the English template, so that error enums don't need to expose
+//! them. Text after the end of the template is kept as is, as are the
+//! placeholders ending the template that the translation doesn't use, such
+//! as `{suggestions}`, and messages that don't match their template stay in
+//! English.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use once_cell::sync::OnceCell;
+use serde::Deserialize;
+
+use crate::find_diagnostic_code;
+
+static MESSAGE_CATALOG: OnceCell<Box<dyn MessageCatalog>> = OnceCell::new();
+
+/// Templates of the messages of diagnostics, by code (e.g. `RELAY1001`).
+pub trait MessageCatalog: Send + Sync {
+    fn template(&self, code: &str) -> Option<&str>;
+}
+
+/// A catalog read from a JSON object of templates by code.
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct JsonMessageCatalog(HashMap<String, String>);
+
+impl JsonMessageCatalog {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let content = fs::read_to_string(path)?;
+        serde_json::from_str(&content)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+}
+
+impl MessageCatalog for JsonMessageCatalog {
+    fn template(&self, code: &str) -> Option<&str> {
+        self.0.get(code).map(|template| template.as_str())
+    }
+}
+
+/// Installs the catalog used to print diagnostics for the rest of the
+/// process. Returns false if a catalog was already installed.
+pub fn set_message_catalog(catalog: Box<dyn MessageCatalog>) -> bool {
+    MESSAGE_CATALOG.set(catalog).is_ok()
+}
+
+/// Translates the English `message` of the diagnostics with `code` with the
+/// installed catalog, if it has a template for the code.
+pub(crate) fn translate_message(code: &str, message: &str) -> Option<String> {
+    let catalog = MESSAGE_CATALOG.get()?;
+    let diagnostic_code = find_diagnostic_code(code)?;
+    let template = catalog.template(diagnostic_code.code)?;
+    localize_message(diagnostic_code.summary, message, template)
+}
+
+/// Renders `translated_template` with the arguments of `message`, matched
+/// against `english_template`.
+pub fn localize_message(
+    english_template: &str,
+    message: &str,
+    translated_template: &str,
+) -> Option<String> {
+    let english_segments = parse_template(english_template);
+    let (arguments, rest) = match_template(&english_segments, message)?;
+    let translated_segments = parse_template(translated_template);
+    let mut localized = String::new();
+    for segment in &translated_segments {
+        match segment {
+            Segment::Text(text) => localized.push_str(text),
+            Segment::Placeholder(name) => {
+                let (_, value) = arguments.iter().find(|(argument, _)| argument == name)?;
+                localized.push_str(value);
+            }
+        }
+    }
+    let trailing_placeholders = english_segments
+        .iter()
+        .rev()
+        .take_while(|segment| matches!(segment, Segment::Placeholder(_)))
+        .count();
+    for segment in &english_segments[english_segments.len() - trailing_placeholders..] {
+        if let Segment::Placeholder(name) = segment {
+            if !translated_segments.contains(segment) {
+                let (_, value) = arguments.iter().find(|(argument, _)| argument == name)?;
+                localized.push_str(value);
+            }
+        }
+    }
+    localized.push_str(rest);
+    Some(localized)
+}
+
+#[derive(Debug, PartialEq)]
+enum Segment<'a> {
+    Text(&'a str),
+    /// The name of a `{name}` or `{name:?}` placeholder.
+    Placeholder(&'a str),
+}
+
+fn parse_template(template: &str) -> Vec<Segment<'_>> {
+    let mut segments = Vec::new();
+    let mut text_start = 0;
+    let mut index = 0;
+    while let Some(offset) = template[index..].find(['{', '}']) {
+        let start = index + offset;
+        // `{{` and `}}` are escaped braces, and other `}` are text.
+        let brace = &template[start..start + 1];
+        if template[start + 1..].starts_with(brace) {
+            segments.push(Segment::Text(&template[text_start..start + 1]));
+            text_start = start + 2;
+            index = start + 2;
+            continue;
+        }
+        if brace == "}" {
+            index = start + 1;
+            continue;
+        }
+        let placeholder = template[start + 1..].find('}').and_then(|length| {
+            let inner = &template[start + 1..start + 1 + length];
+            let name = inner.split(':').next().unwrap_or_default();
+            let is_placeholder = !name.is_empty()
+                && name
+                    .chars()
+                    .all(|chr| chr.is_ascii_alphanumeric() || chr == '_');
+            if is_placeholder {
+                Some((name, start + length + 2))
+            } else {
+                None
+            }
+        });
+        match placeholder {
+            Some((name, end)) => {
+                if start > text_start {
+                    segments.push(Segment::Text(&template[text_start..start]));
+                }
+                segments.push(Segment::Placeholder(name));
+                text_start = end;
+                index = end;
+            }
+            None => index = start + 1,
+        }
+    }
+    if text_start < template.len() {
+        segments.push(Segment::Text(&template[text_start..]));
+    }
+    segments
+}
+
+/// Matches the start of `message` against `segments`, with the shortest
+/// values of the placeholders, except for a placeholder ending the template
+/// which takes the rest of the first line. Returns the values of the
+/// placeholders and the unmatched end of the message.
+fn match_template<'a, 'b>(
+    segments: &[Segment<'a>],
+    message: &'b str,
+) -> Option<(Vec<(&'a str, &'b str)>, &'b str)> {
+    match segments.split_first() {
+        None => Some((Vec::new(), message)),
+        Some((Segment::Text(text), rest)) => match_template(rest, message.strip_prefix(*text)?),
+        Some((Segment::Placeholder(name), [])) => {
+            let end = message.find('\n').unwrap_or(message.len());
+            Some((vec![(*name, &message[..end])], &message[end..]))
+        }
+        Some((Segment::Placeholder(name), rest)) => message
+            .char_indices()
+            .map(|(index, _)| index)
+            .chain(std::iter::once(message.len()))
+            .find_map(|end| {
+                let (mut arguments, unmatched) = match_template(rest, &message[end..])?;
+                arguments.insert(0, (*name, &message[..end]));
+                Some((arguments, unmatched))
+            }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_placeholders() {
+        assert_eq!(
+            parse_template("Expected '{0:?}' on '{name}' { {inner} }"),
+            vec![
+                Segment::Text("Expected '"),
+                Segment::Placeholder("0"),
+                Segment::Text("' on '"),
+                Segment::Placeholder("name"),
+                Segment::Text("' { "),
+                Segment::Placeholder("inner"),
+                Segment::Text(" }"),
+            ]
+        );
+    }
+
+    #[test]
+    fn localize_with_arguments() {
+        assert_eq!(
+            localize_message(
+                "Variable `${variable_name}` is never used in operation `{operation_name}`",
+                "Variable `$first` is never used in operation `AppQuery`",
+                "L'opération `{operation_name}` n'utilise pas la variable `${variable_name}`",
+            )
+            .as_deref(),
+            Some("L'opération `AppQuery` n'utilise pas la variable `$first`")
+        );
+    }
+
+    #[test]
+    fn localize_keeps_the_rest_of_the_message() {
+        assert_eq!(
+            localize_message(
+                "The type `{type_}` has no field `{field}`.",
+                "The type `User` has no field `nmae`. Did you mean `name`?",
+                "Le type `{type_}` n'a pas de champ `{field}`.",
+            )
+            .as_deref(),
+            Some("Le type `User` n'a pas de champ `nmae`. Did you mean `name`?")
+        );
+    }
+
+    #[test]
+    fn localize_keeps_the_unused_placeholders_ending_the_template() {
+        assert_eq!(
+            localize_message(
+                "Unknown type '{type_name}'.{suggestions}",
+                "Unknown type 'Usr'. Did you mean `User`?",
+                "Type inconnu '{type_name}'.",
+            )
+            .as_deref(),
+            Some("Type inconnu 'Usr'. Did you mean `User`?")
+        );
+    }
+
+    #[test]
+    fn localize_with_escaped_braces() {
+        assert_eq!(
+            localize_message(
+                "Expected `{type_name} {{ id: ID! }}`",
+                "Expected `Node { id: ID! }`",
+                "`{type_name} {{ id: ID! }}` attendu",
+            )
+            .as_deref(),
+            Some("`Node { id: ID! }` attendu")
+        );
+    }
+
+    #[test]
+    fn localize_unmatched_message() {
+        assert_eq!(
+            localize_message(
+                "Duplicate definitions for '{0}'",
+                "Unknown type 'Foo'",
+                "Définitions en double de '{0}'",
+            ),
+            None
+        );
+        assert_eq!(
+            localize_message(
+                "Duplicate definitions for '{0}'",
+                "Duplicate definitions for 'Foo'",
+                "Définitions en double de '{name}'",
+            ),
+            None
+        );
+    }
+}
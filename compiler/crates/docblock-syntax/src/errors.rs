@@ -5,13 +5,18 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use common::diagnostic_codes;
 use thiserror::Error;
 
-#[derive(Clone, Copy, Debug, Error, Eq, PartialEq, Ord, PartialOrd, Hash)]
-pub enum SyntaxError {
-    #[error("Expected \"{expected}\".")]
-    ExpectedString { expected: &'static str },
+diagnostic_codes! {
+    #[derive(Clone, Copy, Debug, Error, Eq, PartialEq, Ord, PartialOrd, Hash)]
+    pub enum SyntaxError {
+        #[error("Expected \"{expected}\".")]
+        #[code("RELAY5001")]
+        ExpectedString { expected: &'static str },
 
-    #[error("Expected @ to be followed by a field name.")]
-    ExpectedFieldName,
+        #[error("Expected @ to be followed by a field name.")]
+        #[code("RELAY5002")]
+        ExpectedFieldName,
+    }
 }
@@ -0,0 +1,17 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Entry point for fuzzing the docblock parser, used by the targets in
+//! `compiler/fuzz`. Input that isn't valid UTF-8 is skipped.
+
+use common::SourceLocationKey;
+
+pub fn fuzz_docblock(data: &[u8]) {
+    if let Ok(source) = std::str::from_utf8(data) {
+        let _ = crate::parse_docblock(source, SourceLocationKey::generated());
+    }
+}
@@ -7,6 +7,8 @@
 
 mod ast;
 mod errors;
+#[cfg(feature = "fuzzing")]
+pub mod fuzzing;
 
 use std::iter::Peekable;
 use std::str::Chars;
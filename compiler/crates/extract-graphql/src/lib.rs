@@ -9,7 +9,9 @@
 #![deny(rust_2018_idioms)]
 #![deny(clippy::all)]
 
+use std::ffi::OsStr;
 use std::iter::Peekable;
+use std::path::Path;
 use std::str::CharIndices;
 
 use common::TextSource;
@@ -213,6 +215,95 @@ pub fn extract(input: &str) -> Vec<JavaScriptSourceFeature> {
     res
 }
 
+/// Whether the file is a Vue or Svelte single-file component, where the
+/// JavaScript code lives in `<script>` blocks.
+pub fn is_single_file_component(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(OsStr::to_str),
+        Some("vue") | Some("svelte")
+    )
+}
+
+/// Extract graphql`text` literals and @RelayResolver comments from a file,
+/// using the extraction appropriate for its extension.
+pub fn extract_for_path(path: &Path, input: &str) -> Vec<JavaScriptSourceFeature> {
+    if is_single_file_component(path) {
+        extract_from_single_file_component(input)
+    } else {
+        extract(input)
+    }
+}
+
+/// Extract graphql`text` literals and @RelayResolver comments from the
+/// `<script>` blocks of a Vue or Svelte single-file component. Line and
+/// column indices of the features are relative to the whole file.
+pub fn extract_from_single_file_component(input: &str) -> Vec<JavaScriptSourceFeature> {
+    let mut res = Vec::new();
+    let mut offset = 0;
+    while let Some((start, end)) = next_script_block(input, offset) {
+        let (line_offset, column_offset) = position_at(input, start);
+        res.extend(extract(&input[start..end]).into_iter().map(|feature| {
+            let source = feature.text_source();
+            // Only features on the first line of the block are shifted by the
+            // column of the block's start
+            let line_index = source.line_index + line_offset;
+            let column_index = if source.line_index == 0 {
+                source.column_index + column_offset
+            } else {
+                source.column_index
+            };
+            match feature {
+                JavaScriptSourceFeature::GraphQL(graphql_source) => {
+                    JavaScriptSourceFeature::GraphQL(GraphQLSource::new(
+                        graphql_source.to_text_source().text,
+                        line_index,
+                        column_index,
+                    ))
+                }
+                JavaScriptSourceFeature::Docblock(docblock_source) => {
+                    JavaScriptSourceFeature::Docblock(DocblockSource::new(
+                        docblock_source.to_text_source().text,
+                        line_index,
+                        column_index,
+                    ))
+                }
+            }
+        }));
+        offset = end;
+    }
+    res
+}
+
+/// Returns the byte range of the contents of the next `<script>` block
+/// starting at `offset`. An unterminated block extends to the end of the
+/// input, so that documents being edited are still handled.
+fn next_script_block(input: &str, mut offset: usize) -> Option<(usize, usize)> {
+    loop {
+        let tag_start = offset + input[offset..].find("<script")?;
+        let after_name = tag_start + "<script".len();
+        offset = after_name;
+        match input[after_name..].chars().next() {
+            Some('>') | Some('/') => {}
+            Some(c) if c.is_whitespace() => {}
+            _ => continue,
+        }
+        let start = after_name + input[after_name..].find('>')? + 1;
+        if input[..start].ends_with("/>") {
+            continue;
+        }
+        let end = input[start..]
+            .find("</script")
+            .map_or(input.len(), |end| start + end);
+        return Some((start, end));
+    }
+}
+
+fn position_at(input: &str, offset: usize) -> (usize, usize) {
+    let mut it = CharReader::new(&input[..offset]);
+    while it.next().is_some() {}
+    (it.line_index, it.column_index)
+}
+
 fn consume_identifier(it: &mut CharReader<'_>) {
     for (_, c) in it {
         match c {
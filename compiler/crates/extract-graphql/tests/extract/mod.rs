@@ -5,12 +5,14 @@
  * LICENSE file in the root directory of this source tree.
  */
 
-use extract_graphql::extract;
+use std::path::Path;
+
+use extract_graphql::extract_for_path;
 use extract_graphql::JavaScriptSourceFeature;
 use fixture_tests::Fixture;
 
 pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
-    let features = extract(fixture.content);
+    let features = extract_for_path(Path::new(fixture.file_name), fixture.content);
     Ok(features
         .into_iter()
         .map(|feature| match feature {
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<7414a7d21db69b967b49c36af99ace12>>
+ * @generated SignedSource<<ff66b275d7c052312484d02e148b1f2e>>
  */
 
 mod extract;
@@ -68,6 +68,13 @@ fn simple() {
     test_fixture(transform_fixture, "simple.flow", "extract/fixtures/simple.expected", input, expected);
 }
 
+#[test]
+fn svelte_component() {
+    let input = include_str!("extract/fixtures/svelte_component.svelte");
+    let expected = include_str!("extract/fixtures/svelte_component.expected");
+    test_fixture(transform_fixture, "svelte_component.svelte", "extract/fixtures/svelte_component.expected", input, expected);
+}
+
 #[test]
 fn tabbed() {
     let input = include_str!("extract/fixtures/tabbed.js");
@@ -82,6 +89,13 @@ fn template_literal() {
     test_fixture(transform_fixture, "template_literal.js", "extract/fixtures/template_literal.expected", input, expected);
 }
 
+#[test]
+fn vue_component() {
+    let input = include_str!("extract/fixtures/vue_component.vue");
+    let expected = include_str!("extract/fixtures/vue_component.expected");
+    test_fixture(transform_fixture, "vue_component.vue", "extract/fixtures/vue_component.expected", input, expected);
+}
+
 #[test]
 fn with_space() {
     let input = include_str!("extract/fixtures/with_space.js");
@@ -0,0 +1,66 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+const FILE_MARKER_PREFIX: &str = "%file:";
+const FILE_MARKER_SUFFIX: &str = "%";
+
+/// One of the named input documents of a multi-file fixture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixtureDocument<'a> {
+    /// The name of the document, as it should appear in locations.
+    pub name: &'a str,
+    pub content: &'a str,
+}
+
+/// Splits the content of a fixture into named documents. A document starts
+/// with a `%file: <name>%` marker line and ends at the next marker or at the
+/// end of the content, for example:
+///
+/// ```text
+/// %file: UserProfile.graphql%
+/// fragment UserProfile on User { ...UserName }
+/// %file: UserName.graphql%
+/// fragment UserName on User { name }
+/// ```
+///
+/// Content before the first marker is a document named `default_name`, and is
+/// omitted if it's blank and followed by a marker. Content without markers is
+/// returned as a single document.
+pub fn split_documents<'a>(default_name: &'a str, content: &'a str) -> Vec<FixtureDocument<'a>> {
+    let mut documents = Vec::new();
+    let mut name = default_name;
+    let mut start = 0;
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+        if let Some(marker_name) = parse_file_marker(line) {
+            let previous = &content[start..line_start];
+            if !(documents.is_empty() && previous.trim().is_empty()) {
+                documents.push(FixtureDocument {
+                    name,
+                    content: previous,
+                });
+            }
+            name = marker_name;
+            start = offset;
+        }
+    }
+    documents.push(FixtureDocument {
+        name,
+        content: &content[start..],
+    });
+    documents
+}
+
+fn parse_file_marker(line: &str) -> Option<&str> {
+    line.trim()
+        .strip_prefix(FILE_MARKER_PREFIX)?
+        .strip_suffix(FILE_MARKER_SUFFIX)
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+}
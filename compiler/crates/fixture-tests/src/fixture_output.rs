@@ -0,0 +1,122 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::fmt;
+
+const DIAGNOSTICS_HEADER: &str =
+    "================================= DIAGNOSTICS =================================";
+
+/// Structured result of a transform, for fixtures that expect diagnostics.
+///
+/// The output and the diagnostics are snapshotted in separate sections of the
+/// `.expected` file, so a change to a diagnostic shows up as a change to a
+/// single line of the DIAGNOSTICS section instead of the whole snapshot
+/// switching between an OUTPUT and an ERROR section.
+#[derive(Debug, Default)]
+pub struct FixtureOutput {
+    /// The output of the transform, if it produced one.
+    pub output: Option<String>,
+    pub diagnostics: Vec<FixtureDiagnostic>,
+}
+
+impl FixtureOutput {
+    pub fn new(output: impl Into<String>) -> Self {
+        Self {
+            output: Some(output.into()),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    pub fn from_diagnostics(diagnostics: Vec<FixtureDiagnostic>) -> Self {
+        Self {
+            output: None,
+            diagnostics,
+        }
+    }
+
+    pub fn with_diagnostics(mut self, diagnostics: Vec<FixtureDiagnostic>) -> Self {
+        self.diagnostics.extend(diagnostics);
+        self
+    }
+}
+
+impl fmt::Display for FixtureOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(output) = &self.output {
+            writeln!(f, "{}", output.trim_end())?;
+        }
+        if !self.diagnostics.is_empty() {
+            writeln!(f, "{}", DIAGNOSTICS_HEADER)?;
+            for diagnostic in &self.diagnostics {
+                writeln!(f, "{}", diagnostic)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A diagnostic as it is snapshotted in the DIAGNOSTICS section of a fixture.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FixtureDiagnostic {
+    /// The location comes first so that sorted diagnostics are in source order.
+    pub span: Option<FixtureSpan>,
+    pub severity: FixtureSeverity,
+    pub code: Option<String>,
+    pub message: String,
+}
+
+impl fmt::Display for FixtureDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.severity)?;
+        if let Some(code) = &self.code {
+            write!(f, "[{}]", code)?;
+        }
+        write!(f, ": {}", self.message.trim().replace('\n', "\n  "))?;
+        if let Some(span) = &self.span {
+            write!(f, "\n  --> {}", span)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FixtureSeverity {
+    Error,
+    Warning,
+    Info,
+    Hint,
+}
+
+impl fmt::Display for FixtureSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FixtureSeverity::Error => "error",
+            FixtureSeverity::Warning => "warning",
+            FixtureSeverity::Info => "info",
+            FixtureSeverity::Hint => "hint",
+        })
+    }
+}
+
+/// A range of a fixture file, with 1-based lines and columns. The end is
+/// exclusive.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FixtureSpan {
+    pub file_name: String,
+    pub start: (u32, u32),
+    pub end: (u32, u32),
+}
+
+impl fmt::Display for FixtureSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}-{}:{}",
+            self.file_name, self.start.0, self.start.1, self.end.0, self.end.1
+        )
+    }
+}
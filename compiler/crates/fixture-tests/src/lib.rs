@@ -44,19 +44,60 @@
 //! cargo run -p fixture-tests "example-create/tests/first_transform"
 //! ```
 //!
+//! A fixtures directory may also contain a `schema.graphql` and/or an
+//! `extensions.graphql` file. These aren't fixtures: their content is passed
+//! to every fixture of the directory as `Fixture::schema` and
+//! `Fixture::extensions`.
+//!
+//! When an output change is intentional, run the tests with `UPDATE_SNAPSHOTS=1`
+//! to rewrite the `.expected` files from the actual output. Each rewritten file
+//! is reported on stderr with the number of added and removed lines, even
+//! though the test output is captured.
+//!
+//! ```ignore
+//! UPDATE_SNAPSHOTS=1 cargo test -p example-crate
+//! ```
+//!
+//! A fixture may contain several input documents, for transforms whose
+//! behavior depends on definitions being in different files. Each document
+//! starts with a `%file: <name>%` line, and `Fixture::documents` returns them
+//! with their names so that the transform can give each its own location.
+//!
+//! Transforms that report diagnostics can return a `FixtureOutput` instead of
+//! a string. Its diagnostics (severity, code, message and span) are written to
+//! a DIAGNOSTICS section of the `.expected` file, after the output, so that the
+//! fixture doesn't need to throw to snapshot them. To migrate a fixtures
+//! directory whose transform used to fail with printed diagnostics, make the
+//! transform return `Ok(FixtureOutput)`, remove the `expected-to-throw` markers
+//! and update the snapshots:
+//!
+//! ```ignore
+//! cargo run -p fixture-tests -- --migrate-diagnostics "example-crate/tests/first_transform"
+//! UPDATE_SNAPSHOTS=1 cargo test -p example-crate
+//! ```
+//!
 //! *FB-internal: see `scripts/generate_fixture_tests.sh` to generate all.*
 //
 //! *FB-internal: use buck run //relay/oss/crates/fixture-tests:fixture-tests-bin -- <path to tests dir>
 //! *FB-internal: if you don't want to use cargo run. This is useful for development on a dev-server or
 //! *FB-internal: or machines w/o cargo installed.
 
+mod fixture_documents;
+mod fixture_output;
 mod print_diff;
 
 use std::env;
 use std::fs::File;
+use std::io;
 use std::io::prelude::*;
 use std::sync::Arc;
 
+pub use fixture_documents::split_documents;
+pub use fixture_documents::FixtureDocument;
+pub use fixture_output::FixtureDiagnostic;
+pub use fixture_output::FixtureOutput;
+pub use fixture_output::FixtureSeverity;
+pub use fixture_output::FixtureSpan;
 use lazy_static::lazy_static;
 use parking_lot::Mutex;
 
@@ -70,6 +111,21 @@ pub struct Fixture<'a> {
     pub file_name: &'a str,
     /// The text content of the fixture.
     pub content: &'a str,
+    /// The content of the `schema.graphql` file of the fixtures directory, if
+    /// any. Tests that build a schema should use it instead of their default
+    /// schema.
+    pub schema: Option<&'a str>,
+    /// The content of the `extensions.graphql` file of the fixtures directory,
+    /// if any. Tests that build a schema should extend it with these.
+    pub extensions: Option<&'a str>,
+}
+
+impl<'a> Fixture<'a> {
+    /// The named input documents of the fixture, see `split_documents`. The
+    /// content before the first marker is named after the fixture file.
+    pub fn documents(&self) -> Vec<FixtureDocument<'a>> {
+        split_documents(self.file_name, self.content)
+    }
 }
 
 /// This is an internal function and is typically called from generated code
@@ -84,10 +140,38 @@ pub fn test_fixture<T, U, V>(
     T: FnOnce(&Fixture<'_>) -> Result<U, V>,
     U: std::fmt::Display,
     V: std::fmt::Display,
+{
+    test_fixture_with_schema(
+        transform,
+        input_file_name,
+        expected_file_name,
+        input,
+        expected,
+        None,
+        None,
+    )
+}
+
+/// Like `test_fixture`, for fixtures directories with a `schema.graphql` or
+/// `extensions.graphql` file.
+pub fn test_fixture_with_schema<T, U, V>(
+    transform: T,
+    input_file_name: &str,
+    expected_file_name: &str,
+    input: &str,
+    expected: &str,
+    schema: Option<&str>,
+    extensions: Option<&str>,
+) where
+    T: FnOnce(&Fixture<'_>) -> Result<U, V>,
+    U: std::fmt::Display,
+    V: std::fmt::Display,
 {
     let fixture = Fixture {
         file_name: input_file_name,
         content: input,
+        schema,
+        extensions,
     };
     let expect_ok = !input.contains("expected-to-throw");
     let actual_result: Result<U, V>;
@@ -157,8 +241,22 @@ pub fn test_fixture<T, U, V>(
                 })
                 .write_all(actual.as_bytes())
                 .unwrap();
+            report_updated_snapshot(&file_name, expected, &actual);
         } else {
             panic!("Snapshot did not match. Run with UPDATE_SNAPSHOTS=1 to update.");
         }
     }
 }
+
+/// Writes a summary line for an updated snapshot to stderr directly: the test
+/// harness only captures the output of the `print!` family of macros.
+fn report_updated_snapshot(file_name: &str, expected: &str, actual: &str) {
+    let summary = if expected.starts_with("\x40nocommit\n") {
+        format!("Created {}\n", file_name)
+    } else {
+        let (added, removed) = print_diff::diff_stat(expected, actual);
+        format!("Updated {} (+{} -{})\n", file_name, added, removed)
+    };
+    let _guard = LOCK.lock();
+    io::stderr().write_all(summary.as_bytes()).unwrap();
+}
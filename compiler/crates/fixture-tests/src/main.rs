@@ -26,6 +26,11 @@ struct Options {
     /// from which a test file will be generated
     #[clap(name = "DIR", parse(from_os_str))]
     dirs: Vec<PathBuf>,
+
+    /// Removes the `expected-to-throw` markers of the fixtures, for transforms
+    /// migrated to report their diagnostics with a `FixtureOutput`
+    #[clap(long)]
+    migrate_diagnostics: bool,
 }
 
 #[derive(Debug)]
@@ -36,6 +41,10 @@ struct TestCase {
 }
 
 const EXPECTED_EXTENSION: &str = "expected";
+/// Files in the fixtures directory that are passed to every fixture of the
+/// directory instead of being fixtures themselves.
+const SCHEMA_FILE_NAME: &str = "schema.graphql";
+const EXTENSIONS_FILE_NAME: &str = "extensions.graphql";
 
 fn main() {
     let opt = Options::parse();
@@ -51,6 +60,13 @@ fn main() {
             if path.extension().is_none() {
                 continue;
             }
+            let file_name = path.file_name().unwrap();
+            if file_name == SCHEMA_FILE_NAME || file_name == EXTENSIONS_FILE_NAME {
+                continue;
+            }
+            if opt.migrate_diagnostics && path.extension().unwrap() != EXPECTED_EXTENSION {
+                remove_expected_to_throw_marker(&path);
+            }
             let name = sanitize_identifier(path.file_stem().unwrap().to_str().unwrap());
             let mut test_case = test_cases.entry(name.clone()).or_insert_with(|| TestCase {
                 name,
@@ -85,6 +101,16 @@ fn main() {
                 }
             }
         }
+        let schema_file = fixtures_dir
+            .join(SCHEMA_FILE_NAME)
+            .exists()
+            .then(|| format!("{}/fixtures/{}", test_name, SCHEMA_FILE_NAME));
+        let extensions_file = fixtures_dir
+            .join(EXTENSIONS_FILE_NAME)
+            .exists()
+            .then(|| format!("{}/fixtures/{}", test_name, EXTENSIONS_FILE_NAME));
+        let has_schema_files = schema_file.is_some() || extensions_file.is_some();
+
         let mut test_cases: Vec<(_, _)> = test_cases.into_iter().collect();
         test_cases.sort_by_key(|entry| entry.0.to_owned());
 
@@ -92,33 +118,51 @@ fn main() {
             .into_iter()
             .map(|(_, test_case)| {
                 let test_case_name = &test_case.name;
+                let input_file_name = test_case
+                    .input
+                    .unwrap_or_else(|| panic!(
+                        "Expected input for test {:?} to exist",
+                        test_case_name
+                    ));
+                let input_file_name = input_file_name
+                    .file_name()
+                    .and_then(|x| x.to_str())
+                    .unwrap();
+                let expected_file_name = test_case
+                    .expected
+                    .unwrap_or_else(|| panic!(
+                        "Expected output for test {:?} to exist",
+                        test_case_name
+                    ));
+                let expected_file_name = expected_file_name
+                    .file_name()
+                    .and_then(|x| x.to_str())
+                    .unwrap();
+                let call = if has_schema_files {
+                    format!(
+                        r#"let schema = {3};
+    let extensions = {4};
+    test_fixture_with_schema(transform_fixture, "{0}", "{1}/fixtures/{2}", input, expected, schema, extensions);"#,
+                        input_file_name,
+                        &test_name,
+                        expected_file_name,
+                        include_str_option(&schema_file),
+                        include_str_option(&extensions_file),
+                    )
+                } else {
+                    format!(
+                        r#"test_fixture(transform_fixture, "{0}", "{1}/fixtures/{2}", input, expected);"#,
+                        input_file_name, &test_name, expected_file_name,
+                    )
+                };
                 format!(
                     r#"#[test]
 fn {0}() {{
     let input = include_str!("{1}/fixtures/{2}");
     let expected = include_str!("{1}/fixtures/{3}");
-    test_fixture(transform_fixture, "{2}", "{1}/fixtures/{3}", input, expected);
+    {4}
 }}"#,
-                    test_case.name,
-                    &test_name,
-                    test_case
-                        .input
-                        .unwrap_or_else(|| panic!(
-                            "Expected input for test {:?} to exist",
-                            test_case_name
-                        ))
-                        .file_name()
-                        .and_then(|x| x.to_str())
-                        .unwrap(),
-                    test_case
-                        .expected
-                        .unwrap_or_else(|| panic!(
-                            "Expected output for test {:?} to exist",
-                            test_case_name
-                        ))
-                        .file_name()
-                        .and_then(|x| x.to_str())
-                        .unwrap()
+                    test_case.name, &test_name, input_file_name, expected_file_name, call,
                 )
             })
             .collect::<Vec<_>>()
@@ -165,12 +209,17 @@ fn {0}() {{
 mod {test_name};
 
 use {test_name}::transform_fixture;
-use fixture_tests::test_fixture;
+use fixture_tests::{test_fixture};
 
 {test_cases}
 ",
             header = header,
             test_name = &test_name,
+            test_fixture = if has_schema_files {
+                "test_fixture_with_schema"
+            } else {
+                "test_fixture"
+            },
             test_cases = test_cases,
         );
         file.write_all(sign_file(&content).as_bytes()).unwrap();
@@ -202,6 +251,32 @@ fn check_targets_file(test_dir: &Path) {
     }
 }
 
+/// Removes the lines that only contain an `expected-to-throw` marker, within a
+/// comment in the syntax of the fixture.
+fn remove_expected_to_throw_marker(path: &Path) {
+    let content = fs::read_to_string(path).unwrap();
+    let migrated = content
+        .split_inclusive('\n')
+        .filter(|line| {
+            line.trim()
+                .trim_start_matches(|chr| matches!(chr, '#' | '/' | '*'))
+                .trim()
+                != "expected-to-throw"
+        })
+        .collect::<String>();
+    if migrated != content {
+        fs::write(path, migrated).unwrap();
+        eprintln!("Removed expected-to-throw marker from {:?}", path);
+    }
+}
+
+fn include_str_option(path: &Option<String>) -> String {
+    match path {
+        Some(path) => format!("Some(include_str!(\"{}\"))", path),
+        None => "None".to_string(),
+    }
+}
+
 fn sanitize_identifier(input: &str) -> String {
     input
         .chars()
@@ -30,6 +30,18 @@ pub(crate) fn print_diff(expected: &str, actual: &str) {
     }
 }
 
+/// Returns the number of added and removed lines between the expected and
+/// actual strings.
+pub(crate) fn diff_stat(expected: &str, actual: &str) -> (usize, usize) {
+    diff::lines(expected, actual)
+        .iter()
+        .fold((0, 0), |(added, removed), change| match change {
+            Both(..) => (added, removed),
+            Left(_) => (added, removed + 1),
+            Right(_) => (added + 1, removed),
+        })
+}
+
 fn is_change<T>(result: &diff::Result<T>) -> bool {
     matches!(result, Left(_) | Right(_))
 }
@@ -0,0 +1,38 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use fixture_tests::Fixture;
+use fixture_tests::FixtureDiagnostic;
+use fixture_tests::FixtureOutput;
+use fixture_tests::FixtureSeverity;
+use fixture_tests::FixtureSpan;
+
+/// Uppercases the fixture and reports a warning for each `todo`.
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<FixtureOutput, String> {
+    let diagnostics = fixture
+        .content
+        .lines()
+        .enumerate()
+        .flat_map(|(line_index, line)| {
+            line.match_indices("todo").map(move |(column, _)| {
+                let line = line_index as u32 + 1;
+                let column = column as u32 + 1;
+                FixtureDiagnostic {
+                    span: Some(FixtureSpan {
+                        file_name: fixture.file_name.to_string(),
+                        start: (line, column),
+                        end: (line, column + 4),
+                    }),
+                    severity: FixtureSeverity::Warning,
+                    code: Some("Todo".to_string()),
+                    message: "Unresolved todo.".to_string(),
+                }
+            })
+        })
+        .collect();
+    Ok(FixtureOutput::new(fixture.content.to_uppercase()).with_diagnostics(diagnostics))
+}
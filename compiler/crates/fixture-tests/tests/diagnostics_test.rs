@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<2b907398674efd74889a1872e68ea5c0>>
+ */
+
+mod diagnostics;
+
+use diagnostics::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn no_todos() {
+    let input = include_str!("diagnostics/fixtures/no-todos.txt");
+    let expected = include_str!("diagnostics/fixtures/no-todos.expected");
+    test_fixture(transform_fixture, "no-todos.txt", "diagnostics/fixtures/no-todos.expected", input, expected);
+}
+
+#[test]
+fn todos() {
+    let input = include_str!("diagnostics/fixtures/todos.txt");
+    let expected = include_str!("diagnostics/fixtures/todos.expected");
+    test_fixture(transform_fixture, "todos.txt", "diagnostics/fixtures/todos.expected", input, expected);
+}
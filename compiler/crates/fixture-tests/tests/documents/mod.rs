@@ -0,0 +1,17 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use fixture_tests::Fixture;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    Ok(fixture
+        .documents()
+        .iter()
+        .map(|document| format!("{}:\n{}", document.name, document.content.trim_end()))
+        .collect::<Vec<_>>()
+        .join("\n---\n"))
+}
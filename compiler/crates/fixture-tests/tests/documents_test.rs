@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<ac52e4e8df3f617a091da8580037b155>>
+ */
+
+mod documents;
+
+use documents::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn content_before_first_marker() {
+    let input = include_str!("documents/fixtures/content-before-first-marker.txt");
+    let expected = include_str!("documents/fixtures/content-before-first-marker.expected");
+    test_fixture(transform_fixture, "content-before-first-marker.txt", "documents/fixtures/content-before-first-marker.expected", input, expected);
+}
+
+#[test]
+fn multiple_documents() {
+    let input = include_str!("documents/fixtures/multiple-documents.txt");
+    let expected = include_str!("documents/fixtures/multiple-documents.expected");
+    test_fixture(transform_fixture, "multiple-documents.txt", "documents/fixtures/multiple-documents.expected", input, expected);
+}
+
+#[test]
+fn single_document() {
+    let input = include_str!("documents/fixtures/single-document.txt");
+    let expected = include_str!("documents/fixtures/single-document.expected");
+    test_fixture(transform_fixture, "single-document.txt", "documents/fixtures/single-document.expected", input, expected);
+}
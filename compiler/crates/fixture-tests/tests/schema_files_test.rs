@@ -0,0 +1,22 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<41e838a91c78740a42b6fde1a23cf660>>
+ */
+
+mod schema_files;
+
+use schema_files::transform_fixture;
+use fixture_tests::test_fixture_with_schema;
+
+#[test]
+fn greeting() {
+    let input = include_str!("schema_files/fixtures/greeting.graphql");
+    let expected = include_str!("schema_files/fixtures/greeting.expected");
+    let schema = Some(include_str!("schema_files/fixtures/schema.graphql"));
+    let extensions = Some(include_str!("schema_files/fixtures/extensions.graphql"));
+    test_fixture_with_schema(transform_fixture, "greeting.graphql", "schema_files/fixtures/greeting.expected", input, expected, schema, extensions);
+}
@@ -11,6 +11,7 @@ use common::SourceLocationKey;
 use common::Span;
 use common::TextSource;
 use graphql_cli::DiagnosticPrinter;
+use graphql_cli::DiagnosticsFormat;
 
 const EXAMPLE: &str = "fragment Example on User {
   photo(size: 40) {
@@ -19,9 +20,6 @@ const EXAMPLE: &str = "fragment Example on User {
 }";
 
 fn main() {
-    let printer =
-        DiagnosticPrinter::new(|_| Some(TextSource::from_whole_document(EXAMPLE.to_string())));
-
     let span_photo = Span::new(29, 34);
     let span_type = Span::new(20, 24);
 
@@ -29,9 +27,19 @@ fn main() {
     let location_field = Location::new(source_location, span_photo);
     let location_type = Location::new(source_location, span_type);
 
-    let mut res = String::new();
     let diagnostic = Diagnostic::error("Field `photo` is not defined type `User`", location_field)
         .annotate("type defined here", location_type);
-    printer.write_diagnostic(&mut res, &diagnostic).unwrap();
-    print!("{}", res);
+
+    for format in [
+        DiagnosticsFormat::Plain,
+        DiagnosticsFormat::Rich,
+        DiagnosticsFormat::Short,
+    ] {
+        let printer =
+            DiagnosticPrinter::new(|_| Some(TextSource::from_whole_document(EXAMPLE.to_string())))
+                .with_format(format);
+        let mut res = String::new();
+        printer.write_diagnostic(&mut res, &diagnostic).unwrap();
+        println!("{:?}:\n{}\n", format, res);
+    }
 }
@@ -0,0 +1,278 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use common::Span;
+use common::TextSource;
+
+use crate::Style;
+use crate::Styles;
+
+/// Lines of context printed around the annotated lines.
+const CONTEXT: usize = 1;
+
+/// A span of a source underlined with `marker`, with an optional label
+/// printed after the underline.
+pub struct Annotation {
+    pub span: Span,
+    pub style: Style,
+    pub marker: char,
+    pub label: Option<String>,
+}
+
+/// Prints the lines of a source covered by some annotations, with a gutter of
+/// line numbers and a row of markers under each annotated line, e.g.:
+///
+/// ```text
+/// 3 │   me {
+/// 4 │     nmae
+///   │     ^^^^
+/// 5 │   }
+/// ```
+pub struct AnnotatedSourcePrinter<'a> {
+    source: &'a TextSource,
+    lines: Vec<Vec<char>>,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> AnnotatedSourcePrinter<'a> {
+    pub fn new(source: &'a TextSource) -> Self {
+        let mut lines = vec![Vec::new()];
+        let mut line_starts = vec![0];
+        for (char_index, chr) in source.text.chars().enumerate() {
+            match chr {
+                '\n' => {
+                    lines.push(Vec::new());
+                    line_starts.push(char_index + 1);
+                }
+                '\r' => {}
+                // Printed as a single column to keep the markers aligned
+                '\t' => lines.last_mut().unwrap().push(' '),
+                _ => lines.last_mut().unwrap().push(chr),
+            }
+        }
+        Self {
+            source,
+            lines,
+            line_starts,
+        }
+    }
+
+    /// The largest line number printed for `annotations`, to align the
+    /// gutters of several frames.
+    pub fn last_line_number(&self, annotations: &[Annotation]) -> usize {
+        self.printed_lines(annotations)
+            .into_iter()
+            .next_back()
+            .map_or(0, |line| self.line_number(line))
+    }
+
+    pub fn write_annotations<W: Write>(
+        &self,
+        writer: &mut W,
+        annotations: &[Annotation],
+        gutter_width: usize,
+    ) -> std::fmt::Result {
+        let gutter_padding = " ".repeat(gutter_width);
+        let mut previous_line = None;
+        for line in self.printed_lines(annotations) {
+            if matches!(previous_line, Some(previous) if previous + 1 != line) {
+                writeln!(
+                    writer,
+                    "{} {}",
+                    gutter_padding,
+                    Styles::blue("·".to_string())
+                )?;
+            }
+            previous_line = Some(line);
+
+            writeln!(
+                writer,
+                "{} {}",
+                Styles::blue(format!(
+                    "{:>width$} │",
+                    self.line_number(line),
+                    width = gutter_width
+                )),
+                self.lines[line].iter().collect::<String>().trim_end()
+            )?;
+
+            let mut rows: Vec<(usize, usize, &Annotation, bool)> = annotations
+                .iter()
+                .filter_map(|annotation| {
+                    let rows = self.marker_rows(annotation.span);
+                    let is_last_row = rows.last().map(|(row_line, ..)| *row_line) == Some(line);
+                    rows.into_iter()
+                        .find(|(row_line, ..)| *row_line == line)
+                        .map(|(_, start, end)| (start, end, annotation, is_last_row))
+                })
+                .collect();
+            rows.sort_by_key(|(start, ..)| *start);
+            for (start, end, annotation, is_last_row) in rows {
+                let mut row = format!(
+                    "{}{}",
+                    " ".repeat(start),
+                    (annotation.style)(annotation.marker.to_string().repeat(end - start))
+                );
+                if let (true, Some(label)) = (is_last_row, &annotation.label) {
+                    write!(row, " {}", (annotation.style)(label.to_string()))?;
+                }
+                writeln!(
+                    writer,
+                    "{} {}",
+                    Styles::blue(format!("{} │", gutter_padding)),
+                    row
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn line_number(&self, line: usize) -> usize {
+        line + self.source.line_index + 1
+    }
+
+    /// The position of a char index as a line and column of the source.
+    fn position(&self, char_index: usize) -> (usize, usize) {
+        let line = match self.line_starts.binary_search(&char_index) {
+            Ok(line) => line,
+            Err(line) => line - 1,
+        };
+        let column = (char_index - self.line_starts[line]).min(self.lines[line].len());
+        (line, column)
+    }
+
+    /// The columns to underline on each line of `span`. The lines after the
+    /// first are underlined from their indentation, and empty spans with a
+    /// single marker.
+    fn marker_rows(&self, span: Span) -> Vec<(usize, usize, usize)> {
+        let (start_line, start_column) = self.position(span.start as usize);
+        let (end_line, end_column) = self.position(span.end as usize);
+        let mut rows = Vec::new();
+        for line in start_line..=end_line {
+            let line_chars = &self.lines[line];
+            let start = if line == start_line {
+                start_column
+            } else {
+                line_chars
+                    .iter()
+                    .position(|chr| !chr.is_whitespace())
+                    .unwrap_or(line_chars.len())
+            };
+            let end = if line == end_line {
+                end_column
+            } else {
+                line_chars.len()
+            };
+            if end > start {
+                rows.push((line, start, end));
+            }
+        }
+        if rows.is_empty() {
+            rows.push((start_line, start_column, start_column + 1));
+        }
+        rows
+    }
+
+    /// The annotated lines and their context, without blank context lines.
+    fn printed_lines(&self, annotations: &[Annotation]) -> BTreeSet<usize> {
+        let mut printed_lines = BTreeSet::new();
+        for annotation in annotations {
+            let rows = self.marker_rows(annotation.span);
+            let first_line = rows.first().map_or(0, |(line, ..)| *line);
+            let last_line = rows.last().map_or(0, |(line, ..)| *line);
+            for line in first_line.saturating_sub(CONTEXT)..=last_line + CONTEXT {
+                if line >= self.lines.len() {
+                    break;
+                }
+                let is_context = line < first_line || line > last_line;
+                if !(is_context && self.lines[line].iter().all(|chr| chr.is_whitespace())) {
+                    printed_lines.insert(line);
+                }
+            }
+        }
+        printed_lines
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::Span;
+    use common::TextSource;
+
+    use super::AnnotatedSourcePrinter;
+    use super::Annotation;
+    use crate::Styles;
+
+    fn print(text: &str, annotations: &[Annotation]) -> String {
+        colored::control::set_override(false);
+        let source = TextSource::new(text, 0, 0);
+        let printer = AnnotatedSourcePrinter::new(&source);
+        let gutter_width = printer.last_line_number(annotations).to_string().len();
+        let mut printed = String::new();
+        printer
+            .write_annotations(&mut printed, annotations, gutter_width)
+            .unwrap();
+        printed
+    }
+
+    fn annotation(text: &str, start: &str, end: &str, label: Option<&str>) -> Annotation {
+        let start = text.find(start).unwrap();
+        let end = text[start..].find(end).unwrap() + start + end.len();
+        Annotation {
+            span: Span::new(start as u32, end as u32),
+            style: Styles::red,
+            marker: '^',
+            label: label.map(|label| label.to_string()),
+        }
+    }
+
+    #[test]
+    fn single_line_annotations_test() {
+        let text = "fragment Foo on User {\n  id\n  name\n\n  email\n  phone\n  url\n}\n";
+        let printed = print(
+            text,
+            &[
+                annotation(text, "id", "id", Some("first")),
+                annotation(text, "url", "url", None),
+            ],
+        );
+        assert_eq!(
+            printed,
+            r#"1 │ fragment Foo on User {
+2 │   id
+  │   ^^ first
+3 │   name
+  ·
+6 │   phone
+7 │   url
+  │   ^^^
+8 │ }
+"#
+        );
+    }
+
+    #[test]
+    fn multi_line_annotation_test() {
+        let text = "query Foo {\n  me {\n    id\n  }\n}";
+        let printed = print(text, &[annotation(text, "me", "}", Some("label"))]);
+        assert_eq!(
+            printed,
+            r#"1 │ query Foo {
+2 │   me {
+  │   ^^^^
+3 │     id
+  │     ^^
+4 │   }
+  │   ^ label
+5 │ }
+"#
+        );
+    }
+}
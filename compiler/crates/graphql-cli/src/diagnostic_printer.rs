@@ -9,21 +9,47 @@ use std::fmt::Write;
 
 use colored::Colorize;
 use common::Diagnostic;
+use common::DiagnosticSeverity;
 use common::Location;
 use common::SourceLocationKey;
 use common::TextSource;
 
+use crate::annotated_source_printer::AnnotatedSourcePrinter;
+use crate::annotated_source_printer::Annotation;
 use crate::SourcePrinter;
 use crate::Style;
 use crate::Styles;
 
+/// How `DiagnosticPrinter` renders a diagnostic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiagnosticsFormat {
+    /// The message, then the path and an excerpt of the source of each
+    /// location.
+    Plain,
+    /// A `severity[code]: message` header, then frames of the sources with
+    /// the location and the related locations underlined and labeled, then
+    /// notes with the rest of the message and the available fix.
+    Rich,
+    /// A single `path:line:column: severity[code]: message` line.
+    Short,
+}
+
 pub struct DiagnosticPrinter<T: Sources> {
     sources: T,
+    format: DiagnosticsFormat,
 }
 
 impl<TSources: Sources> DiagnosticPrinter<TSources> {
     pub fn new(sources: TSources) -> Self {
-        Self { sources }
+        Self {
+            sources,
+            format: DiagnosticsFormat::Plain,
+        }
+    }
+
+    pub fn with_format(mut self, format: DiagnosticsFormat) -> Self {
+        self.format = format;
+        self
     }
 
     pub fn diagnostics_to_string(&self, diagnostics: &[Diagnostic]) -> String {
@@ -45,17 +71,28 @@ impl<TSources: Sources> DiagnosticPrinter<TSources> {
         writer: &mut W,
         diagnostic: &Diagnostic,
     ) -> std::fmt::Result {
+        match self.format {
+            DiagnosticsFormat::Plain => self.write_plain_diagnostic(writer, diagnostic),
+            DiagnosticsFormat::Rich => self.write_rich_diagnostic(writer, diagnostic),
+            DiagnosticsFormat::Short => self.write_short_diagnostic(writer, diagnostic),
+        }
+    }
+
+    fn write_plain_diagnostic<W: Write>(
+        &self,
+        writer: &mut W,
+        diagnostic: &Diagnostic,
+    ) -> std::fmt::Result {
+        let localized_message = diagnostic.localized_message();
         let (message, text_color): (String, Style) = match diagnostic.severity() {
-            common::DiagnosticSeverity::ERROR => {
-                (format!("✖︎ {}", diagnostic.message()), Styles::red)
-            }
+            common::DiagnosticSeverity::ERROR => (format!("✖︎ {}", localized_message), Styles::red),
             common::DiagnosticSeverity::WARNING => {
-                (format!("︎⚠ {}", diagnostic.message()), Styles::yellow)
+                (format!("︎⚠ {}", localized_message), Styles::yellow)
             }
             common::DiagnosticSeverity::INFORMATION | common::DiagnosticSeverity::HINT => {
-                (format!("ℹ {}", diagnostic.message()), Styles::blue)
+                (format!("ℹ {}", localized_message), Styles::blue)
             }
-            _ => (format!("ℹ {}", diagnostic.message()), Styles::blue),
+            _ => (format!("ℹ {}", localized_message), Styles::blue),
         };
 
         writeln!(writer, "{}\n", text_color(message))?;
@@ -72,6 +109,148 @@ impl<TSources: Sources> DiagnosticPrinter<TSources> {
         Ok(())
     }
 
+    fn write_rich_diagnostic<W: Write>(
+        &self,
+        writer: &mut W,
+        diagnostic: &Diagnostic,
+    ) -> std::fmt::Result {
+        let (severity, style) = severity_label(diagnostic.severity());
+        let message = diagnostic.localized_message();
+        let mut message_lines = message.lines();
+        writeln!(
+            writer,
+            "{}: {}",
+            style(header(severity, diagnostic)).bold(),
+            message_lines.next().unwrap_or_default().bold()
+        )?;
+
+        // The annotations of each source, in the order of their first location
+        let mut frames: Vec<(SourceLocationKey, Option<TextSource>, Vec<Annotation>)> = vec![(
+            diagnostic.location().source_location(),
+            self.sources.get(diagnostic.location().source_location()),
+            vec![Annotation {
+                span: *diagnostic.location().span(),
+                style,
+                marker: '^',
+                label: None,
+            }],
+        )];
+        let mut notes: Vec<String> = message_lines
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.trim().to_string())
+            .collect();
+        for related_information in diagnostic.related_information() {
+            let source_location = related_information.location.source_location();
+            let annotation = Annotation {
+                span: *related_information.location.span(),
+                style: Styles::blue,
+                marker: '-',
+                label: Some(related_information.message.to_string()),
+            };
+            if let Some((_, _, annotations)) = frames
+                .iter_mut()
+                .find(|(key, source, _)| *key == source_location && source.is_some())
+            {
+                annotations.push(annotation);
+            } else if let Some(source) = self.sources.get(source_location) {
+                frames.push((source_location, Some(source), vec![annotation]));
+            } else {
+                notes.push(format!(
+                    "{} ({})",
+                    related_information.message,
+                    source_location.path()
+                ));
+            }
+        }
+
+        let gutter_width = frames
+            .iter()
+            .filter_map(|(_, source, annotations)| {
+                source
+                    .as_ref()
+                    .map(|source| AnnotatedSourcePrinter::new(source).last_line_number(annotations))
+            })
+            .max()
+            .unwrap_or(0)
+            .to_string()
+            .len();
+        let gutter_padding = " ".repeat(gutter_width);
+        for (source_location, source, annotations) in &frames {
+            match source {
+                Some(source) => {
+                    let range = source.to_span_range(&annotations[0].span);
+                    writeln!(
+                        writer,
+                        "{}{} {}:{}:{}",
+                        gutter_padding,
+                        Styles::blue("-->".to_string()),
+                        source_location.path(),
+                        range.start.line + 1,
+                        range.start.character + 1
+                    )?;
+                    writeln!(
+                        writer,
+                        "{} {}",
+                        gutter_padding,
+                        Styles::blue("│".to_string())
+                    )?;
+                    AnnotatedSourcePrinter::new(source).write_annotations(
+                        writer,
+                        annotations,
+                        gutter_width,
+                    )?;
+                }
+                None => writeln!(
+                    writer,
+                    "{}{} {}: <missing source>",
+                    gutter_padding,
+                    Styles::blue("-->".to_string()),
+                    source_location.path()
+                )?,
+            }
+        }
+
+        for note in notes {
+            writeln!(writer, "{} = {}: {}", gutter_padding, "note".bold(), note)?;
+        }
+        if let Some(fix) = diagnostic.safe_fix() {
+            writeln!(
+                writer,
+                "{} = {}: {} (applied by `relay-compiler --fix`)",
+                gutter_padding,
+                "help".bold(),
+                fix.title()
+            )?;
+        }
+        Ok(())
+    }
+
+    fn write_short_diagnostic<W: Write>(
+        &self,
+        writer: &mut W,
+        diagnostic: &Diagnostic,
+    ) -> std::fmt::Result {
+        let (severity, style) = severity_label(diagnostic.severity());
+        let location = diagnostic.location();
+        let position = self
+            .sources
+            .get(location.source_location())
+            .map(|source| {
+                let range = source.to_span_range(location.span());
+                format!(":{}:{}", range.start.line + 1, range.start.character + 1)
+            })
+            .unwrap_or_default();
+        let message = diagnostic.localized_message();
+        write!(
+            writer,
+            "{}{}: {}: {}",
+            location.source_location().path(),
+            position,
+            style(header(severity, diagnostic)),
+            message.lines().next().unwrap_or_default()
+        )
+    }
+
     /// Writes the file path and slice of the source code for the given location.
     fn write_source<W: Write>(
         &self,
@@ -106,6 +285,23 @@ impl<TSources: Sources> DiagnosticPrinter<TSources> {
     }
 }
 
+fn severity_label(severity: DiagnosticSeverity) -> (&'static str, Style) {
+    match severity {
+        DiagnosticSeverity::ERROR => ("error", Styles::red),
+        DiagnosticSeverity::WARNING => ("warning", Styles::yellow),
+        DiagnosticSeverity::INFORMATION => ("info", Styles::blue),
+        _ => ("hint", Styles::blue),
+    }
+}
+
+/// The severity of the diagnostic, followed by its stable code if it has one.
+fn header(severity: &str, diagnostic: &Diagnostic) -> String {
+    match diagnostic.error_code() {
+        Some(code) => format!("{}[{}]", severity, code),
+        None => severity.to_string(),
+    }
+}
+
 pub trait Sources {
     fn get(&self, source_location: SourceLocationKey) -> Option<TextSource>;
 }
@@ -118,3 +314,91 @@ where
         self(source_location)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use common::Diagnostic;
+    use common::Location;
+    use common::SourceLocationKey;
+    use common::Span;
+    use common::TextSource;
+
+    use super::DiagnosticPrinter;
+    use super::DiagnosticsFormat;
+
+    const SOURCE: &str = "query Foo {\n  me {\n    nmae\n  }\n}\n";
+
+    fn location(path: &str, needle: &str) -> Location {
+        let start = SOURCE.find(needle).unwrap();
+        Location::new(
+            SourceLocationKey::standalone(path),
+            Span::new(start as u32, (start + needle.len()) as u32),
+        )
+    }
+
+    fn print(diagnostic: &Diagnostic, format: DiagnosticsFormat) -> String {
+        colored::control::set_override(false);
+        let printer = DiagnosticPrinter::new(|source_location: SourceLocationKey| {
+            if source_location.path() == "foo.graphql" {
+                Some(TextSource::new(SOURCE, 0, 0))
+            } else {
+                None
+            }
+        })
+        .with_format(format);
+        printer.diagnostic_to_string(diagnostic)
+    }
+
+    #[test]
+    fn rich_format_test() {
+        let diagnostic = Diagnostic::error(
+            "Unknown field `nmae`\nDid you mean `name`?",
+            location("foo.graphql", "nmae"),
+        )
+        .annotate("In the query `Foo`", location("foo.graphql", "Foo"))
+        .annotate("Defined here", location("schema.graphql", "me"));
+        assert_eq!(
+            print(&diagnostic, DiagnosticsFormat::Rich),
+            r#"error: Unknown field `nmae`
+ --> foo.graphql:3:5
+  │
+1 │ query Foo {
+  │       --- In the query `Foo`
+2 │   me {
+3 │     nmae
+  │     ^^^^
+4 │   }
+  = note: Did you mean `name`?
+  = note: Defined here (schema.graphql)
+"#
+        );
+    }
+
+    #[test]
+    fn rich_format_missing_source_test() {
+        let diagnostic =
+            Diagnostic::error("Unknown field `nmae`", location("missing.graphql", "nmae"));
+        assert_eq!(
+            print(&diagnostic, DiagnosticsFormat::Rich),
+            "error: Unknown field `nmae`\n --> missing.graphql: <missing source>\n"
+        );
+    }
+
+    #[test]
+    fn short_format_test() {
+        let diagnostic = Diagnostic::error(
+            "Unknown field `nmae`\nDid you mean `name`?",
+            location("foo.graphql", "nmae"),
+        );
+        assert_eq!(
+            print(&diagnostic, DiagnosticsFormat::Short),
+            "foo.graphql:3:5: error: Unknown field `nmae`"
+        );
+        let diagnostic =
+            Diagnostic::warning("Unused fragment", location("missing.graphql", "me"), vec![]);
+        assert_eq!(
+            print(&diagnostic, DiagnosticsFormat::Short),
+            "missing.graphql: warning: Unused fragment"
+        );
+    }
+}
@@ -5,11 +5,13 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+mod annotated_source_printer;
 mod diagnostic_printer;
 mod source_printer;
 mod text_style;
 
 pub use diagnostic_printer::DiagnosticPrinter;
+pub use diagnostic_printer::DiagnosticsFormat;
 pub use diagnostic_printer::Sources;
 pub use source_printer::SourcePrinter;
 pub use text_style::Style;
@@ -11,6 +11,7 @@ use std::collections::VecDeque;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
+use common::diagnostic_codes;
 use common::Diagnostic;
 use common::DiagnosticsResult;
 use common::Location;
@@ -564,43 +565,50 @@ mod ignoring_type_and_location {
     }
 }
 
-#[derive(Clone, Debug, Error, Eq, PartialEq, Ord, PartialOrd, Hash)]
-enum ValidationMessage {
-    #[error(
-        "Field '{response_key}' is ambiguous because it references two different fields: '{l_name}' and '{r_name}'"
-    )]
-    AmbiguousFieldAlias {
-        response_key: StringKey,
-        l_name: StringKey,
-        r_name: StringKey,
-    },
-
-    #[error(
-        "Field '{response_key}' is ambiguous because it references fields with different types: '{l_name}' with type '{l_type_string}' and '{r_name}' with type '{r_type_string}'"
-    )]
-    AmbiguousFieldType {
-        response_key: StringKey,
-        l_name: StringKey,
-        l_type_string: String,
-        r_name: StringKey,
-        r_type_string: String,
-    },
-
-    #[error(
-        "Expected all fields on the same parent with the name or alias `{field_name}` to have the same argument values after applying fragment arguments. This field has the applied argument values: {arguments_a}"
-    )]
-    InvalidSameFieldWithDifferentArguments {
-        field_name: StringKey,
-        arguments_a: String,
-    },
-
-    #[error(
-        "Field '{response_key}' is marked with @stream in one place, and not marked in another place. Please use alias to distinguish the 2 fields.'"
-    )]
-    StreamConflictOnlyUsedInOnePlace { response_key: StringKey },
-
-    #[error(
-        "Field '{response_key}' is marked with @stream in multiple places. Please use an alias to distinguish them'"
-    )]
-    StreamConflictUsedInMultiplePlaces { response_key: StringKey },
+diagnostic_codes! {
+    #[derive(Clone, Debug, Error, Eq, PartialEq, Ord, PartialOrd, Hash)]
+    enum ValidationMessage {
+        #[error(
+            "Field '{response_key}' is ambiguous because it references two different fields: '{l_name}' and '{r_name}'"
+        )]
+        #[code("RELAY8001")]
+        AmbiguousFieldAlias {
+            response_key: StringKey,
+            l_name: StringKey,
+            r_name: StringKey,
+        },
+
+        #[error(
+            "Field '{response_key}' is ambiguous because it references fields with different types: '{l_name}' with type '{l_type_string}' and '{r_name}' with type '{r_type_string}'"
+        )]
+        #[code("RELAY8002")]
+        AmbiguousFieldType {
+            response_key: StringKey,
+            l_name: StringKey,
+            l_type_string: String,
+            r_name: StringKey,
+            r_type_string: String,
+        },
+
+        #[error(
+            "Expected all fields on the same parent with the name or alias `{field_name}` to have the same argument values after applying fragment arguments. This field has the applied argument values: {arguments_a}"
+        )]
+        #[code("RELAY8003")]
+        InvalidSameFieldWithDifferentArguments {
+            field_name: StringKey,
+            arguments_a: String,
+        },
+
+        #[error(
+            "Field '{response_key}' is marked with @stream in one place, and not marked in another place. Please use alias to distinguish the 2 fields.'"
+        )]
+        #[code("RELAY8004")]
+        StreamConflictOnlyUsedInOnePlace { response_key: StringKey },
+
+        #[error(
+            "Field '{response_key}' is marked with @stream in multiple places. Please use an alias to distinguish them'"
+        )]
+        #[code("RELAY8005")]
+        StreamConflictUsedInMultiplePlaces { response_key: StringKey },
+    }
 }
@@ -10,6 +10,8 @@ use std::collections::HashMap;
 
 use common::ArgumentName;
 use common::Diagnostic;
+use common::DiagnosticEdit;
+use common::DiagnosticFix;
 use common::DiagnosticsResult;
 use common::DirectiveName;
 use common::Location;
@@ -39,6 +41,7 @@ use schema::ArgumentDefinitions;
 use schema::Enum;
 use schema::FieldID;
 use schema::InputObject;
+use schema::ObjectID;
 use schema::SDLSchema;
 use schema::Scalar;
 use schema::Schema;
@@ -688,13 +691,22 @@ impl<'schema, 'signatures, 'options> Builder<'schema, 'signatures, 'options> {
                         &possible_argument_names,
                         5,
                     );
-                    Err(vec![Diagnostic::error_with_data(
+                    let fix = if suggestions.is_empty() {
+                        self.missing_argument_definition_fix(signature, arg)
+                    } else {
+                        None
+                    };
+                    let diagnostic = Diagnostic::error_with_data(
                         ValidationMessageWithData::UnknownArgument {
                             argument_name: arg.name.value,
                             suggestions,
                         },
                         self.location.with_span(arg.span),
-                    )])
+                    );
+                    Err(vec![match fix {
+                        Some(fix) => diagnostic.with_safe_fix(fix),
+                        None => diagnostic,
+                    }])
                 }
             })
             .collect();
@@ -708,6 +720,43 @@ impl<'schema, 'signatures, 'options> Builder<'schema, 'signatures, 'options> {
         }
     }
 
+    /// Adds an argument of a fragment spread that is missing from the
+    /// `@argumentDefinitions` of the fragment, when its value is a variable
+    /// defined with a type by the definition of the spread.
+    fn missing_argument_definition_fix(
+        &self,
+        signature: &FragmentSignature,
+        argument: &graphql_syntax::Argument,
+    ) -> Option<DiagnosticFix> {
+        let insertion = signature.argument_definitions_insertion?;
+        let variable_definition = match &argument.value {
+            graphql_syntax::Value::Variable(variable) => {
+                self.defined_variables.get(&VariableName(variable.name))?
+            }
+            _ => return None,
+        };
+        let entry = format!(
+            "{}: {{type: \"{}\"}}",
+            argument.name.value,
+            self.schema.get_type_string(&variable_definition.type_)
+        );
+        let replacement = if signature.variable_definitions.is_empty() {
+            format!(" @{}({})", ARGUMENT_DEFINITION.0, entry)
+        } else {
+            format!(", {}", entry)
+        };
+        Some(DiagnosticFix::Edits {
+            title: format!(
+                "Add '{}' to the @{} of '{}'",
+                argument.name.value, ARGUMENT_DEFINITION.0, signature.name.item
+            ),
+            edits: vec![DiagnosticEdit {
+                location: insertion,
+                replacement,
+            }],
+        })
+    }
+
     fn build_fragment_spread(
         &mut self,
         spread: &graphql_syntax::FragmentSpread,
@@ -748,7 +797,7 @@ impl<'schema, 'signatures, 'options> Builder<'schema, 'signatures, 'options> {
                     .signatures
                     .values()
                     .filter(|signature| {
-                        self.find_conflicting_parent_type(parent_types, signature.type_condition)
+                        self.find_type_condition_conflict(parent_types, signature.type_condition)
                             .is_none()
                     })
                     .map(|signature| signature.name.item.0)
@@ -765,16 +814,34 @@ impl<'schema, 'signatures, 'options> Builder<'schema, 'signatures, 'options> {
             }
         };
 
-        if let Some(parent_type) =
-            self.find_conflicting_parent_type(parent_types, signature.type_condition)
+        if let Some(conflict) =
+            self.find_type_condition_conflict(parent_types, signature.type_condition)
         {
             // no possible overlap
-            return Err(vec![Diagnostic::error(
-                ValidationMessage::InvalidFragmentSpreadType {
-                    fragment_name: FragmentDefinitionName(spread.name.value),
-                    parent_type: self.schema.get_type_name(parent_type.inner()),
-                    type_condition: self.schema.get_type_name(signature.type_condition),
+            let fragment_name = FragmentDefinitionName(spread.name.value);
+            let type_condition = self.schema.get_type_name(signature.type_condition);
+            let message = match conflict {
+                TypeConditionConflict::ParentType(parent_type) => {
+                    ValidationMessage::InvalidFragmentSpreadType {
+                        fragment_name,
+                        parent_type: self.schema.get_type_name(parent_type),
+                        type_condition,
+                    }
+                }
+                TypeConditionConflict::ParentTypes {
+                    parent_types,
+                    possible_types,
+                } => ValidationMessage::InvalidFragmentSpreadTypeInParents {
+                    fragment_name,
+                    parent_types: self.type_names(&parent_types),
+                    type_condition,
+                    possible_types: self.object_names(&possible_types),
+                    type_condition_possible_types: self
+                        .object_names(&self.schema.possible_types(signature.type_condition)),
                 },
+            };
+            return Err(vec![Diagnostic::error(
+                message,
                 self.location.with_span(spread.span),
             )]);
         }
@@ -811,7 +878,21 @@ impl<'schema, 'signatures, 'options> Builder<'schema, 'signatures, 'options> {
             return Err(vec![error]);
         }
 
-        let arguments = if let Some(graphql_syntax::Directive {
+        let arguments = if let Some(arg_list) = &spread.arguments {
+            if let Some(directive) = argument_directives.first() {
+                return Err(vec![
+                    Diagnostic::error(
+                        ValidationMessage::FragmentSpreadArgumentsAndArgumentsDirective,
+                        self.location.with_span(directive.span),
+                    )
+                    .annotate(
+                        "arguments are previously passed here",
+                        self.location.with_span(arg_list.span),
+                    ),
+                ]);
+            }
+            self.build_fragment_spread_arguments(signature, arg_list, ValidationLevel::Strict)
+        } else if let Some(graphql_syntax::Directive {
             name,
             arguments: Some(arg_list),
             ..
@@ -896,15 +977,29 @@ impl<'schema, 'signatures, 'options> Builder<'schema, 'signatures, 'options> {
         };
 
         if let Some((type_condition, span)) = type_condition_with_span {
-            if let Some(parent_type) =
-                self.find_conflicting_parent_type(parent_types, type_condition)
+            if let Some(conflict) = self.find_type_condition_conflict(parent_types, type_condition)
             {
                 // no possible overlap
-                return Err(vec![Diagnostic::error(
-                    ValidationMessage::InvalidInlineFragmentTypeCondition {
-                        parent_type: self.schema.get_type_name(parent_type.inner()),
+                let message = match conflict {
+                    TypeConditionConflict::ParentType(parent_type) => {
+                        ValidationMessage::InvalidInlineFragmentTypeCondition {
+                            parent_type: self.schema.get_type_name(parent_type),
+                            type_condition: self.schema.get_type_name(type_condition),
+                        }
+                    }
+                    TypeConditionConflict::ParentTypes {
+                        parent_types,
+                        possible_types,
+                    } => ValidationMessage::InvalidInlineFragmentTypeConditionInParents {
+                        parent_types: self.type_names(&parent_types),
                         type_condition: self.schema.get_type_name(type_condition),
+                        possible_types: self.object_names(&possible_types),
+                        type_condition_possible_types: self
+                            .object_names(&self.schema.possible_types(type_condition)),
                     },
+                };
+                return Err(vec![Diagnostic::error(
+                    message,
                     self.location.with_span(span),
                 )]);
             }
@@ -1310,7 +1405,12 @@ impl<'schema, 'signatures, 'options> Builder<'schema, 'signatures, 'options> {
                 )]);
             }
         };
-        if !directive_definition.locations.contains(&location) {
+        // In Relay, the conditions of fragment definitions are pushed down to
+        // the spreads of the fragments by the `fragment_conditions` transform.
+        let is_fragment_condition = location == DirectiveLocation::FragmentDefinition
+            && self.options.relay_mode.is_some()
+            && matches!(directive.name.value.lookup(), "include" | "skip");
+        if !directive_definition.locations.contains(&location) && !is_fragment_condition {
             return Err(vec![Diagnostic::error(
                 ValidationMessage::InvalidDirectiveUsageUnsupportedLocation(DirectiveName(
                     directive.name.value,
@@ -1913,17 +2013,83 @@ impl<'schema, 'signatures, 'options> Builder<'schema, 'signatures, 'options> {
         None
     }
 
-    fn find_conflicting_parent_type<'a>(
+    /// Finds why `type_condition` can never occur within `parent_types`,
+    /// the immediate parent first: it shares no possible type with one of
+    /// them, or none of its possible types is possible within all of them.
+    fn find_type_condition_conflict(
         &self,
-        parent_types: &'a [TypeReference<Type>],
+        parent_types: &[TypeReference<Type>],
         type_condition: Type,
-    ) -> Option<&'a TypeReference<Type>> {
-        parent_types.iter().find(|parent_type| {
+    ) -> Option<TypeConditionConflict> {
+        if let Some(parent_type) = parent_types.iter().find(|parent_type| {
             !self
                 .schema
                 .are_overlapping_types(parent_type.inner(), type_condition)
-        })
+        }) {
+            return Some(TypeConditionConflict::ParentType(parent_type.inner()));
+        }
+
+        let type_condition_possible_types = self.schema.possible_types(type_condition);
+        if type_condition_possible_types.is_empty() {
+            // An abstract type without implementations within itself
+            return None;
+        }
+        let mut possible_types: Option<Vec<ObjectID>> = None;
+        for (index, parent_type) in parent_types.iter().enumerate() {
+            let parent_possible_types = self.schema.possible_types(parent_type.inner());
+            let scope_possible_types = match possible_types {
+                Some(possible_types) => possible_types
+                    .into_iter()
+                    .filter(|object_id| parent_possible_types.contains(object_id))
+                    .collect(),
+                None => parent_possible_types,
+            };
+            if !scope_possible_types
+                .iter()
+                .any(|object_id| type_condition_possible_types.contains(object_id))
+            {
+                return Some(TypeConditionConflict::ParentTypes {
+                    parent_types: parent_types[..=index]
+                        .iter()
+                        .map(|parent_type| parent_type.inner())
+                        .collect(),
+                    possible_types: scope_possible_types,
+                });
+            }
+            possible_types = Some(scope_possible_types);
+        }
+        None
+    }
+
+    /// The names of `types`, from the outermost parent type.
+    fn type_names(&self, types: &[Type]) -> Vec<StringKey> {
+        types
+            .iter()
+            .rev()
+            .map(|type_| self.schema.get_type_name(*type_))
+            .collect()
     }
+
+    fn object_names(&self, object_ids: &[ObjectID]) -> Vec<StringKey> {
+        let mut names = object_ids
+            .iter()
+            .map(|object_id| self.schema.get_type_name(Type::Object(*object_id)))
+            .collect::<Vec<_>>();
+        names.sort_by_key(|name| name.lookup());
+        names
+    }
+}
+
+/// Why a type condition can never occur within its parent types.
+enum TypeConditionConflict {
+    /// The type condition shares no possible type with this parent type.
+    ParentType(Type),
+    /// None of the possible types of the type condition are possible within
+    /// all of these parent types, the immediate parent first.
+    ParentTypes {
+        parent_types: Vec<Type>,
+        possible_types: Vec<ObjectID>,
+    },
 }
 
 #[derive(Copy, Clone, Eq, PartialEq)]
@@ -14,6 +14,7 @@ use common::DiagnosticsResult;
 use common::DirectiveName;
 use common::Location;
 use common::SourceLocationKey;
+use common::Span;
 use common::WithLocation;
 use errors::par_try_map;
 use errors::try2;
@@ -86,6 +87,11 @@ pub struct FragmentSignature {
     pub name: WithLocation<FragmentDefinitionName>,
     pub variable_definitions: Vec<VariableDefinition>,
     pub type_condition: Type,
+    /// Where an entry is added to the `@argumentDefinitions` of the fragment:
+    /// the end of the last entry, or the end of the type condition when the
+    /// fragment has no arguments. `None` when the fragment defines its
+    /// arguments with variable definitions, or an empty directive.
+    pub argument_definitions_insertion: Option<Location>,
 }
 
 pub fn build_signatures(
@@ -197,6 +203,19 @@ fn build_fragment_signature(
         .unwrap_or_else(|| Ok(Default::default()));
 
     let (type_condition, variable_definitions) = try2(type_condition, variable_definitions)?;
+    let argument_definitions_insertion = if fragment.variable_definitions.is_some() {
+        None
+    } else {
+        match argument_definition_directives.first() {
+            Some(directive) => directive
+                .arguments
+                .as_ref()
+                .and_then(|arguments| arguments.items.last())
+                .map(|argument| argument.span.end),
+            None => Some(fragment.type_condition.span.end),
+        }
+        .map(|end| fragment.location.with_span(Span::new(end, end)))
+    };
 
     Ok(FragmentSignature {
         name: WithLocation::from_span(
@@ -206,6 +225,7 @@ fn build_fragment_signature(
         ),
         type_condition,
         variable_definitions,
+        argument_definitions_insertion,
     })
 }
 
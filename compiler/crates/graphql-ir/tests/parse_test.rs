@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<0ca1fdb35aff7435674d234f070c70fe>>
+ * @generated SignedSource<<d49c4ab49b13fc4e851ad1cc1d659577>>
  */
 
 mod parse;
@@ -208,6 +208,20 @@ fn fragment_spread_on_wrong_type_invalid() {
     test_fixture(transform_fixture, "fragment-spread-on-wrong-type.invalid.graphql", "parse/fixtures/fragment-spread-on-wrong-type.invalid.expected", input, expected);
 }
 
+#[test]
+fn fragment_spread_with_arguments_syntax() {
+    let input = include_str!("parse/fixtures/fragment-spread-with-arguments-syntax.graphql");
+    let expected = include_str!("parse/fixtures/fragment-spread-with-arguments-syntax.expected");
+    test_fixture(transform_fixture, "fragment-spread-with-arguments-syntax.graphql", "parse/fixtures/fragment-spread-with-arguments-syntax.expected", input, expected);
+}
+
+#[test]
+fn fragment_spread_with_arguments_syntax_and_arguments_directive_invalid() {
+    let input = include_str!("parse/fixtures/fragment-spread-with-arguments-syntax-and-arguments-directive.invalid.graphql");
+    let expected = include_str!("parse/fixtures/fragment-spread-with-arguments-syntax-and-arguments-directive.invalid.expected");
+    test_fixture(transform_fixture, "fragment-spread-with-arguments-syntax-and-arguments-directive.invalid.graphql", "parse/fixtures/fragment-spread-with-arguments-syntax-and-arguments-directive.invalid.expected", input, expected);
+}
+
 #[test]
 fn fragment_spread_within_wrong_parent_type_invalid() {
     let input = include_str!("parse/fixtures/fragment-spread-within-wrong-parent-type.invalid.graphql");
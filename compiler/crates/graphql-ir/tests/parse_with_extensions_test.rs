@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<e573b168de2e50d455bd24bed56b02c6>>
+ * @generated SignedSource<<9a1db9bef8aa605905e3859a973911cb>>
  */
 
 mod parse_with_extensions;
@@ -12,6 +12,13 @@ mod parse_with_extensions;
 use parse_with_extensions::transform_fixture;
 use fixture_tests::test_fixture;
 
+#[test]
+fn abstract_type_spreads_invalid() {
+    let input = include_str!("parse_with_extensions/fixtures/abstract-type-spreads.invalid.graphql");
+    let expected = include_str!("parse_with_extensions/fixtures/abstract-type-spreads.invalid.expected");
+    test_fixture(transform_fixture, "abstract-type-spreads.invalid.graphql", "parse_with_extensions/fixtures/abstract-type-spreads.invalid.expected", input, expected);
+}
+
 #[test]
 fn client_fields() {
     let input = include_str!("parse_with_extensions/fixtures/client-fields.graphql");
@@ -0,0 +1,692 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! A lossless concrete syntax tree (CST) of executable documents, for
+//! codemods. The nodes of the tree are built from the spans of the AST, and
+//! every token is a leaf of the innermost node containing it, with the
+//! whitespace, commas and comments before it as its leading trivia. Printing
+//! the tree returns exactly the source it was parsed from, and edits only
+//! change the text of the nodes they target, keeping the formatting of the
+//! rest of the document.
+//!
+//! The spans are relative to the parsed text: for GraphQL embedded in JS,
+//! the edits of a `GraphQLSource` are applied to its `text`, before splicing
+//! it back at its `line_index` and `column_index`.
+
+use std::iter::Peekable;
+use std::vec;
+
+use common::DiagnosticsResult;
+use common::SourceLocationKey;
+use common::Span;
+use logos::Logos;
+use thiserror::Error;
+
+use crate::lexer::TokenKind;
+use crate::node::Argument;
+use crate::node::ConstantArgument;
+use crate::node::ConstantValue;
+use crate::node::Directive;
+use crate::node::ExecutableDefinition;
+use crate::node::List;
+use crate::node::Selection;
+use crate::node::TypeAnnotation;
+use crate::node::TypeCondition;
+use crate::node::Value;
+use crate::node::VariableDefinition;
+use crate::parse_executable;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum SyntaxKind {
+    Document,
+    OperationDefinition,
+    FragmentDefinition,
+    VariableDefinitions,
+    VariableDefinition,
+    DefaultValue,
+    NamedType,
+    ListType,
+    NonNullType,
+    TypeCondition,
+    Directive,
+    Arguments,
+    Argument,
+    SelectionSet,
+    ScalarField,
+    LinkedField,
+    FragmentSpread,
+    InlineFragment,
+    Alias,
+    Variable,
+    IntValue,
+    FloatValue,
+    StringValue,
+    BooleanValue,
+    NullValue,
+    EnumValue,
+    ListValue,
+    ObjectValue,
+    ObjectField,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum TriviaKind {
+    Whitespace,
+    /// A run of commas, which are insignificant in GraphQL.
+    Comma,
+    /// A comment, from the `#` to the end of the line.
+    Comment,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Trivia {
+    pub kind: TriviaKind,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CstToken {
+    pub kind: TokenKind,
+    pub span: Span,
+    /// The whitespace, commas and comments between the previous token and
+    /// this one.
+    pub leading_trivia: Vec<Trivia>,
+}
+
+impl CstToken {
+    /// The span of the token, including its leading trivia.
+    pub fn full_span(&self) -> Span {
+        let start = self
+            .leading_trivia
+            .first()
+            .map_or(self.span.start, |trivia| trivia.span.start);
+        Span::new(start, self.span.end)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CstElement {
+    Node(CstNode),
+    Token(CstToken),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CstNode {
+    pub kind: SyntaxKind,
+    /// The span of the tokens of the node, without the leading trivia of its
+    /// first token.
+    pub span: Span,
+    pub children: Vec<CstElement>,
+}
+
+impl CstNode {
+    pub fn child_nodes(&self) -> impl Iterator<Item = &CstNode> {
+        self.children.iter().filter_map(|child| match child {
+            CstElement::Node(node) => Some(node),
+            CstElement::Token(_) => None,
+        })
+    }
+
+    /// The nodes of the subtree in document order, starting with this node.
+    pub fn descendants(&self) -> Vec<&CstNode> {
+        let mut descendants = vec![self];
+        for child in self.child_nodes() {
+            descendants.extend(child.descendants());
+        }
+        descendants
+    }
+
+    /// The tokens of the subtree in document order.
+    pub fn tokens(&self) -> Vec<&CstToken> {
+        let mut tokens = Vec::new();
+        for child in &self.children {
+            match child {
+                CstElement::Node(node) => tokens.extend(node.tokens()),
+                CstElement::Token(token) => tokens.push(token),
+            }
+        }
+        tokens
+    }
+
+    pub fn first_token(&self) -> Option<&CstToken> {
+        self.children.first().and_then(|child| match child {
+            CstElement::Node(node) => node.first_token(),
+            CstElement::Token(token) => Some(token),
+        })
+    }
+
+    /// The span of the node, including the leading trivia of its first token.
+    pub fn full_span(&self) -> Span {
+        self.first_token().map_or(self.span, |token| {
+            Span::new(token.full_span().start, self.span.end)
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExecutableCst {
+    source: String,
+    root: CstNode,
+    /// The starts of the tokens, in document order.
+    token_starts: Vec<u32>,
+}
+
+impl ExecutableCst {
+    /// The `Document` node, whose last child is the `EndOfFile` token with
+    /// the trailing trivia of the document.
+    pub fn root(&self) -> &CstNode {
+        &self.root
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn text(&self, span: Span) -> &str {
+        &self.source[span.start as usize..span.end as usize]
+    }
+
+    /// Prints the tokens of the tree with their trivia, which is exactly the
+    /// parsed source.
+    pub fn print(&self) -> String {
+        let mut printed = String::with_capacity(self.source.len());
+        for token in self.root.tokens() {
+            for trivia in &token.leading_trivia {
+                printed.push_str(self.text(trivia.span));
+            }
+            printed.push_str(self.text(token.span));
+        }
+        printed
+    }
+
+    pub fn editor(&self) -> CstEditor<'_> {
+        CstEditor {
+            cst: self,
+            edits: Vec::new(),
+        }
+    }
+
+    /// The start of the first token at or after `offset`.
+    fn next_token_start(&self, offset: u32) -> u32 {
+        let index = self.token_starts.partition_point(|start| *start < offset);
+        self.token_starts
+            .get(index)
+            .copied()
+            .unwrap_or(self.source.len() as u32)
+    }
+}
+
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+pub enum CstEditError {
+    #[error("The edit of {span:?} overlaps another edit of the document")]
+    OverlappingEdits { span: Span },
+}
+
+/// Collects edits of the text of a tree, which are applied together when
+/// printing, so that the spans of the tree stay valid across edits.
+pub struct CstEditor<'a> {
+    cst: &'a ExecutableCst,
+    edits: Vec<(Span, String)>,
+}
+
+impl<'a> CstEditor<'a> {
+    /// Replaces the text of `span`. Empty spans insert the text, in the order
+    /// of the calls for the same offset.
+    pub fn replace(&mut self, span: Span, text: impl Into<String>) {
+        self.edits.push((span, text.into()));
+    }
+
+    /// Replaces the text of the node, keeping its leading trivia.
+    pub fn replace_node(&mut self, node: &CstNode, text: impl Into<String>) {
+        self.replace(node.span, text);
+    }
+
+    pub fn insert_before(&mut self, node: &CstNode, text: impl Into<String>) {
+        self.replace(Span::new(node.span.start, node.span.start), text);
+    }
+
+    pub fn insert_after(&mut self, node: &CstNode, text: impl Into<String>) {
+        self.replace(Span::new(node.span.end, node.span.end), text);
+    }
+
+    /// Removes the node with its leading trivia, e.g. the line of a selection
+    /// and the comments above it. The first node of a list, without leading
+    /// trivia, is removed with the trivia after it instead, e.g. `a: 1, ` in
+    /// `(a: 1, b: 2)`.
+    pub fn remove_node(&mut self, node: &CstNode) {
+        let full_span = node.full_span();
+        let span = if full_span.start < node.span.start {
+            full_span
+        } else {
+            Span::new(node.span.start, self.cst.next_token_start(node.span.end))
+        };
+        self.replace(span, "");
+    }
+
+    /// Prints the source with the edits applied.
+    pub fn print(mut self) -> Result<String, CstEditError> {
+        self.edits.sort_by_key(|(span, _)| (span.start, span.end));
+        let source = self.cst.source();
+        let mut printed = String::with_capacity(source.len());
+        let mut offset = 0;
+        for (span, text) in &self.edits {
+            if span.start < offset {
+                return Err(CstEditError::OverlappingEdits { span: *span });
+            }
+            printed.push_str(&source[offset as usize..span.start as usize]);
+            printed.push_str(text);
+            offset = span.end;
+        }
+        printed.push_str(&source[offset as usize..]);
+        Ok(printed)
+    }
+}
+
+/// Parses an executable document into a lossless concrete syntax tree.
+pub fn parse_executable_cst(
+    source: &str,
+    source_location: SourceLocationKey,
+) -> DiagnosticsResult<ExecutableCst> {
+    let document = parse_executable(source, source_location)?;
+    let tokens = lex_tokens(source);
+    let token_starts = tokens.iter().map(|token| token.span.start).collect();
+    let skeleton = Skeleton::new(
+        SyntaxKind::Document,
+        Span::new(0, source.len() as u32),
+        document
+            .definitions
+            .iter()
+            .map(executable_definition)
+            .collect(),
+    );
+    let mut tokens = tokens.into_iter().peekable();
+    let mut root = skeleton.attach_tokens(&mut tokens);
+    root.children.extend(tokens.map(CstElement::Token));
+    Ok(ExecutableCst {
+        source: source.to_string(),
+        root,
+        token_starts,
+    })
+}
+
+/// The tokens of the source with their leading trivia, ending with an
+/// `EndOfFile` token with the trailing trivia of the source.
+fn lex_tokens(source: &str) -> Vec<CstToken> {
+    let mut lexer = TokenKind::lexer(source);
+    let mut tokens = Vec::new();
+    let mut previous_end = 0;
+    while let Some(kind) = lexer.next() {
+        let span: Span = lexer.span().into();
+        tokens.push(CstToken {
+            kind,
+            span,
+            leading_trivia: lex_trivia(source, Span::new(previous_end, span.start)),
+        });
+        previous_end = span.end;
+    }
+    let end = source.len() as u32;
+    tokens.push(CstToken {
+        kind: TokenKind::EndOfFile,
+        span: Span::new(end, end),
+        leading_trivia: lex_trivia(source, Span::new(previous_end, end)),
+    });
+    tokens
+}
+
+/// Splits the text between two tokens into runs of whitespace and commas,
+/// and comments.
+fn lex_trivia(source: &str, span: Span) -> Vec<Trivia> {
+    let mut trivia = Vec::new();
+    let text = &source[span.start as usize..span.end as usize];
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, chr)) = chars.next() {
+        let kind = match chr {
+            '#' => TriviaKind::Comment,
+            ',' => TriviaKind::Comma,
+            _ => TriviaKind::Whitespace,
+        };
+        let mut end = start + chr.len_utf8();
+        while let Some(&(next_start, next_chr)) = chars.peek() {
+            let continues = match kind {
+                TriviaKind::Comment => next_chr != '\n' && next_chr != '\r',
+                TriviaKind::Comma => next_chr == ',',
+                TriviaKind::Whitespace => next_chr != '#' && next_chr != ',',
+            };
+            if !continues {
+                break;
+            }
+            end = next_start + next_chr.len_utf8();
+            chars.next();
+        }
+        trivia.push(Trivia {
+            kind,
+            span: Span::new(span.start + start as u32, span.start + end as u32),
+        });
+    }
+    trivia
+}
+
+/// A node of the tree before the tokens are attached.
+struct Skeleton {
+    kind: SyntaxKind,
+    span: Span,
+    children: Vec<Skeleton>,
+}
+
+impl Skeleton {
+    fn new(kind: SyntaxKind, span: Span, children: Vec<Skeleton>) -> Self {
+        Self {
+            kind,
+            span,
+            children,
+        }
+    }
+
+    fn leaf(kind: SyntaxKind, span: Span) -> Self {
+        Self::new(kind, span, vec![])
+    }
+
+    /// Takes the tokens starting in the span of the node, descending into
+    /// the child starting at or before each token.
+    fn attach_tokens(self, tokens: &mut Peekable<vec::IntoIter<CstToken>>) -> CstNode {
+        let Skeleton {
+            kind,
+            span,
+            children: child_skeletons,
+        } = self;
+        let mut children = Vec::new();
+        let mut child_skeletons = child_skeletons.into_iter().peekable();
+        while let Some(token_start) = tokens
+            .peek()
+            .map(|token| token.span.start)
+            .filter(|start| *start < span.end)
+        {
+            match child_skeletons.peek() {
+                Some(child) if child.span.start <= token_start => {
+                    let child = child_skeletons.next().unwrap();
+                    children.push(CstElement::Node(child.attach_tokens(tokens)));
+                }
+                _ => children.push(CstElement::Token(tokens.next().unwrap())),
+            }
+        }
+        children.extend(child_skeletons.map(|child| CstElement::Node(child.attach_tokens(tokens))));
+        CstNode {
+            kind,
+            span,
+            children,
+        }
+    }
+}
+
+fn executable_definition(definition: &ExecutableDefinition) -> Skeleton {
+    match definition {
+        ExecutableDefinition::Operation(operation) => {
+            let mut children = Vec::new();
+            if let Some(variable_definitions) = &operation.variable_definitions {
+                children.push(variable_definition_list(variable_definitions));
+            }
+            children.extend(operation.directives.iter().map(directive));
+            children.push(selection_set(&operation.selections));
+            Skeleton::new(
+                SyntaxKind::OperationDefinition,
+                *operation.location.span(),
+                children,
+            )
+        }
+        ExecutableDefinition::Fragment(fragment) => {
+            let mut children = Vec::new();
+            if let Some(variable_definitions) = &fragment.variable_definitions {
+                children.push(variable_definition_list(variable_definitions));
+            }
+            children.push(type_condition(&fragment.type_condition));
+            children.extend(fragment.directives.iter().map(directive));
+            children.push(selection_set(&fragment.selections));
+            Skeleton::new(
+                SyntaxKind::FragmentDefinition,
+                *fragment.location.span(),
+                children,
+            )
+        }
+    }
+}
+
+fn variable_definition_list(list: &List<VariableDefinition>) -> Skeleton {
+    Skeleton::new(
+        SyntaxKind::VariableDefinitions,
+        list.span,
+        list.items.iter().map(variable_definition).collect(),
+    )
+}
+
+fn variable_definition(variable_definition: &VariableDefinition) -> Skeleton {
+    let mut children = vec![
+        Skeleton::leaf(SyntaxKind::Variable, variable_definition.name.span),
+        type_annotation(&variable_definition.type_),
+    ];
+    if let Some(default_value) = &variable_definition.default_value {
+        children.push(Skeleton::new(
+            SyntaxKind::DefaultValue,
+            default_value.span,
+            vec![constant_value(&default_value.value)],
+        ));
+    }
+    children.extend(variable_definition.directives.iter().map(directive));
+    Skeleton::new(
+        SyntaxKind::VariableDefinition,
+        variable_definition.span,
+        children,
+    )
+}
+
+fn type_annotation(type_: &TypeAnnotation) -> Skeleton {
+    match type_ {
+        TypeAnnotation::Named(named) => Skeleton::leaf(SyntaxKind::NamedType, named.name.span),
+        TypeAnnotation::List(list) => Skeleton::new(
+            SyntaxKind::ListType,
+            list.span,
+            vec![type_annotation(&list.type_)],
+        ),
+        TypeAnnotation::NonNull(non_null) => Skeleton::new(
+            SyntaxKind::NonNullType,
+            non_null.span,
+            vec![type_annotation(&non_null.type_)],
+        ),
+    }
+}
+
+fn type_condition(type_condition: &TypeCondition) -> Skeleton {
+    Skeleton::leaf(SyntaxKind::TypeCondition, type_condition.span)
+}
+
+fn directive(directive: &Directive) -> Skeleton {
+    Skeleton::new(
+        SyntaxKind::Directive,
+        directive.span,
+        directive.arguments.iter().map(argument_list).collect(),
+    )
+}
+
+fn argument_list(list: &List<Argument>) -> Skeleton {
+    Skeleton::new(
+        SyntaxKind::Arguments,
+        list.span,
+        list.items
+            .iter()
+            .map(|item| argument(SyntaxKind::Argument, item))
+            .collect(),
+    )
+}
+
+fn argument(kind: SyntaxKind, argument: &Argument) -> Skeleton {
+    Skeleton::new(kind, argument.span, vec![value(&argument.value)])
+}
+
+fn constant_argument(argument: &ConstantArgument) -> Skeleton {
+    Skeleton::new(
+        SyntaxKind::ObjectField,
+        argument.span,
+        vec![constant_value(&argument.value)],
+    )
+}
+
+fn value(value_: &Value) -> Skeleton {
+    match value_ {
+        Value::Constant(constant) => constant_value(constant),
+        Value::Variable(variable) => Skeleton::leaf(SyntaxKind::Variable, variable.span),
+        Value::List(list) => Skeleton::new(
+            SyntaxKind::ListValue,
+            list.span,
+            list.items.iter().map(value).collect(),
+        ),
+        Value::Object(list) => Skeleton::new(
+            SyntaxKind::ObjectValue,
+            list.span,
+            list.items
+                .iter()
+                .map(|item| argument(SyntaxKind::ObjectField, item))
+                .collect(),
+        ),
+    }
+}
+
+fn constant_value(value: &ConstantValue) -> Skeleton {
+    match value {
+        ConstantValue::Int(node) => Skeleton::leaf(SyntaxKind::IntValue, node.token.span),
+        ConstantValue::Float(node) => Skeleton::leaf(SyntaxKind::FloatValue, node.token.span),
+        ConstantValue::String(node) => Skeleton::leaf(SyntaxKind::StringValue, node.token.span),
+        ConstantValue::Boolean(node) => Skeleton::leaf(SyntaxKind::BooleanValue, node.token.span),
+        ConstantValue::Null(token) => Skeleton::leaf(SyntaxKind::NullValue, token.span),
+        ConstantValue::Enum(node) => Skeleton::leaf(SyntaxKind::EnumValue, node.token.span),
+        ConstantValue::List(list) => Skeleton::new(
+            SyntaxKind::ListValue,
+            list.span,
+            list.items.iter().map(constant_value).collect(),
+        ),
+        ConstantValue::Object(list) => Skeleton::new(
+            SyntaxKind::ObjectValue,
+            list.span,
+            list.items.iter().map(constant_argument).collect(),
+        ),
+    }
+}
+
+fn selection_set(list: &List<Selection>) -> Skeleton {
+    Skeleton::new(
+        SyntaxKind::SelectionSet,
+        list.span,
+        list.items.iter().map(selection).collect(),
+    )
+}
+
+fn selection(selection: &Selection) -> Skeleton {
+    match selection {
+        Selection::FragmentSpread(spread) => {
+            let mut children = Vec::new();
+            children.extend(spread.arguments.iter().map(argument_list));
+            children.extend(spread.directives.iter().map(directive));
+            Skeleton::new(SyntaxKind::FragmentSpread, spread.span, children)
+        }
+        Selection::InlineFragment(fragment) => {
+            let mut children = Vec::new();
+            if let Some(condition) = &fragment.type_condition {
+                children.push(type_condition(condition));
+            }
+            children.extend(fragment.directives.iter().map(directive));
+            children.push(selection_set(&fragment.selections));
+            Skeleton::new(SyntaxKind::InlineFragment, fragment.span, children)
+        }
+        Selection::LinkedField(field) => {
+            let mut children = Vec::new();
+            if let Some(alias) = &field.alias {
+                children.push(Skeleton::leaf(SyntaxKind::Alias, alias.span));
+            }
+            children.extend(field.arguments.iter().map(argument_list));
+            children.extend(field.directives.iter().map(directive));
+            children.push(selection_set(&field.selections));
+            Skeleton::new(SyntaxKind::LinkedField, field.span, children)
+        }
+        Selection::ScalarField(field) => {
+            let mut children = Vec::new();
+            if let Some(alias) = &field.alias {
+                children.push(Skeleton::leaf(SyntaxKind::Alias, alias.span));
+            }
+            children.extend(field.arguments.iter().map(argument_list));
+            children.extend(field.directives.iter().map(directive));
+            Skeleton::new(SyntaxKind::ScalarField, field.span, children)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(source: &str) -> ExecutableCst {
+        parse_executable_cst(source, SourceLocationKey::generated()).unwrap()
+    }
+
+    fn find<'a>(cst: &'a ExecutableCst, kind: SyntaxKind, text: &str) -> &'a CstNode {
+        cst.root()
+            .descendants()
+            .into_iter()
+            .find(|node| node.kind == kind && cst.text(node.span) == text)
+            .unwrap()
+    }
+
+    #[test]
+    fn prints_the_source() {
+        let source = "# Leading comment\nquery Q($id: ID = 4, ,$n: [Int!]!) @a(x: {y: [1, $n]}) {\n  node(id: $id) { ... on User { name } ...F }  # trailing\n}\n\n";
+        assert_eq!(parse(source).print(), source);
+    }
+
+    #[test]
+    fn trivia() {
+        let cst = parse("{ a, # x\n b }");
+        let b = find(&cst, SyntaxKind::ScalarField, "b");
+        let trivia: Vec<_> = b
+            .first_token()
+            .unwrap()
+            .leading_trivia
+            .iter()
+            .map(|trivia| (trivia.kind, cst.text(trivia.span)))
+            .collect();
+        assert_eq!(
+            trivia,
+            vec![
+                (TriviaKind::Comma, ","),
+                (TriviaKind::Whitespace, " "),
+                (TriviaKind::Comment, "# x"),
+                (TriviaKind::Whitespace, "\n "),
+            ]
+        );
+    }
+
+    #[test]
+    fn edits() {
+        let cst = parse("query Q {\n  # The name\n  name\n  id @a(x: 1, y: 2)\n}\n");
+        let mut editor = cst.editor();
+        editor.remove_node(find(&cst, SyntaxKind::ScalarField, "name"));
+        editor.remove_node(find(&cst, SyntaxKind::Argument, "x: 1"));
+        editor.replace_node(find(&cst, SyntaxKind::IntValue, "2"), "3");
+        editor.insert_after(find(&cst, SyntaxKind::Directive, "@a(x: 1, y: 2)"), " @b");
+        assert_eq!(editor.print().unwrap(), "query Q {\n  id @a(y: 3) @b\n}\n");
+    }
+
+    #[test]
+    fn overlapping_edits() {
+        let cst = parse("{ a(x: 1) }");
+        let mut editor = cst.editor();
+        editor.replace_node(find(&cst, SyntaxKind::ScalarField, "a(x: 1)"), "b");
+        editor.replace_node(find(&cst, SyntaxKind::IntValue, "1"), "2");
+        assert_eq!(
+            editor.print(),
+            Err(CstEditError::OverlappingEdits {
+                span: Span::new(7, 8)
+            })
+        );
+    }
+}
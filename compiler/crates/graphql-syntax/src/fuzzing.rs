@@ -0,0 +1,38 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Entry points for fuzzing the parsers, used by the targets in
+//! `compiler/fuzz`. Malformed input must produce diagnostics, so any panic is
+//! a bug. Input that isn't valid UTF-8 is skipped: sources are always read as
+//! strings.
+
+use common::SourceLocationKey;
+
+pub fn fuzz_executable_document(data: &[u8]) {
+    if let Ok(source) = std::str::from_utf8(data) {
+        let _ = crate::parse_executable_with_error_recovery(source, SourceLocationKey::generated());
+    }
+}
+
+pub fn fuzz_schema_document(data: &[u8]) {
+    if let Ok(source) = std::str::from_utf8(data) {
+        let _ = crate::parse_schema_document(source, SourceLocationKey::generated());
+    }
+}
+
+/// Documents with both executable and type system definitions, and the
+/// parsers of single types, directives and field definitions.
+pub fn fuzz_document(data: &[u8]) {
+    if let Ok(source) = std::str::from_utf8(data) {
+        let source_location = SourceLocationKey::generated();
+        let _ = crate::parse_document(source, source_location);
+        let _ = crate::parse_type(source, source_location, 0);
+        let _ = crate::parse_directive(source, source_location, 0);
+        let _ = crate::parse_field_definition(source, source_location, 0);
+        let _ = crate::parse_field_definition_stub(source, source_location, 0);
+    }
+}
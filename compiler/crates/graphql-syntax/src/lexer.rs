@@ -168,7 +168,10 @@ fn lex_block_string(lexer: &mut Lexer<'_, TokenKind>) -> bool {
                 return true;
             }
             BlockStringToken::EscapedTripleQuote | BlockStringToken::Other => {}
-            BlockStringToken::Error => unreachable!(),
+            BlockStringToken::Error => {
+                lexer.extras.error_token = Some(TokenKind::ErrorUnsupportedStringCharacter);
+                return false;
+            }
         }
     }
     lexer.extras.error_token = Some(TokenKind::ErrorUnterminatedBlockString);
@@ -10,6 +10,9 @@
 #![deny(clippy::all)]
 #![allow(clippy::large_enum_variant)]
 
+mod cst;
+#[cfg(feature = "fuzzing")]
+pub mod fuzzing;
 mod lexer;
 mod node;
 mod parser;
@@ -19,8 +22,20 @@ mod utils;
 
 use common::DiagnosticsResult;
 use common::SourceLocationKey;
+use common::Span;
 use common::WithDiagnostics;
+pub use cst::parse_executable_cst;
+pub use cst::CstEditError;
+pub use cst::CstEditor;
+pub use cst::CstElement;
+pub use cst::CstNode;
+pub use cst::CstToken;
+pub use cst::ExecutableCst;
+pub use cst::SyntaxKind;
+pub use cst::Trivia;
+pub use cst::TriviaKind;
 pub use lexer::TokenKind;
+use logos::Logos;
 pub use node::*;
 pub use parser::ParserFeatures;
 pub use source::GraphQLSource;
@@ -156,3 +171,16 @@ pub fn parse_directive(
     let parser = Parser::with_offset(source, source_location, features, offset);
     parser.parse_directive()
 }
+
+/// Splits a GraphQL source into tokens without parsing it. Whitespace and
+/// comments are skipped, and invalid input produces error tokens, so this can
+/// be used on incomplete documents.
+pub fn tokenize(source: &str) -> Vec<(TokenKind, Span)> {
+    let mut lexer = TokenKind::lexer(source);
+    let mut tokens = Vec::new();
+    while let Some(kind) = lexer.next() {
+        let span = lexer.span();
+        tokens.push((kind, Span::new(span.start as u32, span.end as u32)));
+    }
+    tokens
+}
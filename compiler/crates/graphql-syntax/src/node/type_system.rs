@@ -9,12 +9,14 @@ use std::fmt;
 
 use common::Span;
 use intern::string_key::StringKey;
+use intern::Lookup;
 
 use super::constant_directive::ConstantDirective;
 use super::constant_value::ConstantValue;
 use super::constant_value::StringNode;
 use super::primitive::*;
 use super::type_annotation::TypeAnnotation;
+use crate::TokenKind;
 
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
 pub enum TypeSystemDefinition {
@@ -73,7 +75,11 @@ impl fmt::Display for TypeSystemDefinition {
                 interfaces,
                 fields,
                 directives,
-            }) => write_object_helper(f, &name.value, interfaces, fields, directives, false),
+                description,
+            }) => {
+                write_description(f, description)?;
+                write_object_helper(f, &name.value, interfaces, fields, directives, false)
+            }
             TypeSystemDefinition::ObjectTypeExtension(ObjectTypeExtension {
                 name,
                 interfaces,
@@ -82,21 +88,29 @@ impl fmt::Display for TypeSystemDefinition {
             }) => write_object_helper(f, &name.value, interfaces, fields, directives, true),
             TypeSystemDefinition::InterfaceTypeDefinition(InterfaceTypeDefinition {
                 name,
+                interfaces,
                 fields,
                 directives,
-                ..
-            }) => write_interface_helper(f, &name.value, fields, directives, false),
+                description,
+            }) => {
+                write_description(f, description)?;
+                write_interface_helper(f, &name.value, interfaces, fields, directives, false)
+            }
             TypeSystemDefinition::InterfaceTypeExtension(InterfaceTypeExtension {
                 name,
-                interfaces: _,
+                interfaces,
                 fields,
                 directives,
-            }) => write_interface_helper(f, &name.value, fields, directives, true),
+            }) => write_interface_helper(f, &name.value, interfaces, fields, directives, true),
             TypeSystemDefinition::UnionTypeDefinition(UnionTypeDefinition {
                 name,
                 directives,
                 members,
-            }) => write_union_type_definition_helper(f, &name.value, directives, members, false),
+                description,
+            }) => {
+                write_description(f, description)?;
+                write_union_type_definition_helper(f, &name.value, directives, members, false)
+            }
             TypeSystemDefinition::UnionTypeExtension(UnionTypeExtension {
                 name,
                 directives,
@@ -120,7 +134,9 @@ impl fmt::Display for TypeSystemDefinition {
                 name,
                 directives,
                 fields,
+                description,
             }) => {
+                write_description(f, description)?;
                 write_input_object_type_definition_helper(f, &name.value, directives, fields, false)
             }
             TypeSystemDefinition::InputObjectTypeExtension(InputObjectTypeExtension {
@@ -134,7 +150,11 @@ impl fmt::Display for TypeSystemDefinition {
                 name,
                 directives,
                 values,
-            }) => write_enum_type_definition_helper(f, &name.value, directives, values, false),
+                description,
+            }) => {
+                write_description(f, description)?;
+                write_enum_type_definition_helper(f, &name.value, directives, values, false)
+            }
             TypeSystemDefinition::EnumTypeExtension(EnumTypeExtension {
                 name,
                 directives,
@@ -143,7 +163,11 @@ impl fmt::Display for TypeSystemDefinition {
             TypeSystemDefinition::ScalarTypeDefinition(ScalarTypeDefinition {
                 name,
                 directives,
-            }) => write_scalar_type_definition_helper(f, &name.value, directives, false),
+                description,
+            }) => {
+                write_description(f, description)?;
+                write_scalar_type_definition_helper(f, &name.value, directives, false)
+            }
             TypeSystemDefinition::ScalarTypeExtension(ScalarTypeExtension { name, directives }) => {
                 write_scalar_type_definition_helper(f, &name.value, directives, true)
             }
@@ -198,6 +222,7 @@ pub struct ObjectTypeDefinition {
     pub interfaces: Vec<Identifier>,
     pub directives: Vec<ConstantDirective>,
     pub fields: Option<List<FieldDefinition>>,
+    pub description: Option<StringNode>,
 }
 
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
@@ -214,6 +239,7 @@ pub struct InterfaceTypeDefinition {
     pub interfaces: Vec<Identifier>,
     pub directives: Vec<ConstantDirective>,
     pub fields: Option<List<FieldDefinition>>,
+    pub description: Option<StringNode>,
 }
 
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
@@ -229,6 +255,7 @@ pub struct UnionTypeDefinition {
     pub name: Identifier,
     pub directives: Vec<ConstantDirective>,
     pub members: Vec<Identifier>,
+    pub description: Option<StringNode>,
 }
 
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
@@ -242,6 +269,7 @@ pub struct UnionTypeExtension {
 pub struct ScalarTypeDefinition {
     pub name: Identifier,
     pub directives: Vec<ConstantDirective>,
+    pub description: Option<StringNode>,
 }
 
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
@@ -255,6 +283,7 @@ pub struct EnumTypeDefinition {
     pub name: Identifier,
     pub directives: Vec<ConstantDirective>,
     pub values: Option<List<EnumValueDefinition>>,
+    pub description: Option<StringNode>,
 }
 
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
@@ -269,6 +298,7 @@ pub struct InputObjectTypeDefinition {
     pub name: Identifier,
     pub directives: Vec<ConstantDirective>,
     pub fields: Option<List<InputValueDefinition>>,
+    pub description: Option<StringNode>,
 }
 
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
@@ -355,10 +385,12 @@ pub struct InputValueDefinition {
     pub type_: TypeAnnotation,
     pub default_value: Option<ConstantValue>,
     pub directives: Vec<ConstantDirective>,
+    pub description: Option<StringNode>,
 }
 
 impl fmt::Display for InputValueDefinition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_description(f, &self.description)?;
         write!(f, "{}: {}", self.name, self.type_)?;
         if let Some(v) = &self.default_value {
             write!(f, " = {}", v)?;
@@ -392,6 +424,7 @@ pub struct FieldDefinition {
 
 impl fmt::Display for FieldDefinition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_description(f, &self.description)?;
         write!(f, "{}", self.name)?;
         if let Some(arguments) = self.arguments.as_ref() {
             write_arguments(f, &arguments.items)?;
@@ -401,6 +434,24 @@ impl fmt::Display for FieldDefinition {
     }
 }
 
+/// Writes a description on its own line. Block strings are printed inline
+/// (`"""text"""`), since the leading whitespace of the first line of a block
+/// string is significant and the others are dedented when they're parsed.
+fn write_description(f: &mut fmt::Formatter<'_>, description: &Option<StringNode>) -> fmt::Result {
+    match description {
+        // Descriptions synthesized outside of the parser may contain newlines,
+        // which are only valid inside block strings.
+        Some(description)
+            if description.token.kind == TokenKind::BlockStringLiteral
+                || description.value.lookup().contains('\n') =>
+        {
+            writeln!(f, "\"\"\"{}\"\"\"", description.value)
+        }
+        Some(description) => writeln!(f, "\"{}\"", description.value),
+        None => Ok(()),
+    }
+}
+
 fn write_list(
     f: &mut fmt::Formatter<'_>,
     list: &[impl fmt::Display],
@@ -438,8 +489,13 @@ fn write_fields(f: &mut fmt::Formatter<'_>, fields: &[impl fmt::Display]) -> fmt
         return Ok(());
     }
 
+    // Indents every line, including the descriptions of fields.
+    let fields = fields
+        .iter()
+        .map(|field| field.to_string().replace('\n', "\n  "))
+        .collect::<Vec<_>>();
     write!(f, " {{\n  ")?;
-    write_list(f, fields, "\n  ")?;
+    write_list(f, &fields, "\n  ")?;
     write!(f, "\n}}")
 }
 
@@ -494,6 +550,7 @@ fn write_object_helper(
 fn write_interface_helper(
     f: &mut fmt::Formatter<'_>,
     name: &StringKey,
+    interfaces: &[Identifier],
     fields: &Option<List<FieldDefinition>>,
     directives: &[ConstantDirective],
     is_extension: bool,
@@ -503,6 +560,10 @@ fn write_interface_helper(
     }
 
     write!(f, "interface {}", name)?;
+    if !interfaces.is_empty() {
+        write!(f, " implements ")?;
+        write_list(f, interfaces, " & ")?;
+    }
     write_directives(f, directives)?;
     if let Some(fields) = fields.as_ref() {
         write_fields(f, &fields.items)?;
@@ -534,14 +595,18 @@ fn write_directive_definition_helper(
     f: &mut fmt::Formatter<'_>,
     name: &StringKey,
     arguments: &Option<List<InputValueDefinition>>,
-    _repeatable: &bool,
+    repeatable: &bool,
     locations: &[DirectiveLocation],
-    _description: &Option<StringNode>,
+    description: &Option<StringNode>,
 ) -> fmt::Result {
+    write_description(f, description)?;
     write!(f, "directive @{}", name)?;
     if let Some(arguments) = arguments.as_ref() {
         write_arguments(f, &arguments.items)?;
     }
+    if *repeatable {
+        write!(f, " repeatable")?;
+    }
     write!(f, " on ")?;
     write_list(f, locations, " | ")?;
     writeln!(f)
@@ -22,12 +22,20 @@ use crate::syntax_error::SyntaxError;
 
 type ParseResult<T> = Result<T, ()>;
 
-#[derive(Default)]
 pub struct ParserFeatures {
-    /// Enable the experimental fragment variables definitions syntax
+    /// Enable the fragment arguments syntax: variable definitions on fragments
+    /// and arguments on fragment spreads. Enabled by default.
     pub enable_variable_definitions: bool,
 }
 
+impl Default for ParserFeatures {
+    fn default() -> Self {
+        Self {
+            enable_variable_definitions: true,
+        }
+    }
+}
+
 pub struct Parser<'a> {
     current: Token,
     features: ParserFeatures,
@@ -342,8 +350,14 @@ impl<'a> Parser<'a> {
         let description = self.parse_optional_description();
         let token = self.peek();
         if token.kind != TokenKind::Identifier {
-            // TODO
-            // self.record_error(error)
+            // A description without a definition, report it at the description
+            // since the next token might be the end of the file.
+            let span = description.map_or(token.span, |description| description.token.span);
+            let error = Diagnostic::error(
+                SyntaxError::ExpectedTypeSystemDefinition,
+                Location::new(self.source_location, span),
+            );
+            self.record_error(error);
             return Err(());
         }
         match self.source(token) {
@@ -351,22 +365,22 @@ impl<'a> Parser<'a> {
                 self.parse_schema_definition()?,
             )),
             "scalar" => Ok(TypeSystemDefinition::ScalarTypeDefinition(
-                self.parse_scalar_type_definition()?,
+                self.parse_scalar_type_definition(description)?,
             )),
             "type" => Ok(TypeSystemDefinition::ObjectTypeDefinition(
-                self.parse_object_type_definition()?,
+                self.parse_object_type_definition(description)?,
             )),
             "interface" => Ok(TypeSystemDefinition::InterfaceTypeDefinition(
-                self.parse_interface_type_definition()?,
+                self.parse_interface_type_definition(description)?,
             )),
             "union" => Ok(TypeSystemDefinition::UnionTypeDefinition(
-                self.parse_union_type_definition()?,
+                self.parse_union_type_definition(description)?,
             )),
             "enum" => Ok(TypeSystemDefinition::EnumTypeDefinition(
-                self.parse_enum_type_definition()?,
+                self.parse_enum_type_definition(description)?,
             )),
             "input" => Ok(TypeSystemDefinition::InputObjectTypeDefinition(
-                self.parse_input_object_type_definition()?,
+                self.parse_input_object_type_definition(description)?,
             )),
             "directive" => Ok(TypeSystemDefinition::DirectiveDefinition(
                 self.parse_directive_definition(description)?,
@@ -501,7 +515,10 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn parse_object_type_definition(&mut self) -> ParseResult<ObjectTypeDefinition> {
+    fn parse_object_type_definition(
+        &mut self,
+        description: Option<StringNode>,
+    ) -> ParseResult<ObjectTypeDefinition> {
         self.parse_keyword("type")?;
         let name = self.parse_identifier()?;
         let interfaces = self.parse_implements_interfaces()?;
@@ -512,10 +529,14 @@ impl<'a> Parser<'a> {
             interfaces,
             directives,
             fields,
+            description,
         })
     }
 
-    fn parse_interface_type_definition(&mut self) -> ParseResult<InterfaceTypeDefinition> {
+    fn parse_interface_type_definition(
+        &mut self,
+        description: Option<StringNode>,
+    ) -> ParseResult<InterfaceTypeDefinition> {
         self.parse_keyword("interface")?;
         let name = self.parse_identifier()?;
         let interfaces = self.parse_implements_interfaces()?;
@@ -526,6 +547,7 @@ impl<'a> Parser<'a> {
             interfaces,
             directives,
             fields,
+            description,
         })
     }
 
@@ -533,7 +555,10 @@ impl<'a> Parser<'a> {
      * UnionTypeDefinition :
      *   - Description? union Name Directives? UnionMemberTypes?
      */
-    fn parse_union_type_definition(&mut self) -> ParseResult<UnionTypeDefinition> {
+    fn parse_union_type_definition(
+        &mut self,
+        description: Option<StringNode>,
+    ) -> ParseResult<UnionTypeDefinition> {
         self.parse_keyword("union")?;
         let name = self.parse_identifier()?;
         let directives = self.parse_constant_directives()?;
@@ -542,6 +567,7 @@ impl<'a> Parser<'a> {
             name,
             directives,
             members,
+            description,
         })
     }
 
@@ -583,7 +609,10 @@ impl<'a> Parser<'a> {
      * EnumTypeDefinition :
      *   - Description? enum Name Directives? EnumValuesDefinition?
      */
-    fn parse_enum_type_definition(&mut self) -> ParseResult<EnumTypeDefinition> {
+    fn parse_enum_type_definition(
+        &mut self,
+        description: Option<StringNode>,
+    ) -> ParseResult<EnumTypeDefinition> {
         self.parse_keyword("enum")?;
         let name = self.parse_identifier()?;
         let directives = self.parse_constant_directives()?;
@@ -592,6 +621,7 @@ impl<'a> Parser<'a> {
             name,
             directives,
             values,
+            description,
         })
     }
 
@@ -692,11 +722,18 @@ impl<'a> Parser<'a> {
     /**
      * ScalarTypeDefinition : Description? scalar Name Directives?
      */
-    fn parse_scalar_type_definition(&mut self) -> ParseResult<ScalarTypeDefinition> {
+    fn parse_scalar_type_definition(
+        &mut self,
+        description: Option<StringNode>,
+    ) -> ParseResult<ScalarTypeDefinition> {
         self.parse_keyword("scalar")?;
         let name = self.parse_identifier()?;
         let directives = self.parse_constant_directives()?;
-        Ok(ScalarTypeDefinition { name, directives })
+        Ok(ScalarTypeDefinition {
+            name,
+            directives,
+            description,
+        })
     }
 
     /**
@@ -714,7 +751,10 @@ impl<'a> Parser<'a> {
      * InputObjectTypeDefinition :
      *   - Description? input Name Directives? InputFieldsDefinition?
      */
-    fn parse_input_object_type_definition(&mut self) -> ParseResult<InputObjectTypeDefinition> {
+    fn parse_input_object_type_definition(
+        &mut self,
+        description: Option<StringNode>,
+    ) -> ParseResult<InputObjectTypeDefinition> {
         self.parse_keyword("input")?;
         let name = self.parse_identifier()?;
         let directives = self.parse_constant_directives()?;
@@ -723,6 +763,7 @@ impl<'a> Parser<'a> {
             name,
             directives,
             fields,
+            description,
         })
     }
 
@@ -931,7 +972,7 @@ impl<'a> Parser<'a> {
      *   - Description? Name : Type DefaultValue? Directives?
      */
     fn parse_input_value_def(&mut self) -> ParseResult<InputValueDefinition> {
-        self.parse_optional_description();
+        let description = self.parse_optional_description();
         let name = self.parse_identifier()?;
         self.parse_kind(TokenKind::Colon)?;
         let type_ = self.parse_type_annotation()?;
@@ -946,6 +987,7 @@ impl<'a> Parser<'a> {
             type_,
             default_value,
             directives,
+            description,
         })
     }
 
@@ -1274,7 +1316,7 @@ impl<'a> Parser<'a> {
         }
     }
 
-    /// FragmentSpread : ... FragmentName Directives?
+    /// FragmentSpread : ... FragmentName Arguments? Directives?
     /// InlineFragment : ... TypeCondition? Directives? SelectionSet
     fn parse_spread(&mut self) -> ParseResult<Selection> {
         let start = self.index();
@@ -1283,11 +1325,17 @@ impl<'a> Parser<'a> {
         if !is_on_keyword && self.peek_token_kind() == TokenKind::Identifier {
             // fragment spread
             let name = self.parse_identifier()?;
+            let arguments = if self.features.enable_variable_definitions {
+                self.parse_optional_arguments()?
+            } else {
+                None
+            };
             let directives = self.parse_directives()?;
             Ok(Selection::FragmentSpread(FragmentSpread {
                 span: Span::new(start, self.end_index),
                 spread,
                 name,
+                arguments,
                 directives,
             }))
         } else {
@@ -5,62 +5,93 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use common::diagnostic_codes;
 use thiserror::Error;
 
 use crate::lexer::TokenKind;
 
-#[derive(Clone, Copy, Debug, Error, Eq, PartialEq, Ord, PartialOrd, Hash)]
-pub enum SyntaxError {
-    #[error("Expected a {0}")]
-    Expected(TokenKind),
-    #[error("Expected a selection: field, inline fragment, or fragment spread")]
-    ExpectedSelection,
-    #[error("Expected a fragment, mutation, query, or subscription definition")]
-    ExpectedDefinition,
-    #[error("Expected a definition")]
-    ExpectedExecutableDefinition,
-    #[error("Expected a 'mutation', 'query', or 'subscription' keyword")]
-    ExpectedOperationKind,
-    #[error(
-        "Expected a valid variable name after $ (alphabetic character followed by any number of alphabetic, number and _ characters)"
-    )]
-    ExpectedVariableIdentifier,
-    #[error("Expected the keyword '{0}'")]
-    ExpectedKeyword(&'static str),
-    #[error("Expected a constant value (boolean, integer, float, string, null, list, or object")]
-    ExpectedConstantValue,
-    #[error("Expected a type annotation (e.g. '<Type>', '[Type]', 'Type!', etc)")]
-    ExpectedTypeAnnotation,
-    #[error(
-        "Expected a value ('$example', boolean, integer, float, string, null, list, or object)"
-    )]
-    ExpectedValue,
-    #[error("Expected a variable ('$example')")]
-    ExpectedVariable,
-    #[error("Expected a spread ('...')")]
-    ExpectedSpread,
-    #[error("Expected an argument")]
-    ExpectedArgument,
-    #[error("Expected the list to be non-empty")]
-    ExpectedNonEmptyList,
-    #[error("Invalid floating point value")]
-    InvalidFloat,
-    #[error("Invalid integer value")]
-    InvalidInteger,
-    #[error("Invalid number value, expected an int or float")]
-    InvalidNumberLiteral,
-    #[error(
-        "Invalid float literal, fractional float literals require a leading 0, e.g. 0.5 instead of .5"
-    )]
-    InvalidFloatLiteralMissingZero,
-    #[error("Invalid float value, GraphQL requires a leading zero, eg. 0.56")]
-    InvalidFloatMissingZeroLiteral,
-    #[error("Unsupported character")]
-    UnsupportedCharacter,
-    #[error("String containing an invalid character")]
-    UnsupportedStringCharacter,
-    #[error("Unterminated string literal (strings cannot contain unescaped line breaks)")]
-    UnterminatedString,
-    #[error("Unterminated block string literal")]
-    UnterminatedBlockString,
+diagnostic_codes! {
+    #[derive(Clone, Copy, Debug, Error, Eq, PartialEq, Ord, PartialOrd, Hash)]
+    pub enum SyntaxError {
+        #[error("Expected a {0}")]
+        #[code("RELAY4001")]
+        Expected(TokenKind),
+        #[error("Expected a selection: field, inline fragment, or fragment spread")]
+        #[code("RELAY4002")]
+        ExpectedSelection,
+        #[error("Expected a fragment, mutation, query, or subscription definition")]
+        #[code("RELAY4003")]
+        ExpectedDefinition,
+        #[error("Expected a definition")]
+        #[code("RELAY4004")]
+        ExpectedExecutableDefinition,
+        #[error(
+            "Expected a type system definition: schema, scalar, type, interface, union, enum, input, directive, or extension"
+        )]
+        #[code("RELAY4005")]
+        ExpectedTypeSystemDefinition,
+        #[error("Expected a 'mutation', 'query', or 'subscription' keyword")]
+        #[code("RELAY4006")]
+        ExpectedOperationKind,
+        #[error(
+            "Expected a valid variable name after $ (alphabetic character followed by any number of alphabetic, number and _ characters)"
+        )]
+        #[code("RELAY4007")]
+        ExpectedVariableIdentifier,
+        #[error("Expected the keyword '{0}'")]
+        #[code("RELAY4008")]
+        ExpectedKeyword(&'static str),
+        #[error("Expected a constant value (boolean, integer, float, string, null, list, or object")]
+        #[code("RELAY4009")]
+        ExpectedConstantValue,
+        #[error("Expected a type annotation (e.g. '<Type>', '[Type]', 'Type!', etc)")]
+        #[code("RELAY4010")]
+        ExpectedTypeAnnotation,
+        #[error(
+            "Expected a value ('$example', boolean, integer, float, string, null, list, or object)"
+        )]
+        #[code("RELAY4011")]
+        ExpectedValue,
+        #[error("Expected a variable ('$example')")]
+        #[code("RELAY4012")]
+        ExpectedVariable,
+        #[error("Expected a spread ('...')")]
+        #[code("RELAY4013")]
+        ExpectedSpread,
+        #[error("Expected an argument")]
+        #[code("RELAY4014")]
+        ExpectedArgument,
+        #[error("Expected the list to be non-empty")]
+        #[code("RELAY4015")]
+        ExpectedNonEmptyList,
+        #[error("Invalid floating point value")]
+        #[code("RELAY4016")]
+        InvalidFloat,
+        #[error("Invalid integer value")]
+        #[code("RELAY4017")]
+        InvalidInteger,
+        #[error("Invalid number value, expected an int or float")]
+        #[code("RELAY4018")]
+        InvalidNumberLiteral,
+        #[error(
+            "Invalid float literal, fractional float literals require a leading 0, e.g. 0.5 instead of .5"
+        )]
+        #[code("RELAY4019")]
+        InvalidFloatLiteralMissingZero,
+        #[error("Invalid float value, GraphQL requires a leading zero, eg. 0.56")]
+        #[code("RELAY4020")]
+        InvalidFloatMissingZeroLiteral,
+        #[error("Unsupported character")]
+        #[code("RELAY4021")]
+        UnsupportedCharacter,
+        #[error("String containing an invalid character")]
+        #[code("RELAY4022")]
+        UnsupportedStringCharacter,
+        #[error("Unterminated string literal (strings cannot contain unescaped line breaks)")]
+        #[code("RELAY4023")]
+        UnterminatedString,
+        #[error("Unterminated block string literal")]
+        #[code("RELAY4024")]
+        UnterminatedBlockString,
+    }
 }
@@ -0,0 +1,50 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::fmt::Write;
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_syntax::parse_executable_cst;
+use graphql_syntax::CstElement;
+use graphql_syntax::CstNode;
+use graphql_syntax::ExecutableCst;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let cst = parse_executable_cst(
+        fixture.content,
+        SourceLocationKey::standalone(fixture.file_name),
+    )
+    .map_err(|diagnostics| format!("{:#?}", diagnostics))?;
+    if cst.print() != fixture.content {
+        return Err("The printed tree differs from the source.".to_string());
+    }
+    let mut lines = Vec::new();
+    print_node(&cst, cst.root(), 0, &mut lines);
+    Ok(lines.join("\n"))
+}
+
+fn print_node(cst: &ExecutableCst, node: &CstNode, depth: usize, lines: &mut Vec<String>) {
+    lines.push(format!("{}{:?}", "  ".repeat(depth), node.kind));
+    for child in &node.children {
+        match child {
+            CstElement::Node(child) => print_node(cst, child, depth + 1, lines),
+            CstElement::Token(token) => {
+                let mut line = format!(
+                    "{}{:?} {:?}",
+                    "  ".repeat(depth + 1),
+                    token.kind,
+                    cst.text(token.span)
+                );
+                for trivia in &token.leading_trivia {
+                    write!(line, " {:?}({:?})", trivia.kind, cst.text(trivia.span)).unwrap();
+                }
+                lines.push(line);
+            }
+        }
+    }
+}
@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<7b6a24a4bba95ec64996a4bbce350790>>
+ */
+
+mod parse_executable_cst;
+
+use parse_executable_cst::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn query_with_trivia() {
+    let input = include_str!("parse_executable_cst/fixtures/query-with-trivia.graphql");
+    let expected = include_str!("parse_executable_cst/fixtures/query-with-trivia.expected");
+    test_fixture(transform_fixture, "query-with-trivia.graphql", "parse_executable_cst/fixtures/query-with-trivia.expected", input, expected);
+}
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<5807697a03122f4474b041010ef2dc34>>
+ * @generated SignedSource<<8e533525ecea80f838fff784bb4816c9>>
  */
 
 mod parse_schema_document;
@@ -12,6 +12,20 @@ mod parse_schema_document;
 use parse_schema_document::transform_fixture;
 use fixture_tests::test_fixture;
 
+#[test]
+fn block_string_with_unsupported_character_invalid() {
+    let input = include_str!("parse_schema_document/fixtures/block_string_with_unsupported_character.invalid.graphql");
+    let expected = include_str!("parse_schema_document/fixtures/block_string_with_unsupported_character.invalid.expected");
+    test_fixture(transform_fixture, "block_string_with_unsupported_character.invalid.graphql", "parse_schema_document/fixtures/block_string_with_unsupported_character.invalid.expected", input, expected);
+}
+
+#[test]
+fn description_without_definition_invalid() {
+    let input = include_str!("parse_schema_document/fixtures/description_without_definition.invalid.graphql");
+    let expected = include_str!("parse_schema_document/fixtures/description_without_definition.invalid.expected");
+    test_fixture(transform_fixture, "description_without_definition.invalid.graphql", "parse_schema_document/fixtures/description_without_definition.invalid.expected", input, expected);
+}
+
 #[test]
 fn directive_description() {
     let input = include_str!("parse_schema_document/fixtures/directive_description.graphql");
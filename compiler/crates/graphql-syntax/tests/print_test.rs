@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<e754c9b986474e80e9bc346aa6c77fcd>>
+ * @generated SignedSource<<963015dfadd84e619b7175f95392ad72>>
  */
 
 mod print;
@@ -13,8 +13,8 @@ use print::transform_fixture;
 use fixture_tests::test_fixture;
 
 #[test]
-fn schema() {
-    let input = include_str!("print/fixtures/schema.graphql");
-    let expected = include_str!("print/fixtures/schema.expected");
-    test_fixture(transform_fixture, "schema.graphql", "print/fixtures/schema.expected", input, expected);
+fn schema_document() {
+    let input = include_str!("print/fixtures/schema-document.graphql");
+    let expected = include_str!("print/fixtures/schema-document.expected");
+    test_fixture(transform_fixture, "schema-document.graphql", "print/fixtures/schema-document.expected", input, expected);
 }
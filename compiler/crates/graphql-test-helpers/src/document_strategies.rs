@@ -0,0 +1,453 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! proptest strategies that generate the text of syntactically valid GraphQL
+//! documents, e.g. to check that the printers and the parser agree with
+//! `graphql_text_printer::check_executable_round_trip`. The documents don't
+//! have to be valid against a schema.
+
+use proptest::collection::vec;
+use proptest::option;
+use proptest::prelude::*;
+
+/// Words that are avoided as names, since they are ambiguous in some
+/// positions (e.g. `... on` or an enum value `true`).
+const RESERVED_NAMES: &[&str] = &[
+    "on",
+    "true",
+    "false",
+    "null",
+    "fragment",
+    "query",
+    "mutation",
+    "subscription",
+    "schema",
+    "scalar",
+    "type",
+    "interface",
+    "union",
+    "enum",
+    "input",
+    "directive",
+    "extend",
+    "implements",
+    "repeatable",
+];
+
+/// Operations and fragments.
+pub fn executable_document() -> impl Strategy<Value = String> {
+    vec(
+        prop_oneof![operation_definition(), fragment_definition()],
+        1..4,
+    )
+    .prop_map(|definitions| definitions.join("\n\n"))
+}
+
+/// Type system definitions and extensions.
+pub fn schema_document() -> impl Strategy<Value = String> {
+    vec(type_system_definition(), 1..5).prop_map(|definitions| definitions.join("\n\n"))
+}
+
+pub fn name() -> impl Strategy<Value = String> {
+    "[_A-Za-z][_0-9A-Za-z]{0,6}".prop_filter("Reserved names are ambiguous", |name| {
+        !RESERVED_NAMES.contains(&name.as_str())
+    })
+}
+
+fn operation_definition() -> impl Strategy<Value = String> {
+    (
+        prop_oneof![Just("query"), Just("mutation"), Just("subscription")],
+        option::of(name()),
+        vec(variable_definition(), 0..3),
+        directives(),
+        selection_set(),
+    )
+        .prop_map(|(kind, name, variables, directives, selections)| {
+            let variables = if variables.is_empty() {
+                String::new()
+            } else {
+                format!("({})", variables.join(", "))
+            };
+            format!(
+                "{} {}{}{} {}",
+                kind,
+                name.unwrap_or_default(),
+                variables,
+                directives,
+                selections
+            )
+        })
+}
+
+fn fragment_definition() -> impl Strategy<Value = String> {
+    (name(), name(), directives(), selection_set()).prop_map(
+        |(name, type_condition, directives, selections)| {
+            format!(
+                "fragment {} on {}{} {}",
+                name, type_condition, directives, selections
+            )
+        },
+    )
+}
+
+fn variable_definition() -> impl Strategy<Value = String> {
+    (
+        name(),
+        type_annotation(),
+        option::of(constant_value()),
+        directives(),
+    )
+        .prop_map(|(name, type_annotation, default_value, directives)| {
+            let default_value = default_value
+                .map(|value| format!(" = {}", value))
+                .unwrap_or_default();
+            format!(
+                "${}: {}{}{}",
+                name, type_annotation, default_value, directives
+            )
+        })
+}
+
+fn selection_set() -> impl Strategy<Value = String> {
+    let leaf = prop_oneof![scalar_field(), fragment_spread()];
+    vec(leaf, 1..4)
+        .prop_map(|selections| format!("{{ {} }}", selections.join(" ")))
+        .prop_recursive(3, 24, 4, |inner| {
+            vec(
+                prop_oneof![
+                    scalar_field(),
+                    fragment_spread(),
+                    linked_field(inner.clone()),
+                    inline_fragment(inner),
+                ],
+                1..4,
+            )
+            .prop_map(|selections| format!("{{ {} }}", selections.join(" ")))
+        })
+}
+
+fn scalar_field() -> impl Strategy<Value = String> {
+    (option::of(name()), name(), arguments(value()), directives()).prop_map(
+        |(alias, name, arguments, directives)| {
+            let alias = alias
+                .map(|alias| format!("{}: ", alias))
+                .unwrap_or_default();
+            format!("{}{}{}{}", alias, name, arguments, directives)
+        },
+    )
+}
+
+fn linked_field(selections: BoxedStrategy<String>) -> impl Strategy<Value = String> {
+    (scalar_field(), selections).prop_map(|(field, selections)| format!("{} {}", field, selections))
+}
+
+fn inline_fragment(selections: BoxedStrategy<String>) -> impl Strategy<Value = String> {
+    (option::of(name()), directives(), selections).prop_map(
+        |(type_condition, directives, selections)| {
+            let type_condition = type_condition
+                .map(|type_condition| format!(" on {}", type_condition))
+                .unwrap_or_default();
+            format!("...{}{} {}", type_condition, directives, selections)
+        },
+    )
+}
+
+fn fragment_spread() -> impl Strategy<Value = String> {
+    (name(), directives()).prop_map(|(name, directives)| format!("...{}{}", name, directives))
+}
+
+fn directives() -> impl Strategy<Value = String> {
+    vec(
+        (name(), arguments(value()))
+            .prop_map(|(name, arguments)| format!("@{}{}", name, arguments)),
+        0..3,
+    )
+    .prop_map(|directives| {
+        directives
+            .into_iter()
+            .map(|directive| format!(" {}", directive))
+            .collect()
+    })
+}
+
+fn constant_directives() -> impl Strategy<Value = String> {
+    vec(
+        (name(), arguments(constant_value()))
+            .prop_map(|(name, arguments)| format!("@{}{}", name, arguments)),
+        0..2,
+    )
+    .prop_map(|directives| {
+        directives
+            .into_iter()
+            .map(|directive| format!(" {}", directive))
+            .collect()
+    })
+}
+
+fn arguments(value: impl Strategy<Value = String>) -> impl Strategy<Value = String> {
+    vec((name(), value), 0..3).prop_map(|arguments| {
+        if arguments.is_empty() {
+            String::new()
+        } else {
+            let arguments = arguments
+                .into_iter()
+                .map(|(name, value)| format!("{}: {}", name, value))
+                .collect::<Vec<_>>();
+            format!("({})", arguments.join(", "))
+        }
+    })
+}
+
+fn type_annotation() -> impl Strategy<Value = String> {
+    name().prop_recursive(3, 6, 1, |inner| {
+        prop_oneof![
+            inner.clone().prop_map(|type_| format!("[{}]", type_)),
+            inner.prop_map(|type_| format!("{}!", type_.trim_end_matches('!'))),
+        ]
+    })
+}
+
+/// Values that may contain variables.
+fn value() -> impl Strategy<Value = String> {
+    let leaf = prop_oneof![scalar_value(), name().prop_map(|name| format!("${}", name)),];
+    leaf.prop_recursive(2, 8, 3, list_or_object)
+}
+
+fn constant_value() -> impl Strategy<Value = String> {
+    scalar_value().prop_recursive(2, 8, 3, list_or_object)
+}
+
+fn list_or_object(value: BoxedStrategy<String>) -> impl Strategy<Value = String> {
+    prop_oneof![
+        vec(value.clone(), 0..3).prop_map(|items| format!("[{}]", items.join(", "))),
+        vec((name(), value), 0..3).prop_map(|fields| {
+            let fields = fields
+                .into_iter()
+                .map(|(name, value)| format!("{}: {}", name, value))
+                .collect::<Vec<_>>();
+            format!("{{{}}}", fields.join(", "))
+        }),
+    ]
+}
+
+fn scalar_value() -> impl Strategy<Value = String> {
+    prop_oneof![
+        any::<i32>().prop_map(|int| int.to_string()),
+        (-1000i32..1000, 0u32..1000).prop_map(|(int, fraction)| format!("{}.{}", int, fraction)),
+        string_value(),
+        prop_oneof![Just("true"), Just("false"), Just("null")].prop_map(str::to_string),
+        name(),
+    ]
+}
+
+fn string_value() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "[ a-zA-Z0-9_.,!?]{0,12}",
+        Just("\\\"escaped\\\"".to_string()),
+        Just("a\\\\b".to_string()),
+        Just("line\\nbreak".to_string()),
+        Just("\\u00e9".to_string()),
+    ]
+    .prop_map(|string| format!("\"{}\"", string))
+}
+
+fn description() -> impl Strategy<Value = String> {
+    option::of(prop_oneof![
+        string_value().prop_map(|string| format!("{}\n", string)),
+        "[ a-zA-Z0-9_.,!?]{1,20}".prop_map(|text| format!("\"\"\"\n{}\n\"\"\"\n", text)),
+        // Multiple lines with different indentation.
+        "( {0,4}[a-zA-Z0-9_.,!?]{1,10}\n){1,3}".prop_map(|text| format!("\"\"\"{}\"\"\"\n", text)),
+    ])
+    .prop_map(Option::unwrap_or_default)
+}
+
+fn type_system_definition() -> impl Strategy<Value = String> {
+    let definition = prop_oneof![
+        scalar_type_definition(),
+        object_type_definition("type"),
+        object_type_definition("interface"),
+        union_type_definition(),
+        enum_type_definition(),
+        input_object_type_definition(),
+        directive_definition(),
+    ];
+    (option::of(Just("extend ")), definition).prop_map(|(extend, definition)| match extend {
+        // Extensions don't have descriptions or directive definitions.
+        Some(extend) if !definition.starts_with('"') && !definition.starts_with("directive") => {
+            format!("{}{}", extend, definition)
+        }
+        _ => definition,
+    })
+}
+
+fn scalar_type_definition() -> impl Strategy<Value = String> {
+    (description(), name(), constant_directives()).prop_map(|(description, name, directives)| {
+        format!("{}scalar {}{}", description, name, directives)
+    })
+}
+
+fn object_type_definition(keyword: &'static str) -> impl Strategy<Value = String> {
+    (
+        description(),
+        name(),
+        vec(name(), 0..3),
+        constant_directives(),
+        vec(field_definition(), 1..4),
+    )
+        .prop_map(move |(description, name, interfaces, directives, fields)| {
+            let interfaces = if interfaces.is_empty() {
+                String::new()
+            } else {
+                format!(" implements {}", interfaces.join(" & "))
+            };
+            format!(
+                "{}{} {}{}{} {{\n{}\n}}",
+                description,
+                keyword,
+                name,
+                interfaces,
+                directives,
+                fields.join("\n")
+            )
+        })
+}
+
+fn field_definition() -> impl Strategy<Value = String> {
+    (
+        description(),
+        name(),
+        vec(input_value_definition(), 0..3),
+        type_annotation(),
+        constant_directives(),
+    )
+        .prop_map(
+            |(description, name, arguments, type_annotation, directives)| {
+                let arguments = if arguments.is_empty() {
+                    String::new()
+                } else {
+                    format!("({})", arguments.join(", "))
+                };
+                format!(
+                    "{}{}{}: {}{}",
+                    description, name, arguments, type_annotation, directives
+                )
+            },
+        )
+}
+
+fn input_value_definition() -> impl Strategy<Value = String> {
+    (
+        name(),
+        type_annotation(),
+        option::of(constant_value()),
+        constant_directives(),
+    )
+        .prop_map(|(name, type_annotation, default_value, directives)| {
+            let default_value = default_value
+                .map(|value| format!(" = {}", value))
+                .unwrap_or_default();
+            format!(
+                "{}: {}{}{}",
+                name, type_annotation, default_value, directives
+            )
+        })
+}
+
+fn union_type_definition() -> impl Strategy<Value = String> {
+    (
+        description(),
+        name(),
+        constant_directives(),
+        vec(name(), 1..4),
+    )
+        .prop_map(|(description, name, directives, members)| {
+            format!(
+                "{}union {}{} = {}",
+                description,
+                name,
+                directives,
+                members.join(" | ")
+            )
+        })
+}
+
+fn enum_type_definition() -> impl Strategy<Value = String> {
+    (
+        description(),
+        name(),
+        constant_directives(),
+        vec((name(), constant_directives()), 1..4),
+    )
+        .prop_map(|(description, name, directives, values)| {
+            let values = values
+                .into_iter()
+                .map(|(value, directives)| format!("{}{}", value, directives))
+                .collect::<Vec<_>>();
+            format!(
+                "{}enum {}{} {{\n{}\n}}",
+                description,
+                name,
+                directives,
+                values.join("\n")
+            )
+        })
+}
+
+fn input_object_type_definition() -> impl Strategy<Value = String> {
+    (
+        description(),
+        name(),
+        constant_directives(),
+        vec(input_value_definition(), 1..4),
+    )
+        .prop_map(|(description, name, directives, fields)| {
+            format!(
+                "{}input {}{} {{\n{}\n}}",
+                description,
+                name,
+                directives,
+                fields.join("\n")
+            )
+        })
+}
+
+fn directive_definition() -> impl Strategy<Value = String> {
+    (
+        description(),
+        name(),
+        vec(input_value_definition(), 0..3),
+        any::<bool>(),
+        vec(
+            prop_oneof![
+                Just("QUERY"),
+                Just("FIELD"),
+                Just("FRAGMENT_SPREAD"),
+                Just("INLINE_FRAGMENT"),
+                Just("OBJECT"),
+                Just("FIELD_DEFINITION"),
+                Just("ARGUMENT_DEFINITION"),
+                Just("ENUM_VALUE"),
+            ],
+            1..4,
+        ),
+    )
+        .prop_map(|(description, name, arguments, repeatable, locations)| {
+            let arguments = if arguments.is_empty() {
+                String::new()
+            } else {
+                format!("({})", arguments.join(", "))
+            };
+            format!(
+                "{}directive @{}{}{} on {}",
+                description,
+                name,
+                arguments,
+                if repeatable { " repeatable" } else { "" },
+                locations.join(" | ")
+            )
+        })
+}
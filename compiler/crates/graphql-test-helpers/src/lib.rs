@@ -5,14 +5,21 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+pub mod document_strategies;
+
 use std::collections::HashMap;
 use std::sync::Arc;
 
 use common::Diagnostic;
+use common::DiagnosticSeverity;
 use common::DiagnosticsResult;
 use common::SourceLocationKey;
 use common::TextSource;
+use fixture_tests::split_documents;
 use fixture_tests::Fixture;
+use fixture_tests::FixtureDiagnostic;
+use fixture_tests::FixtureSeverity;
+use fixture_tests::FixtureSpan;
 use graphql_cli::DiagnosticPrinter;
 use graphql_cli::Sources as DiagnosticPrinterSources;
 use graphql_ir::build_ir_with_extra_features;
@@ -24,33 +31,43 @@ use graphql_syntax::parse_executable;
 use graphql_text_printer::print_fragment;
 use graphql_text_printer::print_operation;
 use graphql_text_printer::PrinterOptions;
+use relay_test_schema::get_schema_with_located_extensions;
 use relay_test_schema::get_test_schema;
-use relay_test_schema::get_test_schema_with_located_extensions;
+use schema::SDLSchema;
 
 pub fn apply_transform_for_test<T>(fixture: &Fixture<'_>, transform: T) -> Result<String, String>
 where
     T: Fn(&Program) -> DiagnosticsResult<Program>,
 {
     let parts: Vec<_> = fixture.content.split("%extensions%").collect();
-    let source_location = SourceLocationKey::embedded(fixture.file_name, 0);
-    let source_text = parts[0];
+    let documents = split_documents(fixture.file_name, parts[0]);
 
     let mut sources_map: HashMap<SourceLocationKey, String> = Default::default();
-
-    sources_map.insert(source_location, source_text.to_string());
-    let ast = parse_executable(source_text, source_location).unwrap();
-    let schema = if let Some(extensions_text) = parts.get(1) {
+    let mut definitions = Vec::new();
+    for document in &documents {
+        // Documents of multi-file fixtures are located in a file of their own
+        let source_location = if documents.len() == 1 {
+            SourceLocationKey::embedded(fixture.file_name, 0)
+        } else {
+            SourceLocationKey::standalone(document.name)
+        };
+        sources_map.insert(source_location, document.content.to_string());
+        let ast = parse_executable(document.content, source_location).unwrap();
+        definitions.extend(ast.definitions);
+    }
+    let inline_extensions = parts.get(1).map(|extensions_text| {
         let extension_location = SourceLocationKey::embedded(fixture.file_name, 1);
         sources_map.insert(extension_location, extensions_text.to_string());
-
-        get_test_schema_with_located_extensions(*extensions_text, extension_location)
-    } else {
-        get_test_schema()
-    };
+        (*extensions_text, extension_location)
+    });
+    if let Some(extensions) = fixture.extensions {
+        sources_map.insert(extensions_file_location(), extensions.to_string());
+    }
+    let schema = get_fixture_schema(fixture, inline_extensions);
 
     let ir_result = build_ir_with_extra_features(
         &schema,
-        &ast.definitions,
+        &definitions,
         &BuilderOptions {
             allow_undefined_fragment_spreads: false,
             fragment_variables_semantic: FragmentVariablesSemantic::PassedValue,
@@ -88,6 +105,31 @@ where
     Ok(printed.join("\n\n"))
 }
 
+/// Returns the schema for a fixture: the `schema.graphql` file of its fixtures
+/// directory or the test schema, extended with the `extensions.graphql` file of
+/// the directory and the `inline_extensions` (typically the `%extensions%`
+/// section of the fixture).
+pub fn get_fixture_schema(
+    fixture: &Fixture<'_>,
+    inline_extensions: Option<(&str, SourceLocationKey)>,
+) -> Arc<SDLSchema> {
+    let extensions = fixture
+        .extensions
+        .map(|extensions| (extensions, extensions_file_location()))
+        .into_iter()
+        .chain(inline_extensions)
+        .collect::<Vec<_>>();
+    if fixture.schema.is_none() && extensions.is_empty() {
+        get_test_schema()
+    } else {
+        get_schema_with_located_extensions(fixture.schema, &extensions)
+    }
+}
+
+fn extensions_file_location() -> SourceLocationKey {
+    SourceLocationKey::standalone("extensions.graphql")
+}
+
 pub fn diagnostics_to_sorted_string(source: &str, diagnostics: &[Diagnostic]) -> String {
     let printer =
         DiagnosticPrinter::new(|_| Some(TextSource::from_whole_document(source.to_string())));
@@ -118,3 +160,59 @@ fn print_diagnostics_to_sorted_string<T: DiagnosticPrinterSources>(
     printed.sort();
     printed.join("\n\n")
 }
+
+/// Converts diagnostics to the structured form of the DIAGNOSTICS section of a
+/// `FixtureOutput`, sorted by location. All the diagnostics are expected to be
+/// located in `source`.
+pub fn diagnostics_to_fixture_diagnostics(
+    source: &str,
+    diagnostics: &[Diagnostic],
+) -> Vec<FixtureDiagnostic> {
+    convert_to_fixture_diagnostics(diagnostics, |_| {
+        Some(TextSource::from_whole_document(source.to_string()))
+    })
+}
+
+pub fn diagnostics_to_fixture_diagnostics_with_sources_map(
+    sources: &HashMap<SourceLocationKey, String>,
+    diagnostics: &[Diagnostic],
+) -> Vec<FixtureDiagnostic> {
+    convert_to_fixture_diagnostics(diagnostics, |source_location| {
+        sources
+            .get(&source_location)
+            .map(TextSource::from_whole_document)
+    })
+}
+
+fn convert_to_fixture_diagnostics(
+    diagnostics: &[Diagnostic],
+    get_source: impl Fn(SourceLocationKey) -> Option<TextSource>,
+) -> Vec<FixtureDiagnostic> {
+    let mut converted = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let location = diagnostic.location();
+            let span = get_source(location.source_location()).map(|source| {
+                let range = source.to_span_range(location.span());
+                FixtureSpan {
+                    file_name: location.source_location().path().to_string(),
+                    start: (range.start.line + 1, range.start.character + 1),
+                    end: (range.end.line + 1, range.end.character + 1),
+                }
+            });
+            FixtureDiagnostic {
+                span,
+                severity: match diagnostic.severity() {
+                    DiagnosticSeverity::WARNING => FixtureSeverity::Warning,
+                    DiagnosticSeverity::INFORMATION => FixtureSeverity::Info,
+                    DiagnosticSeverity::HINT => FixtureSeverity::Hint,
+                    _ => FixtureSeverity::Error,
+                },
+                code: diagnostic.code().map(|kind| kind.to_string()),
+                message: diagnostic.message().to_string(),
+            }
+        })
+        .collect::<Vec<_>>();
+    converted.sort();
+    converted
+}
@@ -12,6 +12,7 @@
 mod print_ast_to_text;
 mod print_full_operation;
 mod print_to_text;
+mod round_trip;
 
 pub use print_ast_to_text::print_executable_definition_ast;
 pub use print_ast_to_text::print_fragment_ast;
@@ -31,3 +32,6 @@ pub use print_to_text::write_arguments;
 pub use print_to_text::write_directives;
 pub use print_to_text::write_value;
 pub use print_to_text::PrinterOptions;
+pub use round_trip::check_executable_round_trip;
+pub use round_trip::check_schema_round_trip;
+pub use round_trip::RoundTripError;
@@ -0,0 +1,143 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Checks that printing a parsed document and parsing the printed text gives
+//! the same document, to keep the parser and the printers compatible.
+
+use std::fmt;
+
+use common::Diagnostic;
+use common::SourceLocationKey;
+use graphql_syntax::parse_executable;
+use graphql_syntax::parse_schema_document;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::print_ast_to_text::print_executable_definition_ast;
+
+/// Why a document doesn't survive a parse, print and parse round trip.
+#[derive(Debug)]
+pub enum RoundTripError {
+    /// The input isn't a valid document.
+    InvalidInput { diagnostics: Vec<Diagnostic> },
+    /// The printer produced text that doesn't parse.
+    InvalidPrintedDocument {
+        printed: String,
+        diagnostics: Vec<Diagnostic>,
+    },
+    /// The printed document parses to a different document, so the printer
+    /// lost or changed part of it.
+    ChangedDocument {
+        printed: String,
+        original_ast: String,
+        reparsed_ast: String,
+    },
+    /// Printing the reparsed document gives different text.
+    NotAFixedPoint { printed: String, reprinted: String },
+}
+
+impl fmt::Display for RoundTripError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoundTripError::InvalidInput { diagnostics } => {
+                write!(f, "The input doesn't parse: {:?}", diagnostics)
+            }
+            RoundTripError::InvalidPrintedDocument {
+                printed,
+                diagnostics,
+            } => write!(
+                f,
+                "The printed document doesn't parse: {:?}\n\nPrinted:\n{}",
+                diagnostics, printed
+            ),
+            RoundTripError::ChangedDocument {
+                printed,
+                original_ast,
+                reparsed_ast,
+            } => write!(
+                f,
+                "The printed document parses to a different document.\n\nPrinted:\n{}\n\nOriginal:\n{}\n\nReparsed:\n{}",
+                printed, original_ast, reparsed_ast
+            ),
+            RoundTripError::NotAFixedPoint { printed, reprinted } => write!(
+                f,
+                "Printing the reparsed document changed it.\n\nPrinted:\n{}\n\nReprinted:\n{}",
+                printed, reprinted
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RoundTripError {}
+
+/// Parses operations and fragments and prints them with
+/// `print_executable_definition_ast`. Checks that the printed text parses to
+/// the same document (ignoring positions and the kinds of tokens, e.g. block
+/// strings printed as strings) and that printing it again gives the same
+/// text. Returns the printed document.
+pub fn check_executable_round_trip(source: &str) -> Result<String, RoundTripError> {
+    check_round_trip(source, |source| {
+        let document = parse_executable(source, SourceLocationKey::generated())?;
+        let printed = document
+            .definitions
+            .iter()
+            .map(print_executable_definition_ast)
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        Ok((format!("{:#?}", document.definitions), printed))
+    })
+}
+
+/// Parses type system definitions and prints them with their `Display`
+/// implementation, with the same checks as `check_executable_round_trip`.
+pub fn check_schema_round_trip(source: &str) -> Result<String, RoundTripError> {
+    check_round_trip(source, |source| {
+        let document = parse_schema_document(source, SourceLocationKey::generated())?;
+        Ok((format!("{:#?}", document.definitions), document.to_string()))
+    })
+}
+
+/// `parse_and_print` returns the debug representation of the parsed
+/// document and the printed document.
+fn check_round_trip(
+    source: &str,
+    parse_and_print: impl Fn(&str) -> Result<(String, String), Vec<Diagnostic>>,
+) -> Result<String, RoundTripError> {
+    let (original_ast, printed) = parse_and_print(source)
+        .map_err(|diagnostics| RoundTripError::InvalidInput { diagnostics })?;
+    let (reparsed_ast, reprinted) = parse_and_print(&printed).map_err(|diagnostics| {
+        RoundTripError::InvalidPrintedDocument {
+            printed: printed.clone(),
+            diagnostics,
+        }
+    })?;
+    let original_ast = without_positions(&original_ast);
+    let reparsed_ast = without_positions(&reparsed_ast);
+    if original_ast != reparsed_ast {
+        Err(RoundTripError::ChangedDocument {
+            printed,
+            original_ast,
+            reparsed_ast,
+        })
+    } else if printed != reprinted {
+        Err(RoundTripError::NotAFixedPoint { printed, reprinted })
+    } else {
+        Ok(printed)
+    }
+}
+
+/// Removes the spans and tokens from the debug representation of an AST.
+fn without_positions(ast: &str) -> String {
+    lazy_static! {
+        static ref TOKEN: Regex = Regex::new(r"(?m)^\s*token: Token \{\n[^}]*\},\n").unwrap();
+        static ref SPAN: Regex = Regex::new(r"(?m)^\s*(span|location): .*\n").unwrap();
+        static ref NESTED_TOKEN: Regex = Regex::new(r"Token \{\n[^}]*\}").unwrap();
+    }
+    let ast = TOKEN.replace_all(ast, "");
+    let ast = SPAN.replace_all(&ast, "");
+    NESTED_TOKEN.replace_all(&ast, "Token").into_owned()
+}
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<ac613948ae97fa3d5385399cf284e3fe>>
+ * @generated SignedSource<<712731cc8ff300d523c761b024094f3d>>
  */
 
 mod print_ast;
@@ -96,6 +96,13 @@ fn empty_args() {
     test_fixture(transform_fixture, "empty_args.graphql", "print_ast/fixtures/empty_args.expected", input, expected);
 }
 
+#[test]
+fn fragment_arguments() {
+    let input = include_str!("print_ast/fixtures/fragment_arguments.graphql");
+    let expected = include_str!("print_ast/fixtures/fragment_arguments.expected");
+    test_fixture(transform_fixture, "fragment_arguments.graphql", "print_ast/fixtures/fragment_arguments.expected", input, expected);
+}
+
 #[test]
 fn kitchen_sink() {
     let input = include_str!("print_ast/fixtures/kitchen-sink.graphql");
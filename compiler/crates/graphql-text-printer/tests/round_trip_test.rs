@@ -0,0 +1,28 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use graphql_test_helpers::document_strategies::executable_document;
+use graphql_test_helpers::document_strategies::schema_document;
+use graphql_text_printer::check_executable_round_trip;
+use graphql_text_printer::check_schema_round_trip;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn executable_documents_round_trip(document in executable_document()) {
+        if let Err(error) = check_executable_round_trip(&document) {
+            panic!("{}\n\nInput:\n{}", error, document);
+        }
+    }
+
+    #[test]
+    fn schema_documents_round_trip(document in schema_document()) {
+        if let Err(error) = check_schema_round_trip(&document) {
+            panic!("{}\n\nInput:\n{}", error, document);
+        }
+    }
+}
@@ -8,6 +8,8 @@
 use std::path::PathBuf;
 use std::process::Output;
 
+use common::diagnostic_codes;
+use common::message_with_code;
 use thiserror::Error;
 
 #[derive(Debug)]
@@ -17,30 +19,45 @@ pub struct ConfigError {
 }
 impl std::fmt::Display for ConfigError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Invalid config file: {:?}: {}", &self.path, &self.code)
+        write!(
+            f,
+            "Invalid config file: {:?}: {}",
+            &self.path,
+            message_with_code(&self.code)
+        )
     }
 }
 
-#[derive(Debug, Error)]
-pub enum ErrorCode {
-    #[error("Error parsing package.json: {error}")]
-    PackageJsonParseError { error: serde_json::Error },
-
-    #[error("Found key `{key}` in package.json, but failed incorrect value: {error}")]
-    PackageJsonInvalidValue {
-        key: String,
-        error: serde_json::Error,
-    },
-
-    #[error("Error parsing JSON: {error}")]
-    JsonParseError {
-        #[from]
-        error: serde_json::Error,
-    },
-
-    #[error("YAML file parsing not supported")]
-    YamlFileUnsupported,
-
-    #[error("Error running node: {}", String::from_utf8_lossy(&output.stderr))]
-    NodeExecuteError { output: Output },
+diagnostic_codes! {
+    #[derive(Debug, Error)]
+    pub enum ErrorCode {
+        #[error("Error parsing package.json: {error}")]
+        #[code("RELAY3057")]
+        PackageJsonParseError { error: serde_json::Error },
+
+        #[error("Found key `{key}` in package.json, but failed incorrect value: {error}")]
+        #[code("RELAY3058")]
+        PackageJsonInvalidValue {
+            key: String,
+            error: serde_json::Error,
+        },
+
+        #[error("Error parsing JSON: {error}")]
+        #[code("RELAY3059")]
+        JsonParseError {
+            #[from]
+            error: serde_json::Error,
+        },
+
+        #[error("Error parsing YAML: {error}")]
+        #[code("RELAY3060")]
+        YamlParseError {
+            #[from]
+            error: serde_yaml::Error,
+        },
+
+        #[error("Error running node: {}", String::from_utf8_lossy(&output.stderr))]
+        #[code("RELAY3061")]
+        NodeExecuteError { output: Output },
+    }
 }
@@ -43,9 +43,12 @@ impl<'a, T: for<'de> Deserialize<'de>> Loader<T> for PackageJsonLoader<'a> {
 }
 
 pub struct YamlLoader;
-impl<T> Loader<T> for YamlLoader {
-    fn load(&self, _path: &Path) -> Result<Option<T>, ErrorCode> {
-        Err(ErrorCode::YamlFileUnsupported)
+impl<T: for<'de> Deserialize<'de> + 'static> Loader<T> for YamlLoader {
+    fn load(&self, path: &Path) -> Result<Option<T>, ErrorCode> {
+        let file = File::open(path).unwrap();
+        let reader = BufReader::new(file);
+        let config = serde_yaml::from_reader(reader)?;
+        Ok(Some(config))
     }
 }
 
@@ -174,7 +174,7 @@ fn config_js_invalid_js() {
 }
 
 #[test]
-fn unsupported_file() {
+fn rc_yaml() {
     let dir = tempdir().unwrap();
     let dir_d = dir.path().join("a/b/c/d");
     let dir_f = dir.path().join("a/b/c/d/e/f");
@@ -182,12 +182,26 @@ fn unsupported_file() {
 
     std::fs::write(dir_d.join(".foorc.yaml"), "name: correct").unwrap();
 
+    let config = search::<TestConfig>("foo", &dir_f).unwrap().unwrap();
+    assert_eq!(config.value.name, "correct");
+    assert_eq!(config.path.file_name().unwrap(), ".foorc.yaml");
+}
+
+#[test]
+fn rc_yaml_invalid_value() {
+    let dir = tempdir().unwrap();
+    let dir_d = dir.path().join("a/b/c/d");
+    let dir_f = dir.path().join("a/b/c/d/e/f");
+    create_dir_all(&dir_f).unwrap();
+
+    std::fs::write(dir_d.join(".foorc.yml"), "other: value").unwrap();
+
     match search::<TestConfig>("foo", &dir_f).unwrap_err() {
         ConfigError {
-            code: ErrorCode::YamlFileUnsupported,
+            code: ErrorCode::YamlParseError { error: _ },
             path,
         } => {
-            assert_eq!(path.file_name().unwrap(), ".foorc.yaml");
+            assert_eq!(path.file_name().unwrap(), ".foorc.yml");
         }
         other => panic!("incorrect error: {:?}", other),
     }
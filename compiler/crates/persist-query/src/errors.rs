@@ -5,33 +5,41 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use common::diagnostic_codes;
 use thiserror::Error;
 
-#[derive(Debug, Error)]
-pub enum PersistError {
-    #[error("Network create error: {error}")]
-    NetworkCreateError {
-        error: Box<dyn std::error::Error + Send>,
-    },
+diagnostic_codes! {
+    #[derive(Debug, Error)]
+    pub enum PersistError {
+        #[error("Network create error: {error}")]
+        #[code("RELAY3052")]
+        NetworkCreateError {
+            error: Box<dyn std::error::Error + Send>,
+        },
 
-    #[error("Network error: {source}")]
-    NetworkError {
-        #[from]
-        source: hyper::Error,
-    },
+        #[error("Network error: {source}")]
+        #[code("RELAY3053")]
+        NetworkError {
+            #[from]
+            source: hyper::Error,
+        },
 
-    #[error("Persisting failed: {message}")]
-    ErrorResponse { message: String },
+        #[error("Persisting failed: {message}")]
+        #[code("RELAY3054")]
+        ErrorResponse { message: String },
 
-    #[error("Failed parsing response: {source}")]
-    ResponseParseError {
-        #[from]
-        source: serde_json::Error,
-    },
+        #[error("Failed parsing response: {source}")]
+        #[code("RELAY3055")]
+        ResponseParseError {
+            #[from]
+            source: serde_json::Error,
+        },
 
-    #[error("IO Error: {source}")]
-    IOError {
-        #[from]
-        source: std::io::Error,
-    },
+        #[error("IO Error: {source}")]
+        #[code("RELAY3056")]
+        IOError {
+            #[from]
+            source: std::io::Error,
+        },
+    }
 }
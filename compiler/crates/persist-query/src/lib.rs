@@ -81,3 +81,20 @@ pub async fn persist(
         }),
     }
 }
+
+/// Sends a `HEAD` request to `uri` and returns the status of the response.
+/// Any response, even with an error status, means that the server is
+/// reachable.
+pub async fn check_reachable(uri: &str) -> Result<u16, PersistError> {
+    let req = Request::builder()
+        .method(Method::HEAD)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|err| PersistError::NetworkCreateError {
+            error: Box::new(err),
+        })?;
+    let https = HttpsConnector::new();
+    let client = Client::builder().build(https);
+    let res = client.request(req).await?;
+    Ok(res.status().as_u16())
+}
@@ -20,4 +20,31 @@ pub enum Error {
 
     #[error("Unable to run relay compiler. Error details: \n{details}")]
     CompilerError { details: String },
+
+    #[error("Unable to compare the artifacts. Error details: \n{details}")]
+    ArtifactDiffError { details: String },
+
+    #[error("Unable to run the benchmark. Error details: \n{details}")]
+    BenchError { details: String },
+
+    #[error("The environment of the compiler has problems. Error details: \n{details}")]
+    DoctorError { details: String },
+
+    #[error("Unable to run the baseline command. Error details: \n{details}")]
+    BaselineError { details: String },
+
+    #[error("Unable to answer the batch requests. Error details: \n{details}")]
+    BatchError { details: String },
+
+    #[error("Unable to merge the shards of the build. Error details: \n{details}")]
+    MergeShardsError { details: String },
+
+    #[error("Unable to load the message catalog. Error details: \n{details}")]
+    MessageCatalogError { details: String },
+
+    #[error("Unable to run the codemod. Error details: \n{details}")]
+    CodemodError { details: String },
+
+    #[error("Unknown diagnostic code `{code}`. Codes look like `RELAY1001`.")]
+    UnknownDiagnosticCode { code: String },
 }
@@ -7,21 +7,47 @@
 
 use std::env;
 use std::env::current_dir;
+use std::io;
 use std::path::PathBuf;
 use std::process::Command;
 use std::sync::Arc;
 
 use clap::ArgEnum;
 use clap::Parser;
+use common::find_diagnostic_code;
+use common::message_with_code;
+use common::set_message_catalog;
 use common::ConsoleLogger;
+use common::JsonMessageCatalog;
+use common::PerfLogger;
 use intern::string_key::Intern;
 use intern::Lookup;
 use log::error;
 use log::info;
+use relay_compiler::artifact_diff::diff_artifact_directories;
+use relay_compiler::baseline::Baseline;
+use relay_compiler::bench::run_benchmark;
+use relay_compiler::bench::write_synthetic_project;
+use relay_compiler::bench::SyntheticProjectOptions;
 use relay_compiler::build_project::artifact_writer::ArtifactValidationWriter;
+use relay_compiler::codemod::FieldCoordinate;
 use relay_compiler::compiler::Compiler;
+use relay_compiler::config::BuildOnly;
 use relay_compiler::config::Config;
+use relay_compiler::doctor::run_doctor;
+use relay_compiler::doctor::DoctorStatus;
 use relay_compiler::errors::Error as CompilerError;
+use relay_compiler::interactive::InteractiveStatusReporter;
+use relay_compiler::interactive::InteractiveWatch;
+use relay_compiler::metrics::Metrics;
+use relay_compiler::metrics::MetricsPerfLogger;
+use relay_compiler::resolver_graph::resolver_graphs_to_json;
+use relay_compiler::saved_state::pull_saved_state;
+use relay_compiler::shard::merge_persisted_queries;
+use relay_compiler::shard::Shard;
+use relay_compiler::status_reporter::ConsoleStatusReporter;
+use relay_compiler::status_reporter::DiagnosticsFormat;
+use relay_compiler::DebugTransformsConfig;
 use relay_compiler::FileSourceKind;
 use relay_compiler::LocalPersister;
 use relay_compiler::OperationPersister;
@@ -84,14 +110,97 @@ struct CompileCommand {
     #[clap(long)]
     repersist: bool,
 
+    /// Removes the stale artifacts in the generated directories, e.g. of
+    /// renamed operations, that a build without a saved state would leave.
+    #[clap(long, conflicts_with = "watch")]
+    prune: bool,
+
     /// Verbosity level
     #[clap(long, arg_enum, default_value = "verbose")]
     output: OutputKind,
 
+    /// How diagnostics are printed
+    #[clap(long, arg_enum, alias = "diagnostics-format", default_value = "plain")]
+    diagnostics_format: DiagnosticsFormatKind,
+
+    /// Prints the messages of diagnostics with the templates of this JSON
+    /// file, by diagnostic code (e.g. `{"RELAY1001": "..."}`).
+    #[clap(long, alias = "message-catalog")]
+    message_catalog: Option<PathBuf>,
+
     /// Looks for pending changes and exits with non-zero code instead of
     /// writing to disk
     #[clap(long)]
     validate: bool,
+
+    /// Applies the fixes of diagnostics that are safe to apply without review
+    /// (e.g. removing unused variables) to the source files before compiling,
+    /// and prints a summary of the edits.
+    #[clap(long, conflicts_with = "validate")]
+    fix: bool,
+
+    /// Prints the extended explanation of a diagnostic code (e.g. `RELAY1001`)
+    /// or kind (e.g. `DuplicateDefinition`), and exits.
+    #[clap(long, value_name = "CODE")]
+    explain: Option<String>,
+
+    /// Writes the printed IR of this operation (with the fragments it spreads)
+    /// after every transform, to find the transform that changed it.
+    #[clap(long, alias = "debug-transforms")]
+    debug_transforms: Option<String>,
+
+    /// Directory to write the `--debugTransforms` output to. Defaults to
+    /// `relay-debug-transforms` in the current directory.
+    #[clap(long, alias = "debug-transforms-output", requires = "debugTransforms")]
+    debug_transforms_output: Option<PathBuf>,
+
+    /// In watch mode, serves `http://127.0.0.1:<PORT>/ensure?file=<path>`,
+    /// which answers once the artifacts of the file are up to date, for
+    /// bundlers to read them after a save without racing with the compiler.
+    #[clap(
+        long,
+        alias = "on-demand-port",
+        value_name = "PORT",
+        requires = "watch"
+    )]
+    on_demand_port: Option<u16>,
+
+    /// In watch mode, serves Prometheus metrics of the builds (durations of
+    /// the phases, artifact and diagnostic counts, memory usage) on
+    /// `http://0.0.0.0:<PORT>/metrics`.
+    #[clap(long, alias = "metrics-port", value_name = "PORT", requires = "watch")]
+    metrics_port: Option<u16>,
+
+    /// Only writes and persists the artifacts of this shard of the build,
+    /// e.g. `3/8`, to split a clean build across workers. The queries of a
+    /// local persister are written next to its file, to be merged by
+    /// `relay-compiler merge-shards` once all the shards are built.
+    #[clap(long, value_name = "INDEX/COUNT", conflicts_with = "watch")]
+    shard: Option<Shard>,
+
+    /// Starts from the saved state of the last commit of the main branch
+    /// found in the `savedStateStorage` of the config, and only compiles the
+    /// files changed since that commit.
+    #[clap(long, alias = "pull-saved-state", conflicts_with = "watch")]
+    pull_saved_state: bool,
+
+    /// Pushes the saved state of the build to the `savedStateStorage` of the
+    /// config, under the commit of `HEAD`, e.g. on CI builds of the main
+    /// branch.
+    #[clap(long, alias = "push-saved-state", conflicts_with = "watch")]
+    push_saved_state: bool,
+
+    /// Only generates the types, without printing or persisting the text of
+    /// the operations (`typegen`), or only the artifacts, without the types
+    /// (`artifacts`). Overrides the `only` option of the config.
+    #[clap(long, arg_enum, conflicts_with = "validate")]
+    only: Option<OnlyKind>,
+
+    /// In watch mode, reads commands from the terminal to rebuild, toggle
+    /// the debug logs or filter the errors by project, and clears the screen
+    /// between builds.
+    #[clap(long, short, requires = "watch")]
+    interactive: bool,
 }
 
 #[derive(Parser)]
@@ -108,12 +217,321 @@ struct LspCommand {
     /// Verbosity level
     #[clap(long, arg_enum, default_value = "quiet-with-errors")]
     output: OutputKind,
+
+    /// Prints the messages of diagnostics with the templates of this JSON
+    /// file, by diagnostic code (e.g. `{"RELAY1001": "..."}`).
+    #[clap(long, alias = "message-catalog")]
+    message_catalog: Option<PathBuf>,
+}
+
+#[derive(Parser)]
+#[clap(
+    about = "Print the schema the compiler uses for a project: the server schema with client schema extensions and Relay Resolver types.",
+    rename_all = "camel_case"
+)]
+struct PrintSchemaCommand {
+    /// Use this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Print the schema of this project. You can pass this argument multiple times.
+    /// If excluded, the schemas of all projects will be printed.
+    #[clap(name = "project", long, short)]
+    projects: Vec<String>,
+
+    /// Only print the types, fields and directives that client schema extensions
+    /// and Relay Resolvers add to the server schema.
+    #[clap(long)]
+    client_only: bool,
+}
+
+#[derive(Parser)]
+#[clap(
+    about = "Compare two directories of generated artifacts, e.g. before and after a compiler upgrade, and report their structural differences grouped by cause.",
+    rename_all = "camel_case"
+)]
+struct DiffArtifactsCommand {
+    /// Directory with the original artifacts.
+    dir_a: PathBuf,
+
+    /// Directory with the new artifacts.
+    dir_b: PathBuf,
+}
+
+#[derive(Parser)]
+#[clap(
+    about = "Generate a synthetic project of the given size, compile it several times and print the duration of each compiler phase.",
+    rename_all = "camel_case"
+)]
+struct BenchCommand {
+    /// Number of fragments, each in its own source file.
+    #[clap(long, default_value = "1000")]
+    fragments: usize,
+
+    /// How deep the selections of each fragment are nested.
+    #[clap(long, default_value = "3")]
+    depth: usize,
+
+    /// Share of the fragments (between 0 and 1) that select an interface with
+    /// inline fragments on its implementations.
+    #[clap(long, alias = "abstract-ratio", default_value = "0.2")]
+    abstract_ratio: f64,
+
+    /// Number of fragments spread by each query.
+    #[clap(long, alias = "fragments-per-query", default_value = "10")]
+    fragments_per_query: usize,
+
+    /// Number of times the project is compiled.
+    #[clap(long, default_value = "5")]
+    iterations: usize,
+
+    /// Directory to generate the project in. It is kept after the benchmark,
+    /// to inspect the generated files or rerun the benchmark on them. Defaults
+    /// to a temporary directory that is removed afterwards.
+    #[clap(long, alias = "project-dir")]
+    project_dir: Option<PathBuf>,
+}
+
+#[derive(Parser)]
+#[clap(
+    about = "Print what the compiler knows about an operation: its source location, the fragments it depends on, the generated artifacts and their size, its persisted ID and its IR after the transforms.",
+    rename_all = "camel_case"
+)]
+struct ExplainCommand {
+    /// Name of the operation.
+    operation: String,
+
+    /// Use this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Look for the operation in this project. You can pass this argument multiple times.
+    /// If excluded, all projects are searched.
+    #[clap(name = "project", long, short)]
+    projects: Vec<String>,
+}
+
+#[derive(Parser)]
+#[clap(
+    about = "Report the diagnostics of the compiler as fast as possible, e.g. in a pre-commit hook: only parses the documents, builds their IR and runs the validations, without generating or writing any artifact.",
+    rename_all = "camel_case"
+)]
+struct LintCommand {
+    /// Use this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Lint only this project. You can pass this argument multiple times.
+    /// If excluded, all projects will be linted.
+    #[clap(name = "project", long, short)]
+    projects: Vec<String>,
+
+    /// Verbosity level
+    #[clap(long, arg_enum, default_value = "verbose")]
+    output: OutputKind,
+
+    /// How diagnostics are printed
+    #[clap(long, arg_enum, alias = "diagnostics-format", default_value = "plain")]
+    diagnostics_format: DiagnosticsFormatKind,
+
+    /// Prints the messages of diagnostics with the templates of this JSON
+    /// file, by diagnostic code (e.g. `{"RELAY1001": "..."}`).
+    #[clap(long, alias = "message-catalog")]
+    message_catalog: Option<PathBuf>,
+}
+
+#[derive(Parser)]
+#[clap(
+    about = "Check the environment of the compiler (Watchman, config, schemas, output directories, persist endpoints and artifacts) and print how to fix the problems found.",
+    rename_all = "camel_case"
+)]
+struct DoctorCommand {
+    /// Use this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+}
+
+#[derive(Parser)]
+#[clap(
+    about = "Merge the queries persisted locally by the shards of a build (`--shard`) into the persisted queries file of each project.",
+    rename_all = "camel_case"
+)]
+struct MergeShardsCommand {
+    /// The number of shards of the build.
+    count: usize,
+
+    /// Use this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+}
+
+#[derive(Parser)]
+#[clap(
+    about = "Record the current validation diagnostics in the baseline file of the config, or check that there are no new ones.",
+    rename_all = "camel_case"
+)]
+struct BaselineCommand {
+    #[clap(arg_enum)]
+    action: BaselineAction,
+
+    /// Use this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// How diagnostics are printed
+    #[clap(long, arg_enum, alias = "diagnostics-format", default_value = "plain")]
+    diagnostics_format: DiagnosticsFormatKind,
+}
+
+#[derive(Parser)]
+#[clap(
+    about = "Compile the files sent on stdin, for bundler plugins running the compiler as a long-lived process: each line is a JSON request `{\"file\": ..., \"contents\": ...}`, answered by a JSON line on stdout with the artifacts and diagnostics of the file. No artifact is written to disk.",
+    rename_all = "camel_case"
+)]
+struct BatchCommand {
+    /// Use this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Compile only this project. You can pass this argument multiple times.
+    /// If excluded, all projects will be compiled.
+    #[clap(name = "project", long, short)]
+    projects: Vec<String>,
+}
+
+#[derive(Parser)]
+#[clap(
+    about = "Print the graph of the dependencies of the Relay Resolvers: the root fragment of each resolver field, the fragments, resolvers and server fields they select, and the server fields each resolver ultimately reads.",
+    rename_all = "camel_case"
+)]
+struct ResolverGraphCommand {
+    /// Use this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Print the graph of this project. You can pass this argument multiple times.
+    /// If excluded, the graphs of all projects will be printed.
+    #[clap(name = "project", long, short)]
+    projects: Vec<String>,
+
+    /// Output format
+    #[clap(long, arg_enum, default_value = "json")]
+    format: ResolverGraphFormat,
+}
+
+#[derive(Parser)]
+#[clap(
+    about = "Rewrite the GraphQL documents of the projects in place.",
+    rename_all = "camel_case"
+)]
+struct CodemodCommand {
+    #[clap(subcommand)]
+    codemod: Codemod,
+}
+
+#[derive(clap::Subcommand)]
+enum Codemod {
+    RenameField(RenameFieldCommand),
+    ReplaceDeprecatedFields(ReplaceDeprecatedFieldsCommand),
+    MigrateFragmentArguments(MigrateFragmentArgumentsCommand),
+}
+
+#[derive(Parser)]
+#[clap(
+    about = "Rename the selections of a field in all documents, with their aliases and in fragments on the interfaces of its type. Ambiguous selections are reported and left unchanged. The schema isn't changed.",
+    rename_all = "camel_case"
+)]
+struct RenameFieldCommand {
+    /// The field to rename, e.g. `User.name`.
+    field: String,
+
+    /// The new name of the field, on the same type, e.g. `User.fullName`.
+    new_field: String,
+
+    /// Use this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Rename the selections of this project. You can pass this argument multiple times.
+    /// If excluded, the selections of all projects are renamed.
+    #[clap(name = "project", long, short)]
+    projects: Vec<String>,
+}
+
+#[derive(Parser)]
+#[clap(
+    about = "Replace the selections of the deprecated fields whose deprecation reason names a replacement field, like \"Use `fullName` instead.\", keeping their response keys. Selections with arguments the replacement doesn't accept are reported and left unchanged.",
+    rename_all = "camel_case"
+)]
+struct ReplaceDeprecatedFieldsCommand {
+    /// Use this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Replace the deprecated fields in this project. You can pass this argument multiple times.
+    /// If excluded, the deprecated fields of all projects are replaced.
+    #[clap(name = "project", long, short)]
+    projects: Vec<String>,
+}
+
+#[derive(Parser)]
+#[clap(
+    about = "Rewrite the `@argumentDefinitions` of the fragments to variable definitions, like `fragment Foo($size: Int = 32) on User`, and the `@arguments` of their spreads to arguments, like `...Foo(size: 64)`. Arguments with a `provider` and spreads with `@uncheckedArguments_DEPRECATED` are reported and left unchanged.",
+    rename_all = "camel_case"
+)]
+struct MigrateFragmentArgumentsCommand {
+    /// Use this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Migrate the documents of this project. You can pass this argument multiple times.
+    /// If excluded, the documents of all projects are migrated.
+    #[clap(name = "project", long, short)]
+    projects: Vec<String>,
+}
+
+#[derive(ArgEnum, Clone, Copy)]
+enum ResolverGraphFormat {
+    /// A JSON object with the graph of each project, by project name
+    Json,
+    /// A Graphviz digraph per project
+    Dot,
+}
+
+#[derive(ArgEnum, Clone, Copy)]
+enum BaselineAction {
+    /// Records the diagnostics of all projects, replacing the baseline
+    Generate,
+    /// Fails on diagnostics missing from the baseline
+    Check,
 }
 
 #[derive(clap::Subcommand)]
 enum Commands {
     Compiler(CompileCommand),
     Lsp(LspCommand),
+    PrintSchema(PrintSchemaCommand),
+    DiffArtifacts(DiffArtifactsCommand),
+    Bench(BenchCommand),
+    Explain(ExplainCommand),
+    Lint(LintCommand),
+    Doctor(DoctorCommand),
+    Baseline(BaselineCommand),
+    Batch(BatchCommand),
+    MergeShards(MergeShardsCommand),
+    ResolverGraph(ResolverGraphCommand),
+    Codemod(CodemodCommand),
 }
 
 #[derive(ArgEnum, Clone, Copy)]
@@ -124,6 +542,24 @@ enum OutputKind {
     Verbose,
 }
 
+#[derive(ArgEnum, Clone, Copy)]
+enum OnlyKind {
+    /// The types, for typechecking
+    Typegen,
+    /// The artifacts without the types
+    Artifacts,
+}
+
+#[derive(ArgEnum, Clone, Copy)]
+enum DiagnosticsFormatKind {
+    /// The message followed by source excerpts
+    Plain,
+    /// Source excerpts with the locations underlined and labeled
+    Rich,
+    /// One line per diagnostic
+    Short,
+}
+
 #[derive(Parser)]
 #[clap(rename_all = "camel_case")]
 pub struct CliConfig {
@@ -170,33 +606,54 @@ async fn main() {
     let result = match command {
         Commands::Compiler(command) => handle_compiler_command(command).await,
         Commands::Lsp(command) => handle_lsp_command(command).await,
+        Commands::PrintSchema(command) => handle_print_schema_command(command).await,
+        Commands::DiffArtifacts(command) => handle_diff_artifacts_command(command),
+        Commands::Bench(command) => handle_bench_command(command).await,
+        Commands::Explain(command) => handle_explain_command(command).await,
+        Commands::Lint(command) => handle_lint_command(command).await,
+        Commands::Doctor(command) => handle_doctor_command(command).await,
+        Commands::Baseline(command) => handle_baseline_command(command).await,
+        Commands::Batch(command) => handle_batch_command(command).await,
+        Commands::MergeShards(command) => handle_merge_shards_command(command),
+        Commands::ResolverGraph(command) => handle_resolver_graph_command(command).await,
+        Commands::Codemod(command) => handle_codemod_command(command).await,
     };
 
     match result {
         Ok(_) => info!("Done."),
         Err(err) => {
-            error!("{}", err);
+            match &err {
+                Error::ConfigError(error) => error!("{}", message_with_code(error)),
+                _ => error!("{}", err),
+            }
             std::process::exit(1);
         }
     }
 }
 
 fn get_config(config_path: Option<PathBuf>) -> Result<Config, Error> {
-    match config_path {
+    let mut config = match config_path {
         Some(config_path) => Config::load(config_path).map_err(Error::ConfigError),
         None => Config::search(&current_dir().expect("Unable to get current working directory."))
             .map_err(Error::ConfigError),
+    }?;
+    if let Some(version) = option_env!("CARGO_PKG_VERSION") {
+        config.compiler_version = version.to_string();
     }
+    Ok(config)
 }
 
-fn configure_logger(output: OutputKind, terminal_mode: TerminalMode) {
-    let log_level = match output {
+fn log_level(output: OutputKind) -> LevelFilter {
+    match output {
         OutputKind::Debug => LevelFilter::Debug,
         OutputKind::Quiet => LevelFilter::Off,
         OutputKind::QuietWithErrors => LevelFilter::Error,
         OutputKind::Verbose => LevelFilter::Info,
-    };
+    }
+}
 
+fn configure_logger(output: OutputKind, terminal_mode: TerminalMode) {
+    let log_level = log_level(output);
     let log_config = SimpleLogConfigBuilder::new()
         .set_time_level(LevelFilter::Off)
         .set_target_level(LevelFilter::Off)
@@ -207,6 +664,55 @@ fn configure_logger(output: OutputKind, terminal_mode: TerminalMode) {
     TermLogger::init(log_level, log_config, terminal_mode, ColorChoice::Auto).unwrap();
 }
 
+/// Print the diagnostics of the compiler in the format of the
+/// `diagnostics-format` flag
+fn set_diagnostics_format(config: &mut Config, diagnostics_format: DiagnosticsFormatKind) {
+    config.status_reporter = Box::new(console_status_reporter(config, diagnostics_format));
+}
+
+fn console_status_reporter(
+    config: &Config,
+    diagnostics_format: DiagnosticsFormatKind,
+) -> ConsoleStatusReporter {
+    let diagnostics_format = match diagnostics_format {
+        DiagnosticsFormatKind::Rich => DiagnosticsFormat::Rich,
+        DiagnosticsFormatKind::Plain => DiagnosticsFormat::Plain,
+        DiagnosticsFormatKind::Short => DiagnosticsFormat::Short,
+    };
+    ConsoleStatusReporter::new(config.root_dir.clone(), config.projects.len() > 1)
+        .with_diagnostics_format(diagnostics_format)
+}
+
+/// Reads the commands of the interactive watch mode from the terminal, and
+/// prints the builds through it
+fn set_interactive_flag(
+    config: &mut Config,
+    output: OutputKind,
+    diagnostics_format: DiagnosticsFormatKind,
+) {
+    let interactive = Arc::new(InteractiveWatch::new(
+        config.projects.keys().copied().collect(),
+        log_level(output),
+    ));
+    config.status_reporter = Box::new(InteractiveStatusReporter::new(
+        console_status_reporter(config, diagnostics_format),
+        Arc::clone(&interactive),
+    ));
+    config.interactive = Some(interactive);
+}
+
+/// Installs the message catalog of the `messageCatalog` flag, if set
+fn set_message_catalog_flag(path: Option<PathBuf>) -> Result<(), Error> {
+    if let Some(path) = path {
+        let catalog =
+            JsonMessageCatalog::load(&path).map_err(|err| Error::MessageCatalogError {
+                details: format!("{}: {}", path.display(), err),
+            })?;
+        set_message_catalog(Box::new(catalog));
+    }
+    Ok(())
+}
+
 /// Update Config if the `project` flag is set
 fn set_project_flag(config: &mut Config, projects: Vec<String>) -> Result<(), Error> {
     if projects.is_empty() {
@@ -241,7 +747,19 @@ fn set_project_flag(config: &mut Config, projects: Vec<String>) -> Result<(), Er
 }
 
 async fn handle_compiler_command(command: CompileCommand) -> Result<(), Error> {
-    configure_logger(command.output, TerminalMode::Mixed);
+    if let Some(code) = &command.explain {
+        return explain_diagnostic_code(code);
+    }
+
+    // The interactive watch mode toggles the debug logs.
+    configure_logger(
+        if command.interactive {
+            OutputKind::Debug
+        } else {
+            command.output
+        },
+        TerminalMode::Mixed,
+    );
 
     if command.cli_config.is_defined() {
         return Err(Error::ConfigError(CompilerError::ConfigError {
@@ -255,21 +773,28 @@ async fn handle_compiler_command(command: CompileCommand) -> Result<(), Error> {
     let mut config = get_config(command.config)?;
 
     set_project_flag(&mut config, command.projects)?;
+    set_diagnostics_format(&mut config, command.diagnostics_format);
+    if command.interactive {
+        set_interactive_flag(&mut config, command.output, command.diagnostics_format);
+    }
+    set_message_catalog_flag(command.message_catalog)?;
 
     if command.validate {
         config.artifact_writer = Box::new(ArtifactValidationWriter::default());
     }
 
-    config.create_operation_persister = Some(Box::new(|project_config| {
+    let shard = command.shard;
+    config.create_operation_persister = Some(Box::new(move |project_config| {
         project_config.persist.as_ref().map(
             |persist_config| -> Box<dyn OperationPersister + Send + Sync> {
                 match persist_config {
                     PersistConfig::Remote(remote_config) => {
                         Box::new(RemotePersister::new(remote_config.clone()))
                     }
-                    PersistConfig::Local(local_config) => {
-                        Box::new(LocalPersister::new(local_config.clone()))
-                    }
+                    PersistConfig::Local(local_config) => Box::new(match shard {
+                        Some(shard) => LocalPersister::for_shard(local_config.clone(), shard),
+                        None => LocalPersister::new(local_config.clone()),
+                    }),
                 }
             },
         )
@@ -280,7 +805,44 @@ async fn handle_compiler_command(command: CompileCommand) -> Result<(), Error> {
     } else {
         FileSourceKind::WalkDir
     };
+    if command.pull_saved_state || command.push_saved_state {
+        if config.saved_state_storage.is_none() {
+            return Err(Error::ConfigError(CompilerError::ConfigError {
+                details: "`--pullSavedState` and `--pushSavedState` require the `savedStateStorage` option in the config.".to_string(),
+            }));
+        }
+        if command.pull_saved_state {
+            pull_saved_state(&mut config)
+                .await
+                .map_err(|err| Error::CompilerError {
+                    details: format!("{}", err),
+                })?;
+        }
+    }
     config.repersist_operations = command.repersist;
+    config.prune_stale_artifacts = command.prune;
+    config.shard = command.shard;
+    if let Some(only) = command.only {
+        config.only = Some(match only {
+            OnlyKind::Typegen => BuildOnly::Typegen,
+            OnlyKind::Artifacts => BuildOnly::Artifacts,
+        });
+    }
+    config.on_demand_port = command.on_demand_port;
+
+    if let Some(operation_name) = command.debug_transforms {
+        let output_dir = command
+            .debug_transforms_output
+            .unwrap_or_else(|| current_dir().unwrap().join("relay-debug-transforms"));
+        info!(
+            "Writing the IR of `{}` after every transform to {:?}",
+            operation_name, output_dir
+        );
+        config.debug_transforms = Some(DebugTransformsConfig {
+            operation_name: operation_name.intern(),
+            output_dir,
+        });
+    }
 
     if command.watch && !matches!(&config.file_source_config, FileSourceKind::Watchman) {
         panic!(
@@ -288,19 +850,65 @@ async fn handle_compiler_command(command: CompileCommand) -> Result<(), Error> {
         );
     }
 
-    let compiler = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger));
+    let config = Arc::new(config);
+    match command.metrics_port {
+        Some(port) => {
+            let metrics = Arc::new(Metrics::default());
+            let perf_logger = MetricsPerfLogger::new(ConsoleLogger, Arc::clone(&metrics));
+            let compiler = Compiler::new(config, Arc::new(perf_logger)).with_metrics(metrics, port);
+            run_compiler(
+                compiler,
+                command.fix,
+                command.watch,
+                command.push_saved_state,
+            )
+            .await
+        }
+        None => {
+            let compiler = Compiler::new(config, Arc::new(ConsoleLogger));
+            run_compiler(
+                compiler,
+                command.fix,
+                command.watch,
+                command.push_saved_state,
+            )
+            .await
+        }
+    }
+}
+
+async fn run_compiler<TPerfLogger: PerfLogger + 'static>(
+    compiler: Compiler<TPerfLogger>,
+    fix: bool,
+    watch: bool,
+    push_saved_state: bool,
+) -> Result<(), Error> {
+    if fix {
+        let summary = compiler.fix().await.map_err(|err| Error::CompilerError {
+            details: format!("{}", err),
+        })?;
+        info!("{}", summary.to_string().trim_end());
+    }
 
-    if command.watch {
+    if watch {
         compiler.watch().await.map_err(|err| Error::CompilerError {
             details: format!("{:?}", err),
         })?;
     } else {
-        compiler
+        let compiler_state = compiler
             .compile()
             .await
             .map_err(|err| Error::CompilerError {
                 details: format!("{}", err),
             })?;
+        if push_saved_state {
+            compiler
+                .push_saved_state(&compiler_state)
+                .await
+                .map_err(|err| Error::CompilerError {
+                    details: format!("{}", err),
+                })?;
+        }
     }
 
     Ok(())
@@ -310,6 +918,7 @@ async fn handle_lsp_command(command: LspCommand) -> Result<(), Error> {
     configure_logger(command.output, TerminalMode::Stderr);
 
     let config = get_config(command.config)?;
+    set_message_catalog_flag(command.message_catalog)?;
 
     let perf_logger = Arc::new(ConsoleLogger);
     let extra_data_provider = Box::new(DummyExtraDataProvider::new());
@@ -333,6 +942,472 @@ async fn handle_lsp_command(command: LspCommand) -> Result<(), Error> {
     Ok(())
 }
 
+async fn handle_print_schema_command(command: PrintSchemaCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+
+    let mut config = get_config(command.config)?;
+
+    set_project_flag(&mut config, command.projects)?;
+
+    config.file_source_config = if should_use_watchman() {
+        FileSourceKind::Watchman
+    } else {
+        FileSourceKind::WalkDir
+    };
+
+    let compiler = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger));
+    let schemas = compiler
+        .build_schemas()
+        .await
+        .map_err(|err| Error::CompilerError {
+            details: format!("{}", err),
+        })?;
+
+    let relay_extensions =
+        relay_schema::build_relay_extensions_schema().map_err(|errors| Error::CompilerError {
+            details: format!("{:?}", errors),
+        })?;
+    let print_headers = schemas.len() > 1;
+    for (project_name, schema) in schemas {
+        if print_headers {
+            println!("# Project: {}\n", project_name);
+        }
+        if command.client_only {
+            print!(
+                "{}",
+                schema_print::print_client_extensions(&schema, &relay_extensions)
+            );
+        } else {
+            print!("{}", schema_print::print(&schema));
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_resolver_graph_command(command: ResolverGraphCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+
+    let mut config = get_config(command.config)?;
+
+    set_project_flag(&mut config, command.projects)?;
+
+    config.file_source_config = if should_use_watchman() {
+        FileSourceKind::Watchman
+    } else {
+        FileSourceKind::WalkDir
+    };
+
+    let compiler = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger));
+    let graphs = compiler
+        .resolver_graphs()
+        .await
+        .map_err(|err| Error::CompilerError {
+            details: format!("{}", err),
+        })?;
+
+    match command.format {
+        ResolverGraphFormat::Json => println!("{}", resolver_graphs_to_json(&graphs)),
+        ResolverGraphFormat::Dot => {
+            for (project_name, graph) in graphs {
+                print!("{}", graph.to_dot(project_name.lookup()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_codemod_command(command: CodemodCommand) -> Result<(), Error> {
+    match command.codemod {
+        Codemod::RenameField(command) => handle_rename_field_command(command).await,
+        Codemod::ReplaceDeprecatedFields(command) => {
+            handle_replace_deprecated_fields_command(command).await
+        }
+        Codemod::MigrateFragmentArguments(command) => {
+            handle_migrate_fragment_arguments_command(command).await
+        }
+    }
+}
+
+async fn handle_rename_field_command(command: RenameFieldCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+
+    let field: FieldCoordinate = command
+        .field
+        .parse()
+        .map_err(|details| Error::CodemodError { details })?;
+    let new_field: FieldCoordinate = command
+        .new_field
+        .parse()
+        .map_err(|details| Error::CodemodError { details })?;
+    if new_field.type_name != field.type_name {
+        return Err(Error::CodemodError {
+            details: format!(
+                "`{}` can't be renamed to `{}`, the field must stay on the same type.",
+                field, new_field
+            ),
+        });
+    }
+
+    let mut config = get_config(command.config)?;
+
+    set_project_flag(&mut config, command.projects)?;
+
+    config.file_source_config = if should_use_watchman() {
+        FileSourceKind::Watchman
+    } else {
+        FileSourceKind::WalkDir
+    };
+
+    let compiler = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger));
+    let summary = compiler
+        .rename_field(field, new_field.field_name)
+        .await
+        .map_err(|err| Error::CompilerError {
+            details: format!("{}", err),
+        })?;
+    print!("{}", summary);
+
+    Ok(())
+}
+
+async fn handle_replace_deprecated_fields_command(
+    command: ReplaceDeprecatedFieldsCommand,
+) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+
+    let mut config = get_config(command.config)?;
+
+    set_project_flag(&mut config, command.projects)?;
+
+    config.file_source_config = if should_use_watchman() {
+        FileSourceKind::Watchman
+    } else {
+        FileSourceKind::WalkDir
+    };
+
+    let compiler = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger));
+    let summary =
+        compiler
+            .replace_deprecated_fields()
+            .await
+            .map_err(|err| Error::CompilerError {
+                details: format!("{}", err),
+            })?;
+    print!("{}", summary);
+
+    Ok(())
+}
+
+async fn handle_migrate_fragment_arguments_command(
+    command: MigrateFragmentArgumentsCommand,
+) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+
+    let mut config = get_config(command.config)?;
+
+    set_project_flag(&mut config, command.projects)?;
+
+    config.file_source_config = if should_use_watchman() {
+        FileSourceKind::Watchman
+    } else {
+        FileSourceKind::WalkDir
+    };
+
+    let compiler = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger));
+    let summary =
+        compiler
+            .migrate_fragment_arguments()
+            .await
+            .map_err(|err| Error::CompilerError {
+                details: format!("{}", err),
+            })?;
+    print!("{}", summary);
+
+    Ok(())
+}
+
+fn handle_diff_artifacts_command(command: DiffArtifactsCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+
+    let report = diff_artifact_directories(&command.dir_a, &command.dir_b).map_err(|err| {
+        Error::ArtifactDiffError {
+            details: format!("{}", err),
+        }
+    })?;
+    print!("{}", report);
+
+    Ok(())
+}
+
+async fn handle_bench_command(command: BenchCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+
+    if command.iterations == 0 {
+        return Err(Error::BenchError {
+            details: "`--iterations` must be at least 1.".to_string(),
+        });
+    }
+    if !(0.0..=1.0).contains(&command.abstract_ratio) {
+        return Err(Error::BenchError {
+            details: "`--abstractRatio` must be between 0 and 1.".to_string(),
+        });
+    }
+
+    let options = SyntheticProjectOptions {
+        fragments: command.fragments,
+        depth: command.depth,
+        abstract_ratio: command.abstract_ratio,
+        fragments_per_query: command.fragments_per_query,
+    };
+    let (project_dir, is_temporary) = match command.project_dir {
+        Some(project_dir) => (project_dir, false),
+        None => (
+            env::temp_dir().join(format!("relay-bench-{}", std::process::id())),
+            true,
+        ),
+    };
+    let config_path =
+        write_synthetic_project(&project_dir, &options).map_err(|err| Error::BenchError {
+            details: format!("Unable to write the project to {:?}: {}", project_dir, err),
+        })?;
+    println!(
+        "Compiling {} fragments (depth {}, {}% abstract) in {:?}\n",
+        options.fragments,
+        options.depth,
+        options.abstract_ratio * 100.0,
+        project_dir
+    );
+
+    let result = run_benchmark(&config_path, command.iterations).await;
+    if is_temporary {
+        let _ = std::fs::remove_dir_all(&project_dir);
+    }
+    let report = result.map_err(|err| Error::CompilerError {
+        details: format!("{}", err),
+    })?;
+    print!("{}", report);
+
+    Ok(())
+}
+
+async fn handle_explain_command(command: ExplainCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+
+    let mut config = get_config(command.config)?;
+
+    set_project_flag(&mut config, command.projects)?;
+
+    config.file_source_config = if should_use_watchman() {
+        FileSourceKind::Watchman
+    } else {
+        FileSourceKind::WalkDir
+    };
+
+    let compiler = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger));
+    let explanation = compiler
+        .explain_operation(command.operation.intern())
+        .await
+        .map_err(|err| Error::CompilerError {
+            details: format!("{}", err),
+        })?;
+    print!("{}", explanation);
+
+    Ok(())
+}
+
+fn explain_diagnostic_code(code: &str) -> Result<(), Error> {
+    let diagnostic_code =
+        find_diagnostic_code(code).ok_or_else(|| Error::UnknownDiagnosticCode {
+            code: code.to_string(),
+        })?;
+    println!("{}: {}\n", diagnostic_code.code, diagnostic_code.kind);
+    println!("{}\n", diagnostic_code.summary);
+    match diagnostic_code.explanation {
+        Some(explanation) => println!("{}", explanation),
+        None => println!("No extended explanation is available for this diagnostic yet."),
+    }
+    Ok(())
+}
+
+async fn handle_lint_command(command: LintCommand) -> Result<(), Error> {
+    configure_logger(command.output, TerminalMode::Mixed);
+
+    let mut config = get_config(command.config)?;
+
+    set_project_flag(&mut config, command.projects)?;
+    set_diagnostics_format(&mut config, command.diagnostics_format);
+    set_message_catalog_flag(command.message_catalog)?;
+
+    config.file_source_config = if should_use_watchman() {
+        FileSourceKind::Watchman
+    } else {
+        FileSourceKind::WalkDir
+    };
+
+    let compiler = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger));
+    compiler.lint().await.map_err(|err| Error::CompilerError {
+        details: format!("{}", err),
+    })?;
+
+    Ok(())
+}
+
+async fn handle_doctor_command(command: DoctorCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+
+    // Config errors are reported as a failed check.
+    let config = match command.config {
+        Some(config_path) => Config::load(config_path),
+        None => Config::search(&current_dir().expect("Unable to get current working directory.")),
+    }
+    .map(|mut config| {
+        config.file_source_config = if should_use_watchman() {
+            FileSourceKind::Watchman
+        } else {
+            FileSourceKind::WalkDir
+        };
+        config
+    });
+
+    let report = run_doctor(config).await;
+    print!("{}", report);
+
+    if report.has_errors() {
+        let failed_checks = report
+            .checks
+            .iter()
+            .filter(|check| check.status == DoctorStatus::Error)
+            .count();
+        return Err(Error::DoctorError {
+            details: format!("{} check(s) failed.", failed_checks),
+        });
+    }
+
+    Ok(())
+}
+
+async fn handle_baseline_command(command: BaselineCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::Verbose, TerminalMode::Mixed);
+
+    let mut config = get_config(command.config)?;
+    set_diagnostics_format(&mut config, command.diagnostics_format);
+    config.file_source_config = if should_use_watchman() {
+        FileSourceKind::Watchman
+    } else {
+        FileSourceKind::WalkDir
+    };
+    // The diagnostics are collected without the baseline, to compare them
+    // with it.
+    let baseline = config.baseline.take().ok_or_else(|| Error::BaselineError {
+        details: "The config has no `baseline` file.".to_string(),
+    })?;
+    let root_dir = config.root_dir.clone();
+
+    let config = Arc::new(config);
+    let compiler = Compiler::new(Arc::clone(&config), Arc::new(ConsoleLogger));
+    let result = compiler.validation_diagnostics().await;
+    let diagnostics = result.map_err(|err| Error::CompilerError {
+        details: format!("{}", err),
+    })?;
+
+    match command.action {
+        BaselineAction::Generate => {
+            let baseline =
+                Baseline::from_diagnostics(baseline.path().to_path_buf(), &root_dir, &diagnostics);
+            baseline.write().map_err(|err| Error::BaselineError {
+                details: format!("Unable to write `{}`: {}", baseline.path().display(), err),
+            })?;
+            info!(
+                "Recorded {} diagnostic(s) in {}.",
+                baseline.len(),
+                baseline.path().display()
+            );
+        }
+        BaselineAction::Check => {
+            let comparison = baseline.compare(&root_dir, diagnostics);
+            if comparison.fixed_count > 0 {
+                info!(
+                    "{} diagnostic(s) of the baseline are fixed, run `relay-compiler baseline generate` to remove them from it.",
+                    comparison.fixed_count
+                );
+            }
+            if !comparison.new_diagnostics.is_empty() {
+                let new_count = comparison.new_diagnostics.len();
+                config
+                    .status_reporter
+                    .build_errors(&CompilerError::DiagnosticsError {
+                        errors: comparison.new_diagnostics,
+                    });
+                return Err(Error::BaselineError {
+                    details: format!("{} diagnostic(s) are missing from the baseline.", new_count),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_merge_shards_command(command: MergeShardsCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::Verbose, TerminalMode::Mixed);
+
+    let config = get_config(command.config)?;
+    let mut merged_files = Vec::new();
+    for project_config in config.enabled_projects() {
+        if let Some(PersistConfig::Local(local_config)) = &project_config.persist {
+            // Projects may share their persisted queries file.
+            if merged_files.contains(&local_config.file) {
+                continue;
+            }
+            merged_files.push(local_config.file.clone());
+            let query_count =
+                merge_persisted_queries(&local_config.file, command.count).map_err(|err| {
+                    Error::MergeShardsError {
+                        details: format!("[{}] {}", project_config.name, err),
+                    }
+                })?;
+            info!(
+                "[{}] merged {} shards into {:?} ({} queries)",
+                project_config.name, command.count, local_config.file, query_count
+            );
+        }
+    }
+    Ok(())
+}
+
+async fn handle_batch_command(command: BatchCommand) -> Result<(), Error> {
+    // Stdout is reserved for the responses.
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+
+    let mut config = get_config(command.config)?;
+
+    set_project_flag(&mut config, command.projects)?;
+
+    config.file_source_config = if should_use_watchman() {
+        FileSourceKind::Watchman
+    } else {
+        FileSourceKind::WalkDir
+    };
+
+    let compiler = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger));
+    let mut batch_compiler =
+        compiler
+            .batch_compiler()
+            .await
+            .map_err(|err| Error::CompilerError {
+                details: format!("{}", err),
+            })?;
+    batch_compiler
+        .serve(io::stdin().lock(), io::stdout().lock())
+        .map_err(|err| Error::BatchError {
+            details: format!("{}", err),
+        })?;
+
+    Ok(())
+}
+
 /// Check if `watchman` is available.
 /// Additionally, this method is checking for an existence of `FORCE_NO_WATCHMAN`
 /// environment variable. If this `FORCE_NO_WATCHMAN` is set, this method will return `false`
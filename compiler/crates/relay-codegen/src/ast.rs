@@ -5,6 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use common::Location;
 use fnv::FnvBuildHasher;
 use graphql_syntax::FloatValue;
 use graphql_syntax::OperationKind;
@@ -99,6 +100,10 @@ pub enum Primitive {
         key: StringKey,
         plural: bool,
     },
+
+    // Printed as a comment with the source of the selections of an object
+    // instead of an entry, see `debug_source_comments`
+    SourceComment(Location),
 }
 
 impl Primitive {
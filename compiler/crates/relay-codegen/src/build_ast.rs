@@ -5,13 +5,16 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use common::Location;
 use common::NamedItem;
+use common::SourceLocationKey;
 use common::WithLocation;
 use graphql_ir::Argument;
 use graphql_ir::Condition;
 use graphql_ir::ConditionValue;
 use graphql_ir::ConstantValue;
 use graphql_ir::Directive;
+use graphql_ir::Field;
 use graphql_ir::FragmentDefinition;
 use graphql_ir::FragmentSpread;
 use graphql_ir::InlineFragment;
@@ -233,6 +236,28 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
         self.ast_builder.intern(Ast::Array(array))
     }
 
+    /// Prepends a comment with the source of the selections of an object of a
+    /// reader AST, if the project has `debug_source_comments`.
+    fn with_source_comment(
+        &self,
+        mut object: Vec<ObjectEntry>,
+        location: Location,
+    ) -> Vec<ObjectEntry> {
+        if self.variant == CodegenVariant::Reader
+            && self.project_config.debug_source_comments
+            && location.source_location() != SourceLocationKey::Generated
+        {
+            object.insert(
+                0,
+                ObjectEntry {
+                    key: CODEGEN_CONSTANTS.source_comment,
+                    value: Primitive::SourceComment(location),
+                },
+            );
+        }
+        object
+    }
+
     fn build_operation(&mut self, operation: &OperationDefinition) -> AstKey {
         let mut context = ContextualMetadata::default();
         match operation.directives.named(*DIRECTIVE_SPLIT_OPERATION) {
@@ -343,6 +368,7 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
                     Primitive::SkippableNull
                 },
         };
+        let object = self.with_source_comment(object, fragment.name.location);
         self.object(object)
     }
 
@@ -792,7 +818,7 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
             self.build_field_name_and_alias(schema_field.name.item, field.alias, &field.directives);
         let args = self.build_arguments(&field.arguments);
         let selections = self.build_selections(context, field.selections.iter());
-        let primitive = Primitive::Key(self.object(object! {
+        let object = object! {
             :build_alias(alias, name),
             args: match args {
                     None => Primitive::SkippableNull,
@@ -817,7 +843,9 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
                     }
                 }
             },
-        }));
+        };
+        let object = self.with_source_comment(object, field.alias_or_name_location());
+        let primitive = Primitive::Key(self.object(object));
 
         if let Some(required_metadata) = RequiredMetadataDirective::find(&field.directives) {
             self.build_required_field(required_metadata, primitive)
@@ -1103,6 +1131,20 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
             })
         }
 
+        // The resolvers of a project with a resolver context type are passed
+        // the context after their arguments.
+        if self
+            .project_config
+            .typegen_config
+            .resolver_context_type
+            .is_some()
+        {
+            object_props.push(ObjectEntry {
+                key: CODEGEN_CONSTANTS.has_context,
+                value: Primitive::Bool(true),
+            })
+        }
+
         Primitive::Key(self.object(object_props))
     }
 
@@ -1148,7 +1190,10 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
             .item
             .expect_string_literal()
             .to_string()
-            .trim_end_matches(".graphql")
+            .trim_end_matches(&format!(
+                ".{}",
+                self.project_config.artifact_filename.suffix
+            ))
             .intern();
         Primitive::Key(self.object(object! {
             fragment: Primitive::GraphQLModuleDependency(normalization_name),
@@ -1423,7 +1468,7 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
                     }
                 }
                 let selections = self.build_selections(context, inline_frag.selections.iter());
-                let primitive = Primitive::Key(self.object(object! {
+                let object = object! {
                     kind: Primitive::String(CODEGEN_CONSTANTS.inline_fragment),
                     selections: selections,
                     type_: Primitive::String(self.schema.get_type_name(type_condition)),
@@ -1435,7 +1480,9 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
                         } else {
                             Primitive::SkippableNull
                         },
-                }));
+                };
+                let object = self.with_source_comment(object, inline_frag.spread_location);
+                let primitive = Primitive::Key(self.object(object));
                 if let Some(fragment_alias_metadata) =
                     FragmentAliasMetadata::find(&inline_frag.directives)
                 {
@@ -1716,7 +1763,10 @@ impl<'schema, 'builder, 'config> CodegenBuilder<'schema, 'builder, 'config> {
                     key: CODEGEN_CONSTANTS.operation_module_provider,
                     value: Primitive::DynamicImport {
                         provider: dynamic_module_provider,
-                        module: get_fragment_filename(fragment_name),
+                        module: get_fragment_filename(
+                            fragment_name,
+                            &self.project_config.artifact_filename,
+                        ),
                     },
                 });
             }
@@ -58,6 +58,7 @@ pub struct CodegenConstants {
     pub handle_args: StringKey,
     pub handle: StringKey,
     pub has_client_edges: StringKey,
+    pub has_context: StringKey,
     pub id: StringKey,
     pub identifier_field: StringKey,
     pub if_: StringKey,
@@ -104,6 +105,7 @@ pub struct CodegenConstants {
     pub scalar_field: StringKey,
     pub scalar_handle: StringKey,
     pub selections: StringKey,
+    pub source_comment: StringKey,
     pub split_operation: StringKey,
     pub storage_key: StringKey,
     pub stream: StringKey,
@@ -167,6 +169,7 @@ lazy_static! {
         handle_args: "handleArgs".intern(),
         handle: "handle".intern(),
         has_client_edges: "hasClientEdges".intern(),
+        has_context: "hasContext".intern(),
         id: "id".intern(),
         identifier_field: "identifierField".intern(),
         if_: "if".intern(),
@@ -213,6 +216,7 @@ lazy_static! {
         scalar_field: "ScalarField".intern(),
         scalar_handle: "ScalarHandle".intern(),
         selections: "selections".intern(),
+        source_comment: "__sourceComment".intern(),
         split_operation: "SplitOperation".intern(),
         storage_key: "storageKey".intern(),
         stream: "Stream".intern(),
@@ -23,6 +23,7 @@ pub use ast::QueryID;
 pub use ast::RequestParameters;
 pub use build_ast::build_request_params;
 pub use build_ast::is_static_storage_key_available;
+pub use build_ast::md5;
 pub use build_ast::CodegenBuilder;
 pub use build_ast::CodegenVariant;
 pub use constants::CODEGEN_CONSTANTS;
@@ -32,5 +33,6 @@ pub use printer::print_request;
 pub use printer::print_request_params;
 pub use printer::JSONPrinter;
 pub use printer::Printer;
+pub use printer::SourceCommentResolver;
 pub use relay_config::JsModuleFormat;
 pub use top_level_statements::TopLevelStatement;
@@ -11,6 +11,7 @@ use std::fmt::Result as FmtResult;
 use std::fmt::Write;
 use std::path::Path;
 
+use common::Location;
 use common::WithLocation;
 use fnv::FnvBuildHasher;
 use fnv::FnvHashSet;
@@ -19,6 +20,7 @@ use graphql_ir::OperationDefinition;
 use indexmap::IndexMap;
 use intern::string_key::StringKey;
 use intern::Lookup;
+use relay_config::ArtifactFilenameConfig;
 use relay_config::DynamicModuleProvider;
 use relay_config::ProjectConfig;
 use schema::SDLSchema;
@@ -107,10 +109,19 @@ pub fn print_request_params(
     printer.print(request_parameters_ast_key, false)
 }
 
+/// Resolves the comments printed in the reader ASTs of the projects with
+/// `debug_source_comments`, e.g. `UserProfile_user, src/UserProfile.js:12`,
+/// from the location of the selections, since the codegen doesn't have the
+/// sources.
+pub trait SourceCommentResolver: Send + Sync {
+    fn source_comment(&self, location: Location) -> Option<String>;
+}
+
 pub struct Printer<'p> {
     project_config: &'p ProjectConfig,
     builder: AstBuilder,
     dedupe: bool,
+    source_comments: Option<&'p dyn SourceCommentResolver>,
 }
 
 impl<'p> Printer<'p> {
@@ -119,6 +130,7 @@ impl<'p> Printer<'p> {
             project_config,
             builder: Default::default(),
             dedupe: true,
+            source_comments: None,
         }
     }
 
@@ -127,9 +139,17 @@ impl<'p> Printer<'p> {
             project_config,
             builder: Default::default(),
             dedupe: false,
+            source_comments: None,
         }
     }
 
+    /// Prints the comments of the `debug_source_comments` of the project with
+    /// this resolver, they are omitted otherwise.
+    pub fn with_source_comments(mut self, source_comments: &'p dyn SourceCommentResolver) -> Self {
+        self.source_comments = Some(source_comments);
+        self
+    }
+
     pub fn print_provided_variables(
         &mut self,
         schema: &SDLSchema,
@@ -143,7 +163,8 @@ impl<'p> Printer<'p> {
             WithLocation::new(operation.name.location, operation.name.item.0),
             self.project_config,
         )?;
-        let printer = JSONPrinter::new(&self.builder, self.project_config, top_level_statements);
+        let printer = JSONPrinter::new(&self.builder, self.project_config, top_level_statements)
+            .with_source_comments(self.source_comments);
         Some(printer.print(key, self.dedupe))
     }
 
@@ -170,7 +191,8 @@ impl<'p> Printer<'p> {
             &self.builder,
             self.project_config,
             &mut top_level_statements,
-        );
+        )
+        .with_source_comments(self.source_comments);
         printer.print(key, self.dedupe)
     }
 
@@ -201,7 +223,8 @@ impl<'p> Printer<'p> {
             fragment.name.map(|x| x.0),
             self.project_config,
         );
-        let printer = JSONPrinter::new(&self.builder, self.project_config, top_level_statements);
+        let printer = JSONPrinter::new(&self.builder, self.project_config, top_level_statements)
+            .with_source_comments(self.source_comments);
         printer.print(key, self.dedupe)
     }
 
@@ -218,7 +241,8 @@ impl<'p> Printer<'p> {
             WithLocation::new(operation.name.location, operation.name.item.0),
             self.project_config,
         );
-        let printer = JSONPrinter::new(&self.builder, self.project_config, top_level_statements);
+        let printer = JSONPrinter::new(&self.builder, self.project_config, top_level_statements)
+            .with_source_comments(self.source_comments);
         printer.print(key, self.dedupe)
     }
 
@@ -235,7 +259,8 @@ impl<'p> Printer<'p> {
             fragment.name.map(|x| x.0),
             self.project_config,
         );
-        let printer = JSONPrinter::new(&self.builder, self.project_config, top_level_statements);
+        let printer = JSONPrinter::new(&self.builder, self.project_config, top_level_statements)
+            .with_source_comments(self.source_comments);
         printer.print(key, self.dedupe)
     }
 
@@ -255,7 +280,8 @@ impl<'p> Printer<'p> {
             WithLocation::new(operation.name.location, operation.name.item.0),
             self.project_config,
         );
-        let printer = JSONPrinter::new(&self.builder, self.project_config, top_level_statements);
+        let printer = JSONPrinter::new(&self.builder, self.project_config, top_level_statements)
+            .with_source_comments(self.source_comments);
         printer.print(key, self.dedupe)
     }
 }
@@ -268,8 +294,10 @@ pub struct JSONPrinter<'b> {
     builder: &'b AstBuilder,
     eager_es_modules: bool,
     js_module_format: JsModuleFormat,
+    artifact_filename: ArtifactFilenameConfig,
     top_level_statements: &'b mut TopLevelStatements,
     skip_printing_nulls: bool,
+    source_comments: Option<&'b dyn SourceCommentResolver>,
 }
 
 impl<'b> JSONPrinter<'b> {
@@ -284,14 +312,24 @@ impl<'b> JSONPrinter<'b> {
             duplicates: Default::default(),
             builder,
             js_module_format: project_config.js_module_format,
+            artifact_filename: project_config.artifact_filename.clone(),
             eager_es_modules: project_config.typegen_config.eager_es_modules,
             skip_printing_nulls: project_config
                 .feature_flags
                 .skip_printing_nulls
                 .is_fully_enabled(),
+            source_comments: None,
         }
     }
 
+    pub fn with_source_comments(
+        mut self,
+        source_comments: Option<&'b dyn SourceCommentResolver>,
+    ) -> Self {
+        self.source_comments = source_comments;
+        self
+    }
+
     pub fn print(mut self, root_key: AstKey, dedupe: bool) -> String {
         if dedupe {
             let mut visited = Default::default();
@@ -390,6 +428,18 @@ impl<'b> JSONPrinter<'b> {
                     for ObjectEntry { key, value } in object {
                         match value {
                             Primitive::SkippableNull if self.skip_printing_nulls => continue,
+                            Primitive::SourceComment(location) => {
+                                if let Some(comment) =
+                                    self.source_comments.and_then(|source_comments| {
+                                        source_comments.source_comment(*location)
+                                    })
+                                {
+                                    f.push('\n');
+                                    print_indentation(f, next_indent);
+                                    write!(f, "// {}", comment).unwrap();
+                                }
+                                continue;
+                            }
                             _ => {}
                         }
                         f.push('\n');
@@ -468,10 +518,10 @@ impl<'b> JSONPrinter<'b> {
             Primitive::GraphQLModuleDependency(key) => self.write_js_dependency(
                 f,
                 ModuleImportName::Default(format!("{}_graphql", key)),
-                Cow::Owned(format!(
-                    "{}.graphql",
-                    get_module_path(self.js_module_format, *key)
-                )),
+                Cow::Owned(
+                    self.artifact_filename
+                        .module_name(get_module_path(self.js_module_format, *key)),
+                ),
             ),
             Primitive::JSModuleDependency(JSModuleDependency {
                 path,
@@ -528,6 +578,8 @@ impl<'b> JSONPrinter<'b> {
                 indent,
                 is_dedupe_var,
             ),
+            // Printed by the object containing it
+            Primitive::SourceComment(_) => Ok(()),
         }
     }
 
@@ -591,10 +643,10 @@ impl<'b> JSONPrinter<'b> {
         self.write_js_dependency(
             f,
             ModuleImportName::Default(format!("{}_graphql", graphql_module)),
-            Cow::Owned(format!(
-                "{}.graphql",
-                get_module_path(self.js_module_format, graphql_module)
-            )),
+            Cow::Owned(
+                self.artifact_filename
+                    .module_name(get_module_path(self.js_module_format, graphql_module)),
+            ),
         )?;
         write!(f, ", ")?;
         self.write_js_dependency(
@@ -810,5 +862,6 @@ fn write_constant_value(f: &mut String, builder: &AstBuilder, value: &Primitive)
         Primitive::RelayResolverWeakObjectWrapper { .. } => {
             panic!("Unexpected RelayResolverWeakObjectWrapper")
         }
+        Primitive::SourceComment(_) => panic!("Unexpected SourceComment"),
     }
 }
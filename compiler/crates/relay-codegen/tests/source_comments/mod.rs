@@ -0,0 +1,74 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::Location;
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::build;
+use graphql_ir::ExecutableDefinition;
+use graphql_syntax::parse_executable;
+use relay_codegen::JsModuleFormat;
+use relay_codegen::Printer;
+use relay_codegen::SourceCommentResolver;
+use relay_config::ProjectConfig;
+use relay_test_schema::TEST_SCHEMA;
+
+struct FixtureSourceComments<'a> {
+    text: &'a str,
+}
+
+impl SourceCommentResolver for FixtureSourceComments<'_> {
+    fn source_comment(&self, location: Location) -> Option<String> {
+        let start = location.span().start as usize;
+        let line = self.text[..start].matches('\n').count() + 1;
+        Some(format!("{}:{}", location.source_location().path(), line))
+    }
+}
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let ast = parse_executable(
+        fixture.content,
+        SourceLocationKey::standalone(fixture.file_name),
+    )
+    .unwrap();
+    let project_config = ProjectConfig {
+        js_module_format: JsModuleFormat::Haste,
+        debug_source_comments: true,
+        ..Default::default()
+    };
+    let source_comments = FixtureSourceComments {
+        text: fixture.content,
+    };
+    build(&TEST_SCHEMA, &ast.definitions)
+        .map(|definitions| {
+            definitions
+                .iter()
+                .map(|def| {
+                    let mut printer = Printer::with_dedupe(&project_config)
+                        .with_source_comments(&source_comments);
+                    let mut import_statements = Default::default();
+                    let printed = match def {
+                        ExecutableDefinition::Operation(operation) => {
+                            printer.print_operation(&TEST_SCHEMA, operation, &mut import_statements)
+                        }
+                        ExecutableDefinition::Fragment(fragment) => {
+                            printer.print_fragment(&TEST_SCHEMA, fragment, &mut import_statements)
+                        }
+                    };
+                    format!("{}{}", import_statements, printed)
+                })
+                .collect::<Vec<_>>()
+                .join("\n\n")
+        })
+        .map_err(|errors| {
+            errors
+                .into_iter()
+                .map(|error| format!("{:?}", error))
+                .collect::<Vec<_>>()
+                .join("\n\n")
+        })
+}
@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<3436560c5b5dcd4b1f1e60b1e2230d0c>>
+ */
+
+mod source_comments;
+
+use source_comments::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn fragment() {
+    let input = include_str!("source_comments/fixtures/fragment.graphql");
+    let expected = include_str!("source_comments/fixtures/fragment.expected");
+    test_fixture(transform_fixture, "fragment.graphql", "source_comments/fixtures/fragment.expected", input, expected);
+}
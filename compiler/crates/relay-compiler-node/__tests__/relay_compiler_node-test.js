@@ -0,0 +1,84 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @format
+ */
+
+'use strict';
+
+const relay = require('../');
+
+const SCHEMA = `
+type User {
+  name: String
+  age: Int
+}
+
+type Query {
+  me: User
+}`;
+
+const DOCUMENT = `
+query MyQuery {
+  me {
+    name
+    ...AgeFragment
+  }
+}
+
+fragment AgeFragment on User {
+  age
+}`;
+
+describe('compileDocument', () => {
+  test('compiles the definitions', () => {
+    const result = relay.compileDocument(SCHEMA, DOCUMENT);
+    expect(result.diagnostics).toEqual([]);
+    expect(
+      result.definitions.map(definition => [definition.kind, definition.name]),
+    ).toEqual([
+      ['fragment', 'AgeFragment'],
+      ['operation', 'MyQuery'],
+    ]);
+    const operation = result.definitions[1];
+    expect(operation.operationText).toContain('fragment AgeFragment on User');
+    expect(operation.normalizationAst).toContain('"name": "MyQuery"');
+    expect(operation.types).toContain('MyQuery$data');
+  });
+
+  test('reports the diagnostics of the document', () => {
+    const result = relay.compileDocument(
+      SCHEMA,
+      'query MyQuery {\n  me {\n    nmae\n  }\n}',
+    );
+    expect(result.definitions).toEqual([]);
+    expect(result.diagnostics).toEqual([
+      expect.objectContaining({
+        code: 'RELAY1077',
+        severity: 'error',
+        path: '<document>',
+        startLine: 2,
+        startColumn: 4,
+        endLine: 2,
+        endColumn: 8,
+      }),
+    ]);
+  });
+
+  test('rejects invalid feature flags', () => {
+    expect(() =>
+      relay.compileDocument(SCHEMA, DOCUMENT, {featureFlags: {no_inline: 1}}),
+    ).toThrow('Invalid featureFlags');
+  });
+});
+
+describe('loadConfig', () => {
+  test('reports a missing config file', () => {
+    expect(() =>
+      relay.loadConfig({configPath: '/nonexistent/relay.config.json'}),
+    ).toThrow();
+  });
+});
@@ -0,0 +1,23 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @format
+ */
+
+'use strict';
+
+// `napi build --platform` names the binary after the target, e.g.
+// `relay-compiler-node.darwin-arm64.node`.
+const ABI_SUFFIXES = {
+  linux: '-gnu',
+  win32: '-msvc',
+};
+
+const binding = require(`./relay-compiler-node.${process.platform}-${
+  process.arch
+}${ABI_SUFFIXES[process.platform] ?? ''}.node`);
+
+module.exports = binding;
@@ -0,0 +1,231 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::Diagnostic;
+use common::FeatureFlags;
+use common::NoopPerfLogger;
+use common::SourceLocationKey;
+use common::TextSource;
+use graphql_ir::Program;
+use graphql_text_printer::print_full_operation;
+use graphql_text_printer::PrinterOptions;
+use intern::string_key::Intern;
+use intern::Lookup;
+use napi_derive::napi;
+use relay_codegen::print_fragment;
+use relay_codegen::print_operation;
+use relay_config::ProjectConfig;
+use relay_schema::build_schema_with_extensions;
+use relay_transforms::apply_transforms;
+use relay_transforms::Programs;
+use relay_typegen::generate_fragment_type_exports_section;
+use relay_typegen::generate_operation_type_exports_section;
+use relay_typegen::FragmentLocations;
+use relay_typegen::TypegenConfig;
+use schema::SDLSchema;
+
+use crate::diagnostics::to_js_diagnostic;
+use crate::diagnostics::JsDiagnostic;
+
+/// The paths of the diagnostics of the schema and the document.
+const SCHEMA_PATH: &str = "<schema>";
+const DOCUMENT_PATH: &str = "<document>";
+
+#[napi(object)]
+pub struct CompileDocumentOptions {
+    /// The `featureFlags` of a project config.
+    pub feature_flags: Option<serde_json::Value>,
+    /// The typegen options of a project config, e.g. `{"language": "typescript"}`.
+    pub typegen_config: Option<serde_json::Value>,
+}
+
+/// The artifacts of a fragment or an operation.
+#[napi(object)]
+pub struct CompiledDefinition {
+    pub name: String,
+    /// `"fragment"` or `"operation"`.
+    pub kind: String,
+    /// The printed reader AST.
+    pub reader_ast: Option<String>,
+    /// The printed normalization AST of an operation.
+    pub normalization_ast: Option<String>,
+    /// The query text sent to the server for an operation.
+    pub operation_text: Option<String>,
+    pub types: String,
+}
+
+#[napi(object)]
+pub struct CompiledDocument {
+    pub definitions: Vec<CompiledDefinition>,
+    /// The diagnostics of the schema and the document, located at the paths
+    /// `<schema>` and `<document>`. There are no definitions if one of them
+    /// is an error.
+    pub diagnostics: Vec<JsDiagnostic>,
+}
+
+/// Compiles the definitions of `document_text` against the SDL of
+/// `schema_text`, with the default project config.
+pub fn compile_document(
+    schema_text: &str,
+    document_text: &str,
+    options: Option<CompileDocumentOptions>,
+) -> Result<CompiledDocument, String> {
+    let project_config = get_project_config(options)?;
+    let definitions = get_programs(schema_text, document_text, &project_config)
+        .map(|(schema, programs)| print_definitions(&schema, &programs, &project_config));
+    Ok(match definitions {
+        Ok(definitions) => CompiledDocument {
+            definitions,
+            diagnostics: Vec::new(),
+        },
+        Err(diagnostics) => CompiledDocument {
+            definitions: Vec::new(),
+            diagnostics: diagnostics
+                .iter()
+                .map(|diagnostic| {
+                    to_js_diagnostic(diagnostic, None, &|source_location| match source_location {
+                        SourceLocationKey::Standalone { path } if path.lookup() == SCHEMA_PATH => {
+                            Some(TextSource::from_whole_document(schema_text))
+                        }
+                        SourceLocationKey::Standalone { path }
+                            if path.lookup() == DOCUMENT_PATH =>
+                        {
+                            Some(TextSource::from_whole_document(document_text))
+                        }
+                        _ => None,
+                    })
+                })
+                .collect(),
+        },
+    })
+}
+
+fn get_project_config(options: Option<CompileDocumentOptions>) -> Result<ProjectConfig, String> {
+    let options = options.unwrap_or(CompileDocumentOptions {
+        feature_flags: None,
+        typegen_config: None,
+    });
+    let feature_flags: FeatureFlags = options
+        .feature_flags
+        .map(serde_json::from_value)
+        .transpose()
+        .map_err(|err| format!("Invalid featureFlags: {}", err))?
+        .unwrap_or_default();
+    let typegen_config: TypegenConfig = options
+        .typegen_config
+        .map(serde_json::from_value)
+        .transpose()
+        .map_err(|err| format!("Invalid typegenConfig: {}", err))?
+        .unwrap_or_default();
+
+    Ok(ProjectConfig {
+        name: "node_project".intern(),
+        feature_flags: Arc::new(feature_flags),
+        typegen_config,
+        ..Default::default()
+    })
+}
+
+fn get_programs(
+    schema_text: &str,
+    document_text: &str,
+    project_config: &ProjectConfig,
+) -> Result<(Arc<SDLSchema>, Programs), Vec<Diagnostic>> {
+    let schema = Arc::new(build_schema_with_extensions(
+        &[(schema_text, SourceLocationKey::standalone(SCHEMA_PATH))],
+        &Vec::<(&str, SourceLocationKey)>::new(),
+    )?);
+    let document = graphql_syntax::parse_executable(
+        document_text,
+        SourceLocationKey::standalone(DOCUMENT_PATH),
+    )?;
+    let ir = graphql_ir::build(&schema, &document.definitions)?;
+    let program = Program::from_definitions(Arc::clone(&schema), ir);
+
+    let programs = apply_transforms(
+        project_config,
+        Arc::new(program),
+        Default::default(),
+        Arc::new(NoopPerfLogger),
+        None,
+        None,
+        None,
+    )?;
+    Ok((schema, programs))
+}
+
+fn print_definitions(
+    schema: &SDLSchema,
+    programs: &Programs,
+    project_config: &ProjectConfig,
+) -> Vec<CompiledDefinition> {
+    let fragment_locations = FragmentLocations::new(programs.typegen.fragments());
+    let fragments = programs.reader.fragments().map(|fragment| {
+        let mut import_statements = Default::default();
+        let reader_ast = print_fragment(schema, fragment, project_config, &mut import_statements);
+        let types = programs
+            .typegen
+            .fragment(fragment.name.item)
+            .map(|typegen_fragment| {
+                generate_fragment_type_exports_section(
+                    typegen_fragment,
+                    schema,
+                    project_config,
+                    &fragment_locations,
+                )
+            })
+            .unwrap_or_default();
+        CompiledDefinition {
+            name: fragment.name.item.0.lookup().to_string(),
+            kind: "fragment".to_string(),
+            reader_ast: Some(format!("{}{}", import_statements, reader_ast)),
+            normalization_ast: None,
+            operation_text: None,
+            types,
+        }
+    });
+    let operations = programs.typegen.operations().map(|typegen_operation| {
+        let name = typegen_operation.name.item;
+        let normalization_operation = programs.normalization.operation(name).unwrap();
+        let reader_ast = programs.reader.operation(name).map(|operation| {
+            let mut import_statements = Default::default();
+            let ast = print_operation(schema, operation, project_config, &mut import_statements);
+            format!("{}{}", import_statements, ast)
+        });
+        let mut import_statements = Default::default();
+        let normalization_ast = print_operation(
+            schema,
+            normalization_operation,
+            project_config,
+            &mut import_statements,
+        );
+        let operation_text = programs.operation_text.operation(name).map(|operation| {
+            print_full_operation(
+                &programs.operation_text,
+                operation,
+                PrinterOptions::default(),
+            )
+        });
+        CompiledDefinition {
+            name: name.0.lookup().to_string(),
+            kind: "operation".to_string(),
+            reader_ast,
+            normalization_ast: Some(format!("{}{}", import_statements, normalization_ast)),
+            operation_text,
+            types: generate_operation_type_exports_section(
+                typegen_operation,
+                normalization_operation,
+                schema,
+                project_config,
+                &fragment_locations,
+            ),
+        }
+    });
+    fragments.chain(operations).collect()
+}
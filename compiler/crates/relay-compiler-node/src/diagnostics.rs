@@ -0,0 +1,186 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::path::PathBuf;
+
+use common::Diagnostic;
+use common::DiagnosticSeverity;
+use common::SourceLocationKey;
+use common::TextSource;
+use intern::Lookup;
+use napi_derive::napi;
+use relay_compiler::compiler_state::ProjectName;
+use relay_compiler::errors::BuildProjectError;
+use relay_compiler::errors::Error;
+use relay_compiler::source_for_location;
+use relay_compiler::status_reporter::StatusReporter;
+use relay_compiler::FsSourceReader;
+
+/// A diagnostic of the compiler, with its range in its file.
+#[napi(object)]
+pub struct JsDiagnostic {
+    /// The stable code of the diagnostic (e.g. `RELAY1001`), or its kind if
+    /// it has none.
+    pub code: Option<String>,
+    /// `"error"`, `"warning"`, `"information"` or `"hint"`.
+    pub severity: String,
+    pub message: String,
+    /// The project the diagnostic was reported in.
+    pub project: Option<String>,
+    /// The file of the diagnostic, relative to the root directory.
+    pub path: Option<String>,
+    /// 0-based lines and columns of the range of the diagnostic in its file.
+    pub start_line: u32,
+    pub start_column: u32,
+    pub end_line: u32,
+    pub end_column: u32,
+}
+
+/// Converts `diagnostic`, reading the text of its location with
+/// `get_source`.
+pub fn to_js_diagnostic(
+    diagnostic: &Diagnostic,
+    project_name: Option<ProjectName>,
+    get_source: &dyn Fn(SourceLocationKey) -> Option<TextSource>,
+) -> JsDiagnostic {
+    let location = diagnostic.location();
+    let path = match location.source_location() {
+        SourceLocationKey::Embedded { path, .. } | SourceLocationKey::Standalone { path } => {
+            Some(path.lookup().to_string())
+        }
+        SourceLocationKey::Generated => None,
+    };
+    let range = get_source(location.source_location())
+        .map(|source| source.to_span_range(location.span()))
+        .unwrap_or_default();
+    JsDiagnostic {
+        code: diagnostic.error_code().map(|code| code.to_string()),
+        severity: severity_name(diagnostic.severity()).to_string(),
+        message: diagnostic.localized_message(),
+        project: project_name.map(|name| name.lookup().to_string()),
+        path,
+        start_line: range.start.line,
+        start_column: range.start.character,
+        end_line: range.end.line,
+        end_column: range.end.character,
+    }
+}
+
+/// A diagnostic without a location, for the errors of the compiler that are
+/// not about the sources.
+fn error_diagnostic(message: String, project_name: Option<ProjectName>) -> JsDiagnostic {
+    JsDiagnostic {
+        code: None,
+        severity: severity_name(DiagnosticSeverity::ERROR).to_string(),
+        message,
+        project: project_name.map(|name| name.lookup().to_string()),
+        path: None,
+        start_line: 0,
+        start_column: 0,
+        end_line: 0,
+        end_column: 0,
+    }
+}
+
+fn severity_name(severity: DiagnosticSeverity) -> &'static str {
+    match severity {
+        DiagnosticSeverity::ERROR => "error",
+        DiagnosticSeverity::WARNING => "warning",
+        DiagnosticSeverity::INFORMATION => "information",
+        _ => "hint",
+    }
+}
+
+/// The state of a build of the compiler, with its diagnostics.
+#[napi(object)]
+pub struct DiagnosticsEvent {
+    /// `"started"`, `"completed"`, `"failed"` or `"cancelled"`.
+    pub status: String,
+    pub diagnostics: Vec<JsDiagnostic>,
+}
+
+/// A `StatusReporter` passing the diagnostics of the builds to `on_event`,
+/// instead of printing them.
+pub struct DiagnosticsStatusReporter<F> {
+    root_dir: PathBuf,
+    on_event: F,
+}
+
+impl<F: Fn(DiagnosticsEvent) + Send + Sync> DiagnosticsStatusReporter<F> {
+    pub fn new(root_dir: PathBuf, on_event: F) -> Self {
+        Self { root_dir, on_event }
+    }
+
+    fn convert(&self, diagnostic: &Diagnostic, project_name: Option<ProjectName>) -> JsDiagnostic {
+        to_js_diagnostic(diagnostic, project_name, &|source_location| {
+            source_for_location(&self.root_dir, source_location, &FsSourceReader)
+                .map(|source| source.to_text_source())
+        })
+    }
+
+    fn error_diagnostics(&self, error: &Error) -> Vec<JsDiagnostic> {
+        match error {
+            Error::DiagnosticsError { errors } => errors
+                .iter()
+                .map(|diagnostic| self.convert(diagnostic, None))
+                .collect(),
+            Error::BuildProjectsErrors { errors } => errors
+                .iter()
+                .flat_map(|error| match error {
+                    BuildProjectError::ValidationErrors {
+                        errors,
+                        project_name,
+                    } => errors
+                        .iter()
+                        .map(|diagnostic| self.convert(diagnostic, Some(*project_name)))
+                        .collect(),
+                    BuildProjectError::PersistErrors {
+                        errors,
+                        project_name,
+                    } => errors
+                        .iter()
+                        .map(|error| error_diagnostic(error.to_string(), Some(*project_name)))
+                        .collect(),
+                    error => vec![error_diagnostic(error.to_string(), None)],
+                })
+                .collect(),
+            Error::Cancelled => Vec::new(),
+            error => vec![error_diagnostic(error.to_string(), None)],
+        }
+    }
+}
+
+impl<F: Fn(DiagnosticsEvent) + Send + Sync> StatusReporter for DiagnosticsStatusReporter<F> {
+    fn build_starts(&self) {
+        (self.on_event)(DiagnosticsEvent {
+            status: "started".to_string(),
+            diagnostics: Vec::new(),
+        });
+    }
+
+    fn build_completes(&self, diagnostics: &[Diagnostic]) {
+        (self.on_event)(DiagnosticsEvent {
+            status: "completed".to_string(),
+            diagnostics: diagnostics
+                .iter()
+                .map(|diagnostic| self.convert(diagnostic, None))
+                .collect(),
+        });
+    }
+
+    fn build_errors(&self, error: &Error) {
+        let status = if matches!(error, Error::Cancelled) {
+            "cancelled"
+        } else {
+            "failed"
+        };
+        (self.on_event)(DiagnosticsEvent {
+            status: status.to_string(),
+            diagnostics: self.error_diagnostics(error),
+        });
+    }
+}
@@ -0,0 +1,290 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Node.js bindings of the compiler, for build tools embedding it in-process
+//! instead of running `relay-compiler` and parsing its output.
+
+mod compile_document;
+mod diagnostics;
+
+use std::env::current_dir;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use common::ConsoleLogger;
+use intern::string_key::Intern;
+use intern::Lookup;
+use napi::bindgen_prelude::AsyncTask;
+use napi::threadsafe_function::ErrorStrategy;
+use napi::threadsafe_function::ThreadsafeFunction;
+use napi::threadsafe_function::ThreadsafeFunctionCallMode;
+use napi::Env;
+use napi::JsFunction;
+use napi::Task;
+use napi_derive::napi;
+use once_cell::sync::Lazy;
+use relay_compiler::compiler::Compiler;
+use relay_compiler::config::Config;
+use relay_compiler::ArtifactValidationWriter;
+use relay_compiler::FileSourceKind;
+use relay_compiler::LocalPersister;
+use relay_compiler::OperationPersister;
+use relay_compiler::PersistConfig;
+use relay_compiler::RemotePersister;
+use relay_compiler::SchemaLocation;
+use tokio::runtime::Runtime;
+use tokio::sync::Notify;
+
+use crate::compile_document::CompileDocumentOptions;
+use crate::compile_document::CompiledDocument;
+use crate::diagnostics::DiagnosticsEvent;
+use crate::diagnostics::DiagnosticsStatusReporter;
+use crate::diagnostics::JsDiagnostic;
+
+/// Runs the builds of the compiler, outside of the event loop of Node.js.
+static RUNTIME: Lazy<Runtime> =
+    Lazy::new(|| Runtime::new().expect("Unable to start the runtime of the compiler."));
+
+#[napi(object)]
+pub struct ConfigOptions {
+    /// The path of the config file. By default, the config is searched from
+    /// the current directory, like `relay-compiler` does.
+    pub config_path: Option<String>,
+    /// Names of the projects to build. By default, all the projects are
+    /// built.
+    pub projects: Option<Vec<String>>,
+}
+
+#[napi(object)]
+pub struct ProjectSummary {
+    pub name: String,
+    /// The schema file or directory, relative to the root directory.
+    pub schema: String,
+    /// The directory of the artifacts, if they are not next to the sources.
+    pub output: Option<String>,
+    pub enabled: bool,
+}
+
+#[napi(object)]
+pub struct ConfigSummary {
+    pub root_dir: String,
+    pub projects: Vec<ProjectSummary>,
+}
+
+#[napi(object)]
+pub struct BuildOptions {
+    pub config_path: Option<String>,
+    pub projects: Option<Vec<String>>,
+    /// Checks that the artifacts are up to date instead of writing them,
+    /// like `relay-compiler --validate`.
+    pub validate: Option<bool>,
+}
+
+#[napi(object)]
+pub struct BuildResult {
+    pub success: bool,
+    pub diagnostics: Vec<JsDiagnostic>,
+}
+
+fn get_config(config_path: Option<String>, projects: Option<Vec<String>>) -> napi::Result<Config> {
+    let mut config = match config_path {
+        Some(config_path) => Config::load(PathBuf::from(config_path)),
+        None => Config::search(&current_dir().map_err(|err| {
+            napi::Error::from_reason(format!("Unable to get current working directory: {}", err))
+        })?),
+    }
+    .map_err(|err| napi::Error::from_reason(err.to_string()))?;
+
+    if let Some(projects) = projects {
+        for project_config in config.projects.values_mut() {
+            project_config.enabled = false;
+        }
+        for project in projects {
+            match config.projects.get_mut(&project.as_str().intern()) {
+                Some(project_config) => project_config.enabled = true,
+                None => {
+                    return Err(napi::Error::from_reason(format!(
+                        "Project `{}` not found in the config.",
+                        project
+                    )));
+                }
+            }
+        }
+    }
+
+    config.create_operation_persister = Some(Box::new(|project_config| {
+        project_config.persist.as_ref().map(
+            |persist_config| -> Box<dyn OperationPersister + Send + Sync> {
+                match persist_config {
+                    PersistConfig::Remote(remote_config) => {
+                        Box::new(RemotePersister::new(remote_config.clone()))
+                    }
+                    PersistConfig::Local(local_config) => {
+                        Box::new(LocalPersister::new(local_config.clone()))
+                    }
+                }
+            },
+        )
+    }));
+    Ok(config)
+}
+
+/// Loads the config, and summarizes its projects.
+#[napi]
+pub fn load_config(options: Option<ConfigOptions>) -> napi::Result<ConfigSummary> {
+    let (config_path, projects) = options.map_or((None, None), |options| {
+        (options.config_path, options.projects)
+    });
+    let config = get_config(config_path, projects)?;
+    let relative_path = |path: &PathBuf| {
+        path.strip_prefix(&config.root_dir)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    };
+    Ok(ConfigSummary {
+        root_dir: config.root_dir.display().to_string(),
+        projects: config
+            .projects
+            .values()
+            .map(|project_config| ProjectSummary {
+                name: project_config.name.lookup().to_string(),
+                schema: match &project_config.schema_location {
+                    SchemaLocation::File(path) | SchemaLocation::Directory(path) => {
+                        relative_path(path)
+                    }
+                },
+                output: project_config.output.as_ref().map(relative_path),
+                enabled: project_config.enabled,
+            })
+            .collect(),
+    })
+}
+
+pub struct BuildTask {
+    config: Option<Config>,
+}
+
+impl Task for BuildTask {
+    type Output = BuildResult;
+    type JsValue = BuildResult;
+
+    fn compute(&mut self) -> napi::Result<BuildResult> {
+        let mut config = self
+            .config
+            .take()
+            .ok_or_else(|| napi::Error::from_reason("The build already ran.".to_string()))?;
+        let last_event: Arc<Mutex<Option<DiagnosticsEvent>>> = Default::default();
+        let reporter_event = Arc::clone(&last_event);
+        config.status_reporter = Box::new(DiagnosticsStatusReporter::new(
+            config.root_dir.clone(),
+            move |event| *reporter_event.lock().unwrap() = Some(event),
+        ));
+
+        let compiler = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger));
+        let success = RUNTIME.block_on(compiler.compile()).is_ok();
+        let diagnostics = last_event
+            .lock()
+            .unwrap()
+            .take()
+            .map_or_else(Vec::new, |event| event.diagnostics);
+        Ok(BuildResult {
+            success,
+            diagnostics,
+        })
+    }
+
+    fn resolve(&mut self, _env: Env, output: BuildResult) -> napi::Result<BuildResult> {
+        Ok(output)
+    }
+}
+
+/// Builds the enabled projects once, like `relay-compiler`. The promise
+/// resolves with the diagnostics of the build, and rejects if the config
+/// can't be loaded.
+#[napi(ts_return_type = "Promise<BuildResult>")]
+pub fn build(options: Option<BuildOptions>) -> napi::Result<AsyncTask<BuildTask>> {
+    let (config_path, projects, validate) = options.map_or((None, None, false), |options| {
+        (
+            options.config_path,
+            options.projects,
+            options.validate.unwrap_or(false),
+        )
+    });
+    let mut config = get_config(config_path, projects)?;
+    if validate {
+        config.artifact_writer = Box::new(ArtifactValidationWriter::default());
+    }
+    Ok(AsyncTask::new(BuildTask {
+        config: Some(config),
+    }))
+}
+
+/// Compiles a single document against a schema, without a config or any
+/// file. Returns the artifacts of its definitions, or its diagnostics.
+#[napi]
+pub fn compile_document(
+    schema: String,
+    document: String,
+    options: Option<CompileDocumentOptions>,
+) -> napi::Result<CompiledDocument> {
+    compile_document::compile_document(&schema, &document, options)
+        .map_err(napi::Error::from_reason)
+}
+
+/// Stops the builds of `subscribeDiagnostics`.
+#[napi]
+pub struct DiagnosticsSubscription {
+    stop: Arc<Notify>,
+}
+
+#[napi]
+impl DiagnosticsSubscription {
+    #[napi]
+    pub fn close(&self) {
+        self.stop.notify_one();
+    }
+}
+
+/// Builds the enabled projects whenever their files change, like
+/// `relay-compiler --watch`, and calls `callback` with the diagnostics of
+/// each build. Requires Watchman.
+#[napi(
+    ts_args_type = "options: ConfigOptions | undefined | null, callback: (event: DiagnosticsEvent) => void"
+)]
+pub fn subscribe_diagnostics(
+    options: Option<ConfigOptions>,
+    callback: JsFunction,
+) -> napi::Result<DiagnosticsSubscription> {
+    let (config_path, projects) = options.map_or((None, None), |options| {
+        (options.config_path, options.projects)
+    });
+    let mut config = get_config(config_path, projects)?;
+    let callback: ThreadsafeFunction<DiagnosticsEvent, ErrorStrategy::Fatal> =
+        callback.create_threadsafe_function(0, |context| Ok(vec![context.value]))?;
+    config.file_source_config = FileSourceKind::Watchman;
+    config.status_reporter = Box::new(DiagnosticsStatusReporter::new(
+        config.root_dir.clone(),
+        move |event| {
+            callback.call(event, ThreadsafeFunctionCallMode::NonBlocking);
+        },
+    ));
+
+    let stop = Arc::new(Notify::new());
+    let stopped = Arc::clone(&stop);
+    std::thread::spawn(move || {
+        let compiler = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger));
+        RUNTIME.block_on(async {
+            tokio::select! {
+                _ = compiler.watch() => {},
+                _ = stopped.notified() => {},
+            }
+        });
+    });
+    Ok(DiagnosticsSubscription { stop })
+}
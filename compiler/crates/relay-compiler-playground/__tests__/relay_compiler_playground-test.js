@@ -63,6 +63,15 @@ query MyQuery {
 }
 `;
 
+// Valid with regards to schema, but fails the validations of the compiler
+const UNUSED_VARIABLE_DOCUMENT = `
+query MyQuery($unused: ID) {
+    me {
+        name
+    }
+}
+`;
+
 describe('Ok', () => {
   test('parse_to_ast', () => {
     const actual = JSON.parse(playground.parse_to_ast(DOCUMENT));
@@ -93,6 +102,11 @@ describe('Ok', () => {
     expect(actual.Ok).toMatchSnapshot();
   });
 
+  test('validate', () => {
+    const actual = JSON.parse(playground.validate('{}', SCHEMA, DOCUMENT));
+    expect(actual.Ok).toBe('');
+  });
+
   test('types (flow)', () => {
     const actual = JSON.parse(
       playground.parse_to_types('{}', '{"language": "flow"}', SCHEMA, DOCUMENT),
@@ -284,4 +298,21 @@ describe('Err', () => {
       ],
     });
   });
+
+  test('validate', () => {
+    const actual = JSON.parse(
+      playground.validate('{}', SCHEMA, UNUSED_VARIABLE_DOCUMENT),
+    );
+    expect(actual.Err).toEqual({
+      DocumentDiagnostics: [
+        expect.objectContaining({
+          line_end: 1,
+          line_start: 1,
+          message: expect.stringMatching(
+            /^Variable `\$unused` is never used in operation `MyQuery`/,
+          ),
+        }),
+      ],
+    });
+  });
 });
@@ -262,6 +262,46 @@ pub fn parse_to_types_impl(
     Ok(types_string)
 }
 
+#[wasm_bindgen]
+pub fn validate(feature_flags_json: &str, schema_text: &str, document_text: &str) -> String {
+    serialize_result(validate_impl(
+        feature_flags_json,
+        schema_text,
+        document_text,
+    ))
+}
+
+/// Runs the validation rules of the compiler, and returns the printed
+/// diagnostics that don't fail the build.
+pub fn validate_impl(
+    feature_flags_json: &str,
+    schema_text: &str,
+    document_text: &str,
+) -> PlaygroundResult {
+    let schema = Arc::new(
+        build_schema_with_extensions(
+            &[(schema_text, SourceLocationKey::Generated)],
+            &Vec::<(&str, SourceLocationKey)>::new(),
+        )
+        .map_err(|diagnostics| map_diagnostics(diagnostics, &InputType::Schema(schema_text)))?,
+    );
+    let project_config = get_project_config(feature_flags_json, None)?;
+    let document = graphql_syntax::parse_executable(document_text, Generated)
+        .map_err(|diagnostics| map_diagnostics(diagnostics, &InputType::Document(document_text)))?;
+    let ir = graphql_ir::build(&schema, &document.definitions)
+        .map_err(|diagnostics| map_diagnostics(diagnostics, &InputType::Document(document_text)))?;
+    let program = Program::from_definitions(schema, ir);
+
+    let result = relay_transforms::validate(&program, &project_config, &None, &[])
+        .map_err(|diagnostics| map_diagnostics(diagnostics, &InputType::Document(document_text)))?;
+    Ok(result
+        .diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.print_without_source())
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
 #[wasm_bindgen]
 pub fn transform(feature_flags_json: &str, schema_text: &str, document_text: &str) -> String {
     serialize_result(transform_impl(
@@ -351,6 +391,7 @@ fn get_programs(
         Arc::new(NoopPerfLogger),
         None,
         None,
+        None,
     )
     .map_err(|diagnostics: Vec<Diagnostic>| {
         map_diagnostics(diagnostics, &InputType::Document(document_text))
@@ -0,0 +1,607 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! A remote cache of the artifacts of the projects, for CI machines to
+//! download the artifacts of a clean checkout instead of compiling them.
+//!
+//! The key of a project is a hash of everything its artifacts are generated
+//! from: its GraphQL and docblock sources (and those of its base project), its
+//! schema and extensions, the version of the compiler and the config file.
+//! The first build of the compiler restores the projects found in the cache,
+//! without building them, and uploads the artifacts of the other projects once
+//! they are built. Later builds of the watch mode compile as usual.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use common::diagnostic_codes;
+use fnv::FnvHashMap;
+use fnv::FnvHashSet;
+use futures::future::join_all;
+use hyper::Body;
+use hyper::Client;
+use hyper::Method;
+use hyper::Request;
+use hyper::StatusCode;
+use hyper_tls::HttpsConnector;
+use indexmap::IndexMap;
+use intern::string_key::StringKey;
+use intern::Lookup;
+use log::debug;
+use log::info;
+use log::warn;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+use thiserror::Error;
+
+use crate::artifact_map::ArtifactMap;
+use crate::artifact_map::ArtifactRecord;
+use crate::compiler_state::ArtifactMapKind;
+use crate::compiler_state::CompilerState;
+use crate::compiler_state::ProjectName;
+use crate::config::Config;
+use crate::errors::BuildProjectError;
+use crate::errors::Error;
+use crate::errors::Result;
+
+/// The `artifactCache` section of the config file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct ArtifactCacheConfig {
+    /// The artifacts of a project are read with `GET <url>/<key>`, and
+    /// written with `PUT <url>/<key>`.
+    pub url: String,
+
+    /// Headers of the requests, e.g. for authentication.
+    #[serde(default)]
+    pub headers: IndexMap<String, String>,
+
+    /// Only reads the cache, e.g. on the machines of developers.
+    #[serde(default)]
+    pub read_only: bool,
+}
+
+diagnostic_codes! {
+    #[derive(Debug, Error)]
+    pub enum ArtifactCacheError {
+        #[error("Unable to reach the artifact cache: {error}")]
+        #[code("RELAY3050")]
+        NetworkError { error: String },
+
+        #[error("The artifact cache answered with status {status}")]
+        #[code("RELAY3051")]
+        UnexpectedStatus { status: u16 },
+    }
+}
+
+/// The storage of the cached artifacts, e.g. an HTTP server or an
+/// S3-compatible bucket.
+#[async_trait]
+pub trait ArtifactCache: Send + Sync {
+    /// Returns the entry of `key`, or `None` if it's not in the cache.
+    async fn get(&self, key: &str) -> std::result::Result<Option<Vec<u8>>, ArtifactCacheError>;
+
+    async fn put(&self, key: &str, entry: Vec<u8>) -> std::result::Result<(), ArtifactCacheError>;
+}
+
+/// An `ArtifactCache` storing the entries on an HTTP server.
+pub struct HttpArtifactCache {
+    config: ArtifactCacheConfig,
+}
+
+impl HttpArtifactCache {
+    pub fn new(config: ArtifactCacheConfig) -> Self {
+        Self { config }
+    }
+
+    async fn request(
+        &self,
+        method: Method,
+        key: &str,
+        body: Body,
+    ) -> std::result::Result<(StatusCode, Vec<u8>), ArtifactCacheError> {
+        let mut builder = Request::builder().method(method).uri(format!(
+            "{}/{}",
+            self.config.url.trim_end_matches('/'),
+            key
+        ));
+        for (name, value) in &self.config.headers {
+            builder = builder.header(name, value);
+        }
+        let request = builder
+            .body(body)
+            .map_err(|err| ArtifactCacheError::NetworkError {
+                error: err.to_string(),
+            })?;
+        let client = Client::builder().build(HttpsConnector::new());
+        let response =
+            client
+                .request(request)
+                .await
+                .map_err(|err| ArtifactCacheError::NetworkError {
+                    error: err.to_string(),
+                })?;
+        let status = response.status();
+        let bytes = hyper::body::to_bytes(response.into_body())
+            .await
+            .map_err(|err| ArtifactCacheError::NetworkError {
+                error: err.to_string(),
+            })?;
+        Ok((status, bytes.to_vec()))
+    }
+}
+
+#[async_trait]
+impl ArtifactCache for HttpArtifactCache {
+    async fn get(&self, key: &str) -> std::result::Result<Option<Vec<u8>>, ArtifactCacheError> {
+        match self.request(Method::GET, key, Body::empty()).await? {
+            (StatusCode::OK, bytes) => Ok(Some(bytes)),
+            (StatusCode::NOT_FOUND, _) => Ok(None),
+            (status, _) => Err(ArtifactCacheError::UnexpectedStatus {
+                status: status.as_u16(),
+            }),
+        }
+    }
+
+    async fn put(&self, key: &str, entry: Vec<u8>) -> std::result::Result<(), ArtifactCacheError> {
+        if self.config.read_only {
+            return Ok(());
+        }
+        match self.request(Method::PUT, key, Body::from(entry)).await? {
+            (status, _) if status.is_success() => Ok(()),
+            (status, _) => Err(ArtifactCacheError::UnexpectedStatus {
+                status: status.as_u16(),
+            }),
+        }
+    }
+}
+
+/// An artifact in the entry of a project.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedArtifact {
+    path: PathBuf,
+    source_definition_names: Vec<StringKey>,
+    persisted_operation_id: Option<String>,
+    #[serde(default)]
+    source_file: Option<PathBuf>,
+    content: String,
+}
+
+/// The key of the artifacts of `project_name`.
+pub fn project_cache_key(
+    config: &Config,
+    compiler_state: &CompilerState,
+    project_name: ProjectName,
+) -> String {
+    let mut hash = Sha256::new();
+    let mut update = |value: &[u8]| {
+        hash.update(value.len().to_le_bytes());
+        hash.update(value);
+    };
+    update(config.compiler_version.as_bytes());
+    update(config.saved_state_version.as_bytes());
+    // The builds of only the types or the artifacts generate other artifacts.
+    if let Some(only) = config.only {
+        update(format!("{:?}", only).as_bytes());
+    }
+
+    let mut project = Some(project_name);
+    while let Some(project_name) = project {
+        update(project_name.lookup().as_bytes());
+        for sources in [
+            compiler_state.schemas.get(&project_name),
+            compiler_state.extensions.get(&project_name),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            for (path, text) in sources.get_all() {
+                update(path.to_string_lossy().as_bytes());
+                update(text.as_bytes());
+            }
+        }
+        if let Some(sources) = compiler_state.graphql_sources.get(&project_name) {
+            for (path, sources) in sources.get_all() {
+                update(path.to_string_lossy().as_bytes());
+                for source in sources {
+                    update(source.graphql_source.text_source().text.as_bytes());
+                }
+            }
+        }
+        if let Some(sources) = compiler_state.docblocks.get(&project_name) {
+            for (path, sources) in sources.get_all() {
+                update(path.to_string_lossy().as_bytes());
+                for source in sources {
+                    update(source.docblock_source.text_source().text.as_bytes());
+                }
+            }
+        }
+        project = config
+            .projects
+            .get(&project_name)
+            .and_then(|project_config| project_config.base);
+    }
+    hex::encode(hash.finalize())
+}
+
+/// The projects of the first build, with the keys of the ones that were not
+/// restored from the cache.
+#[derive(Default)]
+pub struct CachedProjects {
+    restored: FnvHashSet<ProjectName>,
+    missed: FnvHashMap<ProjectName, String>,
+}
+
+impl CachedProjects {
+    pub fn is_restored(&self, project_name: ProjectName) -> bool {
+        self.restored.contains(&project_name)
+    }
+}
+
+/// Writes the artifacts of the cached projects in `project_names`, and
+/// records their artifact maps in `compiler_state`. Failures of the cache are
+/// logged, and the projects are built instead.
+pub async fn restore_cached_projects(
+    config: &Config,
+    artifact_cache: &dyn ArtifactCache,
+    compiler_state: &mut CompilerState,
+    project_names: Vec<ProjectName>,
+) -> Result<CachedProjects> {
+    let keys: Vec<_> = project_names
+        .into_iter()
+        .map(|project_name| {
+            (
+                project_name,
+                project_cache_key(config, compiler_state, project_name),
+            )
+        })
+        .collect();
+    let entries = join_all(keys.iter().map(|(_, key)| artifact_cache.get(key))).await;
+
+    let mut cached_projects = CachedProjects::default();
+    for ((project_name, key), entry) in keys.into_iter().zip(entries) {
+        let artifacts = match entry {
+            Ok(Some(entry)) => match decode_entry(&entry) {
+                Ok(artifacts) => artifacts,
+                Err(err) => {
+                    warn!(
+                        "[{}] ignoring invalid cached artifacts: {}",
+                        project_name, err
+                    );
+                    cached_projects.missed.insert(project_name, key);
+                    continue;
+                }
+            },
+            Ok(None) => {
+                debug!("[{}] no cached artifacts for {}", project_name, key);
+                cached_projects.missed.insert(project_name, key);
+                continue;
+            }
+            Err(err) => {
+                warn!("[{}] {}", project_name, err);
+                cached_projects.missed.insert(project_name, key);
+                continue;
+            }
+        };
+        info!(
+            "[{}] restoring {} artifacts from the cache",
+            project_name,
+            artifacts.len()
+        );
+        let artifact_map = write_cached_artifacts(config, compiler_state, project_name, artifacts)
+            .map_err(|error| Error::BuildProjectsErrors {
+                errors: vec![error],
+            })?;
+        compiler_state
+            .artifacts
+            .insert(project_name, ArtifactMapKind::Mapping(artifact_map).into());
+        cached_projects.restored.insert(project_name);
+    }
+    Ok(cached_projects)
+}
+
+fn write_cached_artifacts(
+    config: &Config,
+    compiler_state: &CompilerState,
+    project_name: ProjectName,
+    artifacts: Vec<CachedArtifact>,
+) -> std::result::Result<ArtifactMap, BuildProjectError> {
+    let artifact_map = ArtifactMap::default();
+    let mut paths = FnvHashSet::default();
+    for artifact in artifacts {
+        let path = config.root_dir.join(&artifact.path);
+        let content = artifact.content.into_bytes();
+        if config.artifact_writer.should_write(&path, &content)? {
+            config.artifact_writer.write(path, content)?;
+            config.hooks.record_written(&artifact.path);
+            config.events.record_written(project_name, &artifact.path);
+        }
+        for source_definition_name in artifact.source_definition_names {
+            artifact_map
+                .0
+                .entry(source_definition_name)
+                .or_default()
+                .push(ArtifactRecord {
+                    path: artifact.path.clone(),
+                    persisted_operation_id: artifact.persisted_operation_id.clone(),
+                    source_file: artifact.source_file.clone(),
+                });
+        }
+        paths.insert(artifact.path);
+    }
+    // Like the first build of a project, removes the generated files that
+    // are not artifacts anymore.
+    if let Some(artifact_map_kind) = compiler_state.artifacts.get(&project_name) {
+        if let ArtifactMapKind::Unconnected(existing_artifacts) = artifact_map_kind.as_ref() {
+            for existing_artifact in existing_artifacts {
+                if !paths.contains(existing_artifact) {
+                    config
+                        .artifact_writer
+                        .remove(config.root_dir.join(existing_artifact))?;
+                    config.hooks.record_removed(existing_artifact);
+                    config
+                        .events
+                        .record_removed(project_name, existing_artifact);
+                }
+            }
+        }
+    }
+    Ok(artifact_map)
+}
+
+/// Uploads the artifacts of the built projects that were missing from the
+/// cache. Failures are logged without failing the build.
+pub async fn upload_built_projects(
+    config: &Config,
+    artifact_cache: &dyn ArtifactCache,
+    compiler_state: &CompilerState,
+    cached_projects: CachedProjects,
+) {
+    let uploads = cached_projects
+        .missed
+        .into_iter()
+        .filter_map(|(project_name, key)| {
+            let artifact_map = match compiler_state.artifacts.get(&project_name)?.as_ref() {
+                ArtifactMapKind::Mapping(artifact_map) => artifact_map,
+                ArtifactMapKind::Unconnected(_) => return None,
+            };
+            match encode_entry(config, artifact_map) {
+                Ok(entry) => Some(async move {
+                    match artifact_cache.put(&key, entry).await {
+                        Ok(()) => debug!("[{}] uploaded artifacts to {}", project_name, key),
+                        Err(err) => warn!("[{}] {}", project_name, err),
+                    }
+                }),
+                Err(err) => {
+                    warn!(
+                        "[{}] unable to read artifacts to cache: {}",
+                        project_name, err
+                    );
+                    None
+                }
+            }
+        });
+    join_all(uploads).await;
+}
+
+/// The source definitions, persisted operation id and source file of an
+/// artifact.
+type ArtifactSources = (Vec<StringKey>, Option<String>, Option<PathBuf>);
+
+fn encode_entry(config: &Config, artifact_map: &ArtifactMap) -> std::io::Result<Vec<u8>> {
+    let mut artifacts: BTreeMap<PathBuf, ArtifactSources> = BTreeMap::new();
+    for entry in artifact_map.0.iter() {
+        for record in entry.value() {
+            let artifact = artifacts.entry(record.path.clone()).or_default();
+            artifact.0.push(*entry.key());
+            artifact.1 = record.persisted_operation_id.clone();
+            artifact.2 = record.source_file.clone();
+        }
+    }
+    let artifacts = artifacts
+        .into_iter()
+        .map(
+            |(path, (mut source_definition_names, persisted_operation_id, source_file))| {
+                source_definition_names.sort();
+                let content = fs::read_to_string(config.root_dir.join(&path))?;
+                Ok(CachedArtifact {
+                    path,
+                    source_definition_names,
+                    persisted_operation_id,
+                    source_file,
+                    content,
+                })
+            },
+        )
+        .collect::<std::io::Result<Vec<_>>>()?;
+    zstd::encode_all(serde_json::to_vec(&artifacts)?.as_slice(), 0)
+}
+
+fn decode_entry(entry: &[u8]) -> std::io::Result<Vec<CachedArtifact>> {
+    let json = zstd::decode_all(entry)?;
+    Ok(serde_json::from_slice(&json)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use intern::string_key::Intern;
+
+    use super::*;
+    use crate::NoopArtifactWriter;
+    use crate::compiler_state::SchemaSources;
+    use crate::config::BuildOnly;
+
+    #[derive(Default)]
+    struct MemoryArtifactCache {
+        entries: Mutex<FnvHashMap<String, Vec<u8>>>,
+    }
+
+    #[async_trait]
+    impl ArtifactCache for MemoryArtifactCache {
+        async fn get(&self, key: &str) -> std::result::Result<Option<Vec<u8>>, ArtifactCacheError> {
+            Ok(self.entries.lock().unwrap().get(key).cloned())
+        }
+
+        async fn put(
+            &self,
+            key: &str,
+            entry: Vec<u8>,
+        ) -> std::result::Result<(), ArtifactCacheError> {
+            self.entries.lock().unwrap().insert(key.to_string(), entry);
+            Ok(())
+        }
+    }
+
+    fn create_config(root_dir: PathBuf) -> Config {
+        let mut config = Config::from_string_for_test(
+            r#"
+            {
+                "sources": {"src": "project"},
+                "projects": {"project": {"schema": "schema.graphql", "language": "flow"}}
+            }
+            "#,
+        )
+        .unwrap();
+        config.root_dir = root_dir;
+        config.artifact_writer = Box::new(NoopArtifactWriter);
+        config
+    }
+
+    fn create_compiler_state(schema: &str) -> CompilerState {
+        let mut schemas = SchemaSources::default();
+        schemas
+            .pending
+            .insert(PathBuf::from("schema.graphql"), schema.to_string());
+        let mut compiler_state = CompilerState::default();
+        compiler_state.schemas.insert("project".intern(), schemas);
+        compiler_state
+    }
+
+    #[test]
+    fn test_project_cache_key() {
+        let config = create_config(PathBuf::from("/root"));
+        let project_name = "project".intern();
+        let key = project_cache_key(
+            &config,
+            &create_compiler_state("type Query { a: Int }"),
+            project_name,
+        );
+        assert_eq!(
+            key,
+            project_cache_key(
+                &config,
+                &create_compiler_state("type Query { a: Int }"),
+                project_name
+            )
+        );
+        assert_ne!(
+            key,
+            project_cache_key(
+                &config,
+                &create_compiler_state("type Query { b: Int }"),
+                project_name
+            )
+        );
+
+        let mut config = config;
+        config.only = Some(BuildOnly::Typegen);
+        assert_ne!(
+            key,
+            project_cache_key(
+                &config,
+                &create_compiler_state("type Query { a: Int }"),
+                project_name
+            )
+        );
+
+        config.only = None;
+        config.compiler_version = "0.0.1".to_string();
+        assert_ne!(
+            key,
+            project_cache_key(
+                &config,
+                &create_compiler_state("type Query { a: Int }"),
+                project_name
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_restores_uploaded_artifacts() {
+        let root_dir = env::temp_dir().join("relay_artifact_cache_test");
+        fs::create_dir_all(root_dir.join("src/__generated__")).unwrap();
+        fs::write(
+            root_dir.join("src/__generated__/Foo.graphql.js"),
+            "module.exports = {};\n",
+        )
+        .unwrap();
+        let config = create_config(root_dir);
+        let project_name = "project".intern();
+        let artifact_cache = Arc::new(MemoryArtifactCache::default());
+
+        let mut compiler_state = create_compiler_state("type Query { a: Int }");
+        let cached_projects = restore_cached_projects(
+            &config,
+            artifact_cache.as_ref(),
+            &mut compiler_state,
+            vec![project_name],
+        )
+        .await
+        .unwrap();
+        assert!(!cached_projects.is_restored(project_name));
+
+        // The build of the project records its artifact map
+        let artifact_map = ArtifactMap::default();
+        artifact_map.0.insert(
+            "Foo".intern(),
+            vec![ArtifactRecord {
+                path: PathBuf::from("src/__generated__/Foo.graphql.js"),
+                persisted_operation_id: None,
+                source_file: Some(PathBuf::from("src/Foo.js")),
+            }],
+        );
+        compiler_state.artifacts.insert(
+            project_name,
+            Arc::new(ArtifactMapKind::Mapping(artifact_map)),
+        );
+        upload_built_projects(
+            &config,
+            artifact_cache.as_ref(),
+            &compiler_state,
+            cached_projects,
+        )
+        .await;
+
+        let mut compiler_state = create_compiler_state("type Query { a: Int }");
+        let cached_projects = restore_cached_projects(
+            &config,
+            artifact_cache.as_ref(),
+            &mut compiler_state,
+            vec![project_name],
+        )
+        .await
+        .unwrap();
+        assert!(cached_projects.is_restored(project_name));
+        match compiler_state.artifacts[&project_name].as_ref() {
+            ArtifactMapKind::Mapping(artifact_map) => {
+                assert_eq!(
+                    artifact_map.0.get(&"Foo".intern()).unwrap()[0].path,
+                    PathBuf::from("src/__generated__/Foo.graphql.js")
+                );
+            }
+            ArtifactMapKind::Unconnected(_) => panic!("Expected an artifact map."),
+        }
+    }
+}
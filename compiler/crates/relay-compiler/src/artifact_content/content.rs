@@ -11,9 +11,13 @@ use std::fmt::Write;
 use std::sync::Arc;
 
 use common::NamedItem;
+use common::SourceLocationKey;
 use graphql_ir::FragmentDefinition;
 use graphql_ir::FragmentDefinitionName;
 use graphql_ir::OperationDefinition;
+use graphql_ir::OperationDefinitionName;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
 use intern::Lookup;
 use relay_codegen::build_request_params;
 use relay_codegen::Printer;
@@ -25,15 +29,19 @@ use relay_transforms::ReactFlightLocalComponentsMetadata;
 use relay_transforms::RelayClientComponentMetadata;
 use relay_transforms::RelayDataDrivenDependencyMetadata;
 use relay_transforms::ASSIGNABLE_DIRECTIVE;
+use relay_typegen::generate_flow_enum as generate_flow_enum_declaration;
 use relay_typegen::generate_fragment_type_exports_section;
+use relay_typegen::generate_mock_factory as generate_mock_factory_function;
 use relay_typegen::generate_named_validator_export;
+use relay_typegen::generate_operation_registry as generate_operation_registry_type;
 use relay_typegen::generate_operation_type_exports_section;
 use relay_typegen::generate_split_operation_type_exports_section;
+use relay_typegen::generate_typename_guards;
 use relay_typegen::FragmentLocations;
 use relay_typegen::TypegenConfig;
 use relay_typegen::TypegenLanguage;
+use schema::EnumID;
 use schema::SDLSchema;
-use signedsource::SIGNING_TOKEN;
 
 use super::super::ArtifactGeneratedTypes;
 use super::content_section::CommentAnnotationsSection;
@@ -55,6 +63,7 @@ pub fn generate_updatable_query(
     source_hash: String,
     skip_types: bool,
     fragment_locations: &FragmentLocations,
+    schema_hash: Option<&str>,
 ) -> Result<Vec<u8>, FmtError> {
     let operation_fragment = FragmentDefinition {
         name: reader_operation.name.map(|x| FragmentDefinitionName(x.0)),
@@ -72,6 +81,7 @@ pub fn generate_updatable_query(
         config,
         project_config,
         vec![],
+        schema_hash,
     )?));
     // -- End Docblock Section --
 
@@ -89,8 +99,11 @@ pub fn generate_updatable_query(
 
     // -- Begin Types Section --
     let mut section = GenericSection::default();
-    let generated_types =
-        ArtifactGeneratedTypes::from_updatable_query(typegen_operation, skip_types);
+    let generated_types = ArtifactGeneratedTypes::from_updatable_query(
+        typegen_operation,
+        skip_types,
+        &project_config.typegen_config.type_names,
+    );
 
     if project_config.typegen_config.language == TypegenLanguage::Flow {
         writeln!(section, "/*::")?;
@@ -159,7 +172,7 @@ pub fn generate_updatable_query(
     content_sections.push(ContentSection::Generic(section));
     // -- End Export Query Node Section --
 
-    content_sections.into_signed_bytes()
+    content_sections.into_signed_bytes(config.artifact_signer.as_ref())
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -176,15 +189,39 @@ pub fn generate_operation(
     id_and_text_hash: &Option<QueryID>,
     skip_types: bool,
     fragment_locations: &FragmentLocations,
+    schema_hash: Option<&str>,
 ) -> Result<Vec<u8>, FmtError> {
-    let mut request_parameters = build_request_params(normalization_operation);
+    let operation_name = normalization_operation.name.item.0;
+    // The persisted id is used as the name: unlike a hash of the name, it
+    // can't be reversed by hashing a list of likely names
+    let obfuscated_name = match id_and_text_hash {
+        Some(QueryID::Persisted { id, .. }) if project_config.obfuscate_operation_names => {
+            Some(id.intern())
+        }
+        Some(QueryID::External(id)) if project_config.obfuscate_operation_names => Some(*id),
+        _ => None,
+    };
+    // The name is replaced in the request parameters, and in the reader and
+    // normalization ASTs
+    let obfuscated_operation = obfuscated_name.map(|obfuscated_name| OperationDefinition {
+        name: normalization_operation
+            .name
+            .map(|_| OperationDefinitionName(obfuscated_name)),
+        ..normalization_operation.clone()
+    });
+    let request_operation = obfuscated_operation
+        .as_ref()
+        .unwrap_or(normalization_operation);
+    let mut request_parameters = build_request_params(request_operation);
     if id_and_text_hash.is_some() {
         request_parameters.id = id_and_text_hash;
     } else {
         request_parameters.text = text.clone();
     };
     let operation_fragment = FragmentDefinition {
-        name: reader_operation.name.map(|x| FragmentDefinitionName(x.0)),
+        name: reader_operation
+            .name
+            .map(|x| FragmentDefinitionName(obfuscated_name.unwrap_or(x.0))),
         variable_definitions: reader_operation.variable_definitions.clone(),
         selections: reader_operation.selections.clone(),
         used_global_variables: Default::default(),
@@ -203,6 +240,7 @@ pub fn generate_operation(
         config,
         project_config,
         v,
+        schema_hash,
     )?));
     // -- End Docblock Section --
 
@@ -254,6 +292,7 @@ pub fn generate_operation(
         typegen_operation,
         skip_types,
         request_parameters.is_client_request(),
+        &project_config.typegen_config.type_names,
     );
 
     if project_config.typegen_config.language == TypegenLanguage::Flow {
@@ -310,7 +349,7 @@ pub fn generate_operation(
 
     let request = printer.print_request(
         schema,
-        normalization_operation,
+        request_operation,
         &operation_fragment,
         request_parameters,
         &mut top_level_statements,
@@ -340,6 +379,14 @@ pub fn generate_operation(
         &mut section,
         &source_hash,
     )?;
+    if obfuscated_name.is_some() {
+        write_dev_operation_name(
+            config,
+            &project_config.typegen_config.language,
+            &mut section,
+            operation_name,
+        )?;
+    }
     content_sections.push(ContentSection::Generic(section));
     // -- End Query Node Hash Section --
 
@@ -385,7 +432,22 @@ pub fn generate_operation(
     content_sections.push(ContentSection::Generic(section));
     // -- End Export Section --
 
-    content_sections.into_signed_bytes()
+    // -- Begin Typename Guards Section --
+    if project_config.typegen_config.generate_typename_guards {
+        if let Some(typename_guards) = generate_typename_guards(
+            typegen_operation.type_,
+            &typegen_operation.selections,
+            schema,
+            project_config,
+        ) {
+            let mut section = GenericSection::default();
+            write!(section, "{}", typename_guards)?;
+            content_sections.push(ContentSection::Generic(section));
+        }
+    }
+    // -- End Typename Guards Section --
+
+    content_sections.into_signed_bytes(config.artifact_signer.as_ref())
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -398,6 +460,7 @@ pub fn generate_split_operation(
     typegen_operation: &Option<Arc<OperationDefinition>>,
     source_hash: Option<&String>,
     fragment_locations: &FragmentLocations,
+    schema_hash: Option<&str>,
     no_optional_fields_in_raw_response_type: bool,
 ) -> Result<Vec<u8>, FmtError> {
     let mut content_sections = ContentSections::default();
@@ -407,6 +470,7 @@ pub fn generate_split_operation(
         config,
         project_config,
         vec![],
+        schema_hash,
     )?));
     // -- End Docblock Section --
 
@@ -497,7 +561,7 @@ pub fn generate_split_operation(
     content_sections.push(ContentSection::Generic(section));
     // -- End Export Section --
 
-    content_sections.into_signed_bytes()
+    content_sections.into_signed_bytes(config.artifact_signer.as_ref())
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -511,6 +575,7 @@ pub fn generate_fragment(
     source_hash: Option<&String>,
     skip_types: bool,
     fragment_locations: &FragmentLocations,
+    schema_hash: Option<&str>,
 ) -> Result<Vec<u8>, FmtError> {
     let is_assignable_fragment = typegen_fragment
         .directives
@@ -524,6 +589,7 @@ pub fn generate_fragment(
             typegen_fragment,
             skip_types,
             fragment_locations,
+            schema_hash,
         )
     } else {
         generate_read_only_fragment(
@@ -536,6 +602,7 @@ pub fn generate_fragment(
             source_hash,
             skip_types,
             fragment_locations,
+            schema_hash,
         )
     }
 }
@@ -551,6 +618,7 @@ fn generate_read_only_fragment(
     source_hash: Option<&String>,
     skip_types: bool,
     fragment_locations: &FragmentLocations,
+    schema_hash: Option<&str>,
 ) -> Result<Vec<u8>, FmtError> {
     let mut content_sections = ContentSections::default();
 
@@ -559,6 +627,7 @@ fn generate_read_only_fragment(
         config,
         project_config,
         vec![],
+        schema_hash,
     )?));
     // -- End Docblock Section --
 
@@ -596,7 +665,11 @@ fn generate_read_only_fragment(
 
     // -- Begin Types Section --
     let mut section = GenericSection::default();
-    let generated_types = ArtifactGeneratedTypes::from_fragment(typegen_fragment, skip_types);
+    let generated_types = ArtifactGeneratedTypes::from_fragment(
+        typegen_fragment,
+        skip_types,
+        &project_config.typegen_config.type_names,
+    );
 
     if project_config.typegen_config.language == TypegenLanguage::Flow {
         writeln!(section, "/*::")?;
@@ -673,7 +746,22 @@ fn generate_read_only_fragment(
     content_sections.push(ContentSection::Generic(section));
     // -- End Fragment Node Export Section --
 
-    content_sections.into_signed_bytes()
+    // -- Begin Typename Guards Section --
+    if project_config.typegen_config.generate_typename_guards {
+        if let Some(typename_guards) = generate_typename_guards(
+            typegen_fragment.type_condition,
+            &typegen_fragment.selections,
+            schema,
+            project_config,
+        ) {
+            let mut section = GenericSection::default();
+            write!(section, "{}", typename_guards)?;
+            content_sections.push(ContentSection::Generic(section));
+        }
+    }
+    // -- End Typename Guards Section --
+
+    content_sections.into_signed_bytes(config.artifact_signer.as_ref())
 }
 
 fn generate_assignable_fragment(
@@ -683,6 +771,7 @@ fn generate_assignable_fragment(
     typegen_fragment: &FragmentDefinition,
     skip_types: bool,
     fragment_locations: &FragmentLocations,
+    schema_hash: Option<&str>,
 ) -> Result<Vec<u8>, FmtError> {
     let mut content_sections = ContentSections::default();
 
@@ -691,6 +780,7 @@ fn generate_assignable_fragment(
         config,
         project_config,
         vec![],
+        schema_hash,
     )?));
     // -- End Docblock Section --
 
@@ -747,7 +837,151 @@ fn generate_assignable_fragment(
     content_sections.push(ContentSection::Generic(section));
     // -- End Export Section --
 
-    content_sections.into_signed_bytes()
+    content_sections.into_signed_bytes(config.artifact_signer.as_ref())
+}
+
+pub fn generate_mock_factory(
+    config: &Config,
+    project_config: &ProjectConfig,
+    schema: &SDLSchema,
+    typegen_operation: &OperationDefinition,
+    source_file: SourceLocationKey,
+    schema_hash: Option<&str>,
+) -> Result<Vec<u8>, FmtError> {
+    let operation_artifact_path =
+        project_config.path_for_artifact(source_file, typegen_operation.name.item.0);
+    let operation_import_path = format!(
+        "./{}",
+        operation_artifact_path
+            .file_stem()
+            .unwrap()
+            .to_string_lossy()
+    );
+
+    let mut content_sections = ContentSections::default();
+
+    // -- Begin Docblock Section --
+    content_sections.push(ContentSection::Docblock(generate_docblock_section(
+        config,
+        project_config,
+        vec![],
+        schema_hash,
+    )?));
+    // -- End Docblock Section --
+
+    // -- Begin Disable Lint Section --
+    content_sections.push(ContentSection::Generic(generate_disable_lint_section(
+        &project_config.typegen_config.language,
+    )?));
+    // -- End Disable Lint Section --
+
+    // -- Begin Use Strict Section --
+    content_sections.push(ContentSection::Generic(generate_use_strict_section(
+        &project_config.typegen_config.language,
+    )?));
+    // -- End Use Strict Section --
+
+    // -- Begin Mock Factory Section --
+    let mut section = GenericSection::default();
+    write!(
+        section,
+        "{}",
+        generate_mock_factory_function(
+            typegen_operation,
+            schema,
+            project_config,
+            &operation_import_path,
+        )
+    )?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Mock Factory Section --
+
+    content_sections.into_signed_bytes(config.artifact_signer.as_ref())
+}
+
+pub fn generate_flow_enum(
+    config: &Config,
+    project_config: &ProjectConfig,
+    schema: &SDLSchema,
+    enum_id: EnumID,
+    schema_hash: Option<&str>,
+) -> Result<Vec<u8>, FmtError> {
+    let mut content_sections = ContentSections::default();
+
+    // -- Begin Docblock Section --
+    content_sections.push(ContentSection::Docblock(generate_docblock_section(
+        config,
+        project_config,
+        vec![],
+        schema_hash,
+    )?));
+    // -- End Docblock Section --
+
+    // -- Begin Disable Lint Section --
+    content_sections.push(ContentSection::Generic(generate_disable_lint_section(
+        &project_config.typegen_config.language,
+    )?));
+    // -- End Disable Lint Section --
+
+    // -- Begin Use Strict Section --
+    content_sections.push(ContentSection::Generic(generate_use_strict_section(
+        &project_config.typegen_config.language,
+    )?));
+    // -- End Use Strict Section --
+
+    // -- Begin Flow Enum Section --
+    let mut section = GenericSection::default();
+    write!(
+        section,
+        "{}",
+        generate_flow_enum_declaration(enum_id, schema, project_config)
+    )?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Flow Enum Section --
+
+    content_sections.into_signed_bytes(config.artifact_signer.as_ref())
+}
+
+pub fn generate_operation_registry(
+    config: &Config,
+    project_config: &ProjectConfig,
+    operations: &[(StringKey, String)],
+    schema_hash: Option<&str>,
+) -> Result<Vec<u8>, FmtError> {
+    let mut content_sections = ContentSections::default();
+
+    // -- Begin Docblock Section --
+    content_sections.push(ContentSection::Docblock(generate_docblock_section(
+        config,
+        project_config,
+        vec![],
+        schema_hash,
+    )?));
+    // -- End Docblock Section --
+
+    // -- Begin Disable Lint Section --
+    content_sections.push(ContentSection::Generic(generate_disable_lint_section(
+        &project_config.typegen_config.language,
+    )?));
+    // -- End Disable Lint Section --
+
+    // -- Begin Types Section --
+    let mut section = GenericSection::default();
+    if project_config.typegen_config.language == TypegenLanguage::Flow {
+        writeln!(section, "/*::")?;
+    }
+    write!(
+        section,
+        "{}",
+        generate_operation_registry_type(operations, project_config)
+    )?;
+    if project_config.typegen_config.language == TypegenLanguage::Flow {
+        writeln!(section, "*/")?;
+    }
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Types Section --
+
+    content_sections.into_signed_bytes(config.artifact_signer.as_ref())
 }
 
 fn write_variable_value_with_type(
@@ -847,6 +1081,7 @@ fn generate_docblock_section(
     config: &Config,
     project_config: &ProjectConfig,
     extra_annotations: Vec<String>,
+    schema_hash: Option<&str>,
 ) -> Result<DocblockSection, FmtError> {
     let mut section = DocblockSection::default();
     if !config.header.is_empty() {
@@ -855,10 +1090,13 @@ fn generate_docblock_section(
         }
         writeln!(section)?;
     }
-    writeln!(section, "{}", SIGNING_TOKEN)?;
+    writeln!(section, "{}", config.artifact_signer.signing_token())?;
     for annotation in extra_annotations {
         writeln!(section, "{}", annotation)?;
     }
+    if let Some(schema_hash) = schema_hash {
+        writeln!(section, "@relaySchemaHash {}", schema_hash)?;
+    }
     if project_config.typegen_config.language == TypegenLanguage::Flow {
         writeln!(section, "@flow")?;
     }
@@ -903,6 +1141,38 @@ fn write_source_hash(
     Ok(())
 }
 
+/// Sets the readable name of an operation whose name is obfuscated in its
+/// request, in dev builds only.
+fn write_dev_operation_name(
+    config: &Config,
+    language: &TypegenLanguage,
+    section: &mut dyn Write,
+    operation_name: StringKey,
+) -> FmtResult {
+    if let Some(is_dev_variable_name) = &config.is_dev_variable_name {
+        writeln!(section, "if ({}) {{", is_dev_variable_name)?;
+        for node in ["fragment", "operation", "params"] {
+            match language {
+                TypegenLanguage::Flow => writeln!(
+                    section,
+                    "  (node.{}/*: any*/).name = \"{}\";",
+                    node, operation_name
+                )?,
+                TypegenLanguage::JavaScript => {
+                    writeln!(section, "  node.{}.name = \"{}\";", node, operation_name)?
+                }
+                TypegenLanguage::TypeScript => writeln!(
+                    section,
+                    "  (node.{} as any).name = \"{}\";",
+                    node, operation_name
+                )?,
+            };
+        }
+        writeln!(section, "}}")?;
+    }
+    Ok(())
+}
+
 fn write_data_driven_dependency_annotation(
     section: &mut CommentAnnotationsSection,
     data_driven_dependency_metadata: &RelayDataDrivenDependencyMetadata,
@@ -9,7 +9,7 @@ use std::fmt::Error as FmtError;
 use std::fmt::Result as FmtResult;
 use std::fmt::Write;
 
-use signedsource::sign_file;
+use crate::artifact_signing::ArtifactSigner;
 
 pub enum ContentSection {
     CommentAnnotations(CommentAnnotationsSection),
@@ -119,14 +119,15 @@ impl ContentSections {
         }
     }
 
-    pub fn into_signed_bytes(self) -> Result<Vec<u8>, FmtError> {
-        Ok(sign_file(
-            self.0
-                .into_iter()
-                .collect::<Vec<String>>()
-                .join("\n")
-                .as_str(),
-        )
-        .into_bytes())
+    pub fn into_signed_bytes(self, signer: &dyn ArtifactSigner) -> Result<Vec<u8>, FmtError> {
+        Ok(signer
+            .sign(
+                self.0
+                    .into_iter()
+                    .collect::<Vec<String>>()
+                    .join("\n")
+                    .as_str(),
+            )
+            .into_bytes())
     }
 }
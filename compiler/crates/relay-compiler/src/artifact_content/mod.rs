@@ -11,17 +11,23 @@ mod content_section;
 use std::sync::Arc;
 
 use common::SourceLocationKey;
+use content::generate_flow_enum;
 use content::generate_fragment;
+use content::generate_mock_factory;
 use content::generate_operation;
+use content::generate_operation_registry;
 use content::generate_split_operation;
 use content::generate_updatable_query;
 use graphql_ir::FragmentDefinition;
 use graphql_ir::OperationDefinition;
+use intern::string_key::StringKey;
 use relay_codegen::Printer;
 use relay_codegen::QueryID;
 use relay_typegen::FragmentLocations;
+use schema::EnumID;
 use schema::SDLSchema;
 
+use crate::config::BuildOnly;
 use crate::config::Config;
 use crate::config::ProjectConfig;
 
@@ -51,12 +57,23 @@ pub enum ArtifactContent {
         source_hash: Option<String>,
         no_optional_fields_in_raw_response_type: bool,
     },
+    MockFactory {
+        typegen_operation: Arc<OperationDefinition>,
+    },
+    FlowEnum {
+        enum_id: EnumID,
+    },
+    OperationRegistry {
+        /// The name of each operation and the module of its artifact.
+        operations: Vec<(StringKey, String)>,
+    },
     Generic {
         content: Vec<u8>,
     },
 }
 
 impl ArtifactContent {
+    #[allow(clippy::too_many_arguments)]
     pub fn as_bytes(
         &self,
         config: &Config,
@@ -65,11 +82,13 @@ impl ArtifactContent {
         schema: &SDLSchema,
         source_file: SourceLocationKey,
         fragment_locations: &FragmentLocations,
+        schema_hash: Option<&str>,
     ) -> Vec<u8> {
-        let skip_types = project_config
-            .skip_types_for_artifact
-            .as_ref()
-            .map_or(false, |skip_types_fn| skip_types_fn(source_file));
+        let skip_types = config.only == Some(BuildOnly::Artifacts)
+            || project_config
+                .skip_types_for_artifact
+                .as_ref()
+                .map_or(false, |skip_types_fn| skip_types_fn(source_file));
         match self {
             ArtifactContent::Operation {
                 normalization_operation,
@@ -91,6 +110,7 @@ impl ArtifactContent {
                 id_and_text_hash,
                 skip_types,
                 fragment_locations,
+                schema_hash,
             )
             .unwrap(),
             ArtifactContent::UpdatableQuery {
@@ -107,6 +127,7 @@ impl ArtifactContent {
                 source_hash.into(),
                 skip_types,
                 fragment_locations,
+                schema_hash,
             )
             .unwrap(),
             ArtifactContent::SplitOperation {
@@ -123,6 +144,7 @@ impl ArtifactContent {
                 typegen_operation,
                 source_hash.as_ref(),
                 fragment_locations,
+                schema_hash,
                 *no_optional_fields_in_raw_response_type,
             )
             .unwrap(),
@@ -140,8 +162,25 @@ impl ArtifactContent {
                 source_hash.as_ref(),
                 skip_types,
                 fragment_locations,
+                schema_hash,
+            )
+            .unwrap(),
+            ArtifactContent::MockFactory { typegen_operation } => generate_mock_factory(
+                config,
+                project_config,
+                schema,
+                typegen_operation,
+                source_file,
+                schema_hash,
             )
             .unwrap(),
+            ArtifactContent::FlowEnum { enum_id } => {
+                generate_flow_enum(config, project_config, schema, *enum_id, schema_hash).unwrap()
+            }
+            ArtifactContent::OperationRegistry { operations } => {
+                generate_operation_registry(config, project_config, operations, schema_hash)
+                    .unwrap()
+            }
             ArtifactContent::Generic { content } => content.clone(),
         }
     }
@@ -0,0 +1,310 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Structural comparison of two sets of generated artifacts, e.g. the
+//! artifacts of a project built with two versions of the compiler. Instead of
+//! a text diff, differences are grouped by their cause, like a key that was
+//! added to the metadata of every operation, so large artifact deltas can be
+//! reviewed.
+
+mod parse;
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use parse::parse_artifact;
+use parse::Value;
+
+/// How many occurrences of a cause are printed in a report.
+const MAX_PRINTED_OCCURRENCES: usize = 10;
+
+/// The differences between two artifacts directories, grouped by cause.
+#[derive(Debug, Default)]
+pub struct ArtifactDiffReport {
+    pub only_in_a: Vec<PathBuf>,
+    pub only_in_b: Vec<PathBuf>,
+    pub changed_artifacts: usize,
+    pub unchanged_artifacts: usize,
+    /// Cause (e.g. "Added key `metadata`") to the artifact and the path in
+    /// the artifact of each occurrence.
+    pub causes: BTreeMap<String, Vec<(PathBuf, String)>>,
+}
+
+impl ArtifactDiffReport {
+    pub fn is_empty(&self) -> bool {
+        self.only_in_a.is_empty() && self.only_in_b.is_empty() && self.changed_artifacts == 0
+    }
+
+    fn add_artifact(&mut self, path: &Path, differences: Vec<(String, String)>) {
+        if differences.is_empty() {
+            self.unchanged_artifacts += 1;
+            return;
+        }
+        self.changed_artifacts += 1;
+        for (cause, location) in differences {
+            self.causes
+                .entry(cause)
+                .or_default()
+                .push((path.to_path_buf(), location));
+        }
+    }
+}
+
+impl fmt::Display for ArtifactDiffReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} changed, {} unchanged, {} only in A, {} only in B",
+            self.changed_artifacts,
+            self.unchanged_artifacts,
+            self.only_in_a.len(),
+            self.only_in_b.len()
+        )?;
+        for (title, paths) in [
+            ("Only in A", &self.only_in_a),
+            ("Only in B", &self.only_in_b),
+        ] {
+            if !paths.is_empty() {
+                writeln!(f, "\n{} ({}):", title, paths.len())?;
+                for path in paths {
+                    writeln!(f, "  {}", path.display())?;
+                }
+            }
+        }
+        let mut causes = self.causes.iter().collect::<Vec<_>>();
+        causes.sort_by_key(|(_, occurrences)| std::cmp::Reverse(occurrences.len()));
+        for (cause, occurrences) in causes {
+            writeln!(f, "\n{} ({}):", cause, occurrences.len())?;
+            for (path, location) in occurrences.iter().take(MAX_PRINTED_OCCURRENCES) {
+                if location.is_empty() {
+                    writeln!(f, "  {}", path.display())?;
+                } else {
+                    writeln!(f, "  {}: {}", path.display(), location)?;
+                }
+            }
+            if occurrences.len() > MAX_PRINTED_OCCURRENCES {
+                writeln!(
+                    f,
+                    "  ... and {} more",
+                    occurrences.len() - MAX_PRINTED_OCCURRENCES
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Compares the files in two artifacts directories (recursively).
+pub fn diff_artifact_directories(dir_a: &Path, dir_b: &Path) -> io::Result<ArtifactDiffReport> {
+    let files_a = list_files(dir_a)?;
+    let files_b = list_files(dir_b)?;
+    let mut report = ArtifactDiffReport::default();
+    for path in &files_a {
+        if !files_b.contains(path) {
+            report.only_in_a.push(path.clone());
+        }
+    }
+    for path in &files_b {
+        if !files_a.contains(path) {
+            report.only_in_b.push(path.clone());
+        }
+    }
+    for path in files_a.iter().filter(|path| files_b.contains(path)) {
+        let content_a = fs::read_to_string(dir_a.join(path))?;
+        let content_b = fs::read_to_string(dir_b.join(path))?;
+        report.add_artifact(path, diff_artifacts(&content_a, &content_b));
+    }
+    Ok(report)
+}
+
+/// Returns the cause and location of each difference between the contents
+/// of two versions of an artifact.
+pub fn diff_artifacts(content_a: &str, content_b: &str) -> Vec<(String, String)> {
+    if content_a == content_b {
+        return vec![];
+    }
+    let (artifact_a, artifact_b) = match (parse_artifact(content_a), parse_artifact(content_b)) {
+        (Some(artifact_a), Some(artifact_b)) => (artifact_a, artifact_b),
+        _ => {
+            return vec![(
+                "Changed content (not a Relay artifact)".to_string(),
+                String::new(),
+            )];
+        }
+    };
+    let mut differences = Vec::new();
+    if artifact_a.preamble != artifact_b.preamble {
+        differences.push(("Changed types or imports".to_string(), String::new()));
+    }
+    diff_values(&artifact_a.node, &artifact_b.node, "node", &mut differences);
+    if artifact_a.hash != artifact_b.hash {
+        differences.push(("Changed source hash".to_string(), String::new()));
+    }
+    if artifact_a.exports != artifact_b.exports {
+        differences.push(("Changed exports".to_string(), String::new()));
+    }
+    differences
+}
+
+fn diff_values(a: &Value, b: &Value, path: &str, differences: &mut Vec<(String, String)>) {
+    match (a, b) {
+        (Value::Object(entries_a), Value::Object(entries_b)) => {
+            for (key, value_a) in entries_a {
+                let child_path = format!("{}.{}", path, key);
+                match entries_b.iter().find(|(other_key, _)| other_key == key) {
+                    Some((_, value_b)) => diff_values(value_a, value_b, &child_path, differences),
+                    None => differences.push((format!("Removed key `{}`", key), child_path)),
+                }
+            }
+            for (key, _) in entries_b {
+                if !entries_a.iter().any(|(other_key, _)| other_key == key) {
+                    differences.push((format!("Added key `{}`", key), format!("{}.{}", path, key)));
+                }
+            }
+        }
+        (Value::Array(items_a), Value::Array(items_b)) => {
+            diff_arrays(items_a, items_b, path, differences)
+        }
+        _ => {
+            if a != b {
+                differences.push((
+                    format!("Changed value of `{}`", last_key(path)),
+                    path.to_string(),
+                ));
+            }
+        }
+    }
+}
+
+/// Items of arrays of selections (objects with a `kind`) are matched by their
+/// kind, alias and name, to tell added, removed and reordered selections
+/// apart, e.g. after changes to flattening. Other arrays are compared by
+/// index.
+fn diff_arrays(
+    items_a: &[Value],
+    items_b: &[Value],
+    path: &str,
+    differences: &mut Vec<(String, String)>,
+) {
+    let keys_a = items_a
+        .iter()
+        .map(selection_key)
+        .collect::<Option<Vec<_>>>();
+    let keys_b = items_b
+        .iter()
+        .map(selection_key)
+        .collect::<Option<Vec<_>>>();
+    let (keys_a, keys_b) = match (keys_a, keys_b) {
+        (Some(keys_a), Some(keys_b)) => (keys_a, keys_b),
+        _ => {
+            if items_a.len() != items_b.len() {
+                differences.push((
+                    format!("Changed length of `{}`", last_key(path)),
+                    path.to_string(),
+                ));
+            }
+            for (index, (item_a, item_b)) in items_a.iter().zip(items_b).enumerate() {
+                diff_values(item_a, item_b, &format!("{}[{}]", path, index), differences);
+            }
+            return;
+        }
+    };
+
+    let mut matched_b = vec![false; items_b.len()];
+    let mut matched_order = Vec::new();
+    for (item_a, key_a) in items_a.iter().zip(&keys_a) {
+        let index_b =
+            (0..keys_b.len()).find(|index| !matched_b[*index] && keys_b[*index] == *key_a);
+        match index_b {
+            Some(index_b) => {
+                matched_b[index_b] = true;
+                matched_order.push(index_b);
+                diff_values(
+                    item_a,
+                    &items_b[index_b],
+                    &format!("{}[{}]", path, key_a),
+                    differences,
+                );
+            }
+            None => differences.push((
+                format!("Removed {} selection", selection_kind(item_a)),
+                format!("{}[{}]", path, key_a),
+            )),
+        }
+    }
+    for ((item_b, key_b), is_matched) in items_b.iter().zip(&keys_b).zip(matched_b) {
+        if !is_matched {
+            differences.push((
+                format!("Added {} selection", selection_kind(item_b)),
+                format!("{}[{}]", path, key_b),
+            ));
+        }
+    }
+    if matched_order.windows(2).any(|pair| pair[0] > pair[1]) {
+        differences.push(("Reordered selections".to_string(), path.to_string()));
+    }
+}
+
+fn selection_key(value: &Value) -> Option<String> {
+    let kind = selection_kind(value);
+    if kind.is_empty() {
+        return None;
+    }
+    let identifier = ["alias", "name", "type", "condition", "fragmentName"]
+        .iter()
+        .find_map(|key| match object_entry(value, key) {
+            Some(Value::String(identifier)) => Some(identifier.as_str()),
+            _ => None,
+        });
+    Some(match identifier {
+        Some(identifier) => format!("{} {}", kind, identifier),
+        None => kind.to_string(),
+    })
+}
+
+fn selection_kind(value: &Value) -> &str {
+    match object_entry(value, "kind") {
+        Some(Value::String(kind)) => kind,
+        _ => "",
+    }
+}
+
+fn object_entry<'a>(value: &'a Value, key: &str) -> Option<&'a Value> {
+    match value {
+        Value::Object(entries) => entries
+            .iter()
+            .find(|(entry_key, _)| entry_key == key)
+            .map(|(_, value)| value),
+        _ => None,
+    }
+}
+
+fn last_key(path: &str) -> &str {
+    let last = path.rsplit('.').next().unwrap_or(path);
+    last.split('[').next().unwrap_or(last)
+}
+
+fn list_files(root: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut directories = vec![root.to_path_buf()];
+    while let Some(directory) = directories.pop() {
+        for entry in fs::read_dir(&directory)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                directories.push(path);
+            } else {
+                files.push(path.strip_prefix(root).unwrap().to_path_buf());
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
@@ -0,0 +1,384 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::collections::HashMap;
+
+/// A JavaScript value of the generated `node` of an artifact.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Value {
+    Null,
+    Boolean(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+    /// `require('module')` and other calls of a function with arguments.
+    Call(Box<Value>, Vec<Value>),
+    /// A property of a value, e.g. `require('module').export`.
+    Member(Box<Value>, String),
+    /// An identifier that doesn't reference a hoisted variable.
+    Identifier(String),
+}
+
+/// The parts of a generated artifact file.
+#[derive(Debug)]
+pub(crate) struct ParsedArtifact {
+    /// Everything before the `node` declaration: types, imports and top
+    /// level statements, without the signature.
+    pub preamble: String,
+    pub node: Value,
+    pub hash: Option<String>,
+    /// Statements after the `node` declaration, without the hash.
+    pub exports: String,
+}
+
+/// Parses the generated `node` of an artifact. Hoisted variables (`v0`, `v1`,
+/// ...) are inlined where they are referenced. Artifacts without a `node`,
+/// like the validators of assignable fragments, only have a preamble. Returns
+/// `None` for files that don't look like Relay artifacts.
+pub(crate) fn parse_artifact(content: &str) -> Option<ParsedArtifact> {
+    let declaration_start = match ["\nvar node", "\nconst node"]
+        .iter()
+        .filter_map(|declaration| content.find(declaration))
+        .min()
+    {
+        Some(declaration_start) => declaration_start + 1,
+        None if content.contains(SIGNATURE_PREFIX) => {
+            return Some(ParsedArtifact {
+                preamble: without_signature(content),
+                node: Value::Null,
+                hash: None,
+                exports: String::new(),
+            });
+        }
+        None => return None,
+    };
+    let value_start = declaration_start + content[declaration_start..].find('=')? + 1;
+
+    let mut parser = Parser::new(&content[value_start..]);
+    let node = parser.parse_node()?;
+    parser.skip_whitespace_and_comments();
+    parser.eat(';');
+    let rest = &content[value_start + parser.position..];
+
+    let mut hash = None;
+    let mut exports = String::new();
+    for line in rest.lines() {
+        if let Some(hash_start) = line.find(".hash = \"") {
+            let value = &line[hash_start + ".hash = \"".len()..];
+            hash = value.split('"').next().map(|hash| hash.to_string());
+        } else {
+            exports.push_str(line);
+            exports.push('\n');
+        }
+    }
+
+    Some(ParsedArtifact {
+        preamble: without_signature(&content[..declaration_start]),
+        node,
+        hash,
+        exports: exports.trim().to_string(),
+    })
+}
+
+/// The line of the signature, for all the signers of `artifact_signing`.
+const SIGNATURE_PREFIX: &str = "@generated";
+
+fn without_signature(content: &str) -> String {
+    content
+        .lines()
+        .filter(|line| !line.contains(SIGNATURE_PREFIX))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    position: usize,
+    variables: HashMap<String, Value>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            position: 0,
+            variables: Default::default(),
+        }
+    }
+
+    /// The node is either a value, or a function that declares the hoisted
+    /// variables and returns the value:
+    /// `(function(){ var v0 = ..., v1 = ...; return {...}; })()`
+    fn parse_node(&mut self) -> Option<Value> {
+        self.skip_whitespace_and_comments();
+        if !self.rest().starts_with("(function") {
+            return self.parse_value();
+        }
+        self.expect_str("(function")?;
+        self.skip_whitespace_and_comments();
+        self.expect_str("()")?;
+        self.skip_whitespace_and_comments();
+        self.expect('{')?;
+        loop {
+            self.skip_whitespace_and_comments();
+            if self.eat_keyword("var") {
+                loop {
+                    self.skip_whitespace_and_comments();
+                    let name = self.parse_identifier()?;
+                    self.skip_whitespace_and_comments();
+                    self.expect('=')?;
+                    let value = self.parse_value()?;
+                    self.variables.insert(name, value);
+                    self.skip_whitespace_and_comments();
+                    if !self.eat(',') {
+                        break;
+                    }
+                }
+                self.skip_whitespace_and_comments();
+                self.eat(';');
+            } else if self.eat_keyword("return") {
+                let value = self.parse_value()?;
+                self.skip_whitespace_and_comments();
+                self.eat(';');
+                self.skip_whitespace_and_comments();
+                self.expect('}')?;
+                self.skip_whitespace_and_comments();
+                self.expect(')')?;
+                self.skip_whitespace_and_comments();
+                self.expect_str("()")?;
+                return Some(value);
+            } else {
+                return None;
+            }
+        }
+    }
+
+    fn parse_value(&mut self) -> Option<Value> {
+        self.skip_whitespace_and_comments();
+        let value = match self.peek()? {
+            '{' => self.parse_object()?,
+            '[' => self.parse_array()?,
+            '"' | '\'' => Value::String(self.parse_string()?),
+            '(' => {
+                // Parenthesized values with a type cast, e.g. `(v0/*: any*/)`
+                self.expect('(')?;
+                let value = self.parse_value()?;
+                self.skip_whitespace_and_comments();
+                if self.eat_str("as") {
+                    self.skip_until_closing_paren();
+                }
+                self.expect(')')?;
+                value
+            }
+            c if c == '-' || c.is_ascii_digit() => Value::Number(self.parse_number()),
+            c if is_identifier_start(c) => {
+                let identifier = self.parse_identifier()?;
+                match identifier.as_str() {
+                    "null" | "undefined" => Value::Null,
+                    "true" => Value::Boolean(true),
+                    "false" => Value::Boolean(false),
+                    _ => {
+                        let mut value = match self.variables.get(&identifier) {
+                            Some(value) => value.clone(),
+                            None => Value::Identifier(identifier),
+                        };
+                        // Member accesses and calls, e.g.
+                        // `require('module').wrapper(require('resolver').field)`
+                        loop {
+                            self.skip_whitespace_and_comments();
+                            if self.peek() == Some('(') {
+                                value = Value::Call(Box::new(value), self.parse_arguments()?);
+                            } else if self.eat('.') {
+                                value = Value::Member(Box::new(value), self.parse_identifier()?);
+                            } else {
+                                break value;
+                            }
+                        }
+                    }
+                }
+            }
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    fn parse_object(&mut self) -> Option<Value> {
+        self.expect('{')?;
+        let mut entries = Vec::new();
+        loop {
+            self.skip_whitespace_and_comments();
+            if self.eat('}') {
+                return Some(Value::Object(entries));
+            }
+            let key = match self.peek()? {
+                '"' | '\'' => self.parse_string()?,
+                _ => self.parse_identifier()?,
+            };
+            self.skip_whitespace_and_comments();
+            self.expect(':')?;
+            entries.push((key, self.parse_value()?));
+            self.skip_whitespace_and_comments();
+            self.eat(',');
+        }
+    }
+
+    fn parse_array(&mut self) -> Option<Value> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        loop {
+            self.skip_whitespace_and_comments();
+            if self.eat(']') {
+                return Some(Value::Array(items));
+            }
+            items.push(self.parse_value()?);
+            self.skip_whitespace_and_comments();
+            self.eat(',');
+        }
+    }
+
+    fn parse_arguments(&mut self) -> Option<Vec<Value>> {
+        self.expect('(')?;
+        let mut arguments = Vec::new();
+        loop {
+            self.skip_whitespace_and_comments();
+            if self.eat(')') {
+                return Some(arguments);
+            }
+            arguments.push(self.parse_value()?);
+            self.skip_whitespace_and_comments();
+            self.eat(',');
+        }
+    }
+
+    fn parse_string(&mut self) -> Option<String> {
+        let quote = self.next()?;
+        let mut value = String::new();
+        loop {
+            match self.next()? {
+                '\\' => {
+                    let escaped = self.next()?;
+                    value.push(match escaped {
+                        'n' => '\n',
+                        't' => '\t',
+                        'r' => '\r',
+                        c => c,
+                    });
+                }
+                c if c == quote => return Some(value),
+                c => value.push(c),
+            }
+        }
+    }
+
+    fn parse_number(&mut self) -> String {
+        let start = self.position;
+        while matches!(self.peek(), Some(c) if c == '-' || c == '.' || c == 'e' || c == '+' || c.is_ascii_digit())
+        {
+            self.next();
+        }
+        self.source[start..self.position].to_string()
+    }
+
+    fn parse_identifier(&mut self) -> Option<String> {
+        let start = self.position;
+        if !matches!(self.peek(), Some(c) if is_identifier_start(c)) {
+            return None;
+        }
+        while matches!(self.peek(), Some(c) if is_identifier_start(c) || c.is_ascii_digit()) {
+            self.next();
+        }
+        Some(self.source[start..self.position].to_string())
+    }
+
+    fn skip_until_closing_paren(&mut self) {
+        while !matches!(self.peek(), None | Some(')')) {
+            self.next();
+        }
+    }
+
+    fn skip_whitespace_and_comments(&mut self) {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.position += rest.len() - trimmed.len();
+            if trimmed.starts_with("/*") {
+                match trimmed.find("*/") {
+                    Some(end) => self.position += end + 2,
+                    None => self.position = self.source.len(),
+                }
+            } else if trimmed.starts_with("//") {
+                match trimmed.find('\n') {
+                    Some(end) => self.position += end + 1,
+                    None => self.position = self.source.len(),
+                }
+            } else {
+                return;
+            }
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.position..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += expected.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_str(&mut self, expected: &str) -> bool {
+        if self.rest().starts_with(expected) {
+            self.position += expected.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let rest = self.rest();
+        if rest.starts_with(keyword)
+            && !matches!(rest[keyword.len()..].chars().next(), Some(c) if is_identifier_start(c))
+        {
+            self.position += keyword.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Option<()> {
+        if self.eat(expected) { Some(()) } else { None }
+    }
+
+    fn expect_str(&mut self, expected: &str) -> Option<()> {
+        if self.eat_str(expected) {
+            Some(())
+        } else {
+            None
+        }
+    }
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_' || c == '$'
+}
@@ -7,8 +7,10 @@
 
 use std::path::PathBuf;
 
+use common::SourceLocationKey;
 use dashmap::mapref::entry::Entry;
 use dashmap::DashMap;
+use fnv::FnvHashSet;
 use intern::string_key::StringKey;
 use relay_codegen::QueryID;
 use serde::Deserialize;
@@ -21,10 +23,12 @@ use crate::build_project::ArtifactContent;
 pub type DefinitionName = StringKey;
 
 /// Record that contains path to the artifact, persisted_operation_id (when available)
+/// and the source file that generated it (when not generated from the schema)
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ArtifactRecord {
     pub path: PathBuf,
     pub persisted_operation_id: Option<String>,
+    pub source_file: Option<PathBuf>,
 }
 /// A map from DefinitionName to output artifacts records
 #[derive(Default, Serialize, Deserialize, Debug, Clone)]
@@ -43,6 +47,10 @@ impl ArtifactMap {
                 },
                 _ => None,
             },
+            source_file: match artifact.source_file {
+                SourceLocationKey::Generated => None,
+                source_file => Some(PathBuf::from(source_file.path())),
+            },
         };
 
         for source_definition_name in artifact.source_definition_names {
@@ -56,6 +64,26 @@ impl ArtifactMap {
             }
         }
     }
+
+    /// Removes the records of the artifacts generated from the source files,
+    /// and returns the paths of those artifacts.
+    pub fn remove_source_files(&self, source_files: &FnvHashSet<PathBuf>) -> Vec<PathBuf> {
+        let mut removed_paths = Vec::new();
+        self.0.retain(|_, records| {
+            records.retain(|record| {
+                let is_removed = record
+                    .source_file
+                    .as_ref()
+                    .map_or(false, |source_file| source_files.contains(source_file));
+                if is_removed {
+                    removed_paths.push(record.path.clone());
+                }
+                !is_removed
+            });
+            !records.is_empty()
+        });
+        removed_paths
+    }
 }
 
 impl From<Vec<Artifact>> for ArtifactMap {
@@ -0,0 +1,196 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The signature of the generated files, which lets tooling detect generated
+//! files that were edited by hand.
+//!
+//! The docblock of each artifact contains the signing token of the signer,
+//! which the signer replaces with the signature of the whole content once it
+//! is generated. By default the artifacts are signed with SignedSource, the
+//! `artifactSigning` option of the config file selects another signer, and
+//! `Config::artifact_signer` can be replaced with a custom implementation.
+
+use std::env;
+
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+use signedsource::sign_file;
+use signedsource::SIGNING_TOKEN;
+
+use crate::errors::Error;
+use crate::errors::Result;
+
+/// The `artifactSigning` section of the config file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase", deny_unknown_fields)]
+pub enum ArtifactSigningConfig {
+    /// The artifacts are only marked with `@generated`.
+    None,
+
+    /// `@generated SignedSource<<md5>>`, with the MD5 hash of the content.
+    #[default]
+    SignedSource,
+
+    /// `@generated HmacSHA256<<signature>>`, with the HMAC-SHA256 of the
+    /// content, keyed with the value of an environment variable, so that
+    /// the signatures can only be produced by who knows the key.
+    Hmac {
+        #[serde(rename = "keyEnvVar")]
+        key_env_var: String,
+    },
+}
+
+pub trait ArtifactSigner: Send + Sync {
+    /// The line written in the docblock of the artifacts.
+    fn signing_token(&self) -> &str;
+
+    /// Signs the content of an artifact, which contains the signing token.
+    fn sign(&self, content: &str) -> String;
+}
+
+pub fn create_artifact_signer(config: ArtifactSigningConfig) -> Result<Box<dyn ArtifactSigner>> {
+    Ok(match config {
+        ArtifactSigningConfig::None => Box::new(NoArtifactSigner),
+        ArtifactSigningConfig::SignedSource => Box::new(SignedSourceArtifactSigner),
+        ArtifactSigningConfig::Hmac { key_env_var } => {
+            let key = env::var(&key_env_var).map_err(|_| Error::ConfigError {
+                details: format!(
+                    "The environment variable `{}` with the key of `artifactSigning` is not set.",
+                    key_env_var
+                ),
+            })?;
+            Box::new(HmacArtifactSigner::new(key.into_bytes()))
+        }
+    })
+}
+
+pub struct NoArtifactSigner;
+
+impl ArtifactSigner for NoArtifactSigner {
+    fn signing_token(&self) -> &str {
+        "\x40generated"
+    }
+
+    fn sign(&self, content: &str) -> String {
+        content.to_string()
+    }
+}
+
+pub struct SignedSourceArtifactSigner;
+
+impl ArtifactSigner for SignedSourceArtifactSigner {
+    fn signing_token(&self) -> &str {
+        SIGNING_TOKEN
+    }
+
+    fn sign(&self, content: &str) -> String {
+        sign_file(content)
+    }
+}
+
+const HMAC_SIGNING_TOKEN: &str = "\x40generated <<HmacSHA256::unsigned>>";
+const HMAC_SIGNATURE_PREFIX: &str = "\x40generated HmacSHA256<<";
+
+/// Signs the artifacts with the HMAC-SHA256 of their content, in which the
+/// signature is replaced with the signing token. To verify an artifact,
+/// replace `HmacSHA256<<signature>>` with `<<HmacSHA256::unsigned>>` and
+/// compare the HMAC of the result with the signature.
+pub struct HmacArtifactSigner {
+    key: Vec<u8>,
+}
+
+impl HmacArtifactSigner {
+    pub fn new(key: Vec<u8>) -> Self {
+        Self { key }
+    }
+
+    /// Whether the signature of a signed artifact matches its content.
+    pub fn is_valid_signature(&self, content: &str) -> bool {
+        content
+            .find(HMAC_SIGNATURE_PREFIX)
+            .and_then(|start| {
+                let signature_start = start + HMAC_SIGNATURE_PREFIX.len();
+                let signature_end = signature_start + content[signature_start..].find(">>")?;
+                let unsigned = format!(
+                    "{}{}{}",
+                    &content[..start],
+                    HMAC_SIGNING_TOKEN,
+                    &content[signature_end + 2..]
+                );
+                Some(
+                    hex::encode(hmac_sha256(&self.key, unsigned.as_bytes()))
+                        == content[signature_start..signature_end],
+                )
+            })
+            .unwrap_or(false)
+    }
+}
+
+impl ArtifactSigner for HmacArtifactSigner {
+    fn signing_token(&self) -> &str {
+        HMAC_SIGNING_TOKEN
+    }
+
+    fn sign(&self, content: &str) -> String {
+        let signature = hex::encode(hmac_sha256(&self.key, content.as_bytes()));
+        content.replacen(
+            HMAC_SIGNING_TOKEN,
+            &format!("{}{}>>", HMAC_SIGNATURE_PREFIX, signature),
+            1,
+        )
+    }
+}
+
+/// HMAC (RFC 2104) with SHA-256.
+fn hmac_sha256(key: &[u8], message: &[u8]) -> Vec<u8> {
+    const BLOCK_SIZE: usize = 64;
+    let mut block_key = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        let hashed_key = Sha256::digest(key);
+        block_key[..hashed_key.len()].copy_from_slice(&hashed_key);
+    } else {
+        block_key[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(block_key.map(|byte| byte ^ 0x36));
+    inner.update(message);
+    let mut outer = Sha256::new();
+    outer.update(block_key.map(|byte| byte ^ 0x5c));
+    outer.update(inner.finalize());
+    outer.finalize().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hmac_sha256() {
+        // Test case 2 of RFC 4231.
+        assert_eq!(
+            hex::encode(hmac_sha256(b"Jefe", b"what do ya want for nothing?")),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_hmac_signature() {
+        let signer = HmacArtifactSigner::new(b"secret".to_vec());
+        let content = format!(
+            "/**\n * {}\n */\n\nmodule.exports = 1;\n",
+            HMAC_SIGNING_TOKEN
+        );
+        let signed = signer.sign(&content);
+        assert!(signed.contains(HMAC_SIGNATURE_PREFIX));
+        assert!(signer.is_valid_signature(&signed));
+        assert!(!signer.is_valid_signature(&signed.replace('1', "2")));
+        assert!(!HmacArtifactSigner::new(b"other".to_vec()).is_valid_signature(&signed));
+    }
+}
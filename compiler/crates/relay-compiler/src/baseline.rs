@@ -0,0 +1,308 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! A baseline of tolerated validation diagnostics, to enable a validation on
+//! a codebase with existing violations while blocking new ones.
+//! `relay-compiler baseline generate` records the current diagnostics in the
+//! file of the `baseline` config option, and the compiler then leaves the
+//! recorded ones out of the diagnostics of the validations.
+//!
+//! Diagnostics are identified by their file, their code and a fingerprint of
+//! their message and of the source text at their location, so that they stay
+//! recorded when lines are added or removed around them.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use common::Diagnostic;
+use common::SourceLocationKey;
+use intern::Lookup;
+use md5::Digest;
+use md5::Md5;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::source_for_location;
+use crate::FsSourceReader;
+
+const VERSION: u32 = 1;
+
+/// The length of the hex fingerprints, long enough to not collide within a
+/// file.
+const FINGERPRINT_LENGTH: usize = 16;
+
+/// Recorded diagnostics of a file with the same code and fingerprint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineEntry {
+    /// The file of the diagnostics, relative to the root directory.
+    pub path: String,
+    /// The stable code of the diagnostics, or their kind if they have none.
+    pub code: String,
+    pub fingerprint: String,
+    pub count: usize,
+    /// The message of the diagnostics, for the readers of the file.
+    pub message: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BaselineFile {
+    version: u32,
+    diagnostics: Vec<BaselineEntry>,
+}
+
+type BaselineKey = (String, String, String);
+
+#[derive(Debug)]
+pub struct Baseline {
+    /// The absolute path of the baseline file.
+    path: PathBuf,
+    entries: BTreeMap<BaselineKey, BaselineEntry>,
+}
+
+/// The diagnostics of a run compared to a baseline.
+#[derive(Debug)]
+pub struct BaselineComparison {
+    /// The diagnostics missing from the baseline.
+    pub new_diagnostics: Vec<Diagnostic>,
+    /// The number of recorded diagnostics that are not reported anymore.
+    pub fixed_count: usize,
+}
+
+impl Baseline {
+    pub fn empty(path: PathBuf) -> Self {
+        Self {
+            path,
+            entries: Default::default(),
+        }
+    }
+
+    /// Reads the baseline file at `path`. A missing file is an empty baseline,
+    /// which `relay-compiler baseline generate` creates.
+    pub fn load(path: PathBuf) -> io::Result<Self> {
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                return Ok(Self::empty(path));
+            }
+            Err(error) => return Err(error),
+        };
+        let file: BaselineFile = serde_json::from_str(&content)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        if file.version != VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Unsupported baseline version {}, expected {}. Generate it again with `relay-compiler baseline generate`.",
+                    file.version, VERSION
+                ),
+            ));
+        }
+        let entries = file
+            .diagnostics
+            .into_iter()
+            .map(|entry| {
+                (
+                    (
+                        entry.path.clone(),
+                        entry.code.clone(),
+                        entry.fingerprint.clone(),
+                    ),
+                    entry,
+                )
+            })
+            .collect();
+        Ok(Self { path, entries })
+    }
+
+    /// A baseline recording `diagnostics`, to be written to `path`.
+    pub fn from_diagnostics(path: PathBuf, root_dir: &Path, diagnostics: &[Diagnostic]) -> Self {
+        let mut entries: BTreeMap<BaselineKey, BaselineEntry> = BTreeMap::new();
+        for diagnostic in diagnostics {
+            let key = baseline_key(root_dir, diagnostic);
+            entries
+                .entry(key.clone())
+                .or_insert_with(|| BaselineEntry {
+                    path: key.0,
+                    code: key.1,
+                    fingerprint: key.2,
+                    count: 0,
+                    message: first_line(&diagnostic.message().to_string()),
+                })
+                .count += 1;
+        }
+        Self { path, entries }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The number of recorded diagnostics.
+    pub fn len(&self) -> usize {
+        self.entries.values().map(|entry| entry.count).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Writes the baseline file, with the entries sorted by file to keep the
+    /// diffs readable.
+    pub fn write(&self) -> io::Result<()> {
+        let file = BaselineFile {
+            version: VERSION,
+            diagnostics: self.entries.values().cloned().collect(),
+        };
+        let mut content = serde_json::to_string_pretty(&file)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        content.push('\n');
+        fs::write(&self.path, content)
+    }
+
+    /// Separates the diagnostics missing from the baseline. A file may have
+    /// as many diagnostics with the same fingerprint as recorded.
+    pub fn compare(&self, root_dir: &Path, diagnostics: Vec<Diagnostic>) -> BaselineComparison {
+        let mut remaining: BTreeMap<&BaselineKey, usize> = self
+            .entries
+            .iter()
+            .map(|(key, entry)| (key, entry.count))
+            .collect();
+        let mut new_diagnostics = Vec::new();
+        for diagnostic in diagnostics {
+            let key = baseline_key(root_dir, &diagnostic);
+            match remaining.get_mut(&key) {
+                Some(count) if *count > 0 => *count -= 1,
+                _ => new_diagnostics.push(diagnostic),
+            }
+        }
+        BaselineComparison {
+            new_diagnostics,
+            fixed_count: remaining.values().sum(),
+        }
+    }
+
+    /// The diagnostics missing from the baseline.
+    pub fn filter(&self, root_dir: &Path, diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+        if self.entries.is_empty() {
+            return diagnostics;
+        }
+        self.compare(root_dir, diagnostics).new_diagnostics
+    }
+}
+
+fn baseline_key(root_dir: &Path, diagnostic: &Diagnostic) -> BaselineKey {
+    let location = diagnostic.location();
+    let path = match location.source_location() {
+        SourceLocationKey::Embedded { path, .. } | SourceLocationKey::Standalone { path } => {
+            path.lookup().to_string()
+        }
+        SourceLocationKey::Generated => String::new(),
+    };
+    let code = diagnostic.error_code().unwrap_or_default().to_string();
+    let span = location.span();
+    let snippet = source_for_location(root_dir, location.source_location(), &FsSourceReader)
+        .and_then(|source| {
+            source
+                .to_text_source()
+                .text
+                .get(span.start as usize..span.end as usize)
+                .map(|text| text.split_whitespace().collect::<Vec<_>>().join(" "))
+        })
+        .unwrap_or_default();
+
+    let mut md5 = Md5::new();
+    md5.update(&code);
+    md5.update("\n");
+    md5.update(first_line(&diagnostic.message().to_string()));
+    md5.update("\n");
+    md5.update(snippet);
+    let mut fingerprint = hex::encode(md5.finalize());
+    fingerprint.truncate(FINGERPRINT_LENGTH);
+    (path, code, fingerprint)
+}
+
+/// Messages may end with a link to the error reference on another line.
+fn first_line(message: &str) -> String {
+    message.lines().next().unwrap_or_default().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use common::Location;
+    use common::Span;
+    use graphql_ir::ValidationMessage;
+    use graphql_ir::VariableName;
+    use intern::string_key::Intern;
+
+    use super::*;
+
+    /// Diagnostics for the variables of `text` named in `names`.
+    fn diagnostics(root_dir: &Path, text: &str, names: &[&'static str]) -> Vec<Diagnostic> {
+        fs::write(root_dir.join("Query.graphql"), text).unwrap();
+        names
+            .iter()
+            .map(|name| {
+                let start = text.find(&format!("${}", name)).unwrap() as u32;
+                let location = Location::new(
+                    SourceLocationKey::standalone("Query.graphql"),
+                    Span::new(start, start + 1 + name.len() as u32),
+                );
+                Diagnostic::error(
+                    ValidationMessage::UnusedVariable {
+                        variable_name: VariableName(name.intern()),
+                        operation_name: "Q".intern(),
+                    },
+                    location,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn compare_with_moved_and_new_diagnostics() {
+        let root_dir = env::temp_dir().join(format!("relay-baseline-test-{}", std::process::id()));
+        fs::create_dir_all(&root_dir).unwrap();
+        let path = root_dir.join("baseline.json");
+
+        let baseline = Baseline::from_diagnostics(
+            path.clone(),
+            &root_dir,
+            &diagnostics(
+                &root_dir,
+                "query Q($a: ID, $b: ID) { me { id } }",
+                &["a", "b"],
+            ),
+        );
+        assert_eq!(baseline.len(), 2);
+        baseline.write().unwrap();
+        let baseline = Baseline::load(path).unwrap();
+
+        // Moved to another line, with a new unused variable and `$b` fixed
+        let comparison = baseline.compare(
+            &root_dir,
+            diagnostics(
+                &root_dir,
+                "# Comment\nquery Q($a: ID, $c: ID) { me { id } }",
+                &["a", "c"],
+            ),
+        );
+        fs::remove_dir_all(&root_dir).unwrap();
+
+        assert_eq!(comparison.fixed_count, 1);
+        assert_eq!(comparison.new_diagnostics.len(), 1);
+        assert_eq!(
+            comparison.new_diagnostics[0].message().to_string(),
+            "Variable `$c` is never used in operation `Q`"
+        );
+    }
+}
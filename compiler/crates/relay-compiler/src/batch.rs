@@ -0,0 +1,552 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Compiles single files for bundler plugins (Vite, esbuild, webpack
+//! loaders...), which keep one compiler process running and send it the
+//! contents of the files they load as newline-delimited JSON requests. Used by
+//! the `batch` command.
+
+use std::io;
+use std::io::BufRead;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use common::Diagnostic;
+use common::DiagnosticSeverity;
+use common::PerfLogger;
+use common::SourceLocationKey;
+use common::TextSource;
+use common::WithDiagnostics;
+use intern::Lookup;
+use relay_codegen::Printer;
+use relay_typegen::FragmentLocations;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::build_project::build_project;
+use crate::build_project::compute_schema_hash;
+use crate::build_project::BuildProjectFailure;
+use crate::compiler_state::CompilerState;
+use crate::config::Config;
+use crate::errors::BuildProjectError;
+use crate::errors::Error;
+use crate::file_source::FileCategorizer;
+use crate::file_source::FileGroup;
+use crate::fix::fix_source_location;
+use crate::fix::resolve_fix;
+use crate::graphql_asts::GraphQLAsts;
+
+/// A line of the input of the `batch` command.
+#[derive(Debug, Deserialize)]
+pub struct BatchRequest {
+    /// The source file to compile, absolute or relative to the root
+    /// directory.
+    pub file: PathBuf,
+    /// The contents of the file, used instead of the ones on disk.
+    pub contents: String,
+}
+
+/// A line of the output of the `batch` command, answering the request with
+/// the same `file`.
+#[derive(Debug, Default, Serialize)]
+pub struct BatchResponse {
+    pub file: PathBuf,
+    /// The artifacts of the definitions of the file. They are not written to
+    /// disk.
+    pub artifacts: Vec<BatchArtifact>,
+    /// The diagnostics located in the file.
+    pub diagnostics: Vec<BatchDiagnostic>,
+    /// Why the file could not be compiled, when it is not because of its
+    /// diagnostics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchArtifact {
+    /// Relative to the root directory.
+    pub path: PathBuf,
+    pub content: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchDiagnostic {
+    /// The stable code of the diagnostic (e.g. `RELAY1001`), if it has one.
+    pub code: Option<&'static str>,
+    /// `"error"`, `"warning"`, `"information"` or `"hint"`.
+    pub severity: &'static str,
+    pub message: String,
+    /// 0-based lines and columns of the range of the diagnostic in the file.
+    pub start_line: u32,
+    pub start_column: u32,
+    pub end_line: u32,
+    pub end_column: u32,
+    /// The fix of the diagnostic that is safe to apply without review.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<BatchFix>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchFix {
+    pub title: String,
+    /// The edits of the file, applied together.
+    pub edits: Vec<BatchEdit>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchEdit {
+    /// 0-based lines and columns of the range to replace in the file.
+    pub start_line: u32,
+    pub start_column: u32,
+    pub end_line: u32,
+    pub end_column: u32,
+    pub new_text: String,
+}
+
+/// Keeps the sources of the projects in memory between the requests, so that
+/// each request compiles the latest contents of every file sent so far. The
+/// projects of the file are rebuilt from scratch for each request, and only
+/// the artifacts of the file are returned.
+pub struct BatchCompiler<TPerfLogger: PerfLogger + 'static> {
+    config: Arc<Config>,
+    perf_logger: Arc<TPerfLogger>,
+    categorizer: FileCategorizer,
+    compiler_state: CompilerState,
+}
+
+impl<TPerfLogger: PerfLogger + 'static> BatchCompiler<TPerfLogger> {
+    pub fn new(
+        config: Arc<Config>,
+        perf_logger: Arc<TPerfLogger>,
+        compiler_state: CompilerState,
+    ) -> Self {
+        let categorizer = FileCategorizer::from_config(&config);
+        Self {
+            config,
+            perf_logger,
+            categorizer,
+            compiler_state,
+        }
+    }
+
+    /// Answers each request of `input` with a line of `output`, until
+    /// `input` is closed. Invalid requests are answered with an `error`.
+    pub fn serve(&mut self, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+        for line in input.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let response = match serde_json::from_str::<BatchRequest>(&line) {
+                Ok(request) => self.compile_file(request),
+                Err(err) => BatchResponse {
+                    error: Some(format!("Invalid request: {}", err)),
+                    ..Default::default()
+                },
+            };
+            serde_json::to_writer(&mut output, &response)?;
+            writeln!(output)?;
+            output.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Compiles the projects of the file of `request` with its new contents,
+    /// which are kept for the next requests.
+    pub fn compile_file(&mut self, request: BatchRequest) -> BatchResponse {
+        let path = request
+            .file
+            .strip_prefix(&self.config.root_dir)
+            .unwrap_or(&request.file)
+            .to_path_buf();
+        let mut response = BatchResponse {
+            file: request.file,
+            ..Default::default()
+        };
+        let project_set = match self.categorizer.categorize(&path) {
+            Ok(FileGroup::Source { project_set }) => project_set,
+            Ok(_) => {
+                response.error = Some("The file is not a source file of a project.".to_string());
+                return response;
+            }
+            Err(err) => {
+                response.error = Some(err.to_string());
+                return response;
+            }
+        };
+
+        self.compiler_state
+            .set_pending_file_contents(&project_set, &path, &request.contents);
+        let request_file = RequestFile::new(&path, &request.contents);
+        match GraphQLAsts::from_graphql_sources_map(
+            &self.compiler_state.graphql_sources,
+            &Default::default(),
+        ) {
+            Ok(graphql_asts) => {
+                for project_name in project_set.iter() {
+                    let project_config = &self.config.projects[project_name];
+                    if !project_config.enabled {
+                        continue;
+                    }
+                    match build_project(
+                        &self.config,
+                        project_config,
+                        &self.compiler_state,
+                        &graphql_asts,
+                        Arc::clone(&self.perf_logger),
+                    ) {
+                        Ok(WithDiagnostics {
+                            item: (_, schema, programs, artifacts),
+                            diagnostics,
+                        }) => {
+                            let schema_hash = if project_config.schema_hash_comment {
+                                Some(compute_schema_hash(&schema))
+                            } else {
+                                None
+                            };
+                            let fragment_locations =
+                                FragmentLocations::new(programs.typegen.fragments());
+                            let mut printer = Printer::with_dedupe(project_config);
+                            for artifact in artifacts {
+                                if !request_file.contains(artifact.source_file) {
+                                    continue;
+                                }
+                                let content = artifact.content.as_bytes(
+                                    &self.config,
+                                    project_config,
+                                    &mut printer,
+                                    &schema,
+                                    artifact.source_file,
+                                    &fragment_locations,
+                                    schema_hash.as_deref(),
+                                );
+                                response.artifacts.push(BatchArtifact {
+                                    path: artifact.path,
+                                    content: String::from_utf8_lossy(&content).into_owned(),
+                                });
+                            }
+                            response
+                                .diagnostics
+                                .extend(request_file.convert_diagnostics(&diagnostics));
+                            self.compiler_state
+                                .schema_cache
+                                .insert(project_config.name, schema);
+                        }
+                        Err(BuildProjectFailure::Error(BuildProjectError::ValidationErrors {
+                            errors,
+                            project_name,
+                        })) => {
+                            let diagnostics = request_file.convert_diagnostics(&errors);
+                            if diagnostics.is_empty() {
+                                response.error = Some(format!(
+                                    "The project `{}` has errors in other files.",
+                                    project_name
+                                ));
+                            }
+                            response.diagnostics.extend(diagnostics);
+                        }
+                        Err(BuildProjectFailure::Error(error)) => {
+                            response.error = Some(error.to_string());
+                        }
+                        Err(BuildProjectFailure::Cancelled) => {
+                            response.error = Some(Error::Cancelled.to_string());
+                        }
+                    }
+                }
+            }
+            Err(Error::DiagnosticsError { errors }) => {
+                let diagnostics = request_file.convert_diagnostics(&errors);
+                if diagnostics.is_empty() {
+                    response.error = Some("Other files have syntax errors.".to_string());
+                }
+                response.diagnostics = diagnostics;
+            }
+            Err(error) => {
+                response.error = Some(error.to_string());
+            }
+        }
+        // The contents of the request are the latest ones, even if the
+        // build failed.
+        self.compiler_state.complete_compilation();
+        response
+    }
+}
+
+/// The file of a request, to select the artifacts and diagnostics located in
+/// it, and compute the ranges of the diagnostics from the contents of the
+/// request.
+struct RequestFile<'a> {
+    path: &'a Path,
+    contents: &'a str,
+}
+
+impl<'a> RequestFile<'a> {
+    fn new(path: &'a Path, contents: &'a str) -> Self {
+        Self { path, contents }
+    }
+
+    fn contains(&self, source_location: SourceLocationKey) -> bool {
+        match source_location {
+            SourceLocationKey::Embedded { path, .. } | SourceLocationKey::Standalone { path } => {
+                Path::new(path.lookup()) == self.path
+            }
+            SourceLocationKey::Generated => false,
+        }
+    }
+
+    fn text_source(&self, source_location: SourceLocationKey) -> Option<TextSource> {
+        match source_location {
+            SourceLocationKey::Embedded { index, .. } => {
+                extract_graphql::extract_for_path(self.path, self.contents)
+                    .into_iter()
+                    .nth(index.into())
+                    .map(|feature| feature.to_text_source())
+            }
+            SourceLocationKey::Standalone { .. } => {
+                Some(TextSource::from_whole_document(self.contents))
+            }
+            SourceLocationKey::Generated => None,
+        }
+    }
+
+    fn convert_diagnostics(&self, diagnostics: &[Diagnostic]) -> Vec<BatchDiagnostic> {
+        diagnostics
+            .iter()
+            .filter(|diagnostic| self.contains(diagnostic.location().source_location()))
+            .map(|diagnostic| {
+                let location = diagnostic.location();
+                let text_source = self.text_source(location.source_location());
+                let range = text_source
+                    .as_ref()
+                    .map(|source| source.to_span_range(location.span()))
+                    .unwrap_or_default();
+                let fix = text_source
+                    .as_ref()
+                    .and_then(|source| convert_fix(diagnostic, source));
+                BatchDiagnostic {
+                    code: diagnostic.error_code(),
+                    severity: match diagnostic.severity() {
+                        DiagnosticSeverity::ERROR => "error",
+                        DiagnosticSeverity::WARNING => "warning",
+                        DiagnosticSeverity::INFORMATION => "information",
+                        _ => "hint",
+                    },
+                    message: diagnostic.localized_message(),
+                    start_line: range.start.line,
+                    start_column: range.start.character,
+                    end_line: range.end.line,
+                    end_column: range.end.character,
+                    fix,
+                }
+            })
+            .collect()
+    }
+}
+
+/// The safe fix of `diagnostic`, resolved to edits of the file of `source`.
+/// Fixes editing another source are left out.
+fn convert_fix(diagnostic: &Diagnostic, source: &TextSource) -> Option<BatchFix> {
+    let fix = diagnostic.safe_fix()?;
+    let location = diagnostic.location();
+    if fix_source_location(location, fix) != Some(location.source_location()) {
+        return None;
+    }
+    let edits = resolve_fix(&source.text, *diagnostic.location().span(), fix)?;
+    Some(BatchFix {
+        title: fix.title(),
+        edits: edits
+            .into_iter()
+            .map(|(span, new_text)| {
+                let range = source.to_span_range(&span);
+                BatchEdit {
+                    start_line: range.start.line,
+                    start_column: range.start.character,
+                    end_line: range.end.line,
+                    end_column: range.end.character,
+                    new_text,
+                }
+            })
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use common::NoopPerfLogger;
+    use intern::string_key::Intern;
+
+    use super::*;
+    use crate::compiler_state::SchemaSources;
+
+    const SCHEMA: &str = "type Query { me: User } type User { id: ID! name: String }";
+
+    fn create_batch_compiler() -> BatchCompiler<NoopPerfLogger> {
+        let config = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src": "public"
+                    },
+                    "projects": {
+                        "public": {
+                            "schema": "schema.graphql",
+                            "language": "javascript"
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap();
+        let mut schema_sources = SchemaSources::default();
+        schema_sources
+            .pending
+            .insert(PathBuf::from("schema.graphql"), SCHEMA.to_string());
+        let mut compiler_state = CompilerState::default();
+        compiler_state
+            .schemas
+            .insert("public".intern(), schema_sources);
+        BatchCompiler::new(Arc::new(config), Arc::new(NoopPerfLogger), compiler_state)
+    }
+
+    fn request(file: &str, contents: &str) -> BatchRequest {
+        BatchRequest {
+            file: PathBuf::from(file),
+            contents: contents.to_string(),
+        }
+    }
+
+    #[test]
+    fn returns_the_artifacts_of_the_file() {
+        let mut batch_compiler = create_batch_compiler();
+        let response = batch_compiler.compile_file(request(
+            "src/Profile.js",
+            "graphql`query ProfileQuery { me { name } }`;",
+        ));
+        assert_eq!(response.error, None);
+        assert!(response.diagnostics.is_empty());
+        let paths: Vec<_> = response
+            .artifacts
+            .iter()
+            .map(|artifact| artifact.path.clone())
+            .collect();
+        assert_eq!(
+            paths,
+            vec![PathBuf::from("src/__generated__/ProfileQuery.graphql.js")]
+        );
+        assert!(response.artifacts[0].content.contains("ProfileQuery"));
+    }
+
+    #[test]
+    fn returns_the_diagnostics_of_the_file() {
+        let mut batch_compiler = create_batch_compiler();
+        let response = batch_compiler.compile_file(request(
+            "src/Profile.js",
+            "const query = graphql`\n  query ProfileQuery { me { age } }\n`;",
+        ));
+        assert!(response.artifacts.is_empty());
+        assert_eq!(response.diagnostics.len(), 1);
+        let diagnostic = &response.diagnostics[0];
+        assert_eq!(diagnostic.code, Some("RELAY1077"));
+        assert_eq!(diagnostic.severity, "error");
+        assert_eq!((diagnostic.start_line, diagnostic.start_column), (1, 28));
+    }
+
+    #[test]
+    fn returns_the_fixes_of_the_diagnostics() {
+        let mut batch_compiler = create_batch_compiler();
+        let response = batch_compiler.compile_file(request(
+            "src/Profile.js",
+            "graphql`query ProfileQuery($id: ID) { me { name } }`;",
+        ));
+        assert_eq!(response.diagnostics.len(), 1);
+        let fix = response.diagnostics[0].fix.as_ref().unwrap();
+        assert_eq!(fix.title, "Remove unused variable");
+        assert_eq!(fix.edits.len(), 1);
+        assert_eq!(
+            (fix.edits[0].start_column, fix.edits[0].end_column),
+            (26, 35)
+        );
+        assert_eq!(fix.edits[0].new_text, "");
+    }
+
+    #[test]
+    fn keeps_the_contents_of_previous_requests() {
+        let mut batch_compiler = create_batch_compiler();
+        batch_compiler.compile_file(request(
+            "src/User.js",
+            "graphql`fragment User_user on User { name }`;",
+        ));
+        let response = batch_compiler.compile_file(request(
+            "src/Profile.js",
+            "graphql`query ProfileQuery { me { ...User_user } }`;",
+        ));
+        assert_eq!(response.error, None);
+        assert!(response.diagnostics.is_empty());
+        assert_eq!(response.artifacts.len(), 1);
+    }
+
+    #[test]
+    fn compiles_the_latest_contents_of_other_files() {
+        let mut batch_compiler = create_batch_compiler();
+        batch_compiler.compile_file(request(
+            "src/User.js",
+            "graphql`fragment User_user on User { id }`;",
+        ));
+        let response = batch_compiler.compile_file(request(
+            "src/Profile.js",
+            "graphql`query ProfileQuery { me { ...User_user } }`;",
+        ));
+        assert!(!response.artifacts[0].content.contains("\"name\": \"name\""));
+
+        batch_compiler.compile_file(request(
+            "src/User.js",
+            "graphql`fragment User_user on User { id name }`;",
+        ));
+        let response = batch_compiler.compile_file(request(
+            "src/Profile.js",
+            "graphql`query ProfileQuery { me { ...User_user } }`;",
+        ));
+        assert_eq!(response.error, None);
+        assert!(response.artifacts[0].content.contains("\"name\": \"name\""));
+    }
+
+    #[test]
+    fn answers_each_line() {
+        let mut batch_compiler = create_batch_compiler();
+        let input = concat!(
+            r#"{"file": "src/Profile.js", "contents": "graphql`query ProfileQuery { me { name } }`;"}"#,
+            "\n",
+            "not json\n",
+            r#"{"file": "schema.graphql", "contents": ""}"#,
+            "\n",
+        );
+        let mut output = Vec::new();
+        batch_compiler.serve(input.as_bytes(), &mut output).unwrap();
+        let responses: Vec<serde_json::Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(responses.len(), 3);
+        assert_eq!(responses[0]["file"], "src/Profile.js");
+        assert!(responses[0].get("error").is_none());
+        assert!(
+            responses[1]["error"]
+                .as_str()
+                .unwrap()
+                .starts_with("Invalid request")
+        );
+        assert_eq!(
+            responses[2]["error"],
+            "The file is not a source file of a project."
+        );
+    }
+}
@@ -0,0 +1,199 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Times the phases of the compiler on generated projects of configurable
+//! size, to measure performance regressions in transforms and codegen across
+//! releases.
+
+mod synthetic_project;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use common::PerfLogEvent;
+use common::PerfLogger;
+pub use synthetic_project::generate_synthetic_project;
+pub use synthetic_project::write_synthetic_project;
+pub use synthetic_project::SyntheticProjectOptions;
+
+use crate::compiler::Compiler;
+use crate::config::Config;
+use crate::errors::Error;
+use crate::errors::Result;
+use crate::FileSourceKind;
+
+/// Name of the timing of a complete compilation in a [`BenchReport`].
+const TOTAL_TIMER: &str = "total";
+
+/// Collects the duration of every timer of the compiler's perf log events.
+#[derive(Default)]
+pub struct BenchPerfLogger {
+    timings: Arc<Mutex<Vec<(&'static str, Duration)>>>,
+}
+
+impl BenchPerfLogger {
+    /// Returns the total duration of each timer since the last call.
+    fn take_timings(&self) -> HashMap<&'static str, Duration> {
+        let mut totals = HashMap::new();
+        for (name, duration) in self.timings.lock().unwrap().drain(..) {
+            *totals.entry(name).or_default() += duration;
+        }
+        totals
+    }
+}
+
+impl PerfLogger for BenchPerfLogger {
+    type PerfLogEvent = BenchPerfLogEvent;
+
+    fn create_event(&self, _name: &'static str) -> Self::PerfLogEvent {
+        BenchPerfLogEvent {
+            timings: Arc::clone(&self.timings),
+        }
+    }
+}
+
+pub struct BenchPerfLogEvent {
+    timings: Arc<Mutex<Vec<(&'static str, Duration)>>>,
+}
+
+impl PerfLogEvent for BenchPerfLogEvent {
+    type Timer = (&'static str, Instant);
+
+    fn number(&self, _name: &'static str, _number: usize) {}
+
+    fn string(&self, _name: &'static str, _value: String) {}
+
+    fn start(&self, name: &'static str) -> Self::Timer {
+        (name, Instant::now())
+    }
+
+    fn stop(&self, timer: Self::Timer) {
+        let (name, start) = timer;
+        self.timings.lock().unwrap().push((name, start.elapsed()));
+    }
+
+    fn complete(self) {}
+}
+
+/// The durations of each phase in every iteration of a benchmark.
+#[derive(Debug, Default)]
+pub struct BenchReport {
+    pub iterations: usize,
+    /// Phase (the name of a perf log timer) to its total duration in each
+    /// iteration that logged it.
+    pub phases: HashMap<&'static str, Vec<Duration>>,
+}
+
+impl fmt::Display for BenchReport {
+    /// Prints the minimum, median and maximum duration of each phase, slowest
+    /// first. Phases that run in parallel (e.g. the transforms of different
+    /// pipelines) overlap, so their durations don't add up to the total.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut phases = self
+            .phases
+            .iter()
+            .map(|(name, durations)| {
+                let mut durations = durations.clone();
+                durations.sort();
+                (*name, durations)
+            })
+            .collect::<Vec<_>>();
+        phases.sort_by(|(name_a, durations_a), (name_b, durations_b)| {
+            median(durations_b)
+                .cmp(&median(durations_a))
+                .then(name_a.cmp(name_b))
+        });
+        let name_width = phases.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+        writeln!(
+            f,
+            "{:name_width$}  {:>10}  {:>10}  {:>10}",
+            format!("phase ({} iterations)", self.iterations),
+            "min",
+            "median",
+            "max",
+            name_width = name_width.max(24)
+        )?;
+        for (name, durations) in phases {
+            writeln!(
+                f,
+                "{:name_width$}  {:>10}  {:>10}  {:>10}",
+                name,
+                format_duration(durations[0]),
+                format_duration(median(&durations)),
+                format_duration(durations[durations.len() - 1]),
+                name_width = name_width.max(24)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Compiles the project of the config file `iterations` times from scratch,
+/// removing its artifacts before each compilation, and reports the duration
+/// of each phase.
+pub async fn run_benchmark(config_path: &Path, iterations: usize) -> Result<BenchReport> {
+    let mut config = Config::load(config_path.to_path_buf())?;
+    // Watchman would add the variance of its queries to the timings.
+    config.file_source_config = FileSourceKind::WalkDir;
+    let config = Arc::new(config);
+    let perf_logger = Arc::new(BenchPerfLogger::default());
+    let compiler = Compiler::new(Arc::clone(&config), Arc::clone(&perf_logger));
+
+    let mut report = BenchReport {
+        iterations,
+        ..Default::default()
+    };
+    for _ in 0..iterations {
+        for project_config in config.projects.values() {
+            if let Some(output) = &project_config.output {
+                remove_dir_contents(&config.root_dir.join(output))?;
+            }
+        }
+        let start = Instant::now();
+        compiler.compile().await?;
+        let total = start.elapsed();
+        for (name, duration) in perf_logger.take_timings() {
+            report.phases.entry(name).or_default().push(duration);
+        }
+        report.phases.entry(TOTAL_TIMER).or_default().push(total);
+    }
+    Ok(report)
+}
+
+fn remove_dir_contents(dir: &Path) -> Result<()> {
+    let entries = fs::read_dir(dir).map_err(|err| Error::ReadFileError {
+        file: dir.to_path_buf(),
+        source: err,
+    })?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let result = if path.is_dir() {
+            fs::remove_dir_all(&path)
+        } else {
+            fs::remove_file(&path)
+        };
+        result.map_err(|err| Error::WriteFileError {
+            file: path,
+            source: err,
+        })?;
+    }
+    Ok(())
+}
+
+fn median(sorted_durations: &[Duration]) -> Duration {
+    sorted_durations[sorted_durations.len() / 2]
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.1}ms", duration.as_secs_f64() * 1000.0)
+}
@@ -0,0 +1,241 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// The size and shape of a generated project.
+#[derive(Clone, Debug)]
+pub struct SyntheticProjectOptions {
+    /// Number of fragments, each in its own source file.
+    pub fragments: usize,
+    /// How deep the selections of each fragment are nested.
+    pub depth: usize,
+    /// Share of the fragments (between 0 and 1) that select fields of an
+    /// interface with inline fragments on its implementations.
+    pub abstract_ratio: f64,
+    /// Number of fragments spread by each query. Every fragment also spreads
+    /// the previous fragment of its query.
+    pub fragments_per_query: usize,
+}
+
+impl Default for SyntheticProjectOptions {
+    fn default() -> Self {
+        Self {
+            fragments: 1000,
+            depth: 3,
+            abstract_ratio: 0.2,
+            fragments_per_query: 10,
+        }
+    }
+}
+
+/// Returns the relative paths and contents of the files of a project with the
+/// given shape: a `relay.config.json`, the schema and the source files. The
+/// output is deterministic, so timings of different runs can be compared. The
+/// config uses the multi project format, which resolves paths relative to the
+/// config file instead of the working directory.
+pub fn generate_synthetic_project(options: &SyntheticProjectOptions) -> Vec<(PathBuf, String)> {
+    let mut files = vec![
+        (
+            PathBuf::from("relay.config.json"),
+            r#"{
+  "sources": {
+    "src": "bench"
+  },
+  "projects": {
+    "bench": {
+      "schema": "schema.graphql",
+      "language": "javascript",
+      "output": "__generated__"
+    }
+  }
+}
+"#
+            .to_string(),
+        ),
+        (
+            PathBuf::from("schema.graphql"),
+            generate_schema(options.depth),
+        ),
+    ];
+
+    let fragments_per_query = options.fragments_per_query.max(1);
+    for index in 0..options.fragments {
+        let previous = if index % fragments_per_query == 0 {
+            None
+        } else {
+            Some(index - 1)
+        };
+        files.push((
+            PathBuf::from(format!("src/{}.js", fragment_module(index))),
+            source_file(&generate_fragment(index, previous, options)),
+        ));
+    }
+    let fragment_indices = (0..options.fragments).collect::<Vec<_>>();
+    for (query_index, fragment_indices) in fragment_indices.chunks(fragments_per_query).enumerate()
+    {
+        files.push((
+            PathBuf::from(format!("src/BenchQuery{}.js", query_index)),
+            source_file(&generate_query(query_index, fragment_indices)),
+        ));
+    }
+    files
+}
+
+/// Writes the files of a generated project and its (empty) artifact directory
+/// to `root_dir` and returns the path of its config file.
+pub fn write_synthetic_project(
+    root_dir: &Path,
+    options: &SyntheticProjectOptions,
+) -> io::Result<PathBuf> {
+    fs::create_dir_all(root_dir.join("__generated__"))?;
+    for (path, content) in generate_synthetic_project(options) {
+        let path = root_dir.join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, content)?;
+    }
+    Ok(root_dir.join("relay.config.json"))
+}
+
+/// A chain of `Type0`, `Type1`, ... with one more type than the depth, that
+/// all implement the `Entity` interface.
+fn generate_schema(depth: usize) -> String {
+    let mut schema = String::from(
+        "type Query {
+  node(id: ID!): Node
+  viewer: Type0
+}
+
+interface Node {
+  id: ID!
+}
+
+interface Entity {
+  id: ID!
+  name: String
+}
+",
+    );
+    for level in 0..=depth {
+        write!(
+            schema,
+            "
+type Type{level} implements Node & Entity {{
+  id: ID!
+  name: String
+  count: Int
+  enabled: Boolean
+  entity: Entity
+",
+            level = level
+        )
+        .unwrap();
+        if level < depth {
+            write!(
+                schema,
+                "  child: Type{next}
+  children(first: Int): [Type{next}]
+",
+                next = level + 1
+            )
+            .unwrap();
+        }
+        schema.push_str("}\n");
+    }
+    schema
+}
+
+fn generate_fragment(
+    index: usize,
+    previous: Option<usize>,
+    options: &SyntheticProjectOptions,
+) -> String {
+    let mut fragment = format!("fragment {}_data on Type0 {{\n", fragment_module(index));
+    if let Some(previous) = previous {
+        writeln!(fragment, "  ...{}_data", fragment_module(previous)).unwrap();
+    }
+    write_selections(
+        &mut fragment,
+        0,
+        options.depth,
+        is_abstract(index, options.abstract_ratio),
+    );
+    fragment.push('}');
+    fragment
+}
+
+fn write_selections(output: &mut String, level: usize, depth: usize, is_abstract: bool) {
+    let indentation = "  ".repeat(level + 1);
+    for field in ["id", "name", "count"] {
+        writeln!(output, "{}{}", indentation, field).unwrap();
+    }
+    if level < depth {
+        writeln!(output, "{}child {{", indentation).unwrap();
+        write_selections(output, level + 1, depth, is_abstract);
+        writeln!(output, "{}}}", indentation).unwrap();
+        if level == 0 {
+            writeln!(output, "{}children(first: 10) {{", indentation).unwrap();
+            writeln!(output, "{}  id", indentation).unwrap();
+            writeln!(output, "{}  enabled", indentation).unwrap();
+            writeln!(output, "{}}}", indentation).unwrap();
+        }
+    } else if is_abstract {
+        writeln!(output, "{}entity {{", indentation).unwrap();
+        writeln!(output, "{}  name", indentation).unwrap();
+        writeln!(output, "{}  ... on Type0 {{", indentation).unwrap();
+        writeln!(output, "{}    enabled", indentation).unwrap();
+        writeln!(output, "{}  }}", indentation).unwrap();
+        writeln!(output, "{}  ... on Type{} {{", indentation, depth).unwrap();
+        writeln!(output, "{}    count", indentation).unwrap();
+        writeln!(output, "{}  }}", indentation).unwrap();
+        writeln!(output, "{}}}", indentation).unwrap();
+    }
+}
+
+fn generate_query(query_index: usize, fragment_indices: &[usize]) -> String {
+    let mut query = format!(
+        "query BenchQuery{}Query($id: ID!) {{\n  viewer {{\n",
+        query_index
+    );
+    for index in fragment_indices {
+        writeln!(query, "    ...{}_data", fragment_module(*index)).unwrap();
+    }
+    query.push_str(
+        "  }
+  node(id: $id) {
+    id
+    ... on Type0 {
+      name
+    }
+  }
+}",
+    );
+    query
+}
+
+/// Spreads the abstract fragments evenly: fragment `index` is abstract if the
+/// number of abstract fragments up to it grows.
+fn is_abstract(index: usize, abstract_ratio: f64) -> bool {
+    ((index + 1) as f64 * abstract_ratio).floor() > (index as f64 * abstract_ratio).floor()
+}
+
+fn fragment_module(index: usize) -> String {
+    format!("BenchComponent{}", index)
+}
+
+fn source_file(document: &str) -> String {
+    format!(
+        "const {{graphql}} = require('relay-runtime');\n\ngraphql`\n{}\n`;\n",
+        document
+    )
+}
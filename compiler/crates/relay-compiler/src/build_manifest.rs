@@ -0,0 +1,207 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! A manifest of the artifacts of the projects, written after each build to
+//! the file of the `buildManifest` config option. It maps every artifact to
+//! the definitions and source files it is generated from, with its persisted
+//! id and the hash of its content, for tooling that acts on the changes of
+//! the artifacts (e.g. to invalidate caches of a deployment).
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use fnv::FnvHashMap;
+use intern::string_key::StringKey;
+use md5::Digest;
+use md5::Md5;
+use serde::Serialize;
+
+use crate::compiler_state::ArtifactMapKind;
+use crate::compiler_state::ProjectName;
+
+const VERSION: u32 = 1;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildManifestArtifact {
+    /// The artifact, relative to the root directory.
+    pub path: PathBuf,
+    pub project: ProjectName,
+    /// The source files of the definitions, relative to the root directory.
+    /// Empty for artifacts generated from the schema.
+    pub source_files: Vec<PathBuf>,
+    pub definition_names: Vec<StringKey>,
+    pub persisted_id: Option<String>,
+    /// The md5 of the content of the artifact, `None` if it isn't on disk.
+    pub content_hash: Option<String>,
+}
+
+impl BuildManifestArtifact {
+    fn new(path: &Path, project: ProjectName) -> Self {
+        Self {
+            path: path.to_owned(),
+            project,
+            source_files: vec![],
+            definition_names: vec![],
+            persisted_id: None,
+            content_hash: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct BuildManifestFile {
+    version: u32,
+    artifacts: Vec<BuildManifestArtifact>,
+}
+
+/// The artifacts of the projects with a known artifact map, sorted by path.
+/// The projects compiled without a saved state have one after their first
+/// build.
+pub fn build_manifest_artifacts(
+    root_dir: &Path,
+    artifact_maps: &FnvHashMap<ProjectName, Arc<ArtifactMapKind>>,
+) -> Vec<BuildManifestArtifact> {
+    let mut artifacts: BTreeMap<PathBuf, BuildManifestArtifact> = BTreeMap::new();
+    for (project_name, artifact_map) in artifact_maps {
+        let artifact_map = match artifact_map.as_ref() {
+            ArtifactMapKind::Mapping(artifact_map) => artifact_map,
+            ArtifactMapKind::Unconnected(_) => continue,
+        };
+        for entry in artifact_map.0.iter() {
+            for record in entry.value() {
+                let artifact = artifacts
+                    .entry(record.path.clone())
+                    .or_insert_with(|| BuildManifestArtifact::new(&record.path, *project_name));
+                artifact.definition_names.push(*entry.key());
+                if let Some(source_file) = &record.source_file {
+                    artifact.source_files.push(source_file.clone());
+                }
+                if record.persisted_operation_id.is_some() {
+                    artifact.persisted_id = record.persisted_operation_id.clone();
+                }
+            }
+        }
+    }
+    artifacts
+        .into_values()
+        .map(|mut artifact| {
+            artifact.definition_names.sort();
+            artifact.definition_names.dedup();
+            artifact.source_files.sort();
+            artifact.source_files.dedup();
+            artifact.content_hash = fs::read(root_dir.join(&artifact.path))
+                .ok()
+                .map(|content| md5(&content));
+            artifact
+        })
+        .collect()
+}
+
+/// Writes the manifest of the artifacts to `path`.
+pub fn write_build_manifest(
+    path: &Path,
+    root_dir: &Path,
+    artifact_maps: &FnvHashMap<ProjectName, Arc<ArtifactMapKind>>,
+) -> io::Result<()> {
+    let file = BuildManifestFile {
+        version: VERSION,
+        artifacts: build_manifest_artifacts(root_dir, artifact_maps),
+    };
+    let mut content = serde_json::to_string_pretty(&file)?;
+    content.push('\n');
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content)
+}
+
+fn md5(data: &[u8]) -> String {
+    let mut md5 = Md5::new();
+    md5.update(data);
+    hex::encode(md5.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use intern::string_key::Intern;
+
+    use super::*;
+    use crate::artifact_map::ArtifactMap;
+    use crate::artifact_map::ArtifactRecord;
+
+    #[test]
+    fn artifacts_of_definitions() {
+        let root_dir =
+            env::temp_dir().join(format!("relay-build-manifest-test-{}", std::process::id()));
+        fs::create_dir_all(root_dir.join("src/__generated__")).unwrap();
+        fs::write(
+            root_dir.join("src/__generated__/FooQuery.graphql.js"),
+            "content",
+        )
+        .unwrap();
+
+        let query_record = ArtifactRecord {
+            path: PathBuf::from("src/__generated__/FooQuery.graphql.js"),
+            persisted_operation_id: Some("123".to_string()),
+            source_file: Some(PathBuf::from("src/Foo.js")),
+        };
+        let artifact_map = ArtifactMap::default();
+        artifact_map
+            .0
+            .insert("FooQuery".intern(), vec![query_record.clone()]);
+        // The refetch query of a fragment of another file
+        artifact_map.0.insert(
+            "Bar_user".intern(),
+            vec![
+                ArtifactRecord {
+                    path: PathBuf::from("src/__generated__/Bar_user.graphql.js"),
+                    persisted_operation_id: None,
+                    source_file: Some(PathBuf::from("src/Bar.js")),
+                },
+                ArtifactRecord {
+                    source_file: Some(PathBuf::from("src/Bar.js")),
+                    ..query_record
+                },
+            ],
+        );
+        let mut artifact_maps = FnvHashMap::default();
+        artifact_maps.insert(
+            "project".intern(),
+            Arc::new(ArtifactMapKind::Mapping(artifact_map)),
+        );
+
+        let artifacts = build_manifest_artifacts(&root_dir, &artifact_maps);
+        fs::remove_dir_all(&root_dir).unwrap();
+
+        assert_eq!(artifacts.len(), 2);
+        assert_eq!(
+            artifacts[0].path,
+            PathBuf::from("src/__generated__/Bar_user.graphql.js")
+        );
+        assert_eq!(artifacts[0].content_hash, None);
+        assert_eq!(
+            artifacts[1].definition_names,
+            vec!["Bar_user".intern(), "FooQuery".intern()]
+        );
+        assert_eq!(
+            artifacts[1].source_files,
+            vec![PathBuf::from("src/Bar.js"), PathBuf::from("src/Foo.js")]
+        );
+        assert_eq!(artifacts[1].persisted_id.as_deref(), Some("123"));
+        assert_eq!(
+            artifacts[1].content_hash.as_deref(),
+            Some("9a0364b9e99bb480dd25e1f0284c8555")
+        );
+    }
+}
@@ -9,6 +9,7 @@ use common::NamedItem;
 use graphql_ir::FragmentDefinition;
 use graphql_ir::OperationDefinition;
 use graphql_syntax::OperationKind;
+use relay_config::TypeNamesConfig;
 use relay_transforms::RefetchableMetadata;
 use relay_transforms::INLINE_DIRECTIVE_NAME;
 use relay_transforms::UPDATABLE_DIRECTIVE;
@@ -27,6 +28,7 @@ impl ArtifactGeneratedTypes {
         operation: &OperationDefinition,
         skip_types: bool,
         is_client_only: bool,
+        type_names: &TypeNamesConfig,
     ) -> Self {
         if skip_types {
             Self {
@@ -49,17 +51,21 @@ impl ArtifactGeneratedTypes {
                     "ConcreteRequest, GraphQLSubscription",
                 ),
             };
+            let name = operation.name.item.0;
             let exported_type = if has_raw_response_type_directive(operation) {
                 format!(
-                    "{kind}<\n  {name}$variables,\n  {name}$data,\n  {name}$rawResponse,\n>",
+                    "{kind}<\n  {variables},\n  {data},\n  {raw_response},\n>",
                     kind = kind,
-                    name = operation.name.item.0
+                    variables = type_names.variables(name),
+                    data = type_names.data(name),
+                    raw_response = type_names.raw_response(name),
                 )
             } else {
                 format!(
-                    "{kind}<\n  {name}$variables,\n  {name}$data,\n>",
+                    "{kind}<\n  {variables},\n  {data},\n>",
                     kind = kind,
-                    name = operation.name.item.0
+                    variables = type_names.variables(name),
+                    data = type_names.data(name),
                 )
             };
             Self {
@@ -74,7 +80,11 @@ impl ArtifactGeneratedTypes {
         }
     }
 
-    pub fn from_updatable_query(typegen_operation: &OperationDefinition, skip_types: bool) -> Self {
+    pub fn from_updatable_query(
+        typegen_operation: &OperationDefinition,
+        skip_types: bool,
+        type_names: &TypeNamesConfig,
+    ) -> Self {
         if skip_types {
             Self {
                 imported_types: "ConcreteUpdatableQuery",
@@ -82,9 +92,11 @@ impl ArtifactGeneratedTypes {
                 exported_type: None,
             }
         } else {
+            let name = typegen_operation.name.item.0;
             let exported_type = format!(
-                "UpdatableQuery<\n  {name}$variables,\n  {name}$data,\n>",
-                name = typegen_operation.name.item.0,
+                "UpdatableQuery<\n  {variables},\n  {data},\n>",
+                variables = type_names.variables(name),
+                data = type_names.data(name),
             );
             Self {
                 imported_types: "UpdatableQuery, ConcreteUpdatableQuery",
@@ -94,7 +106,11 @@ impl ArtifactGeneratedTypes {
         }
     }
 
-    pub fn from_fragment(fragment: &FragmentDefinition, skip_types: bool) -> Self {
+    pub fn from_fragment(
+        fragment: &FragmentDefinition,
+        skip_types: bool,
+        type_names: &TypeNamesConfig,
+    ) -> Self {
         let is_inline_data_fragment = fragment.directives.named(*INLINE_DIRECTIVE_NAME).is_some();
         let is_updatable_fragment = fragment.directives.named(*UPDATABLE_DIRECTIVE).is_some();
 
@@ -117,8 +133,9 @@ impl ArtifactGeneratedTypes {
                 imported_types: "InlineFragment, ReaderInlineDataFragment",
                 ast_type: "ReaderInlineDataFragment",
                 exported_type: Some(format!(
-                    "InlineFragment<\n  {name}$fragmentType,\n  {name}$data,\n>",
-                    name = fragment.name.item
+                    "InlineFragment<\n  {fragment_type},\n  {data},\n>",
+                    fragment_type = type_names.fragment_type(fragment.name.item),
+                    data = type_names.data(fragment.name.item),
                 )),
             }
         } else if let Some(refetchable_metadata) = RefetchableMetadata::find(&fragment.directives) {
@@ -126,9 +143,10 @@ impl ArtifactGeneratedTypes {
                 imported_types: "ReaderFragment, RefetchableFragment",
                 ast_type: "ReaderFragment",
                 exported_type: Some(format!(
-                    "RefetchableFragment<\n  {name}$fragmentType,\n  {name}$data,\n  {refetchable_name}$variables,\n>",
-                    name = fragment.name.item,
-                    refetchable_name = refetchable_metadata.operation_name
+                    "RefetchableFragment<\n  {fragment_type},\n  {data},\n  {variables},\n>",
+                    fragment_type = type_names.fragment_type(fragment.name.item),
+                    data = type_names.data(fragment.name.item),
+                    variables = type_names.variables(refetchable_metadata.operation_name),
                 )),
             }
         } else if is_updatable_fragment {
@@ -136,8 +154,9 @@ impl ArtifactGeneratedTypes {
                 imported_types: "UpdatableFragment, ReaderFragment",
                 ast_type: "ReaderFragment",
                 exported_type: Some(format!(
-                    "UpdatableFragment<\n  {name}$fragmentType,\n  {name}$data,\n>",
-                    name = fragment.name.item
+                    "UpdatableFragment<\n  {fragment_type},\n  {data},\n>",
+                    fragment_type = type_names.fragment_type(fragment.name.item),
+                    data = type_names.data(fragment.name.item),
                 )),
             }
         } else {
@@ -145,8 +164,9 @@ impl ArtifactGeneratedTypes {
                 imported_types: "Fragment, ReaderFragment",
                 ast_type: "ReaderFragment",
                 exported_type: Some(format!(
-                    "Fragment<\n  {name}$fragmentType,\n  {name}$data,\n>",
-                    name = fragment.name.item
+                    "Fragment<\n  {fragment_type},\n  {data},\n>",
+                    fragment_type = type_names.fragment_type(fragment.name.item),
+                    data = type_names.data(fragment.name.item),
                 )),
             }
         }
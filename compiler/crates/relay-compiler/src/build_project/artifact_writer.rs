@@ -18,6 +18,7 @@ use log::info;
 use serde::Serialize;
 use serde::Serializer;
 
+use super::schema_hash::is_generated_from_different_schema;
 use crate::errors::BuildProjectError;
 use crate::errors::Error;
 
@@ -244,6 +245,8 @@ pub struct ArtifactValidationWriter {
     added: DashSet<PathBuf>,
     updated: DashSet<PathBuf>,
     removed: DashSet<PathBuf>,
+    /// Updated artifacts that were generated from a different schema.
+    schema_changed: DashSet<PathBuf>,
 }
 
 impl ArtifactWriter for ArtifactValidationWriter {
@@ -254,8 +257,11 @@ impl ArtifactWriter for ArtifactValidationWriter {
         })
     }
 
-    fn write(&self, path: PathBuf, _: Vec<u8>) -> BuildProjectResult {
+    fn write(&self, path: PathBuf, content: Vec<u8>) -> BuildProjectResult {
         if path.exists() {
+            if is_generated_from_different_schema(&path, &content) {
+                self.schema_changed.insert(path.clone());
+            }
             self.updated.insert(path);
         } else {
             self.added.insert(path);
@@ -275,6 +281,11 @@ impl ArtifactWriter for ArtifactValidationWriter {
         write_outdated_artifacts(&mut output, "\nOut of date:", &self.updated);
         write_outdated_artifacts(&mut output, "\nMissing:", &self.added);
         write_outdated_artifacts(&mut output, "\nExtra:", &self.removed);
+        write_outdated_artifacts(
+            &mut output,
+            "\nGenerated from a different schema:",
+            &self.schema_changed,
+        );
 
         if output.is_empty() {
             Ok(())
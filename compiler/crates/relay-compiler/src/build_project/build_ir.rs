@@ -5,15 +5,28 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::path::Path;
+use std::path::PathBuf;
+
 use common::Diagnostic;
+use common::Location;
+use common::SourceLocationKey;
 use dependency_analyzer::get_reachable_ir;
 use fnv::FnvHashMap;
+use fnv::FnvHashSet;
+use graphql_ir::Selection;
 use graphql_syntax::ExecutableDefinition;
 use graphql_text_printer::print_executable_definition_ast;
 use intern::string_key::StringKey;
+use intern::string_key::StringKeySet;
+use intern::Lookup;
 use md5::Digest;
 use md5::Md5;
+use schema::FieldID;
+use schema::ObjectID;
 use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
 
 use super::ProjectAsts;
 use crate::config::ProjectConfig;
@@ -52,10 +65,18 @@ pub fn build_ir(
     let source_hashes = SourceHashes::from_definitions(&asts);
     let ir = graphql_ir::build_ir_in_relay_mode(schema, &asts)?;
     if is_incremental_build {
+        let mut changed_names = project_asts.changed_names;
+        if !project_asts.changed_docblock_files.is_empty() {
+            changed_names.extend(find_definitions_using_files(
+                &ir,
+                schema,
+                &project_asts.changed_docblock_files,
+            ));
+        }
         let affected_ir = get_reachable_ir(
             ir,
             project_asts.base_definition_names,
-            project_asts.changed_names,
+            changed_names,
             schema,
         );
         Ok(BuildIRResult {
@@ -67,8 +88,162 @@ pub fn build_ir(
     }
 }
 
+/// The names of the definitions that select a field defined in one of the
+/// `files`, or a field of a type defined in them or returning it. The fields
+/// and types of Relay Resolvers are located in the files of their docblocks.
+/// Interfaces and unions are also affected by the types of the files that
+/// implement them or are their members.
+fn find_definitions_using_files(
+    ir: &[graphql_ir::ExecutableDefinition],
+    schema: &SDLSchema,
+    files: &FnvHashSet<PathBuf>,
+) -> StringKeySet {
+    let is_in_files = |location: Location| match location.source_location() {
+        SourceLocationKey::Embedded { path, .. } => files.contains(Path::new(path.lookup())),
+        _ => false,
+    };
+    let is_object_in_files = |id: &ObjectID| is_in_files(schema.object(*id).name.location);
+    let is_type_in_files = |type_: Type| match type_ {
+        Type::Object(id) => is_object_in_files(&id),
+        Type::Interface(id) => {
+            let interface = schema.interface(id);
+            is_in_files(interface.name.location)
+                || interface
+                    .implementing_objects
+                    .iter()
+                    .any(is_object_in_files)
+        }
+        Type::Union(id) => {
+            let union = schema.union(id);
+            is_in_files(union.name.location) || union.members.iter().any(is_object_in_files)
+        }
+        Type::Scalar(id) => is_in_files(schema.scalar(id).name.location),
+        _ => false,
+    };
+    let is_field_in_files = |field_id: FieldID| {
+        let field = schema.field(field_id);
+        is_in_files(field.name.location)
+            || is_type_in_files(field.type_.inner())
+            || field.parent_type.map_or(false, is_type_in_files)
+    };
+    ir.iter()
+        .filter(|definition| match definition {
+            graphql_ir::ExecutableDefinition::Operation(operation) => {
+                selections_use(&operation.selections, &is_field_in_files, &is_type_in_files)
+            }
+            graphql_ir::ExecutableDefinition::Fragment(fragment) => {
+                is_type_in_files(fragment.type_condition)
+                    || selections_use(&fragment.selections, &is_field_in_files, &is_type_in_files)
+            }
+        })
+        .map(|definition| definition.name_with_location().item)
+        .collect()
+}
+
+fn selections_use(
+    selections: &[Selection],
+    is_field_in_files: &impl Fn(FieldID) -> bool,
+    is_type_in_files: &impl Fn(Type) -> bool,
+) -> bool {
+    selections.iter().any(|selection| match selection {
+        Selection::ScalarField(field) => is_field_in_files(field.definition.item),
+        Selection::LinkedField(field) => {
+            is_field_in_files(field.definition.item)
+                || selections_use(&field.selections, is_field_in_files, is_type_in_files)
+        }
+        Selection::InlineFragment(fragment) => {
+            fragment.type_condition.map_or(false, is_type_in_files)
+                || selections_use(&fragment.selections, is_field_in_files, is_type_in_files)
+        }
+        Selection::Condition(condition) => {
+            selections_use(&condition.selections, is_field_in_files, is_type_in_files)
+        }
+        Selection::FragmentSpread(_) => false,
+    })
+}
+
 fn md5(data: &str) -> String {
     let mut md5 = Md5::new();
     md5.update(data);
     hex::encode(md5.finalize())
 }
+
+#[cfg(test)]
+mod tests {
+    use graphql_syntax::parse_executable;
+    use relay_schema::build_schema_with_extensions;
+
+    use super::*;
+
+    const SCHEMA: &str = r#"
+        type Query { me: User node: Node }
+        interface Node { id: ID! }
+        type User implements Node { id: ID! name: String }
+    "#;
+
+    fn build_incremental_ir(changed_docblock_files: &[&str]) -> Vec<String> {
+        let schema = build_schema_with_extensions(
+            &[(SCHEMA, SourceLocationKey::generated())],
+            &[
+                (
+                    "extend type User { greeting: String }",
+                    SourceLocationKey::embedded("src/UserGreetingResolver.js", 0),
+                ),
+                (
+                    "type Comment implements Node { id: ID! }",
+                    SourceLocationKey::embedded("src/CommentResolver.js", 0),
+                ),
+                (
+                    "union SearchResult = User | Comment extend type Query { search: SearchResult }",
+                    SourceLocationKey::embedded("src/SearchResolver.js", 0),
+                ),
+            ],
+        )
+        .unwrap();
+        let definitions = parse_executable(
+            r#"
+                query ProfileQuery { me { ...ProfileGreeting_user } }
+                fragment ProfileGreeting_user on User { greeting }
+                query SettingsQuery { me { name } }
+                query NodeQuery { node { id } }
+                fragment Node_node on Node { id }
+                query SearchQuery { search { __typename } }
+            "#,
+            SourceLocationKey::standalone("src/Profile.graphql"),
+        )
+        .unwrap()
+        .definitions;
+        let project_asts = ProjectAsts {
+            changed_names: Default::default(),
+            changed_docblock_files: changed_docblock_files.iter().map(PathBuf::from).collect(),
+            base_definition_names: Default::default(),
+            definitions,
+        };
+        let BuildIRResult { ir, .. } =
+            build_ir(&Default::default(), project_asts, &schema, true).unwrap();
+        let mut names: Vec<_> = ir
+            .iter()
+            .map(|definition| definition.name_with_location().item.to_string())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn docblock_change_rebuilds_only_the_definitions_using_it_test() {
+        assert_eq!(
+            build_incremental_ir(&["src/UserGreetingResolver.js"]),
+            vec!["ProfileGreeting_user", "ProfileQuery"]
+        );
+        assert!(build_incremental_ir(&["src/OtherResolver.js"]).is_empty());
+    }
+
+    #[test]
+    fn docblock_change_rebuilds_the_definitions_using_its_abstract_types_test() {
+        // `Comment` implements `Node` and is a member of `SearchResult`
+        assert_eq!(
+            build_incremental_ir(&["src/CommentResolver.js"]),
+            vec!["NodeQuery", "Node_node", "SearchQuery"]
+        );
+    }
+}
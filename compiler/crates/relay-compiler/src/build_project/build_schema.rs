@@ -9,6 +9,8 @@ use std::sync::Arc;
 
 use common::DiagnosticsResult;
 use fnv::FnvHashMap;
+use relay_config::SchemaConfig;
+use schema::BuildSchemaOptions;
 use schema::SDLSchema;
 
 use super::build_resolvers_schema::extend_schema_with_resolvers;
@@ -17,6 +19,15 @@ use crate::compiler_state::ProjectName;
 use crate::config::ProjectConfig;
 use crate::GraphQLAsts;
 
+/// The options to build the schema of a project with `schema_config`.
+pub fn schema_options(schema_config: &SchemaConfig) -> BuildSchemaOptions<'static> {
+    if schema_config.federation {
+        relay_schema::FEDERATION_SCHEMA_OPTIONS
+    } else {
+        Default::default()
+    }
+}
+
 pub fn build_schema(
     compiler_state: &CompilerState,
     project_config: &ProjectConfig,
@@ -46,8 +57,18 @@ pub fn build_schema(
                     .into_iter()
                     .map(|(schema, location_key)| (schema.as_str(), location_key)),
             );
-            let mut schema =
-                relay_schema::build_schema_with_extensions(&schema_sources, &extensions)?;
+            let options = BuildSchemaOptions {
+                removed_coordinates: match &project_config.schema_variant {
+                    Some(schema_variant) => &schema_variant.removed_coordinates,
+                    None => &[],
+                },
+                ..schema_options(&project_config.schema_config)
+            };
+            let mut schema = relay_schema::build_schema_with_extensions_and_options(
+                &schema_sources,
+                &extensions,
+                options,
+            )?;
 
             if project_config.feature_flags.enable_relay_resolver_transform {
                 extend_schema_with_resolvers(
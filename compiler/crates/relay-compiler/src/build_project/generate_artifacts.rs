@@ -5,6 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -15,8 +16,10 @@ use graphql_ir::FragmentDefinition;
 use graphql_ir::OperationDefinition;
 use graphql_text_printer::OperationPrinter;
 use graphql_text_printer::PrinterOptions;
+use indexmap::IndexMap;
 use intern::string_key::StringKey;
 use intern::Lookup;
+use relay_config::JsModuleFormat;
 use relay_transforms::ClientEdgeGeneratedQueryMetadataDirective;
 use relay_transforms::Programs;
 use relay_transforms::RawResponseGenerationMode;
@@ -26,9 +29,16 @@ use relay_transforms::CLIENT_EDGE_GENERATED_FRAGMENT_KEY;
 use relay_transforms::CLIENT_EDGE_SOURCE_NAME;
 use relay_transforms::DIRECTIVE_SPLIT_OPERATION;
 use relay_transforms::UPDATABLE_DIRECTIVE;
+use relay_typegen::referenced_enums;
+use relay_typegen::uses_flow_enums;
+use relay_typegen::TypegenLanguage;
+use schema::EnumID;
+use schema::SDLSchema;
+use schema::Schema;
 
 pub use super::artifact_content::ArtifactContent;
 use super::build_ir::SourceHashes;
+use crate::config::BuildOnly;
 use crate::config::Config;
 use crate::config::ProjectConfig;
 
@@ -43,7 +53,7 @@ pub struct Artifact {
 }
 
 pub fn generate_artifacts(
-    _config: &Config,
+    config: &Config,
     project_config: &ProjectConfig,
     programs: &Programs,
     source_hashes: Arc<SourceHashes>,
@@ -55,8 +65,10 @@ pub fn generate_artifacts(
             .is_fully_enabled(),
         ..Default::default()
     };
-    let mut operation_printer = OperationPrinter::new(&programs.operation_text, printer_options);
-    return group_operations(programs)
+    // The builds of the types only don't print the text of the operations.
+    let mut operation_printer = (config.only != Some(BuildOnly::Typegen))
+        .then(|| OperationPrinter::new(&programs.operation_text, printer_options));
+    let mut artifacts: Vec<Artifact> = group_operations(programs)
         .into_iter()
         .map(|(_, operations)| -> Artifact {
             if let Some(normalization) = operations.normalization {
@@ -96,7 +108,7 @@ pub fn generate_artifacts(
                     let source_hash = source_hashes.get(&source_name.0).cloned().unwrap();
 
                     return generate_normalization_artifact(
-                        &mut operation_printer,
+                        operation_printer.as_mut(),
                         source_name.0,
                         project_config,
                         &operations,
@@ -113,7 +125,7 @@ pub fn generate_artifacts(
                         .source_location();
                     let source_hash = source_hashes.get(&source_name).cloned().unwrap();
                     return generate_normalization_artifact(
-                        &mut operation_printer,
+                        operation_printer.as_mut(),
                         source_name,
                         project_config,
                         &operations,
@@ -126,7 +138,7 @@ pub fn generate_artifacts(
                         .cloned()
                         .unwrap();
                     return generate_normalization_artifact(
-                        &mut operation_printer,
+                        operation_printer.as_mut(),
                         normalization.name.item.0,
                         project_config,
                         &operations,
@@ -184,10 +196,172 @@ pub fn generate_artifacts(
             )
         }))
         .collect();
+
+    if project_config.typegen_config.generate_mock_factories {
+        artifacts.extend(generate_mock_factory_artifacts(project_config, programs));
+    }
+    if uses_flow_enums(&project_config.typegen_config) {
+        artifacts.extend(generate_flow_enum_artifacts(project_config, programs));
+    }
+    artifacts
+}
+
+/// The operation registry maps the operations of the project, with their
+/// name and source file, to the modules of their artifacts. It lists all the
+/// operations, including the ones unchanged in an incremental build.
+pub fn generate_operation_registry_artifact(
+    project_config: &ProjectConfig,
+    mut operations: Vec<(StringKey, SourceLocationKey)>,
+) -> Option<Artifact> {
+    let registry_path = project_config.typegen_config.operation_registry.as_ref()?;
+    if project_config.typegen_config.language == TypegenLanguage::JavaScript {
+        return None;
+    }
+    operations.sort_by_key(|(name, _)| name.lookup());
+    let registry_dir = registry_path.parent().unwrap_or_else(|| Path::new(""));
+    let artifact_modules = operations
+        .iter()
+        .map(|(name, source_file)| {
+            let artifact_path = project_config.path_for_artifact(*source_file, *name);
+            let module_name = artifact_path.file_stem().unwrap().to_string_lossy();
+            let module = match project_config.js_module_format {
+                JsModuleFormat::Haste => module_name.to_string(),
+                JsModuleFormat::CommonJS => {
+                    let artifact_dir = artifact_path.parent().unwrap_or_else(|| Path::new(""));
+                    let module = pathdiff::diff_paths(artifact_dir, registry_dir)
+                        .unwrap()
+                        .join(module_name.as_ref())
+                        .to_string_lossy()
+                        .replace('\\', "/");
+                    if module.starts_with('.') {
+                        module
+                    } else {
+                        format!("./{}", module)
+                    }
+                }
+            };
+            (*name, module)
+        })
+        .collect();
+    Some(Artifact {
+        source_definition_names: operations.iter().map(|(name, _)| *name).collect(),
+        path: registry_path.clone(),
+        content: ArtifactContent::OperationRegistry {
+            operations: artifact_modules,
+        },
+        source_file: SourceLocationKey::generated(),
+    })
+}
+
+/// The client schema artifact is the SDL of what the client schema extensions
+/// and Relay Resolvers add to the server schema, without the definitions that
+/// Relay adds to every schema.
+pub fn generate_client_schema_artifact(
+    project_config: &ProjectConfig,
+    schema: &SDLSchema,
+) -> Option<Artifact> {
+    let client_schema_path = project_config.client_schema_output.as_ref()?;
+    let relay_extensions = relay_schema::build_relay_extensions_schema()
+        .expect("Expected the Relay extensions schema to be valid.");
+    Some(Artifact {
+        source_definition_names: vec![],
+        path: client_schema_path.clone(),
+        content: ArtifactContent::Generic {
+            content: schema_print::print_client_extensions(schema, &relay_extensions).into_bytes(),
+        },
+        source_file: SourceLocationKey::generated(),
+    })
+}
+
+/// A Flow Enum module is generated for each enum referenced by the
+/// definitions of the project, next to the schema that defines it. The
+/// module is kept as long as any of these definitions references the enum.
+fn generate_flow_enum_artifacts(
+    project_config: &ProjectConfig,
+    programs: &Programs,
+) -> Vec<Artifact> {
+    let schema = &programs.typegen.schema;
+    let mut definitions_by_enum: IndexMap<EnumID, Vec<StringKey>> = IndexMap::new();
+    let operations = programs.typegen.operations().map(|operation| {
+        (
+            operation.name.item.0,
+            referenced_enums(
+                schema,
+                &operation.variable_definitions,
+                &operation.selections,
+            ),
+        )
+    });
+    let fragments = programs.typegen.fragments().map(|fragment| {
+        (
+            fragment.name.item.0,
+            referenced_enums(schema, &fragment.variable_definitions, &fragment.selections),
+        )
+    });
+    for (definition_name, enum_ids) in operations.chain(fragments) {
+        for enum_id in enum_ids {
+            definitions_by_enum
+                .entry(enum_id)
+                .or_default()
+                .push(definition_name);
+        }
+    }
+
+    definitions_by_enum
+        .into_iter()
+        .map(|(enum_id, source_definition_names)| {
+            let enum_name = schema.enum_(enum_id).name;
+            let source_file = enum_name.location.source_location();
+            Artifact {
+                source_definition_names,
+                path: project_config.path_for_artifact(source_file, enum_name.item.0),
+                content: ArtifactContent::FlowEnum { enum_id },
+                source_file,
+            }
+        })
+        .collect()
+}
+
+/// Mock factories are generated for the operations of the project, but not
+/// for generated (e.g. refetch) or @updatable operations.
+fn generate_mock_factory_artifacts<'a>(
+    project_config: &'a ProjectConfig,
+    programs: &'a Programs,
+) -> impl Iterator<Item = Artifact> + 'a {
+    programs
+        .typegen
+        .operations()
+        .filter(|typegen_operation| {
+            programs
+                .source
+                .operation(typegen_operation.name.item)
+                .is_some()
+                && typegen_operation
+                    .directives
+                    .named(*UPDATABLE_DIRECTIVE)
+                    .is_none()
+        })
+        .map(|typegen_operation| {
+            let name = typegen_operation.name.item.0;
+            let source_file = typegen_operation.name.location.source_location();
+            let extension = match project_config.typegen_config.language {
+                TypegenLanguage::Flow | TypegenLanguage::JavaScript => "js",
+                TypegenLanguage::TypeScript => "ts",
+            };
+            Artifact {
+                source_definition_names: vec![name],
+                path: project_config
+                    .create_path_for_artifact(source_file, format!("{}.mock.{}", name, extension)),
+                content: ArtifactContent::MockFactory {
+                    typegen_operation: Arc::clone(typegen_operation),
+                },
+                source_file,
+            }
+        })
 }
 
 fn generate_normalization_artifact(
-    operation_printer: &mut OperationPrinter<'_>,
+    operation_printer: Option<&mut OperationPrinter<'_>>,
     source_definition_name: StringKey,
     project_config: &ProjectConfig,
     operations: &OperationGroup<'_>,
@@ -196,7 +370,8 @@ fn generate_normalization_artifact(
 ) -> Artifact {
     let text = operations
         .operation_text
-        .map(|operation| operation_printer.print(operation));
+        .zip(operation_printer)
+        .map(|(operation, operation_printer)| operation_printer.print(operation));
 
     let normalization = operations
         .normalization
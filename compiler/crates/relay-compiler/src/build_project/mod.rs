@@ -18,8 +18,9 @@ pub mod generate_extra_artifacts;
 mod log_program_stats;
 mod persist_operations;
 mod project_asts;
+mod schema_hash;
+mod source_comments;
 mod source_control;
-mod validate;
 
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -30,6 +31,7 @@ pub use build_ir::SourceHashes;
 pub use build_schema::build_schema;
 use common::sync::*;
 use common::Diagnostic;
+use common::DiagnosticSeverity;
 use common::PerfLogEvent;
 use common::PerfLogger;
 use common::WithDiagnostics;
@@ -39,6 +41,8 @@ use fnv::FnvBuildHasher;
 use fnv::FnvHashMap;
 use fnv::FnvHashSet;
 pub use generate_artifacts::generate_artifacts;
+use generate_artifacts::generate_client_schema_artifact;
+use generate_artifacts::generate_operation_registry_artifact;
 pub use generate_artifacts::Artifact;
 pub use generate_artifacts::ArtifactContent;
 use graphql_ir::FragmentDefinitionNameSet;
@@ -50,30 +54,39 @@ use log::warn;
 use rayon::iter::IntoParallelRefIterator;
 use rayon::slice::ParallelSlice;
 use relay_codegen::Printer;
+use relay_transforms::apply_configured_no_inline_directives;
 use relay_transforms::apply_transforms;
+pub use relay_transforms::validate;
+pub use relay_transforms::AdditionalValidations;
 use relay_transforms::CustomTransformsConfig;
+pub use relay_transforms::CustomValidation;
+use relay_transforms::DebugTransformsConfig;
 use relay_transforms::Programs;
 use relay_typegen::FragmentLocations;
 use schema::SDLSchema;
 pub use source_control::add_to_mercurial;
-pub use validate::validate;
-pub use validate::AdditionalValidations;
 
 use self::log_program_stats::print_stats;
+pub(crate) use self::persist_operations::read_persisted_id;
 pub use self::project_asts::find_duplicates;
 pub use self::project_asts::get_project_asts;
 pub use self::project_asts::ProjectAstData;
 pub use self::project_asts::ProjectAsts;
+pub(crate) use self::schema_hash::compute_schema_hash;
+use self::source_comments::SourceComments;
 use super::artifact_content;
 use crate::artifact_map::ArtifactMap;
 use crate::compiler_state::ArtifactMapKind;
 use crate::compiler_state::CompilerState;
 use crate::compiler_state::ProjectName;
+use crate::config::BuildOnly;
 use crate::config::Config;
 use crate::config::ProjectConfig;
 use crate::errors::BuildProjectError;
 use crate::file_source::SourceControlUpdateStatus;
 use crate::graphql_asts::GraphQLAsts;
+use crate::resolver_graph::ResolverGraph;
+use crate::suppressions::apply_suppressions;
 
 type BuildProjectOutput = WithDiagnostics<(ProjectName, Arc<SDLSchema>, Programs, Vec<Artifact>)>;
 type BuildProgramsOutput = WithDiagnostics<(Programs, Arc<SourceHashes>)>;
@@ -99,6 +112,22 @@ pub fn build_raw_program(
     log_event: &impl PerfLogEvent,
     is_incremental_build: bool,
 ) -> Result<(Program, SourceHashes), BuildProjectError> {
+    let experiment_variants = project_asts.experiment_variants();
+    if !experiment_variants.is_empty() {
+        let operation_names = experiment_variants
+            .iter()
+            .map(|operation_name| operation_name.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        log_event.string("experiment_variants", operation_names.clone());
+        info!(
+            "[{}] compiling {} experiment variant(s): {}",
+            project_config.name,
+            experiment_variants.len(),
+            operation_names
+        );
+    }
+
     // Build a type aware IR.
     let BuildIRResult { ir, source_hashes } = log_event.time("build_ir_time", || {
         build_ir::build_ir(project_config, project_asts, &schema, is_incremental_build).map_err(
@@ -110,10 +139,27 @@ pub fn build_raw_program(
     })?;
 
     // Turn the IR into a base Program.
-    let program = log_event.time("build_program_time", || {
+    let mut program = log_event.time("build_program_time", || {
         Program::from_definitions(schema, ir)
     });
 
+    let no_inline_fragments =
+        apply_configured_no_inline_directives(&mut program, &project_config.no_inline_fragments);
+    if !no_inline_fragments.is_empty() {
+        let fragment_names = no_inline_fragments
+            .iter()
+            .map(|fragment_name| fragment_name.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        log_event.string("configured_no_inline_fragments", fragment_names.clone());
+        info!(
+            "[{}] compiling {} fragment(s) as @no_inline from config: {}",
+            project_config.name,
+            no_inline_fragments.len(),
+            fragment_names
+        );
+    }
+
     Ok((program, source_hashes))
 }
 
@@ -125,15 +171,31 @@ pub fn validate_program(
 ) -> Result<Vec<Diagnostic>, BuildProjectError> {
     let timer = log_event.start("validate_time");
     log_event.number("validate_documents_count", program.document_count());
-    let result = validate(program, project_config, &config.additional_validations).map_or_else(
-        |errors| {
-            Err(BuildProjectError::ValidationErrors {
-                errors,
-                project_name: project_config.name,
-            })
-        },
-        |result| Ok(result.diagnostics),
-    );
+    let diagnostics = validate(
+        program,
+        project_config,
+        &config.additional_validations,
+        &config.custom_validations,
+    )
+    .map_or_else(|errors| errors, |result| result.diagnostics);
+    // Diagnostics suppressed by a comment or recorded in the baseline are
+    // tolerated, so the validations only fail on the errors left.
+    let mut diagnostics = apply_suppressions(&config.root_dir, program, diagnostics);
+    if let Some(baseline) = &config.baseline {
+        diagnostics = baseline.filter(&config.root_dir, diagnostics);
+    }
+    let result = if diagnostics
+        .iter()
+        .any(|diagnostic| diagnostic.severity() == DiagnosticSeverity::ERROR)
+    {
+        Err(diagnostics)
+    } else {
+        Ok(diagnostics)
+    }
+    .map_err(|errors| BuildProjectError::ValidationErrors {
+        errors,
+        project_name: project_config.name,
+    });
 
     log_event.stop(timer);
 
@@ -148,6 +210,7 @@ pub fn transform_program(
     perf_logger: Arc<impl PerfLogger + 'static>,
     log_event: &impl PerfLogEvent,
     custom_transforms_config: Option<&CustomTransformsConfig>,
+    debug_transforms: Option<&DebugTransformsConfig>,
 ) -> Result<Programs, BuildProjectFailure> {
     let timer = log_event.start("apply_transforms_time");
     let result = apply_transforms(
@@ -157,6 +220,7 @@ pub fn transform_program(
         perf_logger,
         Some(print_stats),
         custom_transforms_config,
+        debug_transforms,
     )
     .map_err(|errors| {
         BuildProjectFailure::Error(BuildProjectError::ValidationErrors {
@@ -213,6 +277,7 @@ pub fn build_programs(
         Arc::clone(&perf_logger),
         log_event,
         config.custom_transforms.as_ref(),
+        config.debug_transforms.as_ref(),
     )?;
 
     Ok(WithDiagnostics {
@@ -227,6 +292,32 @@ pub fn build_project(
     compiler_state: &CompilerState,
     graphql_asts_map: &FnvHashMap<ProjectName, GraphQLAsts>,
     perf_logger: Arc<impl PerfLogger + 'static>,
+) -> Result<BuildProjectOutput, BuildProjectFailure> {
+    let WithDiagnostics { item, diagnostics } = build_project_impl(
+        config,
+        project_config,
+        compiler_state,
+        graphql_asts_map,
+        perf_logger,
+    )
+    .map_err(|failure| match failure {
+        BuildProjectFailure::Error(error) => {
+            BuildProjectFailure::Error(override_error_severities(project_config, error))
+        }
+        BuildProjectFailure::Cancelled => BuildProjectFailure::Cancelled,
+    })?;
+    Ok(WithDiagnostics {
+        item,
+        diagnostics: override_severities(project_config, diagnostics)?,
+    })
+}
+
+fn build_project_impl(
+    config: &Config,
+    project_config: &ProjectConfig,
+    compiler_state: &CompilerState,
+    graphql_asts_map: &FnvHashMap<ProjectName, GraphQLAsts>,
+    perf_logger: Arc<impl PerfLogger + 'static>,
 ) -> Result<BuildProjectOutput, BuildProjectFailure> {
     let log_event = perf_logger.create_event("build_project");
     let build_time = log_event.start("build_project_time");
@@ -249,13 +340,21 @@ pub fn build_project(
     let ProjectAstData {
         project_asts,
         base_fragment_names,
-    } = get_project_asts(&schema, graphql_asts_map, project_config)?;
+    } = get_project_asts(&schema, compiler_state, graphql_asts_map, project_config)?;
 
     if compiler_state.should_cancel_current_build() {
         debug!("Build is cancelled: updates in source code/or new file changes are pending.");
         return Err(BuildProjectFailure::Cancelled);
     }
 
+    // The operation registry lists all the operations of the project, while
+    // the programs of an incremental build only have the changed ones.
+    let registry_operations = if project_config.typegen_config.operation_registry.is_some() {
+        project_asts.operations()
+    } else {
+        vec![]
+    };
+
     // Apply different transform pipelines to produce the `Programs`.
     let WithDiagnostics {
         item: (programs, source_hashes),
@@ -278,12 +377,17 @@ pub fn build_project(
 
     // Generate artifacts by collecting information from the `Programs`.
     let artifacts_timer = log_event.start("generate_artifacts_time");
-    let artifacts = generate_artifacts(
+    let mut artifacts = generate_artifacts(
         config,
         project_config,
         &programs,
         Arc::clone(&source_hashes),
     );
+    artifacts.extend(generate_operation_registry_artifact(
+        project_config,
+        registry_operations,
+    ));
+    artifacts.extend(generate_client_schema_artifact(project_config, &schema));
     log_event.stop(artifacts_timer);
 
     log_event.number(
@@ -299,6 +403,160 @@ pub fn build_project(
     })
 }
 
+/// Builds the IR of the project and runs the validations on it, without
+/// applying the transforms or generating any artifact. Used by the `lint`
+/// command, where only the diagnostics matter.
+pub fn lint_project(
+    config: &Config,
+    project_config: &ProjectConfig,
+    compiler_state: &CompilerState,
+    graphql_asts_map: &FnvHashMap<ProjectName, GraphQLAsts>,
+    perf_logger: Arc<impl PerfLogger + 'static>,
+) -> Result<Vec<Diagnostic>, BuildProjectError> {
+    lint_project_impl(
+        config,
+        project_config,
+        compiler_state,
+        graphql_asts_map,
+        perf_logger,
+    )
+    .map_err(|error| override_error_severities(project_config, error))
+    .and_then(|diagnostics| override_severities(project_config, diagnostics))
+}
+
+fn lint_project_impl(
+    config: &Config,
+    project_config: &ProjectConfig,
+    compiler_state: &CompilerState,
+    graphql_asts_map: &FnvHashMap<ProjectName, GraphQLAsts>,
+    perf_logger: Arc<impl PerfLogger + 'static>,
+) -> Result<Vec<Diagnostic>, BuildProjectError> {
+    let log_event = perf_logger.create_event("lint_project");
+    let lint_time = log_event.start("lint_project_time");
+    log_event.string("project", project_config.name.to_string());
+
+    let schema = log_event
+        .time("build_schema_time", || {
+            build_schema(compiler_state, project_config, graphql_asts_map)
+        })
+        .map_err(|errors| BuildProjectError::ValidationErrors {
+            errors,
+            project_name: project_config.name,
+        })?;
+
+    let ProjectAstData { project_asts, .. } =
+        get_project_asts(&schema, compiler_state, graphql_asts_map, project_config)?;
+    let (program, _) = build_raw_program(project_config, project_asts, schema, &log_event, false)?;
+    let diagnostics = validate_program(config, project_config, &program, &log_event)?;
+
+    log_event.stop(lint_time);
+    log_event.complete();
+    Ok(diagnostics)
+}
+
+/// Replaces the severity of the diagnostics reported by a project with the
+/// one configured in its `severityOverrides`, whichever phase reported them
+/// (building the schema or the IR, the validations or the transforms).
+/// Diagnostics promoted to errors fail the build.
+fn override_severities(
+    project_config: &ProjectConfig,
+    mut diagnostics: Vec<Diagnostic>,
+) -> Result<Vec<Diagnostic>, BuildProjectError> {
+    project_config
+        .diagnostic_report_config
+        .apply_severity_overrides(&mut diagnostics);
+    if diagnostics
+        .iter()
+        .any(|diagnostic| diagnostic.severity() == DiagnosticSeverity::ERROR)
+    {
+        Err(BuildProjectError::ValidationErrors {
+            errors: diagnostics,
+            project_name: project_config.name,
+        })
+    } else {
+        Ok(diagnostics)
+    }
+}
+
+/// Like `override_severities` for the diagnostics of a failed build. Demoting
+/// its errors can't recover the output of the phase that failed, so they keep
+/// their severity and only the other diagnostics are overridden.
+fn override_error_severities(
+    project_config: &ProjectConfig,
+    mut error: BuildProjectError,
+) -> BuildProjectError {
+    if let BuildProjectError::ValidationErrors { errors, .. } = &mut error {
+        let report_config = &project_config.diagnostic_report_config;
+        for diagnostic in errors
+            .iter_mut()
+            .filter(|diagnostic| diagnostic.severity() != DiagnosticSeverity::ERROR)
+        {
+            if let Some(severity) = report_config.severity_override(diagnostic) {
+                diagnostic.remap_severity(severity);
+            }
+        }
+    }
+    error
+}
+
+/// Builds the IR of the project, without the validations and the transforms,
+/// and returns the graph of the dependencies of its Relay Resolvers. Used by
+/// the `resolver-graph` command.
+pub fn build_resolver_graph(
+    project_config: &ProjectConfig,
+    compiler_state: &CompilerState,
+    graphql_asts_map: &FnvHashMap<ProjectName, GraphQLAsts>,
+    perf_logger: Arc<impl PerfLogger + 'static>,
+) -> Result<ResolverGraph, BuildProjectError> {
+    let log_event = perf_logger.create_event("build_resolver_graph");
+    log_event.string("project", project_config.name.to_string());
+
+    let schema = log_event
+        .time("build_schema_time", || {
+            build_schema(compiler_state, project_config, graphql_asts_map)
+        })
+        .map_err(|errors| BuildProjectError::ValidationErrors {
+            errors,
+            project_name: project_config.name,
+        })?;
+
+    let ProjectAstData { project_asts, .. } =
+        get_project_asts(&schema, compiler_state, graphql_asts_map, project_config)?;
+    let (program, _) = build_raw_program(project_config, project_asts, schema, &log_event, false)?;
+    let graph = log_event.time("build_resolver_graph_time", || ResolverGraph::new(&program));
+
+    log_event.complete();
+    Ok(graph)
+}
+
+/// Builds the IR of the project, without the validations and the transforms,
+/// for the codemods to find the selections they change.
+pub fn build_codemod_program(
+    project_config: &ProjectConfig,
+    compiler_state: &CompilerState,
+    graphql_asts_map: &FnvHashMap<ProjectName, GraphQLAsts>,
+    perf_logger: Arc<impl PerfLogger + 'static>,
+) -> Result<Program, BuildProjectError> {
+    let log_event = perf_logger.create_event("build_codemod_program");
+    log_event.string("project", project_config.name.to_string());
+
+    let schema = log_event
+        .time("build_schema_time", || {
+            build_schema(compiler_state, project_config, graphql_asts_map)
+        })
+        .map_err(|errors| BuildProjectError::ValidationErrors {
+            errors,
+            project_name: project_config.name,
+        })?;
+
+    let ProjectAstData { project_asts, .. } =
+        get_project_asts(&schema, compiler_state, graphql_asts_map, project_config)?;
+    let (program, _) = build_raw_program(project_config, project_asts, schema, &log_event, false)?;
+
+    log_event.complete();
+    Ok(program)
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn commit_project(
     config: &Config,
@@ -310,6 +568,8 @@ pub async fn commit_project(
     artifact_map: Arc<ArtifactMapKind>,
     // Definitions that are removed from the previous artifact map
     removed_definition_names: Vec<StringKey>,
+    // Source files that no longer generate the artifacts of the previous artifact map
+    removed_source_files: Vec<PathBuf>,
     // Dirty artifacts that should be removed if no longer in the artifacts map
     mut artifacts_to_remove: DashSet<PathBuf, FnvBuildHasher>,
     source_control_update_status: Arc<SourceControlUpdateStatus>,
@@ -319,14 +579,31 @@ pub async fn commit_project(
     let commit_time = log_event.start("commit_project_time");
 
     let fragment_locations = FragmentLocations::new(programs.typegen.fragments());
+    let source_comments = project_config
+        .debug_source_comments
+        .then(|| SourceComments::new(&config.root_dir, &programs.source));
     if source_control_update_status.is_started() {
         debug!("commit_project cancelled before persisting due to source control updates");
         return Err(BuildProjectFailure::Cancelled);
     }
 
+    // The other shards write and remove the other artifacts.
+    let in_shard = |path: &PathBuf| config.shard.map_or(true, |shard| shard.contains(path));
+    if let Some(shard) = config.shard {
+        artifacts.retain(|artifact| in_shard(&artifact.path));
+        debug!(
+            "[{}] {} artifacts in shard {}",
+            project_config.name,
+            artifacts.len(),
+            shard
+        );
+    }
+
+    // The builds of the types only have no operation text to persist.
     if let Some(operation_persister) = config
         .create_operation_persister
         .as_ref()
+        .filter(|_| config.only != Some(BuildOnly::Typegen))
         .and_then(|create_fn| create_fn(project_config))
     {
         let persist_operations_timer = log_event.start("persist_operations_time");
@@ -391,6 +668,7 @@ pub async fn commit_project(
                 should_stop_updating_artifacts,
                 &artifacts,
                 &fragment_locations,
+                source_comments.as_ref(),
             )?;
             for artifact in &artifacts {
                 if !existing_artifacts.remove(&artifact.path) {
@@ -406,8 +684,15 @@ pub async fn commit_project(
                 if should_stop_updating_artifacts() {
                     break;
                 }
+                if !in_shard(remaining_artifact) {
+                    continue;
+                }
                 let path = config.root_dir.join(remaining_artifact);
                 config.artifact_writer.remove(path)?;
+                config.hooks.record_removed(remaining_artifact);
+                config
+                    .events
+                    .record_removed(project_config.name, remaining_artifact);
             }
             log_event.stop(delete_artifacts_time);
             ArtifactMap::from(artifacts)
@@ -426,6 +711,7 @@ pub async fn commit_project(
                 should_stop_updating_artifacts,
                 &artifacts,
                 &fragment_locations,
+                source_comments.as_ref(),
             )?;
             artifacts.into_par_iter().for_each(|artifact| {
                 current_paths_map.insert(artifact);
@@ -439,6 +725,14 @@ pub async fn commit_project(
                         artifacts_to_remove.extend(artifacts.into_iter().map(|a| a.path));
                     }
                 }
+                // As well as the paths generated from removed source files, which
+                // are not always the paths of removed definitions (e.g. when renamed)
+                if !removed_source_files.is_empty() {
+                    artifacts_to_remove.extend(
+                        artifact_map
+                            .remove_source_files(&FnvHashSet::from_iter(removed_source_files)),
+                    );
+                }
                 // Update the artifact map, and delete any removed artifacts
                 current_paths_map.0.into_par_iter().for_each(
                     |(definition_name, artifact_records)| match artifact_map
@@ -478,7 +772,12 @@ pub async fn commit_project(
                 if should_stop_updating_artifacts() {
                     break;
                 }
-                config.artifact_writer.remove(config.root_dir.join(path))?;
+                if !in_shard(&path) {
+                    continue;
+                }
+                config.artifact_writer.remove(config.root_dir.join(&path))?;
+                config.hooks.record_removed(&path);
+                config.events.record_removed(project_config.name, &path);
             }
             log_event.stop(delete_artifacts_incremental_time);
 
@@ -524,9 +823,21 @@ fn write_artifacts<F: Fn() -> bool + Sync + Send>(
     should_stop_updating_artifacts: F,
     artifacts: &[Artifact],
     fragment_locations: &FragmentLocations,
+    source_comments: Option<&SourceComments<'_>>,
 ) -> Result<(), BuildProjectFailure> {
+    let schema_hash = if project_config.schema_hash_comment {
+        Some(schema_hash::compute_schema_hash(schema))
+    } else {
+        None
+    };
     artifacts.par_chunks(8192).try_for_each_init(
-        || Printer::with_dedupe(project_config),
+        || {
+            let printer = Printer::with_dedupe(project_config);
+            match source_comments {
+                Some(source_comments) => printer.with_source_comments(source_comments),
+                None => printer,
+            }
+        },
         |mut printer, artifacts| {
             for artifact in artifacts {
                 if should_stop_updating_artifacts() {
@@ -540,9 +851,14 @@ fn write_artifacts<F: Fn() -> bool + Sync + Send>(
                     schema,
                     artifact.source_file,
                     fragment_locations,
+                    schema_hash.as_deref(),
                 );
                 if config.artifact_writer.should_write(&path, &content)? {
                     config.artifact_writer.write(path, content)?;
+                    config.hooks.record_written(&artifact.path);
+                    config
+                        .events
+                        .record_written(project_config.name, &artifact.path);
                 }
             }
             Ok(())
@@ -550,3 +866,74 @@ fn write_artifacts<F: Fn() -> bool + Sync + Send>(
     )?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use common::Location;
+    use graphql_ir::ValidationMessage;
+    use intern::string_key::Intern;
+    use relay_config::DiagnosticLevel;
+    use relay_config::DiagnosticReportConfig;
+
+    use super::*;
+
+    fn project_config(severity_overrides: &[(&str, DiagnosticLevel)]) -> ProjectConfig {
+        ProjectConfig {
+            name: "test".intern(),
+            diagnostic_report_config: DiagnosticReportConfig {
+                severity_overrides: severity_overrides
+                    .iter()
+                    .map(|(kind, level)| (kind.to_string(), *level))
+                    .collect(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn warning() -> Diagnostic {
+        Diagnostic::warning(
+            ValidationMessage::ExpectedOperationName,
+            Location::generated(),
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_override_severities() {
+        assert!(override_severities(&project_config(&[]), vec![warning()]).is_ok());
+
+        // Promoting a diagnostic to an error fails the build
+        let project_config = project_config(&[("RELAY1007", DiagnosticLevel::Error)]);
+        match override_severities(&project_config, vec![warning()]) {
+            Err(BuildProjectError::ValidationErrors { errors, .. }) => {
+                assert_eq!(errors[0].severity(), DiagnosticSeverity::ERROR)
+            }
+            _ => panic!("Expected the build to fail"),
+        }
+    }
+
+    #[test]
+    fn test_override_error_severities() {
+        // The errors of a failed phase can't be demoted, the other
+        // diagnostics reported with them are overridden
+        let error = BuildProjectError::ValidationErrors {
+            errors: vec![
+                Diagnostic::error(
+                    ValidationMessage::ExpectedOperationName,
+                    Location::generated(),
+                ),
+                warning(),
+            ],
+            project_name: "test".intern(),
+        };
+        let project_config = project_config(&[("ExpectedOperationName", DiagnosticLevel::Info)]);
+        match override_error_severities(&project_config, error) {
+            BuildProjectError::ValidationErrors { errors, .. } => {
+                assert_eq!(errors[0].severity(), DiagnosticSeverity::ERROR);
+                assert_eq!(errors[1].severity(), DiagnosticSeverity::INFORMATION);
+            }
+            _ => panic!("Expected the validation errors"),
+        }
+    }
+}
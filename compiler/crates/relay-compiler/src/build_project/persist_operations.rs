@@ -118,6 +118,13 @@ pub async fn persist_operations(
     Ok(())
 }
 
+/// The persisted ID of a query text, if the artifact at `path` was generated
+/// from the same text by a previous build.
+pub(crate) fn read_persisted_id(path: &PathBuf, text: &str) -> Option<QueryID> {
+    let text_hash = md5(text);
+    extract_persist_id(path, &text_hash).map(|id| QueryID::Persisted { id, text_hash })
+}
+
 fn extract_persist_id(path: &PathBuf, text_hash: &str) -> Option<String> {
     let content = fs::read_to_string(path).ok()?;
 
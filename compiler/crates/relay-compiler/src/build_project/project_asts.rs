@@ -5,29 +5,77 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::path::PathBuf;
+
 use common::Diagnostic;
+use common::SourceLocationKey;
 use dependency_analyzer::get_reachable_ast;
 use dependency_analyzer::ReachableAst;
 use fnv::FnvHashMap;
+use fnv::FnvHashSet;
 use graphql_ir::FragmentDefinitionName;
 use graphql_ir::FragmentDefinitionNameSet;
 use graphql_syntax::ExecutableDefinition;
+use intern::string_key::StringKey;
 use intern::string_key::StringKeySet;
 use relay_config::ProjectConfig;
 use relay_transforms::get_resolver_fragment_name;
+use relay_transforms::EXPERIMENT_VARIANT_DIRECTIVE_NAME;
 use schema::SDLSchema;
 use schema::Schema;
 
+use crate::compiler_state::CompilerState;
 use crate::compiler_state::ProjectName;
 use crate::errors::BuildProjectError;
 use crate::GraphQLAsts;
 
 pub struct ProjectAsts {
     pub changed_names: StringKeySet,
+    /// The files with changes to the docblocks of their Relay Resolvers, which
+    /// affect the documents that select the fields and types they define.
+    pub changed_docblock_files: FnvHashSet<PathBuf>,
     pub base_definition_names: StringKeySet,
     pub definitions: Vec<ExecutableDefinition>,
 }
 
+impl ProjectAsts {
+    /// The name and source file of the operations of the project, without the
+    /// ones of its base project.
+    pub fn operations(&self) -> Vec<(StringKey, SourceLocationKey)> {
+        self.definitions
+            .iter()
+            .filter_map(|definition| match definition {
+                ExecutableDefinition::Operation(operation) => {
+                    let name = operation.name.as_ref()?.value;
+                    if self.base_definition_names.contains(&name) {
+                        None
+                    } else {
+                        Some((name, operation.location.source_location()))
+                    }
+                }
+                ExecutableDefinition::Fragment(_) => None,
+            })
+            .collect()
+    }
+
+    /// The names of the operations of the project that are the variant of an
+    /// experiment, renamed after their `@experimentVariant`.
+    pub fn experiment_variants(&self) -> Vec<StringKey> {
+        let mut names = self
+            .definitions
+            .iter()
+            .filter(|definition| {
+                matches!(definition, ExecutableDefinition::Operation(_))
+                    && definition.has_directive(EXPERIMENT_VARIANT_DIRECTIVE_NAME.0)
+            })
+            .filter_map(|definition| definition.name())
+            .filter(|name| !self.base_definition_names.contains(name))
+            .collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+}
+
 pub struct ProjectAstData {
     pub project_asts: ProjectAsts,
     pub base_fragment_names: FragmentDefinitionNameSet,
@@ -35,6 +83,7 @@ pub struct ProjectAstData {
 
 pub fn get_project_asts(
     schema: &SDLSchema,
+    compiler_state: &CompilerState,
     graphql_asts: &FnvHashMap<ProjectName, GraphQLAsts>,
     project_config: &ProjectConfig,
 ) -> Result<ProjectAstData, BuildProjectError> {
@@ -84,6 +133,7 @@ pub fn get_project_asts(
             definitions,
             base_definition_names,
             changed_names: find_changed_names(graphql_asts, project_config),
+            changed_docblock_files: find_changed_docblock_files(compiler_state, project_config),
         },
         base_fragment_names,
     })
@@ -108,6 +158,20 @@ fn find_changed_names(
     changed_names
 }
 
+fn find_changed_docblock_files(
+    compiler_state: &CompilerState,
+    project_config: &ProjectConfig,
+) -> FnvHashSet<PathBuf> {
+    let mut changed_files = compiler_state
+        .pending_docblock_files(project_config.name)
+        .into_iter()
+        .collect::<FnvHashSet<_>>();
+    if let Some(base_project_name) = project_config.base {
+        changed_files.extend(compiler_state.pending_docblock_files(base_project_name));
+    }
+    changed_files
+}
+
 pub fn find_duplicates(
     asts: &[ExecutableDefinition],
     base_asts: &[ExecutableDefinition],
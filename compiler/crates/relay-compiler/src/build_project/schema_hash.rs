@@ -0,0 +1,67 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::fs;
+use std::path::Path;
+
+use lazy_static::lazy_static;
+use md5::Digest;
+use md5::Md5;
+use regex::Regex;
+use schema::SDLSchema;
+
+lazy_static! {
+    static ref SCHEMA_HASH_REGEX: Regex = Regex::new(r#"@relaySchemaHash (\w{32})\n"#).unwrap();
+}
+
+/// Hash of the printed schema (including the project's extensions) that is
+/// embedded in generated artifacts when `schemaHashComment` is enabled.
+pub fn compute_schema_hash(schema: &SDLSchema) -> String {
+    let mut md5 = Md5::new();
+    md5.update(schema_print::print(schema));
+    hex::encode(md5.finalize())
+}
+
+/// Reads the artifact at `path` and returns the schema hash it was generated
+/// with, if the file exists and has one.
+pub fn read_artifact_schema_hash(path: &Path) -> Option<String> {
+    let content = fs::read_to_string(path).ok()?;
+    extract_schema_hash(&content).map(|hash| hash.to_owned())
+}
+
+/// Whether the artifact at `path` was generated from a different schema than
+/// its regenerated `content`. Artifacts without a schema hash never are.
+pub fn is_generated_from_different_schema(path: &Path, content: &[u8]) -> bool {
+    let current_hash = std::str::from_utf8(content)
+        .ok()
+        .and_then(extract_schema_hash);
+    match (read_artifact_schema_hash(path), current_hash) {
+        (Some(existing_hash), Some(current_hash)) => existing_hash != current_hash,
+        _ => false,
+    }
+}
+
+fn extract_schema_hash(content: &str) -> Option<&str> {
+    SCHEMA_HASH_REGEX
+        .captures(content)
+        .and_then(|captures| captures.get(1).map(|m| m.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_schema_hash() {
+        let content = "/**\n * @generated SignedSource<<abc>>\n * @relaySchemaHash 0123456789abcdef0123456789abcdef\n * @lightSyntaxTransform\n */\n";
+        assert_eq!(
+            extract_schema_hash(content),
+            Some("0123456789abcdef0123456789abcdef")
+        );
+        assert_eq!(extract_schema_hash("/**\n * @nogrep\n */\n"), None);
+    }
+}
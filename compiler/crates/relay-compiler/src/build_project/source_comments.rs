@@ -0,0 +1,103 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::path::Path;
+
+use common::Location;
+use common::SourceLocationKey;
+use dashmap::DashMap;
+use fnv::FnvBuildHasher;
+use fnv::FnvHashMap;
+use graphql_ir::Program;
+use intern::string_key::StringKey;
+use relay_codegen::SourceCommentResolver;
+
+use crate::source_for_location;
+use crate::FsSourceReader;
+
+/// The comments of the `debugSourceComments` of a project, with the
+/// definition and the line of the selections: `UserProfile_user,
+/// src/UserProfile.js:12`. The sources are read from disk once, when their
+/// first comment is printed.
+pub(crate) struct SourceComments<'a> {
+    root_dir: &'a Path,
+    /// The definitions of each source, by the start of their name.
+    definitions: FnvHashMap<SourceLocationKey, Vec<(u32, StringKey)>>,
+    /// The 0-based line of each source in its file, and the offsets of the
+    /// line breaks of the source.
+    line_breaks: DashMap<SourceLocationKey, Option<(usize, Vec<usize>)>, FnvBuildHasher>,
+}
+
+impl<'a> SourceComments<'a> {
+    pub(crate) fn new(root_dir: &'a Path, program: &Program) -> Self {
+        let mut definitions: FnvHashMap<SourceLocationKey, Vec<(u32, StringKey)>> =
+            FnvHashMap::default();
+        let names = program
+            .operations()
+            .map(|operation| (operation.name.location, operation.name.item.0))
+            .chain(
+                program
+                    .fragments()
+                    .map(|fragment| (fragment.name.location, fragment.name.item.0)),
+            );
+        for (location, name) in names {
+            definitions
+                .entry(location.source_location())
+                .or_default()
+                .push((location.span().start, name));
+        }
+        for source_definitions in definitions.values_mut() {
+            source_definitions.sort_by_key(|(start, _)| *start);
+        }
+        Self {
+            root_dir,
+            definitions,
+            line_breaks: Default::default(),
+        }
+    }
+
+    /// The 1-based line of the location in its file.
+    fn line(&self, location: Location) -> Option<usize> {
+        let source_location = location.source_location();
+        let line_breaks = self.line_breaks.entry(source_location).or_insert_with(|| {
+            source_for_location(self.root_dir, source_location, &FsSourceReader).map(|source| {
+                let text_source = source.to_text_source();
+                let line_breaks = text_source
+                    .text
+                    .match_indices('\n')
+                    .map(|(index, _)| index)
+                    .collect();
+                (text_source.line_index, line_breaks)
+            })
+        });
+        let (line_index, line_breaks) = line_breaks.as_ref()?;
+        let start = location.span().start as usize;
+        Some(line_index + line_breaks.partition_point(|line_break| *line_break < start) + 1)
+    }
+
+    /// The definition of the source containing the location.
+    fn definition(&self, location: Location) -> Option<StringKey> {
+        let start = location.span().start;
+        self.definitions
+            .get(&location.source_location())?
+            .iter()
+            .rev()
+            .find(|(definition_start, _)| *definition_start <= start)
+            .map(|(_, name)| *name)
+    }
+}
+
+impl SourceCommentResolver for SourceComments<'_> {
+    fn source_comment(&self, location: Location) -> Option<String> {
+        let path = location.source_location().path();
+        let line = self.line(location)?;
+        Some(match self.definition(location) {
+            Some(definition) => format!("{}, {}:{}", definition, path, line),
+            None => format!("{}:{}", path, line),
+        })
+    }
+}
@@ -0,0 +1,376 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The `codemod migrate-fragment-arguments` command, rewriting the
+//! `@argumentDefinitions` and `@arguments` directives to the fragment
+//! arguments syntax, `fragment Foo($size: Int = 32) on User` and
+//! `...Foo(size: 64)`.
+
+use common::DirectiveName;
+use common::Location;
+use common::SourceLocationKey;
+use common::Span;
+use graphql_ir::ARGUMENT_DEFINITION;
+use graphql_ir::DIRECTIVE_ARGUMENTS;
+use graphql_syntax::parse_executable;
+use graphql_syntax::Argument;
+use graphql_syntax::ConstantArgument;
+use graphql_syntax::ConstantValue;
+use graphql_syntax::Directive;
+use graphql_syntax::ExecutableDefinition;
+use graphql_syntax::FragmentDefinition;
+use graphql_syntax::FragmentSpread;
+use graphql_syntax::List;
+use graphql_syntax::Selection;
+use graphql_syntax::Value;
+use intern::string_key::Intern;
+use intern::Lookup;
+
+use super::CodemodEdit;
+use super::CodemodEdits;
+use crate::compiler_state::GraphQLSources;
+use crate::file_source::LocatedGraphQLSource;
+
+const UNCHECKED_ARGUMENTS: &str = "uncheckedArguments_DEPRECATED";
+
+/// The edits rewriting the `@argumentDefinitions` of the fragments of the
+/// sources to variable definitions, and the `@arguments` of their spreads to
+/// arguments. The arguments with a `provider` or other options that the
+/// variable definitions can't express are skipped, as are the spreads with
+/// `@uncheckedArguments_DEPRECATED`, whose arguments aren't checked.
+pub fn find_fragment_argument_migrations(graphql_sources: &GraphQLSources) -> CodemodEdits {
+    let mut edits = CodemodEdits::default();
+    for (file_name, sources) in graphql_sources.pending.iter() {
+        for LocatedGraphQLSource {
+            index,
+            graphql_source,
+        } in sources
+        {
+            let source_location = SourceLocationKey::embedded(&file_name.to_string_lossy(), *index);
+            find_source_migrations(
+                &graphql_source.text_source().text,
+                source_location,
+                &mut edits,
+            );
+        }
+    }
+    edits
+}
+
+fn find_source_migrations(
+    source: &str,
+    source_location: SourceLocationKey,
+    edits: &mut CodemodEdits,
+) {
+    // Documents with syntax errors are reported by the builds.
+    let document = match parse_executable(source, source_location) {
+        Ok(document) => document,
+        Err(_) => return,
+    };
+    let mut finder = MigrationFinder {
+        source,
+        source_location,
+        edits,
+    };
+    for definition in &document.definitions {
+        match definition {
+            ExecutableDefinition::Fragment(fragment) => {
+                finder.visit_fragment(fragment);
+                finder.visit_selections(&fragment.selections);
+            }
+            ExecutableDefinition::Operation(operation) => {
+                finder.visit_selections(&operation.selections);
+            }
+        }
+    }
+}
+
+struct MigrationFinder<'a> {
+    source: &'a str,
+    source_location: SourceLocationKey,
+    edits: &'a mut CodemodEdits,
+}
+
+impl<'a> MigrationFinder<'a> {
+    fn visit_fragment(&mut self, fragment: &FragmentDefinition) {
+        let index = match fragment
+            .directives
+            .iter()
+            .position(|directive| DirectiveName(directive.name.value) == *ARGUMENT_DEFINITION)
+        {
+            Some(index) => index,
+            None => return,
+        };
+        let directive = &fragment.directives[index];
+        let skip_reason = if fragment.variable_definitions.is_some() {
+            Some("the fragment already has variable definitions".to_string())
+        } else if self.text(directive.span).contains('#') {
+            Some("`@argumentDefinitions` has comments, which would be lost".to_string())
+        } else {
+            None
+        };
+        let variable_definitions = match skip_reason {
+            Some(reason) => Err(reason),
+            None => directive
+                .arguments
+                .iter()
+                .flat_map(|arguments| &arguments.items)
+                .map(|argument| {
+                    let variable_definition = match &argument.value {
+                        Value::Constant(ConstantValue::Object(options)) => {
+                            self.variable_definition(argument.name.value.lookup(), options)
+                        }
+                        _ => None,
+                    };
+                    variable_definition.ok_or_else(|| {
+                        format!(
+                            "the argument `{}` has options other than `type` and `defaultValue`",
+                            argument.name.value
+                        )
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>(),
+        };
+        match variable_definitions {
+            Ok(variable_definitions) => {
+                let previous_end = match index {
+                    0 => fragment.type_condition.span.end,
+                    _ => fragment.directives[index - 1].span.end,
+                };
+                let replacement = format!(
+                    "{}{}{}",
+                    fragment.name.value,
+                    self.list(
+                        &variable_definitions,
+                        directive.arguments.as_ref(),
+                        fragment.fragment.span.start
+                    ),
+                    self.text(Span::new(fragment.name.span.end, previous_end))
+                );
+                self.push_edit(
+                    Span::new(fragment.name.span.start, directive.span.end),
+                    replacement,
+                    format!(
+                        "`@argumentDefinitions` of `{}` rewritten to variable definitions",
+                        fragment.name.value
+                    ),
+                );
+            }
+            Err(reason) => self
+                .edits
+                .skipped
+                .push((Location::new(self.source_location, directive.span), reason)),
+        }
+    }
+
+    fn visit_selections(&mut self, selections: &List<Selection>) {
+        for selection in &selections.items {
+            match selection {
+                Selection::FragmentSpread(spread) => self.visit_fragment_spread(spread),
+                Selection::InlineFragment(fragment) => self.visit_selections(&fragment.selections),
+                Selection::LinkedField(field) => self.visit_selections(&field.selections),
+                Selection::ScalarField(_) => {}
+            }
+        }
+    }
+
+    fn visit_fragment_spread(&mut self, spread: &FragmentSpread) {
+        let is_arguments = |directive: &Directive| {
+            directive.name.value == *DIRECTIVE_ARGUMENTS
+                || directive.name.value.lookup() == UNCHECKED_ARGUMENTS
+        };
+        let index = match spread.directives.iter().position(is_arguments) {
+            Some(index) => index,
+            None => return,
+        };
+        let directive = &spread.directives[index];
+        let skip_reason = if directive.name.value.lookup() == UNCHECKED_ARGUMENTS {
+            Some(format!(
+                "the arguments of `@{}` aren't checked, unlike the arguments of the spread",
+                UNCHECKED_ARGUMENTS
+            ))
+        } else if spread.arguments.is_some() {
+            Some("the spread already has arguments".to_string())
+        } else if spread.directives[index + 1..].iter().any(is_arguments) {
+            Some("the spread has several `@arguments`".to_string())
+        } else {
+            None
+        };
+        if let Some(reason) = skip_reason {
+            self.edits
+                .skipped
+                .push((Location::new(self.source_location, directive.span), reason));
+            return;
+        }
+
+        let previous_end = match index {
+            0 => spread.name.span.end,
+            _ => spread.directives[index - 1].span.end,
+        };
+        let arguments = directive
+            .arguments
+            .as_ref()
+            .filter(|arguments| !arguments.items.is_empty())
+            .map_or("", |arguments| self.text(arguments.span));
+        let replacement = format!(
+            "{}{}{}",
+            spread.name.value,
+            arguments,
+            self.text(Span::new(spread.name.span.end, previous_end))
+        );
+        self.push_edit(
+            Span::new(spread.name.span.start, directive.span.end),
+            replacement,
+            format!(
+                "`@arguments` of the spread of `{}` rewritten to arguments",
+                spread.name.value
+            ),
+        );
+    }
+
+    /// The variable definition of an argument of `@argumentDefinitions`,
+    /// e.g. `$size: Int = 32` for `size: {type: "Int", defaultValue: 32}`.
+    fn variable_definition(&self, name: &str, options: &List<ConstantArgument>) -> Option<String> {
+        let mut type_ = None;
+        let mut default_value = None;
+        for option in &options.items {
+            match (option.name.value.lookup(), &option.value) {
+                ("type", ConstantValue::String(type_name)) => type_ = Some(type_name.value),
+                ("defaultValue", value) => default_value = Some(self.text(value.span())),
+                _ => return None,
+            }
+        }
+        let type_ = type_?;
+        Some(match default_value {
+            Some(default_value) => format!("${}: {} = {}", name, type_, default_value),
+            None => format!("${}: {}", name, type_),
+        })
+    }
+
+    /// The parenthesized `items`, on separate lines if the arguments of the
+    /// directive they replace are, indented from the line of `start`.
+    fn list(&self, items: &[String], arguments: Option<&List<Argument>>, start: u32) -> String {
+        if items.is_empty() {
+            return String::new();
+        }
+        let is_multiline =
+            arguments.map_or(false, |arguments| self.text(arguments.span).contains('\n'));
+        if !is_multiline {
+            return format!("({})", items.join(", "));
+        }
+        let line_start = self.source[..start as usize]
+            .rfind('\n')
+            .map_or(0, |index| index + 1);
+        let indent: String = self.source[line_start..]
+            .chars()
+            .take_while(|chr| *chr == ' ' || *chr == '\t')
+            .collect();
+        let mut list = "(\n".to_string();
+        for item in items {
+            list.push_str(&format!("{}  {}\n", indent, item));
+        }
+        list.push_str(&format!("{})", indent));
+        list
+    }
+
+    fn push_edit(&mut self, span: Span, replacement: String, description: String) {
+        self.edits.edits.push(CodemodEdit {
+            location: Location::new(self.source_location, span),
+            text: self.text(span).intern(),
+            replacement,
+            description,
+        });
+    }
+
+    fn text(&self, span: Span) -> &'a str {
+        &self.source[span.start as usize..span.end as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn migrate(source: &str) -> (String, Vec<String>) {
+        let mut edits = CodemodEdits::default();
+        find_source_migrations(
+            source,
+            SourceLocationKey::standalone("Foo.graphql"),
+            &mut edits,
+        );
+        let mut migrated = source.to_string();
+        for edit in edits.edits.iter().rev() {
+            let span = edit.location.span();
+            assert_eq!(
+                &source[span.start as usize..span.end as usize],
+                edit.text.lookup()
+            );
+            migrated.replace_range(span.start as usize..span.end as usize, &edit.replacement);
+        }
+        let skipped = edits
+            .skipped
+            .into_iter()
+            .map(|(_, reason)| reason)
+            .collect();
+        (migrated, skipped)
+    }
+
+    #[test]
+    fn migrate_argument_definitions() {
+        assert_eq!(
+            migrate(
+                "fragment Foo on User @argumentDefinitions(size: {type: \"Int\", defaultValue: 32}, id: {type: \"ID!\"}) @relay(mask: false) {\n  id\n}"
+            ),
+            (
+                "fragment Foo($size: Int = 32, $id: ID!) on User @relay(mask: false) {\n  id\n}"
+                    .to_string(),
+                vec![]
+            )
+        );
+        assert_eq!(
+            migrate(
+                "  fragment Foo on User\n    @refetchable(queryName: \"FooQuery\")\n    @argumentDefinitions(\n      sizes: {type: \"[Int]\", defaultValue: [32, 64]}\n    ) {\n    id\n  }"
+            ),
+            (
+                "  fragment Foo(\n    $sizes: [Int] = [32, 64]\n  ) on User\n    @refetchable(queryName: \"FooQuery\") {\n    id\n  }"
+                    .to_string(),
+                vec![]
+            )
+        );
+    }
+
+    #[test]
+    fn migrate_arguments() {
+        assert_eq!(
+            migrate(
+                "query Q {\n  me {\n    ...Foo @arguments(size: 64)\n    ...Bar @include(if: $cond) @arguments(id: $id, sizes: [1, 2])\n    ... on User {\n      ...Baz @arguments\n    }\n  }\n}"
+            ),
+            (
+                "query Q {\n  me {\n    ...Foo(size: 64)\n    ...Bar(id: $id, sizes: [1, 2]) @include(if: $cond)\n    ... on User {\n      ...Baz\n    }\n  }\n}"
+                    .to_string(),
+                vec![]
+            )
+        );
+    }
+
+    #[test]
+    fn skip_arguments_without_spec_syntax() {
+        let source = "fragment Foo on User @argumentDefinitions(scale: {type: \"Float!\", provider: \"Scale.relayprovider\"}) {\n  ...Bar @uncheckedArguments_DEPRECATED(id: 1)\n}";
+        assert_eq!(
+            migrate(source),
+            (
+                source.to_string(),
+                vec![
+                    "the argument `scale` has options other than `type` and `defaultValue`"
+                        .to_string(),
+                    "the arguments of `@uncheckedArguments_DEPRECATED` aren't checked, unlike the arguments of the spread"
+                        .to_string(),
+                ]
+            )
+        );
+    }
+}
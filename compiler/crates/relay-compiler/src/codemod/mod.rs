@@ -0,0 +1,229 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Codemods of the GraphQL documents of the projects, for the
+//! `relay-compiler codemod` command. The selections to change are found in
+//! the IR of the projects, or in their documents for the purely syntactic
+//! migrations, and the source files are rewritten in place with edits of the
+//! concrete syntax trees of their documents, which keep their formatting and
+//! comments.
+
+mod migrate_fragment_arguments;
+mod rename_field;
+mod replace_deprecated_fields;
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use common::Location;
+use common::SourceLocationKey;
+use graphql_syntax::parse_executable_cst;
+use intern::string_key::StringKey;
+use intern::Lookup;
+pub use migrate_fragment_arguments::find_fragment_argument_migrations;
+pub use rename_field::find_field_renames;
+pub use rename_field::FieldCoordinate;
+pub use replace_deprecated_fields::find_deprecated_field_replacements;
+
+use crate::fix::byte_offset;
+use crate::fix::text_source_for_location;
+
+/// A change of the text of a selection.
+#[derive(Clone, Debug)]
+pub struct CodemodEdit {
+    pub location: Location,
+    /// The text of the location when the documents were parsed.
+    pub text: StringKey,
+    pub replacement: String,
+    /// What the edit changes, for the summary of the codemod.
+    pub description: String,
+}
+
+/// The changes of a codemod to the documents of a project.
+#[derive(Debug, Default)]
+pub struct CodemodEdits {
+    pub edits: Vec<CodemodEdit>,
+    /// The selections to change by hand, with the reason they are skipped.
+    pub skipped: Vec<(Location, String)>,
+}
+
+/// A selection changed or skipped by a codemod.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct CodemodSelection {
+    /// The path of the file, relative to the root directory.
+    pub path: PathBuf,
+    /// The 1-based line of the selection in the file.
+    pub line: usize,
+    /// The change, or the reason the selection is skipped.
+    pub message: String,
+}
+
+impl fmt::Display for CodemodSelection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.path.display(), self.line, self.message)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CodemodSummary {
+    pub changed: Vec<CodemodSelection>,
+    pub skipped: Vec<CodemodSelection>,
+}
+
+impl fmt::Display for CodemodSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changed.is_empty() {
+            writeln!(f, "No selection to change.")?;
+        } else {
+            writeln!(f, "Changed {} selection(s):", self.changed.len())?;
+            for selection in &self.changed {
+                writeln!(f, "  {}", selection)?;
+            }
+        }
+        if !self.skipped.is_empty() {
+            writeln!(
+                f,
+                "Skipped {} selection(s), to change by hand:",
+                self.skipped.len()
+            )?;
+            for selection in &self.skipped {
+                writeln!(f, "  {}", selection)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+enum Change {
+    Edit(CodemodEdit),
+    Skip(String),
+}
+
+/// Applies the edits of the projects to the files of `root_dir` they are
+/// located in. The changes are deduplicated by location, as the documents
+/// shared by several projects are in the IR of each of them.
+pub fn apply_codemod_edits(
+    root_dir: &Path,
+    project_edits: Vec<CodemodEdits>,
+) -> io::Result<CodemodSummary> {
+    let mut changes_by_path: BTreeMap<StringKey, BTreeMap<Location, Change>> = BTreeMap::new();
+    for edits in project_edits {
+        let changes = edits
+            .edits
+            .into_iter()
+            .map(|edit| (edit.location, Change::Edit(edit)))
+            .chain(
+                edits
+                    .skipped
+                    .into_iter()
+                    .map(|(location, reason)| (location, Change::Skip(reason))),
+            );
+        for (location, change) in changes {
+            if let Some(path) = source_path(location.source_location()) {
+                changes_by_path
+                    .entry(path)
+                    .or_default()
+                    .entry(location)
+                    .or_insert(change);
+            }
+        }
+    }
+
+    let mut summary = CodemodSummary::default();
+    for (path, changes) in changes_by_path {
+        let absolute_path = root_dir.join(path.lookup());
+        let mut content = fs::read_to_string(&absolute_path)?;
+        let features = extract_graphql::extract_for_path(&absolute_path, &content);
+
+        let mut changes_by_source: BTreeMap<SourceLocationKey, Vec<(Location, Change)>> =
+            BTreeMap::new();
+        for (location, change) in changes {
+            changes_by_source
+                .entry(location.source_location())
+                .or_default()
+                .push((location, change));
+        }
+        // Rewrite the sources from the end of the file, so that the offsets
+        // and lines of the remaining ones stay valid.
+        let mut changed = false;
+        for (source_location, changes) in changes_by_source.into_iter().rev() {
+            let text_source = text_source_for_location(&content, &features, source_location);
+            let offset = text_source.as_ref().and_then(|text_source| {
+                byte_offset(&content, text_source.line_index, text_source.column_index)
+            });
+            let (text_source, offset) = match (text_source, offset) {
+                (Some(text_source), Some(offset)) => (text_source, offset),
+                _ => continue,
+            };
+            let line = |span_start: u32| {
+                content[..offset + span_start as usize]
+                    .matches('\n')
+                    .count()
+                    + 1
+            };
+            let cst = match parse_executable_cst(&text_source.text, source_location) {
+                Ok(cst) => cst,
+                Err(_) => continue,
+            };
+
+            let mut editor = cst.editor();
+            for (location, change) in changes {
+                let span = *location.span();
+                let selection = |message: String| CodemodSelection {
+                    path: PathBuf::from(path.lookup()),
+                    line: line(span.start),
+                    message,
+                };
+                match change {
+                    Change::Edit(edit) if cst.text(span) == edit.text.lookup() => {
+                        editor.replace(span, edit.replacement);
+                        summary.changed.push(selection(edit.description));
+                    }
+                    Change::Edit(_) => summary.skipped.push(selection(
+                        "the selection changed since the documents were parsed".to_string(),
+                    )),
+                    Change::Skip(reason) => summary.skipped.push(selection(reason)),
+                }
+            }
+            let printed = editor
+                .print()
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+            if printed != text_source.text {
+                content.replace_range(offset..offset + text_source.text.len(), &printed);
+                changed = true;
+            }
+        }
+        if changed {
+            fs::write(&absolute_path, content)?;
+        }
+    }
+    summary.changed.sort();
+    summary.skipped.sort();
+    Ok(summary)
+}
+
+/// Whether `name` is a GraphQL name, like the name of a field.
+fn is_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map_or(false, |chr| chr == '_' || chr.is_ascii_alphabetic())
+        && chars.all(|chr| chr == '_' || chr.is_ascii_alphanumeric())
+}
+
+fn source_path(source_location: SourceLocationKey) -> Option<StringKey> {
+    match source_location {
+        SourceLocationKey::Embedded { path, .. } | SourceLocationKey::Standalone { path } => {
+            Some(path)
+        }
+        SourceLocationKey::Generated => None,
+    }
+}
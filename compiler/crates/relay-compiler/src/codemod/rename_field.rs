@@ -0,0 +1,218 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The `codemod rename-field` command, renaming the selections of a field.
+
+use std::fmt;
+use std::str::FromStr;
+
+use common::Location;
+use graphql_ir::LinkedField;
+use graphql_ir::Program;
+use graphql_ir::ScalarField;
+use graphql_ir::Visitor;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use schema::FieldID;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+
+use super::is_name;
+use super::CodemodEdit;
+use super::CodemodEdits;
+
+/// A field of a type of the schema, written `Type.field`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FieldCoordinate {
+    pub type_name: StringKey,
+    pub field_name: StringKey,
+}
+
+impl FromStr for FieldCoordinate {
+    type Err = String;
+
+    fn from_str(coordinate: &str) -> Result<Self, Self::Err> {
+        match coordinate.split_once('.') {
+            Some((type_name, field_name)) if is_name(type_name) && is_name(field_name) => {
+                Ok(Self {
+                    type_name: type_name.intern(),
+                    field_name: field_name.intern(),
+                })
+            }
+            _ => Err(format!(
+                "Expected a field like `User.name`, got `{}`.",
+                coordinate
+            )),
+        }
+    }
+}
+
+impl fmt::Display for FieldCoordinate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.type_name, self.field_name)
+    }
+}
+
+/// The edits renaming the selections of `field` in the documents of the
+/// program to `new_name`, with any alias, and the selections of the
+/// implementations of the field when its type is an interface. Selections on
+/// an interface implemented by the type are ambiguous, as they select the
+/// field of the other implementations too, and are skipped. Returns `None` if
+/// the field isn't in the schema of the program.
+pub fn find_field_renames(
+    program: &Program,
+    field: FieldCoordinate,
+    new_name: StringKey,
+) -> Option<CodemodEdits> {
+    let schema = &program.schema;
+    let type_ = schema.get_type(field.type_name)?;
+    schema.named_field(type_, field.field_name)?;
+    let mut finder = FieldRenameFinder {
+        schema,
+        type_,
+        field,
+        new_name,
+        edits: Default::default(),
+    };
+    finder.visit_program(program);
+    Some(finder.edits)
+}
+
+struct FieldRenameFinder<'a> {
+    schema: &'a SDLSchema,
+    type_: Type,
+    field: FieldCoordinate,
+    new_name: StringKey,
+    edits: CodemodEdits,
+}
+
+impl<'a> FieldRenameFinder<'a> {
+    fn visit_field_definition(&mut self, field_id: FieldID, location: Location) {
+        let field = self.schema.field(field_id);
+        if field.name.item != self.field.field_name {
+            return;
+        }
+        let parent_type = match field.parent_type {
+            Some(parent_type) => parent_type,
+            None => return,
+        };
+        if parent_type == self.type_ || self.implements(parent_type, self.type_) {
+            self.edits.edits.push(CodemodEdit {
+                location,
+                text: self.field.field_name,
+                replacement: self.new_name.to_string(),
+                description: format!("`{}` renamed to `{}`", self.field, self.new_name),
+            });
+        } else if self.implements(self.type_, parent_type) {
+            self.edits.skipped.push((
+                location,
+                format!(
+                    "selected on the interface `{}`, which other types implement",
+                    self.schema.get_type_name(parent_type)
+                ),
+            ));
+        }
+    }
+
+    /// Whether `type_` implements the interface `interface`, directly or
+    /// through another interface.
+    fn implements(&self, type_: Type, interface: Type) -> bool {
+        let interface_id = match interface {
+            Type::Interface(interface_id) => interface_id,
+            _ => return false,
+        };
+        match type_ {
+            Type::Object(object_id) => self
+                .schema
+                .object(object_id)
+                .interfaces
+                .contains(&interface_id),
+            Type::Interface(id) => self.schema.interface(id).interfaces.contains(&interface_id),
+            _ => false,
+        }
+    }
+}
+
+impl<'a> Visitor for FieldRenameFinder<'a> {
+    const NAME: &'static str = "FieldRenameFinder";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_scalar_field(&mut self, field: &ScalarField) {
+        self.visit_field_definition(field.definition.item, field.definition.location);
+    }
+
+    fn visit_linked_field(&mut self, field: &LinkedField) {
+        self.visit_field_definition(field.definition.item, field.definition.location);
+        self.default_visit_linked_field(field);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::SourceLocationKey;
+    use graphql_ir::build;
+    use graphql_syntax::parse_executable;
+    use relay_test_schema::get_test_schema;
+
+    use super::*;
+
+    #[test]
+    fn field_coordinate() {
+        assert_eq!(
+            "User.name".parse::<FieldCoordinate>(),
+            Ok(FieldCoordinate {
+                type_name: "User".intern(),
+                field_name: "name".intern(),
+            })
+        );
+        assert!("User".parse::<FieldCoordinate>().is_err());
+        assert!("User.full-name".parse::<FieldCoordinate>().is_err());
+    }
+
+    #[test]
+    fn field_renames() {
+        let source = "query Q {\n  me {\n    name\n  }\n  node(id: \"1\") {\n    ... on User {\n      userName: name\n    }\n    ... on Actor {\n      name\n    }\n  }\n}";
+        let ast = parse_executable(source, SourceLocationKey::standalone("Q.graphql")).unwrap();
+        let schema = get_test_schema();
+        let ir = build(&schema, &ast.definitions).unwrap();
+        let program = Program::from_definitions(schema, ir);
+        let line = |location: &Location| {
+            source[..location.span().start as usize]
+                .matches('\n')
+                .count()
+                + 1
+        };
+
+        let edits = find_field_renames(&program, "User.name".parse().unwrap(), "fullName".intern())
+            .unwrap();
+        assert_eq!(
+            edits
+                .edits
+                .iter()
+                .map(|edit| (line(&edit.location), edit.replacement.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(3, "fullName"), (7, "fullName")]
+        );
+        assert_eq!(
+            edits
+                .skipped
+                .iter()
+                .map(|(location, reason)| (line(location), reason.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(
+                10,
+                "selected on the interface `Actor`, which other types implement"
+            )]
+        );
+        assert!(
+            find_field_renames(&program, "User.fullName".parse().unwrap(), "name".intern())
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,259 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The `codemod replace-deprecated-fields` command, replacing the selections
+//! of the deprecated fields whose reason names a replacement, like
+//! `@deprecated(reason: "Use `fullName` instead.")`.
+
+use common::WithLocation;
+use graphql_ir::Argument;
+use graphql_ir::LinkedField;
+use graphql_ir::Program;
+use graphql_ir::ScalarField;
+use graphql_ir::Visitor;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use intern::Lookup;
+use schema::Field;
+use schema::FieldID;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+
+use super::is_name;
+use super::CodemodEdit;
+use super::CodemodEdits;
+
+/// The edits replacing the selections of the deprecated fields of the
+/// program with their replacements. Unaliased selections are aliased with the
+/// name of the deprecated field, so that the data read by the components
+/// keeps its shape. Selections whose arguments aren't accepted by the
+/// replacement, or whose replacement has another type, are skipped.
+pub fn find_deprecated_field_replacements(program: &Program) -> CodemodEdits {
+    let mut finder = DeprecatedFieldFinder {
+        schema: &program.schema,
+        edits: Default::default(),
+    };
+    finder.visit_program(program);
+    finder.edits
+}
+
+struct DeprecatedFieldFinder<'a> {
+    schema: &'a SDLSchema,
+    edits: CodemodEdits,
+}
+
+impl<'a> DeprecatedFieldFinder<'a> {
+    fn visit_field(
+        &mut self,
+        definition: WithLocation<FieldID>,
+        alias: Option<WithLocation<StringKey>>,
+        arguments: &[Argument],
+    ) {
+        let field = self.schema.field(definition.item);
+        let replacement_name = match field
+            .deprecated()
+            .and_then(|deprecation| deprecation.reason)
+            .and_then(|reason| replacement_field_name(reason.lookup()))
+        {
+            Some(replacement_name) => replacement_name,
+            None => return,
+        };
+        let parent_type = match field.parent_type {
+            Some(parent_type) => parent_type,
+            None => return,
+        };
+        let coordinate = format!(
+            "{}.{}",
+            self.schema.get_type_name(parent_type),
+            field.name.item
+        );
+        match self.replacement_problem(field, parent_type, replacement_name, arguments) {
+            Some(problem) => self.edits.skipped.push((
+                definition.location,
+                format!(
+                    "deprecated `{}` can't be replaced with `{}`, {}",
+                    coordinate, replacement_name, problem
+                ),
+            )),
+            None => self.edits.edits.push(CodemodEdit {
+                location: definition.location,
+                text: field.name.item,
+                replacement: match alias {
+                    Some(_) => replacement_name.to_string(),
+                    None => format!("{}: {}", field.name.item, replacement_name),
+                },
+                description: format!(
+                    "deprecated `{}` replaced with `{}`",
+                    coordinate, replacement_name
+                ),
+            }),
+        }
+    }
+
+    /// Why the selection of `field` with `arguments` can't be replaced with
+    /// the field `replacement_name` of `parent_type`, if it can't.
+    fn replacement_problem(
+        &self,
+        field: &Field,
+        parent_type: Type,
+        replacement_name: StringKey,
+        arguments: &[Argument],
+    ) -> Option<String> {
+        let replacement = match self.schema.named_field(parent_type, replacement_name) {
+            Some(field_id) => self.schema.field(field_id),
+            None => {
+                return Some(format!(
+                    "it isn't a field of `{}`",
+                    self.schema.get_type_name(parent_type)
+                ));
+            }
+        };
+        if replacement.deprecated().is_some() {
+            return Some("it is deprecated too".to_string());
+        }
+        if replacement.type_ != field.type_ {
+            return Some(format!(
+                "its type `{}` differs from `{}`",
+                self.schema.get_type_string(&replacement.type_),
+                self.schema.get_type_string(&field.type_)
+            ));
+        }
+        for argument in arguments {
+            let argument_type = field
+                .arguments
+                .named(argument.name.item)
+                .map(|definition| &definition.type_);
+            let replacement_argument_type = replacement
+                .arguments
+                .named(argument.name.item)
+                .map(|definition| &definition.type_);
+            if argument_type != replacement_argument_type {
+                return Some(format!(
+                    "it doesn't accept the argument `{}` with the same type",
+                    argument.name.item
+                ));
+            }
+        }
+        replacement
+            .arguments
+            .iter()
+            .find(|definition| {
+                definition.type_.is_non_null()
+                    && definition.default_value.is_none()
+                    && !arguments
+                        .iter()
+                        .any(|argument| argument.name.item == definition.name)
+            })
+            .map(|definition| format!("its argument `{}` is required", definition.name))
+    }
+}
+
+impl<'a> Visitor for DeprecatedFieldFinder<'a> {
+    const NAME: &'static str = "DeprecatedFieldFinder";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_scalar_field(&mut self, field: &ScalarField) {
+        self.visit_field(field.definition, field.alias, &field.arguments);
+    }
+
+    fn visit_linked_field(&mut self, field: &LinkedField) {
+        self.visit_field(field.definition, field.alias, &field.arguments);
+        self.default_visit_linked_field(field);
+    }
+}
+
+/// The field named by a deprecation reason like "Use `fullName` instead.".
+fn replacement_field_name(reason: &str) -> Option<StringKey> {
+    let lowercase_reason = reason.to_ascii_lowercase();
+    let (index, prefix) = lowercase_reason.match_indices("use `").find(|(index, _)| {
+        *index == 0 || !lowercase_reason.as_bytes()[index - 1].is_ascii_alphanumeric()
+    })?;
+    let name = &reason[index + prefix.len()..];
+    let name = &name[..name.find('`')?];
+    if is_name(name) {
+        Some(name.intern())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Location;
+    use common::SourceLocationKey;
+    use graphql_ir::build;
+    use graphql_syntax::parse_executable;
+    use relay_test_schema::get_test_schema_with_extensions;
+
+    use super::*;
+
+    #[test]
+    fn replacement_field_names() {
+        assert_eq!(
+            replacement_field_name("Use `fullName` instead."),
+            Some("fullName".intern())
+        );
+        assert_eq!(
+            replacement_field_name("Deprecated because `name` is ambiguous, use `fullName`."),
+            Some("fullName".intern())
+        );
+        assert_eq!(replacement_field_name("No longer supported."), None);
+        assert_eq!(replacement_field_name("Use `User.fullName`."), None);
+    }
+
+    #[test]
+    fn deprecated_field_replacements() {
+        let schema = get_test_schema_with_extensions(
+            r#"
+            extend type User {
+              oldName: String @deprecated(reason: "Use `name` instead.")
+              oldAge: String @deprecated(reason: "Use `age` instead.")
+              localizedName(locale: String): String @deprecated(reason: "Use `name`.")
+            }
+            "#,
+        );
+        let source = "query Q {\n  me {\n    oldName\n    n: oldName\n    oldAge\n    localizedName(locale: \"en\")\n  }\n}";
+        let ast = parse_executable(source, SourceLocationKey::standalone("Q.graphql")).unwrap();
+        let ir = build(&schema, &ast.definitions).unwrap();
+        let program = Program::from_definitions(schema, ir);
+        let line = |location: &Location| {
+            source[..location.span().start as usize]
+                .matches('\n')
+                .count()
+                + 1
+        };
+
+        let edits = find_deprecated_field_replacements(&program);
+        assert_eq!(
+            edits
+                .edits
+                .iter()
+                .map(|edit| (line(&edit.location), edit.replacement.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(3, "oldName: name"), (4, "name")]
+        );
+        assert_eq!(
+            edits
+                .skipped
+                .iter()
+                .map(|(location, reason)| (line(location), reason.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    5,
+                    "deprecated `User.oldAge` can't be replaced with `age`, it isn't a field of `User`"
+                ),
+                (
+                    6,
+                    "deprecated `User.localizedName` can't be replaced with `name`, it doesn't accept the argument `locale` with the same type"
+                ),
+            ]
+        );
+    }
+}
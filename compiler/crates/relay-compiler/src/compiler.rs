@@ -6,40 +6,84 @@
  */
 
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
 use common::Diagnostic;
 use common::PerfLogEvent;
 use common::PerfLogger;
 use common::WithDiagnostics;
+use futures::future;
 use futures::future::join_all;
+use graphql_ir::Program;
 use graphql_watchman::WatchmanFileSourceSubscriptionNextChange;
+use intern::string_key::StringKey;
 use log::debug;
+use log::error;
 use log::info;
 use rayon::prelude::*;
+use schema::SDLSchema;
 use tokio::sync::Notify;
 use tokio::task;
 use tokio::task::JoinHandle;
-
+use tokio::time;
+use tokio::time::MissedTickBehavior;
+
+use crate::artifact_cache::restore_cached_projects;
+use crate::artifact_cache::upload_built_projects;
+use crate::batch::BatchCompiler;
+use crate::build_manifest::write_build_manifest;
+use crate::build_project::build_codemod_program;
 use crate::build_project::build_project;
+use crate::build_project::build_resolver_graph;
+use crate::build_project::build_schema;
 use crate::build_project::commit_project;
+use crate::build_project::lint_project;
 use crate::build_project::BuildProjectFailure;
+use crate::codemod::apply_codemod_edits;
+use crate::codemod::find_deprecated_field_replacements;
+use crate::codemod::find_field_renames;
+use crate::codemod::find_fragment_argument_migrations;
+use crate::codemod::CodemodEdits;
+use crate::codemod::CodemodSummary;
+use crate::codemod::FieldCoordinate;
+use crate::compiler_events::PhaseTiming;
 use crate::compiler_state::ArtifactMapKind;
 use crate::compiler_state::CompilerState;
+use crate::compiler_state::ProjectName;
 use crate::config::Config;
+use crate::errors::BuildProjectError;
 use crate::errors::Error;
 use crate::errors::Result;
+use crate::explain::explain_operation;
+use crate::explain::OperationExplanation;
 use crate::file_source::FileSource;
 use crate::file_source::FileSourceSubscriptionNextChange;
+use crate::fix::apply_safe_fixes;
+use crate::fix::FixSummary;
 use crate::graphql_asts::GraphQLAsts;
+use crate::metrics::serve_metrics;
+use crate::metrics::Metrics;
+use crate::on_demand::serve_on_demand_requests;
+use crate::on_demand::BuildTracker;
 use crate::red_to_green::RedToGreen;
+use crate::resolver_graph::ResolverGraph;
+use crate::saved_state::push_saved_state;
+use crate::unique_definition_names::validate_unique_definition_names;
 use crate::FileSourceResult;
 
+/// Bounds the passes of `Compiler::fix`, in case fixes keep introducing new
+/// fixable diagnostics.
+const MAX_FIX_PASSES: usize = 10;
+
 pub struct Compiler<TPerfLogger>
 where
     TPerfLogger: PerfLogger + 'static,
 {
     config: Arc<Config>,
     perf_logger: Arc<TPerfLogger>,
+    /// The metrics of the watch mode, and the port to serve them on.
+    metrics: Option<(Arc<Metrics>, u16)>,
 }
 
 impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
@@ -47,9 +91,18 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
         Self {
             config,
             perf_logger,
+            metrics: None,
         }
     }
 
+    /// Records the builds of the watch mode in `metrics`, and serves them on
+    /// `port`. The timers of the builds are only recorded if the perf logger
+    /// is a `MetricsPerfLogger` of `metrics`.
+    pub fn with_metrics(mut self, metrics: Arc<Metrics>, port: u16) -> Self {
+        self.metrics = Some((metrics, port));
+        self
+    }
+
     pub async fn compile(&self) -> Result<CompilerState> {
         let setup_event = self.perf_logger.create_event("compiler_setup");
         self.config.status_reporter.build_starts();
@@ -82,7 +135,354 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
         }
     }
 
+    /// Builds the schema of every enabled project, including client schema
+    /// extensions and types generated from Relay Resolvers, without compiling
+    /// any documents.
+    pub async fn build_schemas(&self) -> Result<Vec<(ProjectName, Arc<SDLSchema>)>> {
+        let setup_event = self.perf_logger.create_event("compiler_build_schemas");
+        let file_source = FileSource::connect(&self.config, &setup_event).await?;
+        let compiler_state = file_source
+            .query(&setup_event, self.perf_logger.as_ref())
+            .await?;
+        let graphql_asts = GraphQLAsts::from_graphql_sources_map(
+            &compiler_state.graphql_sources,
+            &Default::default(),
+        )?;
+        setup_event.complete();
+
+        let mut schemas = Vec::new();
+        let mut errors = Vec::new();
+        for project_config in self.config.enabled_projects() {
+            match build_schema(&compiler_state, project_config, &graphql_asts) {
+                Ok(schema) => schemas.push((project_config.name, schema)),
+                Err(diagnostics) => errors.push(BuildProjectError::ValidationErrors {
+                    errors: diagnostics,
+                    project_name: project_config.name,
+                }),
+            }
+        }
+        if errors.is_empty() {
+            Ok(schemas)
+        } else {
+            Err(Error::BuildProjectsErrors { errors })
+        }
+    }
+
+    /// Builds the IR of the enabled projects and returns the graphs of the
+    /// dependencies of their Relay Resolvers, without running the validations
+    /// and the transforms.
+    pub async fn resolver_graphs(&self) -> Result<Vec<(ProjectName, ResolverGraph)>> {
+        let setup_event = self.perf_logger.create_event("compiler_resolver_graphs");
+        let file_source = FileSource::connect(&self.config, &setup_event).await?;
+        let compiler_state = file_source
+            .query(&setup_event, self.perf_logger.as_ref())
+            .await?;
+        let graphql_asts = GraphQLAsts::from_graphql_sources_map(
+            &compiler_state.graphql_sources,
+            &Default::default(),
+        )?;
+        setup_event.complete();
+
+        let mut graphs = Vec::new();
+        let mut errors = Vec::new();
+        for project_config in self.config.enabled_projects() {
+            match build_resolver_graph(
+                project_config,
+                &compiler_state,
+                &graphql_asts,
+                Arc::clone(&self.perf_logger),
+            ) {
+                Ok(graph) => graphs.push((project_config.name, graph)),
+                Err(error) => errors.push(error),
+            }
+        }
+        if errors.is_empty() {
+            Ok(graphs)
+        } else {
+            Err(Error::BuildProjectsErrors { errors })
+        }
+    }
+
+    /// Parses the documents of the enabled projects, builds their IR and runs
+    /// the validations, skipping the transforms, typegen, codegen and all
+    /// artifact IO. Returns the non-fatal diagnostics.
+    pub async fn lint(&self) -> Result<Vec<Diagnostic>> {
+        self.config.status_reporter.build_starts();
+        match self.lint_diagnostics().await {
+            Ok(diagnostics) => {
+                self.config.status_reporter.build_completes(&diagnostics);
+                Ok(diagnostics)
+            }
+            Err(error) => {
+                self.config.status_reporter.build_errors(&error);
+                Err(error)
+            }
+        }
+    }
+
+    /// Applies the safe fixes of the diagnostics of the enabled projects to
+    /// the source files. The projects are linted again after each pass, until
+    /// no fix applies, as fixes of the same definition may overlap.
+    pub async fn fix(&self) -> Result<FixSummary> {
+        let mut summary = FixSummary::default();
+        for _ in 0..MAX_FIX_PASSES {
+            let diagnostics = self.validation_diagnostics().await?;
+            let pass_summary =
+                apply_safe_fixes(&self.config.root_dir, &diagnostics).map_err(Error::IOError)?;
+            if pass_summary.is_empty() {
+                break;
+            }
+            summary.applied.extend(pass_summary.applied);
+        }
+        Ok(summary)
+    }
+
+    /// The diagnostics of the validations of the enabled projects, including
+    /// the errors, without reporting them. Projects that fail to build before
+    /// the validations have no diagnostics.
+    pub async fn validation_diagnostics(&self) -> Result<Vec<Diagnostic>> {
+        match self.lint_diagnostics().await {
+            Ok(diagnostics) => Ok(diagnostics),
+            Err(Error::BuildProjectsErrors { errors }) => Ok(errors
+                .into_iter()
+                .flat_map(|error| match error {
+                    BuildProjectError::ValidationErrors { errors, .. } => errors,
+                    _ => Vec::new(),
+                })
+                .collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn lint_diagnostics(&self) -> Result<Vec<Diagnostic>> {
+        let setup_event = self.perf_logger.create_event("compiler_lint");
+        let result: Result<Vec<Diagnostic>> = async {
+            let file_source = FileSource::connect(&self.config, &setup_event).await?;
+            let compiler_state = file_source
+                .query(&setup_event, self.perf_logger.as_ref())
+                .await?;
+            let graphql_asts = setup_event.time("parse_sources_time", || {
+                GraphQLAsts::from_graphql_sources_map(
+                    &compiler_state.graphql_sources,
+                    &Default::default(),
+                )
+            })?;
+
+            let lint_results: Vec<_> = self
+                .config
+                .par_enabled_projects()
+                .map(|project_config| {
+                    lint_project(
+                        &self.config,
+                        project_config,
+                        &compiler_state,
+                        &graphql_asts,
+                        Arc::clone(&self.perf_logger),
+                    )
+                })
+                .collect();
+            let mut diagnostics = Vec::new();
+            let mut errors = Vec::new();
+            for result in lint_results {
+                match result {
+                    Ok(project_diagnostics) => diagnostics.extend(project_diagnostics),
+                    Err(error) => errors.push(error),
+                }
+            }
+            if errors.is_empty() {
+                Ok(diagnostics)
+            } else {
+                Err(Error::BuildProjectsErrors { errors })
+            }
+        }
+        .await;
+        setup_event.complete();
+        result
+    }
+
+    /// Builds the enabled projects without writing any artifact, and returns
+    /// what the compiler knows about the operation named `operation_name`.
+    pub async fn explain_operation(
+        &self,
+        operation_name: StringKey,
+    ) -> Result<OperationExplanation> {
+        let setup_event = self.perf_logger.create_event("compiler_explain");
+        let file_source = FileSource::connect(&self.config, &setup_event).await?;
+        let compiler_state = file_source
+            .query(&setup_event, self.perf_logger.as_ref())
+            .await?;
+        let graphql_asts = GraphQLAsts::from_graphql_sources_map(
+            &compiler_state.graphql_sources,
+            &Default::default(),
+        )?;
+        setup_event.complete();
+
+        for project_config in self.config.enabled_projects() {
+            let WithDiagnostics {
+                item: (_, schema, programs, artifacts),
+                ..
+            } = build_project(
+                &self.config,
+                project_config,
+                &compiler_state,
+                &graphql_asts,
+                Arc::clone(&self.perf_logger),
+            )
+            .map_err(|failure| match failure {
+                BuildProjectFailure::Error(error) => Error::BuildProjectsErrors {
+                    errors: vec![error],
+                },
+                BuildProjectFailure::Cancelled => Error::Cancelled,
+            })?;
+            if let Some(explanation) = explain_operation(
+                &self.config,
+                project_config,
+                &schema,
+                &programs,
+                artifacts,
+                operation_name,
+            ) {
+                return Ok(explanation);
+            }
+        }
+        Err(Error::OperationNotFound { operation_name })
+    }
+
+    /// Renames the selections of `field` to `new_name` in the source files of
+    /// the enabled projects, with the `codemod rename-field` command. The
+    /// schema isn't changed.
+    pub async fn rename_field(
+        &self,
+        field: FieldCoordinate,
+        new_name: StringKey,
+    ) -> Result<CodemodSummary> {
+        let project_edits = self
+            .codemod_edits(|program| find_field_renames(program, field, new_name))
+            .await?;
+        if project_edits.is_empty() {
+            return Err(Error::FieldNotFound { field });
+        }
+        apply_codemod_edits(&self.config.root_dir, project_edits).map_err(Error::IOError)
+    }
+
+    /// Replaces the selections of the deprecated fields whose deprecation
+    /// reason names a replacement in the source files of the enabled
+    /// projects, with the `codemod replace-deprecated-fields` command.
+    pub async fn replace_deprecated_fields(&self) -> Result<CodemodSummary> {
+        let project_edits = self
+            .codemod_edits(|program| Some(find_deprecated_field_replacements(program)))
+            .await?;
+        apply_codemod_edits(&self.config.root_dir, project_edits).map_err(Error::IOError)
+    }
+
+    /// Rewrites the `@argumentDefinitions` and `@arguments` directives to the
+    /// fragment arguments syntax in the source files of the enabled projects,
+    /// with the `codemod migrate-fragment-arguments` command.
+    pub async fn migrate_fragment_arguments(&self) -> Result<CodemodSummary> {
+        let setup_event = self.perf_logger.create_event("compiler_codemod");
+        let file_source = FileSource::connect(&self.config, &setup_event).await?;
+        let compiler_state = file_source
+            .query(&setup_event, self.perf_logger.as_ref())
+            .await?;
+        setup_event.complete();
+
+        let project_edits = self
+            .config
+            .enabled_projects()
+            .filter_map(|project_config| compiler_state.graphql_sources.get(&project_config.name))
+            .map(find_fragment_argument_migrations)
+            .collect();
+        apply_codemod_edits(&self.config.root_dir, project_edits).map_err(Error::IOError)
+    }
+
+    /// The edits of a codemod to the IR of each enabled project, skipping the
+    /// projects for which `find_edits` returns `None`.
+    async fn codemod_edits(
+        &self,
+        find_edits: impl Fn(&Program) -> Option<CodemodEdits>,
+    ) -> Result<Vec<CodemodEdits>> {
+        let setup_event = self.perf_logger.create_event("compiler_codemod");
+        let file_source = FileSource::connect(&self.config, &setup_event).await?;
+        let compiler_state = file_source
+            .query(&setup_event, self.perf_logger.as_ref())
+            .await?;
+        let graphql_asts = GraphQLAsts::from_graphql_sources_map(
+            &compiler_state.graphql_sources,
+            &Default::default(),
+        )?;
+        setup_event.complete();
+
+        let mut project_edits = Vec::new();
+        let mut errors = Vec::new();
+        for project_config in self.config.enabled_projects() {
+            match build_codemod_program(
+                project_config,
+                &compiler_state,
+                &graphql_asts,
+                Arc::clone(&self.perf_logger),
+            ) {
+                Ok(program) => project_edits.extend(find_edits(&program)),
+                Err(error) => errors.push(error),
+            }
+        }
+        if errors.is_empty() {
+            Ok(project_edits)
+        } else {
+            Err(Error::BuildProjectsErrors { errors })
+        }
+    }
+
+    /// Pushes the saved state of a build to the `saved_state_storage` of the
+    /// config.
+    pub async fn push_saved_state(&self, compiler_state: &CompilerState) -> Result<()> {
+        push_saved_state(&self.config, compiler_state).await
+    }
+
+    /// Loads the sources of the enabled projects once, to compile the files
+    /// of the requests of the `batch` command against them.
+    pub async fn batch_compiler(&self) -> Result<BatchCompiler<TPerfLogger>> {
+        let setup_event = self.perf_logger.create_event("compiler_batch");
+        let file_source = FileSource::connect(&self.config, &setup_event).await?;
+        let compiler_state = file_source
+            .query(&setup_event, self.perf_logger.as_ref())
+            .await?;
+        setup_event.complete();
+        Ok(BatchCompiler::new(
+            Arc::clone(&self.config),
+            Arc::clone(&self.perf_logger),
+            compiler_state,
+        ))
+    }
+
     pub async fn watch(&self) -> Result<()> {
+        let build_tracker = self.config.on_demand_port.map(|port| {
+            let build_tracker = Arc::new(BuildTracker::default());
+            let config = Arc::clone(&self.config);
+            let server_build_tracker = Arc::clone(&build_tracker);
+            task::spawn(async move {
+                if let Err(err) = serve_on_demand_requests(config, server_build_tracker, port).await
+                {
+                    error!(
+                        "Unable to serve on-demand requests on port {}: {}",
+                        port, err
+                    );
+                }
+            });
+            build_tracker
+        });
+        if let Some(interactive) = &self.config.interactive {
+            interactive.spawn_command_reader();
+        }
+        if let Some((metrics, port)) = &self.metrics {
+            let metrics = Arc::clone(metrics);
+            let port = *port;
+            task::spawn(async move {
+                if let Err(err) = serve_metrics(metrics, port).await {
+                    error!("Unable to serve metrics on port {}: {}", port, err);
+                }
+            });
+        }
+
         'watch: loop {
             let setup_event = self.perf_logger.create_event("compiler_setup");
             self.config.status_reporter.build_starts();
@@ -147,7 +547,10 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
             match result {
                 Ok((mut compiler_state, notify_receiver, subscription_handle)) => {
                     let mut red_to_green = RedToGreen::new();
-                    match self.build_projects(&mut compiler_state, &setup_event).await {
+                    match self
+                        .build_watched_projects(&mut compiler_state, &setup_event, &build_tracker)
+                        .await
+                    {
                         Ok(diagnostics) => {
                             self.config.status_reporter.build_completes(&diagnostics);
                         }
@@ -164,6 +567,7 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
                         compiler_state,
                         notify_receiver,
                         &subscription_handle,
+                        &build_tracker,
                     )
                     .await;
                 }
@@ -180,11 +584,55 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
         mut compiler_state: CompilerState,
         notify_receiver: Arc<Notify>,
         subscription_handle: &JoinHandle<()>,
+        build_tracker: &Option<Arc<BuildTracker>>,
     ) {
         let mut red_to_green = RedToGreen::new();
+        let mut schema_poll = self
+            .config
+            .watch
+            .schema_poll_interval_ms
+            .map(|interval_ms| {
+                let period = Duration::from_millis(interval_ms);
+                let mut schema_poll = time::interval_at(time::Instant::now() + period, period);
+                schema_poll.set_missed_tick_behavior(MissedTickBehavior::Delay);
+                schema_poll
+            });
 
         loop {
-            notify_receiver.notified().await;
+            let rebuild_requested = async {
+                match &self.config.interactive {
+                    Some(interactive) => interactive.rebuild_requested().await,
+                    None => future::pending().await,
+                }
+            };
+            let schema_polled = async {
+                match &mut schema_poll {
+                    Some(schema_poll) => {
+                        schema_poll.tick().await;
+                    }
+                    None => future::pending().await,
+                }
+            };
+            tokio::select! {
+                _ = notify_receiver.notified() => {}
+                // The watch loop makes a full build with a new subscription.
+                _ = rebuild_requested => {
+                    subscription_handle.abort();
+                    return;
+                }
+                _ = schema_polled => {
+                    match compiler_state.changed_schema_files(&self.config.root_dir) {
+                        Some(changed_schema_files) => {
+                            compiler_state
+                                .pending_file_source_changes
+                                .write()
+                                .unwrap()
+                                .push(changed_schema_files);
+                        }
+                        None => continue,
+                    }
+                }
+            }
 
             if compiler_state.source_control_update_status.is_started() {
                 continue;
@@ -195,9 +643,17 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
                 return;
             }
 
-            // Single change to file sometimes produces 2 watchman change events for the same file
-            // wait for 50ms in case there is a subsequent request
-            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            // A single change to a file sometimes produces 2 watchman change
+            // events, and operations like a rebase produce many: wait for the
+            // batch of changes to be complete before compiling them together.
+            self.config
+                .watch
+                .wait_for_batch(&notify_receiver, || compiler_state.pending_file_count())
+                .await;
+            if compiler_state.source_control_update_status.is_completed() {
+                subscription_handle.abort();
+                return;
+            }
 
             if compiler_state.has_pending_file_source_changes() {
                 let incremental_build_event =
@@ -224,7 +680,11 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
                     info!("Change detected, start compiling...");
 
                     match self
-                        .build_projects(&mut compiler_state, &incremental_build_event)
+                        .build_watched_projects(
+                            &mut compiler_state,
+                            &incremental_build_event,
+                            build_tracker,
+                        )
                         .await
                     {
                         Ok(diagnostics) => {
@@ -248,11 +708,35 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
         }
     }
 
+    /// Builds the projects like `build_projects`, and reports the build to
+    /// the on-demand endpoint and the metrics if there are any.
+    async fn build_watched_projects(
+        &self,
+        compiler_state: &mut CompilerState,
+        setup_event: &impl PerfLogEvent,
+        build_tracker: &Option<Arc<BuildTracker>>,
+    ) -> Result<Vec<Diagnostic>> {
+        let result = match build_tracker {
+            Some(build_tracker) => {
+                let files = build_tracker.start_build(compiler_state);
+                let result = self.build_projects(compiler_state, setup_event).await;
+                build_tracker.complete_build(files, &result);
+                result
+            }
+            None => self.build_projects(compiler_state, setup_event).await,
+        };
+        if let Some((metrics, _)) = &self.metrics {
+            metrics.record_build(&result);
+        }
+        result
+    }
+
     async fn build_projects(
         &self,
         compiler_state: &mut CompilerState,
         setup_event: &impl PerfLogEvent,
     ) -> Result<Vec<Diagnostic>> {
+        self.config.events.build_started();
         let build_projects_time = setup_event.start("build_projects_time");
         let result = build_projects(
             Arc::clone(&self.config),
@@ -262,10 +746,18 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
         )
         .await;
         setup_event.stop(build_projects_time);
-        setup_event.time("post_build_projects_time", || {
+        let result = setup_event.time("post_build_projects_time", || {
             result.and_then(|diagnostics| {
                 compiler_state.complete_compilation();
                 self.config.artifact_writer.finalize()?;
+                if let Some(build_manifest) = &self.config.build_manifest {
+                    write_build_manifest(
+                        build_manifest,
+                        &self.config.root_dir,
+                        &compiler_state.artifacts,
+                    )
+                    .map_err(Error::IOError)?;
+                }
                 if let Some(post_artifacts_write) = &self.config.post_artifacts_write {
                     post_artifacts_write(&self.config)
                         .map_err(|error| Error::PostArtifactsError { error })?;
@@ -273,7 +765,10 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
 
                 Ok(diagnostics)
             })
-        })
+        });
+        self.config.hooks.run(&self.config.root_dir, &result);
+        self.config.events.build_finished(&result);
+        result
     }
 }
 
@@ -284,20 +779,53 @@ async fn build_projects<TPerfLogger: PerfLogger + 'static>(
     compiler_state: &mut CompilerState,
 ) -> Result<Vec<Diagnostic>> {
     let mut graphql_asts = setup_event.time("parse_sources_time", || {
-        GraphQLAsts::from_graphql_sources_map(
-            &compiler_state.graphql_sources,
-            &compiler_state.get_dirty_definitions(&config),
-        )
+        config.events.time("parse_sources", None, || {
+            GraphQLAsts::from_graphql_sources_map(
+                &compiler_state.graphql_sources,
+                &compiler_state.get_dirty_definitions(&config),
+            )
+        })
     })?;
 
+    if !config.unique_definition_name_groups.is_empty() {
+        setup_event.time("validate_unique_definition_names_time", || {
+            validate_unique_definition_names(&config.unique_definition_name_groups, &graphql_asts)
+                .map_err(|errors| Error::DiagnosticsError { errors })
+        })?;
+    }
+
     if compiler_state.should_cancel_current_build() {
         debug!("Build is cancelled: new file changes are pending.");
         return Err(Error::Cancelled);
     }
 
+    // Only the first build restores artifacts from the cache.
+    let cached_projects = match &config.artifact_cache {
+        Some(artifact_cache) if !compiler_state.has_processed_changes() => {
+            let project_names = config
+                .enabled_projects()
+                .filter(|project_config| {
+                    compiler_state.project_has_pending_changes(project_config.name)
+                })
+                .map(|project_config| project_config.name)
+                .collect();
+            restore_cached_projects(
+                &config,
+                artifact_cache.as_ref(),
+                compiler_state,
+                project_names,
+            )
+            .await?
+        }
+        _ => Default::default(),
+    };
+
     let build_results: Vec<_> = config
         .par_enabled_projects()
         .filter(|project_config| {
+            if cached_projects.is_restored(project_config.name) {
+                return false;
+            }
             if let Some(base) = project_config.base {
                 if compiler_state.project_has_pending_changes(base) {
                     return true;
@@ -306,13 +834,17 @@ async fn build_projects<TPerfLogger: PerfLogger + 'static>(
             compiler_state.project_has_pending_changes(project_config.name)
         })
         .map(|project_config| {
-            build_project(
-                &config,
-                project_config,
-                compiler_state,
-                &graphql_asts,
-                Arc::clone(&perf_logger),
-            )
+            config
+                .events
+                .time("build_project", Some(project_config.name), || {
+                    build_project(
+                        &config,
+                        project_config,
+                        compiler_state,
+                        &graphql_asts,
+                        Arc::clone(&perf_logger),
+                    )
+                })
         })
         .collect();
     let mut results = Vec::new();
@@ -351,10 +883,13 @@ async fn build_projects<TPerfLogger: PerfLogger + 'static>(
             .get(&project_name)
             .cloned()
             .unwrap_or_else(|| Arc::new(ArtifactMapKind::Unconnected(Default::default())));
-        let removed_definition_names = graphql_asts
+        let GraphQLAsts {
+            removed_definition_names,
+            removed_source_files,
+            ..
+        } = graphql_asts
             .remove(&project_name)
-            .expect("Expect GraphQLAsts to exist.")
-            .removed_definition_names;
+            .expect("Expect GraphQLAsts to exist.");
         let dirty_artifact_paths = compiler_state
             .dirty_artifact_paths
             .get(&project_name)
@@ -364,26 +899,27 @@ async fn build_projects<TPerfLogger: PerfLogger + 'static>(
         let source_control_update_status = Arc::clone(&compiler_state.source_control_update_status);
         handles.push(task::spawn(async move {
             let project_config = &config.projects[&project_name];
-            Ok((
-                (
-                    project_name,
-                    commit_project(
-                        &config,
-                        project_config,
-                        perf_logger,
-                        &schema,
-                        programs,
-                        artifacts,
-                        artifact_map,
-                        removed_definition_names,
-                        dirty_artifact_paths,
-                        source_control_update_status,
-                    )
-                    .await?,
-                    schema,
-                ),
-                diagnostics,
-            ))
+            let commit_start = Instant::now();
+            let next_artifact_map = commit_project(
+                &config,
+                project_config,
+                perf_logger,
+                &schema,
+                programs,
+                artifacts,
+                artifact_map,
+                removed_definition_names,
+                removed_source_files,
+                dirty_artifact_paths,
+                source_control_update_status,
+            )
+            .await;
+            config.events.record_phase(PhaseTiming {
+                phase: "commit_project",
+                project_name: Some(project_name),
+                duration: commit_start.elapsed(),
+            });
+            Ok(((project_name, next_artifact_map?, schema), diagnostics))
         }));
     }
 
@@ -421,5 +957,15 @@ async fn build_projects<TPerfLogger: PerfLogger + 'static>(
         return Err(Error::Cancelled);
     }
 
+    if let Some(artifact_cache) = &config.artifact_cache {
+        upload_built_projects(
+            &config,
+            artifact_cache.as_ref(),
+            compiler_state,
+            cached_projects,
+        )
+        .await;
+    }
+
     Ok(all_diagnostics)
 }
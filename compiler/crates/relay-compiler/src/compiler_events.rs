@@ -0,0 +1,279 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Events of the builds for the `CompilerEventListener`s of the config, so
+//! that embedders of the compiler can report telemetry without parsing the
+//! logs. The listeners are registered on `Config::events` before the config
+//! is passed to the compiler:
+//!
+//! ```ignore
+//! config.events.add_listener(Box::new(AnalyticsListener::new()));
+//! ```
+
+use std::mem;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use common::Diagnostic;
+use common::DiagnosticSeverity;
+
+use crate::compiler_state::ProjectName;
+use crate::errors::Error;
+use crate::errors::Result;
+use crate::metrics::error_count;
+
+/// Receives the events of the builds. The methods are called from the
+/// threads of the compiler, and should return quickly.
+pub trait CompilerEventListener: Send + Sync {
+    fn build_started(&self) {}
+
+    /// A phase of the current build completed.
+    fn phase_completed(&self, _timing: &PhaseTiming) {}
+
+    /// The current build finished, or was cancelled by new changes.
+    fn build_finished(&self, _summary: &BuildSummary) {}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildOutcome {
+    Success,
+    Failure,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhaseTiming {
+    /// The name of the phase, like `build_project`.
+    pub phase: &'static str,
+    /// The project of the phase, if it is run for each project.
+    pub project_name: Option<ProjectName>,
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildSummary {
+    pub outcome: BuildOutcome,
+    pub duration: Duration,
+    pub phases: Vec<PhaseTiming>,
+    pub errors: usize,
+    pub warnings: usize,
+    /// The artifacts written by the build, relative to the root directory.
+    pub written_artifacts: Vec<(ProjectName, PathBuf)>,
+    /// The artifacts removed by the build, relative to the root directory.
+    pub removed_artifacts: Vec<(ProjectName, PathBuf)>,
+}
+
+#[derive(Debug, Default)]
+struct BuildRecord {
+    start: Option<Instant>,
+    phases: Vec<PhaseTiming>,
+    written_artifacts: Vec<(ProjectName, PathBuf)>,
+    removed_artifacts: Vec<(ProjectName, PathBuf)>,
+}
+
+/// The listeners of the config, and the events of the current build.
+#[derive(Default)]
+pub struct CompilerEvents {
+    listeners: Vec<Box<dyn CompilerEventListener>>,
+    build: Mutex<BuildRecord>,
+}
+
+impl CompilerEvents {
+    pub fn add_listener(&mut self, listener: Box<dyn CompilerEventListener>) {
+        self.listeners.push(listener);
+    }
+
+    fn is_empty(&self) -> bool {
+        self.listeners.is_empty()
+    }
+
+    pub fn build_started(&self) {
+        if self.is_empty() {
+            return;
+        }
+        *self.build.lock().unwrap() = BuildRecord {
+            start: Some(Instant::now()),
+            ..Default::default()
+        };
+        for listener in &self.listeners {
+            listener.build_started();
+        }
+    }
+
+    /// Runs a phase of the current build, and reports its duration.
+    pub fn time<T>(
+        &self,
+        phase: &'static str,
+        project_name: Option<ProjectName>,
+        run: impl FnOnce() -> T,
+    ) -> T {
+        if self.is_empty() {
+            return run();
+        }
+        let start = Instant::now();
+        let result = run();
+        self.record_phase(PhaseTiming {
+            phase,
+            project_name,
+            duration: start.elapsed(),
+        });
+        result
+    }
+
+    /// Reports a phase of the current build timed by the caller.
+    pub fn record_phase(&self, timing: PhaseTiming) {
+        if self.is_empty() {
+            return;
+        }
+        for listener in &self.listeners {
+            listener.phase_completed(&timing);
+        }
+        self.build.lock().unwrap().phases.push(timing);
+    }
+
+    /// Records an artifact written by the current build, relative to the
+    /// root directory.
+    pub fn record_written(&self, project_name: ProjectName, path: &Path) {
+        if !self.is_empty() {
+            self.build
+                .lock()
+                .unwrap()
+                .written_artifacts
+                .push((project_name, path.to_owned()));
+        }
+    }
+
+    /// Records an artifact removed by the current build, relative to the
+    /// root directory.
+    pub fn record_removed(&self, project_name: ProjectName, path: &Path) {
+        if !self.is_empty() {
+            self.build
+                .lock()
+                .unwrap()
+                .removed_artifacts
+                .push((project_name, path.to_owned()));
+        }
+    }
+
+    /// Reports the result of the current build.
+    pub fn build_finished(&self, result: &Result<Vec<Diagnostic>>) {
+        if self.is_empty() {
+            return;
+        }
+        let summary = summarize_build(mem::take(&mut *self.build.lock().unwrap()), result);
+        for listener in &self.listeners {
+            listener.build_finished(&summary);
+        }
+    }
+}
+
+fn summarize_build(mut build: BuildRecord, result: &Result<Vec<Diagnostic>>) -> BuildSummary {
+    let (outcome, errors, warnings) = match result {
+        Ok(diagnostics) => {
+            let errors = diagnostics
+                .iter()
+                .filter(|diagnostic| diagnostic.severity() == DiagnosticSeverity::ERROR)
+                .count();
+            (BuildOutcome::Success, errors, diagnostics.len() - errors)
+        }
+        Err(Error::Cancelled) => (BuildOutcome::Cancelled, 0, 0),
+        Err(error) => (BuildOutcome::Failure, error_count(error), 0),
+    };
+    build.written_artifacts.sort();
+    build.written_artifacts.dedup();
+    build.removed_artifacts.sort();
+    build.removed_artifacts.dedup();
+    BuildSummary {
+        outcome,
+        duration: build.start.map(|start| start.elapsed()).unwrap_or_default(),
+        phases: build.phases,
+        errors,
+        warnings,
+        written_artifacts: build.written_artifacts,
+        removed_artifacts: build.removed_artifacts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use intern::string_key::Intern;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct RecordingListener {
+        phases: Mutex<Vec<&'static str>>,
+        summaries: Mutex<Vec<BuildSummary>>,
+    }
+
+    impl CompilerEventListener for Arc<RecordingListener> {
+        fn phase_completed(&self, timing: &PhaseTiming) {
+            self.phases.lock().unwrap().push(timing.phase);
+        }
+
+        fn build_finished(&self, summary: &BuildSummary) {
+            self.summaries.lock().unwrap().push(summary.clone());
+        }
+    }
+
+    #[test]
+    fn test_build_summary() {
+        let listener = Arc::new(RecordingListener::default());
+        let mut events = CompilerEvents::default();
+        events.add_listener(Box::new(Arc::clone(&listener)));
+        let project_name: ProjectName = "test".intern();
+
+        events.build_started();
+        assert_eq!(events.time("parse_sources", None, || 42), 42);
+        events.record_written(project_name, Path::new("b.graphql.js"));
+        events.record_written(project_name, Path::new("a.graphql.js"));
+        events.record_written(project_name, Path::new("a.graphql.js"));
+        events.record_removed(project_name, Path::new("c.graphql.js"));
+        events.build_finished(&Ok(Vec::new()));
+
+        assert_eq!(*listener.phases.lock().unwrap(), vec!["parse_sources"]);
+        let summaries = listener.summaries.lock().unwrap();
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].outcome, BuildOutcome::Success);
+        assert_eq!(summaries[0].phases.len(), 1);
+        assert_eq!(
+            summaries[0].written_artifacts,
+            vec![
+                (project_name, PathBuf::from("a.graphql.js")),
+                (project_name, PathBuf::from("b.graphql.js")),
+            ]
+        );
+        assert_eq!(
+            summaries[0].removed_artifacts,
+            vec![(project_name, PathBuf::from("c.graphql.js"))]
+        );
+    }
+
+    #[test]
+    fn test_failed_build() {
+        let listener = Arc::new(RecordingListener::default());
+        let mut events = CompilerEvents::default();
+        events.add_listener(Box::new(Arc::clone(&listener)));
+
+        events.build_started();
+        events.build_finished(&Err(Error::ConfigError {
+            details: "invalid".to_string(),
+        }));
+        events.build_started();
+        events.build_finished(&Err(Error::Cancelled));
+
+        let summaries = listener.summaries.lock().unwrap();
+        assert_eq!(summaries[0].outcome, BuildOutcome::Failure);
+        assert_eq!(summaries[0].errors, 1);
+        assert_eq!(summaries[1].outcome, BuildOutcome::Cancelled);
+    }
+}
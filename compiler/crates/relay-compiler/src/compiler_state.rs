@@ -12,6 +12,7 @@ use std::fs::File as FsFile;
 use std::hash::Hash;
 use std::io::BufReader;
 use std::io::BufWriter;
+use std::path::Path;
 use std::path::PathBuf;
 use std::slice;
 use std::sync::Arc;
@@ -27,10 +28,17 @@ use fnv::FnvBuildHasher;
 use fnv::FnvHashMap;
 use fnv::FnvHashSet;
 use intern::string_key::StringKey;
+use intern::Lookup;
+use log::info;
 use rayon::prelude::*;
 use relay_config::SchemaConfig;
+use schema::ObjectID;
 use schema::SDLSchema;
+use schema::Schema;
+use schema_diff::definitions::ArgumentChange;
+use schema_diff::definitions::DefinitionChange;
 use schema_diff::definitions::SchemaChange;
+use schema_diff::definitions::TypeChange;
 use schema_diff::detect_changes;
 use serde::Deserialize;
 use serde::Serialize;
@@ -38,13 +46,17 @@ use zstd::stream::read::Decoder as ZstdDecoder;
 use zstd::stream::write::Encoder as ZstdEncoder;
 
 use crate::artifact_map::ArtifactMap;
+use crate::build_project::build_schema::schema_options;
 use crate::config::Config;
 use crate::errors::Error;
 use crate::errors::Result;
 use crate::file_source::categorize_files;
+use crate::file_source::extract_javascript_features;
 use crate::file_source::extract_javascript_features_from_file;
+use crate::file_source::find_generated_files;
 use crate::file_source::read_file_to_string;
 use crate::file_source::Clock;
+use crate::file_source::ExternalFileSourceResult;
 use crate::file_source::File;
 use crate::file_source::FileGroup;
 use crate::file_source::FileSourceResult;
@@ -351,6 +363,27 @@ impl CompilerState {
             }
         }
 
+        if config.prune_stale_artifacts {
+            setup_event.time("find_generated_files_time", || {
+                for (project_name, generated_files) in find_generated_files(config) {
+                    match result.artifacts.entry(project_name) {
+                        Entry::Occupied(mut entry) => {
+                            if let Some(ArtifactMapKind::Unconnected(existing_artifacts)) =
+                                Arc::get_mut(entry.get_mut())
+                            {
+                                existing_artifacts.extend(generated_files);
+                            }
+                        }
+                        Entry::Vacant(entry) => {
+                            entry.insert(Arc::new(ArtifactMapKind::Unconnected(
+                                generated_files.into_iter().collect(),
+                            )));
+                        }
+                    }
+                }
+            });
+        }
+
         Ok(result)
     }
 
@@ -412,15 +445,20 @@ impl CompilerState {
         if schema_change == SchemaChange::None {
             true
         } else {
+            info!(
+                "Schema changed: {}",
+                summarize_schema_change(&schema_change)
+            );
             let current_sources_with_location = sources
                 .get_sources_with_location()
                 .into_iter()
                 .map(|(schema, location_key)| (schema.as_str(), location_key))
                 .collect::<Vec<_>>();
 
-            match relay_schema::build_schema_with_extensions(
+            match relay_schema::build_schema_with_extensions_and_options(
                 &current_sources_with_location,
                 &Vec::<(&str, SourceLocationKey)>::new(),
+                schema_options(schema_config),
             ) {
                 Ok(schema) => schema_change.is_safe(&schema, schema_config),
                 Err(_) => false,
@@ -454,10 +492,13 @@ impl CompilerState {
                 return true;
             }
         }
-        if let Some(docblocks) = self.docblocks.get(&project_name) {
-            if !docblocks.pending.is_empty() {
-                return true;
-            }
+        // Changes to the docblocks of Relay Resolvers are not breaking: the
+        // documents that select the fields and types of the changed files are
+        // rebuilt, see `pending_docblock_files`. The types the files defined
+        // may however be removed from interfaces or unions, which the new
+        // schema doesn't tell.
+        if self.has_pending_abstract_type_docblock_change(project_name) {
+            return true;
         }
         if let Some(schema) = self.schemas.get(&project_name) {
             if !(schema.pending.is_empty() || self.is_change_safe(schema, schema_config)) {
@@ -467,6 +508,46 @@ impl CompilerState {
         false
     }
 
+    /// The files of the project with pending changes to the docblocks of their
+    /// Relay Resolvers, which are the paths of the source locations of the
+    /// schema extensions built from these docblocks.
+    pub fn pending_docblock_files(&self, project_name: ProjectName) -> Vec<PathBuf> {
+        self.docblocks
+            .get(&project_name)
+            .map_or_else(Vec::new, |docblocks| {
+                docblocks.pending.keys().cloned().collect()
+            })
+    }
+
+    /// Whether the files of the pending docblock changes of the project
+    /// defined types implementing an interface or members of a union in the
+    /// schema of the previous build, or there is no previous build to tell.
+    fn has_pending_abstract_type_docblock_change(&self, project_name: ProjectName) -> bool {
+        let files = self.pending_docblock_files(project_name);
+        if files.is_empty() {
+            return false;
+        }
+        let schema = match self.schema_cache.get(&project_name) {
+            Some(schema) => schema,
+            None => return true,
+        };
+        let is_object_in_files =
+            |id: &ObjectID| match schema.object(*id).name.location.source_location() {
+                SourceLocationKey::Embedded { path, .. } => files
+                    .iter()
+                    .any(|file| file.as_path() == Path::new(path.lookup())),
+                _ => false,
+            };
+        schema.interfaces().any(|interface| {
+            interface
+                .implementing_objects
+                .iter()
+                .any(is_object_in_files)
+        }) || schema
+            .unions()
+            .any(|union| union.members.iter().any(is_object_in_files))
+    }
+
     /// Merges pending changes from the file source into the compiler state.
     /// Returns a boolean indicating if any new changes were merged.
     pub fn merge_file_source_changes(
@@ -649,10 +730,72 @@ impl CompilerState {
             })
     }
 
+    /// The schema files of the projects whose contents on disk differ from
+    /// the last read ones, as changes of the file source to merge, if there
+    /// are any. Used to poll the schemas in watch mode.
+    pub fn changed_schema_files(&self, root_dir: &Path) -> Option<FileSourceResult> {
+        let mut paths: Vec<&PathBuf> = Vec::new();
+        for sources in self.schemas.values() {
+            for (path, processed) in sources.processed.iter() {
+                if paths.contains(&path) {
+                    continue;
+                }
+                let contents = sources.pending.get(path).unwrap_or(processed);
+                match std::fs::read_to_string(root_dir.join(path)) {
+                    Ok(current) if &current == contents => {}
+                    _ => paths.push(path),
+                }
+            }
+        }
+        if paths.is_empty() {
+            return None;
+        }
+        paths.sort();
+        Some(FileSourceResult::External(ExternalFileSourceResult {
+            files: paths
+                .into_iter()
+                .map(|path| File {
+                    name: path.clone(),
+                    exists: root_dir.join(path).exists(),
+                })
+                .collect(),
+            resolved_root: root_dir.to_path_buf(),
+        }))
+    }
+
     pub fn has_pending_file_source_changes(&self) -> bool {
         !self.pending_file_source_changes.read().unwrap().is_empty()
     }
 
+    /// The number of files of the pending file source changes.
+    pub fn pending_file_count(&self) -> usize {
+        self.pending_file_source_changes
+            .read()
+            .unwrap()
+            .iter()
+            .map(|file_source_changes| file_source_changes.size())
+            .sum()
+    }
+
+    /// Sets the sources extracted from the unsaved `contents` of the source
+    /// file `path` as pending changes of the projects of `project_set`.
+    pub fn set_pending_file_contents(
+        &mut self,
+        project_set: &ProjectSet,
+        path: &Path,
+        contents: &str,
+    ) {
+        let features = extract_javascript_features(path, contents);
+        let mut graphql_sources: GraphQLSourceSet = FnvHashMap::default();
+        graphql_sources.insert(path.to_path_buf(), features.graphql_sources);
+        let mut docblock_sources: DocblockSourceSet = FnvHashMap::default();
+        docblock_sources.insert(path.to_path_buf(), features.docblock_sources);
+        for project_name in project_set.iter() {
+            self.set_pending_source_set(*project_name, &graphql_sources);
+            self.set_pending_docblock_set(*project_name, &docblock_sources);
+        }
+    }
+
     fn set_pending_source_set(&mut self, project_name: ProjectName, source_set: &GraphQLSourceSet) {
         let entry = &mut self.graphql_sources.entry(project_name).or_default();
         entry.merge_pending_sources(source_set);
@@ -743,6 +886,145 @@ fn extract_sources(
     Ok((graphql_sources, docblock_sources))
 }
 
+/// The types added, removed and changed by `schema_change`, with the fields
+/// and members of the changed types, for the logs. The names are truncated
+/// after `MAX_LOGGED_NAMES` in each list, the changes can be large for
+/// generated schemas.
+fn summarize_schema_change(schema_change: &SchemaChange) -> String {
+    let changes = match schema_change {
+        SchemaChange::None => return "none".to_string(),
+        SchemaChange::GenericChange => return "generic change".to_string(),
+        SchemaChange::InvalidSchema => return "invalid schema".to_string(),
+        SchemaChange::DefinitionChanges(changes) => changes,
+    };
+    let mut added = vec![];
+    let mut removed = vec![];
+    let mut changed = vec![];
+    for change in changes {
+        match change {
+            DefinitionChange::EnumAdded(name)
+            | DefinitionChange::UnionAdded(name)
+            | DefinitionChange::ScalarAdded(name)
+            | DefinitionChange::InputObjectAdded(name)
+            | DefinitionChange::InterfaceAdded(name)
+            | DefinitionChange::ObjectAdded(name) => added.push(name.to_string()),
+            DefinitionChange::EnumRemoved(name)
+            | DefinitionChange::UnionRemoved(name)
+            | DefinitionChange::ScalarRemoved(name)
+            | DefinitionChange::InputObjectRemoved(name)
+            | DefinitionChange::InterfaceRemoved(name)
+            | DefinitionChange::ObjectRemoved(name) => removed.push(name.to_string()),
+            DefinitionChange::EnumChanged { name } => changed.push(name.to_string()),
+            DefinitionChange::UnionChanged {
+                name,
+                added,
+                removed,
+            } => changed.push(summarize_type_change(
+                *name,
+                &[("added members", added), ("removed members", removed)],
+            )),
+            DefinitionChange::InputObjectChanged {
+                name,
+                added,
+                removed,
+            } => changed.push(summarize_type_change(
+                *name,
+                &[
+                    ("added fields", &field_names(added)),
+                    ("removed fields", &field_names(removed)),
+                ],
+            )),
+            DefinitionChange::InterfaceChanged {
+                name,
+                added,
+                removed,
+                changed: arguments_changed,
+            } => changed.push(summarize_type_change(
+                *name,
+                &[
+                    ("added fields", &field_names(added)),
+                    ("removed fields", &field_names(removed)),
+                    (
+                        "changed arguments of",
+                        &argument_field_names(arguments_changed),
+                    ),
+                ],
+            )),
+            DefinitionChange::ObjectChanged {
+                name,
+                added,
+                removed,
+                changed: arguments_changed,
+                interfaces_added,
+                interfaces_removed,
+            } => changed.push(summarize_type_change(
+                *name,
+                &[
+                    ("added fields", &field_names(added)),
+                    ("removed fields", &field_names(removed)),
+                    (
+                        "changed arguments of",
+                        &argument_field_names(arguments_changed),
+                    ),
+                    ("added interfaces", interfaces_added),
+                    ("removed interfaces", interfaces_removed),
+                ],
+            )),
+        }
+    }
+    let summary = [
+        ("added types", added),
+        ("removed types", removed),
+        ("changed types", changed),
+    ]
+    .iter()
+    .filter(|(_, names)| !names.is_empty())
+    .map(|(label, names)| format!("{}: {}", label, join_names(names)))
+    .collect::<Vec<_>>();
+    if summary.is_empty() {
+        "none".to_string()
+    } else {
+        summary.join("; ")
+    }
+}
+
+const MAX_LOGGED_NAMES: usize = 20;
+
+/// `Name (label: a, b; label: c)`, without the empty lists.
+fn summarize_type_change(name: StringKey, changes: &[(&str, &Vec<StringKey>)]) -> String {
+    let changes = changes
+        .iter()
+        .filter(|(_, names)| !names.is_empty())
+        .map(|(label, names)| format!("{}: {}", label, join_names(names)))
+        .collect::<Vec<_>>();
+    if changes.is_empty() {
+        name.to_string()
+    } else {
+        format!("{} ({})", name, changes.join("; "))
+    }
+}
+
+fn join_names(names: &[impl fmt::Display]) -> String {
+    let mut joined = names
+        .iter()
+        .take(MAX_LOGGED_NAMES)
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    if names.len() > MAX_LOGGED_NAMES {
+        joined.push_str(&format!(" and {} more", names.len() - MAX_LOGGED_NAMES));
+    }
+    joined
+}
+
+fn field_names(changes: &[TypeChange]) -> Vec<StringKey> {
+    changes.iter().map(|change| change.name).collect()
+}
+
+fn argument_field_names(changes: &[ArgumentChange]) -> Vec<StringKey> {
+    changes.iter().map(|change| change.name).collect()
+}
+
 /// A module to serialize a watchman Clock value via JSON.
 /// The reason is that `Clock` internally uses an untagged enum value
 /// which requires "self descriptive" serialization formats and `bincode` does not
@@ -794,6 +1076,8 @@ mod clock_json_string {
 
 #[cfg(test)]
 mod tests {
+    use intern::string_key::Intern;
+
     // Note this useful idiom: importing names from outer (for mod tests) scope.
     use super::*;
 
@@ -803,6 +1087,125 @@ mod tests {
         }
     }
 
+    #[test]
+    fn summarize_schema_change_test() {
+        let schema_change = SchemaChange::DefinitionChanges(vec![
+            DefinitionChange::ObjectAdded("User".intern()),
+            DefinitionChange::EnumAdded("Color".intern()),
+            DefinitionChange::ScalarRemoved("Date".intern()),
+            DefinitionChange::EnumChanged {
+                name: "Size".intern(),
+            },
+            DefinitionChange::ObjectChanged {
+                name: "Query".intern(),
+                added: vec![TypeChange {
+                    name: "me".intern(),
+                    type_: schema_diff::definitions::Type::Named("User".intern()),
+                }],
+                removed: vec![],
+                changed: vec![],
+                interfaces_added: vec!["Node".intern()],
+                interfaces_removed: vec![],
+            },
+        ]);
+        assert_eq!(
+            summarize_schema_change(&schema_change),
+            "added types: User, Color; removed types: Date; changed types: Size, Query (added fields: me; added interfaces: Node)"
+        );
+        assert_eq!(
+            summarize_schema_change(&SchemaChange::GenericChange),
+            "generic change"
+        );
+    }
+
+    #[test]
+    fn abstract_type_docblock_change_is_breaking_test() {
+        let project_name = "test".intern();
+        let mut compiler_state = CompilerState::default();
+        let schema_config = SchemaConfig::default();
+        compiler_state
+            .docblocks
+            .entry(project_name)
+            .or_default()
+            .pending
+            .insert(PathBuf::from("src/CommentResolver.js"), vec![]);
+        // Without a previous build, the types of the file are unknown
+        assert!(compiler_state.has_breaking_schema_change(project_name, &schema_config));
+
+        let build_schema = |file: &str, extension: &str| {
+            Arc::new(
+                relay_schema::build_schema_with_extensions(
+                    &[(
+                        "type Query { node: Node } interface Node { id: ID! }",
+                        SourceLocationKey::generated(),
+                    )],
+                    &[(extension, SourceLocationKey::embedded(file, 0))],
+                )
+                .unwrap(),
+            )
+        };
+        compiler_state.schema_cache.insert(
+            project_name,
+            build_schema(
+                "src/CommentResolver.js",
+                "type Comment implements Node { id: ID! }",
+            ),
+        );
+        assert!(compiler_state.has_breaking_schema_change(project_name, &schema_config));
+
+        compiler_state.schema_cache.insert(
+            project_name,
+            build_schema("src/CommentResolver.js", "type Comment { id: ID! }"),
+        );
+        assert!(!compiler_state.has_breaking_schema_change(project_name, &schema_config));
+    }
+
+    #[test]
+    fn changed_schema_files_test() {
+        let root_dir = tempfile::tempdir().unwrap();
+        let write = |path: &str, contents: &str| {
+            std::fs::write(root_dir.path().join(path), contents).unwrap();
+        };
+        write("schema.graphql", "type Query { a: Int }");
+        write("other.graphql", "type User { id: ID }");
+        let mut compiler_state = CompilerState::default();
+        for (project_name, path, contents) in [
+            ("a", "schema.graphql", "type Query { a: Int }"),
+            ("a", "other.graphql", "type User { id: ID }"),
+            ("b", "schema.graphql", "type Query { a: Int }"),
+        ] {
+            compiler_state
+                .schemas
+                .entry(project_name.intern())
+                .or_default()
+                .processed
+                .insert(PathBuf::from(path), contents.to_string());
+        }
+        assert!(
+            compiler_state
+                .changed_schema_files(root_dir.path())
+                .is_none()
+        );
+
+        // A file shared by the projects is reported once
+        write("schema.graphql", "type Query { b: Int }");
+        std::fs::remove_file(root_dir.path().join("other.graphql")).unwrap();
+        match compiler_state.changed_schema_files(root_dir.path()) {
+            Some(FileSourceResult::External(result)) => {
+                let files = result
+                    .files
+                    .iter()
+                    .map(|file| (file.name.to_str().unwrap(), file.exists))
+                    .collect::<Vec<_>>();
+                assert_eq!(
+                    files,
+                    vec![("other.graphql", false), ("schema.graphql", true)]
+                );
+            }
+            _ => panic!("Expected the changed schema files"),
+        }
+    }
+
     #[test]
     fn empty_pending_incremental_source_overwrites_existing_pending_source() {
         let mut incremental_source: IncrementalSources<Vec<u32>> = IncrementalSources::default();
@@ -29,7 +29,10 @@ use log::warn;
 use persist_query::PersistError;
 use rayon::prelude::*;
 use regex::Regex;
+use relay_config::parse_schema_removals;
+use relay_config::ArtifactFilenameConfig;
 use relay_config::CustomScalarType;
+use relay_config::CustomScalarTypeImport;
 use relay_config::DiagnosticReportConfig;
 use relay_config::FlowTypegenConfig;
 use relay_config::JsModuleFormat;
@@ -40,9 +43,13 @@ pub use relay_config::ProjectConfig;
 pub use relay_config::RemotePersistConfig;
 use relay_config::SchemaConfig;
 pub use relay_config::SchemaLocation;
+use relay_config::SchemaVariant;
+use relay_config::SchemaVariantConfig;
+use relay_config::TypeNamesConfig;
 use relay_config::TypegenConfig;
 use relay_config::TypegenLanguage;
 use relay_transforms::CustomTransformsConfig;
+pub use relay_transforms::DebugTransformsConfig;
 use serde::de::Error as DeError;
 use serde::Deserialize;
 use serde::Deserializer;
@@ -52,18 +59,36 @@ use sha1::Digest;
 use sha1::Sha1;
 use watchman_client::pdu::ScmAwareClockData;
 
+use crate::artifact_cache::ArtifactCache;
+use crate::artifact_cache::ArtifactCacheConfig;
+use crate::artifact_cache::HttpArtifactCache;
+use crate::artifact_signing::create_artifact_signer;
+use crate::artifact_signing::ArtifactSigner;
+use crate::artifact_signing::ArtifactSigningConfig;
+use crate::baseline::Baseline;
 use crate::build_project::artifact_writer::ArtifactFileWriter;
 use crate::build_project::artifact_writer::ArtifactWriter;
 use crate::build_project::generate_extra_artifacts::GenerateExtraArtifactsFn;
 use crate::build_project::AdditionalValidations;
+use crate::build_project::CustomValidation;
+use crate::compiler_events::CompilerEvents;
 use crate::compiler_state::ProjectName;
 use crate::compiler_state::ProjectSet;
 use crate::errors::ConfigValidationError;
 use crate::errors::Error;
 use crate::errors::Result;
+use crate::graphql_config::GraphQLConfigFile;
+use crate::hooks::Hooks;
+use crate::hooks::HooksConfig;
+use crate::interactive::InteractiveWatch;
+use crate::saved_state::HttpSavedStateStorage;
 use crate::saved_state::SavedStateLoader;
+use crate::saved_state::SavedStateStorage;
+use crate::saved_state::SavedStateStorageConfig;
+use crate::shard::Shard;
 use crate::status_reporter::ConsoleStatusReporter;
 use crate::status_reporter::StatusReporter;
+use crate::watch_config::WatchConfig;
 
 type FnvIndexMap<K, V> = IndexMap<K, V, FnvBuildHasher>;
 
@@ -93,6 +118,9 @@ pub struct Config {
     pub projects: FnvIndexMap<ProjectName, ProjectConfig>,
     pub header: Vec<String>,
     pub codegen_command: Option<String>,
+    /// SDL files (relative to the root) with directive definitions that are
+    /// part of the schema of every project.
+    pub shared_directive_defs: Vec<PathBuf>,
     /// If set, tries to initialize the compiler from the saved state file.
     pub load_saved_state_file: Option<PathBuf>,
     /// Function to generate extra
@@ -115,10 +143,19 @@ pub struct Config {
     /// Do not reuse persist ids from artifacts even if the text hash matches.
     pub repersist_operations: bool,
 
+    /// Remove the artifacts in the generated directories of the projects
+    /// that no definition generates anymore, e.g. after an operation was
+    /// renamed. The generated directories are usually excluded from the
+    /// sources, so a build without a saved state doesn't know them.
+    pub prune_stale_artifacts: bool,
+
     pub saved_state_config: Option<ScmAwareClockData>,
     pub saved_state_loader: Option<Box<dyn SavedStateLoader + Send + Sync>>,
     pub saved_state_version: String,
 
+    /// The remote storage of the saved states, see `saved_state_storage`.
+    pub saved_state_storage: Option<Box<dyn SavedStateStorage>>,
+
     /// Function that creates a function that is
     /// called to save operation text (e.g. to a database) and to generate an id.
     pub create_operation_persister: Option<OperationPersisterCreator>,
@@ -129,6 +166,10 @@ pub struct Config {
     /// validation rules.
     pub additional_validations: Option<AdditionalValidations>,
 
+    /// Named validation rules that run with the default validation rules,
+    /// without having to fork the compiler.
+    pub custom_validations: Vec<CustomValidation>,
+
     pub status_reporter: Box<dyn StatusReporter + Send + Sync>,
 
     /// We may generate some content in the artifacts that's stripped in production if __DEV__ variable is set
@@ -144,6 +185,68 @@ pub struct Config {
     pub custom_transforms: Option<CustomTransformsConfig>,
 
     pub export_persisted_query_ids_to_file: Option<PathBuf>,
+
+    /// Writes the printed IR of an operation after every transform of
+    /// `apply_transforms(...)` to a directory.
+    pub debug_transforms: Option<DebugTransformsConfig>,
+
+    /// In watch mode, the port of localhost where bundlers can wait for the
+    /// artifacts of a file to be up to date. See `on_demand`.
+    pub on_demand_port: Option<u16>,
+
+    /// Validation diagnostics that are tolerated, read from the file of the
+    /// `baseline` option.
+    pub baseline: Option<Baseline>,
+
+    /// Shell commands run after the builds, see `hooks`.
+    pub hooks: Hooks,
+
+    /// How the changes of the watch mode are batched, see `watch`.
+    pub watch: WatchConfig,
+
+    /// In watch mode, reads the commands of the interactive watch mode from
+    /// the terminal.
+    pub interactive: Option<Arc<InteractiveWatch>>,
+
+    /// Only generates the types or the artifacts without the types, see
+    /// `only`.
+    pub only: Option<BuildOnly>,
+
+    /// The listeners of the events of the builds, registered by embedders.
+    pub events: CompilerEvents,
+
+    /// The remote cache of the artifacts of the projects, see
+    /// `artifact_cache`.
+    pub artifact_cache: Option<Box<dyn ArtifactCache>>,
+
+    /// Signs the generated files, see `artifact_signing`.
+    pub artifact_signer: Box<dyn ArtifactSigner>,
+
+    /// The version of the compiler, part of the keys of the artifact cache.
+    pub compiler_version: String,
+
+    /// Only writes and persists the artifacts of this shard, see `shard`.
+    pub shard: Option<Shard>,
+
+    /// The absolute path of the manifest of the artifacts written after each
+    /// build, see `build_manifest`.
+    pub build_manifest: Option<PathBuf>,
+
+    /// Groups of projects whose operations and fragments must have distinct
+    /// names, see `unique_definition_name_groups`.
+    pub unique_definition_name_groups: Vec<Vec<ProjectName>>,
+}
+
+/// The part of the output of the builds to generate, for pipelines that
+/// only need one of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BuildOnly {
+    /// Only the types: the text of the operations is neither printed nor
+    /// persisted, and the artifacts are written without it.
+    Typegen,
+    /// Only the artifacts, without the types and the type-only artifacts.
+    Artifacts,
 }
 
 pub enum FileSourceKind {
@@ -196,32 +299,77 @@ impl Config {
                 LoaderSource::Json("relay.config.json".to_string()),
                 LoaderSource::Js("relay.config.js".to_string()),
             ],
+            &[
+                // `.graphqlrc` may be either JSON or YAML
+                LoaderSource::Yaml(".graphqlrc".to_string()),
+                LoaderSource::Json(".graphqlrc.json".to_string()),
+                LoaderSource::Yaml(".graphqlrc.yml".to_string()),
+                LoaderSource::Yaml(".graphqlrc.yaml".to_string()),
+                LoaderSource::Json("graphql.config.json".to_string()),
+                LoaderSource::Yaml("graphql.config.yml".to_string()),
+            ],
         )
     }
 
     pub fn load(config_path: PathBuf) -> Result<Self> {
-        let loader = if config_path.extension() == Some(OsStr::new("js")) {
+        let is_graphql_config = config_path.file_name().map_or(false, |name| {
+            let name = name.to_string_lossy();
+            name.starts_with(".graphqlrc") || name.starts_with("graphql.config")
+        });
+        let extension = config_path.extension();
+        let loader = if extension == Some(OsStr::new("js")) && !is_graphql_config {
             LoaderSource::Js(config_path.display().to_string())
-        } else if config_path.extension() == Some(OsStr::new("json")) {
+        } else if extension == Some(OsStr::new("json")) {
             LoaderSource::Json(config_path.display().to_string())
+        } else if is_graphql_config
+            && (extension.is_none()
+                || extension == Some(OsStr::new("yml"))
+                || extension == Some(OsStr::new("yaml")))
+        {
+            LoaderSource::Yaml(config_path.display().to_string())
         } else {
             return Err(Error::ConfigError {
                 details: format!(
-                    "Invalid file extension. Expected `.js` or `.json`. Provided file \"{}\".",
+                    "Invalid file extension. Expected `.js`, `.json`, or a `.graphqlrc` file. Provided file \"{}\".",
                     config_path.display()
                 ),
             });
         };
+        let (loaders_sources, graphql_config_sources) = if is_graphql_config {
+            (vec![], vec![loader])
+        } else {
+            (vec![loader], vec![])
+        };
         Self::load_config(
             &current_dir().expect("Unable to get current working directory."),
-            &[loader],
+            &loaders_sources,
+            &graphql_config_sources,
         )
     }
 
-    fn load_config(start_dir: &Path, loaders_sources: &[LoaderSource]) -> Result<Self> {
-        match js_config_loader::load(start_dir, loaders_sources) {
-            Ok(Some(config)) => Self::from_struct(config.path, config.value, true),
-            Ok(None) => Err(Error::ConfigError {
+    /// Loads the first Relay config of `loaders_sources`, or else the first
+    /// graphql-config file of `graphql_config_sources`.
+    fn load_config(
+        start_dir: &Path,
+        loaders_sources: &[LoaderSource],
+        graphql_config_sources: &[LoaderSource],
+    ) -> Result<Self> {
+        let config = match js_config_loader::load(start_dir, loaders_sources) {
+            Ok(Some(config)) => Some(config),
+            Ok(None) => Self::load_graphql_config(start_dir, graphql_config_sources)?,
+            Err(error) => {
+                return Err(Error::ConfigError {
+                    details: format!("Error searching config: {}", error),
+                });
+            }
+        };
+        let loaders_sources: Vec<_> = loaders_sources
+            .iter()
+            .chain(graphql_config_sources)
+            .collect();
+        match config {
+            Some(config) => Self::from_struct(config.path, config.value, true),
+            None => Err(Error::ConfigError {
                 details: format!(
                     r#"
 Configuration for Relay compiler not found.
@@ -251,6 +399,32 @@ Example file:
                     }
                 ),
             }),
+        }
+    }
+
+    fn load_graphql_config(
+        start_dir: &Path,
+        sources: &[LoaderSource],
+    ) -> Result<Option<js_config_loader::Config<ConfigFile>>> {
+        match js_config_loader::load::<GraphQLConfigFile>(start_dir, sources) {
+            Ok(Some(config)) => {
+                let value = config
+                    .value
+                    .into_relay_config()
+                    .and_then(|value| serde_json::from_value(value).map_err(|err| err.to_string()))
+                    .map_err(|details| Error::ConfigError {
+                        details: format!(
+                            "Unable to map the graphql-config file `{}` to a Relay config: {}",
+                            config.path.display(),
+                            details
+                        ),
+                    })?;
+                Ok(Some(js_config_loader::Config {
+                    path: config.path,
+                    value,
+                }))
+            }
+            Ok(None) => Ok(None),
             Err(error) => Err(Error::ConfigError {
                 details: format!("Error searching config: {}", error),
             }),
@@ -337,11 +511,28 @@ Example file:
                         }],
                     })?;
 
+                let no_inline_fragments = config_file_project
+                    .no_inline_fragments
+                    .iter()
+                    .map(|s| Regex::new(s))
+                    .collect::<std::result::Result<Vec<_>, _>>()
+                    .map_err(|error| Error::ConfigFileValidation {
+                        config_path: config_path.clone(),
+                        validation_errors: vec![ConfigValidationError::InvalidRegex {
+                            key: "noInlineFragments",
+                            project_name,
+                            error,
+                        }],
+                    })?;
+
                 let project_config = ProjectConfig {
                     name: project_name,
                     base: config_file_project.base,
                     enabled: true,
                     schema_extensions: config_file_project.schema_extensions,
+                    schema_extensions_from_sources: config_file_project
+                        .schema_extensions_from_sources,
+                    client_schema_output: config_file_project.client_schema_output,
                     output: config_file_project.output,
                     extra_artifacts_output: config_file_project.extra_artifacts_output,
                     shard_output: config_file_project.shard_output,
@@ -351,8 +542,16 @@ Example file:
                     typegen_config: config_file_project.typegen_config,
                     persist: config_file_project.persist,
                     variable_names_comment: config_file_project.variable_names_comment,
+                    schema_hash_comment: config_file_project.schema_hash_comment,
+                    readable_artifacts: config_file_project.readable_artifacts,
+                    debug_source_comments: config_file_project.debug_source_comments,
+                    obfuscate_operation_names: config_file_project.obfuscate_operation_names,
+                    no_inline_fragments,
                     extra: config_file_project.extra,
                     test_path_regex,
+                    operation_directories: config_file_project.operation_directories,
+                    feature_flag_values: config_file_project.feature_flag_values,
+                    fragment_condition_defaults: config_file_project.fragment_condition_defaults,
                     feature_flags: Arc::new(
                         config_file_project
                             .feature_flags
@@ -364,10 +563,16 @@ Example file:
                     js_module_format: config_file_project.js_module_format,
                     module_import_config: config_file_project.module_import_config,
                     diagnostic_report_config: config_file_project.diagnostic_report_config,
+                    artifact_filename: config_file_project.artifact_filename,
+                    schema_variant: None,
                 };
-                Ok((project_name, project_config))
+                Ok((
+                    project_name,
+                    project_config,
+                    config_file_project.schema_variants,
+                ))
             })
-            .collect::<Result<FnvIndexMap<_, _>>>()?;
+            .collect::<Result<Vec<_>>>()?;
 
         let config_file_dir = config_path.parent().unwrap();
         let root_dir = if let Some(config_root) = config_file.root {
@@ -376,6 +581,42 @@ Example file:
             config_file_dir.to_owned()
         };
 
+        let baseline = config_file
+            .baseline
+            .map(|baseline_path| {
+                let baseline_path = root_dir.join(baseline_path);
+                if validate_fs {
+                    Baseline::load(baseline_path.clone()).map_err(|err| Error::ConfigError {
+                        details: format!(
+                            "Unable to read the baseline file `{}`: {}",
+                            baseline_path.display(),
+                            err
+                        ),
+                    })
+                } else {
+                    Ok(Baseline::empty(baseline_path))
+                }
+            })
+            .transpose()?;
+
+        let build_manifest = config_file
+            .build_manifest
+            .map(|build_manifest| root_dir.join(build_manifest));
+
+        let mut sources = config_file.sources;
+        let projects =
+            create_schema_variant_projects(projects, &mut sources, &root_dir, validate_fs)?;
+
+        let saved_state_version = hex::encode(hash.finalize());
+        let saved_state_storage = config_file.saved_state_storage.map(
+            |saved_state_storage_config| -> Box<dyn SavedStateStorage> {
+                Box::new(HttpSavedStateStorage::new(
+                    saved_state_storage_config,
+                    saved_state_version.clone(),
+                ))
+            },
+        );
+
         let config = Self {
             name: config_file.name,
             artifact_writer: Box::new(ArtifactFileWriter::new(None, root_dir.clone())),
@@ -384,26 +625,48 @@ Example file:
                 is_multi_project,
             )),
             root_dir,
-            sources: config_file.sources,
+            sources,
             excludes: config_file.excludes,
             projects,
             header: config_file.header,
             codegen_command: config_file.codegen_command,
+            shared_directive_defs: config_file.shared_directive_defs,
             load_saved_state_file: None,
             generate_extra_artifacts: None,
             generate_virtual_id_file_name: None,
             saved_state_config: config_file.saved_state_config,
             saved_state_loader: None,
-            saved_state_version: hex::encode(hash.finalize()),
+            saved_state_version,
+            saved_state_storage,
             create_operation_persister: None,
             compile_everything: false,
             repersist_operations: false,
+            prune_stale_artifacts: false,
             post_artifacts_write: None,
             additional_validations: None,
+            custom_validations: vec![],
             is_dev_variable_name: config_file.is_dev_variable_name,
             file_source_config: FileSourceKind::Watchman,
             custom_transforms: None,
             export_persisted_query_ids_to_file: None,
+            debug_transforms: None,
+            on_demand_port: None,
+            baseline,
+            hooks: Hooks::new(config_file.hooks),
+            watch: config_file.watch,
+            interactive: None,
+            only: config_file.only,
+            events: Default::default(),
+            artifact_cache: config_file.artifact_cache.map(
+                |artifact_cache_config| -> Box<dyn ArtifactCache> {
+                    Box::new(HttpArtifactCache::new(artifact_cache_config))
+                },
+            ),
+            artifact_signer: create_artifact_signer(config_file.artifact_signing)?,
+            compiler_version: env!("CARGO_PKG_VERSION").to_string(),
+            shard: None,
+            build_manifest,
+            unique_definition_name_groups: config_file.unique_definition_name_groups,
         };
 
         let mut validation_errors = Vec::new();
@@ -467,6 +730,31 @@ Example file:
                     })
                 }
             }
+
+            // The generated types of every document need distinct names
+            for template in project_config
+                .typegen_config
+                .type_names
+                .templates_without_name()
+            {
+                errors.push(ConfigValidationError::TypeNameTemplateWithoutName {
+                    project_name,
+                    template: template.to_string(),
+                });
+            }
+
+            // Only the names of the persisted operations are obfuscated
+            if project_config.obfuscate_operation_names && project_config.persist.is_none() {
+                errors.push(ConfigValidationError::ObfuscationWithoutPersist { project_name });
+            }
+        }
+
+        for &project_name in self.unique_definition_name_groups.iter().flatten() {
+            if self.projects.get(&project_name).is_none() {
+                errors.push(
+                    ConfigValidationError::UniqueDefinitionNameGroupProjectMissing { project_name },
+                );
+            }
         }
     }
 
@@ -526,7 +814,72 @@ Example file:
                 }
             }
         }
+
+        for shared_directive_defs_file in &self.shared_directive_defs {
+            let file = self.root_dir.join(shared_directive_defs_file);
+            if !file.is_file() {
+                errors.push(ConfigValidationError::SharedDirectiveDefsNotFile { file });
+            }
+        }
+    }
+}
+
+/// Adds a project for each of the `schemaVariants` of the projects, after
+/// the project, with the sources of the project.
+fn create_schema_variant_projects(
+    projects: Vec<(
+        ProjectName,
+        ProjectConfig,
+        FnvIndexMap<StringKey, SchemaVariantConfig>,
+    )>,
+    sources: &mut FnvIndexMap<PathBuf, ProjectSet>,
+    root_dir: &Path,
+    validate_fs: bool,
+) -> Result<FnvIndexMap<ProjectName, ProjectConfig>> {
+    let mut all_projects = FnvIndexMap::default();
+    for (project_name, project_config, schema_variants) in projects {
+        let mut variant_projects = Vec::new();
+        for (variant_name, variant_config) in schema_variants {
+            let variant_project_name = format!("{}_{}", project_name, variant_name).intern();
+            let mut removed_coordinates = Vec::new();
+            if validate_fs {
+                for removals_file in &variant_config.schema_removals {
+                    let removals_path = root_dir.join(removals_file);
+                    let content = std::fs::read_to_string(&removals_path).map_err(|err| {
+                        Error::ConfigError {
+                            details: format!(
+                                "Unable to read the schema removals `{}` of `{}`: {}",
+                                removals_path.display(),
+                                variant_name,
+                                err
+                            ),
+                        }
+                    })?;
+                    removed_coordinates.extend(parse_schema_removals(&content));
+                }
+            }
+            for project_set in sources.values_mut() {
+                if project_set.iter().any(|name| *name == project_name) {
+                    project_set.insert(variant_project_name);
+                }
+            }
+            variant_projects.push((
+                variant_project_name,
+                project_config.create_schema_variant(
+                    variant_project_name,
+                    SchemaVariant {
+                        name: variant_name,
+                        project_name,
+                        removed_coordinates,
+                    },
+                    variant_config.schema_extensions,
+                ),
+            ));
+        }
+        all_projects.insert(project_name, project_config);
+        all_projects.extend(variant_projects);
     }
+    Ok(all_projects)
 }
 
 impl fmt::Debug for Config {
@@ -542,6 +895,7 @@ impl fmt::Debug for Config {
             projects,
             header,
             codegen_command,
+            shared_directive_defs,
             load_saved_state_file,
             generate_extra_artifacts,
             saved_state_config,
@@ -566,6 +920,7 @@ impl fmt::Debug for Config {
             .field("projects", projects)
             .field("header", header)
             .field("codegen_command", codegen_command)
+            .field("shared_directive_defs", shared_directive_defs)
             .field("load_saved_state_file", load_saved_state_file)
             .field("saved_state_config", saved_state_config)
             .field(
@@ -627,6 +982,12 @@ struct MultiProjectConfigFile {
     #[serde(default = "get_default_excludes")]
     excludes: Vec<String>,
 
+    /// SDL files with directive definitions (e.g. custom client directives)
+    /// that are merged into the schema of every project. Identical
+    /// definitions of a directive in several files are allowed.
+    #[serde(default)]
+    shared_directive_defs: Vec<PathBuf>,
+
     /// Configuration of projects to compile.
     projects: FnvIndexMap<ProjectName, ConfigFileProject>,
 
@@ -638,6 +999,50 @@ struct MultiProjectConfigFile {
 
     /// Then name of the global __DEV__ variable to use in generated artifacts
     is_dev_variable_name: Option<String>,
+
+    /// File (relative to the root) recording the validation diagnostics that
+    /// are tolerated, see `relay-compiler baseline`.
+    #[serde(default)]
+    baseline: Option<PathBuf>,
+
+    /// Shell commands to run after the builds, e.g. in watch mode.
+    #[serde(default)]
+    hooks: HooksConfig,
+
+    /// How the changes of the watch mode are batched into builds.
+    #[serde(default)]
+    watch: WatchConfig,
+
+    /// Only generates the types (`typegen`) or the artifacts without the
+    /// types (`artifacts`).
+    #[serde(default)]
+    only: Option<BuildOnly>,
+
+    /// Remote cache of the artifacts, for clean checkouts on CI.
+    #[serde(default)]
+    artifact_cache: Option<ArtifactCacheConfig>,
+
+    /// Remote storage of the saved states of the builds of the main branch,
+    /// for incremental builds on CI.
+    #[serde(default)]
+    saved_state_storage: Option<SavedStateStorageConfig>,
+
+    /// How the generated files are signed: `none`, `signedSource` (the
+    /// default) or `hmac` with a key read from an environment variable.
+    #[serde(default)]
+    artifact_signing: ArtifactSigningConfig,
+
+    /// File (relative to the root) to which a manifest of the artifacts, with
+    /// their source files, persisted ids and content hashes, is written after
+    /// each build.
+    #[serde(default)]
+    build_manifest: Option<PathBuf>,
+
+    /// Groups of projects whose operations and fragments must have distinct
+    /// names across the projects of the group, e.g. because their operations
+    /// are persisted to a shared store keyed by name.
+    #[serde(default)]
+    unique_definition_name_groups: Vec<Vec<ProjectName>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -672,12 +1077,23 @@ pub struct SingleProjectConfigFile {
     /// List of directories with schema extensions.
     pub schema_extensions: Vec<PathBuf>,
 
+    /// Treat any `*.client.graphql` file under `src` as a schema extension.
+    pub schema_extensions_from_sources: bool,
+
+    /// Path of a file to generate with the SDL of the client schema
+    /// extensions and Relay Resolvers.
+    pub client_schema_output: Option<PathBuf>,
+
     /// This option controls whether or not a catch-all entry is added to enum type definitions
     /// for values that may be added in the future. Enabling this means you will have to update
     /// your application whenever the GraphQL server schema adds new enum values to prevent it
     /// from breaking.
     pub no_future_proof_enums: bool,
 
+    /// For Flow only. Emit Flow Enums for the enums of the schema instead of
+    /// string literal unions.
+    pub use_flow_enums: bool,
+
     /// The name of the language plugin (?) used for input files and artifacts
     pub language: Option<TypegenLanguage>,
 
@@ -685,9 +1101,42 @@ pub struct SingleProjectConfigFile {
     /// types, for type emission purposes.
     pub custom_scalars: FnvIndexMap<ScalarName, CustomScalarType>,
 
+    /// Fail the build on the custom scalars used by the documents that have
+    /// no type in `custom_scalars`, instead of typing them as `any`.
+    pub require_custom_scalar_types: bool,
+
     /// This option enables emitting es modules artifacts.
     pub eager_es_modules: bool,
 
+    /// Emit a mock data factory next to each operation artifact.
+    pub generate_mock_factories: bool,
+
+    /// Emit a type mapping the modules of each `@match` field to the props
+    /// of their components.
+    pub generate_match_modules_types: bool,
+
+    /// Export a type guard on the `__typename` for each object type that a
+    /// selection on an interface or a union refines to.
+    pub generate_typename_guards: bool,
+
+    /// Path of a file to generate with a type mapping the name of each
+    /// operation to its variables and response types.
+    pub operation_registry: Option<PathBuf>,
+
+    /// Make the variables and input fields with a default value optional in
+    /// the generated types.
+    pub optional_variables_with_defaults: bool,
+
+    /// The type of the errors of the fields with `@catch`.
+    pub catch_error_type: Option<CustomScalarType>,
+
+    /// The type of the context passed to the Relay Resolvers.
+    pub resolver_context_type: Option<CustomScalarTypeImport>,
+
+    /// Templates of the names of the generated types, e.g.
+    /// `{ "data": "T{name}$data" }`.
+    pub type_names: TypeNamesConfig,
+
     /// Query Persist Configuration
     /// It contains URL and addition parameters that will be included
     /// with the request (think API_KEY, APP_ID, etc...)
@@ -700,6 +1149,26 @@ pub struct SingleProjectConfigFile {
     /// Name of the command that runs the relay compiler
     pub codegen_command: Option<String>,
 
+    /// Generates a `@relaySchemaHash` header in generated files, and
+    /// `--validate` reports artifacts generated from a different schema.
+    pub schema_hash_comment: bool,
+
+    /// Generates reader ASTs that mirror the authored fragments, for
+    /// debugging in dev builds.
+    pub readable_artifacts: bool,
+
+    /// Prints comments with the source fragment and line of the selections
+    /// in the reader ASTs, for debugging in dev builds.
+    pub debug_source_comments: bool,
+
+    /// Replaces the names of the persisted operations with their persisted
+    /// id in the artifacts, see `obfuscateOperationNames` of the project config.
+    pub obfuscate_operation_names: bool,
+
+    /// Regexes of fragment names that are compiled as if they had the
+    /// `@no_inline` directive.
+    pub no_inline_fragments: Vec<String>,
+
     /// Formatting style for generated files.
     pub js_module_format: JsModuleFormat,
 
@@ -710,6 +1179,23 @@ pub struct SingleProjectConfigFile {
     #[serde(default)]
     pub module_import_config: ModuleImportConfig,
 
+    /// The suffix, extension and directory of the artifacts.
+    #[serde(default)]
+    pub artifact_filename: ArtifactFilenameConfig,
+
+    /// Values of the flags of the `@featureFlag` directive.
+    #[serde(default)]
+    pub feature_flag_values: FnvIndexMap<StringKey, bool>,
+
+    /// Values of the variables of the conditions of fragment definitions.
+    #[serde(default)]
+    pub fragment_condition_defaults: FnvIndexMap<StringKey, bool>,
+
+    /// Variants of the schema, compiled to artifacts with the name of the
+    /// variant after their suffix.
+    #[serde(default)]
+    pub schema_variants: FnvIndexMap<StringKey, SchemaVariantConfig>,
+
     /// Added in 13.1.1 to customize Final/Compat mode in the single project config file
     /// Removed in 14.0.0
     #[serde(default)]
@@ -717,6 +1203,35 @@ pub struct SingleProjectConfigFile {
 
     #[serde(default)]
     pub feature_flags: Option<FeatureFlags>,
+
+    /// File recording the validation diagnostics that are tolerated, see
+    /// `relay-compiler baseline`.
+    pub baseline: Option<PathBuf>,
+
+    /// Shell commands to run after the builds, e.g. in watch mode.
+    pub hooks: HooksConfig,
+
+    /// How the changes of the watch mode are batched into builds.
+    pub watch: WatchConfig,
+
+    /// Only generates the types (`typegen`) or the artifacts without the
+    /// types (`artifacts`).
+    pub only: Option<BuildOnly>,
+
+    /// Remote cache of the artifacts, for clean checkouts on CI.
+    pub artifact_cache: Option<ArtifactCacheConfig>,
+
+    /// Remote storage of the saved states of the builds of the main branch,
+    /// for incremental builds on CI.
+    pub saved_state_storage: Option<SavedStateStorageConfig>,
+
+    /// How the generated files are signed: `none`, `signedSource` (the
+    /// default) or `hmac` with a key read from an environment variable.
+    pub artifact_signing: ArtifactSigningConfig,
+
+    /// File to which a manifest of the artifacts, with their source files,
+    /// persisted ids and content hashes, is written after each build.
+    pub build_manifest: Option<PathBuf>,
 }
 
 impl Default for SingleProjectConfigFile {
@@ -730,18 +1245,47 @@ impl Default for SingleProjectConfigFile {
             extensions: vec![],
             excludes: get_default_excludes(),
             schema_extensions: vec![],
+            schema_extensions_from_sources: false,
+            client_schema_output: None,
             no_future_proof_enums: false,
+            use_flow_enums: false,
             language: None,
             custom_scalars: Default::default(),
+            require_custom_scalar_types: false,
             schema_config: Default::default(),
             eager_es_modules: false,
+            generate_mock_factories: false,
+            generate_match_modules_types: false,
+            generate_typename_guards: false,
+            operation_registry: None,
+            optional_variables_with_defaults: false,
+            catch_error_type: None,
+            resolver_context_type: None,
+            type_names: Default::default(),
             persist_config: None,
             is_dev_variable_name: None,
             codegen_command: None,
+            schema_hash_comment: false,
+            readable_artifacts: false,
+            debug_source_comments: false,
+            obfuscate_operation_names: false,
+            no_inline_fragments: vec![],
             js_module_format: JsModuleFormat::CommonJS,
             typegen_phase: None,
             feature_flags: None,
             module_import_config: Default::default(),
+            artifact_filename: Default::default(),
+            feature_flag_values: Default::default(),
+            fragment_condition_defaults: Default::default(),
+            schema_variants: Default::default(),
+            baseline: None,
+            hooks: Default::default(),
+            watch: Default::default(),
+            only: None,
+            artifact_cache: None,
+            saved_state_storage: None,
+            artifact_signing: Default::default(),
+            build_manifest: None,
         }
     }
 }
@@ -860,20 +1404,40 @@ impl SingleProjectConfigFile {
                     )
                 })
                 .collect(),
+            schema_extensions_from_sources: self.schema_extensions_from_sources,
+            client_schema_output: self.client_schema_output,
             persist: self.persist_config,
             typegen_config: TypegenConfig {
                 language,
                 custom_scalar_types: self.custom_scalars.clone(),
+                require_custom_scalar_types: self.require_custom_scalar_types,
                 eager_es_modules: self.eager_es_modules,
+                generate_mock_factories: self.generate_mock_factories,
+                generate_match_modules_types: self.generate_match_modules_types,
+                generate_typename_guards: self.generate_typename_guards,
+                operation_registry: self.operation_registry,
+                optional_variables_with_defaults: self.optional_variables_with_defaults,
+                catch_error_type: self.catch_error_type,
+                resolver_context_type: self.resolver_context_type,
+                type_names: self.type_names,
                 flow_typegen: FlowTypegenConfig {
                     no_future_proof_enums: self.no_future_proof_enums,
-                    ..Default::default()
+                    use_flow_enums: self.use_flow_enums,
                 },
                 ..Default::default()
             },
             js_module_format: self.js_module_format,
             feature_flags: self.feature_flags,
             module_import_config: self.module_import_config,
+            artifact_filename: self.artifact_filename,
+            feature_flag_values: self.feature_flag_values,
+            fragment_condition_defaults: self.fragment_condition_defaults,
+            schema_variants: self.schema_variants,
+            schema_hash_comment: self.schema_hash_comment,
+            readable_artifacts: self.readable_artifacts,
+            debug_source_comments: self.debug_source_comments,
+            obfuscate_operation_names: self.obfuscate_operation_names,
+            no_inline_fragments: self.no_inline_fragments,
             ..Default::default()
         };
 
@@ -881,7 +1445,24 @@ impl SingleProjectConfigFile {
         projects.insert(self.project_name, project_config);
 
         let mut sources = FnvIndexMap::default();
-        let src = normalize_path_from_config(current_dir, common_root_dir.clone(), self.src);
+        let src =
+            normalize_path_from_config(current_dir.clone(), common_root_dir.clone(), self.src);
+
+        // Relative to the current directory like the other paths, but it may
+        // not exist yet
+        let baseline = self.baseline.map(|path| {
+            let path = current_dir.join(path);
+            path.strip_prefix(&common_root_dir)
+                .map(Path::to_path_buf)
+                .unwrap_or(path)
+        });
+
+        let build_manifest = self.build_manifest.map(|path| {
+            let path = current_dir.join(path);
+            path.strip_prefix(&common_root_dir)
+                .map(Path::to_path_buf)
+                .unwrap_or(path)
+        });
 
         sources.insert(src, ProjectSet::of(self.project_name));
 
@@ -892,6 +1473,14 @@ impl SingleProjectConfigFile {
             excludes: self.excludes,
             is_dev_variable_name: self.is_dev_variable_name,
             codegen_command: self.codegen_command,
+            baseline,
+            hooks: self.hooks,
+            watch: self.watch,
+            only: self.only,
+            artifact_cache: self.artifact_cache,
+            saved_state_storage: self.saved_state_storage,
+            artifact_signing: self.artifact_signing,
+            build_manifest,
             ..Default::default()
         })
     }
@@ -969,6 +1558,17 @@ pub struct ConfigFileProject {
     #[serde(default)]
     schema_extensions: Vec<PathBuf>,
 
+    /// Treat any `*.client.graphql` file in the sources of this project as a
+    /// schema extension, in addition to the `schema_extensions` directories.
+    #[serde(default)]
+    schema_extensions_from_sources: bool,
+
+    /// Path, relative to the root directory, of a file to generate with the
+    /// SDL of what the client schema extensions and Relay Resolvers add to
+    /// the server schema, with their descriptions and deprecations.
+    #[serde(default)]
+    client_schema_output: Option<PathBuf>,
+
     /// Path to the schema.graphql or a directory containing a schema broken up
     /// in multiple *.graphql files.
     /// Exactly 1 of these options needs to be defined.
@@ -987,10 +1587,68 @@ pub struct ConfigFileProject {
     #[serde(default)]
     test_path_regex: Option<String>,
 
+    /// Optional list of directories, relative to the root directory, to
+    /// which the operations of the project are restricted, e.g. the route
+    /// entrypoints. Fragments may be defined in any directory.
+    #[serde(default)]
+    operation_directories: Option<Vec<PathBuf>>,
+
+    /// Variants of the schema of the project, keyed by name, for which the
+    /// sources of the project are compiled again to artifacts with the name of
+    /// the variant after their suffix, e.g. `MyQuery.graphql.embedded.js`.
+    #[serde(default)]
+    schema_variants: FnvIndexMap<StringKey, SchemaVariantConfig>,
+
+    /// Values of the flags of the `@featureFlag` directive, e.g.
+    /// `{"new_profile": true}`. Selections with a flag that is missing or
+    /// `false` are removed from the artifacts, so that projects with
+    /// different values generate different artifacts from the same sources.
+    #[serde(default)]
+    feature_flag_values: FnvIndexMap<StringKey, bool>,
+
+    /// Values of the variables of the `@include` and `@skip` conditions of
+    /// fragment definitions (see the `enable_fragment_conditions` feature
+    /// flag), e.g. `{"showComments": false}`, used where a fragment is spread
+    /// without defining the variable.
+    #[serde(default)]
+    fragment_condition_defaults: FnvIndexMap<StringKey, bool>,
+
     /// Generates a `// @relayVariables name1 name2` header in generated operation files
     #[serde(default)]
     variable_names_comment: bool,
 
+    /// Generates a `@relaySchemaHash` header with a hash of the project's
+    /// schema in generated files, and `--validate` reports the artifacts on
+    /// disk that were generated from a different schema.
+    #[serde(default)]
+    schema_hash_comment: bool,
+
+    /// Generates reader ASTs that mirror the authored fragments: inline
+    /// fragments on concrete types are kept and redundant selections are not
+    /// removed. Artifacts are larger, so this is intended for dev builds.
+    #[serde(default)]
+    readable_artifacts: bool,
+
+    /// Prints comments in the reader ASTs noting the source fragment and line
+    /// of the selections of each object, to find where the selections of an
+    /// artifact come from. Not printed by default, intended for dev builds.
+    #[serde(default)]
+    debug_source_comments: bool,
+
+    /// Replaces the names of the persisted operations in their artifacts
+    /// with their persisted id, so that the names are not sent to the network
+    /// layer of production builds. The names of the fields, aliases and
+    /// fragments are kept. The readable names are set when `isDevVariableName`
+    /// is set and true. Requires `persist`.
+    #[serde(default)]
+    obfuscate_operation_names: bool,
+
+    /// Fragments with a name matching one of these regexes are compiled as
+    /// if they had the `@no_inline` directive, without having to add it to
+    /// each of them.
+    #[serde(default)]
+    no_inline_fragments: Vec<String>,
+
     /// A placeholder for allowing extra information in the config file
     #[serde(default)]
     extra: serde_json::Value,
@@ -1014,6 +1672,11 @@ pub struct ConfigFileProject {
 
     #[serde(default)]
     pub diagnostic_report_config: DiagnosticReportConfig,
+
+    /// The suffix, extension and directory of the artifacts, e.g.
+    /// `{"suffix": "relay"}` for `__generated__/MyQuery.relay.js`.
+    #[serde(default)]
+    pub artifact_filename: ArtifactFilenameConfig,
 }
 
 pub type PersistId = String;
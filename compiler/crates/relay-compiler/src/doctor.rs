@@ -0,0 +1,404 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Checks the environment the compiler runs in: Watchman, the config, the
+//! schemas, the output directories, the persist endpoints and the artifacts.
+//! Each problem comes with the steps to fix it. Used by the `doctor` command.
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use common::Diagnostic;
+use common::NoopPerfLogger;
+use persist_query::check_reachable;
+use persist_query::PersistError;
+
+use crate::build_project::artifact_writer::ArtifactValidationWriter;
+use crate::compiler::Compiler;
+use crate::config::ArtifactForPersister;
+use crate::config::Config;
+use crate::config::PersistConfig;
+use crate::errors::BuildProjectError;
+use crate::errors::Error;
+use crate::errors::Result;
+use crate::status_reporter::StatusReporter;
+use crate::OperationPersister;
+
+/// How long to wait for a persist endpoint to respond.
+const PERSIST_ENDPOINT_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DoctorStatus {
+    Ok,
+    /// The compiler works, but not as well as it could.
+    Warning,
+    /// The compiler fails, or generates wrong artifacts.
+    Error,
+}
+
+#[derive(Debug)]
+pub struct DoctorCheck {
+    pub name: String,
+    pub status: DoctorStatus,
+    pub message: String,
+    /// What to do about a failed check.
+    pub remediation: Option<String>,
+}
+
+impl DoctorCheck {
+    fn ok(name: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: DoctorStatus::Ok,
+            message: message.into(),
+            remediation: None,
+        }
+    }
+
+    fn warning(
+        name: impl Into<String>,
+        message: impl Into<String>,
+        remediation: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            status: DoctorStatus::Warning,
+            message: message.into(),
+            remediation: Some(remediation.into()),
+        }
+    }
+
+    fn error(
+        name: impl Into<String>,
+        message: impl Into<String>,
+        remediation: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            status: DoctorStatus::Error,
+            message: message.into(),
+            remediation: Some(remediation.into()),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct DoctorReport {
+    pub checks: Vec<DoctorCheck>,
+}
+
+impl DoctorReport {
+    pub fn has_errors(&self) -> bool {
+        self.checks
+            .iter()
+            .any(|check| check.status == DoctorStatus::Error)
+    }
+}
+
+impl fmt::Display for DoctorReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for check in &self.checks {
+            let status = match check.status {
+                DoctorStatus::Ok => "ok",
+                DoctorStatus::Warning => "warning",
+                DoctorStatus::Error => "error",
+            };
+            writeln!(
+                f,
+                "[{}] {}: {}",
+                status,
+                check.name,
+                check.message.trim_end().replace('\n', "\n    ")
+            )?;
+            if let Some(remediation) = &check.remediation {
+                writeln!(f, "    Fix: {}", remediation)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Runs the checks. The checks that need a config are skipped if it failed
+/// to load, since `config` is the result of loading it.
+pub async fn run_doctor(config: Result<Config>) -> DoctorReport {
+    let mut report = DoctorReport::default();
+    report.checks.push(check_watchman());
+
+    let mut config = match config {
+        Ok(config) => {
+            report.checks.push(DoctorCheck::ok(
+                "Config",
+                format!(
+                    "{} project(s) in {}",
+                    config.enabled_projects().count(),
+                    config.root_dir.display()
+                ),
+            ));
+            config
+        }
+        Err(error) => {
+            report.checks.push(DoctorCheck::error(
+                "Config",
+                error.to_string(),
+                "Fix the config in `relay.config.json` (or the `relay` key of `package.json`), or pass the path of the config file to use.",
+            ));
+            return report;
+        }
+    };
+
+    report.checks.extend(check_output_directories(&config));
+    report.checks.extend(check_persist_endpoints(&config).await);
+
+    // Only report what would change, without writing or persisting anything.
+    config.artifact_writer = Box::new(ArtifactValidationWriter::default());
+    config.create_operation_persister = Some(Box::new(|project_config| {
+        project_config
+            .persist
+            .as_ref()
+            .map(|_| -> Box<dyn OperationPersister + Send + Sync> {
+                Box::new(UnpersistedOperations)
+            })
+    }));
+    config.status_reporter = Box::new(SilentStatusReporter);
+    let compiler = Compiler::new(Arc::new(config), Arc::new(NoopPerfLogger));
+
+    let schema_checks = check_schemas(&compiler).await;
+    let has_schema_errors = schema_checks
+        .iter()
+        .any(|check| check.status == DoctorStatus::Error);
+    report.checks.extend(schema_checks);
+    if !has_schema_errors {
+        report.checks.push(check_artifacts(&compiler).await);
+    }
+
+    report
+}
+
+fn check_watchman() -> DoctorCheck {
+    const NAME: &str = "Watchman";
+    if env::var_os("FORCE_NO_WATCHMAN").is_some() {
+        return DoctorCheck::warning(
+            NAME,
+            "`FORCE_NO_WATCHMAN` is set, the compiler walks the file system instead of querying Watchman.",
+            "Unset `FORCE_NO_WATCHMAN` to use Watchman, which is faster and required by `--watch`.",
+        );
+    }
+    match Command::new("watchman").arg("--version").output() {
+        Ok(output) if output.status.success() => DoctorCheck::ok(
+            NAME,
+            format!("version {}", String::from_utf8_lossy(&output.stdout).trim()),
+        ),
+        Ok(output) => DoctorCheck::error(
+            NAME,
+            format!(
+                "`watchman --version` failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+            "Restart Watchman with `watchman shutdown-server`, or set `FORCE_NO_WATCHMAN=1` to compile without it.",
+        ),
+        Err(_) => DoctorCheck::warning(
+            NAME,
+            "Watchman is not installed, the compiler walks the file system instead.",
+            "Install Watchman to use `--watch` and to speed up compilation.",
+        ),
+    }
+}
+
+/// The directories the artifacts of the enabled projects are written to:
+/// the `output` of the project, or the source directories if artifacts are
+/// written next to the sources.
+fn check_output_directories(config: &Config) -> Vec<DoctorCheck> {
+    let mut directories: Vec<PathBuf> = Vec::new();
+    for project_config in config.enabled_projects() {
+        match &project_config.output {
+            Some(output) => directories.push(output.clone()),
+            None => directories.extend(
+                config
+                    .sources
+                    .iter()
+                    .filter(|(_, project_set)| {
+                        project_set
+                            .iter()
+                            .any(|project_name| *project_name == project_config.name)
+                    })
+                    .map(|(source_dir, _)| source_dir.clone()),
+            ),
+        }
+        directories.extend(project_config.extra_artifacts_output.clone());
+    }
+    directories.sort();
+    directories.dedup();
+
+    directories
+        .into_iter()
+        .map(|directory| {
+            let name = format!("Output directory `{}`", directory.display());
+            match check_writable(&config.root_dir.join(&directory)) {
+                Ok(()) => DoctorCheck::ok(name, "writable"),
+                Err(error) => DoctorCheck::error(
+                    name,
+                    format!("not writable: {}", error),
+                    "Create the directory, or give the user running the compiler permission to write to it.",
+                ),
+            }
+        })
+        .collect()
+}
+
+fn check_writable(directory: &Path) -> io::Result<()> {
+    let probe = directory.join(format!(".relay-doctor-{}", std::process::id()));
+    fs::write(&probe, b"")?;
+    fs::remove_file(&probe)
+}
+
+async fn check_persist_endpoints(config: &Config) -> Vec<DoctorCheck> {
+    let mut checks = Vec::new();
+    for project_config in config.enabled_projects() {
+        let name = format!("Persisting of project `{}`", project_config.name);
+        match &project_config.persist {
+            Some(PersistConfig::Remote(remote_config)) => {
+                let url = &remote_config.url;
+                checks.push(
+                    match tokio::time::timeout(PERSIST_ENDPOINT_TIMEOUT, check_reachable(url))
+                        .await
+                    {
+                        Ok(Ok(status)) => {
+                            DoctorCheck::ok(name, format!("`{}` responded ({})", url, status))
+                        }
+                        Ok(Err(error)) => DoctorCheck::error(
+                            name,
+                            format!("`{}` is unreachable: {}", url, error),
+                            "Check the `persist.url` of the project, and that this machine can reach it (VPN, proxy, firewall).",
+                        ),
+                        Err(_) => DoctorCheck::error(
+                            name,
+                            format!(
+                                "`{}` did not respond within {} seconds",
+                                url,
+                                PERSIST_ENDPOINT_TIMEOUT.as_secs()
+                            ),
+                            "Check the `persist.url` of the project, and that this machine can reach it (VPN, proxy, firewall).",
+                        ),
+                    },
+                );
+            }
+            Some(PersistConfig::Local(local_config)) => {
+                let file = config.root_dir.join(&local_config.file);
+                let directory = file.parent().unwrap_or(&config.root_dir);
+                checks.push(match check_writable(directory) {
+                    Ok(()) => DoctorCheck::ok(name, format!("`{}` is writable", file.display())),
+                    Err(error) => DoctorCheck::error(
+                        name,
+                        format!("`{}` is not writable: {}", file.display(), error),
+                        "Create the directory of the `persist.file` of the project, or give the user running the compiler permission to write to it.",
+                    ),
+                });
+            }
+            None => {}
+        }
+    }
+    checks
+}
+
+async fn check_schemas(compiler: &Compiler<NoopPerfLogger>) -> Vec<DoctorCheck> {
+    const NAME: &str = "Schemas";
+    match compiler.build_schemas().await {
+        Ok(schemas) => schemas
+            .into_iter()
+            .map(|(project_name, _)| {
+                DoctorCheck::ok(format!("Schema of project `{}`", project_name), "valid")
+            })
+            .collect(),
+        Err(Error::BuildProjectsErrors { errors }) => errors
+            .into_iter()
+            .map(|error| {
+                let name = match &error {
+                    BuildProjectError::ValidationErrors { project_name, .. } => {
+                        format!("Schema of project `{}`", project_name)
+                    }
+                    _ => NAME.to_string(),
+                };
+                DoctorCheck::error(
+                    name,
+                    error.to_string(),
+                    "Fix the schema, or the client schema extensions of the project.",
+                )
+            })
+            .collect(),
+        Err(error) => vec![DoctorCheck::error(
+            NAME,
+            error.to_string(),
+            "Make sure the files of the projects can be read. If Watchman fails, set `FORCE_NO_WATCHMAN=1` to compile without it.",
+        )],
+    }
+}
+
+async fn check_artifacts(compiler: &Compiler<NoopPerfLogger>) -> DoctorCheck {
+    const NAME: &str = "Artifacts";
+    match compiler.compile().await {
+        Ok(_) => DoctorCheck::ok(NAME, "up to date"),
+        Err(Error::ArtifactsValidationError { error }) => DoctorCheck::error(
+            NAME,
+            format!("some artifacts are outdated:{}", error),
+            "Run `relay-compiler` to update them, and commit the changes.",
+        ),
+        Err(Error::BuildProjectsErrors { errors })
+            if errors
+                .iter()
+                .all(|error| matches!(error, BuildProjectError::PersistErrors { .. })) =>
+        {
+            DoctorCheck::error(
+                NAME,
+                "the text of some operations changed since they were persisted",
+                "Run `relay-compiler` to persist them and update their artifacts.",
+            )
+        }
+        Err(error) => DoctorCheck::warning(
+            NAME,
+            format!(
+                "the projects don't compile, so the artifacts were not checked:\n{}",
+                error
+            ),
+            "Run `relay-compiler` to see the errors with their source.",
+        ),
+    }
+}
+
+/// Fails to persist any operation whose artifact doesn't already have the
+/// persisted ID of its text, which means that the artifact is outdated.
+struct UnpersistedOperations;
+
+#[async_trait]
+impl OperationPersister for UnpersistedOperations {
+    async fn persist_artifact(
+        &self,
+        artifact: ArtifactForPersister,
+    ) -> std::result::Result<String, PersistError> {
+        Err(PersistError::ErrorResponse {
+            message: format!("`{}` is not persisted", artifact.relative_path.display()),
+        })
+    }
+}
+
+/// The doctor reports the outcome of the compilation itself.
+struct SilentStatusReporter;
+
+impl StatusReporter for SilentStatusReporter {
+    fn build_starts(&self) {}
+
+    fn build_completes(&self, _diagnostics: &[Diagnostic]) {}
+
+    fn build_errors(&self, _error: &Error) {}
+}
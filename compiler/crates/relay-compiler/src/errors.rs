@@ -8,252 +8,335 @@
 use std::io;
 use std::path::PathBuf;
 
+use common::diagnostic_codes;
+use common::message_with_code;
 use common::Diagnostic;
 use glob::PatternError;
+use intern::string_key::StringKey;
 use persist_query::PersistError;
 use thiserror::Error;
 
+use crate::codemod::FieldCoordinate;
 use crate::compiler_state::ProjectName;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
-#[derive(Debug, Error)]
-pub enum Error {
-    #[error("Unable to initialize relay compiler configuration. Error details: \n{details}")]
-    ConfigError { details: String },
-
-    #[error(
-        "Config `{config_path}` is invalid:{}",
-        validation_errors
-            .iter()
-            .map(|err| format!("\n - {}", err))
-            .collect::<Vec<_>>()
-            .join("")
-    )]
-    ConfigFileValidation {
-        config_path: PathBuf,
-        validation_errors: Vec<ConfigValidationError>,
-    },
-
-    #[error(
-        "Diagnostics Error:{}",
-        errors
-            .iter()
-            .map(|err| format!("\n - {}", err.print_without_source()))
-            .collect::<Vec<_>>()
-            .join("")
-    )]
-    DiagnosticsError { errors: Vec<Diagnostic> },
-
-    #[error(
-        "Failed to build:{}",
-        errors
-            .iter()
-            .map(|err| format!("\n - {}", err))
-            .collect::<Vec<_>>()
-            .join("")
-    )]
-    BuildProjectsErrors { errors: Vec<BuildProjectError> },
-
-    #[error("Failed to read file `{file}`: {source}")]
-    ReadFileError { file: PathBuf, source: io::Error },
-
-    #[error("Failed to write file `{file}`: {source}")]
-    WriteFileError { file: PathBuf, source: io::Error },
-
-    #[error("Unable to serialize state to file: `{file}`, because of `{source}`.")]
-    SerializationError {
-        file: PathBuf,
-        source: Box<bincode::ErrorKind>,
-    },
-
-    #[error("Unable to deserialize state from file: `{file}`, because of `{source}`.")]
-    DeserializationError {
-        file: PathBuf,
-        source: Box<bincode::ErrorKind>,
-    },
-
-    #[error("Failed to canonicalize root: `{root}`.")]
-    CanonicalizeRoot {
-        root: PathBuf,
-        source: std::io::Error,
-    },
-
-    #[error("Watchman error: {source}")]
-    Watchman {
-        #[from]
-        source: watchman_client::Error,
-    },
-
-    #[error("Watchman query returned no results.")]
-    EmptyQueryResult,
-
-    #[error("Failed to read file: `{file}`.")]
-    FileRead {
-        file: PathBuf,
-        source: std::io::Error,
-    },
-
-    #[error("A thread that the Relay compiler spun up did not shut down gracefully: {error}")]
-    JoinError { error: String },
-
-    #[error("Artifacts validation failed: {error}")]
-    ArtifactsValidationError { error: String },
-
-    #[error("Error in post artifact writer: {error}")]
-    PostArtifactsError {
-        error: Box<dyn std::error::Error + Sync + Send>,
-    },
-
-    #[error("Compilation cancelled due to new changes")]
-    Cancelled,
-
-    #[error("IO error {0}")]
-    IOError(std::io::Error),
-
-    #[error("Unable to parse changed files list. {reason}")]
-    ExternalSourceParseError { reason: String },
-
-    #[error("JSON parse error in `{file}`: {source}")]
-    SerdeError {
-        file: PathBuf,
-        source: serde_json::Error,
-    },
-
-    #[error("glob pattern error: {0}")]
-    PatternError(PatternError),
-
-    #[error(
-        "Saved state versions mismatch. Saved state: {saved_state_version}, config: {config_version}."
-    )]
-    SavedStateVersionMismatch {
-        saved_state_version: String,
-        config_version: String,
-    },
+diagnostic_codes! {
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("Unable to initialize relay compiler configuration. Error details: \n{details}")]
+        #[code("RELAY3004")]
+        ConfigError { details: String },
+
+        #[error(
+            "Config `{config_path}` is invalid:{}",
+            validation_errors
+                .iter()
+                .map(|err| format!("\n - {}", message_with_code(err)))
+                .collect::<Vec<_>>()
+                .join("")
+        )]
+        #[code("RELAY3005")]
+        ConfigFileValidation {
+            config_path: PathBuf,
+            validation_errors: Vec<ConfigValidationError>,
+        },
+
+        #[error(
+            "Diagnostics Error:{}",
+            errors
+                .iter()
+                .map(|err| format!("\n - {}", err.print_without_source()))
+                .collect::<Vec<_>>()
+                .join("")
+        )]
+        #[code("RELAY3006")]
+        DiagnosticsError { errors: Vec<Diagnostic> },
+
+        #[error(
+            "Failed to build:{}",
+            errors
+                .iter()
+                .map(|err| format!("\n - {}", err))
+                .collect::<Vec<_>>()
+                .join("")
+        )]
+        #[code("RELAY3007")]
+        BuildProjectsErrors { errors: Vec<BuildProjectError> },
+
+        #[error("Failed to read file `{file}`: {source}")]
+        #[code("RELAY3008")]
+        ReadFileError { file: PathBuf, source: io::Error },
+
+        #[error("Failed to write file `{file}`: {source}")]
+        #[code("RELAY3009")]
+        WriteFileError { file: PathBuf, source: io::Error },
+
+        #[error("Unable to serialize state to file: `{file}`, because of `{source}`.")]
+        #[code("RELAY3010")]
+        SerializationError {
+            file: PathBuf,
+            source: Box<bincode::ErrorKind>,
+        },
+
+        #[error("Unable to deserialize state from file: `{file}`, because of `{source}`.")]
+        #[code("RELAY3011")]
+        DeserializationError {
+            file: PathBuf,
+            source: Box<bincode::ErrorKind>,
+        },
+
+        #[error("Failed to canonicalize root: `{root}`.")]
+        #[code("RELAY3012")]
+        CanonicalizeRoot {
+            root: PathBuf,
+            source: std::io::Error,
+        },
+
+        #[error("Watchman error: {source}")]
+        #[code("RELAY3013")]
+        Watchman {
+            #[from]
+            source: watchman_client::Error,
+        },
+
+        #[error("Watchman query returned no results.")]
+        #[code("RELAY3014")]
+        EmptyQueryResult,
+
+        #[error("Failed to read file: `{file}`.")]
+        #[code("RELAY3015")]
+        FileRead {
+            file: PathBuf,
+            source: std::io::Error,
+        },
+
+        #[error("A thread that the Relay compiler spun up did not shut down gracefully: {error}")]
+        #[code("RELAY3016")]
+        JoinError { error: String },
+
+        #[error("Artifacts validation failed: {error}")]
+        #[code("RELAY3017")]
+        ArtifactsValidationError { error: String },
+
+        #[error("Error in post artifact writer: {error}")]
+        #[code("RELAY3018")]
+        PostArtifactsError {
+            error: Box<dyn std::error::Error + Sync + Send>,
+        },
+
+        #[error("Compilation cancelled due to new changes")]
+        #[code("RELAY3019")]
+        Cancelled,
+
+        #[error("No operation named `{operation_name}` was found in the enabled projects.")]
+        #[code("RELAY3020")]
+        OperationNotFound { operation_name: StringKey },
+
+        #[error("No field `{field}` was found in the schemas of the enabled projects.")]
+        #[code("RELAY3021")]
+        FieldNotFound { field: FieldCoordinate },
+
+        #[error("IO error {0}")]
+        #[code("RELAY3022")]
+        IOError(std::io::Error),
+
+        #[error("Unable to parse changed files list. {reason}")]
+        #[code("RELAY3023")]
+        ExternalSourceParseError { reason: String },
+
+        #[error("JSON parse error in `{file}`: {source}")]
+        #[code("RELAY3024")]
+        SerdeError {
+            file: PathBuf,
+            source: serde_json::Error,
+        },
+
+        #[error("glob pattern error: {0}")]
+        #[code("RELAY3025")]
+        PatternError(PatternError),
+
+        #[error(
+            "Saved state versions mismatch. Saved state: {saved_state_version}, config: {config_version}."
+        )]
+        #[code("RELAY3026")]
+        SavedStateVersionMismatch {
+            saved_state_version: String,
+            config_version: String,
+        },
+    }
 }
 
-#[derive(Debug, Error)]
-pub enum ConfigValidationError {
-    #[error("Root `{root_dir}` is not a directory.")]
-    RootNotDirectory { root_dir: PathBuf },
-
-    #[error("Source `{source_dir}` does not exist.")]
-    SourceNotExistent { source_dir: PathBuf },
-
-    #[error("Source `{source_dir}` is not a directory.")]
-    SourceNotDirectory { source_dir: PathBuf },
-
-    #[error(
-        "There is no source for the project `{project_name}`, the `sources` map should contain at least one path mapping to this project name."
-    )]
-    ProjectSourceMissing { project_name: ProjectName },
-
-    #[error(
-        "Source dir `{source_dir}` tries to use project `{project_name}`, but no such project exists."
-    )]
-    ProjectDefinitionMissing {
-        source_dir: PathBuf,
-        project_name: ProjectName,
-    },
-
-    #[error(
-        "The project `{project_name}` defines the base project `{base_project_name}`, but no such project exists."
-    )]
-    ProjectBaseMissing {
-        project_name: ProjectName,
-        base_project_name: ProjectName,
-    },
-
-    #[error("Project `{project_name}` needs to define exactly one of `schema` or `schema_dir`.")]
-    ProjectNeedsSchemaXorSchemaDir { project_name: ProjectName },
-
-    #[error(
-        "The `schema` configured for project `{project_name}` does not exist at `{schema_file}`."
-    )]
-    SchemaFileNotExistent {
-        project_name: ProjectName,
-        schema_file: PathBuf,
-    },
-
-    #[error(
-        "The `schema` configured for project `{project_name}` to be `{schema_file}` is not a file."
-    )]
-    SchemaFileNotFile {
-        project_name: ProjectName,
-        schema_file: PathBuf,
-    },
-
-    #[error(
-        "The `schema_dir` configured for project `{project_name}` does not exist at `{schema_dir}`."
-    )]
-    SchemaDirNotExistent {
-        project_name: ProjectName,
-        schema_dir: PathBuf,
-    },
-
-    #[error(
-        "The `schemaExtensions` configured for project `{project_name}` does not exist at `{extension_dir}`."
-    )]
-    ExtensionDirNotExistent {
-        project_name: ProjectName,
-        extension_dir: PathBuf,
-    },
-
-    #[error(
-        "The `schema_dir` configured for project `{project_name}` to be `{schema_dir}` is not a directory."
-    )]
-    SchemaDirNotDirectory {
-        project_name: ProjectName,
-        schema_dir: PathBuf,
-    },
-
-    #[error("The regex in `{key}` for project `{project_name}` is invalid.\n {error}.")]
-    InvalidRegex {
-        key: &'static str,
-        project_name: ProjectName,
-        error: regex::Error,
-    },
-
-    #[error("The `artifactDirectory` does not exist at `{path}`.")]
-    ArtifactDirectoryNotExistent { path: PathBuf },
-
-    #[error("Unable to find common path for directories in the config file.")]
-    CommonPathNotFound,
-
-    #[error("The config option `{name}` is no longer supported. {action}")]
-    RemovedConfigField {
-        name: &'static str,
-        action: &'static str,
-    },
+diagnostic_codes! {
+    #[derive(Debug, Error)]
+    pub enum ConfigValidationError {
+        #[error("Root `{root_dir}` is not a directory.")]
+        #[code("RELAY3027")]
+        RootNotDirectory { root_dir: PathBuf },
+
+        #[error("Source `{source_dir}` does not exist.")]
+        #[code("RELAY3028")]
+        SourceNotExistent { source_dir: PathBuf },
+
+        #[error("Source `{source_dir}` is not a directory.")]
+        #[code("RELAY3029")]
+        SourceNotDirectory { source_dir: PathBuf },
+
+        #[error(
+            "There is no source for the project `{project_name}`, the `sources` map should contain at least one path mapping to this project name."
+        )]
+        #[code("RELAY3030")]
+        ProjectSourceMissing { project_name: ProjectName },
+
+        #[error(
+            "Source dir `{source_dir}` tries to use project `{project_name}`, but no such project exists."
+        )]
+        #[code("RELAY3031")]
+        ProjectDefinitionMissing {
+            source_dir: PathBuf,
+            project_name: ProjectName,
+        },
+
+        #[error(
+            "The project `{project_name}` defines the base project `{base_project_name}`, but no such project exists."
+        )]
+        #[code("RELAY3032")]
+        ProjectBaseMissing {
+            project_name: ProjectName,
+            base_project_name: ProjectName,
+        },
+
+        #[error(
+            "The `uniqueDefinitionNameGroups` include the project `{project_name}`, but no such project exists."
+        )]
+        #[code("RELAY3033")]
+        UniqueDefinitionNameGroupProjectMissing { project_name: ProjectName },
+
+        #[error("Project `{project_name}` needs to define exactly one of `schema` or `schema_dir`.")]
+        #[code("RELAY3034")]
+        ProjectNeedsSchemaXorSchemaDir { project_name: ProjectName },
+
+        #[error(
+            "The `schema` configured for project `{project_name}` does not exist at `{schema_file}`."
+        )]
+        #[code("RELAY3035")]
+        SchemaFileNotExistent {
+            project_name: ProjectName,
+            schema_file: PathBuf,
+        },
+
+        #[error(
+            "The `schema` configured for project `{project_name}` to be `{schema_file}` is not a file."
+        )]
+        #[code("RELAY3036")]
+        SchemaFileNotFile {
+            project_name: ProjectName,
+            schema_file: PathBuf,
+        },
+
+        #[error("The file `{file}` listed in `sharedDirectiveDefs` does not exist or is not a file.")]
+        #[code("RELAY3037")]
+        SharedDirectiveDefsNotFile { file: PathBuf },
+
+        #[error(
+            "The `schema_dir` configured for project `{project_name}` does not exist at `{schema_dir}`."
+        )]
+        #[code("RELAY3038")]
+        SchemaDirNotExistent {
+            project_name: ProjectName,
+            schema_dir: PathBuf,
+        },
+
+        #[error(
+            "The `schemaExtensions` configured for project `{project_name}` does not exist at `{extension_dir}`."
+        )]
+        #[code("RELAY3039")]
+        ExtensionDirNotExistent {
+            project_name: ProjectName,
+            extension_dir: PathBuf,
+        },
+
+        #[error(
+            "The `schema_dir` configured for project `{project_name}` to be `{schema_dir}` is not a directory."
+        )]
+        #[code("RELAY3040")]
+        SchemaDirNotDirectory {
+            project_name: ProjectName,
+            schema_dir: PathBuf,
+        },
+
+        #[error(
+            "The `typeNames` template `{template}` of project `{project_name}` does not contain `{{name}}`."
+        )]
+        #[code("RELAY3041")]
+        TypeNameTemplateWithoutName {
+            project_name: ProjectName,
+            template: String,
+        },
+
+        #[error(
+            "Project `{project_name}` sets `obfuscateOperationNames`, which requires the `persist` config."
+        )]
+        #[code("RELAY3042")]
+        ObfuscationWithoutPersist { project_name: ProjectName },
+
+        #[error("The regex in `{key}` for project `{project_name}` is invalid.\n {error}.")]
+        #[code("RELAY3043")]
+        InvalidRegex {
+            key: &'static str,
+            project_name: ProjectName,
+            error: regex::Error,
+        },
+
+        #[error("The `artifactDirectory` does not exist at `{path}`.")]
+        #[code("RELAY3044")]
+        ArtifactDirectoryNotExistent { path: PathBuf },
+
+        #[error("Unable to find common path for directories in the config file.")]
+        #[code("RELAY3045")]
+        CommonPathNotFound,
+
+        #[error("The config option `{name}` is no longer supported. {action}")]
+        #[code("RELAY3046")]
+        RemovedConfigField {
+            name: &'static str,
+            action: &'static str,
+        },
+    }
 }
 
-#[derive(Debug, Error)]
-pub enum BuildProjectError {
-    #[error(
-        "Validation errors:{}",
-        errors
-            .iter()
-            .map(|err| format!("\n - {}", err.print_without_source()))
-            .collect::<Vec<_>>()
-            .join("")
-    )]
-    ValidationErrors {
-        errors: Vec<Diagnostic>,
-        project_name: ProjectName,
-    },
-
-    #[error("Persisting operation(s) failed:{0}",
-        errors
-            .iter()
-            .map(|err| format!("\n - {}", err))
-            .collect::<Vec<_>>()
-            .join("")
-    )]
-    PersistErrors {
-        errors: Vec<PersistError>,
-        project_name: ProjectName,
-    },
-
-    #[error("Failed to write file `{file}`: {source}")]
-    WriteFileError { file: PathBuf, source: io::Error },
+diagnostic_codes! {
+    #[derive(Debug, Error)]
+    pub enum BuildProjectError {
+        #[error(
+            "Validation errors:{}",
+            errors
+                .iter()
+                .map(|err| format!("\n - {}", err.print_without_source()))
+                .collect::<Vec<_>>()
+                .join("")
+        )]
+        #[code("RELAY3047")]
+        ValidationErrors {
+            errors: Vec<Diagnostic>,
+            project_name: ProjectName,
+        },
+
+        #[error("Persisting operation(s) failed:{0}",
+            errors
+                .iter()
+                .map(|err| format!("\n - {}", err))
+                .collect::<Vec<_>>()
+                .join("")
+        )]
+        #[code("RELAY3048")]
+        PersistErrors {
+            errors: Vec<PersistError>,
+            project_name: ProjectName,
+        },
+
+        #[error("Failed to write file `{file}`: {source}")]
+        #[code("RELAY3049")]
+        WriteFileError { file: PathBuf, source: io::Error },
+    }
 }
@@ -0,0 +1,271 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Collects what the compiler knows about one operation: where it is defined,
+//! the fragments it depends on, its IR after the transforms and the artifacts
+//! generated for it. Used by the `explain` command.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::path::PathBuf;
+
+use common::Location;
+use graphql_ir::FragmentDefinitionName;
+use graphql_ir::FragmentSpread;
+use graphql_ir::OperationDefinitionName;
+use graphql_ir::Program;
+use graphql_ir::Visitor;
+use graphql_text_printer::print_full_operation;
+use intern::string_key::StringKey;
+use relay_codegen::Printer;
+use relay_codegen::QueryID;
+use relay_transforms::Programs;
+use relay_typegen::FragmentLocations;
+use schema::SDLSchema;
+
+use crate::build_project::compute_schema_hash;
+use crate::build_project::read_persisted_id;
+use crate::compiler_state::ProjectName;
+use crate::config::Config;
+use crate::config::ProjectConfig;
+use crate::source_for_location;
+use crate::Artifact;
+use crate::ArtifactContent;
+use crate::FsSourceReader;
+
+#[derive(Debug)]
+pub struct OperationExplanation {
+    pub project_name: ProjectName,
+    pub operation_name: StringKey,
+    /// `path:line:column` of the name of the operation.
+    pub source_location: String,
+    /// Names of the fragments the operation spreads, directly or through
+    /// other fragments, sorted.
+    pub fragments: Vec<FragmentDefinitionName>,
+    /// The printed normalization IR of the operation, with its fragments.
+    pub transformed_ir: String,
+    pub artifacts: Vec<ExplainedArtifact>,
+    pub persisted_id: PersistedId,
+    /// Size in bytes of the query text sent to the server, if the operation
+    /// has one.
+    pub text_size: Option<usize>,
+}
+
+#[derive(Debug)]
+pub struct ExplainedArtifact {
+    pub path: PathBuf,
+    pub size: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PersistedId {
+    /// The project doesn't persist its operations.
+    NotConfigured,
+    /// The artifact on disk is missing, or was generated from a different
+    /// query text.
+    Outdated,
+    Persisted(String),
+}
+
+/// Explains the operation `operation_name` if it is defined in the project.
+pub fn explain_operation(
+    config: &Config,
+    project_config: &ProjectConfig,
+    schema: &SDLSchema,
+    programs: &Programs,
+    artifacts: Vec<Artifact>,
+    operation_name: StringKey,
+) -> Option<OperationExplanation> {
+    // Operations generated by the transforms, like refetch queries, are only
+    // in the transformed programs.
+    let source_operation = programs
+        .source
+        .operation(OperationDefinitionName(operation_name))
+        .or_else(|| {
+            programs
+                .reader
+                .operation(OperationDefinitionName(operation_name))
+        })?;
+
+    let mut fragments = Default::default();
+    collect_fragments(
+        &programs.source,
+        &mut fragments,
+        &mut FragmentSpreadCollector::collect(|collector| {
+            collector.default_visit_operation(source_operation)
+        }),
+    );
+
+    let transformed_ir = programs
+        .normalization
+        .operation(OperationDefinitionName(operation_name))
+        .map(|operation| {
+            print_full_operation(&programs.normalization, operation, Default::default())
+        })
+        .unwrap_or_default();
+
+    let mut persisted_id = PersistedId::NotConfigured;
+    let mut text_size = None;
+    let schema_hash = if project_config.schema_hash_comment {
+        Some(compute_schema_hash(schema))
+    } else {
+        None
+    };
+    let fragment_locations = FragmentLocations::new(programs.typegen.fragments());
+    let mut printer = Printer::with_dedupe(project_config);
+    let mut explained_artifacts = Vec::new();
+    for mut artifact in artifacts {
+        // The artifacts of generated operations are attributed to the
+        // definition they are generated from.
+        let is_operation_artifact = matches!(
+            &artifact.content,
+            ArtifactContent::Operation { normalization_operation, .. }
+                if normalization_operation.name.item.0 == operation_name
+        );
+        if !is_operation_artifact && !artifact.source_definition_names.contains(&operation_name) {
+            continue;
+        }
+        if let ArtifactContent::Operation {
+            text: Some(text),
+            id_and_text_hash,
+            ..
+        } = &mut artifact.content
+        {
+            text_size = Some(text.len());
+            if project_config.persist.is_some() {
+                *id_and_text_hash = read_persisted_id(&config.root_dir.join(&artifact.path), text);
+                persisted_id = match id_and_text_hash {
+                    Some(QueryID::Persisted { id, .. }) => PersistedId::Persisted(id.clone()),
+                    _ => PersistedId::Outdated,
+                };
+            }
+        }
+        let content = artifact.content.as_bytes(
+            config,
+            project_config,
+            &mut printer,
+            schema,
+            artifact.source_file,
+            &fragment_locations,
+            schema_hash.as_deref(),
+        );
+        explained_artifacts.push(ExplainedArtifact {
+            path: artifact.path,
+            size: content.len(),
+        });
+    }
+    explained_artifacts.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Some(OperationExplanation {
+        project_name: project_config.name,
+        operation_name,
+        source_location: format_location(config, source_operation.name.location),
+        fragments: fragments.into_iter().collect(),
+        transformed_ir,
+        artifacts: explained_artifacts,
+        persisted_id,
+        text_size,
+    })
+}
+
+fn collect_fragments(
+    program: &Program,
+    fragments: &mut BTreeSet<FragmentDefinitionName>,
+    spreads: &mut Vec<FragmentDefinitionName>,
+) {
+    while let Some(fragment_name) = spreads.pop() {
+        if !fragments.insert(fragment_name) {
+            continue;
+        }
+        if let Some(fragment) = program.fragment(fragment_name) {
+            spreads.extend(FragmentSpreadCollector::collect(|collector| {
+                collector.default_visit_fragment(fragment)
+            }));
+        }
+    }
+}
+
+fn format_location(config: &Config, location: Location) -> String {
+    let path = location.source_location().path();
+    match source_for_location(
+        &config.root_dir,
+        location.source_location(),
+        &FsSourceReader,
+    ) {
+        Some(source) => {
+            let range = source.to_text_source().to_span_range(location.span());
+            format!(
+                "{}:{}:{}",
+                path,
+                range.start.line + 1,
+                range.start.character + 1
+            )
+        }
+        None => path.to_string(),
+    }
+}
+
+#[derive(Default)]
+struct FragmentSpreadCollector {
+    spreads: Vec<FragmentDefinitionName>,
+}
+
+impl FragmentSpreadCollector {
+    fn collect(visit: impl FnOnce(&mut Self)) -> Vec<FragmentDefinitionName> {
+        let mut collector = Self::default();
+        visit(&mut collector);
+        collector.spreads
+    }
+}
+
+impl Visitor for FragmentSpreadCollector {
+    const NAME: &'static str = "FragmentSpreadCollector";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_fragment_spread(&mut self, spread: &FragmentSpread) {
+        self.spreads.push(spread.fragment.item);
+    }
+}
+
+impl fmt::Display for OperationExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Operation `{}` in project `{}`",
+            self.operation_name, self.project_name
+        )?;
+        writeln!(f, "  Source: {}", self.source_location)?;
+
+        writeln!(f, "\nFragments ({}):", self.fragments.len())?;
+        for fragment in &self.fragments {
+            writeln!(f, "  {}", fragment)?;
+        }
+
+        writeln!(f, "\nArtifacts:")?;
+        for artifact in &self.artifacts {
+            writeln!(f, "  {} ({} bytes)", artifact.path.display(), artifact.size)?;
+        }
+        if let Some(text_size) = self.text_size {
+            writeln!(f, "  Query text: {} bytes", text_size)?;
+        }
+
+        match &self.persisted_id {
+            PersistedId::NotConfigured => writeln!(
+                f,
+                "\nPersisted ID: none, the project doesn't persist queries"
+            )?,
+            PersistedId::Outdated => writeln!(
+                f,
+                "\nPersisted ID: none, the artifact on disk is missing or outdated. Run the compiler to persist the query."
+            )?,
+            PersistedId::Persisted(id) => writeln!(f, "\nPersisted ID: {}", id)?,
+        }
+
+        write!(f, "\nTransformed IR:\n{}", self.transformed_ir)
+    }
+}
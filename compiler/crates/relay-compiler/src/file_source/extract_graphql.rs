@@ -61,7 +61,13 @@ pub fn extract_javascript_features_from_file(
     file: &File,
 ) -> Result<LocatedJavascriptSourceFeatures> {
     let contents = read_file_to_string(file_source_result, file)?;
-    let features = extract_graphql::extract(&contents);
+    Ok(extract_javascript_features(&file.name, &contents))
+}
+
+/// Extracts `graphql` tagged literals and Relay-specific docblocks from the
+/// contents of a JavaScript file, which may not be saved yet.
+pub fn extract_javascript_features(path: &Path, contents: &str) -> LocatedJavascriptSourceFeatures {
+    let features = extract_graphql::extract_for_path(path, contents);
     let mut graphql_sources = Vec::new();
     let mut docblock_sources = Vec::new();
     for (index, feature) in features.into_iter().enumerate() {
@@ -81,10 +87,10 @@ pub fn extract_javascript_features_from_file(
         }
     }
 
-    Ok(LocatedJavascriptSourceFeatures {
+    LocatedJavascriptSourceFeatures {
         graphql_sources,
         docblock_sources,
-    })
+    }
 }
 
 pub fn source_for_location(
@@ -96,7 +102,7 @@ pub fn source_for_location(
         SourceLocationKey::Embedded { path, index } => {
             let absolute_path = root_dir.join(path.lookup());
             let contents = source_reader.read_file_to_string(&absolute_path).ok()?;
-            let file_sources = extract_graphql::extract(&contents);
+            let file_sources = extract_graphql::extract_for_path(&absolute_path, &contents);
             file_sources.into_iter().nth(index.into())
         }
         SourceLocationKey::Standalone { path } => {
@@ -10,6 +10,7 @@ use std::borrow::Cow;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::ffi::OsStr;
+use std::ffi::OsString;
 use std::path::Component;
 use std::path::Path;
 use std::path::PathBuf;
@@ -135,11 +136,16 @@ fn categorize_non_watchman_files(
 pub struct FileCategorizer {
     source_language: HashMap<ProjectName, TypegenLanguage>,
     extensions_mapping: PathMapping<ProjectSet>,
-    default_generated_dir: &'static OsStr,
+    relative_generated_dirs: FnvHashSet<OsString>,
+    artifact_extensions: FnvHashSet<OsString>,
+    /// The project and the artifact suffix of each schema variant project.
+    schema_variants: HashMap<ProjectName, (ProjectName, String)>,
     generated_dir_mapping: PathMapping<ProjectName>,
     source_mapping: PathMapping<ProjectSet>,
     schema_file_mapping: HashMap<PathBuf, ProjectSet>,
     schema_dir_mapping: PathMapping<ProjectSet>,
+    source_extensions_projects: FnvHashSet<ProjectName>,
+    shared_directive_defs_mapping: HashMap<PathBuf, ProjectSet>,
 }
 
 impl FileCategorizer {
@@ -149,6 +155,13 @@ impl FileCategorizer {
             source_mapping.push((path.clone(), project_set.clone()));
         }
 
+        let source_extensions_projects = config
+            .projects
+            .iter()
+            .filter(|(_, project_config)| project_config.schema_extensions_from_sources)
+            .map(|(project_name, _)| *project_name)
+            .collect();
+
         let mut extensions_map: HashMap<PathBuf, ProjectSet> = Default::default();
         for (&project_name, project_config) in &config.projects {
             for extension_dir in &project_config.schema_extensions {
@@ -177,6 +190,15 @@ impl FileCategorizer {
             }
         }
 
+        // Shared directive definitions are extensions of every project.
+        let mut shared_directive_defs_mapping: HashMap<PathBuf, ProjectSet> = Default::default();
+        if !config.projects.is_empty() {
+            let all_projects = ProjectSet::new(config.projects.keys().copied().collect());
+            for file in &config.shared_directive_defs {
+                shared_directive_defs_mapping.insert(file.clone(), all_projects.clone());
+            }
+        }
+
         let mut schema_dir_mapping_map: HashMap<PathBuf, ProjectSet> = Default::default();
         for (&project_name, project_config) in &config.projects {
             if let SchemaLocation::Directory(directory) = &project_config.schema_location {
@@ -205,6 +227,32 @@ impl FileCategorizer {
                 generated_dir_mapping.push((extra_artifacts_output.clone(), project_name));
             }
         }
+        let relative_generated_dirs = config
+            .projects
+            .values()
+            .map(|project_config| OsString::from(&project_config.artifact_filename.directory))
+            .collect();
+        let artifact_extensions = config
+            .projects
+            .values()
+            .filter_map(|project_config| project_config.artifact_filename.extension.as_ref())
+            .map(OsString::from)
+            .collect();
+        let schema_variants = config
+            .projects
+            .iter()
+            .filter_map(|(&project_name, project_config)| {
+                let schema_variant = project_config.schema_variant.as_ref()?;
+                Some((
+                    project_name,
+                    (
+                        schema_variant.project_name,
+                        format!(".{}", project_config.artifact_filename.suffix),
+                    ),
+                ))
+            })
+            .collect();
+
         let source_language: HashMap<ProjectName, TypegenLanguage> = config
             .projects
             .iter()
@@ -216,11 +264,15 @@ impl FileCategorizer {
         Self {
             source_language,
             extensions_mapping: PathMapping::new(extensions_map.into_iter().collect()),
-            default_generated_dir: OsStr::new("__generated__"),
+            relative_generated_dirs,
+            artifact_extensions,
+            schema_variants,
             generated_dir_mapping: PathMapping::new(generated_dir_mapping),
             schema_file_mapping,
             schema_dir_mapping: PathMapping::new(schema_dir_mapping),
             source_mapping: PathMapping::new(source_mapping),
+            source_extensions_projects,
+            shared_directive_defs_mapping,
         }
     }
 
@@ -232,7 +284,10 @@ impl FileCategorizer {
 
         if let Some(project_name) = self.generated_dir_mapping.find(path) {
             return if let Some(extension) = extension {
-                if is_source_code_extension(extension) || is_extra_extensions(extension) {
+                if is_source_code_extension(extension)
+                    || is_extra_extensions(extension)
+                    || self.artifact_extensions.contains(extension)
+                {
                     Ok(FileGroup::Generated { project_name })
                 } else {
                     Ok(FileGroup::Ignore)
@@ -244,21 +299,25 @@ impl FileCategorizer {
 
         let extension = extension.ok_or(Cow::Borrowed("Got unexpected path without extension."))?;
 
-        if is_source_code_extension(extension) {
+        if is_source_code_extension(extension) || self.artifact_extensions.contains(extension) {
             let project_set = self
                 .source_mapping
                 .find(path)
                 .ok_or(Cow::Borrowed("File is not in any source set."))?;
             if self.in_relative_generated_dir(path) {
-                if project_set.has_multiple_projects() {
+                if !project_set.has_multiple_projects() {
+                    let project_name = project_set.into_iter().next().unwrap();
+                    Ok(FileGroup::Generated { project_name })
+                } else if let Some(project_name) =
+                    self.find_schema_variant_project(&project_set, path)
+                {
+                    Ok(FileGroup::Generated { project_name })
+                } else {
                     Err(Cow::Owned(format!(
                         "Overlapping input sources are incompatible with relative generated \
                         directories. Got file in a relative generated directory with source set {:?}.",
                         project_set,
                     )))
-                } else {
-                    let project_name = project_set.into_iter().next().unwrap();
-                    Ok(FileGroup::Generated { project_name })
                 }
             } else {
                 let is_valid_extension =
@@ -274,8 +333,14 @@ impl FileCategorizer {
                 Ok(FileGroup::Schema {
                     project_set: project_set.clone(),
                 })
+            } else if let Some(project_set) = self.shared_directive_defs_mapping.get(path) {
+                Ok(FileGroup::Extension {
+                    project_set: project_set.clone(),
+                })
             } else if let Some(project_set) = self.extensions_mapping.find(path) {
                 Ok(FileGroup::Extension { project_set })
+            } else if let Some(project_set) = self.find_source_extension_project_set(path) {
+                Ok(FileGroup::Extension { project_set })
             } else if let Some(project_set) = self.schema_dir_mapping.find(path) {
                 Ok(FileGroup::Schema { project_set })
             } else {
@@ -290,9 +355,58 @@ impl FileCategorizer {
         }
     }
 
+    /// Finds the projects of a `*.client.graphql` file in the sources of
+    /// projects that discover schema extensions from their sources.
+    fn find_source_extension_project_set(&self, path: &Path) -> Option<ProjectSet> {
+        if self.source_extensions_projects.is_empty() || !is_client_schema_extension(path) {
+            return None;
+        }
+        let project_names: Vec<ProjectName> = self
+            .source_mapping
+            .find(path)?
+            .into_iter()
+            .filter(|project_name| self.source_extensions_projects.contains(project_name))
+            .collect();
+        if project_names.is_empty() {
+            None
+        } else {
+            Some(ProjectSet::new(project_names))
+        }
+    }
+
+    /// The project of a generated file in the relative generated directory of
+    /// sources shared by a project and its schema variants only, which is the
+    /// variant whose artifact suffix ends the name of the file, if any.
+    fn find_schema_variant_project(
+        &self,
+        project_set: &ProjectSet,
+        path: &Path,
+    ) -> Option<ProjectName> {
+        let mut projects = project_set
+            .iter()
+            .filter(|project_name| !self.schema_variants.contains_key(*project_name));
+        let project_name = *projects.next()?;
+        if projects.next().is_some() {
+            return None;
+        }
+        let file_stem = path.file_stem()?.to_str()?;
+        let mut variant_name = None;
+        for name in project_set.iter() {
+            if let Some((variant_project_name, suffix)) = self.schema_variants.get(name) {
+                if *variant_project_name != project_name {
+                    return None;
+                }
+                if file_stem.ends_with(suffix.as_str()) {
+                    variant_name = Some(*name);
+                }
+            }
+        }
+        Some(variant_name.unwrap_or(project_name))
+    }
+
     fn in_relative_generated_dir(&self, path: &Path) -> bool {
         path.components().any(|comp| match comp {
-            Component::Normal(comp) => comp == self.default_generated_dir,
+            Component::Normal(comp) => self.relative_generated_dirs.contains(comp),
             _ => false,
         })
     }
@@ -341,13 +455,33 @@ impl<T: Clone> PathMapping<T> {
 }
 
 fn is_source_code_extension(extension: &OsStr) -> bool {
-    extension == "js" || extension == "jsx" || extension == "ts" || extension == "tsx"
+    extension == "js"
+        || extension == "jsx"
+        || extension == "ts"
+        || extension == "tsx"
+        || is_single_file_component_extension(extension)
+}
+
+/// Vue and Svelte components embed the code of any project language.
+fn is_single_file_component_extension(extension: &OsStr) -> bool {
+    extension == "vue" || extension == "svelte"
 }
 
 fn is_schema_extension(extension: &OsStr) -> bool {
     extension == "graphql" || extension == "gql"
 }
 
+/// Files named by this convention in the sources of projects with
+/// `schema_extensions_from_sources` are treated as schema extensions.
+pub(crate) const CLIENT_SCHEMA_EXTENSION_SUFFIX: &str = ".client.graphql";
+
+fn is_client_schema_extension(path: &Path) -> bool {
+    matches!(
+        path.file_name().and_then(|name| name.to_str()),
+        Some(name) if name.ends_with(CLIENT_SCHEMA_EXTENSION_SUFFIX)
+    )
+}
+
 fn is_extra_extensions(extension: &OsStr) -> bool {
     extension == "php" || extension == "json"
 }
@@ -356,13 +490,14 @@ fn is_valid_source_code_extension(typegen_language: &TypegenLanguage, extension:
     match typegen_language {
         TypegenLanguage::TypeScript => is_source_code_extension(extension),
         TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
-            extension == "js" || extension == "jsx"
+            extension == "js" || extension == "jsx" || is_single_file_component_extension(extension)
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use common::SourceLocationKey;
     use intern::string_key::Intern;
 
     use super::*;
@@ -491,6 +626,198 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_categorize_shared_directive_defs() {
+        let config = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src/a": "a",
+                        "src/b": "b"
+                    },
+                    "sharedDirectiveDefs": ["graphql/directives.graphql"],
+                    "projects": {
+                        "a": {
+                            "schema": "graphql/a.graphql",
+                            "language": "flow"
+                        },
+                        "b": {
+                            "schema": "graphql/b.graphql",
+                            "language": "flow"
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap();
+        let categorizer = FileCategorizer::from_config(&config);
+
+        assert_eq!(
+            categorizer
+                .categorize(&PathBuf::from("graphql/directives.graphql"))
+                .unwrap(),
+            FileGroup::Extension {
+                project_set: ProjectSet::new(vec!["a".intern(), "b".intern()]),
+            },
+        );
+    }
+
+    #[test]
+    fn test_categorize_artifact_filename() {
+        let config = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src": "a"
+                    },
+                    "projects": {
+                        "a": {
+                            "schema": "graphql/a.graphql",
+                            "language": "flow",
+                            "artifactFilename": {
+                                "suffix": "relay",
+                                "extension": "mjs",
+                                "directory": "__relay__"
+                            }
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap();
+        let categorizer = FileCategorizer::from_config(&config);
+
+        assert_eq!(
+            categorizer
+                .categorize(&PathBuf::from("src/nested/__relay__/Foo.relay.mjs"))
+                .unwrap(),
+            FileGroup::Generated {
+                project_name: "a".intern()
+            },
+        );
+        assert_eq!(
+            categorizer
+                .categorize(&PathBuf::from("src/nested/__relay__/Foo.js"))
+                .unwrap(),
+            FileGroup::Generated {
+                project_name: "a".intern()
+            },
+        );
+        assert_eq!(
+            categorizer
+                .categorize(&PathBuf::from("src/nested/__generated__/Foo.js"))
+                .unwrap(),
+            FileGroup::Source {
+                project_set: ProjectSet::of("a".intern()),
+            },
+        );
+        assert_eq!(
+            config.projects[&"a".intern()].path_for_artifact(
+                SourceLocationKey::standalone("src/nested/Foo.js"),
+                "FooQuery".intern()
+            ),
+            PathBuf::from("src/nested/__relay__/FooQuery.relay.mjs"),
+        );
+    }
+
+    #[test]
+    fn test_categorize_schema_variants() {
+        let config = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src": "web"
+                    },
+                    "projects": {
+                        "web": {
+                            "schema": "graphql/web.graphql",
+                            "language": "flow",
+                            "schemaVariants": {
+                                "embedded": {
+                                    "schemaExtensions": ["graphql/embedded"]
+                                }
+                            }
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap();
+        let categorizer = FileCategorizer::from_config(&config);
+        let project_set = ProjectSet::new(vec!["web".intern(), "web_embedded".intern()]);
+
+        assert_eq!(
+            categorizer
+                .categorize(&PathBuf::from("src/Foo.js"))
+                .unwrap(),
+            FileGroup::Source {
+                project_set: project_set.clone(),
+            },
+        );
+        assert_eq!(
+            categorizer
+                .categorize(&PathBuf::from("graphql/web.graphql"))
+                .unwrap(),
+            FileGroup::Schema { project_set },
+        );
+        assert_eq!(
+            categorizer
+                .categorize(&PathBuf::from("graphql/embedded/extensions.graphql"))
+                .unwrap(),
+            FileGroup::Extension {
+                project_set: ProjectSet::of("web_embedded".intern()),
+            },
+        );
+        assert_eq!(
+            categorizer
+                .categorize(&PathBuf::from("src/__generated__/FooQuery.graphql.js"))
+                .unwrap(),
+            FileGroup::Generated {
+                project_name: "web".intern()
+            },
+        );
+        assert_eq!(
+            categorizer
+                .categorize(&PathBuf::from(
+                    "src/__generated__/FooQuery.graphql.embedded.js"
+                ))
+                .unwrap(),
+            FileGroup::Generated {
+                project_name: "web_embedded".intern()
+            },
+        );
+        assert_eq!(
+            config.projects[&"web_embedded".intern()].path_for_artifact(
+                SourceLocationKey::standalone("src/Foo.js"),
+                "FooQuery".intern()
+            ),
+            PathBuf::from("src/__generated__/FooQuery.graphql.embedded.js"),
+        );
+    }
+
+    #[test]
+    fn test_categorize_single_file_components() {
+        let config = create_test_config();
+        let categorizer = FileCategorizer::from_config(&config);
+
+        assert_eq!(
+            categorizer
+                .categorize(&PathBuf::from("src/js/Profile.vue"))
+                .unwrap(),
+            FileGroup::Source {
+                project_set: ProjectSet::of("public".intern()),
+            },
+        );
+        assert_eq!(
+            categorizer
+                .categorize(&PathBuf::from("src/typescript/Profile.svelte"))
+                .unwrap(),
+            FileGroup::Source {
+                project_set: ProjectSet::of("typescript".intern()),
+            },
+        );
+    }
+
     #[test]
     fn test_invalid_extension() {
         let config = create_test_config();
@@ -544,4 +871,52 @@ mod tests {
             )),
         );
     }
+
+    #[test]
+    fn test_categorize_client_schema_extensions_from_sources() {
+        let config = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src/js": "public",
+                        "src/js/internal": "internal"
+                    },
+                    "projects": {
+                        "public": {
+                            "schema": "graphql/public.graphql",
+                            "language": "flow",
+                            "schemaExtensionsFromSources": true
+                        },
+                        "internal": {
+                            "schema": "graphql/internal.graphql",
+                            "language": "flow"
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap();
+        let categorizer = FileCategorizer::from_config(&config);
+
+        assert_eq!(
+            categorizer
+                .categorize(&PathBuf::from("src/js/feature/local.client.graphql"))
+                .unwrap(),
+            FileGroup::Extension {
+                project_set: ProjectSet::of("public".intern()),
+            },
+        );
+        assert_eq!(
+            categorizer.categorize(&PathBuf::from("src/js/feature/local.graphql")),
+            Err(Cow::Borrowed(
+                "Expected *.graphql/*.gql file to be either a schema or extension."
+            )),
+        );
+        assert_eq!(
+            categorizer.categorize(&PathBuf::from("src/js/internal/local.client.graphql")),
+            Err(Cow::Borrowed(
+                "Expected *.graphql/*.gql file to be either a schema or extension."
+            )),
+        );
+    }
 }
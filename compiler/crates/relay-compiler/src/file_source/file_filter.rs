@@ -75,6 +75,7 @@ fn get_extra_roots(config: &Config, enabled_projects: &FnvHashSet<StringKey>) ->
             SchemaLocation::File(path) | SchemaLocation::Directory(path) => roots.push(path),
         }
     }
+    roots.extend(&config.shared_directive_defs);
     unify_roots(roots)
 }
 
@@ -35,7 +35,8 @@ pub use source_control_update_status::SourceControlUpdateStatus;
 pub use watchman_client::prelude::Clock;
 use watchman_file_source::WatchmanFileSource;
 
-use self::external_file_source::ExternalFileSourceResult;
+pub use self::external_file_source::ExternalFileSourceResult;
+pub use self::extract_graphql::extract_javascript_features;
 pub use self::extract_graphql::extract_javascript_features_from_file;
 pub use self::extract_graphql::source_for_location;
 pub use self::extract_graphql::FsSourceReader;
@@ -43,6 +44,7 @@ pub use self::extract_graphql::LocatedDocblockSource;
 pub use self::extract_graphql::LocatedGraphQLSource;
 pub use self::extract_graphql::LocatedJavascriptSourceFeatures;
 pub use self::extract_graphql::SourceReader;
+pub use self::walk_dir_file_source::find_generated_files;
 use self::walk_dir_file_source::WalkDirFileSource;
 use self::walk_dir_file_source::WalkDirFileSourceResult;
 use crate::compiler_state::CompilerState;
@@ -6,17 +6,22 @@
  */
 
 use std::collections::HashSet;
+use std::fs;
 use std::path::Path;
 use std::path::PathBuf;
 
 use common::PerfLogEvent;
 use common::PerfLogger;
+use fnv::FnvHashMap;
 use log::debug;
 use relay_typegen::TypegenLanguage;
 use walkdir::WalkDir;
 
 use super::File;
+use super::FileCategorizer;
+use super::FileGroup;
 use crate::compiler_state::CompilerState;
+use crate::compiler_state::ProjectName;
 use crate::config::Config;
 use crate::errors::Result;
 use crate::FileSourceResult;
@@ -33,9 +38,11 @@ pub struct WalkDirFileSource<'config> {
 }
 
 fn get_expected_file_extensions(config: &Config) -> HashSet<&str> {
-    let mut file_extensions = HashSet::<&str>::with_capacity(6);
+    let mut file_extensions = HashSet::<&str>::with_capacity(8);
     file_extensions.insert("graphql");
     file_extensions.insert("gql");
+    file_extensions.insert("vue");
+    file_extensions.insert("svelte");
 
     for project in config.enabled_projects() {
         match project.typegen_config.language {
@@ -113,3 +120,45 @@ impl<'config> WalkDirFileSource<'config> {
         Ok(compiler_state)
     }
 }
+
+/// Finds the generated files of each project on disk, including the ones in
+/// the `__generated__` directories next to the sources, which the default
+/// `excludes` hide from the file sources. Only the files marked `@generated`
+/// are returned, so that the files written by hand next to the artifacts are
+/// never removed.
+pub fn find_generated_files(config: &Config) -> FnvHashMap<ProjectName, Vec<PathBuf>> {
+    let categorizer = FileCategorizer::from_config(config);
+    let roots = config.sources.keys().cloned().chain(
+        config
+            .projects
+            .values()
+            .filter_map(|project_config| project_config.output.clone()),
+    );
+    let mut generated_files: FnvHashMap<ProjectName, Vec<PathBuf>> = Default::default();
+    for root in roots {
+        for dir_entry in WalkDir::new(config.root_dir.join(root))
+            .into_iter()
+            .filter_entry(|dir_entry| dir_entry.file_name() != "node_modules")
+            .filter_map(|entry| entry.ok())
+            .filter(|dir_entry| dir_entry.file_type().is_file())
+        {
+            let relative_path = match dir_entry.path().strip_prefix(&config.root_dir) {
+                Ok(relative_path) => relative_path,
+                Err(_) => continue,
+            };
+            if let Ok(FileGroup::Generated { project_name }) = categorizer.categorize(relative_path)
+            {
+                let is_marked_generated = fs::read_to_string(dir_entry.path())
+                    .map_or(false, |content| content.contains("@generated"));
+                if !is_marked_generated {
+                    continue;
+                }
+                generated_files
+                    .entry(project_name)
+                    .or_default()
+                    .push(relative_path.to_path_buf());
+            }
+        }
+    }
+    generated_files
+}
@@ -10,6 +10,7 @@ use std::path::PathBuf;
 use relay_typegen::TypegenLanguage;
 use watchman_client::prelude::*;
 
+use super::file_categorizer::CLIENT_SCHEMA_EXTENSION_SUFFIX;
 use crate::config::Config;
 use crate::config::SchemaLocation;
 
@@ -25,21 +26,41 @@ pub fn get_watchman_expr(config: &Config) -> Expr {
                     .collect::<Vec<_>>()
             })
             .map(|(path, project)| {
+                // Ending in *.js(x) or *.ts(x) depending on the project language,
+                // or a Vue or Svelte single-file component.
+                let source_files_expr = Expr::Suffix(match &project.typegen_config.language {
+                    TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
+                        vec![
+                            PathBuf::from("js"),
+                            PathBuf::from("jsx"),
+                            PathBuf::from("vue"),
+                            PathBuf::from("svelte"),
+                        ]
+                    }
+                    TypegenLanguage::TypeScript => {
+                        vec![
+                            PathBuf::from("js"),
+                            PathBuf::from("jsx"),
+                            PathBuf::from("ts"),
+                            PathBuf::from("tsx"),
+                            PathBuf::from("vue"),
+                            PathBuf::from("svelte"),
+                        ]
+                    }
+                });
                 Expr::All(vec![
-                    // Ending in *.js(x) or *.ts(x) depending on the project language.
-                    Expr::Suffix(match &project.typegen_config.language {
-                        TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
-                            vec![PathBuf::from("js"), PathBuf::from("jsx")]
-                        }
-                        TypegenLanguage::TypeScript => {
-                            vec![
-                                PathBuf::from("js"),
-                                PathBuf::from("jsx"),
-                                PathBuf::from("ts"),
-                                PathBuf::from("tsx"),
-                            ]
-                        }
-                    }),
+                    if project.schema_extensions_from_sources {
+                        // Or a client schema extension by convention.
+                        Expr::Any(vec![
+                            source_files_expr,
+                            Expr::Match(MatchTerm {
+                                glob: format!("*{}", CLIENT_SCHEMA_EXTENSION_SUFFIX),
+                                ..Default::default()
+                            }),
+                        ])
+                    } else {
+                        source_files_expr
+                    },
                     // In the related source root.
                     Expr::DirName(DirNameTerm {
                         path: path.clone(),
@@ -84,6 +105,14 @@ pub fn get_watchman_expr(config: &Config) -> Expr {
         expressions.push(schema_file_expr);
     }
 
+    if !config.shared_directive_defs.is_empty() {
+        let shared_directive_defs_expr = Expr::Name(NameTerm {
+            paths: config.shared_directive_defs.clone(),
+            wholename: true,
+        });
+        expressions.push(shared_directive_defs_expr);
+    }
+
     let schema_dir_paths = get_schema_dir_paths(config);
     if !schema_dir_paths.is_empty() {
         let schema_dir_expr = expr_graphql_files_in_dirs(schema_dir_paths);
@@ -111,6 +140,7 @@ pub fn get_all_roots(config: &Config) -> Vec<PathBuf> {
     let extension_roots = get_extension_roots(config);
     let schema_file_roots = get_schema_file_roots(config);
     let schema_dir_roots = get_schema_dir_paths(config);
+    let shared_directive_defs_roots = get_shared_directive_defs_roots(config);
     unify_roots(
         source_roots
             .into_iter()
@@ -118,6 +148,7 @@ pub fn get_all_roots(config: &Config) -> Vec<PathBuf> {
             .chain(extension_roots)
             .chain(schema_file_roots)
             .chain(schema_dir_roots)
+            .chain(shared_directive_defs_roots)
             .collect(),
     )
 }
@@ -188,6 +219,15 @@ fn get_schema_file_roots(config: &Config) -> impl Iterator<Item = PathBuf> {
         })
 }
 
+/// Returns root directories that contain shared directive definition files.
+fn get_shared_directive_defs_roots(config: &Config) -> impl Iterator<Item = PathBuf> + '_ {
+    config.shared_directive_defs.iter().map(|path| {
+        path.parent()
+            .expect("A shared directive definition file in the project root directory is currently not supported.")
+            .to_owned()
+    })
+}
+
 fn expr_files_in_dirs(roots: Vec<PathBuf>) -> Expr {
     expr_any(
         roots
@@ -0,0 +1,343 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Applies the safe fixes of diagnostics to the source files, for
+//! `relay-compiler --fix`. The LSP resolves the same fixes with `resolve_fix`
+//! to offer them as quick fixes.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use common::Diagnostic;
+use common::DiagnosticFix;
+use common::Location;
+use common::SourceLocationKey;
+use common::Span;
+use common::TextSource;
+use extract_graphql::JavaScriptSourceFeature;
+use graphql_syntax::parse_executable;
+use graphql_syntax::ExecutableDefinition;
+use graphql_syntax::FragmentDefinition;
+use graphql_syntax::List;
+use intern::Lookup;
+
+const ARGUMENT_DEFINITIONS: &str = "argumentDefinitions";
+
+/// A fix applied to a source file.
+#[derive(Debug)]
+pub struct AppliedFix {
+    /// The path of the fixed file, relative to the root directory.
+    pub path: PathBuf,
+    /// The 1-based line of the diagnostic in the file, or of the fix when it
+    /// edits another file than the one of the diagnostic, before any fix.
+    pub line: usize,
+    pub title: String,
+    pub message: String,
+}
+
+#[derive(Debug, Default)]
+pub struct FixSummary {
+    pub applied: Vec<AppliedFix>,
+}
+
+impl FixSummary {
+    pub fn is_empty(&self) -> bool {
+        self.applied.is_empty()
+    }
+}
+
+impl fmt::Display for FixSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.applied.is_empty() {
+            return writeln!(f, "No safe fixes to apply.");
+        }
+        writeln!(
+            f,
+            "Applied {} fix{}:",
+            self.applied.len(),
+            if self.applied.len() == 1 { "" } else { "es" }
+        )?;
+        for fix in &self.applied {
+            writeln!(
+                f,
+                "  {}:{}: {} ({})",
+                fix.path.display(),
+                fix.line,
+                fix.title,
+                fix.message
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The source edited by the `fix` of a diagnostic at `location`: the source of
+/// the diagnostic, or the one of the edits of the fix, if they are all in the
+/// same source.
+pub fn fix_source_location(location: Location, fix: &DiagnosticFix) -> Option<SourceLocationKey> {
+    match fix {
+        DiagnosticFix::Edits { edits, .. } => {
+            let source_location = edits.first()?.location.source_location();
+            edits
+                .iter()
+                .all(|edit| edit.location.source_location() == source_location)
+                .then_some(source_location)
+        }
+        DiagnosticFix::ReplaceWith(_) | DiagnosticFix::RemoveVariableDefinition => {
+            Some(location.source_location())
+        }
+    }
+}
+
+/// Resolves the `fix` of a diagnostic at `span` to the spans of the GraphQL
+/// `text` of the source it edits to replace, ordered and without overlaps,
+/// and their replacements.
+pub fn resolve_fix(text: &str, span: Span, fix: &DiagnosticFix) -> Option<Vec<(Span, String)>> {
+    match fix {
+        DiagnosticFix::ReplaceWith(replacement) => Some(vec![(span, replacement.clone())]),
+        DiagnosticFix::Edits { edits, .. } => {
+            let mut edits: Vec<(Span, String)> = edits
+                .iter()
+                .map(|edit| (*edit.location.span(), edit.replacement.clone()))
+                .collect();
+            edits.sort_by_key(|(span, _)| (span.start, span.end));
+            let valid = edits.iter().all(|(span, _)| {
+                span.start <= span.end
+                    && text.is_char_boundary(span.start as usize)
+                    && text.is_char_boundary(span.end as usize)
+            }) && edits
+                .windows(2)
+                .all(|pair| pair[0].0.end <= pair[1].0.start);
+            (valid && !edits.is_empty()).then_some(edits)
+        }
+        DiagnosticFix::RemoveVariableDefinition => {
+            let document = parse_executable(text, SourceLocationKey::generated()).ok()?;
+            let removed_span =
+                document
+                    .definitions
+                    .iter()
+                    .find_map(|definition| match definition {
+                        ExecutableDefinition::Operation(operation) => {
+                            operation.variable_definitions.as_ref().and_then(|list| {
+                                list_item_removal(
+                                    list,
+                                    |item| item.span,
+                                    |item| item.name.span == span,
+                                )
+                            })
+                        }
+                        ExecutableDefinition::Fragment(fragment) => fragment
+                            .variable_definitions
+                            .as_ref()
+                            .and_then(|list| {
+                                list_item_removal(
+                                    list,
+                                    |item| item.span,
+                                    |item| item.name.span == span,
+                                )
+                            })
+                            .or_else(|| argument_definition_removal(fragment, span)),
+                    })?;
+            Some(vec![(removed_span, String::new())])
+        }
+    }
+}
+
+/// The span to remove to delete the item of `list` matching `predicate`,
+/// including its separator from the other items. The whole list is removed
+/// with its delimiters when it's the only item.
+fn list_item_removal<T>(
+    list: &List<T>,
+    item_span: impl Fn(&T) -> Span,
+    predicate: impl Fn(&T) -> bool,
+) -> Option<Span> {
+    let index = list.items.iter().position(predicate)?;
+    let spans: Vec<Span> = list.items.iter().map(item_span).collect();
+    Some(if spans.len() == 1 {
+        list.span
+    } else if index + 1 < spans.len() {
+        Span::new(spans[index].start, spans[index + 1].start)
+    } else {
+        Span::new(spans[index - 1].end, spans[index].end)
+    })
+}
+
+/// The span to remove to delete the `@argumentDefinitions` entry named at
+/// `span`, or the whole directive when it's the only entry.
+fn argument_definition_removal(fragment: &FragmentDefinition, span: Span) -> Option<Span> {
+    let index = fragment
+        .directives
+        .iter()
+        .position(|directive| directive.name.value.lookup() == ARGUMENT_DEFINITIONS)?;
+    let directive = &fragment.directives[index];
+    let arguments = directive.arguments.as_ref()?;
+    if arguments.items.len() == 1 {
+        if arguments.items[0].name.span != span {
+            return None;
+        }
+        let previous_end = if index == 0 {
+            fragment.type_condition.span.end
+        } else {
+            fragment.directives[index - 1].span.end
+        };
+        return Some(Span::new(previous_end, directive.span.end));
+    }
+    list_item_removal(arguments, |item| item.span, |item| item.name.span == span)
+}
+
+/// Applies the safe fixes of `diagnostics` to the files of `root_dir` they
+/// edit. Fixes overlapping a fix applied to the same file are skipped: they
+/// can be applied by linting and fixing again.
+pub fn apply_safe_fixes(root_dir: &Path, diagnostics: &[Diagnostic]) -> io::Result<FixSummary> {
+    let mut diagnostics_by_path: BTreeMap<&str, Vec<(&Diagnostic, SourceLocationKey)>> =
+        BTreeMap::new();
+    for diagnostic in diagnostics {
+        let source_location = match diagnostic
+            .safe_fix()
+            .and_then(|fix| fix_source_location(diagnostic.location(), fix))
+        {
+            Some(source_location) => source_location,
+            None => continue,
+        };
+        match source_location {
+            SourceLocationKey::Embedded { path, .. } | SourceLocationKey::Standalone { path } => {
+                diagnostics_by_path
+                    .entry(path.lookup())
+                    .or_default()
+                    .push((diagnostic, source_location))
+            }
+            SourceLocationKey::Generated => {}
+        }
+    }
+
+    let mut summary = FixSummary::default();
+    for (path, diagnostics) in diagnostics_by_path {
+        let absolute_path = root_dir.join(path);
+        let mut content = fs::read_to_string(&absolute_path)?;
+        let features = extract_graphql::extract_for_path(&absolute_path, &content);
+
+        let mut edits = Vec::new();
+        for (diagnostic, source_location) in diagnostics {
+            let location = diagnostic.location();
+            let text_source = match text_source_for_location(&content, &features, source_location) {
+                Some(text_source) => text_source,
+                None => continue,
+            };
+            let text_offset =
+                match byte_offset(&content, text_source.line_index, text_source.column_index) {
+                    Some(offset) => offset,
+                    None => continue,
+                };
+            let fix = diagnostic.safe_fix().unwrap();
+            if let Some(replacements) = resolve_fix(&text_source.text, *location.span(), fix) {
+                let diagnostic_offset = if source_location == location.source_location() {
+                    text_offset + location.span().start as usize
+                } else {
+                    text_offset + replacements[0].0.start as usize
+                };
+                edits.push(Edit {
+                    start: text_offset + replacements[0].0.start as usize,
+                    end: text_offset + replacements[replacements.len() - 1].0.end as usize,
+                    replacements: replacements
+                        .into_iter()
+                        .map(|(span, replacement)| {
+                            (
+                                text_offset + span.start as usize,
+                                text_offset + span.end as usize,
+                                replacement,
+                            )
+                        })
+                        .collect(),
+                    applied_fix: AppliedFix {
+                        path: PathBuf::from(path),
+                        line: content[..diagnostic_offset].matches('\n').count() + 1,
+                        title: fix.title(),
+                        message: first_line(&diagnostic.message().to_string()),
+                    },
+                });
+            }
+        }
+        if edits.is_empty() {
+            continue;
+        }
+
+        // Apply the edits from the end of the file, so that the offsets of
+        // the remaining ones stay valid.
+        edits.sort_by(|a, b| b.start.cmp(&a.start).then(b.end.cmp(&a.end)));
+        let mut applied = Vec::new();
+        let mut applied_start = content.len();
+        for edit in edits {
+            if edit.end > applied_start {
+                continue;
+            }
+            for (start, end, replacement) in edit.replacements.iter().rev() {
+                content.replace_range(*start..*end, replacement);
+            }
+            applied_start = edit.start;
+            applied.push(edit.applied_fix);
+        }
+        fs::write(&absolute_path, content)?;
+        applied.reverse();
+        summary.applied.extend(applied);
+    }
+    Ok(summary)
+}
+
+/// The GraphQL text of `source_location` in the file `content`, with its
+/// position in the file. `features` are the sources extracted from the file.
+pub(crate) fn text_source_for_location(
+    content: &str,
+    features: &[JavaScriptSourceFeature],
+    source_location: SourceLocationKey,
+) -> Option<TextSource> {
+    match source_location {
+        SourceLocationKey::Embedded { index, .. } => match features.get(usize::from(index)) {
+            Some(JavaScriptSourceFeature::GraphQL(source)) => Some(source.text_source().clone()),
+            _ => None,
+        },
+        _ => Some(TextSource::from_whole_document(content.to_string())),
+    }
+}
+
+/// The replacements of a fix, applied together. `start` and `end` are the
+/// bounds of all the replacements.
+struct Edit {
+    start: usize,
+    end: usize,
+    replacements: Vec<(usize, usize, String)>,
+    applied_fix: AppliedFix,
+}
+
+/// The byte offset of the 0-based `line_index` and `column_index` of
+/// `content`, counted in characters with the line terminators of the
+/// extraction of GraphQL sources.
+pub(crate) fn byte_offset(content: &str, line_index: usize, column_index: usize) -> Option<usize> {
+    let (mut line, mut column) = (0, 0);
+    let mut chars = content.char_indices().peekable();
+    loop {
+        if line == line_index && column == column_index {
+            return Some(chars.peek().map_or(content.len(), |(offset, _)| *offset));
+        }
+        match chars.next()? {
+            (_, '\r') if matches!(chars.peek(), Some((_, '\n'))) => {}
+            (_, '\n' | '\r' | '\u{2028}' | '\u{2029}') => {
+                line += 1;
+                column = 0;
+            }
+            _ => column += 1,
+        }
+    }
+}
+
+/// Messages may end with a link to the error reference on another line.
+fn first_line(message: &str) -> String {
+    message.lines().next().unwrap_or_default().to_string()
+}
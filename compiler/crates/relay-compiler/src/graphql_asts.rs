@@ -10,11 +10,14 @@ use std::path::Path;
 use std::path::PathBuf;
 
 use common::Diagnostic;
+use common::DiagnosticsResult;
 use common::SourceLocationKey;
 use fnv::FnvHashMap;
 use graphql_syntax::ExecutableDefinition;
+use graphql_syntax::ExecutableDocument;
 use intern::string_key::StringKey;
 use intern::string_key::StringKeySet;
+use relay_transforms::rename_experiment_variant;
 
 use crate::compiler_state::GraphQLSources;
 use crate::compiler_state::ProjectName;
@@ -29,6 +32,9 @@ pub struct GraphQLAsts {
     pub pending_definition_names: StringKeySet,
     /// Names of fragments and operations that are deleted
     pub removed_definition_names: Vec<StringKey>,
+    /// Files that are deleted or no longer contain fragments and operations,
+    /// whose artifacts are stale even if their definitions moved elsewhere
+    pub removed_source_files: Vec<PathBuf>,
 }
 
 impl GraphQLAsts {
@@ -43,6 +49,10 @@ impl GraphQLAsts {
         self.asts.values().flatten().cloned().collect()
     }
 
+    pub fn executable_definitions(&self) -> impl Iterator<Item = &ExecutableDefinition> {
+        self.asts.values().flatten()
+    }
+
     pub fn from_graphql_sources_map(
         graphql_sources_map: &FnvHashMap<ProjectName, GraphQLSources>,
         dirty_definitions_map: &FnvHashMap<ProjectName, Vec<StringKey>>,
@@ -71,6 +81,7 @@ impl GraphQLAsts {
         let mut asts: FnvHashMap<PathBuf, Vec<ExecutableDefinition>> = Default::default();
         let mut pending_definition_names: StringKeySet = Default::default();
         let mut removed_definition_names = Vec::new();
+        let mut removed_source_files = Vec::new();
 
         if let Some(dirty_definitions) = dirty_definitions {
             pending_definition_names.extend(dirty_definitions);
@@ -88,10 +99,7 @@ impl GraphQLAsts {
             {
                 let source_location =
                     SourceLocationKey::embedded(&file_name.to_string_lossy(), *index);
-                match graphql_syntax::parse_executable(
-                    &graphql_source.text_source().text,
-                    source_location,
-                ) {
+                match parse_executable(&graphql_source.text_source().text, source_location) {
                     Ok(document) => {
                         for def in &document.definitions {
                             if let Some(name) = def.name() {
@@ -120,10 +128,9 @@ impl GraphQLAsts {
                     // TODO: parse name instead of the whole graphql text
                     let source_location =
                         SourceLocationKey::embedded(&file_name.to_string_lossy(), *index);
-                    if let Ok(document) = graphql_syntax::parse_executable(
-                        &graphql_source.text_source().text,
-                        source_location,
-                    ) {
+                    if let Ok(document) =
+                        parse_executable(&graphql_source.text_source().text, source_location)
+                    {
                         for def in document.definitions {
                             let name = def.name();
                             if let Some(def_name) = name {
@@ -135,6 +142,9 @@ impl GraphQLAsts {
                     }
                 }
             }
+            if definitions_for_file.is_empty() {
+                removed_source_files.push(file_name.clone());
+            }
             asts.insert(file_name.clone(), definitions_for_file);
         }
 
@@ -154,10 +164,7 @@ impl GraphQLAsts {
             {
                 let source_location =
                     SourceLocationKey::embedded(&file_name.to_string_lossy(), *index);
-                match graphql_syntax::parse_executable(
-                    &graphql_source.text_source().text,
-                    source_location,
-                ) {
+                match parse_executable(&graphql_source.text_source().text, source_location) {
                     Ok(document) => {
                         definitions_for_file.extend(document.definitions);
                     }
@@ -180,6 +187,7 @@ impl GraphQLAsts {
                 asts,
                 pending_definition_names,
                 removed_definition_names,
+                removed_source_files,
             })
         } else {
             Err(Error::DiagnosticsError {
@@ -188,3 +196,16 @@ impl GraphQLAsts {
         }
     }
 }
+
+/// Parses the document, with the operations of experiment variants renamed
+/// after their variant so that they don't conflict with each other.
+fn parse_executable(
+    source: &str,
+    source_location: SourceLocationKey,
+) -> DiagnosticsResult<ExecutableDocument> {
+    let mut document = graphql_syntax::parse_executable(source, source_location)?;
+    for definition in &mut document.definitions {
+        rename_experiment_variant(definition);
+    }
+    Ok(document)
+}
@@ -0,0 +1,366 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Maps the projects of a graphql-config file (`.graphqlrc`, `.graphqlrc.yml`,
+//! ...) onto a Relay config, for the editors and the compiler to share their
+//! configuration:
+//!
+//! ```yaml
+//! schema: ./schema.graphql
+//! documents: ./src/**/*.tsx
+//! extensions:
+//!   relay:
+//!     language: typescript
+//! ```
+//!
+//! The `schema` is the schema of the project, the base directories of the
+//! `documents` globs are its sources, and the `relay` extension holds the
+//! other options of the Relay config, which take precedence over the mapped
+//! ones. A file with `projects` becomes a multi-project config, where the
+//! `relay` extension at the top level holds the options of the config and the
+//! ones of the projects hold the options of each project.
+
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde_json::Map;
+use serde_json::Value;
+
+/// A graphql-config file, with either a single project at the top level or
+/// several named `projects`.
+#[derive(Debug, Deserialize)]
+pub struct GraphQLConfigFile {
+    #[serde(flatten)]
+    project: GraphQLConfigProject,
+
+    #[serde(default)]
+    projects: IndexMap<String, GraphQLConfigProject>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct GraphQLConfigProject {
+    /// A path or a list of paths, globs or URLs.
+    #[serde(default)]
+    schema: Option<Value>,
+
+    /// A glob or a list of globs.
+    #[serde(default)]
+    documents: Option<Value>,
+
+    /// A glob or a list of globs.
+    #[serde(default)]
+    exclude: Option<Value>,
+
+    #[serde(default)]
+    extensions: Map<String, Value>,
+}
+
+impl GraphQLConfigFile {
+    /// The Relay config of the file, as the JSON value of a `relay.config.json`.
+    pub fn into_relay_config(self) -> Result<Value, String> {
+        if self.projects.is_empty() {
+            return single_project_config(self.project);
+        }
+
+        let mut config = relay_extension(&self.project.extensions)?;
+        insert_default(&mut config, "excludes", || {
+            strings(&self.project.exclude, "exclude").map(Value::from)
+        })?;
+        let mut sources = Map::new();
+        let mut projects = Map::new();
+        for (name, project) in self.projects {
+            for source in source_dirs(&project.documents, &name)? {
+                let source = source.display().to_string();
+                match sources.get_mut(&source) {
+                    Some(Value::Array(names)) => names.push(Value::from(name.clone())),
+                    Some(other) => {
+                        *other = Value::from(vec![other.take(), Value::from(name.clone())])
+                    }
+                    None => {
+                        sources.insert(source, Value::from(name.clone()));
+                    }
+                }
+            }
+            let mut project_config = relay_extension(&project.extensions)?;
+            insert_default(&mut project_config, "schema", || {
+                schema_path(&project.schema, &name)
+            })?;
+            projects.insert(name, Value::Object(project_config));
+        }
+        insert_default(&mut config, "sources", || Ok(Value::Object(sources)))?;
+        insert_default(&mut config, "projects", || Ok(Value::Object(projects)))?;
+        Ok(Value::Object(config))
+    }
+}
+
+fn single_project_config(project: GraphQLConfigProject) -> Result<Value, String> {
+    let mut config = relay_extension(&project.extensions)?;
+    insert_default(&mut config, "schema", || {
+        schema_path(&project.schema, "default")
+    })?;
+    insert_default(&mut config, "src", || {
+        // A single project has one source directory, the common one of all
+        // the documents.
+        let dirs = source_dirs(&project.documents, "default")?;
+        let mut src = dirs[0].clone();
+        for dir in &dirs[1..] {
+            while !dir.starts_with(&src) {
+                src.pop();
+            }
+        }
+        if src.as_os_str().is_empty() {
+            src.push(".");
+        }
+        Ok(Value::from(src.display().to_string()))
+    })?;
+    insert_default(&mut config, "excludes", || {
+        strings(&project.exclude, "exclude").map(Value::from)
+    })?;
+    Ok(Value::Object(config))
+}
+
+/// The options of the `relay` extension of a project.
+fn relay_extension(extensions: &Map<String, Value>) -> Result<Map<String, Value>, String> {
+    match extensions.get("relay") {
+        None => Ok(Map::new()),
+        Some(Value::Object(options)) => Ok(options.clone()),
+        Some(_) => Err("The `relay` extension must be an object.".to_string()),
+    }
+}
+
+/// Sets an option mapped from graphql-config, unless the `relay` extension
+/// sets it. Options without a value in graphql-config are left out.
+fn insert_default(
+    config: &mut Map<String, Value>,
+    key: &str,
+    value: impl FnOnce() -> Result<Value, String>,
+) -> Result<(), String> {
+    if !config.contains_key(key) {
+        let value = value()?;
+        if !is_empty(&value) {
+            config.insert(key.to_string(), value);
+        }
+    }
+    Ok(())
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Array(values) => values.is_empty(),
+        Value::Object(values) => values.is_empty(),
+        _ => false,
+    }
+}
+
+fn strings(value: &Option<Value>, field: &str) -> Result<Vec<String>, String> {
+    match value {
+        None => Ok(Vec::new()),
+        Some(Value::String(value)) => Ok(vec![value.clone()]),
+        Some(Value::Array(values)) => values
+            .iter()
+            .map(|value| match value {
+                Value::String(value) => Ok(value.clone()),
+                _ => Err(format!("Expected `{}` to be a list of strings.", field)),
+            })
+            .collect(),
+        Some(_) => Err(format!(
+            "Expected `{}` to be a string or a list of strings.",
+            field
+        )),
+    }
+}
+
+/// The schema of a project, which Relay reads from a single local SDL file.
+fn schema_path(schema: &Option<Value>, project: &str) -> Result<Value, String> {
+    let schemas = strings(schema, "schema").map_err(|_| {
+        format!(
+            "The schema of the project `{}` must be a local file.",
+            project
+        )
+    })?;
+    match schemas.as_slice() {
+        [] => Ok(Value::Null),
+        [schema] if schema.starts_with("http://") || schema.starts_with("https://") => {
+            Err(format!(
+                "The schema of the project `{}` is the URL `{}`, but Relay only reads the schema from a local SDL file.",
+                project, schema
+            ))
+        }
+        [schema] if is_glob(schema) => Err(format!(
+            "The schema of the project `{}` is the glob `{}`, but Relay only reads the schema from a single file.",
+            project, schema
+        )),
+        [schema] => Ok(Value::from(schema.as_str())),
+        _ => Err(format!(
+            "The project `{}` has several schemas, but Relay only reads the schema from a single file.",
+            project
+        )),
+    }
+}
+
+/// The directories of the sources of a project, the base directories of its
+/// `documents` globs.
+fn source_dirs(documents: &Option<Value>, project: &str) -> Result<Vec<PathBuf>, String> {
+    let mut dirs: Vec<PathBuf> = Vec::new();
+    for document in strings(documents, "documents")? {
+        let dir = glob_base_dir(&document);
+        if !dirs.contains(&dir) {
+            dirs.push(dir);
+        }
+    }
+    if dirs.is_empty() {
+        return Err(format!(
+            "The project `{}` has no `documents` for the sources of Relay.",
+            project
+        ));
+    }
+    Ok(dirs)
+}
+
+fn is_glob(path: &str) -> bool {
+    path.contains(|c| matches!(c, '*' | '?' | '[' | '{'))
+}
+
+/// The longest path of a glob without wildcards, e.g. `src/components` for
+/// `./src/components/**/*.tsx`.
+fn glob_base_dir(glob: &str) -> PathBuf {
+    let mut dir = PathBuf::new();
+    for component in Path::new(glob).components() {
+        if component == Component::CurDir {
+            continue;
+        }
+        if is_glob(&component.as_os_str().to_string_lossy()) {
+            break;
+        }
+        dir.push(component);
+    }
+    dir
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn parse(config: &str) -> Result<Value, String> {
+        serde_yaml::from_str::<GraphQLConfigFile>(config)
+            .unwrap()
+            .into_relay_config()
+    }
+
+    #[test]
+    fn test_single_project() {
+        assert_eq!(
+            parse(
+                r#"
+schema: ./schema.graphql
+documents:
+  - ./src/components/**/*.tsx
+  - ./src/pages/*.tsx
+exclude: "**/__tests__/**"
+extensions:
+  relay:
+    language: typescript
+"#
+            ),
+            Ok(json!({
+                "language": "typescript",
+                "schema": "./schema.graphql",
+                "src": "src",
+                "excludes": ["**/__tests__/**"],
+            }))
+        );
+    }
+
+    #[test]
+    fn test_relay_extension_takes_precedence() {
+        assert_eq!(
+            parse(
+                r#"
+schema: ./schema.graphql
+documents: ./src/**/*.js
+extensions:
+  relay:
+    src: ./app
+    language: flow
+"#
+            ),
+            Ok(json!({
+                "src": "./app",
+                "language": "flow",
+                "schema": "./schema.graphql",
+            }))
+        );
+    }
+
+    #[test]
+    fn test_multi_project() {
+        assert_eq!(
+            parse(
+                r#"
+extensions:
+  relay:
+    featureFlags: {}
+projects:
+  web:
+    schema: schema/web.graphql
+    documents: web/**/*.tsx
+    extensions:
+      relay:
+        language: typescript
+  shared:
+    schema: schema/web.graphql
+    documents: [shared/**/*.tsx, web/shared/**/*.tsx]
+    extensions:
+      relay:
+        language: typescript
+        output: __generated__/shared
+"#
+            ),
+            Ok(json!({
+                "featureFlags": {},
+                "sources": {
+                    "web": "web",
+                    "shared": "shared",
+                    "web/shared": "shared",
+                },
+                "projects": {
+                    "web": {"language": "typescript", "schema": "schema/web.graphql"},
+                    "shared": {
+                        "language": "typescript",
+                        "output": "__generated__/shared",
+                        "schema": "schema/web.graphql",
+                    },
+                },
+            }))
+        );
+    }
+
+    #[test]
+    fn test_unsupported_schema() {
+        assert!(
+            parse("schema: https://example.com/graphql\ndocuments: src/**/*.js")
+                .unwrap_err()
+                .contains("URL")
+        );
+        assert!(
+            parse("schema: [a.graphql, b.graphql]\ndocuments: src/**/*.js")
+                .unwrap_err()
+                .contains("several schemas")
+        );
+        assert!(
+            parse("schema: schema.graphql")
+                .unwrap_err()
+                .contains("no `documents`")
+        );
+    }
+}
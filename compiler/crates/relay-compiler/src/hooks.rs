@@ -0,0 +1,283 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Shell commands of the `hooks` config, run after the builds with a JSON
+//! payload on stdin:
+//!
+//! ```json
+//! {
+//!   "event": "buildSuccess",
+//!   "rootDir": "/path/to/root",
+//!   "writtenArtifacts": ["src/__generated__/Foo_user.graphql.ts"],
+//!   "removedArtifacts": [],
+//!   "error": null
+//! }
+//! ```
+//!
+//! The paths of the artifacts are relative to the root directory, and `error`
+//! is the message of the failure for `buildFailure`.
+
+use std::io::Write;
+use std::mem;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Stdio;
+use std::sync::Mutex;
+use std::thread;
+
+use common::Diagnostic;
+use log::debug;
+use log::warn;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+use serde_json::Value;
+
+use crate::errors::Error;
+use crate::errors::Result;
+
+/// The `hooks` section of the config file.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct HooksConfig {
+    /// Runs after each successful build.
+    #[serde(default)]
+    pub on_build_success: Option<String>,
+
+    /// Runs after each failed build, but not after cancelled ones.
+    #[serde(default)]
+    pub on_build_failure: Option<String>,
+
+    /// Runs after each build that wrote or removed artifacts.
+    #[serde(default)]
+    pub on_artifacts_changed: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct ArtifactChanges {
+    written: Vec<PathBuf>,
+    removed: Vec<PathBuf>,
+}
+
+/// Runs the commands of a `HooksConfig`, with the artifacts changed since
+/// the last build that was not cancelled.
+#[derive(Debug, Default)]
+pub struct Hooks {
+    config: HooksConfig,
+    changes: Mutex<ArtifactChanges>,
+}
+
+impl Hooks {
+    pub fn new(config: HooksConfig) -> Self {
+        Self {
+            config,
+            changes: Default::default(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.config == HooksConfig::default()
+    }
+
+    /// Records an artifact written by a build, relative to the root directory.
+    pub fn record_written(&self, path: &Path) {
+        if !self.is_empty() {
+            self.changes.lock().unwrap().written.push(path.to_owned());
+        }
+    }
+
+    /// Records an artifact removed by a build, relative to the root directory.
+    pub fn record_removed(&self, path: &Path) {
+        if !self.is_empty() {
+            self.changes.lock().unwrap().removed.push(path.to_owned());
+        }
+    }
+
+    /// Runs the commands for the result of a build, and waits for them.
+    /// Failures of the commands are logged, without failing the build.
+    pub fn run(&self, root_dir: &Path, result: &Result<Vec<Diagnostic>>) {
+        for (command, payload) in self.commands_for_result(root_dir, result) {
+            run_command(command, root_dir, &payload);
+        }
+    }
+
+    fn commands_for_result(
+        &self,
+        root_dir: &Path,
+        result: &Result<Vec<Diagnostic>>,
+    ) -> Vec<(&str, Value)> {
+        // The artifacts written by a cancelled build are reported with the
+        // next one.
+        if self.is_empty() || matches!(result, Err(Error::Cancelled)) {
+            return Vec::new();
+        }
+        let mut changes = mem::take(&mut *self.changes.lock().unwrap());
+        changes.written.sort();
+        changes.written.dedup();
+        changes.removed.sort();
+        changes.removed.dedup();
+        let payload = |event: &str, error: Option<String>| {
+            json!({
+                "event": event,
+                "rootDir": root_dir,
+                "writtenArtifacts": changes.written,
+                "removedArtifacts": changes.removed,
+                "error": error,
+            })
+        };
+
+        let mut commands = Vec::new();
+        match result {
+            Ok(_) => {
+                if let Some(command) = &self.config.on_build_success {
+                    commands.push((command.as_str(), payload("buildSuccess", None)));
+                }
+            }
+            Err(error) => {
+                if let Some(command) = &self.config.on_build_failure {
+                    commands.push((
+                        command.as_str(),
+                        payload("buildFailure", Some(error.to_string())),
+                    ));
+                }
+            }
+        }
+        if !changes.written.is_empty() || !changes.removed.is_empty() {
+            if let Some(command) = &self.config.on_artifacts_changed {
+                commands.push((command.as_str(), payload("artifactsChanged", None)));
+            }
+        }
+        commands
+    }
+}
+
+fn shell(command: &str) -> Command {
+    if cfg!(windows) {
+        let mut shell = Command::new("cmd");
+        shell.arg("/C").arg(command);
+        shell
+    } else {
+        let mut shell = Command::new("sh");
+        shell.arg("-c").arg(command);
+        shell
+    }
+}
+
+fn run_command(command: &str, root_dir: &Path, payload: &Value) {
+    debug!("Running hook `{}`", command);
+    let mut child = match shell(command)
+        .current_dir(root_dir)
+        .stdin(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(err) => {
+            warn!("Unable to run hook `{}`: {}", command, err);
+            return;
+        }
+    };
+    // Writes the payload from another thread, for commands that don't read
+    // all their input before exiting.
+    let payload = payload.to_string();
+    let writer = child.stdin.take().map(|mut stdin| {
+        thread::spawn(move || {
+            let _ = stdin.write_all(payload.as_bytes());
+        })
+    });
+    match child.wait() {
+        Ok(status) if status.success() => {}
+        Ok(status) => warn!("Hook `{}` failed with {}", command, status),
+        Err(err) => warn!("Unable to wait for hook `{}`: {}", command, err),
+    }
+    if let Some(writer) = writer {
+        let _ = writer.join();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hooks() -> Hooks {
+        Hooks::new(HooksConfig {
+            on_build_success: Some("on-success".to_string()),
+            on_build_failure: Some("on-failure".to_string()),
+            on_artifacts_changed: Some("on-change".to_string()),
+        })
+    }
+
+    #[test]
+    fn test_success_with_changes() {
+        let hooks = hooks();
+        hooks.record_written(Path::new("b.graphql.js"));
+        hooks.record_written(Path::new("a.graphql.js"));
+        hooks.record_removed(Path::new("c.graphql.js"));
+
+        let commands = hooks.commands_for_result(Path::new("/root"), &Ok(Vec::new()));
+        let names: Vec<_> = commands.iter().map(|(command, _)| *command).collect();
+        assert_eq!(names, vec!["on-success", "on-change"]);
+        assert_eq!(
+            commands[1].1,
+            json!({
+                "event": "artifactsChanged",
+                "rootDir": "/root",
+                "writtenArtifacts": ["a.graphql.js", "b.graphql.js"],
+                "removedArtifacts": ["c.graphql.js"],
+                "error": null,
+            })
+        );
+
+        // The changes are reported once
+        let commands = hooks.commands_for_result(Path::new("/root"), &Ok(Vec::new()));
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].1["writtenArtifacts"], json!([]));
+    }
+
+    #[test]
+    fn test_failure() {
+        let hooks = hooks();
+        let commands = hooks.commands_for_result(
+            Path::new("/root"),
+            &Err(Error::ConfigError {
+                details: "invalid".to_string(),
+            }),
+        );
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].0, "on-failure");
+        assert_eq!(commands[0].1["event"], "buildFailure");
+        assert!(commands[0].1["error"].as_str().unwrap().contains("invalid"));
+    }
+
+    #[test]
+    fn test_cancelled_build_keeps_changes() {
+        let hooks = hooks();
+        hooks.record_written(Path::new("a.graphql.js"));
+        assert!(
+            hooks
+                .commands_for_result(Path::new("/root"), &Err(Error::Cancelled))
+                .is_empty()
+        );
+        let commands = hooks.commands_for_result(Path::new("/root"), &Ok(Vec::new()));
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands[1].1["writtenArtifacts"], json!(["a.graphql.js"]));
+    }
+
+    #[test]
+    fn test_parse_config() {
+        let config: HooksConfig =
+            serde_json::from_str(r#"{"onArtifactsChanged": "tsc --noEmit"}"#).unwrap();
+        assert_eq!(
+            config,
+            HooksConfig {
+                on_artifacts_changed: Some("tsc --noEmit".to_string()),
+                ..Default::default()
+            }
+        );
+        assert!(serde_json::from_str::<HooksConfig>(r#"{"onBuild": "true"}"#).is_err());
+    }
+}
@@ -0,0 +1,263 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The interactive watch mode (`--interactive`): commands typed in the
+//! terminal force a full rebuild, toggle the debug logs, filter the errors by
+//! project, or toggle the clearing of the screen between builds. The
+//! commands are read by lines, so that the terminal stays in its normal mode.
+
+use std::io;
+use std::io::BufRead;
+use std::io::Write;
+use std::str::FromStr;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::RwLock;
+use std::thread;
+
+use common::Diagnostic;
+use intern::string_key::Intern;
+use log::error;
+use log::info;
+use log::warn;
+use log::LevelFilter;
+use tokio::sync::Notify;
+
+use crate::compiler_state::ProjectName;
+use crate::errors::BuildProjectError;
+use crate::errors::Error;
+use crate::status_reporter::ConsoleStatusReporter;
+use crate::status_reporter::StatusReporter;
+
+const HELP: &str = "Watch commands, followed by Enter:
+  r           rebuild all the projects
+  v           toggle the debug logs
+  p <project> only print the errors of this project, `p` prints them all
+  c           toggle the clearing of the screen between builds
+  h           print this help";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WatchCommand {
+    Rebuild,
+    ToggleVerbose,
+    FilterProject(Option<ProjectName>),
+    ToggleClearScreen,
+    Help,
+}
+
+impl FromStr for WatchCommand {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut words = line.split_whitespace();
+        let command = match (words.next(), words.next()) {
+            (Some("r"), None) => WatchCommand::Rebuild,
+            (Some("v"), None) => WatchCommand::ToggleVerbose,
+            (Some("p"), project_name) => {
+                WatchCommand::FilterProject(project_name.map(|project_name| project_name.intern()))
+            }
+            (Some("c"), None) => WatchCommand::ToggleClearScreen,
+            (Some("h"), None) => WatchCommand::Help,
+            _ => return Err(format!("Unknown command `{}`.", line.trim())),
+        };
+        if words.next().is_some() {
+            return Err(format!("Unknown command `{}`.", line.trim()));
+        }
+        Ok(command)
+    }
+}
+
+/// The state of the interactive watch mode, shared by the command reader,
+/// the watch loop and the `InteractiveStatusReporter`.
+pub struct InteractiveWatch {
+    projects: Vec<ProjectName>,
+    rebuild: Notify,
+    /// The level of the logs when the debug logs are off.
+    log_level: LevelFilter,
+    verbose: AtomicBool,
+    clear_screen: AtomicBool,
+    project_filter: RwLock<Option<ProjectName>>,
+}
+
+impl InteractiveWatch {
+    /// The logger must accept the debug logs, `log::max_level` is lowered to
+    /// `log_level` until they are toggled on.
+    pub fn new(projects: Vec<ProjectName>, log_level: LevelFilter) -> Self {
+        log::set_max_level(log_level);
+        Self {
+            projects,
+            rebuild: Notify::new(),
+            log_level,
+            verbose: AtomicBool::new(false),
+            clear_screen: AtomicBool::new(true),
+            project_filter: RwLock::new(None),
+        }
+    }
+
+    /// Reads the commands from the standard input on a new thread.
+    pub(crate) fn spawn_command_reader(self: &Arc<Self>) {
+        let interactive = Arc::clone(self);
+        thread::spawn(move || {
+            info!("{}", HELP);
+            for line in io::stdin().lock().lines() {
+                match line {
+                    Ok(line) if line.trim().is_empty() => {}
+                    Ok(line) => match line.parse() {
+                        Ok(command) => interactive.run(command),
+                        Err(err) => warn!("{} Type `h` for the commands.", err),
+                    },
+                    Err(err) => {
+                        error!("Unable to read the watch commands: {}", err);
+                        break;
+                    }
+                }
+            }
+        });
+    }
+
+    pub fn run(&self, command: WatchCommand) {
+        match command {
+            WatchCommand::Rebuild => {
+                info!("Rebuilding all the projects...");
+                self.rebuild.notify_one();
+            }
+            WatchCommand::ToggleVerbose => {
+                let verbose = !self.verbose.fetch_xor(true, Ordering::SeqCst);
+                log::set_max_level(if verbose {
+                    LevelFilter::Debug
+                } else {
+                    self.log_level
+                });
+                info!("Debug logs {}.", if verbose { "on" } else { "off" });
+            }
+            WatchCommand::FilterProject(Some(project_name))
+                if !self.projects.contains(&project_name) =>
+            {
+                warn!("There is no project `{}`.", project_name);
+            }
+            WatchCommand::FilterProject(project_name) => {
+                match project_name {
+                    Some(project_name) => info!("Only printing the errors of `{}`.", project_name),
+                    None => info!("Printing the errors of all the projects."),
+                }
+                *self.project_filter.write().unwrap() = project_name;
+            }
+            WatchCommand::ToggleClearScreen => {
+                let clear_screen = !self.clear_screen.fetch_xor(true, Ordering::SeqCst);
+                info!(
+                    "Clearing the screen between builds {}.",
+                    if clear_screen { "on" } else { "off" }
+                );
+            }
+            WatchCommand::Help => info!("{}", HELP),
+        }
+    }
+
+    /// Waits for the `r` command.
+    pub(crate) async fn rebuild_requested(&self) {
+        self.rebuild.notified().await
+    }
+
+    pub fn project_filter(&self) -> Option<ProjectName> {
+        *self.project_filter.read().unwrap()
+    }
+
+    pub fn clears_screen(&self) -> bool {
+        self.clear_screen.load(Ordering::SeqCst)
+    }
+}
+
+/// Prints the builds like the `ConsoleStatusReporter`, after clearing the
+/// screen, and only the errors of the filtered project.
+pub struct InteractiveStatusReporter {
+    console: ConsoleStatusReporter,
+    interactive: Arc<InteractiveWatch>,
+}
+
+impl InteractiveStatusReporter {
+    pub fn new(console: ConsoleStatusReporter, interactive: Arc<InteractiveWatch>) -> Self {
+        Self {
+            console,
+            interactive,
+        }
+    }
+}
+
+impl StatusReporter for InteractiveStatusReporter {
+    fn build_starts(&self) {
+        if self.interactive.clears_screen() {
+            let mut stdout = io::stdout();
+            let _ = write!(stdout, "\x1B[2J\x1B[3J\x1B[H");
+            let _ = stdout.flush();
+        }
+        self.console.build_starts();
+    }
+
+    fn build_completes(&self, diagnostics: &[Diagnostic]) {
+        self.console.build_completes(diagnostics);
+    }
+
+    fn build_errors(&self, error: &Error) {
+        match (error, self.interactive.project_filter()) {
+            (Error::BuildProjectsErrors { errors }, Some(filtered_project)) => {
+                let (shown, hidden): (Vec<_>, Vec<_>) =
+                    errors.iter().partition(|error| match error {
+                        BuildProjectError::ValidationErrors { project_name, .. }
+                        | BuildProjectError::PersistErrors { project_name, .. } => {
+                            *project_name == filtered_project
+                        }
+                        _ => true,
+                    });
+                for error in shown {
+                    self.console.print_project_error(error);
+                }
+                if !hidden.is_empty() {
+                    info!(
+                        "Hid the errors of {} other project(s), type `p` to print them.",
+                        hidden.len()
+                    );
+                }
+                error!("Compilation failed.");
+            }
+            _ => self.console.build_errors(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!("r".parse(), Ok(WatchCommand::Rebuild));
+        assert_eq!(" v ".parse(), Ok(WatchCommand::ToggleVerbose));
+        assert_eq!("p".parse(), Ok(WatchCommand::FilterProject(None)));
+        assert_eq!(
+            "p web".parse(),
+            Ok(WatchCommand::FilterProject(Some("web".intern())))
+        );
+        assert!("p web mobile".parse::<WatchCommand>().is_err());
+        assert!("rebuild".parse::<WatchCommand>().is_err());
+    }
+
+    #[test]
+    fn test_filter_project() {
+        let interactive = InteractiveWatch::new(vec!["web".intern()], log::max_level());
+        interactive.run(WatchCommand::FilterProject(Some("web".intern())));
+        assert_eq!(interactive.project_filter(), Some("web".intern()));
+        interactive.run(WatchCommand::FilterProject(Some("mobile".intern())));
+        assert_eq!(interactive.project_filter(), Some("web".intern()));
+        interactive.run(WatchCommand::FilterProject(None));
+        assert_eq!(interactive.project_filter(), None);
+
+        assert!(interactive.clears_screen());
+        interactive.run(WatchCommand::ToggleClearScreen);
+        assert!(!interactive.clears_screen());
+    }
+}
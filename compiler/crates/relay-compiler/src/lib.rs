@@ -9,20 +9,42 @@
 #![deny(rust_2018_idioms)]
 #![deny(clippy::all)]
 
+pub mod artifact_cache;
 pub mod artifact_content;
+pub mod artifact_diff;
+pub mod artifact_signing;
 mod artifact_map;
+pub mod baseline;
+pub mod batch;
+pub mod bench;
+pub mod build_manifest;
 pub mod build_project;
+pub mod codemod;
 pub mod compiler;
+pub mod compiler_events;
 pub mod compiler_state;
 pub mod config;
 mod docblocks;
+pub mod doctor;
 pub mod errors;
+pub mod explain;
 mod file_source;
+pub mod fix;
 mod graphql_asts;
+pub mod graphql_config;
+pub mod hooks;
+pub mod interactive;
+pub mod metrics;
+pub mod on_demand;
 mod operation_persister;
 mod red_to_green;
+pub mod resolver_graph;
 pub mod saved_state;
+pub mod shard;
 pub mod status_reporter;
+pub mod suppressions;
+pub mod unique_definition_names;
+pub mod watch_config;
 
 pub use build_project::add_to_mercurial;
 pub use build_project::artifact_writer::ArtifactDifferenceWriter;
@@ -44,8 +66,10 @@ pub use build_project::Artifact;
 pub use build_project::ArtifactContent;
 pub use build_project::ArtifactGeneratedTypes;
 pub use build_project::BuildProjectFailure;
+pub use build_project::CustomValidation;
 pub use build_project::SourceHashes;
 pub use config::ConfigFileProject;
+pub use config::DebugTransformsConfig;
 pub use config::FileSourceKind;
 pub use config::LocalPersistConfig;
 pub use config::OperationPersister;
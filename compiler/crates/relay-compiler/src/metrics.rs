@@ -0,0 +1,409 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Prometheus metrics of the watch mode, served on
+//! `GET http://<host>:<port>/metrics` in the text exposition format, to
+//! monitor the health of long-running compilers.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use common::Diagnostic;
+use common::DiagnosticSeverity;
+use common::PerfLogEvent;
+use common::PerfLogger;
+use log::debug;
+use log::info;
+use tokio::io::AsyncBufReadExt;
+use tokio::io::AsyncWriteExt;
+use tokio::io::BufReader;
+use tokio::net::TcpListener;
+use tokio::net::TcpStream;
+
+use crate::errors::BuildProjectError;
+use crate::errors::Error;
+use crate::errors::Result;
+
+/// The perf log number with the count of artifacts of a project.
+const GENERATED_ARTIFACTS: &str = "generated_artifacts";
+
+#[derive(Default)]
+struct PhaseDurations {
+    count: u64,
+    total: Duration,
+}
+
+#[derive(Default)]
+struct MetricsState {
+    /// The durations of the perf log timers, by name.
+    phases: BTreeMap<&'static str, PhaseDurations>,
+    /// The artifacts generated by the last build of each project.
+    generated_artifacts: BTreeMap<String, usize>,
+    /// The number of builds, by result.
+    builds: BTreeMap<&'static str, u64>,
+    /// The errors and warnings of the last build that was not cancelled.
+    errors: usize,
+    warnings: usize,
+}
+
+/// The metrics of the builds, recorded by a `MetricsPerfLogger` and
+/// `record_build`.
+#[derive(Default)]
+pub struct Metrics {
+    state: Mutex<MetricsState>,
+}
+
+impl Metrics {
+    fn record_duration(&self, phase: &'static str, duration: Duration) {
+        let mut state = self.state.lock().unwrap();
+        let durations = state.phases.entry(phase).or_default();
+        durations.count += 1;
+        durations.total += duration;
+    }
+
+    fn record_generated_artifacts(&self, project: String, count: usize) {
+        self.state
+            .lock()
+            .unwrap()
+            .generated_artifacts
+            .insert(project, count);
+    }
+
+    /// Records the result of a build.
+    pub fn record_build(&self, result: &Result<Vec<Diagnostic>>) {
+        let mut state = self.state.lock().unwrap();
+        let result_name = match result {
+            Ok(diagnostics) => {
+                state.errors = diagnostics
+                    .iter()
+                    .filter(|diagnostic| diagnostic.severity() == DiagnosticSeverity::ERROR)
+                    .count();
+                state.warnings = diagnostics.len() - state.errors;
+                "success"
+            }
+            Err(Error::Cancelled) => "cancelled",
+            Err(error) => {
+                state.errors = error_count(error);
+                state.warnings = 0;
+                "failure"
+            }
+        };
+        *state.builds.entry(result_name).or_default() += 1;
+    }
+
+    /// Prints the metrics in the text exposition format of Prometheus.
+    pub fn render(&self) -> String {
+        let state = self.state.lock().unwrap();
+        let mut output = String::new();
+
+        write_header(
+            &mut output,
+            "relay_compiler_phase_duration_seconds",
+            "summary",
+            "Time spent in each phase of the builds.",
+        );
+        for (phase, durations) in &state.phases {
+            writeln!(
+                output,
+                "relay_compiler_phase_duration_seconds_sum{{phase=\"{}\"}} {}",
+                phase,
+                durations.total.as_secs_f64()
+            )
+            .unwrap();
+            writeln!(
+                output,
+                "relay_compiler_phase_duration_seconds_count{{phase=\"{}\"}} {}",
+                phase, durations.count
+            )
+            .unwrap();
+        }
+
+        write_header(
+            &mut output,
+            "relay_compiler_builds_total",
+            "counter",
+            "Builds, by result.",
+        );
+        for result_name in ["success", "failure", "cancelled"] {
+            writeln!(
+                output,
+                "relay_compiler_builds_total{{result=\"{}\"}} {}",
+                result_name,
+                state.builds.get(result_name).copied().unwrap_or_default()
+            )
+            .unwrap();
+        }
+
+        write_header(
+            &mut output,
+            "relay_compiler_generated_artifacts",
+            "gauge",
+            "Artifacts generated by the last build of each project.",
+        );
+        for (project, count) in &state.generated_artifacts {
+            writeln!(
+                output,
+                "relay_compiler_generated_artifacts{{project=\"{}\"}} {}",
+                escape_label_value(project),
+                count
+            )
+            .unwrap();
+        }
+
+        write_header(
+            &mut output,
+            "relay_compiler_diagnostics",
+            "gauge",
+            "Diagnostics of the last build, by severity.",
+        );
+        writeln!(
+            output,
+            "relay_compiler_diagnostics{{severity=\"error\"}} {}",
+            state.errors
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "relay_compiler_diagnostics{{severity=\"warning\"}} {}",
+            state.warnings
+        )
+        .unwrap();
+
+        if let Some(resident_memory) = resident_memory_bytes() {
+            write_header(
+                &mut output,
+                "process_resident_memory_bytes",
+                "gauge",
+                "Resident memory size in bytes.",
+            );
+            writeln!(output, "process_resident_memory_bytes {}", resident_memory).unwrap();
+        }
+        output
+    }
+}
+
+fn write_header(output: &mut String, name: &str, kind: &str, help: &str) {
+    writeln!(output, "# HELP {} {}", name, help).unwrap();
+    writeln!(output, "# TYPE {} {}", name, kind).unwrap();
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// The number of errors of a failed build, counting the errors without
+/// diagnostics as one.
+pub(crate) fn error_count(error: &Error) -> usize {
+    match error {
+        Error::DiagnosticsError { errors } => errors.len(),
+        Error::BuildProjectsErrors { errors } => errors
+            .iter()
+            .map(|error| match error {
+                BuildProjectError::ValidationErrors { errors, .. } => errors.len(),
+                BuildProjectError::PersistErrors { errors, .. } => errors.len(),
+                _ => 1,
+            })
+            .sum(),
+        _ => 1,
+    }
+}
+
+/// Reads the resident memory of the process, only available on Linux.
+fn resident_memory_bytes() -> Option<u64> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    let kilobytes = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
+
+/// Records the timers and artifact counts of the perf log events in
+/// `Metrics`, and passes the events to another perf logger.
+pub struct MetricsPerfLogger<TPerfLogger> {
+    perf_logger: TPerfLogger,
+    metrics: Arc<Metrics>,
+}
+
+impl<TPerfLogger: PerfLogger> MetricsPerfLogger<TPerfLogger> {
+    pub fn new(perf_logger: TPerfLogger, metrics: Arc<Metrics>) -> Self {
+        Self {
+            perf_logger,
+            metrics,
+        }
+    }
+}
+
+impl<TPerfLogger: PerfLogger> PerfLogger for MetricsPerfLogger<TPerfLogger> {
+    type PerfLogEvent = MetricsPerfLogEvent<TPerfLogger::PerfLogEvent>;
+
+    fn create_event(&self, name: &'static str) -> Self::PerfLogEvent {
+        MetricsPerfLogEvent {
+            event: self.perf_logger.create_event(name),
+            metrics: Arc::clone(&self.metrics),
+            project: Default::default(),
+        }
+    }
+}
+
+pub struct MetricsPerfLogEvent<TPerfLogEvent> {
+    event: TPerfLogEvent,
+    metrics: Arc<Metrics>,
+    /// The `project` string of the event, labelling its artifact count.
+    project: Mutex<Option<String>>,
+}
+
+impl<TPerfLogEvent: PerfLogEvent> PerfLogEvent for MetricsPerfLogEvent<TPerfLogEvent> {
+    type Timer = (TPerfLogEvent::Timer, &'static str, Instant);
+
+    fn number(&self, name: &'static str, number: usize) {
+        if name == GENERATED_ARTIFACTS {
+            if let Some(project) = self.project.lock().unwrap().clone() {
+                self.metrics.record_generated_artifacts(project, number);
+            }
+        }
+        self.event.number(name, number);
+    }
+
+    fn string(&self, name: &'static str, value: String) {
+        if name == "project" {
+            *self.project.lock().unwrap() = Some(value.clone());
+        }
+        self.event.string(name, value);
+    }
+
+    fn start(&self, name: &'static str) -> Self::Timer {
+        (self.event.start(name), name, Instant::now())
+    }
+
+    fn stop(&self, timer: Self::Timer) {
+        let (timer, name, start) = timer;
+        self.metrics.record_duration(name, start.elapsed());
+        self.event.stop(timer);
+    }
+
+    fn complete(self) {
+        self.event.complete();
+    }
+}
+
+/// Answers the requests for the metrics on `port`, until the process exits.
+pub async fn serve_metrics(metrics: Arc<Metrics>, port: u16) -> io::Result<()> {
+    let listener = TcpListener::bind(("0.0.0.0", port)).await?;
+    info!("Serving metrics on http://0.0.0.0:{}/metrics", port);
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let metrics = Arc::clone(&metrics);
+        tokio::spawn(async move {
+            if let Err(err) = handle_connection(stream, &metrics).await {
+                debug!("Unable to answer a metrics request: {}", err);
+            }
+        });
+    }
+}
+
+async fn handle_connection(stream: TcpStream, metrics: &Metrics) -> io::Result<()> {
+    let mut stream = BufReader::new(stream);
+    let mut request_line = String::new();
+    stream.read_line(&mut request_line).await?;
+    // Skip the headers, requests have no body.
+    let mut header = String::new();
+    while stream.read_line(&mut header).await? > 0 && !header.trim_end().is_empty() {
+        header.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let (status, body) = match (parts.next(), parts.next()) {
+        (Some("GET"), Some("/metrics")) => ("200 OK", metrics.render()),
+        _ => (
+            "404 Not Found",
+            "Only `GET /metrics` is supported.\n".to_string(),
+        ),
+    };
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    let mut stream = stream.into_inner();
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Location;
+    use common::NoopPerfLogger;
+    use common::SourceLocationKey;
+    use common::Span;
+    use intern::string_key::Intern;
+
+    use super::*;
+
+    #[test]
+    fn test_records_perf_log_events() {
+        let metrics = Arc::new(Metrics::default());
+        let perf_logger = MetricsPerfLogger::new(NoopPerfLogger, Arc::clone(&metrics));
+        let event = perf_logger.create_event("build_project");
+        event.string("project", "web".to_string());
+        event.time("build_ir_time", || {});
+        event.time("build_ir_time", || {});
+        event.number(GENERATED_ARTIFACTS, 12);
+        event.complete();
+
+        let output = metrics.render();
+        assert!(
+            output.contains(
+                "relay_compiler_phase_duration_seconds_count{phase=\"build_ir_time\"} 2\n"
+            )
+        );
+        assert!(output.contains("relay_compiler_generated_artifacts{project=\"web\"} 12\n"));
+    }
+
+    #[test]
+    fn test_records_builds() {
+        let metrics = Metrics::default();
+        let location = Location::new(SourceLocationKey::standalone("a.graphql"), Span::empty());
+        metrics.record_build(&Ok(vec![Diagnostic::warning("unused", location, vec![])]));
+        metrics.record_build(&Err(Error::Cancelled));
+        metrics.record_build(&Err(Error::BuildProjectsErrors {
+            errors: vec![BuildProjectError::ValidationErrors {
+                errors: vec![
+                    Diagnostic::error("first", location),
+                    Diagnostic::error("second", location),
+                ],
+                project_name: "web".intern(),
+            }],
+        }));
+
+        let output = metrics.render();
+        assert!(output.contains("relay_compiler_builds_total{result=\"success\"} 1\n"));
+        assert!(output.contains("relay_compiler_builds_total{result=\"failure\"} 1\n"));
+        assert!(output.contains("relay_compiler_builds_total{result=\"cancelled\"} 1\n"));
+        assert!(output.contains("relay_compiler_diagnostics{severity=\"error\"} 2\n"));
+        assert!(output.contains("relay_compiler_diagnostics{severity=\"warning\"} 0\n"));
+    }
+
+    #[test]
+    fn test_escapes_label_values() {
+        assert_eq!(escape_label_value("a\"b\\c\n"), "a\\\"b\\\\c\\n");
+    }
+}
@@ -0,0 +1,412 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! A local HTTP endpoint of the watch mode, where bundlers wait until the
+//! artifacts of a file are up to date before reading them, instead of racing
+//! with the compiler while it writes them after a save:
+//!
+//! `GET http://127.0.0.1:<port>/ensure?file=<path>[&timeout=<ms>]`
+//!
+//! The request blocks until a build included the contents of the file on
+//! disk, and answers with `{"status": "ready"}`, or `{"status": "failed",
+//! "error": ...}` if that build failed.
+
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use fnv::FnvHashMap;
+use log::debug;
+use log::info;
+use serde_json::json;
+use tokio::io::AsyncBufReadExt;
+use tokio::io::AsyncWriteExt;
+use tokio::io::BufReader;
+use tokio::net::TcpListener;
+use tokio::net::TcpStream;
+use tokio::sync::watch;
+use tokio::time::timeout_at;
+use tokio::time::Instant;
+
+use crate::compiler_state::CompilerState;
+use crate::config::Config;
+use crate::errors::Error;
+use crate::errors::Result;
+use crate::file_source::extract_javascript_features;
+use crate::file_source::FileCategorizer;
+use crate::file_source::FileGroup;
+use crate::file_source::LocatedDocblockSource;
+use crate::file_source::LocatedGraphQLSource;
+
+/// How long a request waits for a build by default.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BuildOutcome {
+    Succeeded,
+    Failed(String),
+}
+
+/// Records which contents of the source files the builds of the watch mode
+/// included, for the requests of the endpoint to compare them with the files
+/// on disk.
+pub struct BuildTracker {
+    /// The hash of the sources of each file, as of the last build that
+    /// included it, with the outcome of that build. Files without sources are
+    /// missing.
+    files: Mutex<FnvHashMap<PathBuf, (u64, BuildOutcome)>>,
+    building: AtomicBool,
+    /// Incremented after each build, to wake up the waiting requests.
+    builds: watch::Sender<usize>,
+}
+
+impl Default for BuildTracker {
+    fn default() -> Self {
+        Self {
+            files: Default::default(),
+            building: AtomicBool::new(false),
+            builds: watch::channel(0).0,
+        }
+    }
+}
+
+impl BuildTracker {
+    /// Marks a build of the pending sources of `compiler_state` as started,
+    /// and returns the hashes of the files it includes.
+    pub fn start_build(&self, compiler_state: &CompilerState) -> Vec<(PathBuf, u64)> {
+        self.building.store(true, Ordering::SeqCst);
+        let mut files: FnvHashMap<&PathBuf, (&[LocatedGraphQLSource], &[LocatedDocblockSource])> =
+            Default::default();
+        for sources in compiler_state.graphql_sources.values() {
+            for (path, graphql_sources) in &sources.pending {
+                files.entry(path).or_default().0 = graphql_sources;
+            }
+        }
+        for sources in compiler_state.docblocks.values() {
+            for (path, docblock_sources) in &sources.pending {
+                files.entry(path).or_default().1 = docblock_sources;
+            }
+        }
+        files
+            .into_iter()
+            .map(|(path, (graphql_sources, docblock_sources))| {
+                (
+                    path.clone(),
+                    hash_sources(graphql_sources, docblock_sources),
+                )
+            })
+            .collect()
+    }
+
+    /// Records the outcome of the build of `files`, and wakes up the waiting
+    /// requests. The files of a cancelled build are built again by the next
+    /// one.
+    pub fn complete_build<T>(&self, files: Vec<(PathBuf, u64)>, result: &Result<T>) {
+        let outcome = match result {
+            Ok(_) => Some(BuildOutcome::Succeeded),
+            Err(Error::Cancelled) => None,
+            Err(error) => Some(BuildOutcome::Failed(error.to_string())),
+        };
+        if let Some(outcome) = outcome {
+            let mut recorded_files = self.files.lock().unwrap();
+            for (path, hash) in files {
+                recorded_files.insert(path, (hash, outcome.clone()));
+            }
+        }
+        self.building.store(false, Ordering::SeqCst);
+        self.builds.send_modify(|count| *count += 1);
+    }
+
+    /// Waits until a build included the current contents of the file at
+    /// `path`, relative to `root_dir`, and returns its outcome. Returns
+    /// `None` if no build did before `deadline`.
+    pub async fn wait_for_file(
+        &self,
+        root_dir: &Path,
+        path: &Path,
+        deadline: Instant,
+    ) -> Option<BuildOutcome> {
+        let mut builds = self.builds.subscribe();
+        loop {
+            if !self.building.load(Ordering::SeqCst) {
+                // A missing file has no sources.
+                let contents = fs::read_to_string(root_dir.join(path)).unwrap_or_default();
+                let features = extract_javascript_features(path, &contents);
+                let hash = hash_sources(&features.graphql_sources, &features.docblock_sources);
+                let built_file = self.files.lock().unwrap().get(path).cloned();
+                match built_file {
+                    Some((built_hash, outcome)) if built_hash == hash => {
+                        return Some(outcome);
+                    }
+                    None if hash == hash_sources(&[], &[]) => {
+                        return Some(BuildOutcome::Succeeded);
+                    }
+                    _ => {}
+                }
+            }
+            if !matches!(timeout_at(deadline, builds.changed()).await, Ok(Ok(()))) {
+                return None;
+            }
+        }
+    }
+}
+
+fn hash_sources(
+    graphql_sources: &[LocatedGraphQLSource],
+    docblock_sources: &[LocatedDocblockSource],
+) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for source in graphql_sources {
+        source.index.hash(&mut hasher);
+        source.graphql_source.text_source().text.hash(&mut hasher);
+    }
+    for source in docblock_sources {
+        source.index.hash(&mut hasher);
+        source.docblock_source.text_source().text.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Answers the requests of the endpoint on `port` of localhost, until the
+/// process exits.
+pub async fn serve_on_demand_requests(
+    config: Arc<Config>,
+    build_tracker: Arc<BuildTracker>,
+    port: u16,
+) -> io::Result<()> {
+    let listener = TcpListener::bind(("127.0.0.1", port)).await?;
+    info!(
+        "Waiting for on-demand requests on http://127.0.0.1:{}/ensure",
+        port
+    );
+    let categorizer = Arc::new(FileCategorizer::from_config(&config));
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let config = Arc::clone(&config);
+        let build_tracker = Arc::clone(&build_tracker);
+        let categorizer = Arc::clone(&categorizer);
+        tokio::spawn(async move {
+            if let Err(err) = handle_connection(stream, &config, &build_tracker, &categorizer).await
+            {
+                debug!("Unable to answer an on-demand request: {}", err);
+            }
+        });
+    }
+}
+
+async fn handle_connection(
+    stream: TcpStream,
+    config: &Config,
+    build_tracker: &BuildTracker,
+    categorizer: &FileCategorizer,
+) -> io::Result<()> {
+    let mut stream = BufReader::new(stream);
+    let mut request_line = String::new();
+    stream.read_line(&mut request_line).await?;
+    // Skip the headers, requests have no body.
+    let mut header = String::new();
+    while stream.read_line(&mut header).await? > 0 && !header.trim_end().is_empty() {
+        header.clear();
+    }
+
+    let (status, body) = answer_request(&request_line, config, build_tracker, categorizer).await;
+    let body = body.to_string();
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    let mut stream = stream.into_inner();
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+async fn answer_request(
+    request_line: &str,
+    config: &Config,
+    build_tracker: &BuildTracker,
+    categorizer: &FileCategorizer,
+) -> (&'static str, serde_json::Value) {
+    let mut parts = request_line.split_whitespace();
+    let (method, target) = (parts.next(), parts.next().unwrap_or_default());
+    let (route, query) = target.split_once('?').unwrap_or((target, ""));
+    if method != Some("GET") || route != "/ensure" {
+        return (
+            "404 Not Found",
+            json!({"error": "Only `GET /ensure?file=<path>` is supported."}),
+        );
+    }
+
+    let mut file = None;
+    let mut timeout = DEFAULT_TIMEOUT;
+    for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+        match key.as_ref() {
+            "file" => file = Some(PathBuf::from(value.as_ref())),
+            "timeout" => match value.parse() {
+                Ok(milliseconds) => timeout = Duration::from_millis(milliseconds),
+                Err(_) => {
+                    return (
+                        "400 Bad Request",
+                        json!({"error": "`timeout` must be a number of milliseconds."}),
+                    );
+                }
+            },
+            _ => {}
+        }
+    }
+    let file = match file {
+        Some(file) => file,
+        None => {
+            return (
+                "400 Bad Request",
+                json!({"error": "The `file` parameter is missing."}),
+            );
+        }
+    };
+    let path = file.strip_prefix(&config.root_dir).unwrap_or(&file);
+    if !matches!(categorizer.categorize(path), Ok(FileGroup::Source { .. })) {
+        return (
+            "404 Not Found",
+            json!({"error": "The file is not a source file of a project."}),
+        );
+    }
+
+    match build_tracker
+        .wait_for_file(&config.root_dir, path, Instant::now() + timeout)
+        .await
+    {
+        Some(BuildOutcome::Succeeded) => ("200 OK", json!({"status": "ready"})),
+        Some(BuildOutcome::Failed(error)) => {
+            ("200 OK", json!({"status": "failed", "error": error}))
+        }
+        None => (
+            "504 Gateway Timeout",
+            json!({"error": "No build included the file before the timeout."}),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    fn tracked_file(contents: &str) -> (PathBuf, u64) {
+        let path = PathBuf::from("Profile.js");
+        let features = extract_javascript_features(&path, contents);
+        (
+            path,
+            hash_sources(&features.graphql_sources, &features.docblock_sources),
+        )
+    }
+
+    fn write_file(root_dir: &Path, contents: &str) {
+        fs::create_dir_all(root_dir).unwrap();
+        fs::write(root_dir.join("Profile.js"), contents).unwrap();
+    }
+
+    fn deadline() -> Instant {
+        Instant::now() + Duration::from_millis(200)
+    }
+
+    const CONTENTS: &str = "graphql`query ProfileQuery { me { name } }`;";
+
+    #[tokio::test]
+    async fn ready_once_the_contents_are_built() {
+        let root_dir =
+            env::temp_dir().join(format!("relay-on-demand-ready-{}", std::process::id()));
+        write_file(&root_dir, CONTENTS);
+        let build_tracker = BuildTracker::default();
+        let path = Path::new("Profile.js");
+
+        assert_eq!(
+            build_tracker
+                .wait_for_file(&root_dir, path, deadline())
+                .await,
+            None
+        );
+        build_tracker.complete_build(vec![tracked_file(CONTENTS)], &Ok(()));
+        assert_eq!(
+            build_tracker
+                .wait_for_file(&root_dir, path, deadline())
+                .await,
+            Some(BuildOutcome::Succeeded)
+        );
+
+        // Only the GraphQL of the file matters.
+        write_file(&root_dir, &format!("// Profile\n{}", CONTENTS));
+        assert_eq!(
+            build_tracker
+                .wait_for_file(&root_dir, path, deadline())
+                .await,
+            Some(BuildOutcome::Succeeded)
+        );
+
+        let _ = fs::remove_dir_all(&root_dir);
+    }
+
+    #[tokio::test]
+    async fn waits_for_the_build_of_new_contents() {
+        let root_dir = env::temp_dir().join(format!("relay-on-demand-wait-{}", std::process::id()));
+        write_file(&root_dir, CONTENTS);
+        let build_tracker = Arc::new(BuildTracker::default());
+        build_tracker.complete_build(vec![tracked_file(CONTENTS)], &Ok(()));
+
+        let new_contents = "graphql`query ProfileQuery { me { id } }`;";
+        write_file(&root_dir, new_contents);
+        let waiting = {
+            let build_tracker = Arc::clone(&build_tracker);
+            let root_dir = root_dir.clone();
+            tokio::spawn(async move {
+                build_tracker
+                    .wait_for_file(
+                        &root_dir,
+                        Path::new("Profile.js"),
+                        Instant::now() + Duration::from_secs(5),
+                    )
+                    .await
+            })
+        };
+        build_tracker.complete_build(
+            vec![tracked_file(new_contents)],
+            &Err::<(), _>(Error::DiagnosticsError { errors: Vec::new() }),
+        );
+        assert!(matches!(
+            waiting.await.unwrap(),
+            Some(BuildOutcome::Failed(_))
+        ));
+
+        let _ = fs::remove_dir_all(&root_dir);
+    }
+
+    #[tokio::test]
+    async fn files_without_sources_are_ready() {
+        let root_dir =
+            env::temp_dir().join(format!("relay-on-demand-no-sources-{}", std::process::id()));
+        write_file(&root_dir, "export default 42;");
+        let build_tracker = BuildTracker::default();
+        assert_eq!(
+            build_tracker
+                .wait_for_file(&root_dir, Path::new("Profile.js"), deadline())
+                .await,
+            Some(BuildOutcome::Succeeded)
+        );
+
+        let _ = fs::remove_dir_all(&root_dir);
+    }
+}
@@ -9,6 +9,7 @@ use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::BufWriter;
 use std::io::Write;
+use std::path::PathBuf;
 
 use async_trait::async_trait;
 use dashmap::DashMap;
@@ -21,11 +22,14 @@ use sha1::Sha1;
 use sha2::Sha256;
 
 use crate::config::ArtifactForPersister;
+use crate::shard::Shard;
 use crate::OperationPersister;
 
 pub struct LocalPersister {
     config: LocalPersistConfig,
     query_map: DashMap<String, String>,
+    /// The file the queries are written to, `config.file` unless sharded.
+    output: PathBuf,
 }
 
 impl LocalPersister {
@@ -40,7 +44,22 @@ impl LocalPersister {
             }
         };
 
-        Self { config, query_map }
+        let output = config.file.clone();
+        Self {
+            config,
+            query_map,
+            output,
+        }
+    }
+
+    /// Writes the queries to the file of `shard`, to be merged with the ones
+    /// of the other shards by `merge_persisted_queries`.
+    pub fn for_shard(config: LocalPersistConfig, shard: Shard) -> Self {
+        let output = shard.output_path(&config.file);
+        Self {
+            output,
+            ..Self::new(config)
+        }
     }
 
     fn hash_operation(&self, operation_text: String) -> String {
@@ -86,7 +105,7 @@ impl OperationPersister for LocalPersister {
             .map(|x| (x.key().clone(), x.value().clone()))
             .collect();
 
-        let mut writer = BufWriter::new(File::create(&self.config.file)?);
+        let mut writer = BufWriter::new(File::create(&self.output)?);
         serde_json::to_writer_pretty(&mut writer, &ordered)?;
         writer.write_all(b"\n")?;
         writer.flush()?;
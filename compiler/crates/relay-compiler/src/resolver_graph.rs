@@ -0,0 +1,236 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The graph of the dependencies of the Relay Resolvers of a project: each
+//! resolver field reads its root fragment, which selects server fields, other
+//! resolver fields and other fragments. Used by the `resolver-graph` command,
+//! to see which server data the fields derived on the client ultimately read,
+//! and which resolvers a change of the server schema affects.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use common::NamedItem;
+use fnv::FnvHashSet;
+use graphql_ir::FragmentDefinitionName;
+use graphql_ir::Program;
+use graphql_ir::Selection;
+use intern::string_key::Intern;
+use relay_transforms::get_resolver_fragment_name;
+use relay_transforms::RELAY_RESOLVER_DIRECTIVE_NAME;
+use schema::Field;
+use schema::FieldID;
+use schema::SDLSchema;
+use schema::Schema;
+use serde::Serialize;
+
+use crate::compiler_state::ProjectName;
+
+#[derive(Debug, Default, Serialize)]
+pub struct ResolverGraph {
+    /// The resolver fields of the schema, by `Type.field`.
+    pub resolvers: BTreeMap<String, ResolverNode>,
+    /// The fragments read by the resolvers, directly or through other
+    /// fragments.
+    pub fragments: BTreeMap<String, FragmentNode>,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolverNode {
+    pub root_fragment: Option<String>,
+    /// The server fields the resolver ultimately reads: the ones of its root
+    /// fragment, of the fragments it spreads and of the resolvers it selects,
+    /// transitively.
+    pub server_fields: BTreeSet<String>,
+}
+
+/// The direct dependencies of a fragment.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FragmentNode {
+    pub fragments: BTreeSet<String>,
+    pub resolvers: BTreeSet<String>,
+    pub server_fields: BTreeSet<String>,
+}
+
+impl ResolverGraph {
+    /// The graph of the resolvers of the schema of `program`, which must have
+    /// all the fragments of the project, before the transforms.
+    pub fn new(program: &Program) -> Self {
+        let schema = program.schema.as_ref();
+        let mut graph = ResolverGraph::default();
+        for field in schema.fields() {
+            if let Some(coordinate) = resolver_coordinate(schema, field) {
+                graph.resolvers.insert(
+                    coordinate,
+                    ResolverNode {
+                        root_fragment: get_resolver_fragment_name(field)
+                            .map(|fragment_name| fragment_name.to_string()),
+                        server_fields: Default::default(),
+                    },
+                );
+            }
+        }
+
+        let mut pending_fragments = graph
+            .resolvers
+            .values()
+            .filter_map(|resolver| resolver.root_fragment.clone())
+            .collect::<Vec<_>>();
+        while let Some(fragment_name) = pending_fragments.pop() {
+            if graph.fragments.contains_key(&fragment_name) {
+                continue;
+            }
+            let mut node = FragmentNode::default();
+            if let Some(fragment) =
+                program.fragment(FragmentDefinitionName(fragment_name.as_str().intern()))
+            {
+                collect_dependencies(schema, &fragment.selections, &mut node);
+            }
+            pending_fragments.extend(node.fragments.iter().cloned());
+            graph.fragments.insert(fragment_name, node);
+        }
+
+        let server_fields = graph
+            .resolvers
+            .keys()
+            .map(|resolver| (resolver.clone(), graph.transitive_server_fields(resolver)))
+            .collect::<Vec<_>>();
+        for (resolver, server_fields) in server_fields {
+            if let Some(node) = graph.resolvers.get_mut(&resolver) {
+                node.server_fields = server_fields;
+            }
+        }
+        graph
+    }
+
+    fn transitive_server_fields(&self, resolver: &str) -> BTreeSet<String> {
+        let mut server_fields = BTreeSet::new();
+        let mut visited_resolvers = FnvHashSet::default();
+        let mut visited_fragments = FnvHashSet::default();
+        let mut pending_resolvers = vec![resolver];
+        while let Some(resolver) = pending_resolvers.pop() {
+            if !visited_resolvers.insert(resolver) {
+                continue;
+            }
+            let mut pending_fragments = self
+                .resolvers
+                .get(resolver)
+                .and_then(|node| node.root_fragment.as_deref())
+                .into_iter()
+                .collect::<Vec<_>>();
+            while let Some(fragment_name) = pending_fragments.pop() {
+                if !visited_fragments.insert(fragment_name) {
+                    continue;
+                }
+                if let Some(node) = self.fragments.get(fragment_name) {
+                    server_fields.extend(node.server_fields.iter().cloned());
+                    pending_fragments.extend(node.fragments.iter().map(String::as_str));
+                    pending_resolvers.extend(node.resolvers.iter().map(String::as_str));
+                }
+            }
+        }
+        server_fields
+    }
+
+    /// The graph in the DOT language of Graphviz: resolvers are boxes, with
+    /// an edge to their root fragment, and server fields are plain text.
+    pub fn to_dot(&self, graph_name: &str) -> String {
+        let mut result = String::new();
+        self.write_dot(&mut result, graph_name).unwrap();
+        result
+    }
+
+    fn write_dot(&self, writer: &mut String, graph_name: &str) -> std::fmt::Result {
+        writeln!(writer, "digraph \"{}\" {{", graph_name)?;
+        for (resolver, node) in &self.resolvers {
+            writeln!(writer, "  \"{}\" [shape=box];", resolver)?;
+            if let Some(root_fragment) = &node.root_fragment {
+                writeln!(writer, "  \"{}\" -> \"{}\";", resolver, root_fragment)?;
+            }
+        }
+        let mut server_fields = BTreeSet::new();
+        for (fragment_name, node) in &self.fragments {
+            for dependency in node
+                .fragments
+                .iter()
+                .chain(&node.resolvers)
+                .chain(&node.server_fields)
+            {
+                writeln!(writer, "  \"{}\" -> \"{}\";", fragment_name, dependency)?;
+            }
+            server_fields.extend(&node.server_fields);
+        }
+        for server_field in server_fields {
+            writeln!(writer, "  \"{}\" [shape=plaintext];", server_field)?;
+        }
+        writeln!(writer, "}}")
+    }
+}
+
+/// The graphs of several projects as a JSON object, by project name.
+pub fn resolver_graphs_to_json(graphs: &[(ProjectName, ResolverGraph)]) -> String {
+    let graphs = graphs
+        .iter()
+        .map(|(project_name, graph)| (project_name.to_string(), graph))
+        .collect::<BTreeMap<_, _>>();
+    serde_json::to_string_pretty(&graphs).unwrap()
+}
+
+fn collect_dependencies(schema: &SDLSchema, selections: &[Selection], node: &mut FragmentNode) {
+    for selection in selections {
+        match selection {
+            Selection::FragmentSpread(spread) => {
+                node.fragments.insert(spread.fragment.item.to_string());
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                collect_dependencies(schema, &inline_fragment.selections, node);
+            }
+            Selection::Condition(condition) => {
+                collect_dependencies(schema, &condition.selections, node);
+            }
+            Selection::LinkedField(field) => {
+                add_field(schema, field.definition.item, node);
+                collect_dependencies(schema, &field.selections, node);
+            }
+            Selection::ScalarField(field) => add_field(schema, field.definition.item, node),
+        }
+    }
+}
+
+fn add_field(schema: &SDLSchema, field_id: FieldID, node: &mut FragmentNode) {
+    let field = schema.field(field_id);
+    if let Some(coordinate) = resolver_coordinate(schema, field) {
+        node.resolvers.insert(coordinate);
+    } else if !field.is_extension {
+        // Fields of all types, like `__typename`, have no parent type.
+        if let Some(parent_type) = field.parent_type {
+            node.server_fields.insert(format!(
+                "{}.{}",
+                schema.get_type_name(parent_type),
+                field.name.item
+            ));
+        }
+    }
+}
+
+/// `Type.field` of a resolver field, `None` for the other fields.
+fn resolver_coordinate(schema: &SDLSchema, field: &Field) -> Option<String> {
+    if !field.is_extension
+        || field
+            .directives
+            .named(*RELAY_RESOLVER_DIRECTIVE_NAME)
+            .is_none()
+    {
+        return None;
+    }
+    field
+        .parent_type
+        .map(|parent_type| format!("{}.{}", schema.get_type_name(parent_type), field.name.item))
+}
@@ -5,12 +5,452 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+//! Loading of the saved states of the compiler, and their remote storage.
+//!
+//! The saved state of a build of the main branch is pushed to the storage of
+//! the `savedStateStorage` config under the commit it was built from. Builds
+//! on other machines, e.g. on CI, pull the saved state of the last commit of
+//! the main branch they are based on, and only compile the files changed
+//! since that commit.
+
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use std::path::Path;
 use std::path::PathBuf;
+use std::process::Command;
 
+use async_trait::async_trait;
+use indexmap::IndexMap;
+use log::debug;
+use log::info;
+use log::warn;
+use serde::Deserialize;
+use serde::Serialize;
 use serde_bser::value::Value;
+use serde_json::json;
 
+use crate::artifact_cache::ArtifactCache;
+use crate::artifact_cache::ArtifactCacheConfig;
+use crate::artifact_cache::ArtifactCacheError;
+use crate::artifact_cache::HttpArtifactCache;
+use crate::compiler_state::CompilerState;
 use crate::config::Config;
+use crate::config::FileSourceKind;
+use crate::errors::Error;
+use crate::errors::Result;
 
 pub trait SavedStateLoader {
     fn load(&self, saved_state_info: &Value, config: &Config) -> Option<PathBuf>;
 }
+
+/// The `savedStateStorage` section of the config file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct SavedStateStorageConfig {
+    /// The saved states are read with `GET <url>/<key>`, and written with
+    /// `PUT <url>/<key>`.
+    pub url: String,
+
+    /// Headers of the requests, e.g. for authentication.
+    #[serde(default)]
+    pub headers: IndexMap<String, String>,
+
+    /// Only reads the saved states, e.g. on the machines of developers.
+    #[serde(default)]
+    pub read_only: bool,
+
+    /// The branch whose builds push their saved state.
+    #[serde(default = "default_main_branch")]
+    pub main_branch: String,
+
+    /// How many commits of the main branch are tried, from the most recent
+    /// one, when pulling a saved state.
+    #[serde(default = "default_max_commits")]
+    pub max_commits: usize,
+}
+
+fn default_main_branch() -> String {
+    "main".to_string()
+}
+
+fn default_max_commits() -> usize {
+    20
+}
+
+/// The storage of the saved states, keyed by the commit they were built
+/// from, e.g. an HTTP server or an S3 or GCS bucket.
+#[async_trait]
+pub trait SavedStateStorage: Send + Sync {
+    /// Returns the saved state of `commit`, or `None` if it's not stored.
+    async fn pull(&self, commit: &str) -> std::result::Result<Option<Vec<u8>>, ArtifactCacheError>;
+
+    async fn push(
+        &self,
+        commit: &str,
+        saved_state: Vec<u8>,
+    ) -> std::result::Result<(), ArtifactCacheError>;
+
+    /// The branch whose commits have a saved state.
+    fn main_branch(&self) -> &str;
+
+    /// How many commits of the main branch are tried when pulling.
+    fn max_commits(&self) -> usize;
+}
+
+/// A `SavedStateStorage` storing the saved states on an HTTP server. The key
+/// of a saved state includes `Config::saved_state_version`, so that the
+/// builds only pull the saved states of the same compiler and config.
+pub struct HttpSavedStateStorage {
+    storage: HttpArtifactCache,
+    version: String,
+    main_branch: String,
+    max_commits: usize,
+}
+
+impl HttpSavedStateStorage {
+    pub fn new(config: SavedStateStorageConfig, saved_state_version: String) -> Self {
+        Self {
+            storage: HttpArtifactCache::new(ArtifactCacheConfig {
+                url: config.url,
+                headers: config.headers,
+                read_only: config.read_only,
+            }),
+            version: saved_state_version,
+            main_branch: config.main_branch,
+            max_commits: config.max_commits,
+        }
+    }
+
+    fn key(&self, commit: &str) -> String {
+        format!("{}-{}", commit, self.version)
+    }
+}
+
+#[async_trait]
+impl SavedStateStorage for HttpSavedStateStorage {
+    async fn pull(&self, commit: &str) -> std::result::Result<Option<Vec<u8>>, ArtifactCacheError> {
+        self.storage.get(&self.key(commit)).await
+    }
+
+    async fn push(
+        &self,
+        commit: &str,
+        saved_state: Vec<u8>,
+    ) -> std::result::Result<(), ArtifactCacheError> {
+        self.storage.put(&self.key(commit), saved_state).await
+    }
+
+    fn main_branch(&self) -> &str {
+        &self.main_branch
+    }
+
+    fn max_commits(&self) -> usize {
+        self.max_commits
+    }
+}
+
+/// Pulls the saved state of the most recent commit of the main branch that
+/// `HEAD` is based on, and configures the compiler to load it and compile
+/// the files changed since that commit. Returns the commit of the saved
+/// state, or `None` if none was found, in which case the compiler makes a
+/// full build. Failures of the storage are logged.
+pub async fn pull_saved_state(config: &mut Config) -> Result<Option<String>> {
+    let storage = match &config.saved_state_storage {
+        Some(storage) => storage,
+        None => return Ok(None),
+    };
+    let merge_base = match git(
+        &config.root_dir,
+        &["merge-base", "HEAD", storage.main_branch()],
+    ) {
+        Some(merge_base) => merge_base,
+        None => {
+            warn!(
+                "Unable to find the merge base of HEAD and `{}`, the saved state is not pulled.",
+                storage.main_branch()
+            );
+            return Ok(None);
+        }
+    };
+    let commits = git(
+        &config.root_dir,
+        &[
+            "rev-list",
+            "--first-parent",
+            &format!("--max-count={}", storage.max_commits()),
+            &merge_base,
+        ],
+    )
+    .unwrap_or_default();
+
+    for commit in commits.lines() {
+        let saved_state = match storage.pull(commit).await {
+            Ok(Some(saved_state)) => saved_state,
+            Ok(None) => {
+                debug!("No saved state for commit {}", commit);
+                continue;
+            }
+            Err(err) => {
+                warn!("Unable to pull the saved state: {}", err);
+                return Ok(None);
+            }
+        };
+        let changed_files = match changed_files_since(&config.root_dir, commit) {
+            Some(changed_files) => changed_files,
+            None => {
+                warn!(
+                    "Unable to list the files changed since commit {}, the saved state is not used.",
+                    commit
+                );
+                return Ok(None);
+            }
+        };
+        info!(
+            "Pulled the saved state of commit {}, {} file(s) changed since",
+            commit,
+            changed_files.len()
+        );
+
+        let temp_dir = env::temp_dir();
+        let saved_state_file = temp_dir.join(format!("relay-saved-state-{}", commit));
+        let changed_files_list = temp_dir.join(format!("relay-changed-files-{}.json", commit));
+        let changed_files = changed_files
+            .into_iter()
+            .map(|name| {
+                let exists = config.root_dir.join(&name).exists();
+                json!({ "name": name, "exists": exists })
+            })
+            .collect::<Vec<_>>();
+        write_file(&saved_state_file, saved_state)?;
+        write_file(
+            &changed_files_list,
+            serde_json::to_vec(&changed_files).unwrap(),
+        )?;
+        config.load_saved_state_file = Some(saved_state_file);
+        config.file_source_config = FileSourceKind::External(changed_files_list);
+        return Ok(Some(commit.to_string()));
+    }
+    info!("No saved state found for the last commits of the main branch");
+    Ok(None)
+}
+
+/// Pushes the saved state of a build of the commit `HEAD`. Builds of a
+/// working tree with changes are not pushed, since their saved state doesn't
+/// match the commit. Failures of the storage are logged.
+pub async fn push_saved_state(config: &Config, compiler_state: &CompilerState) -> Result<()> {
+    let storage = match &config.saved_state_storage {
+        Some(storage) => storage,
+        None => return Ok(()),
+    };
+    let commit = match git(&config.root_dir, &["rev-parse", "HEAD"]) {
+        Some(commit) => commit,
+        None => {
+            warn!("Unable to find the commit of HEAD, the saved state is not pushed.");
+            return Ok(());
+        }
+    };
+    if git(&config.root_dir, &["status", "--porcelain", "--", "."])
+        .map_or(true, |status| !status.is_empty())
+    {
+        warn!("The working tree has changes, the saved state is not pushed.");
+        return Ok(());
+    }
+
+    let saved_state_file = env::temp_dir().join(format!("relay-saved-state-{}", commit));
+    compiler_state.serialize_to_file(&saved_state_file)?;
+    let saved_state = fs::read(&saved_state_file).map_err(|err| Error::ReadFileError {
+        file: saved_state_file.clone(),
+        source: err,
+    })?;
+    let _ = fs::remove_file(&saved_state_file);
+    match storage.push(&commit, saved_state).await {
+        Ok(()) => info!("Pushed the saved state of commit {}", commit),
+        Err(err) => warn!("Unable to push the saved state: {}", err),
+    }
+    Ok(())
+}
+
+/// The files of the root directory, relative to it, that changed since
+/// `commit`: the committed and uncommitted changes, and the untracked files.
+fn changed_files_since(root_dir: &Path, commit: &str) -> Option<BTreeSet<String>> {
+    let changed = git(root_dir, &["diff", "--name-only", "--relative", commit])?;
+    let untracked = git(root_dir, &["ls-files", "--others", "--exclude-standard"])?;
+    Some(
+        changed
+            .lines()
+            .chain(untracked.lines())
+            .map(str::to_string)
+            .collect(),
+    )
+}
+
+/// Runs git in `root_dir`, and returns its trimmed output if it succeeds.
+fn git(root_dir: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(root_dir)
+        .output()
+        .ok()?;
+    if output.status.success() {
+        String::from_utf8(output.stdout)
+            .ok()
+            .map(|stdout| stdout.trim().to_string())
+    } else {
+        debug!(
+            "`git {}` failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        None
+    }
+}
+
+fn write_file(path: &Path, content: Vec<u8>) -> Result<()> {
+    fs::write(path, content).map_err(|err| Error::WriteFileError {
+        file: path.to_path_buf(),
+        source: err,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use fnv::FnvHashMap;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct MemorySavedStateStorage {
+        saved_states: Mutex<FnvHashMap<String, Vec<u8>>>,
+    }
+
+    #[async_trait]
+    impl SavedStateStorage for MemorySavedStateStorage {
+        async fn pull(
+            &self,
+            commit: &str,
+        ) -> std::result::Result<Option<Vec<u8>>, ArtifactCacheError> {
+            Ok(self.saved_states.lock().unwrap().get(commit).cloned())
+        }
+
+        async fn push(
+            &self,
+            commit: &str,
+            saved_state: Vec<u8>,
+        ) -> std::result::Result<(), ArtifactCacheError> {
+            self.saved_states
+                .lock()
+                .unwrap()
+                .insert(commit.to_string(), saved_state);
+            Ok(())
+        }
+
+        fn main_branch(&self) -> &str {
+            "main"
+        }
+
+        fn max_commits(&self) -> usize {
+            20
+        }
+    }
+
+    /// Commits `file` to the repository of `root_dir`, and returns the commit.
+    fn commit_file(root_dir: &Path, file: &str) -> String {
+        fs::write(root_dir.join(file), "").unwrap();
+        git(root_dir, &["add", file]).unwrap();
+        git(
+            root_dir,
+            &[
+                "-c",
+                "user.name=Relay",
+                "-c",
+                "user.email=relay@example.com",
+                "commit",
+                "--message",
+                file,
+            ],
+        )
+        .unwrap();
+        git(root_dir, &["rev-parse", "HEAD"]).unwrap()
+    }
+
+    fn create_config(root_dir: PathBuf, storage: MemorySavedStateStorage) -> Config {
+        let mut config = Config::from_string_for_test(
+            r#"
+            {
+                "sources": {"src": "project"},
+                "projects": {"project": {"schema": "schema.graphql", "language": "flow"}}
+            }
+            "#,
+        )
+        .unwrap();
+        config.root_dir = root_dir;
+        config.saved_state_storage = Some(Box::new(storage));
+        config
+    }
+
+    #[test]
+    fn key_test() {
+        let storage = HttpSavedStateStorage::new(
+            SavedStateStorageConfig {
+                url: "https://example.com/relay".to_string(),
+                headers: Default::default(),
+                read_only: false,
+                main_branch: default_main_branch(),
+                max_commits: default_max_commits(),
+            },
+            "8f3c".to_string(),
+        );
+        assert_eq!(storage.key("a1b2c3"), "a1b2c3-8f3c");
+    }
+
+    #[tokio::test]
+    async fn pull_saved_state_test() {
+        let root_dir =
+            env::temp_dir().join(format!("relay-saved-state-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root_dir);
+        fs::create_dir_all(&root_dir).unwrap();
+        git(&root_dir, &["init", "--initial-branch", "main"]).unwrap();
+        let first_commit = commit_file(&root_dir, "First.js");
+        commit_file(&root_dir, "Second.js");
+
+        // Falls back to the saved state of an older commit of the main branch
+        // if the last one has none
+        let storage = MemorySavedStateStorage::default();
+        storage
+            .push(&first_commit, b"saved state".to_vec())
+            .await
+            .unwrap();
+        let mut config = create_config(root_dir.clone(), storage);
+        assert_eq!(
+            pull_saved_state(&mut config).await.unwrap(),
+            Some(first_commit.clone())
+        );
+        let saved_state_file = config.load_saved_state_file.clone().unwrap();
+        assert_eq!(fs::read(&saved_state_file).unwrap(), b"saved state");
+        match &config.file_source_config {
+            FileSourceKind::External(changed_files_list) => {
+                let changed_files: serde_json::Value =
+                    serde_json::from_slice(&fs::read(changed_files_list).unwrap()).unwrap();
+                assert_eq!(
+                    changed_files,
+                    json!([{ "name": "Second.js", "exists": true }])
+                );
+            }
+            _ => panic!("Expected the files changed since the saved state"),
+        }
+
+        // Makes a full build if none of the commits has a saved state
+        let mut config = create_config(root_dir.clone(), MemorySavedStateStorage::default());
+        assert_eq!(pull_saved_state(&mut config).await.unwrap(), None);
+        assert!(config.load_saved_state_file.is_none());
+        assert!(!matches!(
+            config.file_source_config,
+            FileSourceKind::External(_)
+        ));
+
+        fs::remove_dir_all(&root_dir).unwrap();
+    }
+}
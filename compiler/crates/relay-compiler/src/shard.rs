@@ -0,0 +1,202 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Splits the clean build of large projects across workers: every worker
+//! builds the programs of the projects, and writes and persists only the
+//! artifacts of its shard, chosen by a hash of their paths. The local
+//! persisters of the workers write their queries to files next to the
+//! configured one, merged by `merge_persisted_queries` once all the workers
+//! are done.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use md5::Digest;
+use md5::Md5;
+
+/// The 1-based `index`-th of `count` shards, e.g. `3/8`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shard {
+    index: usize,
+    count: usize,
+}
+
+impl Shard {
+    pub fn new(index: usize, count: usize) -> Result<Self, String> {
+        if count == 0 || index == 0 || index > count {
+            return Err(format!(
+                "Invalid shard `{}/{}`, expected `<index>/<count>` with 1 <= index <= count.",
+                index, count
+            ));
+        }
+        Ok(Self { index, count })
+    }
+
+    /// Whether the artifact at `path`, relative to the root directory,
+    /// belongs to this shard. The paths are hashed with `/` separators, for
+    /// the shards to be the same on every platform.
+    pub fn contains(&self, path: &Path) -> bool {
+        let mut hash = Md5::new();
+        hash.update(path.to_string_lossy().replace('\\', "/"));
+        let digest = hash.finalize();
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&digest[..8]);
+        (u64::from_le_bytes(bytes) % self.count as u64) as usize == self.index - 1
+    }
+
+    /// The file a local persister of this shard writes its queries to, e.g.
+    /// `persisted_queries.shard-3-of-8.json` for `persisted_queries.json`.
+    pub fn output_path(&self, path: &Path) -> PathBuf {
+        shard_output_path(path, self.index, self.count)
+    }
+}
+
+impl FromStr for Shard {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (index, count) = value
+            .split_once('/')
+            .and_then(|(index, count)| Some((index.parse().ok()?, count.parse().ok()?)))
+            .ok_or_else(|| {
+                format!(
+                    "Invalid shard `{}`, expected `<index>/<count>`, e.g. `3/8`.",
+                    value
+                )
+            })?;
+        Self::new(index, count)
+    }
+}
+
+impl fmt::Display for Shard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.index, self.count)
+    }
+}
+
+fn shard_output_path(path: &Path, index: usize, count: usize) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let file_name = match path.extension() {
+        Some(extension) => format!(
+            "{}.shard-{}-of-{}.{}",
+            stem,
+            index,
+            count,
+            extension.to_string_lossy()
+        ),
+        None => format!("{}.shard-{}-of-{}", stem, index, count),
+    };
+    path.with_file_name(file_name)
+}
+
+/// Merges the queries persisted by the `count` shards of a build into the
+/// local persisted queries `file`, and removes the files of the shards.
+/// Returns the number of queries in `file`.
+pub fn merge_persisted_queries(file: &Path, count: usize) -> io::Result<usize> {
+    let mut queries: BTreeMap<String, String> = read_queries(file)?;
+    let shard_files: Vec<_> = (1..=count)
+        .map(|index| shard_output_path(file, index, count))
+        .collect();
+    for shard_file in &shard_files {
+        for (id, text) in read_queries(shard_file)? {
+            match queries.get(&id) {
+                Some(existing_text) if existing_text != &text => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "The query `{}` of `{}` has a different text in another shard.",
+                            id,
+                            shard_file.display()
+                        ),
+                    ));
+                }
+                _ => {
+                    queries.insert(id, text);
+                }
+            }
+        }
+    }
+
+    let mut content = serde_json::to_string_pretty(&queries)?;
+    content.push('\n');
+    fs::write(file, content)?;
+    for shard_file in &shard_files {
+        fs::remove_file(shard_file)?;
+    }
+    Ok(queries.len())
+}
+
+fn read_queries(file: &Path) -> io::Result<BTreeMap<String, String>> {
+    let content = fs::read_to_string(file).map_err(|err| {
+        io::Error::new(
+            err.kind(),
+            format!("Unable to read `{}`: {}", file.display(), err),
+        )
+    })?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("3/8".parse(), Ok(Shard { index: 3, count: 8 }));
+        assert!("0/8".parse::<Shard>().is_err());
+        assert!("9/8".parse::<Shard>().is_err());
+        assert!("3".parse::<Shard>().is_err());
+        assert_eq!(Shard::new(3, 8).unwrap().to_string(), "3/8");
+    }
+
+    #[test]
+    fn test_every_path_is_in_one_shard() {
+        let shards: Vec<_> = (1..=4).map(|index| Shard::new(index, 4).unwrap()).collect();
+        for name in ["A", "B", "C", "D", "E", "F", "G", "H"] {
+            let path = PathBuf::from(format!("src/__generated__/{}.graphql.js", name));
+            let containing = shards.iter().filter(|shard| shard.contains(&path)).count();
+            assert_eq!(containing, 1);
+        }
+    }
+
+    #[test]
+    fn test_output_path() {
+        let shard = Shard::new(3, 8).unwrap();
+        assert_eq!(
+            shard.output_path(Path::new("queries/persisted.json")),
+            PathBuf::from("queries/persisted.shard-3-of-8.json")
+        );
+    }
+
+    #[test]
+    fn test_merge_persisted_queries() {
+        let dir = env::temp_dir().join("relay_shard_merge_test");
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("persisted.json");
+        fs::write(&file, r#"{"a": "query A { a }"}"#).unwrap();
+        fs::write(
+            shard_output_path(&file, 1, 2),
+            r#"{"a": "query A { a }", "b": "query B { b }"}"#,
+        )
+        .unwrap();
+        fs::write(shard_output_path(&file, 2, 2), r#"{"c": "query C { c }"}"#).unwrap();
+
+        assert_eq!(merge_persisted_queries(&file, 2).unwrap(), 3);
+        assert_eq!(
+            read_queries(&file).unwrap().keys().collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+        assert!(!shard_output_path(&file, 1, 2).exists());
+    }
+}
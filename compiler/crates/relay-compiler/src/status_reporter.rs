@@ -7,13 +7,18 @@
 
 use std::path::PathBuf;
 
+use common::message_with_code;
 use common::Diagnostic;
 use common::DiagnosticSeverity;
+use common::Location;
 use graphql_cli::DiagnosticPrinter;
+pub use graphql_cli::DiagnosticsFormat;
+use indexmap::IndexMap;
 use log::error;
 use log::info;
 use log::warn;
 
+use crate::compiler_state::ProjectName;
 use crate::errors::BuildProjectError;
 use crate::errors::Error;
 use crate::source_for_location;
@@ -30,6 +35,7 @@ pub struct ConsoleStatusReporter {
     source_reader: Box<dyn SourceReader + Send + Sync>,
     root_dir: PathBuf,
     is_multi_project: bool,
+    diagnostics_format: DiagnosticsFormat,
 }
 
 impl ConsoleStatusReporter {
@@ -38,8 +44,14 @@ impl ConsoleStatusReporter {
             root_dir,
             source_reader: Box::new(FsSourceReader),
             is_multi_project,
+            diagnostics_format: DiagnosticsFormat::Plain,
         }
     }
+
+    pub fn with_diagnostics_format(mut self, diagnostics_format: DiagnosticsFormat) -> Self {
+        self.diagnostics_format = diagnostics_format;
+        self
+    }
 }
 
 impl ConsoleStatusReporter {
@@ -49,15 +61,22 @@ impl ConsoleStatusReporter {
                 self.print_diagnostics_by_severity(errors);
             }
             Error::BuildProjectsErrors { errors } => {
+                // A diagnostic of a document shared by several projects is
+                // reported by each of them, and printed once.
+                for (diagnostic, project_names) in group_project_diagnostics(errors) {
+                    self.print_project_diagnostic(diagnostic, &project_names);
+                }
                 for error in errors {
-                    self.print_project_error(error);
+                    if !matches!(error, BuildProjectError::ValidationErrors { .. }) {
+                        self.print_project_error(error);
+                    }
                 }
             }
             Error::Cancelled => {
                 info!("Compilation cancelled due to new changes.");
             }
             error => {
-                error!("{}", error);
+                error!("{}", message_with_code(error));
             }
         }
     }
@@ -77,36 +96,15 @@ impl ConsoleStatusReporter {
         }
     }
 
-    fn print_project_error(&self, error: &BuildProjectError) {
+    pub(crate) fn print_project_error(&self, error: &BuildProjectError) {
         match error {
             BuildProjectError::ValidationErrors {
                 errors,
                 project_name,
             } => {
-                errors
-                    .iter()
-                    .map(|diagnostic| {
-                        let output = self.print_diagnostic(diagnostic);
-                        let formatted_output = match diagnostic.severity() {
-                            DiagnosticSeverity::ERROR => {
-                                if self.is_multi_project {
-                                    format!("Error in the project `{}`: {}", project_name, output)
-                                } else {
-                                    format!("Error: {}", output)
-                                }
-                            }
-                            _ => {
-                                if self.is_multi_project {
-                                    format!("In the project `{}`: {}", project_name, output)
-                                } else {
-                                    output
-                                }
-                            }
-                        };
-
-                        (diagnostic.severity(), formatted_output)
-                    })
-                    .for_each(|(severity, output)| self.print_by_severity(severity, output));
+                for diagnostic in errors {
+                    self.print_project_diagnostic(diagnostic, &[*project_name]);
+                }
             }
             BuildProjectError::PersistErrors {
                 errors,
@@ -114,23 +112,70 @@ impl ConsoleStatusReporter {
             } => {
                 for error in errors {
                     if self.is_multi_project {
-                        error!("Error in the project `{}`: {}", project_name, error);
+                        error!(
+                            "Error in the project `{}`: {}",
+                            project_name,
+                            message_with_code(error)
+                        );
                     } else {
-                        error!("Error: {}", error);
+                        error!("Error: {}", message_with_code(error));
                     }
                 }
             }
             _ => {
-                error!("{}", error);
+                error!("{}", message_with_code(error));
             }
         }
     }
 
+    /// Prints a diagnostic reported by the validation of `project_names`.
+    fn print_project_diagnostic(&self, diagnostic: &Diagnostic, project_names: &[ProjectName]) {
+        let output = self.print_diagnostic(diagnostic);
+        let projects = match project_names {
+            [project_name] => format!("the project `{}`", project_name),
+            _ => format!(
+                "{} projects ({})",
+                project_names.len(),
+                project_names
+                    .iter()
+                    .map(|project_name| format!("`{}`", project_name))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+        let formatted_output = match diagnostic.severity() {
+            // The severity is part of the rich and short output
+            _ if self.diagnostics_format != DiagnosticsFormat::Plain => {
+                if self.is_multi_project {
+                    format!("In {}: {}", projects, output)
+                } else {
+                    output
+                }
+            }
+            DiagnosticSeverity::ERROR => {
+                if self.is_multi_project {
+                    format!("Error in {}: {}", projects, output)
+                } else {
+                    format!("Error: {}", output)
+                }
+            }
+            _ => {
+                if self.is_multi_project {
+                    format!("In {}: {}", projects, output)
+                } else {
+                    output
+                }
+            }
+        };
+        self.print_by_severity(diagnostic.severity(), formatted_output);
+    }
+
     fn print_diagnostic(&self, diagnostic: &Diagnostic) -> String {
         let printer = DiagnosticPrinter::new(|source_location| {
             source_for_location(&self.root_dir, source_location, self.source_reader.as_ref())
                 .map(|source| source.to_text_source())
-        });
+        })
+        .with_format(self.diagnostics_format);
         printer.diagnostic_to_string(diagnostic)
     }
 }
@@ -151,3 +196,92 @@ impl StatusReporter for ConsoleStatusReporter {
         }
     }
 }
+
+/// The diagnostics of the validation errors of the projects, deduplicated by
+/// location and code (or message, for the diagnostics without a code), with
+/// the projects that reported each of them.
+pub fn group_project_diagnostics(
+    errors: &[BuildProjectError],
+) -> Vec<(&Diagnostic, Vec<ProjectName>)> {
+    let mut groups: IndexMap<(Location, String), (&Diagnostic, Vec<ProjectName>)> = IndexMap::new();
+    for error in errors {
+        if let BuildProjectError::ValidationErrors {
+            errors,
+            project_name,
+        } = error
+        {
+            for diagnostic in errors {
+                let code = diagnostic
+                    .error_code()
+                    .map_or_else(|| diagnostic.print_without_source(), str::to_string);
+                let (_, project_names) = groups
+                    .entry((diagnostic.location(), code))
+                    .or_insert_with(|| (diagnostic, Vec::new()));
+                if !project_names.contains(project_name) {
+                    project_names.push(*project_name);
+                }
+            }
+        }
+    }
+    groups.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::SourceLocationKey;
+    use common::Span;
+    use intern::string_key::Intern;
+
+    use super::*;
+
+    fn validation_errors(project_name: &str, diagnostics: Vec<Diagnostic>) -> BuildProjectError {
+        BuildProjectError::ValidationErrors {
+            errors: diagnostics,
+            project_name: project_name.intern(),
+        }
+    }
+
+    #[test]
+    fn test_group_project_diagnostics() {
+        let shared = Location::new(
+            SourceLocationKey::standalone("shared.graphql"),
+            Span::new(0, 1),
+        );
+        let other = Location::new(
+            SourceLocationKey::standalone("web.graphql"),
+            Span::new(0, 1),
+        );
+        let errors = vec![
+            validation_errors(
+                "web",
+                vec![
+                    Diagnostic::error("Unknown field", shared),
+                    Diagnostic::error("Unknown field", other),
+                ],
+            ),
+            validation_errors("mobile", vec![Diagnostic::error("Unknown field", shared)]),
+            validation_errors("admin", vec![Diagnostic::error("Unknown type", shared)]),
+        ];
+
+        let groups = group_project_diagnostics(&errors)
+            .into_iter()
+            .map(|(diagnostic, project_names)| {
+                (
+                    diagnostic.location(),
+                    project_names
+                        .iter()
+                        .map(|project_name| project_name.to_string())
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            groups,
+            vec![
+                (shared, vec!["web".to_string(), "mobile".to_string()]),
+                (other, vec!["web".to_string()]),
+                (shared, vec!["admin".to_string()]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,320 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Inline suppressions of validation diagnostics, for one-off exceptions that
+//! don't deserve a change of the project config:
+//!
+//! ```graphql
+//! query AppQuery($id: ID!) {
+//!   # relay-ignore-next-line RELAY1068 The replacement isn't deployed yet
+//!   legacyField
+//! }
+//! ```
+//!
+//! A suppression applies to the diagnostics with one of its codes starting on
+//! the next line that isn't blank or a comment. Several codes are separated by
+//! commas, and the reason is required. Suppressions that don't apply to any
+//! diagnostic are reported, so that they are removed with the code they
+//! excused.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use common::diagnostic_codes;
+use common::find_diagnostic_code;
+use common::Diagnostic;
+use common::DiagnosticTag;
+use common::Location;
+use common::SourceLocationKey;
+use common::Span;
+use fnv::FnvHashMap;
+use graphql_ir::Program;
+use thiserror::Error;
+
+use crate::source_for_location;
+use crate::FsSourceReader;
+
+const SUPPRESSION_PREFIX: &str = "relay-ignore-next-line";
+
+diagnostic_codes! {
+    #[derive(Clone, Debug, Error, Eq, PartialEq, Ord, PartialOrd, Hash)]
+    pub enum SuppressionMessage {
+        #[error(
+            "Unused suppression of {codes}: no diagnostic with this code is reported on the next line."
+        )]
+        #[code("RELAY3001")]
+        UnusedSuppression { codes: String },
+
+        #[error("Invalid suppression, expected `# relay-ignore-next-line <code> <reason>`: {details}.")]
+        #[code("RELAY3002")]
+        InvalidSuppression { details: String },
+    }
+}
+
+/// A `# relay-ignore-next-line` comment of a source.
+#[derive(Debug)]
+struct Suppression {
+    /// The span of the comment.
+    span: Span,
+    /// The 0-based line of the suppressed diagnostics.
+    target_line: usize,
+    /// The stable codes of the suppressed diagnostics, and whether a
+    /// diagnostic with the code was suppressed.
+    codes: Vec<(&'static str, bool)>,
+}
+
+/// The suppressions of a GraphQL source.
+#[derive(Debug, Default)]
+struct SourceSuppressions {
+    line_starts: Vec<usize>,
+    suppressions: Vec<Suppression>,
+    /// Diagnostics of the malformed suppression comments.
+    invalid: Vec<Diagnostic>,
+}
+
+impl SourceSuppressions {
+    fn parse(source_location: SourceLocationKey, text: &str) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(text.match_indices('\n').map(|(index, _)| index + 1));
+        if !text.contains(SUPPRESSION_PREFIX) {
+            return Self {
+                line_starts,
+                ..Default::default()
+            };
+        }
+
+        let lines: Vec<&str> = text.split('\n').collect();
+        let mut suppressions = Vec::new();
+        let mut invalid = Vec::new();
+        for (line_index, line) in lines.iter().enumerate() {
+            let comment = line.trim_start();
+            let arguments = match comment
+                .strip_prefix('#')
+                .map(str::trim_start)
+                .and_then(|body| body.strip_prefix(SUPPRESSION_PREFIX))
+            {
+                Some(arguments)
+                    if arguments.is_empty() || arguments.starts_with(char::is_whitespace) =>
+                {
+                    arguments
+                }
+                _ => continue,
+            };
+            let start = line_starts[line_index] + (line.len() - comment.len());
+            let span = Span::new(start as u32, (start + comment.trim_end().len()) as u32);
+            let location = Location::new(source_location, span);
+
+            let mut words = arguments.split_whitespace();
+            let codes = match parse_codes(words.next()) {
+                Ok(codes) => codes,
+                Err(details) => {
+                    invalid.push(invalid_suppression(details, location));
+                    continue;
+                }
+            };
+            if words.next().is_none() {
+                invalid.push(invalid_suppression(
+                    "missing the reason of the suppression".to_string(),
+                    location,
+                ));
+                continue;
+            }
+            let target_line = lines
+                .iter()
+                .enumerate()
+                .skip(line_index + 1)
+                .find(|(_, line)| {
+                    let line = line.trim();
+                    !line.is_empty() && !line.starts_with('#')
+                })
+                .map_or(lines.len(), |(index, _)| index);
+            suppressions.push(Suppression {
+                span,
+                target_line,
+                codes: codes.into_iter().map(|code| (code, false)).collect(),
+            });
+        }
+        Self {
+            line_starts,
+            suppressions,
+            invalid,
+        }
+    }
+
+    /// Marks the suppression of `diagnostic` as used, if there is one.
+    fn suppress(&mut self, diagnostic: &Diagnostic) -> bool {
+        let error_code = match diagnostic.error_code() {
+            Some(error_code) => error_code,
+            None => return false,
+        };
+        let start = diagnostic.location().span().start as usize;
+        let line = match self.line_starts.binary_search(&start) {
+            Ok(line) => line,
+            Err(line) => line - 1,
+        };
+        self.suppressions
+            .iter_mut()
+            .filter(|suppression| suppression.target_line == line)
+            .flat_map(|suppression| suppression.codes.iter_mut())
+            .find(|(code, _)| *code == error_code)
+            .map(|(_, used)| *used = true)
+            .is_some()
+    }
+}
+
+/// The stable codes of the comma separated `codes`, which may also be kinds,
+/// without duplicates.
+fn parse_codes(codes: Option<&str>) -> Result<Vec<&'static str>, String> {
+    let codes =
+        codes.ok_or_else(|| "missing the code of the suppressed diagnostics".to_string())?;
+    let mut stable_codes = Vec::new();
+    for code in codes.split(',').filter(|code| !code.is_empty()) {
+        let stable_code = find_diagnostic_code(code)
+            .ok_or_else(|| format!("unknown code `{}`", code))?
+            .code;
+        if !stable_codes.contains(&stable_code) {
+            stable_codes.push(stable_code);
+        }
+    }
+    Ok(stable_codes)
+}
+
+fn invalid_suppression(details: String, location: Location) -> Diagnostic {
+    Diagnostic::warning(
+        SuppressionMessage::InvalidSuppression { details },
+        location,
+        Vec::new(),
+    )
+}
+
+/// Leaves out the `diagnostics` of `program` suppressed by a comment, and
+/// reports the suppressions of its sources that are unused or malformed.
+pub fn apply_suppressions(
+    root_dir: &Path,
+    program: &Program,
+    diagnostics: Vec<Diagnostic>,
+) -> Vec<Diagnostic> {
+    let source_locations: BTreeSet<SourceLocationKey> = program
+        .operations()
+        .map(|operation| operation.name.location.source_location())
+        .chain(
+            program
+                .fragments()
+                .map(|fragment| fragment.name.location.source_location()),
+        )
+        .chain(
+            diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.location().source_location()),
+        )
+        .filter(|source_location| *source_location != SourceLocationKey::Generated)
+        .collect();
+    let mut sources: FnvHashMap<SourceLocationKey, SourceSuppressions> = source_locations
+        .iter()
+        .filter_map(|source_location| {
+            let source = source_for_location(root_dir, *source_location, &FsSourceReader)?;
+            Some((
+                *source_location,
+                SourceSuppressions::parse(*source_location, &source.to_text_source().text),
+            ))
+        })
+        .collect();
+
+    let mut result: Vec<Diagnostic> = diagnostics
+        .into_iter()
+        .filter(|diagnostic| {
+            !sources
+                .get_mut(&diagnostic.location().source_location())
+                .map_or(false, |source| source.suppress(diagnostic))
+        })
+        .collect();
+    for source_location in source_locations {
+        let source = match sources.remove(&source_location) {
+            Some(source) => source,
+            None => continue,
+        };
+        result.extend(source.invalid);
+        for suppression in source.suppressions {
+            let unused_codes: Vec<&str> = suppression
+                .codes
+                .iter()
+                .filter(|(_, used)| !used)
+                .map(|(code, _)| *code)
+                .collect();
+            if !unused_codes.is_empty() {
+                result.push(Diagnostic::warning(
+                    SuppressionMessage::UnusedSuppression {
+                        codes: unused_codes.join(", "),
+                    },
+                    Location::new(source_location, suppression.span),
+                    vec![DiagnosticTag::UNNECESSARY],
+                ));
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use graphql_ir::ValidationMessage;
+    use intern::string_key::Intern;
+
+    use super::*;
+
+    fn deprecated_field(text: &str, field: &str) -> Diagnostic {
+        let start = text.find(field).unwrap() as u32;
+        Diagnostic::warning(
+            ValidationMessage::DeprecatedField {
+                parent_name: "Query".intern(),
+                field_name: field.intern(),
+                deprecation_reason: None,
+            },
+            Location::new(
+                SourceLocationKey::standalone("Query.graphql"),
+                Span::new(start, start + field.len() as u32),
+            ),
+            Vec::new(),
+        )
+    }
+
+    #[test]
+    fn suppress_the_next_line() {
+        let text = "query Q {\n  # relay-ignore-next-line RELAY1068,DeprecatedField Not deployed\n\n  old\n  older\n}";
+        let mut source =
+            SourceSuppressions::parse(SourceLocationKey::standalone("Query.graphql"), text);
+        assert!(source.invalid.is_empty());
+        assert_eq!(source.suppressions.len(), 1);
+        assert_eq!(source.suppressions[0].target_line, 3);
+        assert_eq!(source.suppressions[0].codes, vec![("RELAY1068", false)]);
+
+        assert!(!source.suppress(&deprecated_field(text, "older")));
+        assert!(source.suppress(&deprecated_field(text, "old\n")));
+        assert_eq!(source.suppressions[0].codes[0], ("RELAY1068", true));
+    }
+
+    #[test]
+    fn invalid_suppressions() {
+        let text = "query Q {\n  # relay-ignore-next-line\n  # relay-ignore-next-line RELAY9999 Reason\n  # relay-ignore-next-line RELAY1068\n  # relay-ignore-next-lines\n  id\n}";
+        let source =
+            SourceSuppressions::parse(SourceLocationKey::standalone("Query.graphql"), text);
+        assert!(source.suppressions.is_empty());
+        let messages: Vec<String> = source
+            .invalid
+            .iter()
+            .map(|diagnostic| diagnostic.message().to_string())
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Invalid suppression, expected `# relay-ignore-next-line <code> <reason>`: missing the code of the suppressed diagnostics.",
+                "Invalid suppression, expected `# relay-ignore-next-line <code> <reason>`: unknown code `RELAY9999`.",
+                "Invalid suppression, expected `# relay-ignore-next-line <code> <reason>`: missing the reason of the suppression.",
+            ]
+        );
+    }
+}
@@ -0,0 +1,166 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Validation that the operations and fragments of the projects of a group
+//! of `uniqueDefinitionNameGroups` have distinct names, for projects whose
+//! artifacts end up in a shared namespace, e.g. a persisted query store keyed
+//! by the names of the operations. The names are only unique within each
+//! project otherwise.
+
+use std::collections::hash_map::Entry;
+
+use common::diagnostic_codes;
+use common::Diagnostic;
+use common::DiagnosticsResult;
+use common::Location;
+use fnv::FnvHashMap;
+use graphql_syntax::ExecutableDefinition;
+use intern::string_key::StringKey;
+use thiserror::Error;
+
+use crate::compiler_state::ProjectName;
+use crate::graphql_asts::GraphQLAsts;
+
+diagnostic_codes! {
+    #[derive(Clone, Debug, Error, Eq, PartialEq, Ord, PartialOrd, Hash)]
+    pub enum UniqueDefinitionNamesMessage {
+        #[error(
+            "Duplicate definitions for `{name}` in the projects `{project_name}` and `{previous_project_name}`. Definition names must be unique across the projects of a group of `uniqueDefinitionNameGroups`."
+        )]
+        #[code("RELAY3003")]
+        CrossProjectDuplicateDefinition {
+            name: StringKey,
+            project_name: ProjectName,
+            previous_project_name: ProjectName,
+        },
+    }
+}
+
+/// Validates that the definitions of the projects of each group have distinct
+/// names. The definitions of a source file shared by several projects of a
+/// group are the same, and aren't duplicates.
+pub fn validate_unique_definition_names(
+    groups: &[Vec<ProjectName>],
+    graphql_asts: &FnvHashMap<ProjectName, GraphQLAsts>,
+) -> DiagnosticsResult<()> {
+    let mut errors = Vec::new();
+    for group in groups {
+        let projects: Vec<_> = group
+            .iter()
+            .filter_map(|project_name| {
+                graphql_asts
+                    .get(project_name)
+                    .map(|asts| (*project_name, asts.executable_definitions().collect()))
+            })
+            .collect();
+        errors.extend(find_duplicates_across_projects(&projects));
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn find_duplicates_across_projects(
+    projects: &[(ProjectName, Vec<&ExecutableDefinition>)],
+) -> Vec<Diagnostic> {
+    // Ordered by project, then by location, so that the errors are reported
+    // on the same definition in every build.
+    let mut definitions: Vec<(usize, Location, StringKey)> = projects
+        .iter()
+        .enumerate()
+        .flat_map(|(project_index, (_, definitions))| {
+            definitions.iter().filter_map(move |definition| {
+                definition.name_identifier().map(|name| {
+                    (
+                        project_index,
+                        definition.location().with_span(name.span),
+                        name.value,
+                    )
+                })
+            })
+        })
+        .collect();
+    definitions.sort();
+
+    let mut index: FnvHashMap<StringKey, (usize, Location)> = FnvHashMap::default();
+    let mut errors = Vec::new();
+    for (project_index, location, name) in definitions {
+        match index.entry(name) {
+            Entry::Vacant(entry) => {
+                entry.insert((project_index, location));
+            }
+            Entry::Occupied(entry) => {
+                let (previous_project_index, previous_location) = *entry.get();
+                // Duplicates within a project are reported by `find_duplicates`
+                if previous_project_index != project_index
+                    && previous_location.source_location() != location.source_location()
+                {
+                    errors.push(
+                        Diagnostic::error(
+                            UniqueDefinitionNamesMessage::CrossProjectDuplicateDefinition {
+                                name,
+                                project_name: projects[project_index].0,
+                                previous_project_name: projects[previous_project_index].0,
+                            },
+                            location,
+                        )
+                        .annotate("previously defined here", previous_location),
+                    );
+                }
+            }
+        }
+    }
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use common::SourceLocationKey;
+    use graphql_syntax::parse_executable;
+    use intern::string_key::Intern;
+
+    use super::*;
+
+    fn definitions(path: &str, text: &str) -> Vec<ExecutableDefinition> {
+        parse_executable(text, SourceLocationKey::standalone(path))
+            .unwrap()
+            .definitions
+    }
+
+    #[test]
+    fn duplicates_across_projects() {
+        let web = definitions("web/App.graphql", "query AppQuery { me { id } }");
+        let mobile = definitions(
+            "mobile/App.graphql",
+            "query AppQuery { me { id } }\nquery MobileQuery { me { id } }",
+        );
+        let shared = definitions("shared/User.graphql", "fragment Shared_user on User { id }");
+        let projects = vec![
+            (
+                "web".intern(),
+                web.iter().chain(shared.iter()).collect::<Vec<_>>(),
+            ),
+            (
+                "mobile".intern(),
+                mobile.iter().chain(shared.iter()).collect(),
+            ),
+        ];
+
+        let messages: Vec<String> = find_duplicates_across_projects(&projects)
+            .iter()
+            .map(|diagnostic| diagnostic.message().to_string())
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Duplicate definitions for `AppQuery` in the projects `mobile` and `web`. Definition names must be unique across the projects of a group of `uniqueDefinitionNameGroups`.",
+            ]
+        );
+    }
+}
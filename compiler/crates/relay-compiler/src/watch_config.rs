@@ -0,0 +1,183 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Debouncing of the changes of the watch mode. Operations changing many
+//! files, like a rebase or a branch switch, produce many change notifications
+//! in a short time. The compiler waits for a quiet period without new changes
+//! before compiling, so that they are compiled in a single build.
+//!
+//! The schema files can also be polled, for the schemas written where the
+//! file source doesn't notify their changes.
+
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde::Serialize;
+use tokio::sync::Notify;
+use tokio::time::timeout;
+use tokio::time::Instant;
+
+/// The `watch` section of the config file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct WatchConfig {
+    /// The build starts once no change was notified for this long, in
+    /// milliseconds.
+    #[serde(default = "default_quiet_period_ms")]
+    pub quiet_period_ms: u64,
+
+    /// The build starts at the latest this long after the first change of
+    /// the batch, in milliseconds, even if changes keep coming.
+    #[serde(default = "default_max_delay_ms")]
+    pub max_delay_ms: u64,
+
+    /// The build starts without waiting for the quiet period once this many
+    /// files changed.
+    #[serde(default)]
+    pub max_batch_files: Option<usize>,
+
+    /// Reads the schema files of the projects again at this interval, in
+    /// milliseconds, to rebuild the projects whose schema changed without
+    /// the file source notifying it.
+    #[serde(default)]
+    pub schema_poll_interval_ms: Option<u64>,
+}
+
+fn default_quiet_period_ms() -> u64 {
+    50
+}
+
+fn default_max_delay_ms() -> u64 {
+    1000
+}
+
+impl Default for WatchConfig {
+    fn default() -> Self {
+        Self {
+            quiet_period_ms: default_quiet_period_ms(),
+            max_delay_ms: default_max_delay_ms(),
+            max_batch_files: None,
+            schema_poll_interval_ms: None,
+        }
+    }
+}
+
+impl WatchConfig {
+    /// Waits after a change was notified until the batch of changes is
+    /// complete: no change was notified during the quiet period, the maximum
+    /// delay elapsed, or `pending_files` reached the maximum batch size.
+    pub(crate) async fn wait_for_batch(&self, notify: &Notify, pending_files: impl Fn() -> usize) {
+        let deadline = Instant::now() + Duration::from_millis(self.max_delay_ms);
+        let quiet_period = Duration::from_millis(self.quiet_period_ms);
+        loop {
+            if let Some(max_batch_files) = self.max_batch_files {
+                if pending_files() >= max_batch_files {
+                    return;
+                }
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return;
+            }
+            if timeout(quiet_period.min(deadline - now), notify.notified())
+                .await
+                .is_err()
+            {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+
+    use super::*;
+
+    /// Notifies a change every `interval`, `count` times.
+    fn notify_changes(
+        notify: &Arc<Notify>,
+        files: &Arc<AtomicUsize>,
+        interval: Duration,
+        count: usize,
+    ) {
+        let notify = Arc::clone(notify);
+        let files = Arc::clone(files);
+        tokio::spawn(async move {
+            for _ in 0..count {
+                tokio::time::sleep(interval).await;
+                files.fetch_add(1, Ordering::SeqCst);
+                notify.notify_one();
+            }
+        });
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_waits_for_quiet_period() {
+        let config = WatchConfig::default();
+        let notify = Arc::new(Notify::new());
+        let files = Arc::new(AtomicUsize::new(1));
+        notify_changes(&notify, &files, Duration::from_millis(20), 10);
+
+        let start = Instant::now();
+        config
+            .wait_for_batch(&notify, || files.load(Ordering::SeqCst))
+            .await;
+        assert_eq!(files.load(Ordering::SeqCst), 11);
+        assert!(start.elapsed() >= Duration::from_millis(250));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_max_delay() {
+        let config = WatchConfig {
+            max_delay_ms: 100,
+            ..Default::default()
+        };
+        let notify = Arc::new(Notify::new());
+        let files = Arc::new(AtomicUsize::new(1));
+        notify_changes(&notify, &files, Duration::from_millis(20), 100);
+
+        let start = Instant::now();
+        config
+            .wait_for_batch(&notify, || files.load(Ordering::SeqCst))
+            .await;
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(100) && elapsed < Duration::from_millis(120));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_max_batch_files() {
+        let config = WatchConfig {
+            max_batch_files: Some(5),
+            ..Default::default()
+        };
+        let notify = Arc::new(Notify::new());
+        let files = Arc::new(AtomicUsize::new(1));
+        notify_changes(&notify, &files, Duration::from_millis(20), 100);
+
+        config
+            .wait_for_batch(&notify, || files.load(Ordering::SeqCst))
+            .await;
+        assert_eq!(files.load(Ordering::SeqCst), 5);
+    }
+
+    #[test]
+    fn test_parse_config() {
+        let config: WatchConfig = serde_json::from_str(r#"{"quietPeriodMs": 200}"#).unwrap();
+        assert_eq!(
+            config,
+            WatchConfig {
+                quiet_period_ms: 200,
+                ..Default::default()
+            }
+        );
+        assert!(serde_json::from_str::<WatchConfig>(r#"{"debounce": 200}"#).is_err());
+    }
+}
@@ -0,0 +1,31 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use fixture_tests::Fixture;
+use relay_compiler::artifact_diff::diff_artifacts;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts: Vec<_> = fixture.content.split("%changed_to%").collect();
+    let (content_a, content_b) = match parts.as_slice() {
+        [content_a, content_b] => (content_a.trim_start(), content_b.trim_start()),
+        _ => panic!("Expected the fixture to have a %changed_to% section."),
+    };
+    let differences = diff_artifacts(content_a, content_b);
+    if differences.is_empty() {
+        return Ok("No differences\n".to_string());
+    }
+    Ok(differences
+        .into_iter()
+        .map(|(cause, location)| {
+            if location.is_empty() {
+                format!("{}\n", cause)
+            } else {
+                format!("{}: {}\n", cause, location)
+            }
+        })
+        .collect())
+}
@@ -0,0 +1,55 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<2ce787889874406700fc9c3444cbc17d>>
+ */
+
+mod artifact_diff;
+
+use artifact_diff::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn assignable_fragment_validator() {
+    let input = include_str!("artifact_diff/fixtures/assignable-fragment-validator.txt");
+    let expected = include_str!("artifact_diff/fixtures/assignable-fragment-validator.expected");
+    test_fixture(transform_fixture, "assignable-fragment-validator.txt", "artifact_diff/fixtures/assignable-fragment-validator.expected", input, expected);
+}
+
+#[test]
+fn changed_selections_and_metadata() {
+    let input = include_str!("artifact_diff/fixtures/changed-selections-and-metadata.txt");
+    let expected = include_str!("artifact_diff/fixtures/changed-selections-and-metadata.expected");
+    test_fixture(transform_fixture, "changed-selections-and-metadata.txt", "artifact_diff/fixtures/changed-selections-and-metadata.expected", input, expected);
+}
+
+#[test]
+fn hoisted_variables() {
+    let input = include_str!("artifact_diff/fixtures/hoisted-variables.txt");
+    let expected = include_str!("artifact_diff/fixtures/hoisted-variables.expected");
+    test_fixture(transform_fixture, "hoisted-variables.txt", "artifact_diff/fixtures/hoisted-variables.expected", input, expected);
+}
+
+#[test]
+fn not_an_artifact() {
+    let input = include_str!("artifact_diff/fixtures/not-an-artifact.txt");
+    let expected = include_str!("artifact_diff/fixtures/not-an-artifact.expected");
+    test_fixture(transform_fixture, "not-an-artifact.txt", "artifact_diff/fixtures/not-an-artifact.expected", input, expected);
+}
+
+#[test]
+fn resolver_modules() {
+    let input = include_str!("artifact_diff/fixtures/resolver-modules.txt");
+    let expected = include_str!("artifact_diff/fixtures/resolver-modules.expected");
+    test_fixture(transform_fixture, "resolver-modules.txt", "artifact_diff/fixtures/resolver-modules.expected", input, expected);
+}
+
+#[test]
+fn typescript_artifact() {
+    let input = include_str!("artifact_diff/fixtures/typescript-artifact.txt");
+    let expected = include_str!("artifact_diff/fixtures/typescript-artifact.expected");
+    test_fixture(transform_fixture, "typescript-artifact.txt", "artifact_diff/fixtures/typescript-artifact.expected", input, expected);
+}
@@ -0,0 +1,24 @@
+# The name of the persisted query is replaced by its md5 hash, and restored in
+# dev builds.
+%file: relay.config.json%
+{
+  "root": ".",
+  "sources": { "src": "test" },
+  "isDevVariableName": "__DEV__",
+  "projects": {
+    "test": {
+      "schema": "schema.graphql",
+      "language": "javascript",
+      "persist": { "url": "https://localhost/persist" },
+      "obfuscateOperationNames": true
+    }
+  }
+}
+%file: src/App.js%
+graphql`
+  query AppQuery {
+    me {
+      name
+    }
+  }
+`;
@@ -0,0 +1,26 @@
+# The artifact of the renamed query is removed, the hand-written files are kept.
+%file: src/App.js%
+graphql`
+  query AppQuery {
+    me {
+      name
+    }
+  }
+`;
+%file: src/__generated__/OldAppQuery.graphql.js%
+/**
+ * @generated SignedSource<<00000000000000000000000000000000>>
+ * @lightSyntaxTransform
+ * @nogrep
+ */
+
+/* eslint-disable */
+
+'use strict';
+
+module.exports = {};
+%file: src/__generated__/mocks.js%
+// Written by hand, next to the artifacts.
+module.exports = {};
+%file: src/utils.js%
+module.exports = {};
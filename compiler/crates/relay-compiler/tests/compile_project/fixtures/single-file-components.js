@@ -0,0 +1,35 @@
+%file: src/App.js%
+graphql`
+  query AppQuery {
+    me {
+      ...Profile_user
+      ...Avatar_user
+    }
+  }
+`;
+%file: src/Profile.vue%
+<template>
+  <div>{{ user.name }}</div>
+</template>
+
+<script>
+import { graphql } from 'react-relay';
+
+const fragment = graphql`
+  fragment Profile_user on User {
+    name
+  }
+`;
+</script>
+%file: src/Avatar.svelte%
+<script>
+  import { graphql } from 'react-relay';
+
+  const fragment = graphql`
+    fragment Avatar_user on User {
+      id
+    }
+  `;
+</script>
+
+<img alt="avatar" />
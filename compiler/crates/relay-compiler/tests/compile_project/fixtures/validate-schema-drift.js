@@ -0,0 +1,32 @@
+# The artifact on disk was generated from another schema.
+%file: relay.config.json%
+{
+  "root": ".",
+  "sources": { "src": "test" },
+  "projects": {
+    "test": {
+      "schema": "schema.graphql",
+      "language": "javascript",
+      "schemaHashComment": true
+    }
+  }
+}
+%file: src/App.js%
+graphql`
+  fragment App_user on User {
+    name
+  }
+`;
+%file: src/__generated__/App_user.graphql.js%
+/**
+ * @generated SignedSource<<00000000000000000000000000000000>>
+ * @relaySchemaHash 00000000000000000000000000000000
+ * @lightSyntaxTransform
+ * @nogrep
+ */
+
+/* eslint-disable */
+
+'use strict';
+
+module.exports = {};
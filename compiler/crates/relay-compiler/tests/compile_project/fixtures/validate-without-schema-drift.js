@@ -0,0 +1,31 @@
+# The artifact on disk is out of date but has no schema hash.
+%file: relay.config.json%
+{
+  "root": ".",
+  "sources": { "src": "test" },
+  "projects": {
+    "test": {
+      "schema": "schema.graphql",
+      "language": "javascript",
+      "schemaHashComment": true
+    }
+  }
+}
+%file: src/App.js%
+graphql`
+  fragment App_user on User {
+    name
+  }
+`;
+%file: src/__generated__/App_user.graphql.js%
+/**
+ * @generated SignedSource<<00000000000000000000000000000000>>
+ * @lightSyntaxTransform
+ * @nogrep
+ */
+
+/* eslint-disable */
+
+'use strict';
+
+module.exports = {};
@@ -0,0 +1,130 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+#[path = "../project_fixture/mod.rs"]
+mod project_fixture;
+
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use common::ConsoleLogger;
+use common::Diagnostic;
+use fixture_tests::Fixture;
+use project_fixture::setup_project;
+use relay_codegen::md5;
+use relay_compiler::compiler::Compiler;
+use relay_compiler::config::ArtifactForPersister;
+use relay_compiler::config::PersistId;
+use relay_compiler::config::PersistResult;
+use relay_compiler::errors::Error;
+use relay_compiler::status_reporter::StatusReporter;
+use relay_compiler::ArtifactValidationWriter;
+use relay_compiler::OperationPersister;
+
+/// Compiles the project of the fixture and prints the diagnostics of the
+/// build followed by the files of the project. Fixtures named `validate-*`
+/// are compiled like with `--validate`, and fixtures named `prune-*` like
+/// with `--prune`. The operations of fixtures named `persist-*` are persisted
+/// with the md5 hash of their text as id, and their artifacts are printed.
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let (project_dir, mut config) = setup_project(fixture)?;
+    let root_dir = project_dir.path();
+    if fixture.file_name.starts_with("validate-") {
+        config.artifact_writer = Box::new(ArtifactValidationWriter::default());
+    }
+    config.prune_stale_artifacts = fixture.file_name.starts_with("prune-");
+    let persist = fixture.file_name.starts_with("persist-");
+    if persist {
+        config.create_operation_persister = Some(Box::new(|project_config| {
+            project_config
+                .persist
+                .as_ref()
+                .map(|_| -> Box<dyn OperationPersister + Send + Sync> { Box::new(Md5Persister) })
+        }));
+    }
+    let reporter = RecordingStatusReporter::default();
+    let diagnostics = Arc::clone(&reporter.diagnostics);
+    config.status_reporter = Box::new(reporter);
+    let compiler = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger));
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let result = runtime.block_on(compiler.compile());
+
+    let mut output = String::new();
+    for diagnostic in diagnostics.lock().unwrap().iter() {
+        output.push_str(&format!("{}\n", diagnostic));
+    }
+    if let Err(error) = result {
+        output.push_str(&format!("{}\n", error));
+    }
+    output.push_str("Files:\n");
+    let mut paths = Vec::new();
+    list_files(root_dir, root_dir, &mut paths)?;
+    paths.sort();
+    for path in &paths {
+        if path != "relay.config.json" && path != "schema.graphql" {
+            output.push_str(&format!("  {}\n", path));
+        }
+    }
+    if persist {
+        for path in paths.iter().filter(|path| path.contains("__generated__")) {
+            let content = fs::read_to_string(root_dir.join(path)).map_err(|err| err.to_string())?;
+            output.push_str(&format!("\n{}:\n{}", path, content));
+        }
+    }
+
+    let canonical_root_dir = root_dir.canonicalize().map_err(|err| err.to_string())?;
+    Ok(output
+        .replace(canonical_root_dir.to_str().unwrap(), "<root>")
+        .replace(root_dir.to_str().unwrap(), "<root>"))
+}
+
+fn list_files(root_dir: &Path, dir: &Path, paths: &mut Vec<String>) -> Result<(), String> {
+    for entry in fs::read_dir(dir).map_err(|err| err.to_string())? {
+        let path = entry.map_err(|err| err.to_string())?.path();
+        if path.is_dir() {
+            list_files(root_dir, &path, paths)?;
+        } else {
+            paths.push(
+                path.strip_prefix(root_dir)
+                    .unwrap()
+                    .to_string_lossy()
+                    .into_owned(),
+            );
+        }
+    }
+    Ok(())
+}
+
+struct Md5Persister;
+
+#[async_trait]
+impl OperationPersister for Md5Persister {
+    async fn persist_artifact(&self, artifact: ArtifactForPersister) -> PersistResult<PersistId> {
+        Ok(md5(&artifact.text))
+    }
+}
+
+#[derive(Default)]
+struct RecordingStatusReporter {
+    diagnostics: Arc<Mutex<Vec<String>>>,
+}
+
+impl StatusReporter for RecordingStatusReporter {
+    fn build_starts(&self) {}
+
+    fn build_completes(&self, diagnostics: &[Diagnostic]) {
+        self.diagnostics
+            .lock()
+            .unwrap()
+            .extend(diagnostics.iter().map(Diagnostic::print_without_source));
+    }
+
+    fn build_errors(&self, _error: &Error) {}
+}
@@ -0,0 +1,58 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<36cfe36fdfa48e31ee9bebd76d78494f>>
+ */
+
+mod compile_project;
+
+use compile_project::transform_fixture;
+use fixture_tests::test_fixture_with_schema;
+
+#[test]
+fn persist_obfuscate_operation_names() {
+    let input = include_str!("compile_project/fixtures/persist-obfuscate-operation-names.js");
+    let expected = include_str!("compile_project/fixtures/persist-obfuscate-operation-names.expected");
+    let schema = Some(include_str!("compile_project/fixtures/schema.graphql"));
+    let extensions = None;
+    test_fixture_with_schema(transform_fixture, "persist-obfuscate-operation-names.js", "compile_project/fixtures/persist-obfuscate-operation-names.expected", input, expected, schema, extensions);
+}
+
+#[test]
+fn prune_stale_artifacts() {
+    let input = include_str!("compile_project/fixtures/prune-stale-artifacts.js");
+    let expected = include_str!("compile_project/fixtures/prune-stale-artifacts.expected");
+    let schema = Some(include_str!("compile_project/fixtures/schema.graphql"));
+    let extensions = None;
+    test_fixture_with_schema(transform_fixture, "prune-stale-artifacts.js", "compile_project/fixtures/prune-stale-artifacts.expected", input, expected, schema, extensions);
+}
+
+#[test]
+fn single_file_components() {
+    let input = include_str!("compile_project/fixtures/single-file-components.js");
+    let expected = include_str!("compile_project/fixtures/single-file-components.expected");
+    let schema = Some(include_str!("compile_project/fixtures/schema.graphql"));
+    let extensions = None;
+    test_fixture_with_schema(transform_fixture, "single-file-components.js", "compile_project/fixtures/single-file-components.expected", input, expected, schema, extensions);
+}
+
+#[test]
+fn validate_schema_drift() {
+    let input = include_str!("compile_project/fixtures/validate-schema-drift.js");
+    let expected = include_str!("compile_project/fixtures/validate-schema-drift.expected");
+    let schema = Some(include_str!("compile_project/fixtures/schema.graphql"));
+    let extensions = None;
+    test_fixture_with_schema(transform_fixture, "validate-schema-drift.js", "compile_project/fixtures/validate-schema-drift.expected", input, expected, schema, extensions);
+}
+
+#[test]
+fn validate_without_schema_drift() {
+    let input = include_str!("compile_project/fixtures/validate-without-schema-drift.js");
+    let expected = include_str!("compile_project/fixtures/validate-without-schema-drift.expected");
+    let schema = Some(include_str!("compile_project/fixtures/schema.graphql"));
+    let extensions = None;
+    test_fixture_with_schema(transform_fixture, "validate-without-schema-drift.js", "compile_project/fixtures/validate-without-schema-drift.expected", input, expected, schema, extensions);
+}
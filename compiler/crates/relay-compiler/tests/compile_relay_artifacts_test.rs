@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<da7b37382824f1ff9dfc3023a2026666>>
+ * @generated SignedSource<<27484ef802d1ec716977182c226463bc>>
  */
 
 mod compile_relay_artifacts;
@@ -593,6 +593,13 @@ fn inline_data_fragment_local_args() {
     test_fixture(transform_fixture, "inline-data-fragment-local-args.graphql", "compile_relay_artifacts/fixtures/inline-data-fragment-local-args.expected", input, expected);
 }
 
+#[test]
+fn inline_data_fragment_nested_local_args() {
+    let input = include_str!("compile_relay_artifacts/fixtures/inline-data-fragment-nested-local-args.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/inline-data-fragment-nested-local-args.expected");
+    test_fixture(transform_fixture, "inline-data-fragment-nested-local-args.graphql", "compile_relay_artifacts/fixtures/inline-data-fragment-nested-local-args.expected", input, expected);
+}
+
 #[test]
 fn inline_fragment_on_abstract_client_type_nested_in_resolver_client_edge() {
     let input = include_str!("compile_relay_artifacts/fixtures/inline-fragment-on-abstract-client-type-nested-in-resolver-client-edge.graphql");
@@ -1167,6 +1174,13 @@ fn relay_resolver_with_fragment_on_client_type() {
     test_fixture(transform_fixture, "relay-resolver-with-fragment-on-client-type.graphql", "compile_relay_artifacts/fixtures/relay-resolver-with-fragment-on-client-type.expected", input, expected);
 }
 
+#[test]
+fn relay_resolver_with_mapped_fragment_args() {
+    let input = include_str!("compile_relay_artifacts/fixtures/relay-resolver-with-mapped-fragment-args.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/relay-resolver-with-mapped-fragment-args.expected");
+    test_fixture(transform_fixture, "relay-resolver-with-mapped-fragment-args.graphql", "compile_relay_artifacts/fixtures/relay-resolver-with-mapped-fragment-args.expected", input, expected);
+}
+
 #[test]
 fn relay_resolver_with_output_type() {
     let input = include_str!("compile_relay_artifacts/fixtures/relay-resolver-with-output-type.graphql");
@@ -1209,6 +1223,13 @@ fn relay_resolvers_with_different_field_args_are_not_merged() {
     test_fixture(transform_fixture, "relay-resolvers-with-different-field-args-are-not-merged.graphql", "compile_relay_artifacts/fixtures/relay-resolvers-with-different-field-args-are-not-merged.expected", input, expected);
 }
 
+#[test]
+fn relay_test_operation_with_resolvers() {
+    let input = include_str!("compile_relay_artifacts/fixtures/relay-test-operation-with-resolvers.graphql");
+    let expected = include_str!("compile_relay_artifacts/fixtures/relay-test-operation-with-resolvers.expected");
+    test_fixture(transform_fixture, "relay-test-operation-with-resolvers.graphql", "compile_relay_artifacts/fixtures/relay-test-operation-with-resolvers.expected", input, expected);
+}
+
 #[test]
 fn required_argument_not_passed_default_value() {
     let input = include_str!("compile_relay_artifacts/fixtures/required_argument_not_passed_default_value.graphql");
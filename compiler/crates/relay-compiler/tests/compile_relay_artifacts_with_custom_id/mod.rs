@@ -86,7 +86,9 @@ pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
         enable_client_edges: FeatureFlag::Enabled,
         skip_printing_nulls: FeatureFlag::Disabled,
         enable_fragment_aliases: FeatureFlag::Enabled,
+        enable_fragment_conditions: FeatureFlag::Disabled,
         compact_query_text: FeatureFlag::Disabled,
+        merge_inline_fragments: FeatureFlag::Disabled,
         use_named_imports_for_relay_resolvers: false,
         relay_resolver_model_syntax_enabled: false,
         relay_resolver_enable_terse_syntax: false,
@@ -103,7 +105,7 @@ pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
         ..Default::default()
     };
 
-    validate(&program, &project_config, &None)
+    validate(&program, &project_config, &None, &[])
         .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
 
     // TODO pass base fragment names
@@ -114,6 +116,7 @@ pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
         Arc::new(ConsoleLogger),
         None,
         None,
+        None,
     )
     .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
 
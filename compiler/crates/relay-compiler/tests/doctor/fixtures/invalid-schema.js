@@ -0,0 +1,12 @@
+%file: schema.graphql%
+type Query {
+  me: Usr
+}
+%file: src/App.js%
+graphql`
+  query AppQuery {
+    me {
+      name
+    }
+  }
+`;
@@ -0,0 +1,28 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+#[path = "../project_fixture/mod.rs"]
+mod project_fixture;
+
+use fixture_tests::Fixture;
+use project_fixture::setup_project;
+use relay_compiler::doctor::run_doctor;
+
+/// Prints the report of the doctor for the project of the fixture. The
+/// Watchman check depends on the machine running the test, so it is left out.
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let (root_dir, config) = setup_project(fixture)?;
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let mut report = runtime.block_on(run_doctor(Ok(config)));
+    report.checks.retain(|check| check.name != "Watchman");
+
+    let canonical_root_dir = root_dir.path().canonicalize().map_err(|err| err.to_string())?;
+    Ok(report
+        .to_string()
+        .replace(canonical_root_dir.to_str().unwrap(), "<root>")
+        .replace(root_dir.path().to_str().unwrap(), "<root>"))
+}
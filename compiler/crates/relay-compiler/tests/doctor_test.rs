@@ -0,0 +1,31 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<5c090b792cebdea1d4e918c7c9c3cd5f>>
+ */
+
+mod doctor;
+
+use doctor::transform_fixture;
+use fixture_tests::test_fixture_with_schema;
+
+#[test]
+fn invalid_schema() {
+    let input = include_str!("doctor/fixtures/invalid-schema.js");
+    let expected = include_str!("doctor/fixtures/invalid-schema.expected");
+    let schema = Some(include_str!("doctor/fixtures/schema.graphql"));
+    let extensions = None;
+    test_fixture_with_schema(transform_fixture, "invalid-schema.js", "doctor/fixtures/invalid-schema.expected", input, expected, schema, extensions);
+}
+
+#[test]
+fn missing_artifacts() {
+    let input = include_str!("doctor/fixtures/missing-artifacts.js");
+    let expected = include_str!("doctor/fixtures/missing-artifacts.expected");
+    let schema = Some(include_str!("doctor/fixtures/schema.graphql"));
+    let extensions = None;
+    test_fixture_with_schema(transform_fixture, "missing-artifacts.js", "doctor/fixtures/missing-artifacts.expected", input, expected, schema, extensions);
+}
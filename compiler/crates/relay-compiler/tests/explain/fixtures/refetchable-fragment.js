@@ -0,0 +1,16 @@
+ProfileQuery
+ProfileRefetchQuery
+%file: src/Profile.js%
+graphql`
+  query ProfileQuery {
+    me {
+      ...Profile_user
+    }
+  }
+`;
+graphql`
+  fragment Profile_user on User
+    @refetchable(queryName: "ProfileRefetchQuery") {
+    name
+  }
+`;
@@ -0,0 +1,30 @@
+AppQuery
+%file: src/App.js%
+graphql`
+  query AppQuery {
+    me {
+      ...AppUser_user
+    }
+  }
+`;
+%file: src/AppUser.js%
+graphql`
+  fragment AppUser_user on User {
+    name
+    friends(first: 10) {
+      ...AppUser_friend
+    }
+  }
+`;
+graphql`
+  fragment AppUser_friend on User {
+    id
+    ...AppUserName_user
+  }
+`;
+%file: src/AppUserName.js%
+graphql`
+  fragment AppUserName_user on User {
+    name
+  }
+`;
@@ -0,0 +1,10 @@
+# expected-to-throw
+UnknownQuery
+%file: src/Known.js%
+graphql`
+  query KnownQuery {
+    me {
+      name
+    }
+  }
+`;
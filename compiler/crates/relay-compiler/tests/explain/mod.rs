@@ -0,0 +1,43 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+#[path = "../project_fixture/mod.rs"]
+mod project_fixture;
+
+use std::sync::Arc;
+
+use common::ConsoleLogger;
+use fixture_tests::Fixture;
+use intern::string_key::Intern;
+use project_fixture::setup_project;
+use relay_compiler::compiler::Compiler;
+
+/// Explains the operations listed in the first document of the fixture, one
+/// per line, in the project of the other documents.
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let operation_names = match fixture.documents().first() {
+        Some(first) if first.name == fixture.file_name => first
+            .content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .collect::<Vec<_>>(),
+        _ => return Err("Expected the names of the operations to explain".to_string()),
+    };
+    let (_root_dir, config) = setup_project(fixture)?;
+    let compiler = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger));
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    let mut explanations = Vec::new();
+    for operation_name in operation_names {
+        let explanation = runtime
+            .block_on(compiler.explain_operation(operation_name.intern()))
+            .map_err(|err| err.to_string())?;
+        explanations.push(explanation.to_string());
+    }
+    Ok(explanations.join("\n"))
+}
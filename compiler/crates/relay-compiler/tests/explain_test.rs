@@ -0,0 +1,40 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<75400346a482ff4ead69bc8c40054634>>
+ */
+
+mod explain;
+
+use explain::transform_fixture;
+use fixture_tests::test_fixture_with_schema;
+
+#[test]
+fn refetchable_fragment() {
+    let input = include_str!("explain/fixtures/refetchable-fragment.js");
+    let expected = include_str!("explain/fixtures/refetchable-fragment.expected");
+    let schema = Some(include_str!("explain/fixtures/schema.graphql"));
+    let extensions = None;
+    test_fixture_with_schema(transform_fixture, "refetchable-fragment.js", "explain/fixtures/refetchable-fragment.expected", input, expected, schema, extensions);
+}
+
+#[test]
+fn transitive_fragments() {
+    let input = include_str!("explain/fixtures/transitive-fragments.js");
+    let expected = include_str!("explain/fixtures/transitive-fragments.expected");
+    let schema = Some(include_str!("explain/fixtures/schema.graphql"));
+    let extensions = None;
+    test_fixture_with_schema(transform_fixture, "transitive-fragments.js", "explain/fixtures/transitive-fragments.expected", input, expected, schema, extensions);
+}
+
+#[test]
+fn unknown_operation() {
+    let input = include_str!("explain/fixtures/unknown-operation.js");
+    let expected = include_str!("explain/fixtures/unknown-operation.expected");
+    let schema = Some(include_str!("explain/fixtures/schema.graphql"));
+    let extensions = None;
+    test_fixture_with_schema(transform_fixture, "unknown-operation.js", "explain/fixtures/unknown-operation.expected", input, expected, schema, extensions);
+}
@@ -0,0 +1,8 @@
+%file: src/App.js%
+graphql`
+  query AppQuery {
+    me {
+      nmae
+    }
+  }
+`;
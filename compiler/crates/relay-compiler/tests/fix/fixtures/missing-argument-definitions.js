@@ -0,0 +1,29 @@
+%file: src/App.js%
+graphql`
+  query AppQuery($first: Int) {
+    me {
+      ...Friends_user @arguments(first: $first)
+      ...Name_user @arguments(first: $first)
+    }
+  }
+`;
+
+%file: src/Friends.js%
+graphql`
+  fragment Friends_user on User {
+    friends(first: $first) {
+      id
+    }
+  }
+`;
+
+%file: src/Name.js%
+graphql`
+  fragment Name_user on User
+    @argumentDefinitions(withName: {type: "Boolean", defaultValue: true}) {
+    name @include(if: $withName)
+    friends(first: $first) {
+      id
+    }
+  }
+`;
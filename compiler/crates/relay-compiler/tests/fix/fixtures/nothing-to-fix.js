@@ -0,0 +1,11 @@
+%file: src/App.js%
+graphql`
+  query AppQuery($first: Int) {
+    me {
+      friends(first: $first) {
+        name
+      }
+      __id: name
+    }
+  }
+`;
@@ -0,0 +1,18 @@
+%file: src/AppUser.js%
+graphql`
+  fragment AppUser_user on User
+    @argumentDefinitions(
+      first: {type: "Int", defaultValue: 10}
+      scale: {type: "Float"}
+    ) {
+    friends(first: $first) {
+      name
+    }
+  }
+`;
+%file: src/AppUserName.js%
+graphql`
+  fragment AppUserName_user on User @argumentDefinitions(scale: {type: "Float"}) {
+    name
+  }
+`;
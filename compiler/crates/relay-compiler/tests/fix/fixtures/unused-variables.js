@@ -0,0 +1,20 @@
+%file: src/App.js%
+graphql`
+  query AppQuery($id: ID!, $first: Int) {
+    me {
+      name
+    }
+  }
+`;
+%file: src/AppFriends.js%
+graphql`
+  query AppFriendsQuery($id: ID!, $first: Int, $skip: Boolean) {
+    node(id: $id) {
+      ... on User {
+        friends(first: $first) {
+          name
+        }
+      }
+    }
+  }
+`;
@@ -0,0 +1,37 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+#[path = "../project_fixture/mod.rs"]
+mod project_fixture;
+
+use std::fs;
+use std::sync::Arc;
+
+use common::ConsoleLogger;
+use fixture_tests::Fixture;
+use project_fixture::project_documents;
+use project_fixture::setup_project;
+use relay_compiler::compiler::Compiler;
+
+/// Applies the safe fixes to the project of the fixture and prints their
+/// summary followed by the fixed documents.
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let (root_dir, config) = setup_project(fixture)?;
+    let compiler = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger));
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let summary = runtime
+        .block_on(compiler.fix())
+        .map_err(|err| err.to_string())?;
+
+    let mut output = summary.to_string();
+    for document in project_documents(fixture) {
+        let content = fs::read_to_string(root_dir.path().join(document.name))
+            .map_err(|err| err.to_string())?;
+        output.push_str(&format!("\n%file: {}%\n{}", document.name, content));
+    }
+    Ok(output)
+}
@@ -0,0 +1,58 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<bc765f5ea0737e60203895ed7a4c7e8d>>
+ */
+
+mod fix;
+
+use fix::transform_fixture;
+use fixture_tests::test_fixture_with_schema;
+
+#[test]
+fn did_you_mean() {
+    let input = include_str!("fix/fixtures/did-you-mean.js");
+    let expected = include_str!("fix/fixtures/did-you-mean.expected");
+    let schema = Some(include_str!("fix/fixtures/schema.graphql"));
+    let extensions = None;
+    test_fixture_with_schema(transform_fixture, "did-you-mean.js", "fix/fixtures/did-you-mean.expected", input, expected, schema, extensions);
+}
+
+#[test]
+fn missing_argument_definitions() {
+    let input = include_str!("fix/fixtures/missing-argument-definitions.js");
+    let expected = include_str!("fix/fixtures/missing-argument-definitions.expected");
+    let schema = Some(include_str!("fix/fixtures/schema.graphql"));
+    let extensions = None;
+    test_fixture_with_schema(transform_fixture, "missing-argument-definitions.js", "fix/fixtures/missing-argument-definitions.expected", input, expected, schema, extensions);
+}
+
+#[test]
+fn nothing_to_fix() {
+    let input = include_str!("fix/fixtures/nothing-to-fix.js");
+    let expected = include_str!("fix/fixtures/nothing-to-fix.expected");
+    let schema = Some(include_str!("fix/fixtures/schema.graphql"));
+    let extensions = None;
+    test_fixture_with_schema(transform_fixture, "nothing-to-fix.js", "fix/fixtures/nothing-to-fix.expected", input, expected, schema, extensions);
+}
+
+#[test]
+fn unused_argument_definitions() {
+    let input = include_str!("fix/fixtures/unused-argument-definitions.js");
+    let expected = include_str!("fix/fixtures/unused-argument-definitions.expected");
+    let schema = Some(include_str!("fix/fixtures/schema.graphql"));
+    let extensions = None;
+    test_fixture_with_schema(transform_fixture, "unused-argument-definitions.js", "fix/fixtures/unused-argument-definitions.expected", input, expected, schema, extensions);
+}
+
+#[test]
+fn unused_variables() {
+    let input = include_str!("fix/fixtures/unused-variables.js");
+    let expected = include_str!("fix/fixtures/unused-variables.expected");
+    let schema = Some(include_str!("fix/fixtures/schema.graphql"));
+    let extensions = None;
+    test_fixture_with_schema(transform_fixture, "unused-variables.js", "fix/fixtures/unused-variables.expected", input, expected, schema, extensions);
+}
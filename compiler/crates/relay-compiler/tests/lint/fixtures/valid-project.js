@@ -0,0 +1,17 @@
+%file: src/App.js%
+graphql`
+  query AppQuery {
+    me {
+      ...AppUser_user
+    }
+  }
+`;
+%file: src/AppUser.js%
+graphql`
+  fragment AppUser_user on User {
+    name
+    friends(first: 10) {
+      id
+    }
+  }
+`;
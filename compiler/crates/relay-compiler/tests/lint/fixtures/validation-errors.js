@@ -0,0 +1,14 @@
+%file: src/App.js%
+graphql`
+  query AppQuery($id: ID!, $first: Int) {
+    me {
+      name
+    }
+  }
+`;
+%file: src/AppUser.js%
+graphql`
+  fragment AppUser_user on User {
+    __id: name
+  }
+`;
@@ -0,0 +1,49 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+#[path = "../project_fixture/mod.rs"]
+mod project_fixture;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common::ConsoleLogger;
+use fixture_tests::Fixture;
+use fixture_tests::FixtureOutput;
+use graphql_test_helpers::diagnostics_to_fixture_diagnostics_with_sources_map;
+use project_fixture::setup_project;
+use relay_compiler::compiler::Compiler;
+use relay_compiler::errors::BuildProjectError;
+use relay_compiler::errors::Error;
+
+/// Lints the project of the fixture and checks that no artifact was written.
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<FixtureOutput, String> {
+    let (root_dir, config) = setup_project(fixture)?;
+    let compiler = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger));
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    let diagnostics = match runtime.block_on(compiler.lint()) {
+        Ok(diagnostics) => diagnostics,
+        Err(Error::BuildProjectsErrors { errors }) => errors
+            .into_iter()
+            .flat_map(|error| match error {
+                BuildProjectError::ValidationErrors { errors, .. } => errors,
+                error => panic!("Unexpected error: {}", error),
+            })
+            .collect(),
+        Err(error) => return Err(error.to_string()),
+    };
+
+    let output = if root_dir.path().join("src/__generated__").exists() {
+        "Artifacts were written"
+    } else {
+        "No artifacts written"
+    };
+    Ok(FixtureOutput::new(output).with_diagnostics(
+        diagnostics_to_fixture_diagnostics_with_sources_map(&HashMap::new(), &diagnostics),
+    ))
+}
@@ -0,0 +1,31 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<af0541cc94036c25c017e0508468a91f>>
+ */
+
+mod lint;
+
+use lint::transform_fixture;
+use fixture_tests::test_fixture_with_schema;
+
+#[test]
+fn valid_project() {
+    let input = include_str!("lint/fixtures/valid-project.js");
+    let expected = include_str!("lint/fixtures/valid-project.expected");
+    let schema = Some(include_str!("lint/fixtures/schema.graphql"));
+    let extensions = None;
+    test_fixture_with_schema(transform_fixture, "valid-project.js", "lint/fixtures/valid-project.expected", input, expected, schema, extensions);
+}
+
+#[test]
+fn validation_errors() {
+    let input = include_str!("lint/fixtures/validation-errors.js");
+    let expected = include_str!("lint/fixtures/validation-errors.expected");
+    let schema = Some(include_str!("lint/fixtures/schema.graphql"));
+    let extensions = None;
+    test_fixture_with_schema(transform_fixture, "validation-errors.js", "lint/fixtures/validation-errors.expected", input, expected, schema, extensions);
+}
@@ -0,0 +1,59 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::fs;
+
+use fixture_tests::Fixture;
+use fixture_tests::FixtureDocument;
+use relay_compiler::config::Config;
+use relay_compiler::FileSourceKind;
+use tempfile::TempDir;
+
+const CONFIG: &str = r#"{
+  "root": ".",
+  "sources": { "src": "test" },
+  "projects": {
+    "test": { "schema": "schema.graphql", "language": "javascript" }
+  }
+}"#;
+
+/// The documents of the fixture that are files of the project: all of them
+/// but the one named after the fixture.
+pub fn project_documents<'a>(fixture: &Fixture<'a>) -> Vec<FixtureDocument<'a>> {
+    fixture
+        .documents()
+        .into_iter()
+        .filter(|document| document.name != fixture.file_name)
+        .collect()
+}
+
+/// Writes the documents of the fixture to a project in a temporary directory,
+/// with the `schema.graphql` of the fixtures directory and a default
+/// `relay.config.json` that a document of the fixture can replace, and loads
+/// its config. The sources are read from the file system.
+pub fn setup_project(fixture: &Fixture<'_>) -> Result<(TempDir, Config), String> {
+    let root_dir = tempfile::tempdir().map_err(|err| err.to_string())?;
+    let mut files = vec![
+        ("relay.config.json", CONFIG),
+        ("schema.graphql", fixture.schema.unwrap()),
+    ];
+    files.extend(
+        project_documents(fixture)
+            .iter()
+            .map(|document| (document.name, document.content)),
+    );
+    for (path, content) in files {
+        let path = root_dir.path().join(path);
+        fs::create_dir_all(path.parent().unwrap()).map_err(|err| err.to_string())?;
+        fs::write(path, content).map_err(|err| err.to_string())?;
+    }
+
+    let mut config =
+        Config::load(root_dir.path().join("relay.config.json")).map_err(|err| err.to_string())?;
+    config.file_source_config = FileSourceKind::WalkDir;
+    Ok((root_dir, config))
+}
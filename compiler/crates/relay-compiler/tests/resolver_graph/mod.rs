@@ -0,0 +1,42 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::build;
+use graphql_ir::Program;
+use graphql_syntax::parse_executable;
+use graphql_test_helpers::diagnostics_to_sorted_string;
+use intern::string_key::Intern;
+use relay_compiler::resolver_graph::resolver_graphs_to_json;
+use relay_compiler::resolver_graph::ResolverGraph;
+use relay_test_schema::get_test_schema_with_extensions;
+
+/// Prints the graph of the resolvers of the `%extensions%` of the fixture,
+/// with the fragments of the fixture, as JSON and as DOT.
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts: Vec<_> = fixture.content.split("%extensions%").collect();
+    let (base, extensions) = match parts.as_slice() {
+        [base, extensions] => (base, extensions),
+        _ => panic!("Expected the fixture to have a `%extensions%` section"),
+    };
+    let schema = get_test_schema_with_extensions(extensions);
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let ast = parse_executable(base, source_location)
+        .map_err(|diagnostics| diagnostics_to_sorted_string(base, &diagnostics))?;
+    let ir = build(&schema, &ast.definitions)
+        .map_err(|diagnostics| diagnostics_to_sorted_string(base, &diagnostics))?;
+    let program = Program::from_definitions(schema, ir);
+
+    let graph = ResolverGraph::new(&program);
+    let dot = graph.to_dot("test");
+    Ok(format!(
+        "{}\n\n{}",
+        resolver_graphs_to_json(&[("test".intern(), graph)]),
+        dot
+    ))
+}
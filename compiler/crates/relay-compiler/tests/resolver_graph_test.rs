@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<a064f6473770e73be9b48d8eccbc714c>>
+ */
+
+mod resolver_graph;
+
+use resolver_graph::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn resolvers() {
+    let input = include_str!("resolver_graph/fixtures/resolvers.graphql");
+    let expected = include_str!("resolver_graph/fixtures/resolvers.expected");
+    test_fixture(transform_fixture, "resolvers.graphql", "resolver_graph/fixtures/resolvers.expected", input, expected);
+}
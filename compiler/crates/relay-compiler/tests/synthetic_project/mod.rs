@@ -0,0 +1,30 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use fixture_tests::Fixture;
+use relay_compiler::bench::generate_synthetic_project;
+use relay_compiler::bench::SyntheticProjectOptions;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let options: serde_json::Value =
+        serde_json::from_str(fixture.content).map_err(|err| err.to_string())?;
+    let size = |key: &str| options[key].as_u64().map(|size| size as usize);
+    let defaults = SyntheticProjectOptions::default();
+    let options = SyntheticProjectOptions {
+        fragments: size("fragments").unwrap_or(defaults.fragments),
+        depth: size("depth").unwrap_or(defaults.depth),
+        abstract_ratio: options["abstractRatio"]
+            .as_f64()
+            .unwrap_or(defaults.abstract_ratio),
+        fragments_per_query: size("fragmentsPerQuery").unwrap_or(defaults.fragments_per_query),
+    };
+    Ok(generate_synthetic_project(&options)
+        .into_iter()
+        .map(|(path, content)| format!("// {}\n{}", path.display(), content))
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
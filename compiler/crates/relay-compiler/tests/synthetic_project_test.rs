@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<499132e747b5a5aeb8e0f83cbde44fc6>>
+ */
+
+mod synthetic_project;
+
+use synthetic_project::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn flat_abstract_fragments() {
+    let input = include_str!("synthetic_project/fixtures/flat-abstract-fragments.json");
+    let expected = include_str!("synthetic_project/fixtures/flat-abstract-fragments.expected");
+    test_fixture(transform_fixture, "flat-abstract-fragments.json", "synthetic_project/fixtures/flat-abstract-fragments.expected", input, expected);
+}
+
+#[test]
+fn small_project() {
+    let input = include_str!("synthetic_project/fixtures/small-project.json");
+    let expected = include_str!("synthetic_project/fixtures/small-project.expected");
+    test_fixture(transform_fixture, "small-project.json", "synthetic_project/fixtures/small-project.expected", input, expected);
+}
@@ -0,0 +1,58 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::fmt::Display;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Configuration of the names of the artifacts, e.g.
+/// `__generated__/MyQuery.graphql.js` by default.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct ArtifactFilenameConfig {
+    /// Suffix of the module names of the artifacts, after the name of the
+    /// definition: `MyQuery.<suffix>`.
+    #[serde(default = "default_suffix")]
+    pub suffix: String,
+
+    /// Extension of the artifacts. Defaults to `js` or `ts` depending on the
+    /// language of the project.
+    #[serde(default)]
+    pub extension: Option<String>,
+
+    /// Name of the directory next to the sources that contains their
+    /// artifacts, when the project has no `output` directory.
+    #[serde(default = "default_directory")]
+    pub directory: String,
+}
+
+fn default_suffix() -> String {
+    "graphql".to_string()
+}
+
+fn default_directory() -> String {
+    "__generated__".to_string()
+}
+
+impl Default for ArtifactFilenameConfig {
+    fn default() -> Self {
+        Self {
+            suffix: default_suffix(),
+            extension: None,
+            directory: default_directory(),
+        }
+    }
+}
+
+impl ArtifactFilenameConfig {
+    /// The name of the module of an artifact, without extension, e.g.
+    /// `MyQuery.graphql`.
+    pub fn module_name(&self, name: impl Display) -> String {
+        format!("{}.{}", name, self.suffix)
+    }
+}
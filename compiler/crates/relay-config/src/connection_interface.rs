@@ -11,7 +11,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 /// Configuration where Relay should expect some fields in the schema.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct ConnectionInterface {
     pub cursor: StringKey,
@@ -5,6 +5,10 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::collections::BTreeMap;
+
+use common::find_diagnostic_code;
+use common::Diagnostic;
 use common::DiagnosticSeverity;
 use serde::Deserialize;
 use serde::Serialize;
@@ -35,19 +39,62 @@ impl From<DiagnosticLevel> for DiagnosticSeverity {
 }
 
 /// Configuration for all diagnostic reporting in the compiler
-#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DiagnosticReportConfig {
     /// Threshold for diagnostics to be critical to the compiler's execution.
     /// All diagnostic with severities at and below this level will cause the
     /// compiler to fatally exit.
     pub critical_level: DiagnosticLevel,
+
+    /// Levels of some kinds of diagnostics, by code (e.g. `RELAY1068`) or kind
+    /// (e.g. `DeprecatedField`), replacing their default severity before the
+    /// `critical_level` is applied. Promoting a rule to `error` makes its
+    /// diagnostics fail the build, and demoting it to `warning` reports them
+    /// without failing it. The errors of the phases other than the
+    /// validations can't be demoted.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub severity_overrides: BTreeMap<String, DiagnosticLevel>,
+}
+
+impl DiagnosticReportConfig {
+    /// The configured severity of `diagnostic`, if its code or kind has an
+    /// override. The code takes precedence over the kind.
+    pub fn severity_override(&self, diagnostic: &Diagnostic) -> Option<DiagnosticSeverity> {
+        if self.severity_overrides.is_empty() {
+            return None;
+        }
+        let kind = diagnostic.code()?;
+        diagnostic
+            .error_code()
+            .and_then(|code| self.severity_overrides.get(code))
+            .or_else(|| self.severity_overrides.get(kind))
+            .map(|level| (*level).into())
+    }
+
+    /// Replaces the severity of the `diagnostics` that have an override.
+    pub fn apply_severity_overrides(&self, diagnostics: &mut [Diagnostic]) {
+        for diagnostic in diagnostics {
+            if let Some(severity) = self.severity_override(diagnostic) {
+                diagnostic.remap_severity(severity);
+            }
+        }
+    }
+
+    /// Whether diagnostics of this code or kind have an override.
+    pub fn has_severity_override(&self, kind: &str) -> bool {
+        self.severity_overrides.contains_key(kind)
+            || find_diagnostic_code(kind).map_or(false, |diagnostic_code| {
+                self.severity_overrides.contains_key(diagnostic_code.code)
+            })
+    }
 }
 
 impl Default for DiagnosticReportConfig {
     fn default() -> Self {
         Self {
             critical_level: DiagnosticLevel::Error,
+            severity_overrides: Default::default(),
         }
     }
 }
@@ -9,14 +9,17 @@
 #![deny(rust_2018_idioms)]
 #![deny(clippy::all)]
 
+mod artifact_filename_config;
 mod connection_interface;
 mod diagnostic_report_config;
 mod js_module_format;
 mod module_import_config;
 mod non_node_id_fields_config;
 mod project_config;
+mod schema_variant_config;
 mod typegen_config;
 
+pub use artifact_filename_config::ArtifactFilenameConfig;
 pub use connection_interface::ConnectionInterface;
 pub use diagnostic_report_config::DiagnosticLevel;
 pub use diagnostic_report_config::DiagnosticReportConfig;
@@ -24,6 +27,7 @@ pub use js_module_format::JsModuleFormat;
 pub use module_import_config::DynamicModuleProvider;
 pub use module_import_config::ModuleImportConfig;
 pub use non_node_id_fields_config::NonNodeIdFieldsConfig;
+pub use project_config::FetchableTypeConfig;
 pub use project_config::LocalPersistAlgorithm;
 pub use project_config::LocalPersistConfig;
 pub use project_config::PersistConfig;
@@ -32,8 +36,12 @@ pub use project_config::ProjectName;
 pub use project_config::RemotePersistConfig;
 pub use project_config::SchemaConfig;
 pub use project_config::SchemaLocation;
+pub use schema_variant_config::parse_schema_removals;
+pub use schema_variant_config::SchemaVariant;
+pub use schema_variant_config::SchemaVariantConfig;
 pub use typegen_config::CustomScalarType;
 pub use typegen_config::CustomScalarTypeImport;
 pub use typegen_config::FlowTypegenConfig;
+pub use typegen_config::TypeNamesConfig;
 pub use typegen_config::TypegenConfig;
 pub use typegen_config::TypegenLanguage;
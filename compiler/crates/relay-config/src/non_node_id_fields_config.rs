@@ -12,7 +12,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 /// Configuration of Relay's validation for `id` fields outside of the `Node` interface.
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct NonNodeIdFieldsConfig {
     /// A map of parent type names to allowed type names for fields named `id`
@@ -30,9 +30,11 @@ use serde::Serialize;
 use serde_json::Value;
 
 use crate::connection_interface::ConnectionInterface;
+use crate::artifact_filename_config::ArtifactFilenameConfig;
 use crate::diagnostic_report_config::DiagnosticReportConfig;
 use crate::module_import_config::ModuleImportConfig;
 use crate::non_node_id_fields_config::NonNodeIdFieldsConfig;
+use crate::schema_variant_config::SchemaVariant;
 use crate::JsModuleFormat;
 use crate::TypegenConfig;
 use crate::TypegenLanguage;
@@ -141,7 +143,7 @@ pub enum SchemaLocation {
     Directory(PathBuf),
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SchemaConfig {
     #[serde(default)]
@@ -153,6 +155,32 @@ pub struct SchemaConfig {
 
     #[serde(default)]
     pub non_node_id_fields: Option<NonNodeIdFieldsConfig>,
+
+    /// The schema is the schema of an Apollo Federation service: the
+    /// federation directives (`@key`, `@external`, ...) are defined when the
+    /// schema doesn't define them, and types extended without being defined
+    /// are treated as definitions.
+    #[serde(default)]
+    pub federation: bool,
+
+    /// Types that are refetched with a root query field taking their
+    /// identifying field, instead of `node(id:)`, keyed by type name. This is
+    /// the project config equivalent of `@fetchable(field_name:)` on a type,
+    /// and takes precedence over it.
+    #[serde(default)]
+    pub fetchable_types: FnvIndexMap<StringKey, FetchableTypeConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct FetchableTypeConfig {
+    /// The `ID` field of the type identifying an object.
+    pub identifying_field: StringKey,
+
+    /// The field of the query type fetching an object by its identifying
+    /// field. Defaults to `fetch__<TypeName>`.
+    #[serde(default)]
+    pub fetch_field: Option<StringKey>,
 }
 
 fn default_node_interface_id_field() -> StringKey {
@@ -165,6 +193,8 @@ impl Default for SchemaConfig {
             connection_interface: ConnectionInterface::default(),
             node_interface_id_field: default_node_interface_id_field(),
             non_node_id_fields: None,
+            federation: false,
+            fetchable_types: Default::default(),
         }
     }
 }
@@ -177,15 +207,35 @@ pub struct ProjectConfig {
     pub shard_output: bool,
     pub shard_strip_regex: Option<Regex>,
     pub schema_extensions: Vec<PathBuf>,
+    pub schema_extensions_from_sources: bool,
+    pub client_schema_output: Option<PathBuf>,
     pub enabled: bool,
     pub schema_location: SchemaLocation,
     pub schema_config: SchemaConfig,
     pub typegen_config: TypegenConfig,
     pub persist: Option<PersistConfig>,
     pub variable_names_comment: bool,
+    pub schema_hash_comment: bool,
+    pub readable_artifacts: bool,
+    /// Prints comments with the source fragment and line of the selections
+    /// in the reader ASTs, for debugging in dev builds.
+    pub debug_source_comments: bool,
+    /// Replaces the names of the persisted operations in their artifacts
+    /// with their persisted id, the readable names are only set in dev builds.
+    pub obfuscate_operation_names: bool,
+    pub no_inline_fragments: Vec<Regex>,
     pub extra: serde_json::Value,
     pub feature_flags: Arc<FeatureFlags>,
     pub test_path_regex: Option<Regex>,
+    /// Directories, relative to the root directory, that may contain the
+    /// operations of the project. Fragments may be defined anywhere.
+    pub operation_directories: Option<Vec<PathBuf>>,
+    /// Values of the flags of the `@featureFlag` directive. Selections with
+    /// a flag that is missing or disabled are removed from the artifacts.
+    pub feature_flag_values: FnvIndexMap<StringKey, bool>,
+    /// Values of the variables of the conditions of fragment definitions,
+    /// for the callers of the fragments that don't define them.
+    pub fragment_condition_defaults: FnvIndexMap<StringKey, bool>,
     pub filename_for_artifact:
         Option<Box<dyn (Fn(SourceLocationKey, StringKey) -> String) + Send + Sync>>,
     pub skip_types_for_artifact: Option<Box<dyn (Fn(SourceLocationKey) -> bool) + Send + Sync>>,
@@ -193,6 +243,9 @@ pub struct ProjectConfig {
     pub js_module_format: JsModuleFormat,
     pub module_import_config: ModuleImportConfig,
     pub diagnostic_report_config: DiagnosticReportConfig,
+    pub artifact_filename: ArtifactFilenameConfig,
+    /// Set on the projects created for the `schemaVariants` of a project.
+    pub schema_variant: Option<SchemaVariant>,
 }
 
 impl Default for ProjectConfig {
@@ -206,20 +259,32 @@ impl Default for ProjectConfig {
             shard_output: false,
             shard_strip_regex: None,
             schema_extensions: vec![],
+            schema_extensions_from_sources: false,
+            client_schema_output: None,
             enabled: true,
             schema_location: SchemaLocation::File(PathBuf::default()),
             schema_config: Default::default(),
             typegen_config: Default::default(),
             persist: None,
             variable_names_comment: false,
+            schema_hash_comment: false,
+            readable_artifacts: false,
+            debug_source_comments: false,
+            obfuscate_operation_names: false,
+            no_inline_fragments: vec![],
             extra: Default::default(),
             test_path_regex: None,
+            operation_directories: None,
+            feature_flag_values: Default::default(),
+            fragment_condition_defaults: Default::default(),
             filename_for_artifact: None,
             skip_types_for_artifact: None,
             rollout: Default::default(),
             js_module_format: Default::default(),
             module_import_config: Default::default(),
             diagnostic_report_config: Default::default(),
+            artifact_filename: Default::default(),
+            schema_variant: None,
         }
     }
 }
@@ -234,21 +299,33 @@ impl Debug for ProjectConfig {
             shard_output,
             shard_strip_regex,
             schema_extensions,
+            schema_extensions_from_sources,
+            client_schema_output,
             enabled,
             schema_location,
             schema_config,
             typegen_config,
             persist,
             variable_names_comment,
+            schema_hash_comment,
+            readable_artifacts,
+            debug_source_comments,
+            obfuscate_operation_names,
+            no_inline_fragments,
             extra,
             feature_flags,
             test_path_regex,
+            operation_directories,
+            feature_flag_values,
+            fragment_condition_defaults,
             filename_for_artifact,
             skip_types_for_artifact,
             rollout,
             js_module_format,
             module_import_config,
             diagnostic_report_config,
+            artifact_filename,
+            schema_variant,
         } = self;
         f.debug_struct("ProjectConfig")
             .field("name", name)
@@ -258,15 +335,28 @@ impl Debug for ProjectConfig {
             .field("shard_output", shard_output)
             .field("shard_strip_regex", shard_strip_regex)
             .field("schema_extensions", schema_extensions)
+            .field(
+                "schema_extensions_from_sources",
+                schema_extensions_from_sources,
+            )
+            .field("client_schema_output", client_schema_output)
             .field("enabled", enabled)
             .field("schema_location", schema_location)
             .field("schema_config", schema_config)
             .field("typegen_config", typegen_config)
             .field("persist", persist)
             .field("variable_names_comment", variable_names_comment)
+            .field("schema_hash_comment", schema_hash_comment)
+            .field("readable_artifacts", readable_artifacts)
+            .field("debug_source_comments", debug_source_comments)
+            .field("obfuscate_operation_names", obfuscate_operation_names)
+            .field("no_inline_fragments", no_inline_fragments)
             .field("extra", extra)
             .field("feature_flags", feature_flags)
             .field("test_path_regex", test_path_regex)
+            .field("operation_directories", operation_directories)
+            .field("feature_flag_values", feature_flag_values)
+            .field("fragment_condition_defaults", fragment_condition_defaults)
             .field(
                 "filename_for_artifact",
                 &if filename_for_artifact.is_some() {
@@ -287,11 +377,104 @@ impl Debug for ProjectConfig {
             .field("js_module_format", js_module_format)
             .field("module_import_config", module_import_config)
             .field("diagnostic_report_config", diagnostic_report_config)
+            .field("artifact_filename", artifact_filename)
+            .field("schema_variant", schema_variant)
             .finish()
     }
 }
 
 impl ProjectConfig {
+    /// Creates the project of a schema variant of this project, which has the
+    /// extensions of the variant in addition to those of this project, and
+    /// artifacts with the name of the variant after their suffix.
+    /// `filename_for_artifact` and `skip_types_for_artifact` aren't copied.
+    pub fn create_schema_variant(
+        &self,
+        name: ProjectName,
+        schema_variant: SchemaVariant,
+        variant_schema_extensions: Vec<PathBuf>,
+    ) -> ProjectConfig {
+        let ProjectConfig {
+            name: _,
+            base,
+            output,
+            extra_artifacts_output,
+            shard_output,
+            shard_strip_regex,
+            schema_extensions,
+            schema_extensions_from_sources,
+            client_schema_output,
+            enabled,
+            schema_location,
+            schema_config,
+            typegen_config,
+            persist,
+            variable_names_comment,
+            schema_hash_comment,
+            readable_artifacts,
+            debug_source_comments,
+            obfuscate_operation_names,
+            no_inline_fragments,
+            extra,
+            feature_flags,
+            test_path_regex,
+            operation_directories,
+            feature_flag_values,
+            fragment_condition_defaults,
+            filename_for_artifact: _,
+            skip_types_for_artifact: _,
+            rollout,
+            js_module_format,
+            module_import_config,
+            diagnostic_report_config,
+            artifact_filename,
+            schema_variant: _,
+        } = self;
+        ProjectConfig {
+            name,
+            base: *base,
+            output: output.clone(),
+            extra_artifacts_output: extra_artifacts_output.clone(),
+            shard_output: *shard_output,
+            shard_strip_regex: shard_strip_regex.clone(),
+            schema_extensions: schema_extensions
+                .iter()
+                .cloned()
+                .chain(variant_schema_extensions)
+                .collect(),
+            schema_extensions_from_sources: *schema_extensions_from_sources,
+            client_schema_output: client_schema_output.clone(),
+            enabled: *enabled,
+            schema_location: schema_location.clone(),
+            schema_config: schema_config.clone(),
+            typegen_config: typegen_config.clone(),
+            persist: persist.clone(),
+            variable_names_comment: *variable_names_comment,
+            schema_hash_comment: *schema_hash_comment,
+            readable_artifacts: *readable_artifacts,
+            debug_source_comments: *debug_source_comments,
+            obfuscate_operation_names: *obfuscate_operation_names,
+            no_inline_fragments: no_inline_fragments.clone(),
+            extra: extra.clone(),
+            feature_flags: Arc::clone(feature_flags),
+            test_path_regex: test_path_regex.clone(),
+            operation_directories: operation_directories.clone(),
+            feature_flag_values: feature_flag_values.clone(),
+            fragment_condition_defaults: fragment_condition_defaults.clone(),
+            filename_for_artifact: None,
+            skip_types_for_artifact: None,
+            rollout: *rollout,
+            js_module_format: *js_module_format,
+            module_import_config: *module_import_config,
+            diagnostic_report_config: diagnostic_report_config.clone(),
+            artifact_filename: ArtifactFilenameConfig {
+                suffix: format!("{}.{}", artifact_filename.suffix, schema_variant.name),
+                ..artifact_filename.clone()
+            },
+            schema_variant: Some(schema_variant),
+        }
+    }
+
     /// This function will create a correct path for an artifact based on the project configuration
     pub fn create_path_for_artifact(
         &self,
@@ -317,7 +500,7 @@ impl ProjectConfig {
             // Otherwise, output into a file relative to the source.
             source_file
                 .get_dir()
-                .join("__generated__")
+                .join(&self.artifact_filename.directory)
                 .join(artifact_file_name)
         }
     }
@@ -330,12 +513,18 @@ impl ProjectConfig {
         let filename = if let Some(filename_for_artifact) = &self.filename_for_artifact {
             filename_for_artifact(source_file, definition_name)
         } else {
-            match &self.typegen_config.language {
-                TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
-                    format!("{}.graphql.js", definition_name)
-                }
-                TypegenLanguage::TypeScript => format!("{}.graphql.ts", definition_name),
-            }
+            let extension = match &self.artifact_filename.extension {
+                Some(extension) => extension.as_str(),
+                None => match &self.typegen_config.language {
+                    TypegenLanguage::Flow | TypegenLanguage::JavaScript => "js",
+                    TypegenLanguage::TypeScript => "ts",
+                },
+            };
+            format!(
+                "{}.{}",
+                self.artifact_filename.module_name(definition_name),
+                extension
+            )
         };
         self.create_path_for_artifact(source_file, filename)
     }
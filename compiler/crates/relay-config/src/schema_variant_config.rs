@@ -0,0 +1,105 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::path::PathBuf;
+
+use intern::string_key::StringKey;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::ProjectName;
+
+/// A variant of the schema of a project, e.g. for a build target that runs
+/// against an older version of the server. The sources of the project are
+/// compiled once more against the variant, to artifacts with the name of the
+/// variant after their suffix: `MyQuery.graphql.<variant>.js`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct SchemaVariantConfig {
+    /// Directories or files with extensions of the schema of the variant, in
+    /// addition to the `schemaExtensions` of the project.
+    #[serde(default)]
+    pub schema_extensions: Vec<PathBuf>,
+
+    /// Files listing the schema coordinates of the types (`User`), fields
+    /// (`User.name`) and enum values (`Color.RED`) that the schema of the
+    /// variant doesn't have, one per line.
+    #[serde(default)]
+    pub schema_removals: Vec<PathBuf>,
+}
+
+/// The schema variant of a project created for one of the `schemaVariants`
+/// of another project.
+#[derive(Debug, Clone)]
+pub struct SchemaVariant {
+    pub name: StringKey,
+    /// The project of which this project is a variant.
+    pub project_name: ProjectName,
+    /// The schema coordinates read from the `schemaRemovals` of the variant.
+    pub removed_coordinates: Vec<String>,
+}
+
+/// Parses the content of a `schemaRemovals` file: a schema coordinate per
+/// line, where blank lines and `#` comments are ignored.
+pub fn parse_schema_removals(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .map(|line| line.split('#').next().unwrap().trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| line.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use intern::string_key::Intern;
+
+    use super::*;
+    use crate::ProjectConfig;
+
+    #[test]
+    fn parse_schema_removals_test() {
+        assert_eq!(
+            parse_schema_removals(
+                "# Not deployed yet\nUser.nickname\n\nColor.RED # Renamed\n  Page\n"
+            ),
+            vec!["User.nickname", "Color.RED", "Page"]
+        );
+    }
+
+    #[test]
+    fn create_schema_variant_test() {
+        let project_config = ProjectConfig {
+            name: "web".intern(),
+            schema_extensions: vec![PathBuf::from("graphql/client")],
+            ..Default::default()
+        };
+        let variant_config = project_config.create_schema_variant(
+            "web_embedded".intern(),
+            SchemaVariant {
+                name: "embedded".intern(),
+                project_name: project_config.name,
+                removed_coordinates: vec!["User.nickname".to_string()],
+            },
+            vec![PathBuf::from("graphql/embedded")],
+        );
+        assert_eq!(variant_config.name, "web_embedded".intern());
+        // The extensions of the variant are added to the ones of the project
+        assert_eq!(
+            variant_config.schema_extensions,
+            vec![
+                PathBuf::from("graphql/client"),
+                PathBuf::from("graphql/embedded")
+            ]
+        );
+        assert_eq!(variant_config.artifact_filename.suffix, "graphql.embedded");
+        let schema_variant = variant_config.schema_variant.unwrap();
+        assert_eq!(schema_variant.project_name, "web".intern());
+        assert_eq!(schema_variant.removed_coordinates, vec!["User.nickname"]);
+        assert!(project_config.schema_variant.is_none());
+    }
+}
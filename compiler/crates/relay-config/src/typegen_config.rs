@@ -5,6 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::fmt::Display;
 use std::path::PathBuf;
 
 use common::ScalarName;
@@ -64,7 +65,7 @@ pub struct CustomScalarTypeImport {
     pub path: PathBuf,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct TypegenConfig {
     /// The desired output language, "flow" or "typescript".
@@ -96,8 +97,9 @@ pub struct TypegenConfig {
     #[serde(default)]
     pub custom_scalar_types: FnvIndexMap<ScalarName, CustomScalarType>,
 
-    /// Require all GraphQL scalar types mapping to be defined, will throw
-    /// if a GraphQL scalar type doesn't have a JS type
+    /// Require all GraphQL scalar types mapping to be defined. The build
+    /// fails with an error for each custom scalar without a JS type, listing
+    /// where the documents select it or use it in the types of variables.
     #[serde(default)]
     pub require_custom_scalar_types: bool,
 
@@ -108,6 +110,184 @@ pub struct TypegenConfig {
     /// This option enables emitting es modules artifacts.
     #[serde(default)]
     pub eager_es_modules: bool,
+
+    /// A map from interfaces and unions to all of their implementing types,
+    /// example: { "Actor": ["User", "Page"] }
+    /// Selections on these types that cover every implementing type are
+    /// typed as an exhaustive union, without a `%other` branch. The build
+    /// fails if the implementing types in the schema differ from the listed
+    /// ones, so new implementations aren't silently unhandled.
+    #[serde(default)]
+    pub closed_abstract_types: FnvIndexMap<StringKey, Vec<StringKey>>,
+
+    /// Emit a `<OperationName>.mock.js` (or `.ts`) file next to each operation
+    /// artifact with a `createMock<OperationName>Data(overrides)` function,
+    /// that returns typed default data for the operation, for use in tests.
+    #[serde(default)]
+    pub generate_mock_factories: bool,
+
+    /// Emit a `{key}$modules` type for each `@match` field, that maps the
+    /// module of each `@module` fragment to the props of its component, for
+    /// the loaders of the components. The name of the type is configured by
+    /// `typeNames.matchModules`.
+    #[serde(default)]
+    pub generate_match_modules_types: bool,
+
+    /// Export an `is<TypeName>(data)` type guard from the artifacts for each
+    /// object type that a selection on an interface or a union refines to,
+    /// to narrow the data by its `__typename`.
+    #[serde(default)]
+    pub generate_typename_guards: bool,
+
+    /// Path, relative to the root directory, of a file to generate with a
+    /// `RelayOperationRegistry` type, that maps the name of each operation of
+    /// the project to its `variables` and `response` types, for helpers that
+    /// are generic over the name of an operation.
+    #[serde(default)]
+    pub operation_registry: Option<PathBuf>,
+
+    /// Make the variables with a default value, and the fields of input
+    /// objects with a default value in the schema, optional in the generated
+    /// types even if their type is non-null.
+    #[serde(default)]
+    pub optional_variables_with_defaults: bool,
+
+    /// The type of the errors of the fields with `@catch`, which are typed
+    /// as `{ok: true, value: T} | {ok: false, errors: ReadonlyArray<TError>}`.
+    /// Either the name of a type or a type to import, example:
+    /// "FieldError" or {"name": "FieldError", "path": "../src/FieldError"}.
+    /// The errors are `mixed` (`unknown` in TypeScript) by default.
+    #[serde(default)]
+    pub catch_error_type: Option<CustomScalarType>,
+
+    /// The type of the context passed to the Relay Resolvers, after their
+    /// arguments, as the name of the type and the module to import it from,
+    /// example: {"name": "ResolverContext", "path": "../src/ResolverContext"}
+    #[serde(default)]
+    pub resolver_context_type: Option<CustomScalarTypeImport>,
+
+    /// Templates of the names of the generated types, see `TypeNamesConfig`.
+    #[serde(default)]
+    pub type_names: TypeNamesConfig,
+}
+
+/// Templates of the names of the types exported and imported by the
+/// generated files, where `{name}` is replaced with the name of the operation
+/// or fragment, example: { "data": "T{name}$data" }
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields, rename_all = "camelCase", default)]
+pub struct TypeNamesConfig {
+    /// The data of an operation or fragment, `{name}$data` by default.
+    pub data: String,
+    /// The variables of an operation, `{name}$variables` by default.
+    pub variables: String,
+    /// The raw response of an operation with `@raw_response_type`,
+    /// `{name}$rawResponse` by default.
+    pub raw_response: String,
+    /// The initial payload of an operation with `@raw_response_type` and
+    /// `@defer` or `@stream`, `{name}$initialPayload` by default.
+    pub initial_payload: String,
+    /// The subsequent payloads of an operation with `@raw_response_type` and
+    /// `@defer` or `@stream`, `{name}$incrementalPayload` by default.
+    pub incremental_payload: String,
+    /// The raw response of a `@module` fragment, in the raw response of an
+    /// operation with `@match` fields, `{name}` by default.
+    pub module_raw_response: String,
+    /// The props of the components of the `@module` fragments of a `@match`
+    /// field by module name, where `{name}` is the key of the `@match`,
+    /// `{name}$modules` by default.
+    pub match_modules: String,
+    /// The key of a fragment, `{name}$key` by default.
+    pub fragment_key: String,
+    /// The opaque type of a fragment, `{name}$fragmentType` by default.
+    pub fragment_type: String,
+    /// The type of an operation with its variables and response, `{name}` by
+    /// default.
+    pub operation: String,
+}
+
+impl Default for TypeNamesConfig {
+    fn default() -> Self {
+        TypeNamesConfig {
+            data: "{name}$data".to_string(),
+            variables: "{name}$variables".to_string(),
+            raw_response: "{name}$rawResponse".to_string(),
+            initial_payload: "{name}$initialPayload".to_string(),
+            incremental_payload: "{name}$incrementalPayload".to_string(),
+            module_raw_response: "{name}".to_string(),
+            match_modules: "{name}$modules".to_string(),
+            fragment_key: "{name}$key".to_string(),
+            fragment_type: "{name}$fragmentType".to_string(),
+            operation: "{name}".to_string(),
+        }
+    }
+}
+
+impl TypeNamesConfig {
+    pub fn data(&self, name: impl Display) -> String {
+        apply_template(&self.data, name)
+    }
+
+    pub fn variables(&self, name: impl Display) -> String {
+        apply_template(&self.variables, name)
+    }
+
+    pub fn raw_response(&self, name: impl Display) -> String {
+        apply_template(&self.raw_response, name)
+    }
+
+    pub fn initial_payload(&self, name: impl Display) -> String {
+        apply_template(&self.initial_payload, name)
+    }
+
+    pub fn incremental_payload(&self, name: impl Display) -> String {
+        apply_template(&self.incremental_payload, name)
+    }
+
+    pub fn module_raw_response(&self, name: impl Display) -> String {
+        apply_template(&self.module_raw_response, name)
+    }
+
+    pub fn match_modules(&self, name: impl Display) -> String {
+        apply_template(&self.match_modules, name)
+    }
+
+    pub fn fragment_key(&self, name: impl Display) -> String {
+        apply_template(&self.fragment_key, name)
+    }
+
+    pub fn fragment_type(&self, name: impl Display) -> String {
+        apply_template(&self.fragment_type, name)
+    }
+
+    pub fn operation(&self, name: impl Display) -> String {
+        apply_template(&self.operation, name)
+    }
+
+    /// The templates without a `{name}` placeholder, that would give every
+    /// operation or fragment the same type names.
+    pub fn templates_without_name(&self) -> Vec<&str> {
+        [
+            &self.data,
+            &self.variables,
+            &self.raw_response,
+            &self.initial_payload,
+            &self.incremental_payload,
+            &self.module_raw_response,
+            &self.match_modules,
+            &self.fragment_key,
+            &self.fragment_type,
+            &self.operation,
+        ]
+        .into_iter()
+        .filter(|template| !template.contains("{name}"))
+        .map(String::as_str)
+        .collect()
+    }
+}
+
+fn apply_template(template: &str, name: impl Display) -> String {
+    template.replace("{name}", &name.to_string())
 }
 
 #[derive(Default, Debug, Serialize, Deserialize, Clone, Copy)]
@@ -119,4 +299,11 @@ pub struct FlowTypegenConfig {
     /// from breaking.
     #[serde(default)]
     pub no_future_proof_enums: bool,
+
+    /// Emit a Flow Enum for each enum of the schema used by the artifacts, in
+    /// a `<EnumName>.graphql.js` module of its own, instead of string literal
+    /// unions. The Flow Enums require `babel-plugin-transform-flow-enums` and
+    /// the `flow-enums-runtime` package.
+    #[serde(default)]
+    pub use_flow_enums: bool,
 }
@@ -5,6 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use common::diagnostic_codes;
 use common::DiagnosticDisplay;
 use common::InterfaceName;
 use common::WithDiagnosticData;
@@ -15,142 +16,235 @@ use thiserror::Error;
 use crate::ON_INTERFACE_FIELD;
 use crate::ON_TYPE_FIELD;
 
-#[derive(Clone, Debug, Error, Eq, PartialEq, Ord, PartialOrd, Hash)]
-pub enum ErrorMessages {
-    #[error("Unexpected docblock field \"@{field_name}\"")]
-    UnknownField { field_name: StringKey },
-
-    #[error("Unexpected duplicate docblock field \"@{field_name}\"")]
-    DuplicateField { field_name: StringKey },
-
-    #[error(
-        "Unexpected free text. Free text in a @RelayResolver docblock is treated as the field's human readable description. Only one description is permitted."
-    )]
-    MultipleDescriptions,
-
-    #[error("Missing docblock field \"@{field_name}\"")]
-    MissingField { field_name: StringKey },
-
-    #[error("Expected docblock field \"@{field_name}\" to have specified a value.")]
-    MissingFieldValue { field_name: StringKey },
-
-    #[error(
-        "Unexpected `onType` and `onInterface`. Only one of these docblock fields should be defined on a given @RelayResolver."
-    )]
-    UnexpectedOnTypeAndOnInterface,
-
-    #[error(
-        "Expected either `onType` or `onInterface` to be defined in a @RelayResolver docblock."
-    )]
-    ExpectedOnTypeOrOnInterface,
-
-    #[error(
-        "Unexpected `edgeTo` and `outputType`. Only one of these docblock fields should be defined on a given @RelayResolver."
-    )]
-    UnexpectedEdgeToAndOutputType,
-
-    // The rest of this sentence is expected to be supplied by `.annotate`.
-    #[error("Unexpected conflicting argument name. This field argument")]
-    ConflictingArguments,
-
-    #[error("Unexpected non-nullable type given in `@edgeTo`.")]
-    UnexpectedNonNullableEdgeTo,
-
-    #[error("Unexpected non-nullable item in list type given in `@edgeTo`.")]
-    UnexpectedNonNullableItemInListEdgeTo,
-
-    #[error(
-        "The type specified in the fragment (`{fragment_type_condition}`) and the type specified in @onInterface (`{interface_type}`) are different. Please make sure these are exactly the same."
-    )]
-    MismatchRootFragmentTypeConditionOnInterface {
-        fragment_type_condition: StringKey,
-        interface_type: StringKey,
-    },
-
-    #[error(
-        "The type specified in the fragment (`{fragment_type_condition}`) and the type specified in @onType (`{type_name}`) are different. Please make sure these are exactly the same."
-    )]
-    MismatchRootFragmentTypeConditionOnType {
-        fragment_type_condition: StringKey,
-        type_name: StringKey,
-    },
-
-    #[error(
-        "The type specified in the fragment (`{fragment_type_condition}`) and the parent type (`{type_name}`) are different. Please make sure these are exactly the same."
-    )]
-    MismatchRootFragmentTypeConditionTerseSyntax {
-        fragment_type_condition: StringKey,
-        type_name: StringKey,
-    },
-
-    #[error(
-        "Unexpected plural server type in `@edgeTo` field. Currently Relay Resolvers only support plural `@edgeTo` if the type is defined via Client Schema Extensions."
-    )]
-    ClientEdgeToPluralServerType,
-
-    #[error("Defining arguments with default values for resolver fields is not supported, yet.")]
-    ArgumentDefaultValuesNoSupported,
-
-    #[error(
-        "Unexpected Relay Resolver for a field which is defined in parent interface. The field `{field_name}` is defined by `{interface_name}`. Relay does not yet support interfaces where different subtypes implement the same field using different Relay Resolvers. As a workaround consider defining Relay Resolver field directly on the interface and checking the `__typename` field to have special handling for different concrete types."
-    )]
-    ResolverImplementingInterfaceField {
-        field_name: StringKey,
-        interface_name: InterfaceName,
-    },
-
-    #[error(
-        "Unexpected character `{found}`. Expected @RelayResolver field to either be a GraphQL typename, or a field definition of the form `ParentType.field_name: ReturnType`."
-    )]
-    UnexpectedNonDot { found: char },
-
-    #[error(
-        "Unexpected character `{found}`. Terse @RelayResolver syntax, where a field is defined in a single line using the `ParentType.field_name: ReturnType` shorthand, is not enabled in your project's config."
-    )]
-    UnexpectedTerseSyntax { found: char },
-
-    #[error(
-        "Unexpected docblock field `{field_name}`. This field is not allowed in combination with terse @RelayResolver syntax, where a field is defined in a single line using the `ParentType.field_name: ReturnType` shorthand."
-    )]
-    UnexpectedFieldInTerseSyntax { field_name: StringKey },
+diagnostic_codes! {
+    #[derive(Clone, Debug, Error, Eq, PartialEq, Ord, PartialOrd, Hash)]
+    pub enum ErrorMessages {
+        #[error("Unexpected docblock field \"@{field_name}\"")]
+        #[code("RELAY9001")]
+        UnknownField { field_name: StringKey },
+
+        #[error("Unexpected duplicate docblock field \"@{field_name}\"")]
+        #[code("RELAY9002")]
+        DuplicateField { field_name: StringKey },
+
+        #[error(
+            "Unexpected free text. Free text in a @RelayResolver docblock is treated as the field's human readable description. Only one description is permitted."
+        )]
+        #[code("RELAY9003")]
+        MultipleDescriptions,
+
+        #[error("Missing docblock field \"@{field_name}\"")]
+        #[code("RELAY9004")]
+        MissingField { field_name: StringKey },
+
+        #[error("Expected docblock field \"@{field_name}\" to have specified a value.")]
+        #[code("RELAY9005")]
+        MissingFieldValue { field_name: StringKey },
+
+        #[error(
+            "Unexpected `onType` and `onInterface`. Only one of these docblock fields should be defined on a given @RelayResolver."
+        )]
+        #[code("RELAY9006")]
+        UnexpectedOnTypeAndOnInterface,
+
+        #[error(
+            "Expected either `onType` or `onInterface` to be defined in a @RelayResolver docblock."
+        )]
+        #[code("RELAY9007")]
+        ExpectedOnTypeOrOnInterface,
+
+        #[error(
+            "Unexpected `edgeTo` and `outputType`. Only one of these docblock fields should be defined on a given @RelayResolver."
+        )]
+        #[code("RELAY9008")]
+        UnexpectedEdgeToAndOutputType,
+
+        #[error(
+            "Unexpected type `{field_argument_type}` for the argument `{argument_name}` of a resolver field, which is passed to the argument `{argument_name}` of type `{fragment_argument_type}` of its root fragment. The values of the field argument must be valid for the fragment argument."
+        )]
+        #[code("RELAY9009")]
+        IncompatibleFragmentArgumentType {
+            argument_name: StringKey,
+            field_argument_type: StringKey,
+            fragment_argument_type: StringKey,
+        },
+
+        #[error("Unexpected non-nullable type given in `@edgeTo`.")]
+        #[code("RELAY9010")]
+        UnexpectedNonNullableEdgeTo,
+
+        #[error("Unexpected non-nullable item in list type given in `@edgeTo`.")]
+        #[code("RELAY9011")]
+        UnexpectedNonNullableItemInListEdgeTo,
+
+        #[error(
+            "The type specified in the fragment (`{fragment_type_condition}`) and the type specified in @onInterface (`{interface_type}`) are different. Please make sure these are exactly the same."
+        )]
+        #[code("RELAY9012")]
+        MismatchRootFragmentTypeConditionOnInterface {
+            fragment_type_condition: StringKey,
+            interface_type: StringKey,
+        },
+
+        #[error(
+            "The type specified in the fragment (`{fragment_type_condition}`) and the type specified in @onType (`{type_name}`) are different. Please make sure these are exactly the same."
+        )]
+        #[code("RELAY9013")]
+        MismatchRootFragmentTypeConditionOnType {
+            fragment_type_condition: StringKey,
+            type_name: StringKey,
+        },
+
+        #[error(
+            "The type specified in the fragment (`{fragment_type_condition}`) and the parent type (`{type_name}`) are different. Please make sure these are exactly the same."
+        )]
+        #[code("RELAY9014")]
+        MismatchRootFragmentTypeConditionTerseSyntax {
+            fragment_type_condition: StringKey,
+            type_name: StringKey,
+        },
+
+        #[error(
+            "Unexpected plural server type in `@edgeTo` field. Currently Relay Resolvers only support plural `@edgeTo` if the type is defined via Client Schema Extensions."
+        )]
+        #[code("RELAY9015")]
+        ClientEdgeToPluralServerType,
+
+        #[error("Defining arguments with default values for resolver fields is not supported, yet.")]
+        #[code("RELAY9016")]
+        ArgumentDefaultValuesNoSupported,
+
+        #[error(
+            "Unexpected type `{type_name}` for the argument `{argument_name}` of a resolver field. The arguments of resolver fields must be of input types: scalars, enums, input objects, or lists of them."
+        )]
+        #[code("RELAY9017")]
+        ArgumentTypeNotInput {
+            argument_name: StringKey,
+            type_name: StringKey,
+        },
+
+        #[error(
+            "Unexpected Relay Resolver for a field which is defined in parent interface. The field `{field_name}` is defined by `{interface_name}`. Relay does not yet support interfaces where different subtypes implement the same field using different Relay Resolvers. As a workaround consider defining Relay Resolver field directly on the interface and checking the `__typename` field to have special handling for different concrete types."
+        )]
+        #[code("RELAY9018")]
+        ResolverImplementingInterfaceField {
+            field_name: StringKey,
+            interface_name: InterfaceName,
+        },
+
+        #[error(
+            "Unexpected Relay Resolver for a field which is already defined by `{type_name}`. Relay Resolvers on the interface `{interface_name}` add the field `{field_name}` to the interface and to all of the types that implement it, so none of them can define a field with the same name."
+        )]
+        #[code("RELAY9019")]
+        ResolverInterfaceFieldConflict {
+            field_name: StringKey,
+            interface_name: InterfaceName,
+            type_name: StringKey,
+        },
+
+        #[error(
+            "Unexpected character `{found}`. Expected @RelayResolver field to either be a GraphQL typename, or a field definition of the form `ParentType.field_name: ReturnType`."
+        )]
+        #[code("RELAY9020")]
+        UnexpectedNonDot { found: char },
+
+        #[error(
+            "Unexpected character `{found}`. Terse @RelayResolver syntax, where a field is defined in a single line using the `ParentType.field_name: ReturnType` shorthand, is not enabled in your project's config."
+        )]
+        #[code("RELAY9021")]
+        UnexpectedTerseSyntax { found: char },
+
+        #[error(
+            "Unexpected docblock field `{field_name}`. This field is not allowed in combination with terse @RelayResolver syntax, where a field is defined in a single line using the `ParentType.field_name: ReturnType` shorthand."
+        )]
+        #[code("RELAY9022")]
+        UnexpectedFieldInTerseSyntax { field_name: StringKey },
+
+        #[error(
+            "Unexpected return type `{type_name}` for the resolver field `{field_name}`. The return type of a resolver field must be an output type: a scalar, an enum, an object, an interface, or a list of them."
+        )]
+        #[code("RELAY9023")]
+        TerseReturnTypeNotOutput {
+            field_name: StringKey,
+            type_name: StringKey,
+        },
+
+        #[error(
+            "Unexpected non-nullable return type for the resolver field `{field_name}`. `{type_name}` has an `id`, so the field is an edge to an object that may be missing from the store, and neither the field nor the items of a plural field can be non-nullable."
+        )]
+        #[code("RELAY9024")]
+        TerseNonNullableEdgeTo {
+            field_name: StringKey,
+            type_name: StringKey,
+        },
+
+        #[error(
+            "Unexpected plural return type for the resolver field `{field_name}`. `{type_name}` is a server type with an `id`, but Relay Resolvers only support plural edges to types defined via Client Schema Extensions."
+        )]
+        #[code("RELAY9025")]
+        TersePluralEdgeToServerType {
+            field_name: StringKey,
+            type_name: StringKey,
+        },
+
+        #[error(
+            "Unexpected docblock field `@{field_name}`. This field is not allowed in combination with `@scalar`, which defines a custom scalar for the opaque values returned by Relay Resolvers."
+        )]
+        #[code("RELAY9026")]
+        UnexpectedFieldOnScalarType { field_name: StringKey },
+
+        #[error(
+            "Unexpected resolver field on `{type_name}`. Relay Resolver fields can only be defined on object types and interfaces, such as the types of `@weak` resolvers, but not on scalars, like the ones defined with `@scalar`, enums, unions or input objects."
+        )]
+        #[code("RELAY9027")]
+        ResolverFieldOnInvalidType { type_name: StringKey },
+    }
 }
 
-#[derive(Clone, Debug, Error, Eq, PartialEq, Ord, PartialOrd, Hash)]
-pub enum ErrorMessagesWithData {
-    #[error(
-        "Invalid interface given for `onInterface`. \"{interface_name}\" is not an existing GraphQL interface.{suggestions}", suggestions = did_you_mean(suggestions))]
-    InvalidOnInterface {
-        interface_name: StringKey,
-        suggestions: Vec<StringKey>,
-    },
-
-    #[error("Invalid type given for `onType`. \"{type_name}\" is not an existing GraphQL type.{suggestions}", suggestions = did_you_mean(suggestions))]
-    InvalidOnType {
-        type_name: StringKey,
-        suggestions: Vec<StringKey>,
-    },
-
-    #[error(
-        "Found `@onType` docblock field referring to an interface. Did you mean `@onInterface`?"
-    )]
-    OnTypeForInterface,
-
-    #[error(
-        "Found `@onInterface` docblock field referring to an object type. Did you mean `@onType`?"
-    )]
-    OnInterfaceForType,
-
-    #[error("Fragment \"{fragment_name}\" not found.{suggestions}", suggestions = did_you_mean(suggestions))]
-    FragmentNotFound {
-        fragment_name: StringKey,
-        suggestions: Vec<StringKey>,
-    },
-
-    #[error("The \"{type_name}\" is not an existing GraphQL type.{suggestions}", suggestions = did_you_mean(suggestions))]
-    TypeNotFound {
-        type_name: StringKey,
-        suggestions: Vec<StringKey>,
-    },
+diagnostic_codes! {
+    #[derive(Clone, Debug, Error, Eq, PartialEq, Ord, PartialOrd, Hash)]
+    pub enum ErrorMessagesWithData {
+        #[error(
+            "Invalid interface given for `onInterface`. \"{interface_name}\" is not an existing GraphQL interface.{suggestions}", suggestions = did_you_mean(suggestions))]
+        #[code("RELAY9028")]
+        InvalidOnInterface {
+            interface_name: StringKey,
+            suggestions: Vec<StringKey>,
+        },
+
+        #[error("Invalid type given for `onType`. \"{type_name}\" is not an existing GraphQL type.{suggestions}", suggestions = did_you_mean(suggestions))]
+        #[code("RELAY9029")]
+        InvalidOnType {
+            type_name: StringKey,
+            suggestions: Vec<StringKey>,
+        },
+
+        #[error(
+            "Found `@onType` docblock field referring to an interface. Did you mean `@onInterface`?"
+        )]
+        #[code("RELAY9030")]
+        OnTypeForInterface,
+
+        #[error(
+            "Found `@onInterface` docblock field referring to an object type. Did you mean `@onType`?"
+        )]
+        #[code("RELAY9031")]
+        OnInterfaceForType,
+
+        #[error("Fragment \"{fragment_name}\" not found.{suggestions}", suggestions = did_you_mean(suggestions))]
+        #[code("RELAY9032")]
+        FragmentNotFound {
+            fragment_name: StringKey,
+            suggestions: Vec<StringKey>,
+        },
+
+        #[error("The \"{type_name}\" is not an existing GraphQL type.{suggestions}", suggestions = did_you_mean(suggestions))]
+        #[code("RELAY9033")]
+        TypeNotFound {
+            type_name: StringKey,
+            suggestions: Vec<StringKey>,
+        },
+    }
 }
 
 impl WithDiagnosticData for ErrorMessagesWithData {
@@ -78,6 +78,8 @@ lazy_static! {
     static ref DEPRECATED_REASON_ARGUMENT_NAME: ArgumentName = ArgumentName("reason".intern());
     static ref HAS_OUTPUT_TYPE_ARGUMENT_NAME: ArgumentName =
         ArgumentName("has_output_type".intern());
+    static ref MAPPED_FRAGMENT_ARGUMENTS_ARGUMENT_NAME: ArgumentName =
+        ArgumentName("mapped_fragment_arguments".intern());
     pub(crate) static ref RESOLVER_VALUE_SCALAR_NAME: StringKey = "RelayResolverValue".intern();
     static ref RESOLVER_MODEL_INSTANCE_FIELD_NAME: StringKey = "__relay_model_instance".intern();
     static ref MODEL_CUSTOM_SCALAR_TYPE_SUFFIX: StringKey = "Model".intern();
@@ -219,6 +221,11 @@ trait ResolverIr {
     fn live(&self) -> Option<IrField>;
     fn named_import(&self) -> Option<StringKey>;
 
+    /// The field arguments which are also passed to the root fragment.
+    fn mapped_fragment_arguments(&self) -> Vec<StringKey> {
+        Vec::new()
+    }
+
     fn to_graphql_schema_ast(
         &self,
         schema_info: SchemaInfo<'_, '_>,
@@ -274,6 +281,15 @@ trait ResolverIr {
                 root_fragment.fragment.map(|x| x.0),
             ));
 
+            let mapped_fragment_arguments = self.mapped_fragment_arguments();
+            if !mapped_fragment_arguments.is_empty() {
+                arguments.push(string_list_argument(
+                    MAPPED_FRAGMENT_ARGUMENTS_ARGUMENT_NAME.0,
+                    mapped_fragment_arguments,
+                    root_fragment.fragment.location,
+                ));
+            }
+
             if let Some(inject_fragment_data) = root_fragment.inject_fragment_data {
                 match inject_fragment_data {
                     FragmentDataInjectionMode::Field(field_name) => {
@@ -290,31 +306,10 @@ trait ResolverIr {
             arguments.push(true_argument(LIVE_ARGUMENT_NAME.0, live_field.key_location))
         }
 
-        let schema = schema_info.schema;
-
         if let Some(output_type) = self.output_type() {
             match output_type {
                 OutputType::Pending(type_) => {
-                    let schema_type = schema.get_type(type_.item.inner().name.value);
-                    let fields = match schema_type {
-                        Some(Type::Object(id)) => {
-                            let object = schema.object(id);
-                            Some(&object.fields)
-                        }
-                        Some(Type::Interface(id)) => {
-                            let interface = schema.interface(id);
-                            Some(&interface.fields)
-                        }
-                        _ => None,
-                    };
-                    let is_edge_to = fields.map_or(false, |fields| {
-                        fields.iter().any(|id| {
-                            schema.field(*id).name.item
-                                == schema_info.config.node_interface_id_field
-                        })
-                    });
-
-                    if !is_edge_to {
+                    if !is_edge_to(schema_info, type_.item.inner().name.value) {
                         // If terse resolver does not return strong object (edge)
                         // it should be `@outputType` resolver
                         arguments.push(true_argument(
@@ -432,6 +427,51 @@ trait ResolverTypeDefinitionIr: ResolverIr {
         definitions
     }
 
+    /// The arguments of the field must be of input types of the schema
+    /// (scalars, enums, input objects or lists of them): the values passed
+    /// at the call sites are validated against these types, and passed to the
+    /// resolver as is.
+    fn validate_field_argument_types(
+        &self,
+        schema_info: SchemaInfo<'_, '_>,
+    ) -> DiagnosticsResult<()> {
+        let schema = schema_info.schema;
+        let arguments = match self.field_arguments() {
+            Some(arguments) => arguments,
+            None => return Ok(()),
+        };
+        let mut errors = vec![];
+        for argument in &arguments.items {
+            let type_name = argument.type_.inner().name.value;
+            let location = Location::new(self.location().source_location(), argument.type_.span());
+            match schema.get_type(type_name) {
+                Some(type_) if type_.is_input_type() => {}
+                Some(_) => errors.push(Diagnostic::error(
+                    ErrorMessages::ArgumentTypeNotInput {
+                        argument_name: argument.name.value,
+                        type_name,
+                    },
+                    location,
+                )),
+                None => {
+                    let suggester = GraphQLSuggestions::new(schema);
+                    errors.push(Diagnostic::error_with_data(
+                        ErrorMessagesWithData::TypeNotFound {
+                            type_name,
+                            suggestions: suggester.input_type_suggestions(type_name),
+                        },
+                        location,
+                    ));
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     // When defining a resolver on an object or interface, we must be sure that this
     // field is not defined on any parent interface because this could lead to a case where
     // someone tries to read the field in an fragment on that interface. In order to support
@@ -464,6 +504,46 @@ trait ResolverTypeDefinitionIr: ResolverIr {
         Ok(())
     }
 
+    // A resolver on an interface adds the field to the interface, and to all of
+    // the objects and interfaces that implement it. Since the interface can be
+    // a server interface, make sure that none of these types already defines
+    // the field, either in the server schema or in a client schema extension.
+    fn validate_interface_implementations(
+        &self,
+        schema_info: SchemaInfo<'_, '_>,
+        interface_id: InterfaceID,
+    ) -> DiagnosticsResult<()> {
+        let schema = schema_info.schema;
+        let interface = schema.interface(interface_id);
+        let field_name = self.field_name().value;
+
+        let implementing_interfaces = schema
+            .interfaces()
+            .filter(|i| i.interfaces.contains(&interface_id))
+            .filter_map(|i| schema.get_type(i.name.item.0));
+        let conflicting_type = std::iter::once(Type::Interface(interface_id))
+            .chain(implementing_interfaces)
+            .chain(
+                interface
+                    .implementing_objects
+                    .iter()
+                    .map(|object_id| Type::Object(*object_id)),
+            )
+            .find(|type_| schema.named_field(*type_, field_name).is_some());
+
+        match conflicting_type {
+            Some(type_) => Err(vec![Diagnostic::error(
+                ErrorMessages::ResolverInterfaceFieldConflict {
+                    field_name,
+                    interface_name: interface.name(),
+                    type_name: schema.get_type_name(type_),
+                },
+                self.location().with_span(self.field_name().span),
+            )]),
+            None => Ok(()),
+        }
+    }
+
     fn object_definitions(
         &self,
         object: &Object,
@@ -518,15 +598,20 @@ trait ResolverTypeDefinitionIr: ResolverIr {
         }])
     }
 
+    /// The arguments of the root fragment, except the ones mapped to field
+    /// arguments, which the field defines.
     fn fragment_argument_definitions(&self) -> Option<List<InputValueDefinition>> {
+        let mapped_fragment_arguments = self.mapped_fragment_arguments();
         self.fragment_arguments().as_ref().map(|args| {
             List::generated(
                 args.iter()
+                    .filter(|arg| !mapped_fragment_arguments.contains(&arg.name.value))
                     .map(|arg| InputValueDefinition {
                         name: arg.name.clone(),
                         type_: arg.type_.clone(),
                         default_value: arg.default_value.clone(),
                         directives: vec![],
+                        description: None,
                     })
                     .collect::<Vec<_>>(),
             )
@@ -552,6 +637,8 @@ impl ResolverIr for TerseRelayResolverIr {
         schema_info: SchemaInfo<'_, '_>,
     ) -> DiagnosticsResult<Vec<TypeSystemDefinition>> {
         let schema = schema_info.schema;
+        self.validate_field_argument_types(schema_info)?;
+        self.validate_return_type(schema_info)?;
 
         if let Some(type_) = schema.get_type(self.type_.item) {
             match type_ {
@@ -561,13 +648,21 @@ impl ResolverIr for TerseRelayResolverIr {
                 }
                 Type::Interface(interface_id) => {
                     let interface = schema.interface(interface_id);
+                    self.validate_interface_implementations(schema_info, interface_id)?;
                     return Ok(self.interface_definitions(
                         interface.name,
                         interface_id,
                         schema_info,
                     ));
                 }
-                _ => panic!("Terser syntax is only supported on non-input objects or interfaces."),
+                _ => {
+                    return Err(vec![Diagnostic::error(
+                        ErrorMessages::ResolverFieldOnInvalidType {
+                            type_name: self.type_.item,
+                        },
+                        self.type_.location,
+                    )]);
+                }
             }
         }
 
@@ -616,6 +711,58 @@ impl ResolverIr for TerseRelayResolverIr {
     fn named_import(&self) -> Option<StringKey> {
         self.named_import
     }
+
+    fn mapped_fragment_arguments(&self) -> Vec<StringKey> {
+        mapped_fragment_arguments(self.field_arguments(), self.fragment_arguments())
+    }
+}
+
+impl TerseRelayResolverIr {
+    /// The return type of the terse syntax may have list and non-null
+    /// modifiers, e.g. `[Type!]!`. Its named type must be an output type, and
+    /// edges to objects with an `id` must be nullable, like `@edgeTo`.
+    fn validate_return_type(&self, schema_info: SchemaInfo<'_, '_>) -> DiagnosticsResult<()> {
+        let schema = schema_info.schema;
+        let field_name = self.field.name.value;
+        let type_name = self.field.type_.inner().name.value;
+        let location = Location::new(self.location.source_location(), self.field.type_.span());
+        // Undefined types are reported when the schema is extended.
+        let type_ = match schema.get_type(type_name) {
+            Some(type_) => type_,
+            None => return Ok(()),
+        };
+        if type_.is_input_object() {
+            return Err(vec![Diagnostic::error(
+                ErrorMessages::TerseReturnTypeNotOutput {
+                    field_name,
+                    type_name,
+                },
+                location,
+            )]);
+        }
+        if !is_edge_to(schema_info, type_name) {
+            return Ok(());
+        }
+        if has_non_null_modifier(&self.field.type_) {
+            return Err(vec![Diagnostic::error(
+                ErrorMessages::TerseNonNullableEdgeTo {
+                    field_name,
+                    type_name,
+                },
+                location,
+            )]);
+        }
+        if matches!(self.field.type_, TypeAnnotation::List(_)) && !schema.is_extension_type(type_) {
+            return Err(vec![Diagnostic::error(
+                ErrorMessages::TersePluralEdgeToServerType {
+                    field_name,
+                    type_name,
+                },
+                location,
+            )]);
+        }
+        Ok(())
+    }
 }
 
 impl ResolverTypeDefinitionIr for TerseRelayResolverIr {
@@ -670,6 +817,7 @@ impl ResolverIr for RelayResolverIr {
                 }
             }
         }
+        self.validate_field_argument_types(schema_info)?;
         match self.on {
             On::Type(PopulatedIrField {
                 key_location,
@@ -710,6 +858,7 @@ impl ResolverIr for RelayResolverIr {
                             schema_info,
                             &schema.interface(interface_type).interfaces,
                         )?;
+                        self.validate_interface_implementations(schema_info, interface_type)?;
                         return Ok(self.interface_definitions(
                             value.map(InterfaceName),
                             interface_type,
@@ -766,6 +915,10 @@ impl ResolverIr for RelayResolverIr {
     fn named_import(&self) -> Option<StringKey> {
         self.named_import
     }
+
+    fn mapped_fragment_arguments(&self) -> Vec<StringKey> {
+        mapped_fragment_arguments(self.field_arguments(), self.fragment_arguments())
+    }
 }
 
 impl ResolverTypeDefinitionIr for RelayResolverIr {
@@ -838,6 +991,7 @@ impl ResolverIr for StrongObjectIr {
                 arguments: None,
             }],
             fields: Some(List::generated(fields)),
+            description: None,
         });
 
         Ok(vec![type_])
@@ -884,6 +1038,9 @@ pub struct WeakObjectIr {
     pub type_name: PopulatedIrField,
     pub description: Option<WithLocation<StringKey>>,
     pub deprecated: Option<IrField>,
+    /// Set by `@scalar`: the type is a custom scalar for the opaque values
+    /// returned by resolvers, instead of an object type wrapping them.
+    pub scalar: Option<IrField>,
     pub location: Location,
 }
 
@@ -938,17 +1095,18 @@ impl WeakObjectIr {
                 directives: vec![],
                 description: self.description.map(as_string_node),
             }])),
+            description: None,
         })
     }
 
     // Generate a custom scalar definition based on the exported type.
-    fn instance_scalar_type_definition(&self) -> TypeSystemDefinition {
+    fn instance_scalar_type_definition(&self, scalar_name: StringKey) -> TypeSystemDefinition {
         let span = self.type_name.value.location.span();
         TypeSystemDefinition::ScalarTypeDefinition(ScalarTypeDefinition {
             name: Identifier {
                 span: *span,
                 token: dummy_token(span),
-                value: self.model_type_name(),
+                value: scalar_name,
             },
             directives: vec![ConstantDirective {
                 span: *span,
@@ -979,6 +1137,7 @@ impl WeakObjectIr {
                     },
                 ])),
             }],
+            description: None,
         })
     }
 
@@ -996,8 +1155,15 @@ impl WeakObjectIr {
 
 impl ResolverIr for WeakObjectIr {
     fn definitions(&self, _: SchemaInfo<'_, '_>) -> DiagnosticsResult<Vec<TypeSystemDefinition>> {
+        if self.scalar.is_some() {
+            // Resolvers return the values of the type as is, typed with the
+            // exported type, and they can only be selected as leaves.
+            return Ok(vec![
+                self.instance_scalar_type_definition(self.type_name.value.item),
+            ]);
+        }
         Ok(vec![
-            self.instance_scalar_type_definition(),
+            self.instance_scalar_type_definition(self.model_type_name()),
             self.type_definition(),
         ])
     }
@@ -1044,6 +1210,30 @@ fn string_argument(name: StringKey, value: WithLocation<StringKey>) -> ConstantA
     }
 }
 
+fn string_list_argument(
+    name: StringKey,
+    values: Vec<StringKey>,
+    location: Location,
+) -> ConstantArgument {
+    let span = location.span();
+    ConstantArgument {
+        span: *span,
+        name: string_key_as_identifier(name),
+        colon: dummy_token(span),
+        value: ConstantValue::List(List::generated(
+            values
+                .into_iter()
+                .map(|value| {
+                    ConstantValue::String(StringNode {
+                        token: dummy_token(span),
+                        value,
+                    })
+                })
+                .collect(),
+        )),
+    }
+}
+
 fn true_argument(name: StringKey, location: Location) -> ConstantArgument {
     let span = location.span();
     ConstantArgument {
@@ -1097,6 +1287,23 @@ fn dummy_token(span: &Span) -> Token {
     }
 }
 
+/// The names of the field arguments which are also arguments of the root
+/// fragment, in the order of the field arguments.
+fn mapped_fragment_arguments(
+    field_arguments: Option<&List<InputValueDefinition>>,
+    fragment_arguments: Option<&Vec<Argument>>,
+) -> Vec<StringKey> {
+    match (field_arguments, fragment_arguments) {
+        (Some(field_arguments), Some(fragment_arguments)) => field_arguments
+            .items
+            .iter()
+            .filter(|arg| fragment_arguments.named(arg.name.value).is_some())
+            .map(|arg| arg.name.value)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
 fn get_root_fragment_for_object(object: Option<&Object>) -> Option<RootFragment> {
     if object?
         .directives
@@ -1120,3 +1327,33 @@ fn get_root_fragment_for_object(object: Option<&Object>) -> Option<RootFragment>
         None
     }
 }
+
+/// Whether a resolver returning `type_name` is an edge to an object with an
+/// `id`, rather than a resolver with an output type.
+fn is_edge_to(schema_info: SchemaInfo<'_, '_>, type_name: StringKey) -> bool {
+    let schema = schema_info.schema;
+    let fields = match schema.get_type(type_name) {
+        Some(Type::Object(id)) => {
+            let object = schema.object(id);
+            Some(&object.fields)
+        }
+        Some(Type::Interface(id)) => {
+            let interface = schema.interface(id);
+            Some(&interface.fields)
+        }
+        _ => None,
+    };
+    fields.map_or(false, |fields| {
+        fields
+            .iter()
+            .any(|id| schema.field(*id).name.item == schema_info.config.node_interface_id_field)
+    })
+}
+
+fn has_non_null_modifier(type_: &TypeAnnotation) -> bool {
+    match type_ {
+        TypeAnnotation::Named(_) => false,
+        TypeAnnotation::List(list) => has_non_null_modifier(&list.type_),
+        TypeAnnotation::NonNull(_) => true,
+    }
+}
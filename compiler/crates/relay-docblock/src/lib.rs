@@ -69,6 +69,7 @@ lazy_static! {
     static ref ROOT_FRAGMENT_FIELD: StringKey = "rootFragment".intern();
     static ref OUTPUT_TYPE_FIELD: StringKey = "outputType".intern();
     static ref WEAK_FIELD: StringKey = "weak".intern();
+    static ref SCALAR_FIELD: StringKey = "scalar".intern();
     static ref EMPTY_STRING: StringKey = "".intern();
     static ref ARGUMENT_DEFINITIONS: DirectiveName = DirectiveName("argumentDefinitions".intern());
     static ref ARGUMENT_TYPE: StringKey = "type".intern();
@@ -133,6 +134,7 @@ impl RelayResolverParser {
                 *LIVE_FIELD,
                 *OUTPUT_TYPE_FIELD,
                 *WEAK_FIELD,
+                *SCALAR_FIELD,
             ],
             options,
         }
@@ -220,19 +222,28 @@ impl RelayResolverParser {
             .transpose()?
             .flatten();
 
-        // Validate that the field arguments don't collide with the fragment arguments.
+        // A field argument with the name of a fragment argument is passed to
+        // the root fragment as the value of that argument, so its values must
+        // be valid for the type of the fragment argument.
         if let (Some(field_arguments), Some(fragment_definition), Some(fragment_arguments)) =
             (&field_arguments, &fragment_definition, &fragment_arguments)
         {
             for field_arg in &field_arguments.items {
                 if let Some(fragment_arg) = fragment_arguments.named(field_arg.name.value) {
+                    if is_assignable_to(&field_arg.type_, &fragment_arg.type_) {
+                        continue;
+                    }
                     self.errors.push(
                         Diagnostic::error(
-                            ErrorMessages::ConflictingArguments,
+                            ErrorMessages::IncompatibleFragmentArgumentType {
+                                argument_name: field_arg.name.value,
+                                field_argument_type: field_arg.type_.to_string().intern(),
+                                fragment_argument_type: fragment_arg.type_.to_string().intern(),
+                            },
                             Location::new(source_location, field_arg.name.span),
                         )
                         .annotate(
-                            "conflicts with this fragment argument",
+                            "the fragment argument is defined here",
                             fragment_definition
                                 .location
                                 .with_span(fragment_arg.name.span),
@@ -663,7 +674,8 @@ impl RelayResolverParser {
             definitions_in_file,
         )? {
             Ok(DocblockIr::TerseRelayResolver(terse_resolver))
-        } else if self.fields.get(&WEAK_FIELD).is_some() {
+        } else if self.fields.get(&WEAK_FIELD).is_some() || self.fields.get(&SCALAR_FIELD).is_some()
+        {
             self.parse_weak_type(ast_location, type_)
                 .map(DocblockIr::WeakObjectType)
         } else {
@@ -761,6 +773,7 @@ impl RelayResolverParser {
             *EDGE_TO_FIELD,
             *OUTPUT_TYPE_FIELD,
             *WEAK_FIELD,
+            *SCALAR_FIELD,
         ] {
             if let Some(field) = self.fields.get(forbidden_field_name) {
                 self.errors.push(Diagnostic::error(
@@ -808,16 +821,50 @@ impl RelayResolverParser {
     }
 
     fn parse_weak_type(
-        &self,
+        &mut self,
         ast_location: Location,
         type_: PopulatedIrField,
     ) -> ParseResult<WeakObjectIr> {
         // TODO: Validate that no incompatible docblock fields are used.
+        let scalar = self.fields.get(&SCALAR_FIELD).copied();
+        if scalar.is_some() {
+            // The type is only a custom scalar wrapping the opaque value, so
+            // it has no fields to describe or to deprecate.
+            for forbidden_field_name in &[*DEPRECATED_FIELD, *WEAK_FIELD, *LIVE_FIELD] {
+                if let Some(field) = self.fields.get(forbidden_field_name) {
+                    self.errors.push(Diagnostic::error(
+                        ErrorMessages::UnexpectedFieldOnScalarType {
+                            field_name: *forbidden_field_name,
+                        },
+                        field.key_location,
+                    ));
+                }
+            }
+        }
         Ok(WeakObjectIr {
             type_name: type_,
             description: self.description,
             deprecated: self.fields.get(&DEPRECATED_FIELD).copied(),
+            scalar,
             location: ast_location,
         })
     }
 }
+
+/// Whether the values of `type_` are valid for `target_type`: the types are
+/// the same, except that non-null types are valid for the nullable ones.
+fn is_assignable_to(type_: &TypeAnnotation, target_type: &TypeAnnotation) -> bool {
+    match (type_, target_type) {
+        (TypeAnnotation::NonNull(of), TypeAnnotation::NonNull(target_of)) => {
+            is_assignable_to(&of.type_, &target_of.type_)
+        }
+        (TypeAnnotation::NonNull(of), _) => is_assignable_to(&of.type_, target_type),
+        (TypeAnnotation::List(of), TypeAnnotation::List(target_of)) => {
+            is_assignable_to(&of.type_, &target_of.type_)
+        }
+        (TypeAnnotation::Named(named), TypeAnnotation::Named(target_named)) => {
+            named.name.value == target_named.name.value
+        }
+        _ => false,
+    }
+}
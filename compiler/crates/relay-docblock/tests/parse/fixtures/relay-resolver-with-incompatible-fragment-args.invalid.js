@@ -11,7 +11,7 @@
  * @RelayResolver
  *
  * @onType User
- * @fieldName greeting(salutation: String!)
+ * @fieldName greeting(salutation: String)
  * @rootFragment myRootFragment
  *
  */
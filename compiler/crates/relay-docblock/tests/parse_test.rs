@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<72de4990b5083582ce28bb5e7f3503d6>>
+ * @generated SignedSource<<a8df3a54fdfc550d559899cab0d1bf2a>>
  */
 
 mod parse;
@@ -166,13 +166,6 @@ fn relay_resolver_with_args_syntax_error_invalid() {
     test_fixture(transform_fixture, "relay-resolver-with-args-syntax-error.invalid.js", "parse/fixtures/relay-resolver-with-args-syntax-error.invalid.expected", input, expected);
 }
 
-#[test]
-fn relay_resolver_with_conflicting_args_invalid() {
-    let input = include_str!("parse/fixtures/relay-resolver-with-conflicting-args.invalid.js");
-    let expected = include_str!("parse/fixtures/relay-resolver-with-conflicting-args.invalid.expected");
-    test_fixture(transform_fixture, "relay-resolver-with-conflicting-args.invalid.js", "parse/fixtures/relay-resolver-with-conflicting-args.invalid.expected", input, expected);
-}
-
 #[test]
 fn relay_resolver_with_field_and_fragment_args() {
     let input = include_str!("parse/fixtures/relay-resolver-with-field-and-fragment-args.js");
@@ -201,6 +194,13 @@ fn relay_resolver_with_fragment_invalid() {
     test_fixture(transform_fixture, "relay-resolver-with-fragment.invalid.js", "parse/fixtures/relay-resolver-with-fragment.invalid.expected", input, expected);
 }
 
+#[test]
+fn relay_resolver_with_incompatible_fragment_args_invalid() {
+    let input = include_str!("parse/fixtures/relay-resolver-with-incompatible-fragment-args.invalid.js");
+    let expected = include_str!("parse/fixtures/relay-resolver-with-incompatible-fragment-args.invalid.expected");
+    test_fixture(transform_fixture, "relay-resolver-with-incompatible-fragment-args.invalid.js", "parse/fixtures/relay-resolver-with-incompatible-fragment-args.invalid.expected", input, expected);
+}
+
 #[test]
 fn relay_resolver_with_output_type() {
     let input = include_str!("parse/fixtures/relay-resolver-with-output-type.js");
@@ -0,0 +1,23 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+// expected-to-throw
+
+/**
+ * @RelayResolver
+ *
+ * @onInterface Actor
+ * @fieldName alternate_name
+ * @rootFragment myRootFragment
+ *
+ */
+
+graphql`
+  fragment myRootFragment on Actor {
+    name
+  }
+`
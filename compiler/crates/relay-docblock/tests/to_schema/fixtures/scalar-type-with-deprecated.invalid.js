@@ -0,0 +1,19 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+// expected-to-throw
+
+/**
+ * @RelayResolver Money
+ * @deprecated Use Price instead
+ * @scalar
+ */
+
+export type Money = {
+  amount: number,
+  currency: string,
+};
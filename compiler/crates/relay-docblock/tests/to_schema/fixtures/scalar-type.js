@@ -0,0 +1,34 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/**
+ * @RelayResolver Money
+ *
+ * @scalar
+ */
+
+/**
+ * @RelayResolver User.balance: Money
+ * @rootFragment myRootFragment
+ */
+
+export type Money = {
+  amount: number,
+  currency: string,
+};
+
+graphql`
+  fragment myRootFragment on User {
+    name
+  }
+`
+
+// %extensions%
+
+graphql`
+  scalar Money
+`;
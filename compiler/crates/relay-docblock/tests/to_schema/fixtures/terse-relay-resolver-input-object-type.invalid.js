@@ -0,0 +1,19 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+// expected-to-throw
+
+/**
+ * @RelayResolver User.next_stop: WayPoint
+ * @rootFragment myRootFragment
+ */
+
+graphql`
+  fragment myRootFragment on User {
+    name
+  }
+`
@@ -0,0 +1,35 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/**
+ * @RelayResolver User.nicknames: [String!]!
+ * @rootFragment myRootFragment
+ */
+
+/**
+ * @RelayResolver User.lucky_number: Int!
+ * @rootFragment myRootFragment
+ */
+
+/**
+ * @RelayResolver User.todos: [ClientTodo]
+ * @rootFragment myRootFragment
+ */
+
+graphql`
+  fragment myRootFragment on User {
+    name
+  }
+`
+
+// %extensions%
+
+graphql`
+  type ClientTodo {
+    id: ID!
+  }
+`;
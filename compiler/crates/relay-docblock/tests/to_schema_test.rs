@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<407145d8046bc95eeeb591420b2d57d9>>
+ * @generated SignedSource<<13959b54d03c6cebefee746adf5f7869>>
  */
 
 mod to_schema;
@@ -110,6 +110,20 @@ fn relay_resolver_on_invalid_type_invalid() {
     test_fixture(transform_fixture, "relay-resolver-on-invalid-type.invalid.js", "to_schema/fixtures/relay-resolver-on-invalid-type.invalid.expected", input, expected);
 }
 
+#[test]
+fn relay_resolver_on_server_interface() {
+    let input = include_str!("to_schema/fixtures/relay-resolver-on-server-interface.js");
+    let expected = include_str!("to_schema/fixtures/relay-resolver-on-server-interface.expected");
+    test_fixture(transform_fixture, "relay-resolver-on-server-interface.js", "to_schema/fixtures/relay-resolver-on-server-interface.expected", input, expected);
+}
+
+#[test]
+fn relay_resolver_on_server_interface_conflicting_with_implementation_invalid() {
+    let input = include_str!("to_schema/fixtures/relay-resolver-on-server-interface-conflicting-with-implementation.invalid.js");
+    let expected = include_str!("to_schema/fixtures/relay-resolver-on-server-interface-conflicting-with-implementation.invalid.expected");
+    test_fixture(transform_fixture, "relay-resolver-on-server-interface-conflicting-with-implementation.invalid.js", "to_schema/fixtures/relay-resolver-on-server-interface-conflicting-with-implementation.invalid.expected", input, expected);
+}
+
 #[test]
 fn relay_resolver_on_type_with_interface_invalid() {
     let input = include_str!("to_schema/fixtures/relay-resolver-on-type-with-interface.invalid.js");
@@ -145,6 +159,27 @@ fn relay_resolver_with_field_args() {
     test_fixture(transform_fixture, "relay-resolver-with-field-args.js", "to_schema/fixtures/relay-resolver-with-field-args.expected", input, expected);
 }
 
+#[test]
+fn relay_resolver_with_input_object_args() {
+    let input = include_str!("to_schema/fixtures/relay-resolver-with-input-object-args.js");
+    let expected = include_str!("to_schema/fixtures/relay-resolver-with-input-object-args.expected");
+    test_fixture(transform_fixture, "relay-resolver-with-input-object-args.js", "to_schema/fixtures/relay-resolver-with-input-object-args.expected", input, expected);
+}
+
+#[test]
+fn relay_resolver_with_mapped_fragment_args() {
+    let input = include_str!("to_schema/fixtures/relay-resolver-with-mapped-fragment-args.js");
+    let expected = include_str!("to_schema/fixtures/relay-resolver-with-mapped-fragment-args.expected");
+    test_fixture(transform_fixture, "relay-resolver-with-mapped-fragment-args.js", "to_schema/fixtures/relay-resolver-with-mapped-fragment-args.expected", input, expected);
+}
+
+#[test]
+fn relay_resolver_with_object_type_args_invalid() {
+    let input = include_str!("to_schema/fixtures/relay-resolver-with-object-type-args.invalid.js");
+    let expected = include_str!("to_schema/fixtures/relay-resolver-with-object-type-args.invalid.expected");
+    test_fixture(transform_fixture, "relay-resolver-with-object-type-args.invalid.js", "to_schema/fixtures/relay-resolver-with-object-type-args.invalid.expected", input, expected);
+}
+
 #[test]
 fn relay_resolver_with_output_type() {
     let input = include_str!("to_schema/fixtures/relay-resolver-with-output-type.js");
@@ -152,6 +187,20 @@ fn relay_resolver_with_output_type() {
     test_fixture(transform_fixture, "relay-resolver-with-output-type.js", "to_schema/fixtures/relay-resolver-with-output-type.expected", input, expected);
 }
 
+#[test]
+fn scalar_type() {
+    let input = include_str!("to_schema/fixtures/scalar-type.js");
+    let expected = include_str!("to_schema/fixtures/scalar-type.expected");
+    test_fixture(transform_fixture, "scalar-type.js", "to_schema/fixtures/scalar-type.expected", input, expected);
+}
+
+#[test]
+fn scalar_type_with_deprecated_invalid() {
+    let input = include_str!("to_schema/fixtures/scalar-type-with-deprecated.invalid.js");
+    let expected = include_str!("to_schema/fixtures/scalar-type-with-deprecated.invalid.expected");
+    test_fixture(transform_fixture, "scalar-type-with-deprecated.invalid.js", "to_schema/fixtures/scalar-type-with-deprecated.invalid.expected", input, expected);
+}
+
 #[test]
 fn terse_relay_resolver() {
     let input = include_str!("to_schema/fixtures/terse-relay-resolver.js");
@@ -159,6 +208,13 @@ fn terse_relay_resolver() {
     test_fixture(transform_fixture, "terse-relay-resolver.js", "to_schema/fixtures/terse-relay-resolver.expected", input, expected);
 }
 
+#[test]
+fn terse_relay_resolver_input_object_type_invalid() {
+    let input = include_str!("to_schema/fixtures/terse-relay-resolver-input-object-type.invalid.js");
+    let expected = include_str!("to_schema/fixtures/terse-relay-resolver-input-object-type.invalid.expected");
+    test_fixture(transform_fixture, "terse-relay-resolver-input-object-type.invalid.js", "to_schema/fixtures/terse-relay-resolver-input-object-type.invalid.expected", input, expected);
+}
+
 #[test]
 fn terse_relay_resolver_interface() {
     let input = include_str!("to_schema/fixtures/terse-relay-resolver-interface.js");
@@ -166,6 +222,34 @@ fn terse_relay_resolver_interface() {
     test_fixture(transform_fixture, "terse-relay-resolver-interface.js", "to_schema/fixtures/terse-relay-resolver-interface.expected", input, expected);
 }
 
+#[test]
+fn terse_relay_resolver_non_null_edge_to_invalid() {
+    let input = include_str!("to_schema/fixtures/terse-relay-resolver-non-null-edge-to.invalid.js");
+    let expected = include_str!("to_schema/fixtures/terse-relay-resolver-non-null-edge-to.invalid.expected");
+    test_fixture(transform_fixture, "terse-relay-resolver-non-null-edge-to.invalid.js", "to_schema/fixtures/terse-relay-resolver-non-null-edge-to.invalid.expected", input, expected);
+}
+
+#[test]
+fn terse_relay_resolver_on_scalar_type_invalid() {
+    let input = include_str!("to_schema/fixtures/terse-relay-resolver-on-scalar-type.invalid.js");
+    let expected = include_str!("to_schema/fixtures/terse-relay-resolver-on-scalar-type.invalid.expected");
+    test_fixture(transform_fixture, "terse-relay-resolver-on-scalar-type.invalid.js", "to_schema/fixtures/terse-relay-resolver-on-scalar-type.invalid.expected", input, expected);
+}
+
+#[test]
+fn terse_relay_resolver_plural_edge_to_server_type_invalid() {
+    let input = include_str!("to_schema/fixtures/terse-relay-resolver-plural-edge-to-server-type.invalid.js");
+    let expected = include_str!("to_schema/fixtures/terse-relay-resolver-plural-edge-to-server-type.invalid.expected");
+    test_fixture(transform_fixture, "terse-relay-resolver-plural-edge-to-server-type.invalid.js", "to_schema/fixtures/terse-relay-resolver-plural-edge-to-server-type.invalid.expected", input, expected);
+}
+
+#[test]
+fn terse_relay_resolver_with_list_and_non_null_types() {
+    let input = include_str!("to_schema/fixtures/terse-relay-resolver-with-list-and-non-null-types.js");
+    let expected = include_str!("to_schema/fixtures/terse-relay-resolver-with-list-and-non-null-types.expected");
+    test_fixture(transform_fixture, "terse-relay-resolver-with-list-and-non-null-types.js", "to_schema/fixtures/terse-relay-resolver-with-list-and-non-null-types.expected", input, expected);
+}
+
 #[test]
 fn terse_relay_resolver_with_output_type() {
     let input = include_str!("to_schema/fixtures/terse-relay-resolver-with-output-type.js");
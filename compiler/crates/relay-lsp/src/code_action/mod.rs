@@ -6,17 +6,20 @@
  */
 
 mod create_name_suggestion;
+mod organize_selections;
 
 use std::collections::HashMap;
 use std::collections::HashSet;
 
 use common::Span;
+use common::TextSource;
 use create_name_suggestion::create_default_name;
 use create_name_suggestion::create_default_name_with_index;
 use create_name_suggestion::create_impactful_name;
 use create_name_suggestion::create_name_wrapper;
 use create_name_suggestion::DefinitionNameSuffix;
 use graphql_syntax::ExecutableDefinition;
+use graphql_syntax::ExecutableDocument;
 use intern::Lookup;
 use lsp_types::request::CodeActionRequest;
 use lsp_types::request::Request;
@@ -29,6 +32,7 @@ use lsp_types::TextDocumentPositionParams;
 use lsp_types::TextEdit;
 use lsp_types::Url;
 use lsp_types::WorkspaceEdit;
+use organize_selections::organize_selections;
 use resolution_path::IdentParent;
 use resolution_path::IdentPath;
 use resolution_path::OperationDefinitionPath;
@@ -36,6 +40,8 @@ use resolution_path::ResolutionPath;
 use resolution_path::ResolvePosition;
 use serde_json::Value;
 
+use crate::diagnostic_reporter::SafeFixData;
+use crate::initialization_options::OrganizeSelectionsOptions;
 use crate::lsp_runtime_error::LSPRuntimeError;
 use crate::lsp_runtime_error::LSPRuntimeResult;
 use crate::server::GlobalState;
@@ -77,9 +83,93 @@ pub(crate) fn on_code_action(
     let path = document.resolve((), position_span);
 
     let used_definition_names = get_definition_names(&definitions);
-    let result = get_code_actions(path, used_definition_names, uri, params.range)
-        .ok_or(LSPRuntimeError::ExpectedError)?;
-    Ok(Some(result))
+    let mut result = get_code_actions(path, used_definition_names, uri.clone(), params.range)
+        .unwrap_or_default();
+    if let Ok(text_source) = state.extract_graphql_text_source(&text_document_position_params) {
+        result.extend(get_organize_selections_code_actions(
+            &document,
+            position_span,
+            &text_source,
+            &uri,
+            state.get_organize_selections_options(),
+        ));
+    }
+    if result.is_empty() {
+        Err(LSPRuntimeError::ExpectedError)
+    } else {
+        Ok(Some(result))
+    }
+}
+
+/// Returns code actions reordering the selections of the definition under the
+/// cursor, and of all definitions of the GraphQL document.
+fn get_organize_selections_code_actions(
+    document: &ExecutableDocument,
+    position_span: Span,
+    text_source: &TextSource,
+    url: &Url,
+    options: OrganizeSelectionsOptions,
+) -> Vec<CodeActionOrCommand> {
+    let mut code_actions = vec![];
+    let mut document_edits = vec![];
+    let mut definition_edit = None;
+    for definition in &document.definitions {
+        let selections = match definition {
+            ExecutableDefinition::Operation(operation) => &operation.selections,
+            ExecutableDefinition::Fragment(fragment) => &fragment.selections,
+        };
+        let (start, end) = selections.span.as_usize();
+        let original_text = match text_source.text.get(start..end) {
+            Some(original_text) => original_text,
+            None => continue,
+        };
+        if let Some(new_text) = organize_selections(&text_source.text, selections, options) {
+            if new_text != original_text {
+                let text_edit = TextEdit {
+                    range: text_source.to_span_range(&selections.span),
+                    new_text,
+                };
+                if definition.location().span().contains(position_span) {
+                    definition_edit = Some(text_edit.clone());
+                }
+                document_edits.push(text_edit);
+            }
+        }
+    }
+    if let Some(text_edit) = definition_edit {
+        code_actions.push(create_source_code_action(
+            "Organize selections",
+            vec![text_edit],
+            url,
+        ));
+    }
+    if document_edits.len() > 1 {
+        code_actions.push(create_source_code_action(
+            "Organize selections in document",
+            document_edits,
+            url,
+        ));
+    }
+    code_actions
+}
+
+fn create_source_code_action(
+    title: &str,
+    text_edits: Vec<TextEdit>,
+    url: &Url,
+) -> CodeActionOrCommand {
+    let mut changes = HashMap::new();
+    changes.insert(url.clone(), text_edits);
+
+    CodeActionOrCommand::CodeAction(CodeAction {
+        title: title.to_string(),
+        kind: Some(lsp_types::CodeActionKind::SOURCE),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            ..Default::default()
+        }),
+        ..Default::default()
+    })
 }
 
 fn get_code_actions_from_diagnostics(
@@ -95,6 +185,9 @@ fn get_code_actions_from_diagnostics(
                     url,
                     diagnostic.range,
                 )),
+                Value::Object(_) => serde_json::from_value::<SafeFixData>(item.clone())
+                    .ok()
+                    .map(|safe_fix| create_safe_fix_code_action(safe_fix, url)),
                 _ => None,
             })
             .collect::<_>()
@@ -252,6 +345,27 @@ fn create_code_action(
     })
 }
 
+/// The code action of a fix that is safe to apply without review, which the
+/// editor may apply with its auto-fix command.
+fn create_safe_fix_code_action(safe_fix: SafeFixData, url: &Url) -> CodeActionOrCommand {
+    let mut changes = HashMap::new();
+    changes.insert(url.clone(), safe_fix.edits);
+
+    CodeActionOrCommand::CodeAction(CodeAction {
+        title: safe_fix.title,
+        kind: Some(lsp_types::CodeActionKind::QUICKFIX),
+        diagnostics: None,
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            ..Default::default()
+        }),
+        command: None,
+        is_preferred: Some(true),
+        ..Default::default()
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use lsp_types::CodeActionOrCommand;
@@ -300,4 +414,44 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_get_code_actions_from_diagnostics_with_safe_fix() {
+        let range = Range {
+            start: Position {
+                line: 1,
+                character: 16,
+            },
+            end: Position {
+                line: 1,
+                character: 26,
+            },
+        };
+        let diagnostic = Diagnostic {
+            range,
+            message: "Variable `$first` is never used".to_string(),
+            data: Some(json!([
+                {
+                    "title": "Remove unused variable",
+                    "edits": [{"range": range, "newText": ""}],
+                },
+                "item1",
+            ])),
+            ..Default::default()
+        };
+        let url = Url::parse("file://relay.js").unwrap();
+        let code_actions = get_code_actions_from_diagnostics(&url, diagnostic).unwrap();
+
+        assert_eq!(code_actions.len(), 2);
+        match &code_actions[0] {
+            CodeActionOrCommand::CodeAction(action) => {
+                assert_eq!(action.title, "Remove unused variable");
+                assert_eq!(action.is_preferred, Some(true));
+                let edits = &action.edit.as_ref().unwrap().changes.as_ref().unwrap()[&url];
+                assert_eq!(edits[0].range, range);
+                assert_eq!(edits[0].new_text, "");
+            }
+            _ => panic!("unexpected case"),
+        }
+    }
 }
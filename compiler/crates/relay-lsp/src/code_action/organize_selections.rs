@@ -0,0 +1,222 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::Span;
+use graphql_syntax::List;
+use graphql_syntax::Selection;
+use graphql_syntax::TokenKind;
+use intern::Lookup;
+
+use crate::initialization_options::OrganizeSelectionsOptions;
+
+/// Returns the text of `selections` (including the braces) with the selections
+/// reordered, recursively, according to `options`.
+///
+/// The original source text of every selection is kept as is, so formatting
+/// and comments within a selection are preserved. Selection sets with comments
+/// between their selections are left in their original order, since we can't
+/// tell which selection a comment belongs to. Returns `None` if the selection
+/// set is incomplete.
+pub(crate) fn organize_selections(
+    text: &str,
+    selections: &List<Selection>,
+    options: OrganizeSelectionsOptions,
+) -> Option<String> {
+    if selections.start.kind == TokenKind::Empty || selections.end.kind == TokenKind::Empty {
+        return None;
+    }
+    let (first, last) = match (selections.items.first(), selections.items.last()) {
+        (Some(first), Some(last)) => (first.span(), last.span()),
+        _ => return slice(text, selections.span).map(|text| text.to_string()),
+    };
+
+    let mut printed_selections = selections
+        .items
+        .iter()
+        .map(|selection| {
+            Some((
+                sort_key(selection, options),
+                print_selection(text, selection, options)?,
+            ))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let separators = selections
+        .items
+        .windows(2)
+        .map(|pair| slice(text, Span::new(pair[0].span().end, pair[1].span().start)))
+        .collect::<Option<Vec<_>>>()?;
+    let prefix = slice(text, Span::new(selections.span.start, first.start))?;
+    let suffix = slice(text, Span::new(last.end, selections.span.end))?;
+
+    let has_comments = prefix.contains('#') || separators.iter().any(|sep| sep.contains('#'));
+    if !has_comments {
+        // `sort_by` is stable, so disabled rules keep the original order
+        printed_selections.sort_by(|a, b| a.0.cmp(&b.0));
+    }
+
+    let mut result = prefix.to_string();
+    for (index, (_, printed_selection)) in printed_selections.iter().enumerate() {
+        result.push_str(printed_selection);
+        if let Some(separator) = separators.get(index) {
+            result.push_str(separator);
+        }
+    }
+    result.push_str(suffix);
+    Some(result)
+}
+
+fn print_selection(
+    text: &str,
+    selection: &Selection,
+    options: OrganizeSelectionsOptions,
+) -> Option<String> {
+    let span = selection.span();
+    let nested_selections = match selection {
+        Selection::LinkedField(field) => &field.selections,
+        Selection::InlineFragment(fragment) => &fragment.selections,
+        Selection::ScalarField(_) | Selection::FragmentSpread(_) => {
+            return slice(text, span).map(|text| text.to_string());
+        }
+    };
+    Some(format!(
+        "{}{}{}",
+        slice(text, Span::new(span.start, nested_selections.span.start))?,
+        organize_selections(text, nested_selections, options)?,
+        slice(text, Span::new(nested_selections.span.end, span.end))?,
+    ))
+}
+
+fn sort_key(selection: &Selection, options: OrganizeSelectionsOptions) -> (u8, &'static str) {
+    let (group, name) = match selection {
+        Selection::ScalarField(field) => (
+            if options.scalars_first { 0 } else { 1 },
+            field
+                .alias
+                .as_ref()
+                .map_or(field.name.value, |alias| alias.alias.value),
+        ),
+        Selection::LinkedField(field) => (
+            1,
+            field
+                .alias
+                .as_ref()
+                .map_or(field.name.value, |alias| alias.alias.value),
+        ),
+        Selection::InlineFragment(fragment) => {
+            return (
+                2,
+                if options.alphabetical {
+                    fragment
+                        .type_condition
+                        .as_ref()
+                        .map_or("", |type_condition| type_condition.type_.value.lookup())
+                } else {
+                    ""
+                },
+            );
+        }
+        Selection::FragmentSpread(spread) => {
+            (if options.spreads_last { 3 } else { 1 }, spread.name.value)
+        }
+    };
+    (
+        group,
+        if options.alphabetical {
+            name.lookup()
+        } else {
+            ""
+        },
+    )
+}
+
+fn slice(text: &str, span: Span) -> Option<&str> {
+    text.get(span.start as usize..span.end as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::SourceLocationKey;
+    use graphql_syntax::parse_executable;
+    use graphql_syntax::ExecutableDefinition;
+
+    use super::organize_selections;
+    use super::OrganizeSelectionsOptions;
+
+    fn organize(text: &str, options: OrganizeSelectionsOptions) -> String {
+        let document = parse_executable(text, SourceLocationKey::generated()).unwrap();
+        let selections = match &document.definitions[0] {
+            ExecutableDefinition::Fragment(fragment) => &fragment.selections,
+            ExecutableDefinition::Operation(operation) => &operation.selections,
+        };
+        organize_selections(text, selections, options).unwrap()
+    }
+
+    #[test]
+    fn test_organize_selections() {
+        let text = r#"fragment Foo on User {
+  ...Bar
+  friends(first: 10) {
+    count
+    edges { cursor }
+  }
+  name
+  ... on User { id }
+  alias: id
+}"#;
+        assert_eq!(
+            organize(text, OrganizeSelectionsOptions::default()),
+            r#"{
+  alias: id
+  name
+  friends(first: 10) {
+    count
+    edges { cursor }
+  }
+  ... on User { id }
+  ...Bar
+}"#
+        );
+    }
+
+    #[test]
+    fn test_organize_selections_keeps_order_without_alphabetical() {
+        let text = "query Foo { me { name id ...Bar } viewer { actor { id } } __typename }";
+        assert_eq!(
+            organize(
+                text,
+                OrganizeSelectionsOptions {
+                    alphabetical: false,
+                    ..Default::default()
+                }
+            ),
+            "{ __typename me { name id ...Bar } viewer { actor { id } } }"
+        );
+    }
+
+    #[test]
+    fn test_organize_selections_with_comments() {
+        let text = r#"fragment Foo on User {
+  name # the name
+  id
+  friends {
+    name
+    id
+  }
+}"#;
+        assert_eq!(
+            organize(text, OrganizeSelectionsOptions::default()),
+            r#"{
+  name # the name
+  id
+  friends {
+    id
+    name
+  }
+}"#
+        );
+    }
+}
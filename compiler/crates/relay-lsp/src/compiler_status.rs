@@ -0,0 +1,295 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Reports the state of the background compiler to the client, both as
+//! standard `$/progress` notifications and as a custom `relay/compilerStatus`
+//! notification that editors can use to render a status bar item.
+//! Recent compiler output is kept in memory and can be requested with
+//! `relay/showCompilerOutput`, to be displayed in a virtual document.
+
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+
+use crossbeam::channel::Sender;
+use lsp_server::Message;
+use lsp_server::Notification as ServerNotification;
+use lsp_server::Request as ServerRequest;
+use lsp_types::notification::Notification;
+use lsp_types::notification::Progress;
+use lsp_types::request::Request;
+use lsp_types::request::WorkDoneProgressCreate;
+use lsp_types::NumberOrString;
+use lsp_types::ProgressParams;
+use lsp_types::ProgressParamsValue;
+use lsp_types::WorkDoneProgress;
+use lsp_types::WorkDoneProgressBegin;
+use lsp_types::WorkDoneProgressCreateParams;
+use lsp_types::WorkDoneProgressEnd;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::lsp_runtime_error::LSPRuntimeResult;
+use crate::server::GlobalState;
+
+const PROGRESS_TOKEN: &str = "relay/compiler";
+const MAX_OUTPUT_LINES: usize = 1000;
+
+/// URI of the virtual document with the compiler output
+pub const COMPILER_OUTPUT_URI: &str = "relay://compiler-output";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompilerState {
+    Idle,
+    Compiling,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompilerStatusParams {
+    pub state: CompilerState,
+    pub error_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+pub(crate) enum CompilerStatusNotification {}
+
+impl Notification for CompilerStatusNotification {
+    type Params = CompilerStatusParams;
+    const METHOD: &'static str = "relay/compilerStatus";
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ShowCompilerOutputResult {
+    pub uri: String,
+    pub text: String,
+}
+
+pub(crate) enum ShowCompilerOutput {}
+
+impl Request for ShowCompilerOutput {
+    type Params = ();
+    type Result = ShowCompilerOutputResult;
+    const METHOD: &'static str = "relay/showCompilerOutput";
+}
+
+pub(crate) fn on_show_compiler_output(
+    state: &impl GlobalState,
+    _params: <ShowCompilerOutput as Request>::Params,
+) -> LSPRuntimeResult<<ShowCompilerOutput as Request>::Result> {
+    Ok(ShowCompilerOutputResult {
+        uri: COMPILER_OUTPUT_URI.to_string(),
+        text: state.get_compiler_output(),
+    })
+}
+
+pub struct CompilerStatusReporter {
+    sender: Sender<Message>,
+    /// The client supports `window.workDoneProgress`, otherwise only the
+    /// `relay/compilerStatus` notifications are sent.
+    work_done_progress: bool,
+    progress_token_created: AtomicBool,
+    is_compiling: AtomicBool,
+    output: Mutex<VecDeque<String>>,
+}
+
+impl CompilerStatusReporter {
+    pub fn new(sender: Sender<Message>, work_done_progress: bool) -> Self {
+        Self {
+            sender,
+            work_done_progress,
+            progress_token_created: AtomicBool::new(false),
+            is_compiling: AtomicBool::new(false),
+            output: Default::default(),
+        }
+    }
+
+    pub fn build_starts(&self, message: &str) {
+        self.log(message);
+        if !self.is_compiling.swap(true, Ordering::SeqCst) {
+            self.create_progress_token();
+            self.send_progress(WorkDoneProgress::Begin(WorkDoneProgressBegin {
+                title: "Relay".to_string(),
+                cancellable: Some(false),
+                message: Some(message.to_string()),
+                percentage: None,
+            }));
+        }
+        self.send_status(CompilerState::Compiling, 0, Some(message.to_string()));
+    }
+
+    pub fn build_completes(&self, error_count: usize) {
+        let message = match error_count {
+            0 => "Compilation completed.".to_string(),
+            1 => "Compilation completed with 1 error.".to_string(),
+            _ => format!("Compilation completed with {} errors.", error_count),
+        };
+        self.log(&message);
+        if self.is_compiling.swap(false, Ordering::SeqCst) {
+            self.send_progress(WorkDoneProgress::End(WorkDoneProgressEnd {
+                message: Some(message.clone()),
+            }));
+        }
+        let state = if error_count == 0 {
+            CompilerState::Idle
+        } else {
+            CompilerState::Error
+        };
+        self.send_status(state, error_count, Some(message));
+    }
+
+    /// Appends a line to the compiler output, dropping the oldest lines once
+    /// the output exceeds `MAX_OUTPUT_LINES`.
+    pub fn log(&self, line: impl Into<String>) {
+        let mut output = self.output.lock().unwrap();
+        output.push_back(line.into());
+        while output.len() > MAX_OUTPUT_LINES {
+            output.pop_front();
+        }
+    }
+
+    pub fn output(&self) -> String {
+        let output = self.output.lock().unwrap();
+        output
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn create_progress_token(&self) {
+        if !self.work_done_progress || self.progress_token_created.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let request = ServerRequest::new(
+            WorkDoneProgressCreate::METHOD.to_string().into(),
+            WorkDoneProgressCreate::METHOD.into(),
+            WorkDoneProgressCreateParams {
+                token: NumberOrString::String(PROGRESS_TOKEN.to_string()),
+            },
+        );
+        self.sender.send(Message::Request(request)).ok();
+    }
+
+    fn send_progress(&self, progress: WorkDoneProgress) {
+        if !self.work_done_progress {
+            return;
+        }
+        let notification = ServerNotification::new(
+            Progress::METHOD.into(),
+            ProgressParams {
+                token: NumberOrString::String(PROGRESS_TOKEN.to_string()),
+                value: ProgressParamsValue::WorkDone(progress),
+            },
+        );
+        self.sender.send(Message::Notification(notification)).ok();
+    }
+
+    fn send_status(&self, state: CompilerState, error_count: usize, message: Option<String>) {
+        let notification = ServerNotification::new(
+            CompilerStatusNotification::METHOD.into(),
+            CompilerStatusParams {
+                state,
+                error_count,
+                message,
+            },
+        );
+        self.sender.send(Message::Notification(notification)).ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossbeam::channel::unbounded;
+    use lsp_server::Message;
+    use lsp_types::notification::Notification;
+    use lsp_types::notification::Progress;
+    use lsp_types::request::Request;
+    use lsp_types::request::WorkDoneProgressCreate;
+
+    use super::CompilerState;
+    use super::CompilerStatusNotification;
+    use super::CompilerStatusParams;
+    use super::CompilerStatusReporter;
+    use super::MAX_OUTPUT_LINES;
+
+    fn methods(messages: &[Message]) -> Vec<&str> {
+        messages
+            .iter()
+            .map(|message| match message {
+                Message::Request(request) => request.method.as_str(),
+                Message::Notification(notification) => notification.method.as_str(),
+                Message::Response(_) => "response",
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reports_progress_and_status() {
+        let (sender, receiver) = unbounded();
+        let reporter = CompilerStatusReporter::new(sender, true);
+        reporter.build_starts("Relay: checking...");
+        reporter.build_completes(2);
+        reporter.build_starts("Relay: checking...");
+
+        let messages: Vec<_> = receiver.try_iter().collect();
+        assert_eq!(
+            methods(&messages),
+            vec![
+                WorkDoneProgressCreate::METHOD,
+                Progress::METHOD,
+                CompilerStatusNotification::METHOD,
+                Progress::METHOD,
+                CompilerStatusNotification::METHOD,
+                Progress::METHOD,
+                CompilerStatusNotification::METHOD,
+            ]
+        );
+        match &messages[4] {
+            Message::Notification(notification) => {
+                let params: CompilerStatusParams =
+                    serde_json::from_value(notification.params.clone()).unwrap();
+                assert_eq!(params.state, CompilerState::Error);
+                assert_eq!(params.error_count, 2);
+            }
+            _ => panic!("Expected a notification"),
+        }
+    }
+
+    #[test]
+    fn skips_progress_without_client_support() {
+        let (sender, receiver) = unbounded();
+        let reporter = CompilerStatusReporter::new(sender, false);
+        reporter.build_starts("Relay: checking...");
+        reporter.build_completes(0);
+
+        let messages: Vec<_> = receiver.try_iter().collect();
+        assert_eq!(
+            methods(&messages),
+            vec![
+                CompilerStatusNotification::METHOD,
+                CompilerStatusNotification::METHOD,
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_recent_output() {
+        let (sender, _receiver) = unbounded();
+        let reporter = CompilerStatusReporter::new(sender, true);
+        for index in 0..MAX_OUTPUT_LINES + 5 {
+            reporter.log(format!("line {}", index));
+        }
+        let output = reporter.output();
+        assert_eq!(output.lines().count(), MAX_OUTPUT_LINES);
+        assert!(output.starts_with("line 5\n"));
+    }
+}
@@ -928,39 +928,52 @@ fn resolve_completion_items_for_fragment_spread(
                 .get_type_name(fragment.type_condition)
                 .lookup()
                 .to_string();
-            if fragment.variable_definitions.is_empty() {
-                valid_fragments.push(CompletionItem::new_simple(label, detail))
-            } else {
-                // Create a snippet if the fragment has required argumentDefinition with no default values
-                let args = create_arguments_snippets(fragment.variable_definitions.iter(), schema);
-                valid_fragments.push(if args.is_empty() {
-                    CompletionItem::new_simple(label, detail)
+            // Fragments are usually defined in other modules, so we let the
+            // user know where the spread is coming from.
+            let documentation = Some(Documentation::MarkupContent(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!(
+                    "Defined in `{}`",
+                    fragment.name.location.source_location().path()
+                ),
+            }));
+            // Fragments on the exact type of the parent selection are the most
+            // likely candidates, so we list them before the abstract ones.
+            let sort_text = Some(format!(
+                "{}{}",
+                if fragment.type_condition == type_ {
+                    0
                 } else {
-                    let insert_text = format!("{} @arguments({})", label, args.join(", "));
-                    CompletionItem {
-                        label,
-                        kind: None,
-                        detail: Some(detail),
-                        documentation: None,
-                        deprecated: None,
-                        preselect: None,
-                        sort_text: None,
-                        filter_text: None,
-                        insert_text: Some(insert_text),
-                        insert_text_format: Some(lsp_types::InsertTextFormat::SNIPPET),
-                        text_edit: None,
-                        additional_text_edits: None,
-                        command: Some(lsp_types::Command::new(
-                            "Suggest".into(),
-                            "editor.action.triggerSuggest".into(),
-                            None,
-                        )),
-                        data: None,
-                        tags: None,
-                        ..Default::default()
-                    }
-                });
-            }
+                    1
+                },
+                label
+            ));
+            // Create a snippet if the fragment has required argumentDefinition with no default values
+            let args = create_arguments_snippets(fragment.variable_definitions.iter(), schema);
+            let (insert_text, insert_text_format, command) = if args.is_empty() {
+                (None, None, None)
+            } else {
+                (
+                    Some(format!("{} @arguments({})", label, args.join(", "))),
+                    Some(lsp_types::InsertTextFormat::SNIPPET),
+                    Some(lsp_types::Command::new(
+                        "Suggest".into(),
+                        "editor.action.triggerSuggest".into(),
+                        None,
+                    )),
+                )
+            };
+            valid_fragments.push(CompletionItem {
+                label,
+                kind: Some(CompletionItemKind::SNIPPET),
+                detail: Some(detail),
+                documentation,
+                sort_text,
+                insert_text,
+                insert_text_format,
+                command,
+                ..Default::default()
+            });
         }
     }
     debug!("get_valid_fragments_for_type {:#?}", valid_fragments);
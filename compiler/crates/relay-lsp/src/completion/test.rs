@@ -18,6 +18,8 @@ use graphql_syntax::parse_executable_with_error_recovery;
 use intern::string_key::Intern;
 use lsp_types::CompletionItem;
 use lsp_types::Documentation;
+use lsp_types::MarkupContent;
+use lsp_types::MarkupKind;
 use relay_test_schema::get_test_schema;
 
 use super::make_markdown_table_documentation;
@@ -190,6 +192,7 @@ fn directive() {
             "__clientField",
             "appendEdge",
             "required",
+            "catch",
             "stream_connection",
             "match",
             "customDirective",
@@ -200,6 +203,7 @@ fn directive() {
             "connection",
             "skip",
             "fb_actor_change",
+            "featureFlag",
             "waterfall",
         ],
     );
@@ -227,6 +231,7 @@ fn directive_on_scalar_field() {
             "__clientField",
             "appendEdge",
             "required",
+            "catch",
             "stream_connection",
             "match",
             "customDirective",
@@ -237,6 +242,7 @@ fn directive_on_scalar_field() {
             "connection",
             "skip",
             "fb_actor_change",
+            "featureFlag",
             "waterfall",
         ],
     );
@@ -345,6 +351,46 @@ fn fragment_spread_on_interface() {
     assert_labels(items.unwrap(), vec!["TestFragment", "TestFragment2"]);
 }
 
+#[test]
+fn fragment_spread_defining_module() {
+    let items = parse_and_resolve_completion_items(
+        r#"
+            fragment Test on Actor {
+               ...T|
+            }
+        "#,
+        Some(build_test_program(
+            r#"
+        fragment TestFragment on Node {
+           __typename
+        }
+
+        fragment TestFragment2 on Actor {
+            __typename
+        }
+    "#,
+        )),
+    )
+    .unwrap();
+
+    let mut items = items
+        .into_iter()
+        .map(|item| (item.sort_text.unwrap(), item.label, item.documentation))
+        .collect::<Vec<_>>();
+    items.sort_by(|a, b| a.0.cmp(&b.0));
+
+    // Fragments on the exact parent type come first
+    assert_eq!(items[0].1, "TestFragment2");
+    assert_eq!(items[1].1, "TestFragment");
+    assert_eq!(
+        items[0].2,
+        Some(Documentation::MarkupContent(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: "Defined in `<generated>`".to_string(),
+        }))
+    );
+}
+
 #[test]
 fn argument_value() {
     let items = parse_and_resolve_completion_items(
@@ -421,6 +467,7 @@ fn empty_directive() {
             "__clientField",
             "appendEdge",
             "required",
+            "catch",
             "stream_connection",
             "match",
             "customDirective",
@@ -431,6 +478,7 @@ fn empty_directive() {
             "connection",
             "skip",
             "fb_actor_change",
+            "featureFlag",
             "waterfall",
         ],
     );
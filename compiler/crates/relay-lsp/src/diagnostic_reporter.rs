@@ -26,16 +26,24 @@ use lsp_types::DiagnosticRelatedInformation as LspDiagnosticRelatedInformation;
 use lsp_types::DiagnosticSeverity;
 use lsp_types::DiagnosticTag;
 use lsp_types::Location as LspLocation;
+use lsp_types::NumberOrString;
 use lsp_types::Position;
 use lsp_types::PublishDiagnosticsParams;
 use lsp_types::Range;
+use lsp_types::TextEdit;
 use lsp_types::Url;
 use relay_compiler::errors::BuildProjectError;
 use relay_compiler::errors::Error;
+use relay_compiler::fix::fix_source_location;
+use relay_compiler::fix::resolve_fix;
 use relay_compiler::source_for_location;
 use relay_compiler::FsSourceReader;
 use relay_compiler::SourceReader;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
 
+use crate::initialization_options::DiagnosticSeverityOverrides;
 use crate::lsp_process_error::LSPProcessResult;
 
 /// Converts a Location to a Url pointing to the canonical path based on the root_dir provided.
@@ -46,6 +54,15 @@ fn url_from_location(location: Location, root_dir: &PathBuf) -> Option<Url> {
     Url::from_file_path(canonical_path).ok()
 }
 
+/// A safe fix of a diagnostic resolved to text edits, passed in the `data`
+/// of the LSP diagnostic to the code actions.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SafeFixData {
+    pub title: String,
+    pub edits: Vec<TextEdit>,
+}
+
 #[derive(Default)]
 struct DiagnosticSet {
     /// Stores the diagnostics from IDE source, which will be updated on any text change
@@ -59,6 +76,7 @@ pub struct DiagnosticReporter {
     sender: Sender<Message>,
     root_dir: PathBuf,
     source_reader: Box<dyn SourceReader + Send + Sync>,
+    severity_overrides: DiagnosticSeverityOverrides,
 }
 
 impl DiagnosticReporter {
@@ -68,9 +86,20 @@ impl DiagnosticReporter {
             sender,
             root_dir,
             source_reader: Box::new(FsSourceReader),
+            severity_overrides: Default::default(),
         }
     }
 
+    /// Diagnostics with a code or kind present in `severity_overrides` are
+    /// reported with the overridden severity.
+    pub fn with_severity_overrides(
+        mut self,
+        severity_overrides: DiagnosticSeverityOverrides,
+    ) -> Self {
+        self.severity_overrides = severity_overrides;
+        self
+    }
+
     pub fn clear_regular_diagnostics(&self) {
         for mut r in self.active_diagnostics.iter_mut() {
             let (url, diagnostics) = r.pair_mut();
@@ -207,23 +236,77 @@ impl DiagnosticReporter {
             .filter_map(|info| self.maybe_convert_related_information(info))
             .collect::<Vec<_>>();
 
+        // Overrides may name the stable code of the diagnostic or its kind
+        let error_code = diagnostic.error_code();
+        let kind = diagnostic.code();
+        let severity = error_code
+            .and_then(|code| self.severity_overrides.get(code))
+            .or_else(|| kind.and_then(|kind| self.severity_overrides.get(kind)))
+            .unwrap_or_else(|| diagnostic.severity());
+
         Diagnostic {
-            code: None,
-            data: get_diagnostics_data(diagnostic),
-            message: diagnostic.message().to_string(),
+            code: error_code.map(|code| NumberOrString::String(code.to_string())),
+            data: self.get_data_with_safe_fix(text_source, diagnostic),
+            message: diagnostic.localized_message(),
             range: text_source.to_span_range(diagnostic.location().span()),
             related_information: if related_information.is_empty() {
                 None
             } else {
                 Some(related_information)
             },
-            severity: Some(diagnostic.severity()),
+            severity: Some(severity),
             tags: if tags.is_empty() { None } else { Some(tags) },
             source: None,
             ..Default::default()
         }
     }
 
+    /// The `data` of the diagnostic, with its safe fix resolved against the
+    /// text source first, so that the code actions offer it as the preferred
+    /// quick fix. Fixes editing another source are left to `--fix`.
+    fn get_data_with_safe_fix(
+        &self,
+        text_source: &TextSource,
+        diagnostic: &CompilerDiagnostic,
+    ) -> Option<Value> {
+        let data = get_diagnostics_data(diagnostic);
+        let fix = match diagnostic.safe_fix() {
+            Some(fix)
+                if fix_source_location(diagnostic.location(), fix)
+                    == Some(diagnostic.location().source_location()) =>
+            {
+                fix
+            }
+            _ => return data,
+        };
+        let edits = match resolve_fix(&text_source.text, *diagnostic.location().span(), fix) {
+            Some(resolved_fix) => resolved_fix
+                .into_iter()
+                .map(|(span, new_text)| TextEdit {
+                    range: text_source.to_span_range(&span),
+                    new_text,
+                })
+                .collect::<Vec<_>>(),
+            None => return data,
+        };
+        let mut items = match data {
+            Some(Value::Array(items)) => items,
+            _ => Vec::new(),
+        };
+        // The suggestion that the fix applies is replaced by the fix
+        items.retain(|item| {
+            !edits
+                .iter()
+                .any(|edit| item.as_str() == Some(edit.new_text.as_str()))
+        });
+        let safe_fix = SafeFixData {
+            title: fix.title(),
+            edits,
+        };
+        items.insert(0, serde_json::to_value(safe_fix).ok()?);
+        Some(Value::Array(items))
+    }
+
     pub fn maybe_convert_related_information(
         &self,
         info: &DiagnosticRelatedInformation,
@@ -289,6 +372,20 @@ impl DiagnosticReporter {
         self.add_diagnostic(url, diagnostic);
     }
 
+    /// Number of errors reported by the last compilation
+    pub fn error_count(&self) -> usize {
+        self.active_diagnostics
+            .iter()
+            .map(|r| {
+                r.value()
+                    .regular_diagnostics
+                    .iter()
+                    .filter(|diagnostic| diagnostic.severity == Some(DiagnosticSeverity::ERROR))
+                    .count()
+            })
+            .sum()
+    }
+
     pub fn get_diagnostics_for_range(&self, url: &Url, range: Range) -> Option<Diagnostic> {
         let diagnostic_set = self.active_diagnostics.get(url)?;
         diagnostic_set
@@ -339,7 +436,11 @@ mod tests {
     use common::Location;
     use common::SourceLocationKey;
     use common::Span;
+    use common::TextSource;
+    use graphql_ir::ValidationMessage;
     use intern::string_key::Intern;
+    use lsp_types::DiagnosticSeverity;
+    use lsp_types::NumberOrString;
     use lsp_types::Position;
     use lsp_types::Range;
     use relay_compiler::SourceReader;
@@ -387,6 +488,49 @@ mod tests {
         assert_eq!(reporter.active_diagnostics.len(), 0);
     }
 
+    #[test]
+    fn severity_override_test() {
+        let root_dir =
+            env::current_dir().expect("expect to be able to get the current working directory");
+        let (sender, _) = crossbeam::channel::unbounded();
+        let overrides =
+            serde_json::from_value(serde_json::json!({"DeprecatedField": "error"})).unwrap();
+        let reporter = DiagnosticReporter::new(root_dir, sender).with_severity_overrides(overrides);
+        let location = Location::new(SourceLocationKey::generated(), Span { start: 0, end: 1 });
+
+        let message = ValidationMessage::DeprecatedField {
+            parent_name: "Query".intern(),
+            field_name: "old".intern(),
+            deprecation_reason: None,
+        };
+
+        let diagnostic = reporter.convert_diagnostic(
+            &TextSource::from_whole_document("Content"),
+            &Diagnostic::hint(message.clone(), location, vec![]),
+        );
+        assert_eq!(diagnostic.severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(
+            diagnostic.code,
+            Some(NumberOrString::String("RELAY1068".to_string()))
+        );
+
+        let overrides =
+            serde_json::from_value(serde_json::json!({"RELAY1068": "warning"})).unwrap();
+        let reporter = reporter.with_severity_overrides(overrides);
+        let diagnostic = reporter.convert_diagnostic(
+            &TextSource::from_whole_document("Content"),
+            &Diagnostic::hint(message.clone(), location, vec![]),
+        );
+        assert_eq!(diagnostic.severity, Some(DiagnosticSeverity::WARNING));
+
+        let diagnostic = reporter.convert_diagnostic(
+            &TextSource::from_whole_document("Content"),
+            &Diagnostic::hint("plain message", location, vec![]),
+        );
+        assert_eq!(diagnostic.severity, Some(DiagnosticSeverity::HINT));
+        assert_eq!(diagnostic.code, None);
+    }
+
     #[test]
     fn sub_range_inner_directly_below() {
         let cursor = Range::new(Position::new(106, 12), Position::new(106, 12));
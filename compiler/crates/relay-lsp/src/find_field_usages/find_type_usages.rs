@@ -0,0 +1,97 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common::Location as IRLocation;
+use graphql_ir::FragmentDefinition;
+use graphql_ir::InlineFragment;
+use graphql_ir::LinkedField;
+use graphql_ir::OperationDefinition;
+use graphql_ir::ScalarField;
+use graphql_ir::Visitor;
+use intern::string_key::StringKey;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+
+// Finds every place in the documents that selects the given type:
+//   - fragments with a matching type condition
+//   - inline fragments with a matching type condition
+//   - fields (linked or scalar) returning the type
+// Subtypes of the type are matched as well, mirroring `FieldUsageFinder`.
+pub(crate) struct TypeUsageFinder<'schema> {
+    pub(crate) usages: HashMap<StringKey, Vec<IRLocation>>,
+    schema: &'schema Arc<SDLSchema>,
+    type_: Type,
+    // name of the enclosing Fragment or Operation
+    label: Option<StringKey>,
+}
+
+impl<'schema> TypeUsageFinder<'schema> {
+    pub(crate) fn new(schema: &'schema Arc<SDLSchema>, type_: Type) -> TypeUsageFinder<'schema> {
+        TypeUsageFinder {
+            usages: Default::default(),
+            schema,
+            type_,
+            label: None,
+        }
+    }
+
+    fn match_type(&self, type_: Type) -> bool {
+        type_ == self.type_ || self.schema.is_named_type_subtype_of(type_, self.type_)
+    }
+
+    fn add_location(&mut self, location: IRLocation) {
+        let current_label = self.label.expect("Expected label in find_type_usages");
+        self.usages.entry(current_label).or_default().push(location);
+    }
+}
+
+impl Visitor for TypeUsageFinder<'_> {
+    const NAME: &'static str = "TypeUsageFinder";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_operation(&mut self, operation: &OperationDefinition) {
+        self.label = Some(operation.name.item.0);
+        self.default_visit_operation(operation);
+        self.label = None;
+    }
+
+    fn visit_fragment(&mut self, fragment: &FragmentDefinition) {
+        self.label = Some(fragment.name.item.0);
+        if self.match_type(fragment.type_condition) {
+            self.add_location(fragment.name.location);
+        }
+        self.default_visit_fragment(fragment);
+        self.label = None;
+    }
+
+    fn visit_inline_fragment(&mut self, fragment: &InlineFragment) {
+        if let Some(type_) = fragment.type_condition {
+            if self.match_type(type_) {
+                self.add_location(fragment.spread_location);
+            }
+        }
+        self.default_visit_inline_fragment(fragment);
+    }
+
+    fn visit_linked_field(&mut self, field: &LinkedField) {
+        if self.match_type(self.schema.field(field.definition.item).type_.inner()) {
+            self.add_location(field.definition.location);
+        }
+        self.default_visit_linked_field(field);
+    }
+
+    fn visit_scalar_field(&mut self, field: &ScalarField) {
+        if self.match_type(self.schema.field(field.definition.item).type_.inner()) {
+            self.add_location(field.definition.location);
+        }
+    }
+}
@@ -6,13 +6,17 @@
  */
 
 mod find_field_locations;
+mod find_type_usages;
+mod type_usage_index;
 
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
 
 use common::Location as IRLocation;
 use common::WithLocation;
 pub(crate) use find_field_locations::find_field_locations;
+use find_type_usages::TypeUsageFinder;
 use graphql_ir::FragmentDefinition;
 use graphql_ir::InlineFragment;
 use graphql_ir::LinkedField;
@@ -30,6 +34,8 @@ use schema::Schema;
 use schema::Type;
 use serde::Deserialize;
 use serde::Serialize;
+pub use type_usage_index::TypeUsageIndex;
+use type_usage_index::visit_definitions;
 
 use crate::location::transform_relay_location_to_lsp_location;
 use crate::server::GlobalState;
@@ -70,6 +76,24 @@ impl Request for FindFieldUsages {
     const METHOD: &'static str = "relay/findFieldUsages";
 }
 
+// FindSchemaMemberUsages accepts either a type (`User`) or a field
+// (`User.name`), and lists every selection of it across the project.
+// Schema owners can use it to assess the impact of changing a schema member.
+pub struct FindSchemaMemberUsages {}
+
+#[derive(Deserialize, Serialize)]
+pub struct FindSchemaMemberUsagesParams {
+    pub schema_name: String,
+    /// Either `Type` or `Type.field`
+    pub member: String,
+}
+
+impl Request for FindSchemaMemberUsages {
+    type Params = FindSchemaMemberUsagesParams;
+    type Result = FindFieldUsagesResult;
+    const METHOD: &'static str = "relay/findSchemaMemberUsages";
+}
+
 pub fn on_find_field_usages(
     state: &impl GlobalState,
     params: <FindFieldUsages as Request>::Params,
@@ -80,9 +104,30 @@ pub fn on_find_field_usages(
 
     let schema = state.get_schema(&schema_name)?;
     let program = state.get_program(&schema_name)?;
-    let root_dir = &state.root_dir();
 
     let ir_locations = get_usages(&program, &schema, type_name, field_name)?;
+    to_find_field_usages_result(&state.root_dir(), ir_locations)
+}
+
+pub fn on_find_schema_member_usages(
+    state: &impl GlobalState,
+    params: <FindSchemaMemberUsages as Request>::Params,
+) -> LSPRuntimeResult<<FindSchemaMemberUsages as Request>::Result> {
+    let schema_name = params.schema_name.intern();
+    let schema = state.get_schema(&schema_name)?;
+    let program = state.get_program(&schema_name)?;
+
+    let type_usage_index = state.get_type_usage_index(&schema_name)?;
+
+    let ir_locations =
+        get_schema_member_usages(&program, &schema, &type_usage_index, &params.member)?;
+    to_find_field_usages_result(&state.root_dir(), ir_locations)
+}
+
+fn to_find_field_usages_result(
+    root_dir: &Path,
+    ir_locations: Vec<(String, IRLocation)>,
+) -> LSPRuntimeResult<FindFieldUsagesResult> {
     let lsp_locations = ir_locations
         .into_iter()
         .map(|(label, ir_location)| {
@@ -111,8 +156,44 @@ pub fn get_usages(
     let mut usage_finder = FieldUsageFinder::new(schema, type_, field_name);
     usage_finder.visit_program(program);
 
-    let mut result = Vec::with_capacity(usage_finder.usages.len());
-    for (label, locations) in usage_finder.usages.into_iter().sorted() {
+    Ok(label_usages(usage_finder.usages))
+}
+
+/// Finds usages of a type (`User`) or a field (`User.name`), only visiting
+/// the definitions that `type_usage_index` lists for the type
+pub fn get_schema_member_usages(
+    program: &Program,
+    schema: &Arc<SDLSchema>,
+    type_usage_index: &TypeUsageIndex,
+    member: &str,
+) -> LSPRuntimeResult<Vec<(String, IRLocation)>> {
+    let (type_name, field_name) = match member.split_once('.') {
+        Some((type_name, field_name)) => (type_name.intern(), Some(field_name.intern())),
+        None => (member.intern(), None),
+    };
+    let type_ = schema.get_type(type_name).ok_or_else(|| {
+        LSPRuntimeError::UnexpectedError(format!("Type {} not found!", type_name))
+    })?;
+    let definition_names = type_usage_index.definitions_selecting(schema, type_);
+
+    let usages = match field_name {
+        Some(field_name) => {
+            let mut usage_finder = FieldUsageFinder::new(schema, type_, field_name);
+            visit_definitions(&mut usage_finder, program, &definition_names);
+            usage_finder.usages
+        }
+        None => {
+            let mut usage_finder = TypeUsageFinder::new(schema, type_);
+            visit_definitions(&mut usage_finder, program, &definition_names);
+            usage_finder.usages
+        }
+    };
+    Ok(label_usages(usages))
+}
+
+fn label_usages(usages: HashMap<StringKey, Vec<IRLocation>>) -> Vec<(String, IRLocation)> {
+    let mut result = Vec::with_capacity(usages.len());
+    for (label, locations) in usages.into_iter().sorted() {
         if let [location] = locations.as_slice() {
             // exactly 1 location, so no need to enumerate
             result.push((label.to_string(), *location));
@@ -122,7 +203,7 @@ pub fn get_usages(
             }
         }
     }
-    Ok(result)
+    result
 }
 
 #[derive(Default)]
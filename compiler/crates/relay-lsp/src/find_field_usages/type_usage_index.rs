@@ -0,0 +1,170 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use graphql_ir::FragmentDefinition;
+use graphql_ir::FragmentDefinitionName;
+use graphql_ir::InlineFragment;
+use graphql_ir::LinkedField;
+use graphql_ir::OperationDefinition;
+use graphql_ir::Program;
+use graphql_ir::ScalarField;
+use graphql_ir::Visitor;
+use intern::string_key::StringKey;
+use intern::string_key::StringKeyMap;
+use intern::string_key::StringKeySet;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+
+// Index of the definitions of a project by the types they select: the type
+// conditions of the definition and of its inline fragments, and the types of
+// its fields. The fields of a type are only selected in the scope of one of
+// these types, so the usages of a type or of one of its fields are in the
+// definitions indexed under the type or one of its subtypes.
+// The types are indexed by name, so the index stays valid when the schema
+// is rebuilt.
+#[derive(Clone, Default)]
+pub struct TypeUsageIndex {
+    definitions_by_type: StringKeyMap<StringKeySet>,
+    types_by_definition: StringKeyMap<Vec<StringKey>>,
+}
+
+impl TypeUsageIndex {
+    pub fn from_program(schema: &SDLSchema, program: &Program) -> Self {
+        let mut index = Self::default();
+        index.update(schema, program, None);
+        index
+    }
+
+    /// Indexes the definitions of `changed_program`, replacing their
+    /// previous entries, and removes the entries of the removed definitions.
+    pub fn update(
+        &mut self,
+        schema: &SDLSchema,
+        changed_program: &Program,
+        removed_definition_names: Option<&[StringKey]>,
+    ) {
+        for name in removed_definition_names.into_iter().flatten() {
+            self.remove_definition(*name);
+        }
+        let mut collector = SelectedTypesCollector {
+            schema,
+            types: Default::default(),
+        };
+        for operation in changed_program.operations() {
+            collector.visit_operation(operation);
+            self.insert_definition(operation.name.item.0, &mut collector.types);
+        }
+        for fragment in changed_program.fragments() {
+            collector.visit_fragment(fragment);
+            self.insert_definition(fragment.name.item.0, &mut collector.types);
+        }
+    }
+
+    /// The names of the definitions that may select `type_`, or one of its
+    /// subtypes.
+    pub fn definitions_selecting(&self, schema: &SDLSchema, type_: Type) -> StringKeySet {
+        let mut definitions = StringKeySet::default();
+        for (type_name, type_definitions) in &self.definitions_by_type {
+            let is_match = schema.get_type(*type_name).map_or(false, |indexed_type| {
+                indexed_type == type_ || schema.is_named_type_subtype_of(indexed_type, type_)
+            });
+            if is_match {
+                definitions.extend(type_definitions);
+            }
+        }
+        definitions
+    }
+
+    fn insert_definition(&mut self, definition_name: StringKey, types: &mut StringKeySet) {
+        self.remove_definition(definition_name);
+        let types: Vec<_> = types.drain().collect();
+        for type_name in &types {
+            self.definitions_by_type
+                .entry(*type_name)
+                .or_default()
+                .insert(definition_name);
+        }
+        self.types_by_definition.insert(definition_name, types);
+    }
+
+    fn remove_definition(&mut self, definition_name: StringKey) {
+        for type_name in self
+            .types_by_definition
+            .remove(&definition_name)
+            .unwrap_or_default()
+        {
+            if let Some(definitions) = self.definitions_by_type.get_mut(&type_name) {
+                definitions.remove(&definition_name);
+                if definitions.is_empty() {
+                    self.definitions_by_type.remove(&type_name);
+                }
+            }
+        }
+    }
+}
+
+/// Visits the definitions of `program` named in `definition_names`.
+pub(crate) fn visit_definitions(
+    visitor: &mut impl Visitor,
+    program: &Program,
+    definition_names: &StringKeySet,
+) {
+    for operation in program.operations() {
+        if definition_names.contains(&operation.name.item.0) {
+            visitor.visit_operation(operation);
+        }
+    }
+    for definition_name in definition_names {
+        if let Some(fragment) = program.fragment(FragmentDefinitionName(*definition_name)) {
+            visitor.visit_fragment(fragment);
+        }
+    }
+}
+
+struct SelectedTypesCollector<'schema> {
+    schema: &'schema SDLSchema,
+    types: StringKeySet,
+}
+
+impl SelectedTypesCollector<'_> {
+    fn add_type(&mut self, type_: Type) {
+        self.types.insert(self.schema.get_type_name(type_));
+    }
+}
+
+impl Visitor for SelectedTypesCollector<'_> {
+    const NAME: &'static str = "SelectedTypesCollector";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_operation(&mut self, operation: &OperationDefinition) {
+        self.add_type(operation.type_);
+        self.default_visit_operation(operation);
+    }
+
+    fn visit_fragment(&mut self, fragment: &FragmentDefinition) {
+        self.add_type(fragment.type_condition);
+        self.default_visit_fragment(fragment);
+    }
+
+    fn visit_inline_fragment(&mut self, fragment: &InlineFragment) {
+        if let Some(type_) = fragment.type_condition {
+            self.add_type(type_);
+        }
+        self.default_visit_inline_fragment(fragment);
+    }
+
+    fn visit_linked_field(&mut self, field: &LinkedField) {
+        self.add_type(self.schema.field(field.definition.item).type_.inner());
+        self.default_visit_linked_field(field);
+    }
+
+    fn visit_scalar_field(&mut self, field: &ScalarField) {
+        self.add_type(self.schema.field(field.definition.item).type_.inner());
+    }
+}
@@ -0,0 +1,225 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Indentation of GraphQL embedded in JavaScript files, used for on-type
+//! formatting (after a new line or a closing brace) and range formatting
+//! (e.g. when a block is pasted).
+
+use std::iter::once;
+use std::ops::RangeInclusive;
+
+use common::TextSource;
+use graphql_syntax::tokenize;
+use graphql_syntax::TokenKind;
+use lsp_types::request::OnTypeFormatting;
+use lsp_types::request::RangeFormatting;
+use lsp_types::request::Request;
+use lsp_types::FormattingOptions;
+use lsp_types::Position;
+use lsp_types::Range;
+use lsp_types::TextDocumentPositionParams;
+use lsp_types::TextEdit;
+
+use crate::lsp_runtime_error::LSPRuntimeResult;
+use crate::server::GlobalState;
+
+pub(crate) fn on_type_formatting(
+    state: &impl GlobalState,
+    params: <OnTypeFormatting as Request>::Params,
+) -> LSPRuntimeResult<<OnTypeFormatting as Request>::Result> {
+    let text_source = state.extract_graphql_text_source(&params.text_document_position)?;
+    let line = params.text_document_position.position.line as usize;
+    Ok(Some(reindent_lines(
+        &text_source,
+        line..=line,
+        &params.options,
+        true,
+    )))
+}
+
+pub(crate) fn on_range_formatting(
+    state: &impl GlobalState,
+    params: <RangeFormatting as Request>::Params,
+) -> LSPRuntimeResult<<RangeFormatting as Request>::Result> {
+    let text_source = state.extract_graphql_text_source(&TextDocumentPositionParams {
+        text_document: params.text_document,
+        position: params.range.start,
+    })?;
+    Ok(Some(reindent_lines(
+        &text_source,
+        params.range.start.line as usize..=params.range.end.line as usize,
+        &params.options,
+        false,
+    )))
+}
+
+/// Returns the edits re-indenting the given lines (of the file) of the GraphQL
+/// source, based on the nesting of braces, parentheses and brackets.
+///
+/// Lines are indented relative to the first non-blank line of the source. The
+/// first line (the one with the opening backtick) and lines within multi-line
+/// tokens such as block strings are left untouched. Blank lines are only
+/// indented with `indent_blank_lines`, so the cursor is placed correctly after
+/// a new line.
+fn reindent_lines(
+    text_source: &TextSource,
+    lines: RangeInclusive<usize>,
+    options: &FormattingOptions,
+    indent_blank_lines: bool,
+) -> Vec<TextEdit> {
+    let text = &text_source.text;
+    let indent_unit = if options.insert_spaces {
+        " ".repeat(options.tab_size as usize)
+    } else {
+        "\t".to_string()
+    };
+    let line_starts: Vec<usize> = once(0)
+        .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+        .collect();
+    let tokens = tokenize(text);
+
+    let mut edits = vec![];
+    let mut base_indent: Option<String> = None;
+    let mut depth: usize = 0;
+    let mut token_index = 0;
+    for (line, &line_start) in line_starts.iter().enumerate().skip(1) {
+        let mut is_within_token = false;
+        while let Some((kind, span)) = tokens.get(token_index) {
+            if span.start as usize >= line_start {
+                break;
+            }
+            if span.end as usize > line_start {
+                is_within_token = true;
+                break;
+            }
+            depth = match kind {
+                TokenKind::OpenBrace | TokenKind::OpenParen | TokenKind::OpenBracket => depth + 1,
+                TokenKind::CloseBrace | TokenKind::CloseParen | TokenKind::CloseBracket => {
+                    depth.saturating_sub(1)
+                }
+                _ => depth,
+            };
+            token_index += 1;
+        }
+        if is_within_token {
+            continue;
+        }
+
+        let line_end = line_starts
+            .get(line + 1)
+            .map_or(text.len(), |next_line_start| next_line_start - 1);
+        let line_text = &text[line_start..line_end];
+        let content = line_text.trim_start_matches([' ', '\t']);
+        let current_indent = &line_text[..line_text.len() - content.len()];
+        let is_blank = content.trim().is_empty();
+
+        if base_indent.is_none() && !is_blank {
+            // The source may start with an opening brace on the first line
+            let nested_indent = indent_unit.repeat(depth);
+            base_indent = Some(
+                current_indent
+                    .strip_suffix(nested_indent.as_str())
+                    .unwrap_or("")
+                    .to_string(),
+            );
+        }
+
+        let file_line = text_source.line_index + line;
+        if !lines.contains(&file_line) || (is_blank && !indent_blank_lines) {
+            continue;
+        }
+        let line_depth = if content.starts_with(['}', ')', ']']) {
+            depth.saturating_sub(1)
+        } else {
+            depth
+        };
+        let expected_indent = format!(
+            "{}{}",
+            base_indent.as_deref().unwrap_or(""),
+            indent_unit.repeat(line_depth)
+        );
+        if expected_indent != current_indent {
+            edits.push(TextEdit {
+                range: Range::new(
+                    Position::new(file_line as u32, 0),
+                    Position::new(file_line as u32, current_indent.len() as u32),
+                ),
+                new_text: expected_indent,
+            });
+        }
+    }
+    edits
+}
+
+#[cfg(test)]
+mod tests {
+    use common::TextSource;
+    use lsp_types::FormattingOptions;
+    use lsp_types::TextEdit;
+
+    use super::reindent_lines;
+
+    fn options() -> FormattingOptions {
+        FormattingOptions {
+            tab_size: 2,
+            insert_spaces: true,
+            ..Default::default()
+        }
+    }
+
+    /// Applies the edits, which are all at the start of a line, to the text
+    fn apply_edits(text: &str, line_index: usize, edits: Vec<TextEdit>) -> String {
+        text.split('\n')
+            .enumerate()
+            .map(|(line, line_text)| {
+                match edits
+                    .iter()
+                    .find(|edit| edit.range.start.line as usize == line + line_index)
+                {
+                    Some(edit) => format!(
+                        "{}{}",
+                        edit.new_text,
+                        &line_text[edit.range.end.character as usize..]
+                    ),
+                    None => line_text.to_string(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn reindent_pasted_block() {
+        let text = "\n  fragment Foo on User {\nfriends(first: 10) {\n        edges {\n    cursor\n}\n    }\n  }\n";
+        let text_source = TextSource::new(text, 10, 20);
+        let edits = reindent_lines(&text_source, 10..=20, &options(), false);
+        assert_eq!(
+            apply_edits(text, 10, edits),
+            "\n  fragment Foo on User {\n    friends(first: 10) {\n      edges {\n        cursor\n      }\n    }\n  }\n"
+        );
+    }
+
+    #[test]
+    fn indent_new_line_in_incomplete_document() {
+        let text = "\n  query Foo {\n    me {\n\n";
+        let text_source = TextSource::new(text, 0, 0);
+        let edits = reindent_lines(&text_source, 3..=3, &options(), true);
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].new_text, "      ");
+    }
+
+    #[test]
+    fn ignores_block_strings_and_first_line() {
+        let text = "query Foo {\nme {\n  name @foo(value: \"\"\"\n{\n  \"\"\")\n}\n}";
+        let text_source = TextSource::new(text, 5, 10);
+        let edits = reindent_lines(&text_source, 5..=11, &options(), false);
+        assert_eq!(
+            apply_edits(text, 5, edits),
+            "query Foo {\n  me {\n    name @foo(value: \"\"\"\n{\n  \"\"\")\n  }\n}"
+        );
+    }
+}
@@ -25,11 +25,15 @@ use graphql_text_printer::print_full_operation;
 use intern::string_key::Intern;
 use intern::string_key::StringKey;
 use lsp_types::request::Request;
+use lsp_types::TextDocumentPositionParams;
 use lsp_types::Url;
+use relay_codegen::print_fragment;
 use relay_compiler::config::ProjectConfig;
 use relay_transforms::apply_transforms;
 use relay_transforms::CustomTransformsConfig;
 use relay_transforms::Programs;
+use relay_typegen::generate_operation_type_exports_section;
+use relay_typegen::FragmentLocations;
 use schema::SDLSchema;
 use schema_documentation::SchemaDocumentation;
 use serde::Deserialize;
@@ -38,6 +42,7 @@ use serde::Serialize;
 use crate::lsp_runtime_error::LSPRuntimeResult;
 use crate::server::GlobalState;
 use crate::server::LSPState;
+use crate::transformed_output::TransformedOutput;
 use crate::LSPRuntimeError;
 
 pub(crate) enum GraphQLExecuteQuery {}
@@ -147,11 +152,12 @@ fn transform_program<TPerfLogger: PerfLogger + 'static>(
         perf_logger,
         None,
         custom_transforms_config,
+        None,
     )
     .map_err(|errors| format!("{:?}", errors))
 }
 
-fn print_full_operation_text(programs: Programs, operation_name: StringKey) -> Option<String> {
+fn print_full_operation_text(programs: &Programs, operation_name: StringKey) -> Option<String> {
     let print_operation_node = programs
         .operation_text
         .operation(OperationDefinitionName(operation_name))?;
@@ -256,7 +262,7 @@ pub(crate) fn get_query_text<
             )
             .map_err(LSPRuntimeError::UnexpectedError)?;
 
-            print_full_operation_text(programs, operation_name).unwrap_or(original_text)
+            print_full_operation_text(&programs, operation_name).unwrap_or(original_text)
         } else {
             original_text
         };
@@ -264,6 +270,101 @@ pub(crate) fn get_query_text<
     Ok(query_text)
 }
 
+/// Builds the output of the compiler for the operation of the document under
+/// the cursor, from its synced source and the fragments of the project.
+pub(crate) fn get_transformed_output<
+    TPerfLogger: PerfLogger + 'static,
+    TSchemaDocumentation: SchemaDocumentation,
+>(
+    state: &LSPState<TPerfLogger, TSchemaDocumentation>,
+    text_document_position: &TextDocumentPositionParams,
+) -> LSPRuntimeResult<TransformedOutput> {
+    let project_name =
+        state.extract_project_name_from_url(&text_document_position.text_document.uri)?;
+    let project_config = state
+        .config
+        .enabled_projects()
+        .find(|project_config| project_config.name == project_name)
+        .ok_or_else(|| {
+            LSPRuntimeError::UnexpectedError(format!(
+                "Unable to get project config for project {}.",
+                project_name
+            ))
+        })?;
+    let schema = state.get_schema(&project_name)?;
+
+    let (document, _) = state.extract_executable_document_from_text(text_document_position, 1)?;
+    let (operation, fragments) =
+        build_operation_ir_with_fragments(&document.definitions, Arc::clone(&schema))
+            .map_err(LSPRuntimeError::UnexpectedError)?;
+    let operation_name = operation.name.item;
+
+    let program = state.get_program(&project_name)?;
+    let program = get_operation_only_program(operation, fragments, &program).ok_or_else(|| {
+        LSPRuntimeError::UnexpectedError(format!(
+            "Unable to find the fragments spread by operation {}.",
+            operation_name
+        ))
+    })?;
+    let programs = transform_program(
+        project_config,
+        Arc::new(program),
+        Arc::clone(&state.perf_logger),
+        state.config.custom_transforms.as_ref(),
+    )
+    .map_err(LSPRuntimeError::UnexpectedError)?;
+
+    let missing_operation = || {
+        LSPRuntimeError::UnexpectedError(format!(
+            "Unable to find operation {} in the transformed programs.",
+            operation_name
+        ))
+    };
+    let reader_operation = programs
+        .reader
+        .operation(operation_name)
+        .ok_or_else(missing_operation)?;
+    let normalization_operation = programs
+        .normalization
+        .operation(operation_name)
+        .ok_or_else(missing_operation)?;
+    let typegen_operation = programs
+        .typegen
+        .operation(operation_name)
+        .ok_or_else(missing_operation)?;
+
+    // Client-only operations don't have a text.
+    let operation_text = print_full_operation_text(&programs, operation_name.0).unwrap_or_default();
+    let operation_fragment = FragmentDefinition {
+        name: reader_operation.name.map(|x| FragmentDefinitionName(x.0)),
+        variable_definitions: reader_operation.variable_definitions.clone(),
+        selections: reader_operation.selections.clone(),
+        used_global_variables: Default::default(),
+        directives: reader_operation.directives.clone(),
+        type_condition: reader_operation.type_,
+    };
+    let reader_ast = print_fragment(
+        &schema,
+        &operation_fragment,
+        project_config,
+        &mut Default::default(),
+    );
+    let types = generate_operation_type_exports_section(
+        typegen_operation,
+        normalization_operation,
+        &schema,
+        project_config,
+        &FragmentLocations::new(programs.typegen.fragments()),
+    );
+
+    Ok(TransformedOutput {
+        operation_name: operation_name.0,
+        operation_text,
+        reader_ast,
+        types,
+    })
+}
+
 pub(crate) fn on_graphql_execute_query(
     state: &impl GlobalState,
     params: GraphQLExecuteQueryParams,
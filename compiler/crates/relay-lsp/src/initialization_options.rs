@@ -0,0 +1,143 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Options provided by the client in the `initializationOptions` of the
+//! `initialize` request.
+
+use fnv::FnvHashMap;
+use log::debug;
+use lsp_types::DiagnosticSeverity;
+use lsp_types::InitializeParams;
+use serde::Deserialize;
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitializationOptions {
+    /// Remaps the severity of compiler diagnostics by their code or kind, e.g.
+    /// `{"RELAY1068": "error", "UnusedVariable": "hint"}`
+    #[serde(default)]
+    pub diagnostic_severity_overrides: DiagnosticSeverityOverrides,
+    /// Rules of the "Organize selections" code action, e.g.
+    /// `{"scalarsFirst": false}`
+    #[serde(default)]
+    pub organize_selections: OrganizeSelectionsOptions,
+}
+
+impl InitializationOptions {
+    /// Invalid options are logged and ignored, so a misconfigured client
+    /// still gets a working language server.
+    pub fn from_params(params: &InitializeParams) -> Self {
+        match &params.initialization_options {
+            Some(options) => serde_json::from_value(options.clone()).unwrap_or_else(|error| {
+                debug!("Unable to parse initialization options: {:?}", error);
+                Default::default()
+            }),
+            None => Default::default(),
+        }
+    }
+}
+
+/// Rules used to reorder the selections of a selection set.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct OrganizeSelectionsOptions {
+    /// Scalar fields are listed before linked fields
+    pub scalars_first: bool,
+    /// Fragment spreads are listed after fields and inline fragments
+    pub spreads_last: bool,
+    /// Selections of the same kind are sorted by alias/name
+    pub alphabetical: bool,
+}
+
+impl Default for OrganizeSelectionsOptions {
+    fn default() -> Self {
+        Self {
+            scalars_first: true,
+            spreads_last: true,
+            alphabetical: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SeverityOverride {
+    Hint,
+    #[serde(alias = "information")]
+    Info,
+    Warning,
+    Error,
+}
+
+impl From<SeverityOverride> for DiagnosticSeverity {
+    fn from(severity: SeverityOverride) -> Self {
+        match severity {
+            SeverityOverride::Hint => DiagnosticSeverity::HINT,
+            SeverityOverride::Info => DiagnosticSeverity::INFORMATION,
+            SeverityOverride::Warning => DiagnosticSeverity::WARNING,
+            SeverityOverride::Error => DiagnosticSeverity::ERROR,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(transparent)]
+pub struct DiagnosticSeverityOverrides(FnvHashMap<String, SeverityOverride>);
+
+impl DiagnosticSeverityOverrides {
+    pub fn get(&self, code: &str) -> Option<DiagnosticSeverity> {
+        self.0.get(code).map(|severity| (*severity).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::DiagnosticSeverity;
+    use serde_json::json;
+
+    use super::InitializationOptions;
+
+    #[test]
+    fn parse_diagnostic_severity_overrides() {
+        let options: InitializationOptions = serde_json::from_value(json!({
+            "diagnosticSeverityOverrides": {
+                "DeprecatedField": "error",
+                "UnusedVariable": "hint",
+                "Other": "information",
+            }
+        }))
+        .unwrap();
+        let overrides = options.diagnostic_severity_overrides;
+        assert_eq!(
+            overrides.get("DeprecatedField"),
+            Some(DiagnosticSeverity::ERROR)
+        );
+        assert_eq!(
+            overrides.get("UnusedVariable"),
+            Some(DiagnosticSeverity::HINT)
+        );
+        assert_eq!(
+            overrides.get("Other"),
+            Some(DiagnosticSeverity::INFORMATION)
+        );
+        assert_eq!(overrides.get("Missing"), None);
+    }
+
+    #[test]
+    fn parse_organize_selections_options() {
+        let options: InitializationOptions = serde_json::from_value(json!({
+            "organizeSelections": {
+                "scalarsFirst": false,
+            }
+        }))
+        .unwrap();
+        let organize_selections = options.organize_selections;
+        assert!(!organize_selections.scalars_first);
+        assert!(organize_selections.spreads_last);
+        assert!(organize_selections.alphabetical);
+    }
+}
@@ -9,14 +9,17 @@
 
 mod client;
 mod code_action;
+pub mod compiler_status;
 pub mod completion;
 pub mod diagnostic_reporter;
 mod docblock_resolution_info;
 mod explore_schema_for_type;
 pub mod find_field_usages;
+mod formatting;
 pub mod goto_definition;
 mod graphql_tools;
 pub mod hover;
+pub mod initialization_options;
 pub mod js_language_server;
 pub mod location;
 mod lsp_extra_data_provider;
@@ -31,6 +34,7 @@ mod shutdown;
 mod status_reporter;
 pub mod status_updater;
 pub mod text_documents;
+pub mod transformed_output;
 pub mod utils;
 use std::sync::Arc;
 
@@ -41,7 +41,7 @@ pub fn transform_relay_location_to_lsp_location(
 
             let file_contents = get_file_contents(&path_to_fragment)?;
 
-            let response = extract_graphql::extract(&file_contents);
+            let response = extract_graphql::extract_for_path(&path_to_fragment, &file_contents);
             let response_length = response.len();
             let embedded_source = response
                 .into_iter()
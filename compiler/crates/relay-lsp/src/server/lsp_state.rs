@@ -5,12 +5,14 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 
 use common::PerfLogger;
 use common::SourceLocationKey;
 use common::Span;
+use common::TextSource;
 use crossbeam::channel::SendError;
 use crossbeam::channel::Sender;
 use dashmap::mapref::entry::Entry;
@@ -46,14 +48,21 @@ use schema_documentation::SchemaDocumentationLoader;
 use tokio::sync::Notify;
 
 use super::task_queue::TaskScheduler;
+use crate::compiler_status::CompilerStatusReporter;
 use crate::diagnostic_reporter::DiagnosticReporter;
 use crate::docblock_resolution_info::create_docblock_resolution_info;
+use crate::find_field_usages::TypeUsageIndex;
 use crate::graphql_tools::get_query_text;
+use crate::graphql_tools::get_transformed_output;
+use crate::initialization_options::InitializationOptions;
+use crate::initialization_options::OrganizeSelectionsOptions;
 use crate::js_language_server::JSLanguageServer;
 use crate::lsp_runtime_error::LSPRuntimeResult;
 use crate::node_resolution_info::create_node_resolution_info;
+use crate::transformed_output::TransformedOutput;
 use crate::utils::extract_executable_definitions_from_text_document;
 use crate::utils::extract_feature_from_text;
+use crate::utils::extract_graphql_text_source;
 use crate::utils::extract_project_name_from_url;
 use crate::ContentConsumerType;
 use crate::DocblockNode;
@@ -64,6 +73,7 @@ use crate::LSPRuntimeError;
 
 pub type Schemas = Arc<DashMap<StringKey, Arc<SDLSchema>, FnvBuildHasher>>;
 pub type SourcePrograms = Arc<DashMap<StringKey, Program, FnvBuildHasher>>;
+pub type TypeUsageIndices = Arc<DashMap<StringKey, Arc<TypeUsageIndex>, FnvBuildHasher>>;
 pub type ProjectStatusMap = Arc<DashMap<StringKey, ProjectStatus, FnvBuildHasher>>;
 
 #[derive(Eq, PartialEq)]
@@ -79,6 +89,11 @@ pub trait GlobalState {
 
     fn get_program(&self, project_name: &StringKey) -> LSPRuntimeResult<Program>;
 
+    fn get_type_usage_index(
+        &self,
+        project_name: &StringKey,
+    ) -> LSPRuntimeResult<Arc<TypeUsageIndex>>;
+
     fn resolve_node(
         &self,
         text_document_position: &TextDocumentPositionParams,
@@ -98,6 +113,11 @@ pub trait GlobalState {
         index_offset: usize,
     ) -> LSPRuntimeResult<(Feature, Span)>;
 
+    fn extract_graphql_text_source(
+        &self,
+        position: &TextDocumentPositionParams,
+    ) -> LSPRuntimeResult<TextSource>;
+
     fn get_schema_documentation(&self, schema_name: &str) -> Self::TSchemaDocumentation;
 
     fn get_extra_data_provider(&self) -> &dyn LSPExtraDataProvider;
@@ -135,6 +155,18 @@ pub trait GlobalState {
     /// we may need to know who's our current consumer.
     /// This is mostly for hover handler (where we render markup)
     fn get_content_consumer_type(&self) -> ContentConsumerType;
+
+    /// Recent output of the background compiler
+    fn get_compiler_output(&self) -> String;
+
+    /// Rules of the "Organize selections" code action
+    fn get_organize_selections_options(&self) -> OrganizeSelectionsOptions;
+
+    /// Output of the compiler for the operation under the cursor
+    fn get_transformed_output(
+        &self,
+        text_document_position: &TextDocumentPositionParams,
+    ) -> LSPRuntimeResult<TransformedOutput>;
 }
 
 /// This structure contains all available resources that we may use in the Relay LSP message/notification
@@ -152,11 +184,14 @@ pub struct LSPState<
     pub(crate) schemas: Schemas,
     schema_documentation_loader: Option<Box<dyn SchemaDocumentationLoader<TSchemaDocumentation>>>,
     pub(crate) source_programs: SourcePrograms,
+    pub(crate) type_usage_indices: TypeUsageIndices,
     synced_javascript_features: DashMap<Url, Vec<JavaScriptSourceFeature>>,
     pub(crate) perf_logger: Arc<TPerfLogger>,
     pub(crate) diagnostic_reporter: Arc<DiagnosticReporter>,
+    pub(crate) compiler_status: Arc<CompilerStatusReporter>,
     pub(crate) notify_lsp_state_resources: Arc<Notify>,
     pub(crate) project_status: ProjectStatusMap,
+    organize_selections_options: OrganizeSelectionsOptions,
     js_resource: Option<Box<dyn JSLanguageServer<TState = Self>>>,
 }
 
@@ -164,6 +199,7 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
     LSPState<TPerfLogger, TSchemaDocumentation>
 {
     /// Private constructor
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         config: Arc<Config>,
         sender: Sender<Message>,
@@ -174,13 +210,20 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
             Box<dyn SchemaDocumentationLoader<TSchemaDocumentation>>,
         >,
         js_resource: Option<Box<dyn JSLanguageServer<TState = Self>>>,
+        initialization_options: InitializationOptions,
+        work_done_progress: bool,
     ) -> Self {
         debug!("Creating lsp_state...");
         let file_categorizer = FileCategorizer::from_config(&config);
         let root_dir = &config.root_dir.clone();
-        let diagnostic_reporter = Arc::new(DiagnosticReporter::new(
-            config.root_dir.clone(),
+        let diagnostic_reporter = Arc::new(
+            DiagnosticReporter::new(config.root_dir.clone(), sender.clone())
+                .with_severity_overrides(initialization_options.diagnostic_severity_overrides),
+        );
+
+        let compiler_status = Arc::new(CompilerStatusReporter::new(
             sender.clone(),
+            work_done_progress,
         ));
 
         let lsp_state = Self {
@@ -188,6 +231,7 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
             sender,
             task_scheduler,
             diagnostic_reporter,
+            compiler_status,
             extra_data_provider,
             file_categorizer,
             notify_lsp_state_resources: Arc::new(Notify::new()),
@@ -198,6 +242,8 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
             schema_documentation_loader,
             source_programs: Arc::new(DashMap::with_hasher(FnvBuildHasher::default())),
             synced_javascript_features: Default::default(),
+            type_usage_indices: Arc::new(DashMap::with_hasher(FnvBuildHasher::default())),
+            organize_selections_options: initialization_options.organize_selections,
             js_resource,
         };
 
@@ -391,6 +437,21 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
             })
     }
 
+    fn get_type_usage_index(
+        &self,
+        project_name: &StringKey,
+    ) -> LSPRuntimeResult<Arc<TypeUsageIndex>> {
+        self.type_usage_indices
+            .get(project_name)
+            .map(|index| Arc::clone(index.value()))
+            .ok_or_else(|| {
+                LSPRuntimeError::UnexpectedError(format!(
+                    "get_type_usage_index: index is missing (or not ready, yet) for the `{}` project.",
+                    project_name
+                ))
+            })
+    }
+
     fn resolve_node(
         &self,
         text_document_position: &TextDocumentPositionParams,
@@ -452,6 +513,15 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
         )
     }
 
+    /// Return the GraphQL source for this LSP request, only if the request
+    /// occurs within a GraphQL document.
+    fn extract_graphql_text_source(
+        &self,
+        position: &TextDocumentPositionParams,
+    ) -> LSPRuntimeResult<TextSource> {
+        extract_graphql_text_source(&self.synced_javascript_features, position)
+    }
+
     fn get_schema_documentation(&self, schema_name: &str) -> Self::TSchemaDocumentation {
         let primary = self
             .schemas
@@ -506,7 +576,7 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
         }
 
         // First we check to see if this document has any GraphQL documents.
-        let embedded_sources = extract_graphql::extract(text);
+        let embedded_sources = extract_graphql::extract_for_path(Path::new(uri.path()), text);
         if embedded_sources.is_empty() {
             Ok(())
         } else {
@@ -520,7 +590,7 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
         }
 
         // First we check to see if this document has any GraphQL documents.
-        let embedded_sources = extract_graphql::extract(full_text);
+        let embedded_sources = extract_graphql::extract_for_path(Path::new(uri.path()), full_text);
         if embedded_sources.is_empty() {
             self.remove_synced_sources(uri);
             Ok(())
@@ -540,6 +610,21 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
     fn get_content_consumer_type(&self) -> ContentConsumerType {
         ContentConsumerType::Relay
     }
+
+    fn get_compiler_output(&self) -> String {
+        self.compiler_status.output()
+    }
+
+    fn get_organize_selections_options(&self) -> OrganizeSelectionsOptions {
+        self.organize_selections_options
+    }
+
+    fn get_transformed_output(
+        &self,
+        text_document_position: &TextDocumentPositionParams,
+    ) -> LSPRuntimeResult<TransformedOutput> {
+        get_transformed_output(self, text_document_position)
+    }
 }
 
 #[derive(Debug)]
@@ -43,6 +43,7 @@ use tokio::task::JoinHandle;
 
 use super::lsp_state::ProjectStatus;
 use super::lsp_state::Task;
+use crate::find_field_usages::TypeUsageIndex;
 use crate::status_updater::set_ready_status;
 use crate::status_updater::update_in_progress_status;
 use crate::LSPState;
@@ -136,6 +137,9 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
                 Some("Building schemas and source programs for LSP"),
                 &self.lsp_state.sender,
             );
+            self.lsp_state
+                .compiler_status
+                .build_starts("Building schemas and source programs for LSP");
 
             self.lsp_state
                 .diagnostic_reporter
@@ -146,6 +150,7 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
                 self.publish_errors(&error, "lsp_state_error");
             }
             set_ready_status(&self.lsp_state.sender);
+            self.report_build_completed();
 
             setup_event.stop(timer);
             setup_event.complete();
@@ -187,6 +192,7 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
                     self.publish_errors(&error, "lsp_state_user_error");
                 }
                 set_ready_status(&self.lsp_state.sender);
+                self.report_build_completed();
 
                 log_event.stop(log_time);
                 log_event.complete();
@@ -224,6 +230,9 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
                 Some("Validating changes, and updating source programs with the latest changes."),
                 &self.lsp_state.sender,
             );
+            self.lsp_state.compiler_status.build_starts(
+                "Validating changes, and updating source programs with the latest changes.",
+            );
 
             self.build_projects(compiler_state, log_event)?;
         }
@@ -319,7 +328,7 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
         let ProjectAstData {
             project_asts,
             base_fragment_names,
-        } = get_project_asts(&schema, graphql_asts_map, project_config)?;
+        } = get_project_asts(&schema, compiler_state, graphql_asts_map, project_config)?;
 
         // This will kick-off the validation for all synced sources
         self.lsp_state.schedule_task(Task::ValidateSyncedSources);
@@ -401,7 +410,7 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
         let (base_program, _) = build_raw_program(
             project_config,
             project_asts,
-            schema,
+            Arc::clone(&schema),
             log_event,
             is_incremental_build,
         )?;
@@ -411,18 +420,29 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
             return Err(BuildProjectFailure::Cancelled);
         }
 
+        let removed_definition_names = graphql_asts
+            .get(&project_config.name)
+            .map(|ast| ast.removed_definition_names.as_ref());
         match self.lsp_state.source_programs.entry(project_config.name) {
             Entry::Vacant(e) => {
                 e.insert(base_program.clone());
             }
             Entry::Occupied(mut e) => {
                 let program = e.get_mut();
-                let removed_definition_names = graphql_asts
-                    .get(&project_config.name)
-                    .map(|ast| ast.removed_definition_names.as_ref());
                 program.merge_program(&base_program, removed_definition_names);
             }
         }
+        match self.lsp_state.type_usage_indices.entry(project_config.name) {
+            Entry::Vacant(e) => {
+                e.insert(Arc::new(TypeUsageIndex::from_program(
+                    &schema,
+                    &base_program,
+                )));
+            }
+            Entry::Occupied(mut e) => {
+                Arc::make_mut(e.get_mut()).update(&schema, &base_program, removed_definition_names);
+            }
+        }
 
         // Call validation rules that go beyond type checking.
         validate_program(
@@ -439,6 +459,7 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
             Arc::clone(&self.lsp_state.perf_logger),
             log_event,
             self.lsp_state.config.custom_transforms.as_ref(),
+            None,
         )?;
         Ok(())
     }
@@ -487,7 +508,16 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
         })
     }
 
+    /// Reports the end of the build (if one is in progress) with the number
+    /// of errors currently reported to the client.
+    fn report_build_completed(&self) {
+        self.lsp_state
+            .compiler_status
+            .build_completes(self.lsp_state.diagnostic_reporter.error_count());
+    }
+
     fn log_errors(&self, log_event_name: &'static str, error: &Error) {
+        self.lsp_state.compiler_status.log(error.to_string());
         let error_event = self.lsp_state.perf_logger.create_event(log_event_name);
         error_event.string("error", error.to_string());
         error_event.complete();
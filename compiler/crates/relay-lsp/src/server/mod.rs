@@ -43,11 +43,14 @@ use lsp_types::request::CodeActionRequest;
 use lsp_types::request::Completion;
 use lsp_types::request::GotoDefinition;
 use lsp_types::request::HoverRequest;
+use lsp_types::request::OnTypeFormatting;
+use lsp_types::request::RangeFormatting;
 use lsp_types::request::References;
 use lsp_types::request::ResolveCompletionItem;
 use lsp_types::request::Shutdown;
 use lsp_types::CodeActionProviderCapability;
 use lsp_types::CompletionOptions;
+use lsp_types::DocumentOnTypeFormattingOptions;
 use lsp_types::InitializeParams;
 use lsp_types::ServerCapabilities;
 use lsp_types::TextDocumentSyncCapability;
@@ -60,18 +63,25 @@ use schema_documentation::SchemaDocumentationLoader;
 
 use self::task_queue::TaskProcessor;
 use crate::code_action::on_code_action;
+use crate::compiler_status::on_show_compiler_output;
+use crate::compiler_status::ShowCompilerOutput;
 use crate::completion::on_completion;
 use crate::completion::on_resolve_completion_item;
 use crate::explore_schema_for_type::on_explore_schema_for_type;
 use crate::explore_schema_for_type::ExploreSchemaForType;
 use crate::find_field_usages::on_find_field_usages;
+use crate::find_field_usages::on_find_schema_member_usages;
 use crate::find_field_usages::FindFieldUsages;
+use crate::find_field_usages::FindSchemaMemberUsages;
+use crate::formatting::on_range_formatting;
+use crate::formatting::on_type_formatting;
 use crate::goto_definition::on_get_source_location_of_type_definition;
 use crate::goto_definition::on_goto_definition;
 use crate::goto_definition::GetSourceLocationOfTypeDefinition;
 use crate::graphql_tools::on_graphql_execute_query;
 use crate::graphql_tools::GraphQLExecuteQuery;
 use crate::hover::on_hover;
+use crate::initialization_options::InitializationOptions;
 use crate::js_language_server::JSLanguageServer;
 use crate::lsp_process_error::LSPProcessResult;
 use crate::lsp_runtime_error::LSPRuntimeError;
@@ -91,6 +101,8 @@ use crate::text_documents::on_did_change_text_document;
 use crate::text_documents::on_did_close_text_document;
 use crate::text_documents::on_did_open_text_document;
 use crate::text_documents::on_did_save_text_document;
+use crate::transformed_output::on_show_transformed_output;
+use crate::transformed_output::ShowTransformedOutput;
 pub use crate::LSPExtraDataProvider;
 
 /// Initializes an LSP connection, handling the `initialize` message and `initialized` notification
@@ -113,6 +125,11 @@ pub fn initialize(connection: &Connection) -> LSPProcessResult<InitializeParams>
         definition_provider: Some(lsp_types::OneOf::Left(true)),
         references_provider: Some(lsp_types::OneOf::Left(true)),
         code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+        document_on_type_formatting_provider: Some(DocumentOnTypeFormattingOptions {
+            first_trigger_character: "\n".into(),
+            more_trigger_character: Some(vec!["}".into()]),
+        }),
+        document_range_formatting_provider: Some(lsp_types::OneOf::Left(true)),
         ..Default::default()
     };
 
@@ -135,7 +152,7 @@ pub async fn run<
 >(
     connection: Connection,
     mut config: Config,
-    _params: InitializeParams,
+    params: InitializeParams,
     perf_logger: Arc<TPerfLogger>,
     extra_data_provider: Box<dyn LSPExtraDataProvider + Send + Sync>,
     schema_documentation_loader: Option<Box<dyn SchemaDocumentationLoader<TSchemaDocumentation>>>,
@@ -155,10 +172,19 @@ where
     let task_queue = TaskQueue::new(Arc::new(task_processor));
     let task_scheduler = task_queue.get_scheduler();
 
+    let initialization_options = InitializationOptions::from_params(&params);
+    let work_done_progress = params
+        .capabilities
+        .window
+        .as_ref()
+        .and_then(|window| window.work_done_progress)
+        .unwrap_or(false);
+
     config.artifact_writer = Box::new(NoopArtifactWriter);
     config.status_reporter = Box::new(LSPStatusReporter::new(
         config.root_dir.clone(),
         connection.sender.clone(),
+        initialization_options.diagnostic_severity_overrides.clone(),
     ));
 
     let lsp_state = Arc::new(LSPState::new(
@@ -169,6 +195,8 @@ where
         extra_data_provider,
         schema_documentation_loader,
         js_resource,
+        initialization_options,
+        work_done_progress,
     ));
 
     LSPStateResources::new(Arc::clone(&lsp_state)).watch();
@@ -239,10 +267,15 @@ fn dispatch_request(request: lsp_server::Request, lsp_state: &impl GlobalState)
             .on_request_sync::<Completion>(on_completion)?
             .on_request_sync::<ResolveCompletionItem>(on_resolve_completion_item)?
             .on_request_sync::<CodeActionRequest>(on_code_action)?
+            .on_request_sync::<OnTypeFormatting>(on_type_formatting)?
+            .on_request_sync::<RangeFormatting>(on_range_formatting)?
             .on_request_sync::<Shutdown>(on_shutdown)?
             .on_request_sync::<GraphQLExecuteQuery>(on_graphql_execute_query)?
             .on_request_sync::<HeartbeatRequest>(on_heartbeat)?
             .on_request_sync::<FindFieldUsages>(on_find_field_usages)?
+            .on_request_sync::<FindSchemaMemberUsages>(on_find_schema_member_usages)?
+            .on_request_sync::<ShowCompilerOutput>(on_show_compiler_output)?
+            .on_request_sync::<ShowTransformedOutput>(on_show_transformed_output)?
             .request();
 
         // If we have gotten here, we have not handled the request
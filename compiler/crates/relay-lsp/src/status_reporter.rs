@@ -15,6 +15,7 @@ use relay_compiler::errors::Error;
 use relay_compiler::status_reporter::StatusReporter;
 
 use crate::diagnostic_reporter::DiagnosticReporter;
+use crate::initialization_options::DiagnosticSeverityOverrides;
 use crate::status_updater::set_error_status;
 use crate::status_updater::set_ready_status;
 use crate::status_updater::update_in_progress_status;
@@ -25,11 +26,16 @@ pub struct LSPStatusReporter {
 }
 
 impl LSPStatusReporter {
-    pub fn new(root_dir: PathBuf, sender: Sender<Message>) -> Self {
+    pub fn new(
+        root_dir: PathBuf,
+        sender: Sender<Message>,
+        severity_overrides: DiagnosticSeverityOverrides,
+    ) -> Self {
         let sender_clone = sender.clone();
         Self {
             sender,
-            diagnostic_reporter: DiagnosticReporter::new(root_dir, sender_clone),
+            diagnostic_reporter: DiagnosticReporter::new(root_dir, sender_clone)
+                .with_severity_overrides(severity_overrides),
         }
     }
 }
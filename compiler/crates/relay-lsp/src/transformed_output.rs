@@ -0,0 +1,73 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The custom `relay/showTransformedOutput` request, returning what the
+//! compiler generates for the operation under the cursor: the printed
+//! operation text, the reader AST and the types. Editors display it in a
+//! read-only virtual document, and request it again when the source changes
+//! to keep the document up to date.
+
+use std::fmt;
+
+use intern::string_key::StringKey;
+use lsp_types::request::Request;
+use lsp_types::TextDocumentPositionParams;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::lsp_runtime_error::LSPRuntimeResult;
+use crate::server::GlobalState;
+
+/// Prefix of the URIs of the virtual documents, followed by the name of the
+/// operation.
+pub const TRANSFORMED_OUTPUT_URI_PREFIX: &str = "relay://transformed-output/";
+
+/// The output of the compiler for one operation, built from the synced
+/// source of the document rather than the artifacts on disk.
+#[derive(Debug)]
+pub struct TransformedOutput {
+    pub operation_name: StringKey,
+    /// The text of the operation sent to the server, with its fragments.
+    pub operation_text: String,
+    /// The reader AST of the operation, as printed in its artifact.
+    pub reader_ast: String,
+    /// The exported types of the operation.
+    pub types: String,
+}
+
+impl fmt::Display for TransformedOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# Operation text\n\n{}", self.operation_text.trim_end())?;
+        writeln!(f, "\n# Reader AST\n\n{}", self.reader_ast.trim_end())?;
+        writeln!(f, "\n# Types\n\n{}", self.types.trim_end())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ShowTransformedOutputResult {
+    pub uri: String,
+    pub text: String,
+}
+
+pub(crate) enum ShowTransformedOutput {}
+
+impl Request for ShowTransformedOutput {
+    type Params = TextDocumentPositionParams;
+    type Result = ShowTransformedOutputResult;
+    const METHOD: &'static str = "relay/showTransformedOutput";
+}
+
+pub(crate) fn on_show_transformed_output(
+    state: &impl GlobalState,
+    params: <ShowTransformedOutput as Request>::Params,
+) -> LSPRuntimeResult<<ShowTransformedOutput as Request>::Result> {
+    let output = state.get_transformed_output(&params)?;
+    Ok(ShowTransformedOutputResult {
+        uri: format!("{}{}", TRANSFORMED_OUTPUT_URI_PREFIX, output.operation_name),
+        text: output.to_string(),
+    })
+}
@@ -96,6 +96,33 @@ pub fn extract_project_name_from_url(
     Ok(project_name)
 }
 
+/// Return the GraphQL source (its text, and its position in the file) for
+/// this LSP request, only if the request occurs within a GraphQL document.
+pub fn extract_graphql_text_source(
+    source_feature_cache: &DashMap<Url, Vec<JavaScriptSourceFeature>>,
+    text_document_position: &TextDocumentPositionParams,
+) -> LSPRuntimeResult<TextSource> {
+    let position = text_document_position.position;
+    let source_features = source_feature_cache
+        .get(&text_document_position.text_document.uri)
+        .ok_or(LSPRuntimeError::ExpectedError)?;
+
+    source_features
+        .iter()
+        .find_map(|source_feature| match source_feature {
+            JavaScriptSourceFeature::GraphQL(graphql_source) => {
+                let range = graphql_source.text_source().to_range();
+                if position >= range.start && position <= range.end {
+                    Some(graphql_source.text_source().clone())
+                } else {
+                    None
+                }
+            }
+            JavaScriptSourceFeature::Docblock(_) => None,
+        })
+        .ok_or(LSPRuntimeError::ExpectedError)
+}
+
 /// Return a parsed executable document, or parsed Docblock IR for this LSP
 /// request, only if the request occurs within a GraphQL document or Docblock.
 pub fn extract_feature_from_text(
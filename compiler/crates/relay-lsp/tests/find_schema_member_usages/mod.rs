@@ -0,0 +1,43 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::build;
+use graphql_ir::Program;
+use graphql_syntax::parse_executable;
+use relay_lsp::find_field_usages;
+use relay_lsp::find_field_usages::TypeUsageIndex;
+use relay_test_schema::get_test_schema;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts: Vec<_> = fixture.content.split("%member%").collect();
+    if let [document, member] = parts.as_slice() {
+        let source_location = SourceLocationKey::standalone(fixture.file_name);
+        let schema = get_test_schema();
+        let ast = parse_executable(document, source_location).unwrap();
+        let ir = build(&schema, &ast.definitions).unwrap();
+        let program = Program::from_definitions(schema, ir);
+        let schema = get_test_schema();
+        let type_usage_index = TypeUsageIndex::from_program(&schema, &program);
+
+        let result = find_field_usages::get_schema_member_usages(
+            &program,
+            &schema,
+            &type_usage_index,
+            member.trim(),
+        )
+        .unwrap()
+        .into_iter()
+        .map(|location| format!("{:?}\n", location))
+        .collect::<Vec<String>>();
+
+        Ok(result.concat())
+    } else {
+        panic!("Fixture {} missing %member%", fixture.file_name);
+    }
+}
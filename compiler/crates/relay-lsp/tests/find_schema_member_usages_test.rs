@@ -0,0 +1,65 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<00b98e13f10130b16d5df1d2a965932f>>
+ */
+
+mod find_schema_member_usages;
+
+use find_schema_member_usages::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn abstract_type_usages() {
+    let input = include_str!("find_schema_member_usages/fixtures/abstract_type_usages.graphql");
+    let expected = include_str!("find_schema_member_usages/fixtures/abstract_type_usages.expected");
+    test_fixture(
+        transform_fixture,
+        "abstract_type_usages.graphql",
+        "find_schema_member_usages/fixtures/abstract_type_usages.expected",
+        input,
+        expected,
+    );
+}
+
+#[test]
+fn enum_type_usages() {
+    let input = include_str!("find_schema_member_usages/fixtures/enum_type_usages.graphql");
+    let expected = include_str!("find_schema_member_usages/fixtures/enum_type_usages.expected");
+    test_fixture(
+        transform_fixture,
+        "enum_type_usages.graphql",
+        "find_schema_member_usages/fixtures/enum_type_usages.expected",
+        input,
+        expected,
+    );
+}
+
+#[test]
+fn field_usages() {
+    let input = include_str!("find_schema_member_usages/fixtures/field_usages.graphql");
+    let expected = include_str!("find_schema_member_usages/fixtures/field_usages.expected");
+    test_fixture(
+        transform_fixture,
+        "field_usages.graphql",
+        "find_schema_member_usages/fixtures/field_usages.expected",
+        input,
+        expected,
+    );
+}
+
+#[test]
+fn type_usages() {
+    let input = include_str!("find_schema_member_usages/fixtures/type_usages.graphql");
+    let expected = include_str!("find_schema_member_usages/fixtures/type_usages.expected");
+    test_fixture(
+        transform_fixture,
+        "type_usages.graphql",
+        "find_schema_member_usages/fixtures/type_usages.expected",
+        input,
+        expected,
+    );
+}
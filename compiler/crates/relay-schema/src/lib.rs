@@ -21,10 +21,12 @@ use common::SourceLocationKey;
 use intern::intern;
 use lazy_static::lazy_static;
 use schema::ArgumentDefinitions;
+use schema::BuildSchemaOptions;
 use schema::SDLSchema;
 use schema::TypeReference;
 
 const RELAY_EXTENSIONS: &str = include_str!("./relay-extensions.graphql");
+const FEDERATION_DIRECTIVES: &str = include_str!("./federation-directives.graphql");
 
 lazy_static! {
     static ref DEFER: DirectiveName = DirectiveName(intern!("defer"));
@@ -38,6 +40,48 @@ lazy_static! {
 pub fn build_schema_with_extensions<T: AsRef<str>, U: AsRef<str>>(
     server_sdls: &[(T, SourceLocationKey)],
     extension_sdls: &[(U, SourceLocationKey)],
+) -> DiagnosticsResult<SDLSchema> {
+    build_relay_schema(server_sdls, extension_sdls, Default::default())
+}
+
+/// Builds a schema with `options`, e.g. `FEDERATION_SCHEMA_OPTIONS` with some
+/// `removed_coordinates` for a variant of a federated schema.
+pub fn build_schema_with_extensions_and_options<T: AsRef<str>, U: AsRef<str>>(
+    server_sdls: &[(T, SourceLocationKey)],
+    extension_sdls: &[(U, SourceLocationKey)],
+    options: BuildSchemaOptions<'_>,
+) -> DiagnosticsResult<SDLSchema> {
+    build_relay_schema(server_sdls, extension_sdls, options)
+}
+
+/// The options of `build_federated_schema_with_extensions`.
+pub const FEDERATION_SCHEMA_OPTIONS: BuildSchemaOptions<'static> = BuildSchemaOptions {
+    default_server_definitions: Some(FEDERATION_DIRECTIVES),
+    define_extended_server_types: true,
+    removed_coordinates: &[],
+};
+
+/// Builds a schema of an Apollo Federation service. The federation directives
+/// (`@key`, `@external`, ...) are defined unless the schema defines them
+/// itself, and types extended without being defined (entities owned by other
+/// services) are treated as definitions.
+pub fn build_federated_schema_with_extensions<T: AsRef<str>, U: AsRef<str>>(
+    server_sdls: &[(T, SourceLocationKey)],
+    extension_sdls: &[(U, SourceLocationKey)],
+) -> DiagnosticsResult<SDLSchema> {
+    build_relay_schema(server_sdls, extension_sdls, FEDERATION_SCHEMA_OPTIONS)
+}
+
+/// Builds a schema with only the directives and types that Relay adds to every
+/// schema, which is useful to tell them apart from project extensions.
+pub fn build_relay_extensions_schema() -> DiagnosticsResult<SDLSchema> {
+    build_relay_schema::<&str, &str>(&[], &[], Default::default())
+}
+
+fn build_relay_schema<T: AsRef<str>, U: AsRef<str>>(
+    server_sdls: &[(T, SourceLocationKey)],
+    extension_sdls: &[(U, SourceLocationKey)],
+    options: BuildSchemaOptions<'_>,
 ) -> DiagnosticsResult<SDLSchema> {
     let extensions: Vec<(&str, SourceLocationKey)> =
         once((RELAY_EXTENSIONS, SourceLocationKey::generated()))
@@ -47,7 +91,8 @@ pub fn build_schema_with_extensions<T: AsRef<str>, U: AsRef<str>>(
                     .map(|(source, location_key)| (source.as_ref(), *location_key)),
             )
             .collect();
-    let mut schema = schema::build_schema_with_extensions(server_sdls, &extensions)?;
+    let mut schema =
+        schema::build_schema_with_extensions_and_options(server_sdls, &extensions, options)?;
 
     // Remove label arg from @defer and @stream directives since the compiler
     // adds these arguments.
@@ -66,3 +111,118 @@ pub fn build_schema_with_extensions<T: AsRef<str>, U: AsRef<str>>(
     }
     Ok(schema)
 }
+
+#[cfg(test)]
+mod tests {
+    use common::DirectiveName;
+    use common::SourceLocationKey;
+    use intern::intern;
+    use schema::BuildSchemaOptions;
+    use schema::Schema;
+
+    use super::build_federated_schema_with_extensions;
+    use super::build_schema_with_extensions;
+    use super::build_schema_with_extensions_and_options;
+
+    const FEDERATED_SCHEMA: &str = r#"
+        extend schema @link(url: "https://specs.apollo.dev/federation/v2.0", import: ["@key"])
+
+        directive @external on FIELD_DEFINITION
+
+        type Query {
+          me: User
+        }
+
+        type User @key(fields: "id") {
+          id: ID!
+          reviews: [Review] @requires(fields: "id")
+        }
+
+        extend type Review @key(fields: "id") {
+          id: ID! @external
+        }
+    "#;
+
+    #[test]
+    fn build_federated_schema() {
+        let schema = build_federated_schema_with_extensions::<_, &str>(
+            &[(FEDERATED_SCHEMA, SourceLocationKey::generated())],
+            &[],
+        )
+        .unwrap();
+        assert!(
+            schema
+                .get_directive(DirectiveName(intern!("key")))
+                .is_some()
+        );
+        assert!(schema.get_type(intern!("Review")).is_some());
+        // The definition of the schema takes precedence
+        let external = schema
+            .get_directive(DirectiveName(intern!("external")))
+            .unwrap();
+        assert!(external.arguments.is_empty());
+    }
+
+    #[test]
+    fn build_federated_schema_without_federation() {
+        assert!(
+            build_schema_with_extensions::<_, &str>(
+                &[(FEDERATED_SCHEMA, SourceLocationKey::generated())],
+                &[],
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn build_schema_with_removed_coordinates() {
+        let schema = build_schema_with_extensions_and_options::<_, &str>(
+            &[(
+                r#"
+                type Query {
+                  me: User
+                  story: Story
+                }
+
+                interface Actor {
+                  name: String
+                }
+
+                type User implements Actor {
+                  name: String
+                  birthday: String
+                }
+
+                type Story {
+                  color: Color
+                }
+
+                enum Color {
+                  RED
+                  GREEN
+                }
+                "#,
+                SourceLocationKey::generated(),
+            )],
+            &[],
+            BuildSchemaOptions {
+                removed_coordinates: &[
+                    "Actor".to_string(),
+                    "User.birthday".to_string(),
+                    "Color.GREEN".to_string(),
+                ],
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert!(schema.get_type(intern!("Actor")).is_none());
+        let user = schema.get_type(intern!("User")).unwrap();
+        assert!(schema.named_field(user, intern!("name")).is_some());
+        assert!(schema.named_field(user, intern!("birthday")).is_none());
+        let color = schema
+            .get_type(intern!("Color"))
+            .and_then(|type_| type_.get_enum_id())
+            .unwrap();
+        assert_eq!(schema.enum_(color).values.len(), 1);
+    }
+}
@@ -60,6 +60,19 @@ pub fn get_test_schema_with_located_extensions(
     )
 }
 
+/// Builds a schema from `schema_sdl` (e.g. the `schema.graphql` file of a
+/// fixtures directory), or the test schema if it's `None`, with the extensions.
+pub fn get_schema_with_located_extensions(
+    schema_sdl: Option<&str>,
+    extensions: &[(&str, SourceLocationKey)],
+) -> Arc<SDLSchema> {
+    let schema_sdl = schema_sdl.unwrap_or(TEST_SCHEMA_DATA);
+    Arc::new(
+        build_schema_with_extensions(&[(schema_sdl, SourceLocationKey::generated())], extensions)
+            .expect("Expected schema (and extensions) to be valid"),
+    )
+}
+
 pub fn get_test_schema_with_custom_id() -> Arc<SDLSchema> {
     Arc::clone(&TEST_SCHEMA_WITH_CUSTOM_ID)
 }
@@ -10,6 +10,7 @@ mod scope;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common::diagnostic_codes;
 use common::ArgumentName;
 use common::Diagnostic;
 use common::DiagnosticsResult;
@@ -691,16 +692,20 @@ fn no_inline_fragment_scope(fragment: &FragmentDefinition) -> Scope {
     scope
 }
 
-#[derive(Debug, Error)]
-enum ValidationMessage {
-    #[error("Found a circular reference from fragment '{fragment_name}'.")]
-    CircularFragmentReference {
-        fragment_name: FragmentDefinitionName,
-    },
-    #[error(
-        "Passing a value to '{original_definition_name}' (a provided variable) through @arguments is not supported."
-    )]
-    ProvidedVariableIncompatibleWithArguments {
-        original_definition_name: VariableName,
-    },
+diagnostic_codes! {
+    #[derive(Debug, Error)]
+    enum ValidationMessage {
+        #[error("Found a circular reference from fragment '{fragment_name}'.")]
+        #[code("RELAY2001")]
+        CircularFragmentReference {
+            fragment_name: FragmentDefinitionName,
+        },
+        #[error(
+            "Passing a value to '{original_definition_name}' (a provided variable) through @arguments is not supported."
+        )]
+        #[code("RELAY2002")]
+        ProvidedVariableIncompatibleWithArguments {
+            original_definition_name: VariableName,
+        },
+    }
 }
@@ -22,6 +22,7 @@ use crate::apply_custom_transforms::CustomTransformsConfig;
 use crate::assignable_fragment_spread::annotate_updatable_fragment_spreads;
 use crate::assignable_fragment_spread::replace_updatable_fragment_spreads;
 use crate::client_extensions_abstract_types::client_extensions_abstract_types;
+use crate::debug_transforms::IRDumper;
 use crate::disallow_non_node_id_fields;
 use crate::generate_relay_resolvers_model_fragments::generate_relay_resolvers_model_fragments;
 use crate::generate_relay_resolvers_operations_for_nested_objects::generate_relay_resolvers_operations_for_nested_objects;
@@ -44,6 +45,7 @@ pub fn apply_transforms<TPerfLogger>(
     perf_logger: Arc<TPerfLogger>,
     print_stats: Option<fn(extra_info: &str, program: &Program) -> ()>,
     custom_transforms_config: Option<&CustomTransformsConfig>,
+    debug_transforms: Option<&DebugTransformsConfig>,
 ) -> DiagnosticsResult<Programs>
 where
     TPerfLogger: PerfLogger + 'static,
@@ -68,6 +70,7 @@ where
                 Arc::clone(&base_fragment_names),
                 Arc::clone(&perf_logger),
                 custom_transforms_config,
+                debug_transforms,
             )?;
 
             try_join(
@@ -78,6 +81,7 @@ where
                         Arc::clone(&base_fragment_names),
                         Arc::clone(&perf_logger),
                         custom_transforms_config,
+                        debug_transforms,
                     )?;
 
                     try_join(
@@ -89,6 +93,7 @@ where
                                 Arc::clone(&perf_logger),
                                 print_stats,
                                 custom_transforms_config,
+                                debug_transforms,
                             )
                         },
                         || {
@@ -98,6 +103,7 @@ where
                                 Arc::clone(&base_fragment_names),
                                 Arc::clone(&perf_logger),
                                 custom_transforms_config,
+                                debug_transforms,
                             )
                         },
                     )
@@ -109,6 +115,7 @@ where
                         Arc::clone(&base_fragment_names),
                         Arc::clone(&perf_logger),
                         custom_transforms_config,
+                        debug_transforms,
                     )
                 },
             )
@@ -120,6 +127,7 @@ where
                 Arc::clone(&base_fragment_names),
                 Arc::clone(&perf_logger),
                 custom_transforms_config,
+                debug_transforms,
             )
         },
     )?;
@@ -140,9 +148,11 @@ fn apply_common_transforms(
     base_fragment_names: Arc<FragmentDefinitionNameSet>,
     perf_logger: Arc<impl PerfLogger>,
     custom_transforms_config: Option<&CustomTransformsConfig>,
+    debug_transforms: Option<&DebugTransformsConfig>,
 ) -> DiagnosticsResult<Arc<Program>> {
     let log_event = perf_logger.create_event("apply_common_transforms");
     log_event.string("project", project_config.name.to_string());
+    let ir_dumper = IRDumper::new(debug_transforms, "common");
 
     let custom_transforms = &custom_transforms_config.and_then(|c| c.common_transforms.as_ref());
     let mut program = apply_before_custom_transforms(
@@ -153,24 +163,45 @@ fn apply_common_transforms(
         &log_event,
         None,
     )?;
+    ir_dumper.dump("before_custom_transforms", &program);
+
+    program = log_event.time("feature_flag_directive", || {
+        feature_flag_directive(&program, &project_config.feature_flag_values)
+    });
+    ir_dumper.dump("feature_flag_directive", &program);
+
+    program = log_event.time("fragment_conditions", || {
+        fragment_conditions(
+            &program,
+            &project_config.feature_flags.enable_fragment_conditions,
+            &project_config.fragment_condition_defaults,
+        )
+    })?;
+    ir_dumper.dump("fragment_conditions", &program);
 
     program = log_event.time("transform_connections", || {
         transform_connections(&program, &project_config.schema_config.connection_interface)
     });
+    ir_dumper.dump("transform_connections", &program);
     program = log_event.time("mask", || mask(&program));
+    ir_dumper.dump("mask", &program);
     program = log_event.time("transform_defer_stream", || {
         transform_defer_stream(&program)
     })?;
+    ir_dumper.dump("transform_defer_stream", &program);
     program = log_event.time("transform_match", || {
         transform_match(
             &program,
             &project_config.feature_flags,
             project_config.module_import_config,
+            &project_config.artifact_filename,
         )
     })?;
+    ir_dumper.dump("transform_match", &program);
     program = log_event.time("transform_subscriptions", || {
-        transform_subscriptions(&program)
+        transform_subscriptions(&program, &project_config.artifact_filename)
     })?;
+    ir_dumper.dump("transform_subscriptions", &program);
     program = log_event.time("transform_refetchable_fragment", || {
         transform_refetchable_fragment(
             &program,
@@ -179,25 +210,35 @@ fn apply_common_transforms(
             false,
         )
     })?;
+    ir_dumper.dump("transform_refetchable_fragment", &program);
 
     if project_config.feature_flags.enable_flight_transform {
         program = log_event.time("react_flight", || react_flight(&program))?;
+        ir_dumper.dump("react_flight", &program);
         program = log_event.time("relay_client_component", || {
-            relay_client_component(&program, &project_config.feature_flags)
+            relay_client_component(
+                &program,
+                &project_config.feature_flags,
+                &project_config.artifact_filename,
+            )
         })?;
+        ir_dumper.dump("relay_client_component", &program);
     }
 
     program = log_event.time("relay_actor_change_transform", || {
         relay_actor_change_transform(&program, &project_config.feature_flags.actor_change_support)
     })?;
+    ir_dumper.dump("relay_actor_change_transform", &program);
 
     program = log_event.time("provided_variable_fragment_transform", || {
         provided_variable_fragment_transform(&program)
     })?;
+    ir_dumper.dump("provided_variable_fragment_transform", &program);
 
     program = log_event.time("generate_relay_resolvers_model_fragments", || {
         generate_relay_resolvers_model_fragments(&program, &project_config.schema_config)
     });
+    ir_dumper.dump("generate_relay_resolvers_model_fragments", &program);
 
     program = log_event.time(
         "generate_relay_resolvers_operations_for_nested_objects",
@@ -208,6 +249,10 @@ fn apply_common_transforms(
             )
         },
     )?;
+    ir_dumper.dump(
+        "generate_relay_resolvers_operations_for_nested_objects",
+        &program,
+    );
 
     program = apply_after_custom_transforms(
         &program,
@@ -217,6 +262,7 @@ fn apply_common_transforms(
         &log_event,
         None,
     )?;
+    ir_dumper.dump("after_custom_transforms", &program);
 
     log_event.complete();
 
@@ -231,9 +277,11 @@ fn apply_reader_transforms(
     base_fragment_names: Arc<FragmentDefinitionNameSet>,
     perf_logger: Arc<impl PerfLogger>,
     custom_transforms_config: Option<&CustomTransformsConfig>,
+    debug_transforms: Option<&DebugTransformsConfig>,
 ) -> DiagnosticsResult<Arc<Program>> {
     let log_event = perf_logger.create_event("apply_reader_transforms");
     log_event.string("project", project_config.name.to_string());
+    let ir_dumper = IRDumper::new(debug_transforms, "reader");
 
     let custom_transforms = &custom_transforms_config.and_then(|c| c.reader_transforms.as_ref());
     let mut program = apply_before_custom_transforms(
@@ -244,6 +292,7 @@ fn apply_reader_transforms(
         &log_event,
         None,
     )?;
+    ir_dumper.dump("before_custom_transforms", &program);
 
     program = log_event.time("fragment_alias_directive", || {
         fragment_alias_directive(
@@ -251,11 +300,14 @@ fn apply_reader_transforms(
             &project_config.feature_flags.enable_fragment_aliases,
         )
     })?;
+    ir_dumper.dump("fragment_alias_directive", &program);
 
     program = log_event.time("required_directive", || required_directive(&program))?;
+    ir_dumper.dump("required_directive", &program);
     program = log_event.time("client_edges", || {
         client_edges(&program, &project_config.schema_config)
     })?;
+    ir_dumper.dump("client_edges", &program);
 
     program = log_event.time("relay_resolvers", || {
         relay_resolvers(
@@ -263,33 +315,52 @@ fn apply_reader_transforms(
             project_config.feature_flags.enable_relay_resolver_transform,
         )
     })?;
+    ir_dumper.dump("relay_resolvers", &program);
 
     program = log_event.time("client_extensions", || client_extensions(&program));
+    ir_dumper.dump("client_extensions", &program);
     program = log_event.time("handle_field_transform", || {
         handle_field_transform(&program)
     });
+    ir_dumper.dump("handle_field_transform", &program);
 
     program = log_event.time(
         "transform_assignable_fragment_spreads_in_regular_queries",
         || transform_assignable_fragment_spreads_in_regular_queries(&program),
     )?;
+    ir_dumper.dump(
+        "transform_assignable_fragment_spreads_in_regular_queries",
+        &program,
+    );
 
     program = log_event.time("inline_data_fragment", || inline_data_fragment(&program))?;
+    ir_dumper.dump("inline_data_fragment", &program);
     program = log_event.time("skip_unreachable_node", || {
         skip_unreachable_node_strict(&program)
     })?;
+    ir_dumper.dump("skip_unreachable_node", &program);
     program = log_event.time("remove_base_fragments", || {
         remove_base_fragments(&program, &base_fragment_names)
     });
-
-    log_event.time("flatten", || flatten(&mut program, true, false))?;
-    program = log_event.time("skip_redundant_nodes", || skip_redundant_nodes(&program));
+    ir_dumper.dump("remove_base_fragments", &program);
+
+    if project_config.readable_artifacts {
+        log_event.time("flatten", || flatten_readable(&mut program))?;
+        ir_dumper.dump("flatten", &program);
+    } else {
+        log_event.time("flatten", || flatten(&mut program, true, false))?;
+        ir_dumper.dump("flatten", &program);
+        program = log_event.time("skip_redundant_nodes", || skip_redundant_nodes(&program));
+        ir_dumper.dump("skip_redundant_nodes", &program);
+    }
     program = log_event.time("generate_data_driven_dependency_metadata", || {
-        generate_data_driven_dependency_metadata(&program)
+        generate_data_driven_dependency_metadata(&program, &project_config.artifact_filename)
     });
+    ir_dumper.dump("generate_data_driven_dependency_metadata", &program);
     program = log_event.time("hash_supported_argument", || {
         hash_supported_argument(&program, &project_config.feature_flags)
     })?;
+    ir_dumper.dump("hash_supported_argument", &program);
 
     program = apply_after_custom_transforms(
         &program,
@@ -299,6 +370,7 @@ fn apply_reader_transforms(
         &log_event,
         None,
     )?;
+    ir_dumper.dump("after_custom_transforms", &program);
 
     log_event.complete();
 
@@ -313,9 +385,11 @@ fn apply_operation_transforms(
     base_fragment_names: Arc<FragmentDefinitionNameSet>,
     perf_logger: Arc<impl PerfLogger>,
     custom_transforms_config: Option<&CustomTransformsConfig>,
+    debug_transforms: Option<&DebugTransformsConfig>,
 ) -> DiagnosticsResult<Arc<Program>> {
     let log_event = perf_logger.create_event("apply_operation_transforms");
     log_event.string("project", project_config.name.to_string());
+    let ir_dumper = IRDumper::new(debug_transforms, "operation");
 
     let custom_transforms = &custom_transforms_config.and_then(|c| c.operation_transforms.as_ref());
     let mut program = apply_before_custom_transforms(
@@ -326,37 +400,45 @@ fn apply_operation_transforms(
         &log_event,
         None,
     )?;
+    ir_dumper.dump("before_custom_transforms", &program);
 
     program = log_event.time("skip_updatable_queries", || {
         skip_updatable_queries(&program)
     });
+    ir_dumper.dump("skip_updatable_queries", &program);
 
     program = log_event.time("client_edges", || {
         client_edges(&program, &project_config.schema_config)
     })?;
+    ir_dumper.dump("client_edges", &program);
     program = log_event.time("relay_resolvers", || {
         relay_resolvers(
             &program,
             project_config.feature_flags.enable_relay_resolver_transform,
         )
     })?;
+    ir_dumper.dump("relay_resolvers", &program);
 
     program = log_event.time("split_module_import", || {
         split_module_import(&program, &base_fragment_names)
     });
+    ir_dumper.dump("split_module_import", &program);
     program = log_event.time("generate_id_field", || {
         generate_id_field(&program, &project_config.schema_config)
     });
+    ir_dumper.dump("generate_id_field", &program);
     program = log_event.time("declarative_connection", || {
         transform_declarative_connection(
             &program,
             &project_config.schema_config.connection_interface,
         )
     })?;
+    ir_dumper.dump("declarative_connection", &program);
 
     program = log_event.time("generate_live_query_metadata", || {
         generate_live_query_metadata(&program)
     })?;
+    ir_dumper.dump("generate_live_query_metadata", &program);
 
     if project_config.schema_config.non_node_id_fields.is_some() {
         log_event.time("disallow_non_node_id_fields", || {
@@ -372,6 +454,7 @@ fn apply_operation_transforms(
         &log_event,
         None,
     )?;
+    ir_dumper.dump("after_custom_transforms", &program);
 
     log_event.complete();
 
@@ -389,9 +472,11 @@ fn apply_normalization_transforms(
     perf_logger: Arc<impl PerfLogger>,
     maybe_print_stats: Option<fn(extra_info: &str, program: &Program) -> ()>,
     custom_transforms_config: Option<&CustomTransformsConfig>,
+    debug_transforms: Option<&DebugTransformsConfig>,
 ) -> DiagnosticsResult<Arc<Program>> {
     let log_event = perf_logger.create_event("apply_normalization_transforms");
     log_event.string("project", project_config.name.to_string());
+    let ir_dumper = IRDumper::new(debug_transforms, "normalization");
     if let Some(print_stats) = maybe_print_stats {
         print_stats("normalization start", &program);
     }
@@ -406,6 +491,7 @@ fn apply_normalization_transforms(
         &log_event,
         maybe_print_stats,
     )?;
+    ir_dumper.dump("before_custom_transforms", &program);
 
     program = log_event.time("apply_fragment_arguments", || {
         apply_fragment_arguments(
@@ -415,6 +501,7 @@ fn apply_normalization_transforms(
             &base_fragment_names,
         )
     })?;
+    ir_dumper.dump("apply_fragment_arguments", &program);
     if let Some(print_stats) = maybe_print_stats {
         print_stats("apply_fragment_arguments", &program);
     }
@@ -422,6 +509,7 @@ fn apply_normalization_transforms(
     program = log_event.time("client_extensions_abstract_types", || {
         client_extensions_abstract_types(&program)
     });
+    ir_dumper.dump("client_extensions_abstract_types", &program);
 
     if let Some(print_stats) = maybe_print_stats {
         print_stats("client_extensions_abstract_types", &program);
@@ -430,6 +518,7 @@ fn apply_normalization_transforms(
     program = log_event.time("remove_client_edge_selections", || {
         remove_client_edge_selections(&program)
     })?;
+    ir_dumper.dump("remove_client_edge_selections", &program);
 
     if let Some(print_stats) = maybe_print_stats {
         print_stats("remove_client_edge_selections", &program);
@@ -438,10 +527,12 @@ fn apply_normalization_transforms(
     program = log_event.time("replace_updatable_fragment_spreads", || {
         replace_updatable_fragment_spreads(&program)
     });
+    ir_dumper.dump("replace_updatable_fragment_spreads", &program);
 
     program = log_event.time("hash_supported_argument", || {
         hash_supported_argument(&program, &project_config.feature_flags)
     })?;
+    ir_dumper.dump("hash_supported_argument", &program);
     if let Some(print_stats) = maybe_print_stats {
         print_stats("hash_supported_argument", &program);
     }
@@ -449,38 +540,56 @@ fn apply_normalization_transforms(
     program = log_event.time("skip_unreachable_node", || {
         skip_unreachable_node_strict(&program)
     })?;
+    ir_dumper.dump("skip_unreachable_node", &program);
     if let Some(print_stats) = maybe_print_stats {
         print_stats("skip_unreachable_node", &program);
     }
 
     program = log_event.time("inline_fragments", || inline_fragments(&program));
+    ir_dumper.dump("inline_fragments", &program);
     if let Some(print_stats) = maybe_print_stats {
         print_stats("inline_fragments", &program);
     }
 
     program = log_event.time("client_extensions", || client_extensions(&program));
+    ir_dumper.dump("client_extensions", &program);
     if let Some(print_stats) = maybe_print_stats {
         print_stats("client_extensions", &program);
     }
 
     program = log_event.time("generate_typename", || generate_typename(&program, true));
+    ir_dumper.dump("generate_typename", &program);
     if let Some(print_stats) = maybe_print_stats {
         print_stats("generate_typename", &program);
     }
 
     log_event.time("flatten", || flatten(&mut program, true, false))?;
+    ir_dumper.dump("flatten", &program);
     if let Some(print_stats) = maybe_print_stats {
         print_stats("flatten", &program);
     }
 
     program = log_event.time("skip_redundant_nodes", || skip_redundant_nodes(&program));
+    ir_dumper.dump("skip_redundant_nodes", &program);
     if let Some(print_stats) = maybe_print_stats {
         print_stats("skip_redundant_nodes", &program);
     }
 
+    program = log_event.time("merge_inline_fragments", || {
+        merge_inline_fragments(
+            &program,
+            &project_config.feature_flags.merge_inline_fragments,
+        )
+    });
+    ir_dumper.dump("merge_inline_fragments", &program);
+    if let Some(print_stats) = maybe_print_stats {
+        print_stats("merge_inline_fragments", &program);
+    }
+
     program = log_event.time("generate_test_operation_metadata", || {
         generate_test_operation_metadata(&program, &project_config.test_path_regex)
     })?;
+    ir_dumper.dump("generate_test_operation_metadata", &program);
     if let Some(print_stats) = maybe_print_stats {
         print_stats("generate_test_operation_metadata", &program);
     }
@@ -493,6 +602,7 @@ fn apply_normalization_transforms(
         &log_event,
         maybe_print_stats,
     )?;
+    ir_dumper.dump("after_custom_transforms", &program);
 
     log_event.complete();
 
@@ -509,9 +619,11 @@ fn apply_operation_text_transforms(
     base_fragment_names: Arc<FragmentDefinitionNameSet>,
     perf_logger: Arc<impl PerfLogger>,
     custom_transforms_config: Option<&CustomTransformsConfig>,
+    debug_transforms: Option<&DebugTransformsConfig>,
 ) -> DiagnosticsResult<Arc<Program>> {
     let log_event = perf_logger.create_event("apply_operation_text_transforms");
     log_event.string("project", project_config.name.to_string());
+    let ir_dumper = IRDumper::new(debug_transforms, "operation_text");
 
     let custom_transforms =
         &custom_transforms_config.and_then(|c| c.operation_text_transforms.as_ref());
@@ -523,6 +635,7 @@ fn apply_operation_text_transforms(
         &log_event,
         None,
     )?;
+    ir_dumper.dump("before_custom_transforms", &program);
 
     program = log_event.time("apply_fragment_arguments", || {
         apply_fragment_arguments(
@@ -532,10 +645,12 @@ fn apply_operation_text_transforms(
             &base_fragment_names,
         )
     })?;
+    ir_dumper.dump("apply_fragment_arguments", &program);
 
     program = log_event.time("remove_client_edge_selections", || {
         remove_client_edge_selections(&program)
     })?;
+    ir_dumper.dump("remove_client_edge_selections", &program);
 
     log_event.time("validate_global_variables", || {
         validate_global_variables(&program)
@@ -544,14 +659,18 @@ fn apply_operation_text_transforms(
     program = log_event.time("replace_updatable_fragment_spreads", || {
         replace_updatable_fragment_spreads(&program)
     });
+    ir_dumper.dump("replace_updatable_fragment_spreads", &program);
 
     program = log_event.time("skip_split_operation", || skip_split_operation(&program));
+    ir_dumper.dump("skip_split_operation", &program);
     program = log_event.time("skip_unreachable_node_strict", || {
         skip_unreachable_node_strict(&program)
     })?;
+    ir_dumper.dump("skip_unreachable_node_strict", &program);
     program = log_event.time("skip_null_arguments_transform", || {
         skip_null_arguments_transform(&program)
     });
+    ir_dumper.dump("skip_null_arguments_transform", &program);
     log_event.time("validate_selection_conflict", || {
         graphql_ir_validations::validate_selection_conflict::<RelayLocationAgnosticBehavior>(
             &program, false,
@@ -560,24 +679,31 @@ fn apply_operation_text_transforms(
     program = log_event.time("skip_client_extensions", || {
         skip_client_extensions(&program)
     });
+    ir_dumper.dump("skip_client_extensions", &program);
     program = log_event.time("skip_unreachable_node_loose", || {
         skip_unreachable_node_loose(&program)
     });
+    ir_dumper.dump("skip_unreachable_node_loose", &program);
 
     program = log_event.time("generate_typename", || generate_typename(&program, false));
+    ir_dumper.dump("generate_typename", &program);
     log_event.time("flatten", || flatten(&mut program, false, true))?;
+    ir_dumper.dump("flatten", &program);
     program = log_event.time("validate_operation_variables", || {
         validate_operation_variables(&program)
     })?;
+    ir_dumper.dump("validate_operation_variables", &program);
     program = log_event.time("skip_client_directives", || {
         skip_client_directives(&program)
     });
+    ir_dumper.dump("skip_client_directives", &program);
     log_event.time("validate_required_arguments", || {
         validate_required_arguments(&program)
     })?;
     program = log_event.time("unwrap_custom_directive_selection", || {
         unwrap_custom_directive_selection(&program)
     });
+    ir_dumper.dump("unwrap_custom_directive_selection", &program);
 
     program = apply_after_custom_transforms(
         &program,
@@ -587,6 +713,7 @@ fn apply_operation_text_transforms(
         &log_event,
         None,
     )?;
+    ir_dumper.dump("after_custom_transforms", &program);
 
     log_event.complete();
 
@@ -599,9 +726,11 @@ fn apply_typegen_transforms(
     base_fragment_names: Arc<FragmentDefinitionNameSet>,
     perf_logger: Arc<impl PerfLogger>,
     custom_transforms_config: Option<&CustomTransformsConfig>,
+    debug_transforms: Option<&DebugTransformsConfig>,
 ) -> DiagnosticsResult<Arc<Program>> {
     let log_event = perf_logger.create_event("apply_typegen_transforms");
     log_event.string("project", project_config.name.to_string());
+    let ir_dumper = IRDumper::new(debug_transforms, "typegen");
 
     let custom_transforms = &custom_transforms_config.and_then(|c| c.typegen_transforms.as_ref());
     let mut program = apply_before_custom_transforms(
@@ -612,6 +741,21 @@ fn apply_typegen_transforms(
         &log_event,
         None,
     )?;
+    ir_dumper.dump("before_custom_transforms", &program);
+
+    program = log_event.time("feature_flag_directive", || {
+        feature_flag_directive(&program, &project_config.feature_flag_values)
+    });
+    ir_dumper.dump("feature_flag_directive", &program);
+
+    program = log_event.time("fragment_conditions", || {
+        fragment_conditions(
+            &program,
+            &project_config.feature_flags.enable_fragment_conditions,
+            &project_config.fragment_condition_defaults,
+        )
+    })?;
+    ir_dumper.dump("fragment_conditions", &program);
 
     program = log_event.time("fragment_alias_directive", || {
         fragment_alias_directive(
@@ -619,22 +763,29 @@ fn apply_typegen_transforms(
             &project_config.feature_flags.enable_fragment_aliases,
         )
     })?;
+    ir_dumper.dump("fragment_alias_directive", &program);
 
     program = log_event.time("mask", || mask(&program));
+    ir_dumper.dump("mask", &program);
     program = log_event.time("transform_match", || {
         transform_match(
             &program,
             &project_config.feature_flags,
             project_config.module_import_config,
+            &project_config.artifact_filename,
         )
     })?;
+    ir_dumper.dump("transform_match", &program);
     program = log_event.time("transform_subscriptions", || {
-        transform_subscriptions(&program)
+        transform_subscriptions(&program, &project_config.artifact_filename)
     })?;
+    ir_dumper.dump("transform_subscriptions", &program);
     program = log_event.time("required_directive", || required_directive(&program))?;
+    ir_dumper.dump("required_directive", &program);
     program = log_event.time("generate_relay_resolvers_model_fragments", || {
         generate_relay_resolvers_model_fragments(&program, &project_config.schema_config)
     });
+    ir_dumper.dump("generate_relay_resolvers_model_fragments", &program);
     program = log_event.time(
         "generate_relay_resolvers_operations_for_nested_objects",
         || {
@@ -644,22 +795,36 @@ fn apply_typegen_transforms(
             )
         },
     )?;
+    ir_dumper.dump(
+        "generate_relay_resolvers_operations_for_nested_objects",
+        &program,
+    );
 
     program = log_event.time("client_edges", || {
         client_edges(&program, &project_config.schema_config)
     })?;
+    ir_dumper.dump("client_edges", &program);
 
     program = log_event.time(
         "transform_assignable_fragment_spreads_in_regular_queries",
         || transform_assignable_fragment_spreads_in_regular_queries(&program),
     )?;
+    ir_dumper.dump(
+        "transform_assignable_fragment_spreads_in_regular_queries",
+        &program,
+    );
     program = log_event.time(
         "transform_assignable_fragment_spreads_in_updatable_queries",
         || transform_assignable_fragment_spreads_in_updatable_queries(&program),
     );
+    ir_dumper.dump(
+        "transform_assignable_fragment_spreads_in_updatable_queries",
+        &program,
+    );
     program = log_event.time("annotate_updatable_fragment_spreads", || {
         annotate_updatable_fragment_spreads(&program)
     });
+    ir_dumper.dump("annotate_updatable_fragment_spreads", &program);
 
     program = log_event.time("relay_resolvers", || {
         relay_resolvers(
@@ -667,7 +832,9 @@ fn apply_typegen_transforms(
             project_config.feature_flags.enable_relay_resolver_transform,
         )
     })?;
+    ir_dumper.dump("relay_resolvers", &program);
     log_event.time("flatten", || flatten(&mut program, false, false))?;
+    ir_dumper.dump("flatten", &program);
     program = log_event.time("transform_refetchable_fragment", || {
         transform_refetchable_fragment(
             &program,
@@ -676,13 +843,16 @@ fn apply_typegen_transforms(
             true,
         )
     })?;
+    ir_dumper.dump("transform_refetchable_fragment", &program);
     program = log_event.time("remove_base_fragments", || {
         remove_base_fragments(&program, &base_fragment_names)
     });
+    ir_dumper.dump("remove_base_fragments", &program);
 
     program = log_event.time("relay_actor_change_transform", || {
         relay_actor_change_transform(&program, &project_config.feature_flags.actor_change_support)
     })?;
+    ir_dumper.dump("relay_actor_change_transform", &program);
 
     program = apply_after_custom_transforms(
         &program,
@@ -692,6 +862,7 @@ fn apply_typegen_transforms(
         &log_event,
         None,
     )?;
+    ir_dumper.dump("after_custom_transforms", &program);
 
     log_event.complete();
 
@@ -262,6 +262,42 @@ impl<'a> UpdatableDirective<'a> {
             Err(errors)
         }
     }
+
+    /// The items of a plural linked field can be reassigned (e.g. to reorder
+    /// them) by passing them back to the setter, which identifies each item by
+    /// its `__id`. Hence, `__id` must not be aliased there.
+    fn validate_plural_field_client_id(&self, parent_field: &LinkedField) -> DiagnosticsResult<()> {
+        let schema = &self.program.schema;
+        if !schema.field(parent_field.definition.item).type_.is_list() {
+            return Ok(());
+        }
+        let errors = parent_field
+            .selections
+            .iter()
+            .filter_map(|selection| match selection {
+                Selection::ScalarField(scalar_field)
+                    if scalar_field.definition.item == schema.clientid_field() =>
+                {
+                    scalar_field.alias
+                }
+                _ => None,
+            })
+            .map(|alias| {
+                Diagnostic::error(
+                    ValidationMessage::UpdatablePluralFieldAliasedClientId {
+                        outer_type_plural: self.executable_definition_info.unwrap().type_plural,
+                        parent_field_alias_or_name: parent_field.alias_or_name(schema),
+                    },
+                    alias.location,
+                )
+            })
+            .collect::<Vec<_>>();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
 }
 
 impl<'a> Validator for UpdatableDirective<'a> {
@@ -316,6 +352,7 @@ impl<'a> Validator for UpdatableDirective<'a> {
         validate!(
             self.validate_fragment_spreads_with_parent(linked_field, fragment_spreads),
             self.validate_inline_fragments_with_parent(linked_field, inline_fragments.collect()),
+            self.validate_plural_field_client_id(linked_field),
             self.validate_selections(&linked_field.selections),
             self.validate_directives(&linked_field.directives)
         )
@@ -0,0 +1,83 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::cell::Cell;
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use graphql_ir::OperationDefinitionName;
+use graphql_ir::Program;
+use graphql_text_printer::print_full_operation;
+use graphql_text_printer::PrinterOptions;
+use intern::string_key::StringKey;
+use log::warn;
+
+/// Configures `apply_transforms` to write the printed IR of one operation
+/// (with the fragments it spreads) after every transform.
+#[derive(Debug, Clone)]
+pub struct DebugTransformsConfig {
+    pub operation_name: StringKey,
+    pub output_dir: PathBuf,
+}
+
+/// Writes the IR of one pipeline of `apply_transforms` to
+/// `<output_dir>/<pipeline>/<step>_<transform>.graphql`. Does nothing if no
+/// `DebugTransformsConfig` is given, or if the operation isn't in the program.
+pub(crate) struct IRDumper<'a> {
+    config: Option<&'a DebugTransformsConfig>,
+    pipeline: &'static str,
+    step: Cell<usize>,
+}
+
+impl<'a> IRDumper<'a> {
+    pub(crate) fn new(config: Option<&'a DebugTransformsConfig>, pipeline: &'static str) -> Self {
+        Self {
+            config,
+            pipeline,
+            step: Cell::new(0),
+        }
+    }
+
+    pub(crate) fn dump(&self, transform: &str, program: &Program) {
+        let config = match self.config {
+            Some(config) => config,
+            None => return,
+        };
+        let operation = match program.operation(OperationDefinitionName(config.operation_name)) {
+            Some(operation) => operation,
+            None => return,
+        };
+        let step = self.step.get();
+        self.step.set(step + 1);
+
+        let text = print_full_operation(program, operation, PrinterOptions::default());
+        let dir = config.output_dir.join(self.pipeline);
+        let path = dir.join(format!("{:03}_{}.graphql", step, transform));
+        write_ir(&dir, &path, transform, text);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write_ir(dir: &Path, path: &Path, transform: &str, text: String) {
+    if let Err(error) = fs::create_dir_all(dir).and_then(|_| fs::write(path, text)) {
+        warn!(
+            "Unable to write the IR after `{}` to {:?}: {}",
+            transform, path, error
+        );
+    }
+}
+
+/// There is no file system to write to when compiling to WebAssembly.
+#[cfg(target_arch = "wasm32")]
+fn write_ir(_dir: &Path, path: &Path, transform: &str, _text: String) {
+    warn!(
+        "Unable to write the IR after `{}` to {:?}: no file system in WebAssembly",
+        transform, path
+    );
+}
@@ -7,6 +7,7 @@
 
 use std::sync::Arc;
 
+use common::diagnostic_codes;
 use common::ArgumentName;
 use common::Diagnostic;
 use common::DiagnosticsResult;
@@ -340,63 +341,73 @@ impl Transformer for DeclarativeConnectionMutationTransform<'_> {
     }
 }
 
-#[derive(Debug, Error)]
-enum ValidationMessage {
-    #[error(
-        "Unsupported use of @{directive_name} on field '${field_name}', 'edgeTypeName' argument must be provided."
-    )]
-    NodeDirectiveMissesRequiredEdgeTypeName {
-        directive_name: DirectiveName,
-        field_name: StringKey,
-    },
+diagnostic_codes! {
+    #[derive(Debug, Error)]
+    enum ValidationMessage {
+        #[error(
+            "Unsupported use of @{directive_name} on field '${field_name}', 'edgeTypeName' argument must be provided."
+        )]
+        #[code("RELAY2031")]
+        NodeDirectiveMissesRequiredEdgeTypeName {
+            directive_name: DirectiveName,
+            field_name: StringKey,
+        },
 
-    #[error("Invalid use of @{directive_name} on scalar field '{field_name}'.")]
-    ConnectionMutationDirectiveOnScalarField {
-        directive_name: DirectiveName,
-        field_name: StringKey,
-    },
+        #[error("Invalid use of @{directive_name} on scalar field '{field_name}'.")]
+        #[code("RELAY2032")]
+        ConnectionMutationDirectiveOnScalarField {
+            directive_name: DirectiveName,
+            field_name: StringKey,
+        },
 
-    #[error(
-        "Invalid use of @{directive_name} on field '{field_name}'. Expected field type 'ID', got '{current_type}'."
-    )]
-    DeleteRecordDirectiveOnUnsupportedType {
-        directive_name: DirectiveName,
-        field_name: StringKey,
-        current_type: String,
-    },
+        #[error(
+            "Invalid use of @{directive_name} on field '{field_name}'. Expected field type 'ID', got '{current_type}'."
+        )]
+        #[code("RELAY2033")]
+        DeleteRecordDirectiveOnUnsupportedType {
+            directive_name: DirectiveName,
+            field_name: StringKey,
+            current_type: String,
+        },
 
-    #[error("Invalid use of @{directive_name} on linked field '{field_name}'.")]
-    DeleteRecordDirectiveOnLinkedField {
-        directive_name: DirectiveName,
-        field_name: StringKey,
-    },
+        #[error("Invalid use of @{directive_name} on linked field '{field_name}'.")]
+        #[code("RELAY2034")]
+        DeleteRecordDirectiveOnLinkedField {
+            directive_name: DirectiveName,
+            field_name: StringKey,
+        },
 
-    #[error(
-        "Invalid use of @{edge_directive_name} and @{node_directive_name} on field '{field_name}' - these directives cannot be used together."
-    )]
-    ConflictingEdgeAndNodeDirectives {
-        edge_directive_name: DirectiveName,
-        node_directive_name: DirectiveName,
-        field_name: StringKey,
-    },
+        #[error(
+            "Invalid use of @{edge_directive_name} and @{node_directive_name} on field '{field_name}' - these directives cannot be used together."
+        )]
+        #[code("RELAY2035")]
+        ConflictingEdgeAndNodeDirectives {
+            edge_directive_name: DirectiveName,
+            node_directive_name: DirectiveName,
+            field_name: StringKey,
+        },
 
-    #[error("Expected the 'connections' argument to be defined on @{directive_name}.")]
-    ConnectionsArgumentRequired { directive_name: DirectiveName },
+        #[error("Expected the 'connections' argument to be defined on @{directive_name}.")]
+        #[code("RELAY2036")]
+        ConnectionsArgumentRequired { directive_name: DirectiveName },
 
-    #[error(
-        "Unsupported use of @{directive_name} on field '{field_name}', expected an edge field (a field with 'cursor' and 'node' selection)."
-    )]
-    EdgeDirectiveOnUnsupportedType {
-        directive_name: DirectiveName,
-        field_name: StringKey,
-    },
+        #[error(
+            "Unsupported use of @{directive_name} on field '{field_name}', expected an edge field (a field with 'cursor' and 'node' selection)."
+        )]
+        #[code("RELAY2037")]
+        EdgeDirectiveOnUnsupportedType {
+            directive_name: DirectiveName,
+            field_name: StringKey,
+        },
 
-    #[error(
-        "Unsupported use of @{directive_name} on field '{field_name}'. Expected an object, union or interface, but got '{current_type}'."
-    )]
-    NodeDirectiveOnUnsupportedType {
-        directive_name: DirectiveName,
-        field_name: StringKey,
-        current_type: String,
-    },
+        #[error(
+            "Unsupported use of @{directive_name} on field '{field_name}'. Expected an object, union or interface, but got '{current_type}'."
+        )]
+        #[code("RELAY2038")]
+        NodeDirectiveOnUnsupportedType {
+            directive_name: DirectiveName,
+            field_name: StringKey,
+            current_type: String,
+        },
+    }
 }
@@ -10,6 +10,7 @@ mod directives;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common::diagnostic_codes;
 use common::ArgumentName;
 use common::Diagnostic;
 use common::DiagnosticsResult;
@@ -424,32 +425,40 @@ fn get_literal_string_argument(
     }
 }
 
-#[derive(Debug, Error)]
-enum ValidationMessage {
-    #[error(
-        "Invalid use of @{directive_name}, the provided label is not unique. Specify a unique 'label' as a literal string."
-    )]
-    LabelNotUniqueForDeferStream { directive_name: DirectiveName },
-
-    #[error("Field '{field_name}' is not of list type, therefore cannot use @stream directive.")]
-    StreamFieldIsNotAList { field_name: StringKey },
-
-    #[error("Invalid use of @stream, the 'initial_count' argument is required.")]
-    StreamInitialCountRequired,
-
-    #[error(
-        "Invalid use of @defer on an inline fragment. Relay only supports @defer on fragment spreads."
-    )]
-    InvalidDeferOnInlineFragment,
-
-    #[error("Invalid use of @stream on scalar field '{field_name}'")]
-    InvalidStreamOnScalarField { field_name: StringKey },
-
-    #[error(
-        "Expected the '{arg_name}' value to @{directive_name} to be a string literal if provided."
-    )]
-    LiteralStringArgumentExpectedForDirective {
-        arg_name: ArgumentName,
-        directive_name: DirectiveName,
-    },
+diagnostic_codes! {
+    #[derive(Debug, Error)]
+    enum ValidationMessage {
+        #[error(
+            "Invalid use of @{directive_name}, the provided label is not unique. Specify a unique 'label' as a literal string."
+        )]
+        #[code("RELAY2039")]
+        LabelNotUniqueForDeferStream { directive_name: DirectiveName },
+
+        #[error("Field '{field_name}' is not of list type, therefore cannot use @stream directive.")]
+        #[code("RELAY2040")]
+        StreamFieldIsNotAList { field_name: StringKey },
+
+        #[error("Invalid use of @stream, the 'initial_count' argument is required.")]
+        #[code("RELAY2041")]
+        StreamInitialCountRequired,
+
+        #[error(
+            "Invalid use of @defer on an inline fragment. Relay only supports @defer on fragment spreads."
+        )]
+        #[code("RELAY2042")]
+        InvalidDeferOnInlineFragment,
+
+        #[error("Invalid use of @stream on scalar field '{field_name}'")]
+        #[code("RELAY2043")]
+        InvalidStreamOnScalarField { field_name: StringKey },
+
+        #[error(
+            "Expected the '{arg_name}' value to @{directive_name} to be a string literal if provided."
+        )]
+        #[code("RELAY2148")]
+        LiteralStringArgumentExpectedForDirective {
+            arg_name: ArgumentName,
+            directive_name: DirectiveName,
+        },
+    }
 }
@@ -0,0 +1,147 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::ArgumentName;
+use common::DirectiveName;
+use common::NamedItem;
+use graphql_ir::OperationDefinition;
+use graphql_syntax::ConstantValue;
+use graphql_syntax::ExecutableDefinition;
+use graphql_syntax::Value;
+use intern::Lookup;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use lazy_static::lazy_static;
+
+lazy_static! {
+    pub static ref EXPERIMENT_VARIANT_DIRECTIVE_NAME: DirectiveName =
+        DirectiveName("experimentVariant".intern());
+    static ref NAME_ARGUMENT_NAME: ArgumentName = ArgumentName("name".intern());
+}
+
+/// Renames the operation to `<name>_<variant>` if it has an
+/// `@experimentVariant(name: "<variant>")`, so that the variants of an
+/// operation defined in several places during an experiment have their own
+/// artifacts. Returns the variant.
+pub fn rename_experiment_variant(definition: &mut ExecutableDefinition) -> Option<StringKey> {
+    let operation = match definition {
+        ExecutableDefinition::Operation(operation) => operation,
+        ExecutableDefinition::Fragment(_) => return None,
+    };
+    let variant = operation
+        .directives
+        .iter()
+        .find(|directive| directive.name.value == EXPERIMENT_VARIANT_DIRECTIVE_NAME.0)?
+        .arguments
+        .as_ref()?
+        .items
+        .iter()
+        .find(|argument| argument.name.value == NAME_ARGUMENT_NAME.0)
+        .and_then(|argument| match &argument.value {
+            Value::Constant(ConstantValue::String(string)) => Some(string.value),
+            _ => None,
+        })?;
+    let name = operation.name.as_mut()?;
+    name.value = format!("{}_{}", name.value, variant).intern();
+    Some(variant)
+}
+
+/// The name of the operation as written in its source, without the suffix of
+/// its `@experimentVariant`.
+pub fn unsuffixed_operation_name(operation: &OperationDefinition) -> StringKey {
+    let name = operation.name.item.0;
+    operation
+        .directives
+        .named(*EXPERIMENT_VARIANT_DIRECTIVE_NAME)
+        .and_then(|directive| directive.arguments.named(*NAME_ARGUMENT_NAME))
+        .and_then(|argument| argument.value.item.get_string_literal())
+        .and_then(|variant| {
+            name.lookup()
+                .strip_suffix(&format!("_{}", variant))
+                .map(|name| name.intern())
+        })
+        .unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::SourceLocationKey;
+    use graphql_ir::build_ir_in_relay_mode;
+    use graphql_syntax::parse_executable;
+    use relay_test_schema::get_test_schema;
+
+    use super::*;
+
+    fn parse(text: &str) -> Vec<ExecutableDefinition> {
+        parse_executable(text, SourceLocationKey::generated())
+            .unwrap()
+            .definitions
+    }
+
+    #[test]
+    fn rename_experiment_variant_test() {
+        let mut definitions = parse(
+            r#"
+            query ProfileQuery @experimentVariant(name: "treatment") { me { id } }
+            query ProfileQuery @experimentVariant(name: "control") { me { name } }
+            query SearchQuery { me { id } }
+            fragment ProfileFragment on User { id }
+            "#,
+        );
+        let variants: Vec<_> = definitions
+            .iter_mut()
+            .map(rename_experiment_variant)
+            .collect();
+        assert_eq!(
+            variants,
+            vec![
+                Some("treatment".intern()),
+                Some("control".intern()),
+                None,
+                None
+            ]
+        );
+        let names: Vec<_> = definitions
+            .iter()
+            .map(|definition| definition.name().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "ProfileQuery_treatment".intern(),
+                "ProfileQuery_control".intern(),
+                "SearchQuery".intern(),
+                "ProfileFragment".intern()
+            ]
+        );
+    }
+
+    #[test]
+    fn unsuffixed_operation_name_test() {
+        let mut definitions = parse(
+            r#"
+            query ProfileQuery @experimentVariant(name: "treatment") { me { id } }
+            query SearchQuery { me { id } }
+            "#,
+        );
+        definitions.iter_mut().for_each(|definition| {
+            rename_experiment_variant(definition);
+        });
+        let schema = get_test_schema();
+        let names: Vec<_> = build_ir_in_relay_mode(&schema, &definitions)
+            .unwrap()
+            .iter()
+            .map(|definition| match definition {
+                graphql_ir::ExecutableDefinition::Operation(operation) => {
+                    unsuffixed_operation_name(operation)
+                }
+                graphql_ir::ExecutableDefinition::Fragment(_) => unreachable!(),
+            })
+            .collect();
+        assert_eq!(names, vec!["ProfileQuery".intern(), "SearchQuery".intern()]);
+    }
+}
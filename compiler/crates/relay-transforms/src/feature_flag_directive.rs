@@ -0,0 +1,143 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::ArgumentName;
+use common::DirectiveName;
+use common::NamedItem;
+use fnv::FnvBuildHasher;
+use graphql_ir::Directive;
+use graphql_ir::FragmentSpread;
+use graphql_ir::InlineFragment;
+use graphql_ir::LinkedField;
+use graphql_ir::Program;
+use graphql_ir::ScalarField;
+use graphql_ir::Selection;
+use graphql_ir::Transformed;
+use graphql_ir::Transformer;
+use indexmap::IndexMap;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use lazy_static::lazy_static;
+
+lazy_static! {
+    pub static ref FEATURE_FLAG_DIRECTIVE_NAME: DirectiveName =
+        DirectiveName("featureFlag".intern());
+    static ref NAME_ARGUMENT_NAME: ArgumentName = ArgumentName("name".intern());
+}
+
+/// Removes the selections with `@featureFlag(name: "...")` whose flag is
+/// missing or disabled in `feature_flag_values`, and the directive from the
+/// selections whose flag is enabled.
+pub fn feature_flag_directive(
+    program: &Program,
+    feature_flag_values: &IndexMap<StringKey, bool, FnvBuildHasher>,
+) -> Program {
+    let mut transform = FeatureFlagDirective {
+        feature_flag_values,
+    };
+    transform
+        .transform_program(program)
+        .replace_or_else(|| program.clone())
+}
+
+struct FeatureFlagDirective<'a> {
+    feature_flag_values: &'a IndexMap<StringKey, bool, FnvBuildHasher>,
+}
+
+impl<'a> FeatureFlagDirective<'a> {
+    /// `None` if the selection has no `@featureFlag`, otherwise whether it is
+    /// kept, with its directives without `@featureFlag`.
+    fn transform_feature_flag_directives(
+        &self,
+        directives: &[Directive],
+    ) -> Option<(bool, Vec<Directive>)> {
+        let directive = directives.named(*FEATURE_FLAG_DIRECTIVE_NAME)?;
+        let enabled = directive
+            .arguments
+            .named(*NAME_ARGUMENT_NAME)
+            .and_then(|argument| argument.value.item.get_string_literal())
+            .and_then(|name| self.feature_flag_values.get(&name))
+            .copied()
+            .unwrap_or(false);
+        Some((
+            enabled,
+            directives
+                .iter()
+                .filter(|directive| directive.name.item != *FEATURE_FLAG_DIRECTIVE_NAME)
+                .cloned()
+                .collect(),
+        ))
+    }
+}
+
+impl<'a> Transformer for FeatureFlagDirective<'a> {
+    const NAME: &'static str = "FeatureFlagDirectiveTransform";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn transform_scalar_field(&mut self, field: &ScalarField) -> Transformed<Selection> {
+        match self.transform_feature_flag_directives(&field.directives) {
+            None => Transformed::Keep,
+            Some((false, _)) => Transformed::Delete,
+            Some((true, directives)) => {
+                Transformed::Replace(Selection::ScalarField(Arc::new(ScalarField {
+                    directives,
+                    ..field.clone()
+                })))
+            }
+        }
+    }
+
+    fn transform_linked_field(&mut self, field: &LinkedField) -> Transformed<Selection> {
+        match self.transform_feature_flag_directives(&field.directives) {
+            None => self.default_transform_linked_field(field),
+            Some((false, _)) => Transformed::Delete,
+            Some((true, directives)) => {
+                let selections = self
+                    .transform_selections(&field.selections)
+                    .replace_or_else(|| field.selections.clone());
+                Transformed::Replace(Selection::LinkedField(Arc::new(LinkedField {
+                    directives,
+                    selections,
+                    ..field.clone()
+                })))
+            }
+        }
+    }
+
+    fn transform_inline_fragment(&mut self, fragment: &InlineFragment) -> Transformed<Selection> {
+        match self.transform_feature_flag_directives(&fragment.directives) {
+            None => self.default_transform_inline_fragment(fragment),
+            Some((false, _)) => Transformed::Delete,
+            Some((true, directives)) => {
+                let selections = self
+                    .transform_selections(&fragment.selections)
+                    .replace_or_else(|| fragment.selections.clone());
+                Transformed::Replace(Selection::InlineFragment(Arc::new(InlineFragment {
+                    directives,
+                    selections,
+                    ..fragment.clone()
+                })))
+            }
+        }
+    }
+
+    fn transform_fragment_spread(&mut self, spread: &FragmentSpread) -> Transformed<Selection> {
+        match self.transform_feature_flag_directives(&spread.directives) {
+            None => Transformed::Keep,
+            Some((false, _)) => Transformed::Delete,
+            Some((true, directives)) => {
+                Transformed::Replace(Selection::FragmentSpread(Arc::new(FragmentSpread {
+                    directives,
+                    ..spread.clone()
+                })))
+            }
+        }
+    }
+}
@@ -60,6 +60,27 @@ pub fn flatten(
     program: &mut Program,
     is_for_codegen: bool,
     should_validate_fragment_spreads: bool,
+) -> DiagnosticsResult<()> {
+    flatten_impl(
+        program,
+        is_for_codegen,
+        should_validate_fragment_spreads,
+        false,
+    )
+}
+
+/// Like `flatten` for codegen, but keeps inline fragments on concrete types
+/// even if they match the type of their parent, so that reader ASTs of
+/// readable artifacts mirror the authored fragments.
+pub fn flatten_readable(program: &mut Program) -> DiagnosticsResult<()> {
+    flatten_impl(program, true, false, true)
+}
+
+fn flatten_impl(
+    program: &mut Program,
+    is_for_codegen: bool,
+    should_validate_fragment_spreads: bool,
+    should_keep_concrete_inline_fragments: bool,
 ) -> DiagnosticsResult<()> {
     let mut fragment_for_validation = FragmentDefinitionNameMap::default();
     if should_validate_fragment_spreads {
@@ -73,6 +94,7 @@ pub fn flatten(
         fragment_for_validation,
         is_for_codegen,
         should_validate_fragment_spreads,
+        should_keep_concrete_inline_fragments,
     );
     let errors = Arc::new(Mutex::new(Vec::new()));
 
@@ -99,6 +121,7 @@ struct FlattenTransform {
     schema: Arc<SDLSchema>,
     is_for_codegen: bool,
     should_validate_fragment_spreads: bool,
+    should_keep_concrete_inline_fragments: bool,
     seen_linked_fields: SeenLinkedFields,
     seen_inline_fragments: SeenInlineFragments,
 }
@@ -109,12 +132,14 @@ impl FlattenTransform {
         fragments: FragmentDefinitionNameMap<Arc<FragmentDefinition>>,
         is_for_codegen: bool,
         should_validate_fragment_spreads: bool,
+        should_keep_concrete_inline_fragments: bool,
     ) -> Self {
         Self {
             schema,
             fragments,
             is_for_codegen,
             should_validate_fragment_spreads,
+            should_keep_concrete_inline_fragments,
             seen_linked_fields: Default::default(),
             seen_inline_fragments: Default::default(),
         }
@@ -303,6 +328,8 @@ impl FlattenTransform {
         for selection in selections {
             if let Selection::InlineFragment(inline_fragment) = selection {
                 if should_flatten_inline_fragment(inline_fragment, parent_type, self.is_for_codegen)
+                    && !(self.should_keep_concrete_inline_fragments
+                        && matches!(inline_fragment.type_condition, Some(Type::Object(_))))
                 {
                     has_changes = true;
                     self.flatten_selections(
@@ -0,0 +1,323 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::ArgumentName;
+use common::Diagnostic;
+use common::DiagnosticsResult;
+use common::DirectiveName;
+use common::FeatureFlag;
+use common::Location;
+use common::NamedItem;
+use fnv::FnvBuildHasher;
+use fnv::FnvHashMap;
+use fnv::FnvHashSet;
+use graphql_ir::Condition;
+use graphql_ir::ConditionValue;
+use graphql_ir::ConstantValue;
+use graphql_ir::Directive;
+use graphql_ir::FragmentDefinition;
+use graphql_ir::FragmentDefinitionName;
+use graphql_ir::FragmentSpread;
+use graphql_ir::OperationDefinition;
+use graphql_ir::Program;
+use graphql_ir::Selection;
+use graphql_ir::Transformed;
+use graphql_ir::Transformer;
+use graphql_ir::Value;
+use graphql_ir::Variable;
+use graphql_ir::VariableName;
+use graphql_ir::Visitor;
+use indexmap::IndexMap;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use lazy_static::lazy_static;
+
+use crate::ValidationMessage;
+
+lazy_static! {
+    static ref INCLUDE_DIRECTIVE_NAME: DirectiveName = DirectiveName("include".intern());
+    static ref SKIP_DIRECTIVE_NAME: DirectiveName = DirectiveName("skip".intern());
+    static ref IF_ARGUMENT_NAME: ArgumentName = ArgumentName("if".intern());
+}
+
+/// Pushes the `@include` and `@skip` conditions of fragment definitions down
+/// to the spreads of the fragments, enabled by the
+/// `enable_fragment_conditions` feature flag.
+///
+/// The variable of a condition must be defined where the fragment is spread:
+/// in the variables of the operation, or in the argument definitions or the
+/// global variables of the fragment. Otherwise the value of the variable in
+/// `condition_defaults` is used, which keeps or removes the spread.
+pub fn fragment_conditions(
+    program: &Program,
+    feature_flag: &FeatureFlag,
+    condition_defaults: &IndexMap<StringKey, bool, FnvBuildHasher>,
+) -> DiagnosticsResult<Program> {
+    let mut errors = Vec::new();
+    let fragment_conditions = program
+        .fragments()
+        .filter_map(|fragment| {
+            let conditions = build_fragment_conditions(fragment, feature_flag, &mut errors);
+            if conditions.is_empty() {
+                None
+            } else {
+                Some((fragment.name.item, conditions))
+            }
+        })
+        .collect::<FnvHashMap<_, _>>();
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    if fragment_conditions.is_empty() {
+        return Ok(program.clone());
+    }
+
+    let mut transform = FragmentConditions {
+        fragment_conditions,
+        condition_defaults,
+        caller_name: "".intern(),
+        caller_variables: Default::default(),
+        errors,
+    };
+    let next_program = transform
+        .transform_program(program)
+        .replace_or_else(|| program.clone());
+    if transform.errors.is_empty() {
+        Ok(next_program)
+    } else {
+        Err(transform.errors)
+    }
+}
+
+struct FragmentCondition {
+    value: ConditionValue,
+    passing_value: bool,
+    location: Location,
+}
+
+fn is_condition_directive(directive: &Directive) -> bool {
+    directive.name.item == *INCLUDE_DIRECTIVE_NAME || directive.name.item == *SKIP_DIRECTIVE_NAME
+}
+
+fn build_fragment_conditions(
+    fragment: &FragmentDefinition,
+    feature_flag: &FeatureFlag,
+    errors: &mut Vec<Diagnostic>,
+) -> Vec<FragmentCondition> {
+    let mut conditions = Vec::new();
+    for directive in fragment
+        .directives
+        .iter()
+        .filter(|directive| is_condition_directive(directive))
+    {
+        if !feature_flag.is_enabled_for(fragment.name.item.0) {
+            errors.push(Diagnostic::error(
+                ValidationMessage::FragmentConditionsDisabled {
+                    directive_name: directive.name.item,
+                },
+                directive.name.location,
+            ));
+            continue;
+        }
+        let argument = match directive.arguments.named(*IF_ARGUMENT_NAME) {
+            Some(argument) => argument,
+            // The required argument is validated when building the IR
+            None => continue,
+        };
+        let value = match &argument.value.item {
+            Value::Constant(ConstantValue::Boolean(value)) => ConditionValue::Constant(*value),
+            Value::Variable(variable) => {
+                if fragment
+                    .variable_definitions
+                    .named(variable.name.item)
+                    .is_some()
+                {
+                    errors.push(Diagnostic::error(
+                        ValidationMessage::FragmentConditionOnArgument {
+                            fragment_name: fragment.name.item,
+                            variable_name: variable.name.item,
+                        },
+                        variable.name.location,
+                    ));
+                    continue;
+                }
+                ConditionValue::Variable(variable.clone())
+            }
+            _ => continue,
+        };
+        conditions.push(FragmentCondition {
+            value,
+            passing_value: directive.name.item == *INCLUDE_DIRECTIVE_NAME,
+            location: directive.name.location,
+        });
+    }
+    conditions
+}
+
+struct FragmentConditions<'a> {
+    fragment_conditions: FnvHashMap<FragmentDefinitionName, Vec<FragmentCondition>>,
+    condition_defaults: &'a IndexMap<StringKey, bool, FnvBuildHasher>,
+    /// The operation or fragment whose selections are transformed, and the
+    /// variables it defines.
+    caller_name: StringKey,
+    caller_variables: FnvHashSet<VariableName>,
+    errors: Vec<Diagnostic>,
+}
+
+impl FragmentConditions<'_> {
+    /// Whether the spread is kept by the `condition` without a variable of
+    /// the caller: the constant condition or the default of the variable
+    /// passes. `None` if the condition is evaluated at runtime.
+    fn static_condition(
+        &self,
+        spread: &FragmentSpread,
+        condition: &FragmentCondition,
+    ) -> Result<Option<bool>, Diagnostic> {
+        match &condition.value {
+            ConditionValue::Constant(value) => Ok(Some(*value == condition.passing_value)),
+            ConditionValue::Variable(variable)
+                if self.caller_variables.contains(&variable.name.item) =>
+            {
+                Ok(None)
+            }
+            ConditionValue::Variable(variable) => {
+                match self.condition_defaults.get(&variable.name.item.0) {
+                    Some(value) => Ok(Some(*value == condition.passing_value)),
+                    None => Err(Diagnostic::error(
+                        ValidationMessage::FragmentConditionVariableUndefined {
+                            fragment_name: spread.fragment.item,
+                            variable_name: variable.name.item,
+                            caller_name: self.caller_name,
+                        },
+                        spread.fragment.location,
+                    )),
+                }
+            }
+        }
+    }
+}
+
+impl Transformer for FragmentConditions<'_> {
+    const NAME: &'static str = "FragmentConditionsTransform";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn transform_operation(
+        &mut self,
+        operation: &OperationDefinition,
+    ) -> Transformed<OperationDefinition> {
+        self.caller_name = operation.name.item.0;
+        self.caller_variables = operation
+            .variable_definitions
+            .iter()
+            .map(|variable| variable.name.item)
+            .collect();
+        self.default_transform_operation(operation)
+    }
+
+    fn transform_fragment(
+        &mut self,
+        fragment: &FragmentDefinition,
+    ) -> Transformed<FragmentDefinition> {
+        if !self.fragment_conditions.contains_key(&fragment.name.item) {
+            self.caller_name = fragment.name.item.0;
+            self.caller_variables = fragment
+                .variable_definitions
+                .iter()
+                .chain(&fragment.used_global_variables)
+                .map(|variable| variable.name.item)
+                .collect();
+            return self.default_transform_fragment(fragment);
+        }
+
+        // The variables of the conditions are not global variables of the
+        // fragment anymore, unless it also uses them in its selections.
+        let directives = fragment
+            .directives
+            .iter()
+            .filter(|directive| !is_condition_directive(directive))
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut used_variables = UsedVariables::default();
+        used_variables.visit_selections(&fragment.selections);
+        used_variables.visit_directives(&directives);
+        let used_global_variables = fragment
+            .used_global_variables
+            .iter()
+            .filter(|variable| used_variables.names.contains(&variable.name.item))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        self.caller_name = fragment.name.item.0;
+        self.caller_variables = fragment
+            .variable_definitions
+            .iter()
+            .chain(&used_global_variables)
+            .map(|variable| variable.name.item)
+            .collect();
+        let selections = self
+            .transform_selections(&fragment.selections)
+            .replace_or_else(|| fragment.selections.clone());
+        Transformed::Replace(FragmentDefinition {
+            directives,
+            selections,
+            used_global_variables,
+            ..fragment.clone()
+        })
+    }
+
+    fn transform_fragment_spread(&mut self, spread: &FragmentSpread) -> Transformed<Selection> {
+        let conditions = match self.fragment_conditions.get(&spread.fragment.item) {
+            Some(conditions) => conditions,
+            None => return Transformed::Keep,
+        };
+        let mut selection = Selection::FragmentSpread(Arc::new(spread.clone()));
+        let mut kept = true;
+        let mut wrapped = false;
+        let mut errors = Vec::new();
+        for condition in conditions {
+            match self.static_condition(spread, condition) {
+                Ok(Some(passes)) => kept &= passes,
+                Ok(None) => {
+                    selection = Selection::Condition(Arc::new(Condition {
+                        selections: vec![selection],
+                        value: condition.value.clone(),
+                        passing_value: condition.passing_value,
+                        location: condition.location,
+                    }));
+                    wrapped = true;
+                }
+                Err(error) => errors.push(error),
+            }
+        }
+        self.errors.extend(errors);
+        if !kept {
+            Transformed::Delete
+        } else if wrapped {
+            Transformed::Replace(selection)
+        } else {
+            Transformed::Keep
+        }
+    }
+}
+
+#[derive(Default)]
+struct UsedVariables {
+    names: FnvHashSet<VariableName>,
+}
+
+impl Visitor for UsedVariables {
+    const NAME: &'static str = "UsedVariables";
+    const VISIT_ARGUMENTS: bool = true;
+    const VISIT_DIRECTIVES: bool = true;
+
+    fn visit_variable(&mut self, variable: &Variable) {
+        self.names.insert(variable.name.item);
+    }
+}
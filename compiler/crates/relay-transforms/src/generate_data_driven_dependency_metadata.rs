@@ -16,6 +16,7 @@ use graphql_ir::Transformer;
 use intern::string_key::StringKey;
 use intern::string_key::StringKeyMap;
 use itertools::Itertools;
+use relay_config::ArtifactFilenameConfig;
 use schema::Schema;
 use schema::Type;
 use schema::TypeReference;
@@ -31,8 +32,11 @@ pub struct RelayDataDrivenDependencyMetadata {
 }
 associated_data_impl!(RelayDataDrivenDependencyMetadata);
 
-pub fn generate_data_driven_dependency_metadata(program: &Program) -> Program {
-    let mut transformer = GenerateDataDrivenDependencyMetadata::new(program);
+pub fn generate_data_driven_dependency_metadata(
+    program: &Program,
+    artifact_filename: &ArtifactFilenameConfig,
+) -> Program {
+    let mut transformer = GenerateDataDrivenDependencyMetadata::new(program, artifact_filename);
     transformer
         .transform_program(program)
         .replace_or_else(|| program.clone())
@@ -40,13 +44,15 @@ pub fn generate_data_driven_dependency_metadata(program: &Program) -> Program {
 
 struct GenerateDataDrivenDependencyMetadata<'s> {
     pub program: &'s Program,
+    artifact_filename: &'s ArtifactFilenameConfig,
     cache: StringKeyMap<Option<ModuleEntries>>,
 }
 
 impl<'s> GenerateDataDrivenDependencyMetadata<'s> {
-    fn new(program: &'s Program) -> Self {
+    fn new(program: &'s Program, artifact_filename: &'s ArtifactFilenameConfig) -> Self {
         GenerateDataDrivenDependencyMetadata {
             program,
+            artifact_filename,
             cache: Default::default(),
         }
     }
@@ -156,7 +162,10 @@ impl<'s> GenerateDataDrivenDependencyMetadata<'s> {
                                         type_name,
                                         Branch {
                                             component,
-                                            fragment: get_fragment_filename(fragment_name),
+                                            fragment: get_fragment_filename(
+                                                fragment_name,
+                                                self.artifact_filename,
+                                            ),
                                         },
                                     );
                                 })
@@ -167,7 +176,10 @@ impl<'s> GenerateDataDrivenDependencyMetadata<'s> {
                                             type_name,
                                             Branch {
                                                 component,
-                                                fragment: get_fragment_filename(fragment_name),
+                                                fragment: get_fragment_filename(
+                                                    fragment_name,
+                                                    self.artifact_filename,
+                                                ),
                                             },
                                         );
                                         map
@@ -5,6 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use common::diagnostic_codes;
 use common::ArgumentName;
 use common::Diagnostic;
 use common::DiagnosticsResult;
@@ -141,20 +142,25 @@ impl Transformer for GenerateLiveQueryMetadata {
     }
 }
 
-#[derive(Error, Debug)]
-enum ValidationMessage {
-    #[error(
-        "Live query expects 'polling_interval' or 'config_id' as an argument to @live_query to for root field {query_name}"
-    )]
-    LiveQueryTransformMissingConfig { query_name: OperationDefinitionName },
+diagnostic_codes! {
+    #[derive(Error, Debug)]
+    enum ValidationMessage {
+        #[error(
+            "Live query expects 'polling_interval' or 'config_id' as an argument to @live_query to for root field {query_name}"
+        )]
+        #[code("RELAY2083")]
+        LiveQueryTransformMissingConfig { query_name: OperationDefinitionName },
 
-    #[error(
-        "Expected the 'polling_interval' argument to @live_query to be a literal number for root field {query_name}"
-    )]
-    LiveQueryTransformInvalidPollingInterval { query_name: OperationDefinitionName },
+        #[error(
+            "Expected the 'polling_interval' argument to @live_query to be a literal number for root field {query_name}"
+        )]
+        #[code("RELAY2084")]
+        LiveQueryTransformInvalidPollingInterval { query_name: OperationDefinitionName },
 
-    #[error(
-        "Expected the 'config_id' argument to @live_query to be a literal string for root field {query_name}"
-    )]
-    LiveQueryTransformInvalidConfigId { query_name: OperationDefinitionName },
+        #[error(
+            "Expected the 'config_id' argument to @live_query to be a literal string for root field {query_name}"
+        )]
+        #[code("RELAY2085")]
+        LiveQueryTransformInvalidConfigId { query_name: OperationDefinitionName },
+    }
 }
@@ -7,6 +7,7 @@
 
 use std::sync::Arc;
 
+use common::diagnostic_codes;
 use common::Diagnostic;
 use common::DiagnosticsResult;
 use common::DirectiveName;
@@ -168,13 +169,17 @@ impl<'s> Transformer for InlineDataFragmentsTransform<'s> {
     }
 }
 
-#[derive(Error, Debug)]
-enum ValidationMessage {
-    #[error("Found a circular reference from fragment '{fragment_name}'.")]
-    CircularFragmentReference {
-        fragment_name: FragmentDefinitionName,
-    },
-
-    #[error("Directives on fragment spreads for @inline fragments are not yet supported")]
-    InlineDataFragmentDirectivesNotSupported,
+diagnostic_codes! {
+    #[derive(Error, Debug)]
+    enum ValidationMessage {
+        #[error("Found a circular reference from fragment '{fragment_name}'.")]
+        #[code("RELAY2150")]
+        CircularFragmentReference {
+            fragment_name: FragmentDefinitionName,
+        },
+
+        #[error("Directives on fragment spreads for @inline fragments are not yet supported")]
+        #[code("RELAY2086")]
+        InlineDataFragmentDirectivesNotSupported,
+    }
 }
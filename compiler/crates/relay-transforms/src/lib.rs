@@ -20,12 +20,16 @@ mod client_edges;
 mod client_extensions;
 mod client_extensions_abstract_types;
 mod connections;
+mod debug_transforms;
 mod declarative_connection;
 mod defer_stream;
 mod directive_finder;
 mod errors;
+mod experiment_variant;
+mod feature_flag_directive;
 mod flatten;
 mod fragment_alias_directive;
+mod fragment_conditions;
 mod generate_data_driven_dependency_metadata;
 mod generate_id_field;
 mod generate_live_query_metadata;
@@ -38,6 +42,7 @@ mod inline_data_fragment;
 mod inline_fragments;
 mod mask;
 mod match_;
+mod merge_inline_fragments;
 mod metadata_directive;
 mod murmurhash;
 mod no_inline;
@@ -65,6 +70,7 @@ mod test_operation_metadata;
 mod transform_connections;
 mod unwrap_custom_directive_selection;
 mod util;
+mod validate;
 mod validate_operation_variables;
 mod validations;
 
@@ -118,15 +124,23 @@ pub use connections::extract_connection_metadata_from_directive;
 pub use connections::ConnectionConstants;
 pub use connections::ConnectionInterface;
 pub use connections::ConnectionMetadata;
+pub use debug_transforms::DebugTransformsConfig;
 pub use declarative_connection::transform_declarative_connection;
 pub use defer_stream::transform_defer_stream;
 pub use defer_stream::DeferDirective;
 pub use defer_stream::StreamDirective;
 pub use defer_stream::DEFER_STREAM_CONSTANTS;
 pub use directive_finder::DirectiveFinder;
+pub use experiment_variant::rename_experiment_variant;
+pub use experiment_variant::unsuffixed_operation_name;
+pub use experiment_variant::EXPERIMENT_VARIANT_DIRECTIVE_NAME;
+pub use feature_flag_directive::feature_flag_directive;
+pub use feature_flag_directive::FEATURE_FLAG_DIRECTIVE_NAME;
 pub use flatten::flatten;
+pub use flatten::flatten_readable;
 pub use fragment_alias_directive::fragment_alias_directive;
 pub use fragment_alias_directive::FragmentAliasMetadata;
+pub use fragment_conditions::fragment_conditions;
 pub use generate_data_driven_dependency_metadata::generate_data_driven_dependency_metadata;
 pub use generate_data_driven_dependency_metadata::RelayDataDrivenDependencyMetadata;
 pub use generate_id_field::generate_id_field;
@@ -151,6 +165,8 @@ pub use match_::RawResponseGenerationMode;
 pub use match_::SplitOperationMetadata;
 pub use match_::DIRECTIVE_SPLIT_OPERATION;
 pub use match_::MATCH_CONSTANTS;
+pub use merge_inline_fragments::merge_inline_fragments;
+pub use no_inline::apply_configured_no_inline_directives;
 pub use no_inline::NO_INLINE_DIRECTIVE_NAME;
 pub use preloadable_directive::is_operation_preloadable;
 pub use preloadable_directive::should_generate_hack_preloader;
@@ -204,6 +220,9 @@ pub use util::generate_abstract_type_refinement_key;
 pub use util::get_fragment_filename;
 pub use util::get_normalization_operation_name;
 pub use util::remove_directive;
+pub use validate::validate;
+pub use validate::AdditionalValidations;
+pub use validate::CustomValidation;
 pub use validate_operation_variables::validate_operation_variables;
 pub use validations::*;
 
@@ -10,6 +10,7 @@ use std::sync::Arc;
 
 use common::Location;
 use graphql_ir::FragmentDefinition;
+use graphql_ir::FragmentDefinitionNameMap;
 use graphql_ir::FragmentSpread;
 use graphql_ir::InlineFragment;
 use graphql_ir::OperationDefinition;
@@ -36,9 +37,22 @@ pub fn mask(program: &Program) -> Program {
 
 type JoinedArguments<'s> = IndexMap<VariableName, &'s VariableDefinition>;
 
+enum UnmaskedFragment<'s> {
+    Visiting,
+    /// The selections of the fragment with its unmasked spreads inlined, and
+    /// the arguments reachable from them.
+    Visited {
+        selections: Vec<Selection>,
+        reachable_arguments: Vec<&'s VariableDefinition>,
+    },
+}
+
 struct Mask<'s> {
     program: &'s Program,
     current_reachable_arguments: Vec<&'s VariableDefinition>,
+    /// Unmasked fragments are inlined once and reused for every spread, so
+    /// that long chains of unmasked fragments compile in linear time.
+    unmasked_fragments: FragmentDefinitionNameMap<UnmaskedFragment<'s>>,
 }
 
 impl<'s> Mask<'s> {
@@ -46,6 +60,7 @@ impl<'s> Mask<'s> {
         Self {
             program,
             current_reachable_arguments: vec![],
+            unmasked_fragments: Default::default(),
         }
     }
 
@@ -120,21 +135,48 @@ impl<'s> Transformer for Mask<'s> {
     }
 
     fn transform_fragment_spread(&mut self, spread: &FragmentSpread) -> Transformed<Selection> {
-        if RelayDirective::is_unmasked_fragment_spread(spread) {
-            let fragment = self.program.fragment(spread.fragment.item).unwrap();
-            self.current_reachable_arguments
-                .extend(&fragment.used_global_variables);
-            Transformed::Replace(Selection::InlineFragment(Arc::new(InlineFragment {
-                type_condition: Some(fragment.type_condition),
-                directives: vec![],
-                selections: self
-                    .transform_selections(&fragment.selections)
-                    .replace_or_else(|| fragment.selections.to_vec()),
-                spread_location: Location::generated(),
-            })))
-        } else {
-            Transformed::Keep
+        if !RelayDirective::is_unmasked_fragment_spread(spread) {
+            return Transformed::Keep;
         }
+        let program = self.program;
+        let fragment = program.fragment(spread.fragment.item).unwrap();
+        let (selections, reachable_arguments) =
+            match self.unmasked_fragments.get(&fragment.name.item) {
+                Some(UnmaskedFragment::Visited {
+                    selections,
+                    reachable_arguments,
+                }) => (selections.clone(), reachable_arguments.clone()),
+                // Cycles are reported by `disallow_circular_unmasked_fragments`.
+                Some(UnmaskedFragment::Visiting) => return Transformed::Keep,
+                None => {
+                    self.unmasked_fragments
+                        .insert(fragment.name.item, UnmaskedFragment::Visiting);
+                    let parent_arguments = std::mem::replace(
+                        &mut self.current_reachable_arguments,
+                        fragment.used_global_variables.iter().collect(),
+                    );
+                    let selections = self
+                        .transform_selections(&fragment.selections)
+                        .replace_or_else(|| fragment.selections.to_vec());
+                    let reachable_arguments =
+                        std::mem::replace(&mut self.current_reachable_arguments, parent_arguments);
+                    self.unmasked_fragments.insert(
+                        fragment.name.item,
+                        UnmaskedFragment::Visited {
+                            selections: selections.clone(),
+                            reachable_arguments: reachable_arguments.clone(),
+                        },
+                    );
+                    (selections, reachable_arguments)
+                }
+            };
+        self.current_reachable_arguments.extend(reachable_arguments);
+        Transformed::Replace(Selection::InlineFragment(Arc::new(InlineFragment {
+            type_condition: Some(fragment.type_condition),
+            directives: vec![],
+            selections,
+            spread_location: Location::generated(),
+        })))
     }
 
     fn transform_scalar_field(&mut self, _field: &ScalarField) -> Transformed<Selection> {
@@ -7,6 +7,7 @@
 
 use std::sync::Arc;
 
+use common::diagnostic_codes;
 use common::Diagnostic;
 use common::DiagnosticsResult;
 use common::FeatureFlag;
@@ -152,10 +153,13 @@ impl<'a> HashSupportedArgumentTransform<'a> {
     }
 }
 
-#[derive(Debug, Error)]
-pub enum HashSupportedArgumentError {
-    #[error(
-        "Variables cannot be passed to the `supported` argument for data driven dependency fields, please use literal values like `\"ExampleValue\"`."
-    )]
-    NonStaticSupportedArg,
+diagnostic_codes! {
+    #[derive(Debug, Error)]
+    pub enum HashSupportedArgumentError {
+        #[error(
+            "Variables cannot be passed to the `supported` argument for data driven dependency fields, please use literal values like `\"ExampleValue\"`."
+        )]
+        #[code("RELAY2087")]
+        NonStaticSupportedArg,
+    }
 }
@@ -42,6 +42,7 @@ use indexmap::IndexSet;
 use intern::string_key::Intern;
 use intern::string_key::StringKey;
 use intern::Lookup;
+use relay_config::ArtifactFilenameConfig;
 use relay_config::ModuleImportConfig;
 use schema::FieldID;
 use schema::ScalarID;
@@ -62,8 +63,14 @@ pub fn transform_match(
     program: &Program,
     feature_flags: &FeatureFlags,
     module_import_config: ModuleImportConfig,
+    artifact_filename: &ArtifactFilenameConfig,
 ) -> DiagnosticsResult<Program> {
-    let mut transformer = MatchTransform::new(program, feature_flags, module_import_config);
+    let mut transformer = MatchTransform::new(
+        program,
+        feature_flags,
+        module_import_config,
+        artifact_filename,
+    );
     let next_program = transformer.transform_program(program);
     if transformer.errors.is_empty() {
         Ok(next_program.replace_or_else(|| program.clone()))
@@ -111,6 +118,7 @@ pub struct MatchTransform<'program, 'flag> {
     // Stores the fragments that should use @no_inline and their parent document name
     no_inline_fragments: FragmentDefinitionNameMap<Vec<StringKey>>,
     module_import_config: ModuleImportConfig,
+    artifact_filename: &'flag ArtifactFilenameConfig,
 }
 
 impl<'program, 'flag> MatchTransform<'program, 'flag> {
@@ -118,6 +126,7 @@ impl<'program, 'flag> MatchTransform<'program, 'flag> {
         program: &'program Program,
         feature_flags: &'flag FeatureFlags,
         module_import_config: ModuleImportConfig,
+        artifact_filename: &'flag ArtifactFilenameConfig,
     ) -> Self {
         Self {
             program,
@@ -132,6 +141,7 @@ impl<'program, 'flag> MatchTransform<'program, 'flag> {
             no_inline_flag: &feature_flags.no_inline,
             no_inline_fragments: Default::default(),
             module_import_config,
+            artifact_filename,
         }
     }
 
@@ -543,8 +553,9 @@ impl<'program, 'flag> MatchTransform<'program, 'flag> {
             module_directive.name.location,
         )];
 
-        let mut normalization_name = get_normalization_operation_name(spread.fragment.item.0);
-        normalization_name.push_str(".graphql");
+        let normalization_name = self
+            .artifact_filename
+            .module_name(get_normalization_operation_name(spread.fragment.item.0));
         let mut operation_field_arguments = vec![build_string_literal_argument(
             MATCH_CONSTANTS.js_field_module_arg,
             normalization_name.intern(),
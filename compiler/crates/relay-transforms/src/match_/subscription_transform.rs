@@ -27,6 +27,7 @@ use graphql_ir::Value;
 use graphql_syntax::OperationKind;
 use intern::string_key::Intern;
 use intern::Lookup;
+use relay_config::ArtifactFilenameConfig;
 use schema::FieldID;
 use schema::Schema;
 use schema::Type;
@@ -36,19 +37,29 @@ use crate::match_::MATCH_CONSTANTS;
 use crate::util::get_normalization_operation_name;
 use crate::ModuleMetadata;
 
-pub fn transform_subscriptions(program: &Program) -> DiagnosticsResult<Program> {
-    let mut transformer = SubscriptionTransform::new(program);
+pub fn transform_subscriptions(
+    program: &Program,
+    artifact_filename: &ArtifactFilenameConfig,
+) -> DiagnosticsResult<Program> {
+    let mut transformer = SubscriptionTransform::new(program, artifact_filename);
     let next_program = transformer.transform_program(program);
     Ok(next_program.replace_or_else(|| program.clone()))
 }
 
 pub struct SubscriptionTransform<'program> {
     program: &'program Program,
+    artifact_filename: &'program ArtifactFilenameConfig,
 }
 
 impl<'program> SubscriptionTransform<'program> {
-    fn new(program: &'program Program) -> Self {
-        Self { program }
+    fn new(
+        program: &'program Program,
+        artifact_filename: &'program ArtifactFilenameConfig,
+    ) -> Self {
+        Self {
+            program,
+            artifact_filename,
+        }
     }
 
     /// Validate that the given operation meets the following conditions:
@@ -152,11 +163,12 @@ impl<'program> SubscriptionTransform<'program> {
         let location = linked_field.definition.location;
         let operation_name_with_suffix =
             format!("{}__subscription", operation.name.item.0.lookup());
-        let normalization_operation_name = format!(
-            "{}.graphql",
-            get_normalization_operation_name(fragment_spread.fragment.item.0)
-        )
-        .intern();
+        let normalization_operation_name = self
+            .artifact_filename
+            .module_name(get_normalization_operation_name(
+                fragment_spread.fragment.item.0,
+            ))
+            .intern();
 
         let mut selections = linked_field.selections.clone();
         selections.push(Selection::ScalarField(Arc::new(ScalarField {
@@ -0,0 +1,319 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::FeatureFlag;
+use common::WithLocation;
+use graphql_ir::node_identifier::LocationAgnosticPartialEq;
+use graphql_ir::transform_list;
+use graphql_ir::Field;
+use graphql_ir::FragmentDefinition;
+use graphql_ir::InlineFragment;
+use graphql_ir::LinkedField;
+use graphql_ir::OperationDefinition;
+use graphql_ir::Program;
+use graphql_ir::ScalarField;
+use graphql_ir::Selection;
+use graphql_ir::Transformed;
+use graphql_ir::TransformedValue;
+use graphql_ir::Transformer;
+use schema::FieldID;
+use schema::ObjectID;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+
+use crate::RelayLocationAgnosticBehavior;
+
+/// A transform that merges the selections shared by the sibling inline
+/// fragments of an abstract selection into the selection itself. Inline
+/// fragments that become empty are removed:
+///
+/// ```graphql
+/// actor { # Actor is implemented by User and Page
+///   ... on User { id name }
+///   ... on Page { id name url }
+/// }
+/// ```
+///
+/// Becomes
+///
+/// ```graphql
+/// actor {
+///   id
+///   name
+///   ... on Page { url }
+/// }
+/// ```
+///
+/// A field is only hoisted if the inline fragments selecting it cover all
+/// possible types of the abstract type and the abstract type defines the
+/// field with the same type. Inline fragments with directives are kept as is.
+///
+/// This is intended to run late on the normalization program, after
+/// `flatten` and `skip_redundant_nodes`.
+pub fn merge_inline_fragments(program: &Program, feature_flag: &FeatureFlag) -> Program {
+    let mut transform = MergeInlineFragmentsTransform {
+        schema: &program.schema,
+        feature_flag,
+        parent_type: None,
+    };
+    transform
+        .transform_program(program)
+        .replace_or_else(|| program.clone())
+}
+
+struct MergeInlineFragmentsTransform<'s> {
+    schema: &'s SDLSchema,
+    feature_flag: &'s FeatureFlag,
+    parent_type: Option<Type>,
+}
+
+impl<'s> MergeInlineFragmentsTransform<'s> {
+    fn possible_object_types(&self, type_: Type) -> &[ObjectID] {
+        match type_ {
+            Type::Interface(id) => &self.schema.interface(id).implementing_objects,
+            Type::Union(id) => &self.schema.union(id).members,
+            _ => &[],
+        }
+    }
+
+    /// Returns the new selections if any selection could be hoisted out of
+    /// the inline fragments in `selections`.
+    fn hoist_common_selections(
+        &self,
+        parent_type: Type,
+        selections: &[Selection],
+    ) -> Option<Vec<Selection>> {
+        let possible_types = self.possible_object_types(parent_type);
+        if possible_types.is_empty() {
+            return None;
+        }
+        let fragments = selections
+            .iter()
+            .filter_map(|selection| match selection {
+                Selection::InlineFragment(fragment) if fragment.directives.is_empty() => {
+                    match fragment.type_condition {
+                        Some(Type::Object(object_id)) => Some((object_id, fragment.as_ref())),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let fragment_of_type = |object_id: ObjectID| {
+            fragments
+                .iter()
+                .find(|(id, _)| *id == object_id)
+                .map(|(_, fragment)| *fragment)
+        };
+        let first_fragment = fragment_of_type(possible_types[0])?;
+        if possible_types[1..]
+            .iter()
+            .any(|object_id| fragment_of_type(*object_id).is_none())
+        {
+            return None;
+        }
+
+        let mut hoisted: Vec<(&Selection, Selection)> = Vec::new();
+        for selection in &first_fragment.selections {
+            let parent_field_id = match self.parent_field(parent_type, selection) {
+                Some(parent_field_id) => parent_field_id,
+                None => continue,
+            };
+            let alias_or_name = match selection {
+                Selection::ScalarField(field) => field.alias_or_name(self.schema),
+                Selection::LinkedField(field) => field.alias_or_name(self.schema),
+                _ => continue,
+            };
+            let is_selected_by_all_types = possible_types[1..].iter().all(|object_id| {
+                fragment_of_type(*object_id)
+                    .unwrap()
+                    .selections
+                    .iter()
+                    .any(|other| self.is_same_field(selection, other))
+            });
+            let conflicts_with_parent_selection = selections.iter().any(|other| match other {
+                Selection::ScalarField(field) => field.alias_or_name(self.schema) == alias_or_name,
+                Selection::LinkedField(field) => field.alias_or_name(self.schema) == alias_or_name,
+                _ => false,
+            });
+            if is_selected_by_all_types && !conflicts_with_parent_selection {
+                hoisted.push((selection, with_definition(selection, parent_field_id)));
+            }
+        }
+        if hoisted.is_empty() {
+            return None;
+        }
+
+        let mut next_selections = Vec::with_capacity(selections.len() + hoisted.len());
+        let mut is_hoisted_inserted = false;
+        for selection in selections {
+            let fragment = match selection {
+                Selection::InlineFragment(fragment)
+                    if fragments
+                        .iter()
+                        .any(|(_, other)| std::ptr::eq(*other, fragment.as_ref())) =>
+                {
+                    fragment
+                }
+                _ => {
+                    next_selections.push(selection.clone());
+                    continue;
+                }
+            };
+            // The hoisted selections take the place of the first fragment.
+            if !is_hoisted_inserted {
+                next_selections.extend(hoisted.iter().map(|(_, selection)| selection.clone()));
+                is_hoisted_inserted = true;
+            }
+            let remaining_selections = fragment
+                .selections
+                .iter()
+                .filter(|selection| {
+                    !hoisted
+                        .iter()
+                        .any(|(original, _)| self.is_same_field(original, selection))
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            if !remaining_selections.is_empty() {
+                next_selections.push(Selection::InlineFragment(Arc::new(InlineFragment {
+                    selections: remaining_selections,
+                    ..fragment.as_ref().clone()
+                })));
+            }
+        }
+        Some(next_selections)
+    }
+
+    /// The field of the abstract `parent_type` that `selection` can be
+    /// hoisted to: it has to have the same type as the selected field.
+    fn parent_field(&self, parent_type: Type, selection: &Selection) -> Option<FieldID> {
+        let (field_id, has_directives) = match selection {
+            Selection::ScalarField(field) => (field.definition.item, !field.directives.is_empty()),
+            Selection::LinkedField(field) => (field.definition.item, !field.directives.is_empty()),
+            _ => return None,
+        };
+        if has_directives {
+            return None;
+        }
+        let field = self.schema.field(field_id);
+        let parent_field_id = self.schema.named_field(parent_type, field.name.item)?;
+        if self.schema.field(parent_field_id).type_ == field.type_ {
+            Some(parent_field_id)
+        } else {
+            None
+        }
+    }
+
+    /// Fields of different types are the same if they have the same alias,
+    /// name, type, arguments and selections.
+    fn is_same_field(&self, a: &Selection, b: &Selection) -> bool {
+        match (a, b) {
+            (Selection::ScalarField(a), Selection::ScalarField(b)) => {
+                a.directives.is_empty()
+                    && b.directives.is_empty()
+                    && a.alias.map(|alias| alias.item) == b.alias.map(|alias| alias.item)
+                    && self.is_same_definition(a.definition.item, b.definition.item)
+                    && a.arguments
+                        .location_agnostic_eq::<RelayLocationAgnosticBehavior>(&b.arguments)
+            }
+            (Selection::LinkedField(a), Selection::LinkedField(b)) => {
+                a.directives.is_empty()
+                    && b.directives.is_empty()
+                    && a.alias.map(|alias| alias.item) == b.alias.map(|alias| alias.item)
+                    && self.is_same_definition(a.definition.item, b.definition.item)
+                    && a.arguments
+                        .location_agnostic_eq::<RelayLocationAgnosticBehavior>(&b.arguments)
+                    && a.selections
+                        .location_agnostic_eq::<RelayLocationAgnosticBehavior>(&b.selections)
+            }
+            _ => false,
+        }
+    }
+
+    fn is_same_definition(&self, a: FieldID, b: FieldID) -> bool {
+        let a = self.schema.field(a);
+        let b = self.schema.field(b);
+        a.name.item == b.name.item && a.type_ == b.type_
+    }
+}
+
+fn with_definition(selection: &Selection, field_id: FieldID) -> Selection {
+    match selection {
+        Selection::ScalarField(field) => Selection::ScalarField(Arc::new(ScalarField {
+            definition: WithLocation::new(field.definition.location, field_id),
+            ..field.as_ref().clone()
+        })),
+        Selection::LinkedField(field) => Selection::LinkedField(Arc::new(LinkedField {
+            definition: WithLocation::new(field.definition.location, field_id),
+            ..field.as_ref().clone()
+        })),
+        _ => panic!("Expected only fields to be hoisted."),
+    }
+}
+
+impl<'s> Transformer for MergeInlineFragmentsTransform<'s> {
+    const NAME: &'static str = "MergeInlineFragmentsTransform";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn transform_operation(
+        &mut self,
+        operation: &OperationDefinition,
+    ) -> Transformed<OperationDefinition> {
+        if !self.feature_flag.is_enabled_for(operation.name.item.0) {
+            return Transformed::Keep;
+        }
+        self.parent_type = Some(operation.type_);
+        self.default_transform_operation(operation)
+    }
+
+    fn transform_fragment(&mut self, _: &FragmentDefinition) -> Transformed<FragmentDefinition> {
+        Transformed::Keep
+    }
+
+    fn transform_linked_field(&mut self, field: &LinkedField) -> Transformed<Selection> {
+        let parent_type = self.parent_type;
+        self.parent_type = Some(self.schema.field(field.definition.item).type_.inner());
+        let result = self.default_transform_linked_field(field);
+        self.parent_type = parent_type;
+        result
+    }
+
+    fn transform_inline_fragment(&mut self, fragment: &InlineFragment) -> Transformed<Selection> {
+        let parent_type = self.parent_type;
+        if fragment.type_condition.is_some() {
+            self.parent_type = fragment.type_condition;
+        }
+        let result = self.default_transform_inline_fragment(fragment);
+        self.parent_type = parent_type;
+        result
+    }
+
+    fn transform_selections(
+        &mut self,
+        selections: &[Selection],
+    ) -> TransformedValue<Vec<Selection>> {
+        let transformed =
+            transform_list(selections, |selection| self.transform_selection(selection));
+        let parent_type = match self.parent_type {
+            Some(parent_type) if parent_type.is_abstract_type() => parent_type,
+            _ => return transformed,
+        };
+        let current_selections = match &transformed {
+            TransformedValue::Keep => selections,
+            TransformedValue::Replace(selections) => selections,
+        };
+        match self.hoist_common_selections(parent_type, current_selections) {
+            Some(next_selections) => TransformedValue::Replace(next_selections),
+            None => transformed,
+        }
+    }
+}
@@ -16,6 +16,7 @@ use common::WithLocation;
 use graphql_ir::Argument;
 use graphql_ir::ConstantValue;
 use graphql_ir::Directive;
+use graphql_ir::FragmentDefinitionName;
 use graphql_ir::FragmentDefinitionNameMap;
 use graphql_ir::FragmentSpread;
 use graphql_ir::Program;
@@ -23,7 +24,9 @@ use graphql_ir::Validator;
 use graphql_ir::Value;
 use intern::string_key::Intern;
 use intern::string_key::StringKey;
+use intern::Lookup;
 use lazy_static::lazy_static;
+use regex::Regex;
 
 use crate::ValidationMessage;
 use crate::MATCH_CONSTANTS;
@@ -75,6 +78,42 @@ pub fn attach_no_inline_directives_to_fragments(
     }
 }
 
+/// Adds `@no_inline` to every fragment whose name matches one of the
+/// `no_inline_fragments` patterns of the project config, unless the fragment
+/// already has the directive. Returns the names of the affected fragments,
+/// sorted.
+pub fn apply_configured_no_inline_directives(
+    program: &mut Program,
+    no_inline_fragments: &[Regex],
+) -> Vec<FragmentDefinitionName> {
+    if no_inline_fragments.is_empty() {
+        return vec![];
+    }
+    let mut fragment_names = program
+        .fragments()
+        .filter(|fragment| {
+            fragment
+                .directives
+                .named(*NO_INLINE_DIRECTIVE_NAME)
+                .is_none()
+                && no_inline_fragments
+                    .iter()
+                    .any(|pattern| pattern.is_match(fragment.name.item.0.lookup()))
+        })
+        .map(|fragment| fragment.name.item)
+        .collect::<Vec<_>>();
+    fragment_names.sort_by_key(|fragment_name| fragment_name.0.lookup());
+    for fragment_name in &fragment_names {
+        let fragment = Arc::make_mut(program.fragment_mut(*fragment_name).unwrap());
+        fragment.directives.push(Directive {
+            name: WithLocation::new(fragment.name.location, *NO_INLINE_DIRECTIVE_NAME),
+            arguments: vec![],
+            data: None,
+        });
+    }
+    fragment_names
+}
+
 pub fn is_raw_response_type_enabled(directive: &Directive) -> bool {
     if let Some(Value::Constant(ConstantValue::Boolean(val))) = directive
         .arguments
@@ -7,6 +7,7 @@
 
 use std::cmp::Reverse;
 
+use common::diagnostic_codes;
 use common::Diagnostic;
 use common::DiagnosticsResult;
 use common::Location;
@@ -239,22 +240,26 @@ impl<'schema> Transformer for ProvidedVariableFragmentTransform<'schema> {
     }
 }
 
-#[derive(Debug, Error)]
-enum ValidationMessage {
-    #[error(
-        "Modules '{module1}' and '{module2}' used by provided variables have indistinguishable names. (All non ascii-alphanumeric characters are stripped in Relay transform)"
-    )]
-    ProvidedVariableConflictingModuleNames {
-        module1: StringKey,
-        module2: StringKey,
-    },
+diagnostic_codes! {
+    #[derive(Debug, Error)]
+    enum ValidationMessage {
+        #[error(
+            "Modules '{module1}' and '{module2}' used by provided variables have indistinguishable names. (All non ascii-alphanumeric characters are stripped in Relay transform)"
+        )]
+        #[code("RELAY2107")]
+        ProvidedVariableConflictingModuleNames {
+            module1: StringKey,
+            module2: StringKey,
+        },
 
-    #[error(
-        "All provided variables using module '{module}' must declare the same type. Expected '{existing_type}' but found '{new_type}'"
-    )]
-    ProvidedVariableConflictingTypes {
-        module: StringKey,
-        existing_type: StringKey,
-        new_type: StringKey,
-    },
+        #[error(
+            "All provided variables using module '{module}' must declare the same type. Expected '{existing_type}' but found '{new_type}'"
+        )]
+        #[code("RELAY2108")]
+        ProvidedVariableConflictingTypes {
+            module: StringKey,
+            existing_type: StringKey,
+            new_type: StringKey,
+        },
+    }
 }
@@ -7,6 +7,7 @@
 
 use std::sync::Arc;
 
+use common::diagnostic_codes;
 use common::ArgumentName;
 use common::Diagnostic;
 use common::DiagnosticsResult;
@@ -429,25 +430,33 @@ impl<'s> Transformer for ReactFlightTransform<'s> {
     }
 }
 
-#[derive(Error, Debug)]
-enum ValidationMessage {
-    #[error(
-        "Expected 'flight' field schema definition to specify its component name with @react_flight_component"
-    )]
-    InvalidFlightFieldMissingModuleDirective,
-
-    #[error("Cannot query field '{field_name}', this type does not define a 'flight' field")]
-    InvalidFlightFieldNotDefinedOnType { field_name: StringKey },
-
-    #[error("Expected @react_flight_component value to be a literal string")]
-    InvalidFlightFieldExpectedModuleNameString,
-
-    #[error("Expected flight field to have a 'props: ReactFlightProps' argument")]
-    InvalidFlightFieldPropsArgument,
-
-    #[error("Expected flight field to have a 'component: String' argument")]
-    InvalidFlightFieldComponentArgument,
-
-    #[error("Expected flight field to return 'ReactFlightComponent'")]
-    InvalidFlightFieldReturnType,
+diagnostic_codes! {
+    #[derive(Error, Debug)]
+    enum ValidationMessage {
+        #[error(
+            "Expected 'flight' field schema definition to specify its component name with @react_flight_component"
+        )]
+        #[code("RELAY2109")]
+        InvalidFlightFieldMissingModuleDirective,
+
+        #[error("Cannot query field '{field_name}', this type does not define a 'flight' field")]
+        #[code("RELAY2110")]
+        InvalidFlightFieldNotDefinedOnType { field_name: StringKey },
+
+        #[error("Expected @react_flight_component value to be a literal string")]
+        #[code("RELAY2111")]
+        InvalidFlightFieldExpectedModuleNameString,
+
+        #[error("Expected flight field to have a 'props: ReactFlightProps' argument")]
+        #[code("RELAY2112")]
+        InvalidFlightFieldPropsArgument,
+
+        #[error("Expected flight field to have a 'component: String' argument")]
+        #[code("RELAY2113")]
+        InvalidFlightFieldComponentArgument,
+
+        #[error("Expected flight field to return 'ReactFlightComponent'")]
+        #[code("RELAY2114")]
+        InvalidFlightFieldReturnType,
+    }
 }
@@ -49,12 +49,14 @@ fn build_refetch_operation(
 ) -> DiagnosticsResult<Option<RefetchRoot>> {
     let id_name = schema_config.node_interface_id_field;
 
-    if let Some(identifier_field_name) = get_fetchable_field_name(fragment, schema)? {
+    if let Some(FetchableType {
+        identifier_field_name,
+        fetch_field_name,
+    }) = get_fetchable_type(fragment, schema, schema_config)?
+    {
         let identifier_field_id = get_identifier_field_id(fragment, schema, identifier_field_name)?;
 
         let query_type = schema.query_type().unwrap();
-        let fetch_field_name =
-            format!("fetch__{}", schema.get_type_name(fragment.type_condition)).intern();
         let (fetch_field_id, id_arg) =
             get_fetch_field_id_and_id_arg(fragment, schema, query_type, fetch_field_name)?;
 
@@ -121,6 +123,39 @@ fn build_refetch_operation(
     }
 }
 
+struct FetchableType {
+    identifier_field_name: StringKey,
+    fetch_field_name: StringKey,
+}
+
+/// A type is fetchable if it's listed in the `fetchableTypes` of the schema
+/// config, or if it's an object with a `@fetchable` directive.
+fn get_fetchable_type(
+    fragment: &FragmentDefinition,
+    schema: &SDLSchema,
+    schema_config: &SchemaConfig,
+) -> DiagnosticsResult<Option<FetchableType>> {
+    let type_name = schema.get_type_name(fragment.type_condition);
+    if let Some(fetchable_type) = schema_config.fetchable_types.get(&type_name) {
+        return Ok(Some(FetchableType {
+            identifier_field_name: fetchable_type.identifying_field,
+            fetch_field_name: fetchable_type
+                .fetch_field
+                .unwrap_or_else(|| default_fetch_field_name(type_name)),
+        }));
+    }
+    Ok(
+        get_fetchable_field_name(fragment, schema)?.map(|identifier_field_name| FetchableType {
+            identifier_field_name,
+            fetch_field_name: default_fetch_field_name(type_name),
+        }),
+    )
+}
+
+fn default_fetch_field_name(type_name: StringKey) -> StringKey {
+    format!("fetch__{}", type_name).intern()
+}
+
 fn get_fetchable_field_name(
     fragment: &FragmentDefinition,
     schema: &SDLSchema,
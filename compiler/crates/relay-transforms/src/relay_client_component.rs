@@ -37,6 +37,7 @@ use intern::string_key::StringKeyMap;
 use intern::string_key::StringKeySet;
 use itertools::Itertools;
 use lazy_static::lazy_static;
+use relay_config::ArtifactFilenameConfig;
 use schema::InterfaceID;
 use schema::Schema;
 use schema::Type;
@@ -69,6 +70,7 @@ associated_data_impl!(RelayClientComponentMetadata);
 pub fn relay_client_component(
     program: &Program,
     feature_flags: &FeatureFlags,
+    artifact_filename: &ArtifactFilenameConfig,
 ) -> DiagnosticsResult<Program> {
     // Noop, the @relay_client_component_server directive is not defined in the schema
     if program
@@ -90,8 +92,12 @@ pub fn relay_client_component(
         })
         .expect("@relay_client_component requires your schema to define the Node interface.");
 
-    let mut transform =
-        RelayClientComponentTransform::new(program, node_interface_id, feature_flags);
+    let mut transform = RelayClientComponentTransform::new(
+        program,
+        node_interface_id,
+        feature_flags,
+        artifact_filename,
+    );
     let mut next_program = transform
         .transform_program(program)
         .replace_or_else(|| program.clone());
@@ -137,6 +143,7 @@ struct RelayClientComponentTransform<'program, 'flag> {
     no_inline_flag: &'flag FeatureFlag,
     // Stores the fragments that should use @no_inline and their parent document name
     no_inline_fragments: FragmentDefinitionNameMap<Vec<StringKey>>,
+    artifact_filename: &'flag ArtifactFilenameConfig,
 }
 
 impl<'program, 'flag> RelayClientComponentTransform<'program, 'flag> {
@@ -144,6 +151,7 @@ impl<'program, 'flag> RelayClientComponentTransform<'program, 'flag> {
         program: &'program Program,
         node_interface_id: InterfaceID,
         feature_flags: &'flag FeatureFlags,
+        artifact_filename: &'flag ArtifactFilenameConfig,
     ) -> Self {
         Self {
             program,
@@ -154,6 +162,7 @@ impl<'program, 'flag> RelayClientComponentTransform<'program, 'flag> {
             split_operation_filenames: Default::default(),
             no_inline_flag: &feature_flags.no_inline,
             no_inline_fragments: Default::default(),
+            artifact_filename,
         }
     }
 
@@ -284,7 +293,7 @@ impl<'program, 'flag> RelayClientComponentTransform<'program, 'flag> {
         }
 
         // @relay_client_component -> @relay_client_component_server(module_id: "...")
-        let module_id = get_fragment_filename(spread.fragment.item);
+        let module_id = get_fragment_filename(spread.fragment.item, self.artifact_filename);
         let mut next_directives = spread.directives.clone();
         if let Some(relay_client_component_directive) = next_directives
             .iter_mut()
@@ -77,6 +77,8 @@ lazy_static! {
         ArgumentName("has_output_type".intern());
     pub static ref RELAY_RESOLVER_INJECT_FRAGMENT_DATA: ArgumentName =
         ArgumentName("inject_fragment_data".intern());
+    pub static ref RELAY_RESOLVER_MAPPED_FRAGMENT_ARGUMENTS: ArgumentName =
+        ArgumentName("mapped_fragment_arguments".intern());
     pub static ref RELAY_RESOLVER_WEAK_OBJECT_DIRECTIVE: DirectiveName =
         DirectiveName("__RelayWeakObject".intern());
 }
@@ -108,6 +110,8 @@ struct RelayResolverFieldMetadata {
     import_name: Option<StringKey>,
     fragment_name: Option<FragmentDefinitionName>,
     fragment_data_injection_mode: Option<FragmentDataInjectionMode>,
+    /// Field arguments which are also arguments of the root fragment.
+    mapped_fragment_arguments: Vec<ArgumentName>,
     field_path: StringKey,
     live: bool,
     output_type_info: Option<ResolverOutputTypeInfo>,
@@ -187,20 +191,35 @@ impl<'program> RelayResolverSpreadTransform<'program> {
                     .expect("Previous validation passes ensured this exists.")
             });
 
-            let (fragment_arguments, field_arguments) = field
+            let is_fragment_argument = |arg: &Argument| {
+                if let Some(fragment_definition) = fragment_definition {
+                    fragment_definition
+                        .variable_definitions
+                        .named(VariableName(arg.name.item.0))
+                        .is_some()
+                } else {
+                    false
+                }
+            };
+            // Mapped arguments are passed both to the root fragment and to
+            // the resolver.
+            let fragment_arguments: Vec<Argument> = field
                 .arguments()
                 .iter()
-                .map(|arg| arg.clone())
-                .partition(|arg| {
-                    if let Some(fragment_definition) = fragment_definition {
-                        fragment_definition
-                            .variable_definitions
-                            .named(VariableName(arg.name.item.0))
-                            .is_some()
-                    } else {
-                        false
-                    }
-                });
+                .filter(|arg| is_fragment_argument(arg))
+                .cloned()
+                .collect();
+            let field_arguments: Vec<Argument> = field
+                .arguments()
+                .iter()
+                .filter(|arg| {
+                    !is_fragment_argument(arg)
+                        || field_metadata
+                            .mapped_fragment_arguments
+                            .contains(&arg.name.item)
+                })
+                .cloned()
+                .collect();
 
             let schema_field = self.program.schema.field(field.definition().item);
             let resolver_metadata = RelayResolverMetadata {
@@ -376,6 +395,7 @@ impl<'program> RelayResolverFieldTransform<'program> {
                     live,
                     has_output_type,
                     fragment_data_injection_mode,
+                    mapped_fragment_arguments,
                 }) => {
                     let mut non_required_directives =
                         field.directives().iter().filter(|directive| {
@@ -457,6 +477,7 @@ impl<'program> RelayResolverFieldTransform<'program> {
                         live,
                         output_type_info,
                         fragment_data_injection_mode,
+                        mapped_fragment_arguments,
                     };
 
                     let mut directives: Vec<Directive> = field.directives().to_vec();
@@ -570,6 +591,7 @@ impl Transformer for RelayResolverFieldTransform<'_> {
 struct ResolverInfo {
     fragment_name: Option<FragmentDefinitionName>,
     fragment_data_injection_mode: Option<FragmentDataInjectionMode>,
+    mapped_fragment_arguments: Vec<ArgumentName>,
     import_path: StringKey,
     import_name: Option<StringKey>,
     live: bool,
@@ -616,9 +638,17 @@ fn get_resolver_info(
                 error_location,
             )
             .ok();
+            let mapped_fragment_arguments = get_string_list_argument_values(
+                arguments,
+                *RELAY_RESOLVER_MAPPED_FRAGMENT_ARGUMENTS,
+            )
+            .into_iter()
+            .map(ArgumentName)
+            .collect();
 
             Ok(ResolverInfo {
                 fragment_name,
+                mapped_fragment_arguments,
                 import_path,
                 import_name,
                 live,
@@ -677,6 +707,37 @@ pub(crate) fn get_argument_value(
     }
 }
 
+/// The strings of a list argument, empty if the argument is omitted.
+fn get_string_list_argument_values(
+    arguments: &[ArgumentValue],
+    argument_name: ArgumentName,
+) -> Vec<StringKey> {
+    match arguments.named(argument_name) {
+        Some(ArgumentValue {
+            value: ConstantValue::List(values),
+            ..
+        }) => values
+            .items
+            .iter()
+            .map(|value| {
+                value.get_string_literal().unwrap_or_else(|| {
+                    panic!(
+                        "Expected the `{}` argument in a @relay_resolver schema directive to be a list of strings.",
+                        argument_name
+                    )
+                })
+            })
+            .collect(),
+        None => vec![],
+        // These schema extensions are generated by our compiler. If they are malformed, it indicates a bug in
+        // relay-docblock.
+        _ => panic!(
+            "Expected the `{}` argument in a @relay_resolver schema directive to be either omitted or a list of strings.",
+            argument_name
+        ),
+    }
+}
+
 pub(crate) fn get_bool_argument_is_true(
     arguments: &[ArgumentValue],
     argument_name: ArgumentName,
@@ -5,39 +5,49 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use common::diagnostic_codes;
 use intern::string_key::StringKey;
 use thiserror::Error;
 
-#[derive(Error, Debug)]
-pub(super) enum ValidationMessage {
-    #[error(
-        "Unexpected @required within inline fragment on an abstract type. At runtime we cannot know if this field is null, or if it's missing because the inline fragment did not match. Consider using `@alias` to give your inline fragment a name."
-    )]
-    RequiredWithinAbstractInlineFragment,
-
-    #[error("@required is not supported within @inline fragments.")]
-    RequiredWithinInlineDirective,
-
-    #[error("Missing `action` argument. @required expects an `action` argument")]
-    RequiredActionArgumentRequired,
-
-    #[error(
-        "All references to a @required field must have matching `action` arguments. The `action` used for '{field_name}'"
-    )]
-    RequiredActionMismatch { field_name: StringKey },
-
-    #[error(
-        "All references to a field must have matching @required declarations. The field '{field_name}` is @required here"
-    )]
-    RequiredFieldMismatch { field_name: StringKey },
-
-    #[error(
-        "@required fields must be included in all instances of their parent. The field '{field_name}` is marked as @required here"
-    )]
-    RequiredFieldMissing { field_name: StringKey },
-
-    #[error(
-        "A @required field may not have an `action` less severe than that of its @required parent. This @required directive should probably have `action: {suggested_action}`"
-    )]
-    RequiredFieldInvalidNesting { suggested_action: StringKey },
+diagnostic_codes! {
+    #[derive(Error, Debug)]
+    pub(super) enum ValidationMessage {
+        #[error(
+            "Unexpected @required within inline fragment on an abstract type. At runtime we cannot know if this field is null, or if it's missing because the inline fragment did not match. Consider using `@alias` to give your inline fragment a name."
+        )]
+        #[code("RELAY2131")]
+        RequiredWithinAbstractInlineFragment,
+
+        #[error("@required is not supported within @inline fragments.")]
+        #[code("RELAY2132")]
+        RequiredWithinInlineDirective,
+
+        #[error("Missing `action` argument. @required expects an `action` argument")]
+        #[code("RELAY2133")]
+        RequiredActionArgumentRequired,
+
+        #[error(
+            "All references to a @required field must have matching `action` arguments. The `action` used for '{field_name}'"
+        )]
+        #[code("RELAY2134")]
+        RequiredActionMismatch { field_name: StringKey },
+
+        #[error(
+            "All references to a field must have matching @required declarations. The field '{field_name}` is @required here"
+        )]
+        #[code("RELAY2135")]
+        RequiredFieldMismatch { field_name: StringKey },
+
+        #[error(
+            "@required fields must be included in all instances of their parent. The field '{field_name}` is marked as @required here"
+        )]
+        #[code("RELAY2136")]
+        RequiredFieldMissing { field_name: StringKey },
+
+        #[error(
+            "A @required field may not have an `action` less severe than that of its @required parent. This @required directive should probably have `action: {suggested_action}`"
+        )]
+        #[code("RELAY2137")]
+        RequiredFieldInvalidNesting { suggested_action: StringKey },
+    }
 }
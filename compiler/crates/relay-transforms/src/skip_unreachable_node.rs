@@ -7,6 +7,7 @@
 
 use std::sync::Arc;
 
+use common::diagnostic_codes;
 use common::Diagnostic;
 use common::DiagnosticsResult;
 use common::NamedItem;
@@ -338,16 +339,19 @@ impl<'s> SkipUnreachableNodeTransform<'s> {
     }
 }
 
-#[derive(Clone, Debug, Error, Eq, PartialEq, Ord, PartialOrd, Hash)]
-enum ValidationMessage {
-    #[error(
-        "After applying transforms to the {document} `{name}` selections of \
-        the `{name}` that would be sent to the server are empty. \
-        This is likely due to the use of `@skip`/`@include` directives with \
-        constant values that remove all selections in the {document}. "
-    )]
-    EmptySelectionsInDocument {
-        name: StringKey,
-        document: &'static str,
-    },
+diagnostic_codes! {
+    #[derive(Clone, Debug, Error, Eq, PartialEq, Ord, PartialOrd, Hash)]
+    enum ValidationMessage {
+        #[error(
+            "After applying transforms to the {document} `{name}` selections of \
+            the `{name}` that would be sent to the server are empty. \
+            This is likely due to the use of `@skip`/`@include` directives with \
+            constant values that remove all selections in the {document}. "
+        )]
+        #[code("RELAY2138")]
+        EmptySelectionsInDocument {
+            name: StringKey,
+            document: &'static str,
+        },
+    }
 }
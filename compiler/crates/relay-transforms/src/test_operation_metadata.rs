@@ -33,6 +33,7 @@ use schema::Schema;
 use schema::Type;
 
 use crate::create_metadata_directive;
+use crate::RelayResolverMetadata;
 use crate::ValidationMessage;
 use crate::DIRECTIVE_SPLIT_OPERATION;
 
@@ -49,6 +50,9 @@ lazy_static! {
 
 /// Transforms the @relay_test_operation directive to @__metadata thats printed
 /// as runtime data during codegen.
+/// Relay Resolver fields (including the ones backing client edges) are
+/// included with their return type, so mock resolvers can generate client data
+/// as well.
 /// If a `test_path_regex` is passed, only allows the directive in
 /// directories matching the regex.
 pub fn generate_test_operation_metadata(
@@ -221,6 +225,19 @@ impl RelayTestOperationMetadata {
             if let Some(current_item) = processing_queue.pop() {
                 let (path, selections) = current_item;
                 for selection in selections {
+                    if let Some(resolver_metadata) =
+                        RelayResolverMetadata::find(selection.directives())
+                    {
+                        let alias_or_name = resolver_metadata
+                            .field_alias
+                            .unwrap_or(resolver_metadata.field_name);
+                        if let Some(field) = resolver_field(schema, resolver_metadata) {
+                            selection_type_info.insert(
+                                next_path(path, alias_or_name),
+                                RelayTestOperationSelectionTypeInfo::new(schema, field),
+                            );
+                        }
+                    }
                     match selection {
                         Selection::ScalarField(scalar_field) => {
                             let field = schema.field(scalar_field.definition.item);
@@ -278,6 +295,16 @@ impl RelayTestOperationMetadata {
     }
 }
 
+/// The schema field of a Relay Resolver.
+fn resolver_field<'s>(
+    schema: &'s SDLSchema,
+    resolver_metadata: &RelayResolverMetadata,
+) -> Option<&'s Field> {
+    let parent_type = schema.get_type(resolver_metadata.field_parent_type)?;
+    let field_id = schema.named_field(parent_type, resolver_metadata.field_name)?;
+    Some(schema.field(field_id))
+}
+
 fn next_path(current_path: Option<StringKey>, field_alias_or_name: StringKey) -> StringKey {
     match current_path {
         None => field_alias_or_name,
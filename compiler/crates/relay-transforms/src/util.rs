@@ -19,6 +19,7 @@ use intern::string_key::StringKey;
 use intern::Lookup;
 use lazy_static::lazy_static;
 use regex::Regex;
+use relay_config::ArtifactFilenameConfig;
 use schema::SDLSchema;
 use schema::Schema;
 use schema::Type;
@@ -168,12 +169,13 @@ pub fn get_normalization_operation_name(name: StringKey) -> String {
     format!("{}$normalization", name)
 }
 
-pub fn get_fragment_filename(fragment_name: FragmentDefinitionName) -> StringKey {
-    format!(
-        "{}.graphql",
-        get_normalization_operation_name(fragment_name.0)
-    )
-    .intern()
+pub fn get_fragment_filename(
+    fragment_name: FragmentDefinitionName,
+    artifact_filename: &ArtifactFilenameConfig,
+) -> StringKey {
+    artifact_filename
+        .module_name(get_normalization_operation_name(fragment_name.0))
+        .intern()
 }
 
 pub fn format_provided_variable_name(module_name: StringKey) -> VariableName {
@@ -0,0 +1,261 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::diagnostics_result;
+use common::escalate_and_check;
+use common::CriticalDiagnostics;
+use common::DiagnosticsResult;
+use common::FeatureFlags;
+use common::StableDiagnostics;
+use common::WithDiagnostics;
+use errors::try_all;
+use graphql_ir::Program;
+use relay_config::ProjectConfig;
+
+use crate::deprecated_fields;
+use crate::disallow_circular_no_inline_fragments;
+use crate::disallow_circular_unmasked_fragments;
+use crate::disallow_reserved_aliases;
+use crate::disallow_typename_on_root;
+use crate::validate_assignable_directive;
+use crate::validate_closed_abstract_types;
+use crate::validate_connections;
+use crate::validate_custom_scalar_types;
+use crate::validate_global_variable_names;
+use crate::validate_module_names;
+use crate::validate_no_double_underscore_alias;
+use crate::validate_no_inline_fragments_with_raw_response_type;
+use crate::validate_operation_directories;
+use crate::validate_relay_directives;
+use crate::validate_resolver_fragments;
+use crate::validate_static_args;
+use crate::validate_unused_fragment_variables;
+use crate::validate_unused_variables;
+use crate::validate_updatable_directive;
+use crate::validate_updatable_fragment_spread;
+
+const DEPRECATED_FIELD: &str = "DeprecatedField";
+
+pub type AdditionalValidations =
+    Box<dyn Fn(&Program, &FeatureFlags) -> DiagnosticsResult<()> + Sync + Send>;
+
+/// A validation rule that runs with the built-in ones, e.g. an org-specific lint
+/// rule. Its diagnostics are escalated according to the project's
+/// `diagnosticReportConfig`, so it can report warnings that don't fail the build.
+#[derive(Clone)]
+pub struct CustomValidation {
+    pub name: &'static str,
+    pub validate: fn(&Program, &ProjectConfig) -> DiagnosticsResult<()>,
+}
+
+impl std::fmt::Debug for CustomValidation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CustomValidation")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+/// Runs the validation rules of the compiler on `program`. They don't read any
+/// file, so that they also run in the browser, e.g. in the playground.
+pub fn validate(
+    program: &Program,
+    project_config: &ProjectConfig,
+    additional_validations: &Option<AdditionalValidations>,
+    custom_validations: &[CustomValidation],
+) -> DiagnosticsResult<WithDiagnostics<()>> {
+    let mut validations = vec![
+        disallow_reserved_aliases(program, &project_config.schema_config),
+        validate_no_double_underscore_alias(program),
+        validate_unused_variables(program),
+        validate_unused_fragment_variables(program),
+        validate_connections(program, &project_config.schema_config.connection_interface),
+        validate_relay_directives(program),
+        validate_global_variable_names(program),
+        validate_module_names(program),
+        validate_no_inline_fragments_with_raw_response_type(program),
+        disallow_typename_on_root(program),
+        validate_static_args(program),
+        if let Some(ref validate) = additional_validations {
+            validate(program, &project_config.feature_flags)
+        } else {
+            Ok(())
+        },
+        disallow_circular_no_inline_fragments(program),
+        disallow_circular_unmasked_fragments(program),
+        validate_updatable_directive(program),
+        validate_updatable_fragment_spread(program),
+        validate_assignable_directive(program),
+        validate_closed_abstract_types(program, &project_config.typegen_config),
+        validate_custom_scalar_types(program, &project_config.typegen_config),
+        validate_operation_directories(program, project_config),
+        if project_config.feature_flags.enable_relay_resolver_transform {
+            validate_resolver_fragments(program)
+        } else {
+            Ok(())
+        },
+    ];
+    validations.extend(
+        custom_validations
+            .iter()
+            .map(|custom_validation| (custom_validation.validate)(program, project_config)),
+    );
+    let report_config = &project_config.diagnostic_report_config;
+    // Deprecated fields are only reported by the compiler when the project
+    // configures their severity, e.g. to ratchet their usage.
+    if report_config.has_severity_override(DEPRECATED_FIELD) {
+        validations.push(
+            deprecated_fields(&program.schema, program)
+                .and_then(|warnings| diagnostics_result((), warnings)),
+        );
+    }
+    let output = try_all(validations);
+
+    match output {
+        Ok(_) => Ok(WithDiagnostics {
+            item: (),
+            diagnostics: Vec::new(),
+        }),
+        Err(mut errors) => {
+            report_config.apply_severity_overrides(&mut errors);
+            let critical_level = report_config.critical_level;
+
+            // We are ignoring the results of successful validations in the error branch, since
+            // `try_map` returns a vector of all errors if any validator returned an error.
+            // This is okay because successful validations return no special information
+            // (i.e. their Ok variant contains ()).
+
+            escalate_and_check(critical_level.into(), errors)
+                .map(|StableDiagnostics(diagnostics)| WithDiagnostics {
+                    item: (),
+                    diagnostics,
+                })
+                .map_err(|CriticalDiagnostics(errors)| errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common::Diagnostic;
+    use common::SourceLocationKey;
+    use graphql_ir::build;
+    use graphql_syntax::parse_executable;
+    use intern::Lookup;
+    use relay_config::DiagnosticLevel;
+    use relay_config::DiagnosticReportConfig;
+    use relay_test_schema::get_test_schema;
+
+    use super::*;
+
+    fn disallow_query_names_ending_in_test(
+        program: &Program,
+        _: &ProjectConfig,
+    ) -> DiagnosticsResult<()> {
+        let errors = program
+            .operations()
+            .filter(|operation| operation.name.item.0.lookup().ends_with("TestQuery"))
+            .map(|operation| {
+                Diagnostic::warning(
+                    format!(
+                        "Operation `{}` looks like a test query.",
+                        operation.name.item
+                    ),
+                    operation.name.location,
+                    vec![],
+                )
+            })
+            .collect::<Vec<_>>();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn build_program(text: &str) -> Program {
+        let schema = get_test_schema();
+        let ast = parse_executable(text, SourceLocationKey::standalone("MyTest.graphql")).unwrap();
+        let ir = build(&schema, &ast.definitions).unwrap();
+        Program::from_definitions(Arc::clone(&schema), ir)
+    }
+
+    #[test]
+    fn test_custom_validations() {
+        let program = build_program("query MyTestQuery { me { id } }");
+        let custom_validations = vec![CustomValidation {
+            name: "disallow_query_names_ending_in_test",
+            validate: disallow_query_names_ending_in_test,
+        }];
+
+        let project_config = ProjectConfig {
+            diagnostic_report_config: DiagnosticReportConfig {
+                critical_level: DiagnosticLevel::Error,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let result = validate(&program, &project_config, &None, &custom_validations).unwrap();
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(
+            result.diagnostics[0].message().to_string(),
+            "Operation `MyTestQuery` looks like a test query."
+        );
+
+        let project_config = ProjectConfig {
+            diagnostic_report_config: DiagnosticReportConfig {
+                critical_level: DiagnosticLevel::Warning,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(validate(&program, &project_config, &None, &custom_validations).is_err());
+    }
+
+    #[test]
+    fn test_severity_overrides() {
+        let program = build_program("query MyTestQuery($unused: ID) { me { id } }");
+        assert!(validate(&program, &Default::default(), &None, &[]).is_err());
+
+        // By kind
+        let project_config = ProjectConfig {
+            diagnostic_report_config: DiagnosticReportConfig {
+                severity_overrides: [("UnusedVariable".to_string(), DiagnosticLevel::Warning)]
+                    .into_iter()
+                    .collect(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let result = validate(&program, &project_config, &None, &[]).unwrap();
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(
+            result.diagnostics[0].severity(),
+            common::DiagnosticSeverity::WARNING
+        );
+
+        // By code, which takes precedence over the kind
+        let project_config = ProjectConfig {
+            diagnostic_report_config: DiagnosticReportConfig {
+                severity_overrides: [
+                    ("UnusedVariable".to_string(), DiagnosticLevel::Warning),
+                    (
+                        result.diagnostics[0].error_code().unwrap().to_string(),
+                        DiagnosticLevel::Error,
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(validate(&program, &project_config, &None, &[]).is_err());
+    }
+}
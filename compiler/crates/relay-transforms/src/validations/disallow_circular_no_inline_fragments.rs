@@ -5,6 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use common::diagnostic_codes;
 use common::Diagnostic;
 use common::DiagnosticsResult;
 use common::NamedItem;
@@ -87,10 +88,13 @@ impl Validator for DisallowCircularNoInlineFragments<'_> {
     }
 }
 
-#[derive(Debug, Error)]
-enum ValidationMessage {
-    #[error("Found a circular reference from fragment '{fragment_name}'.")]
-    CircularFragmentReference {
-        fragment_name: FragmentDefinitionName,
-    },
+diagnostic_codes! {
+    #[derive(Debug, Error)]
+    enum ValidationMessage {
+        #[error("Found a circular reference from fragment '{fragment_name}'.")]
+        #[code("RELAY2151")]
+        CircularFragmentReference {
+            fragment_name: FragmentDefinitionName,
+        },
+    }
 }
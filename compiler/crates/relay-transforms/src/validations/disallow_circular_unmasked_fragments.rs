@@ -0,0 +1,124 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::diagnostic_codes;
+use common::Diagnostic;
+use common::DiagnosticsResult;
+use common::Location;
+use graphql_ir::FragmentDefinition;
+use graphql_ir::FragmentDefinitionName;
+use graphql_ir::FragmentDefinitionNameMap;
+use graphql_ir::FragmentSpread;
+use graphql_ir::Program;
+use graphql_ir::Validator;
+use intern::Lookup;
+use thiserror::Error;
+
+use crate::relay_directive::RelayDirective;
+
+/// Fragment spreads with `@relay(mask: false)` are inlined into their parent,
+/// so they can't form a cycle. Reports each cycle with the path of fragments
+/// that form it.
+pub fn disallow_circular_unmasked_fragments(program: &Program) -> DiagnosticsResult<()> {
+    let mut validator = DisallowCircularUnmaskedFragments::new(program);
+    validator.validate_program(program)
+}
+
+enum FragmentStatus {
+    Visiting,
+    Visited,
+}
+
+struct DisallowCircularUnmaskedFragments<'program> {
+    program: &'program Program,
+    fragments: FragmentDefinitionNameMap<FragmentStatus>,
+    /// The chain of unmasked fragments that is currently visited, with the
+    /// locations where they are spread.
+    path: Vec<(FragmentDefinitionName, Location)>,
+}
+
+impl<'program> DisallowCircularUnmaskedFragments<'program> {
+    fn new(program: &'program Program) -> Self {
+        Self {
+            program,
+            fragments: Default::default(),
+            path: Default::default(),
+        }
+    }
+
+    fn visit_fragment(
+        &mut self,
+        fragment: &FragmentDefinition,
+        location: Location,
+    ) -> DiagnosticsResult<()> {
+        match self.fragments.get(&fragment.name.item) {
+            None => {
+                self.fragments
+                    .insert(fragment.name.item, FragmentStatus::Visiting);
+                self.path.push((fragment.name.item, location));
+                let result = self.validate_selections(&fragment.selections);
+                self.path.pop();
+                self.fragments
+                    .insert(fragment.name.item, FragmentStatus::Visited);
+                result
+            }
+            Some(FragmentStatus::Visited) => Ok(()),
+            Some(FragmentStatus::Visiting) => {
+                let start = self
+                    .path
+                    .iter()
+                    .position(|(name, _)| *name == fragment.name.item)
+                    .unwrap();
+                let cycle = &self.path[start..];
+                let mut fragment_names = cycle
+                    .iter()
+                    .map(|(name, _)| name.0.lookup())
+                    .collect::<Vec<_>>();
+                fragment_names.push(fragment.name.item.0.lookup());
+                let mut error = Diagnostic::error(
+                    ValidationMessage::CircularUnmaskedFragmentReference {
+                        path: fragment_names.join(" -> "),
+                    },
+                    location,
+                );
+                for (name, location) in &cycle[1..] {
+                    error = error.annotate(format!("'{}' is spread here", name), *location);
+                }
+                Err(vec![error])
+            }
+        }
+    }
+}
+
+impl Validator for DisallowCircularUnmaskedFragments<'_> {
+    const NAME: &'static str = "disallow_circular_unmasked_fragments";
+    const VALIDATE_ARGUMENTS: bool = false;
+    const VALIDATE_DIRECTIVES: bool = false;
+
+    fn validate_fragment(&mut self, fragment: &FragmentDefinition) -> DiagnosticsResult<()> {
+        self.visit_fragment(fragment, fragment.name.location)
+    }
+
+    fn validate_fragment_spread(&mut self, spread: &FragmentSpread) -> DiagnosticsResult<()> {
+        if !RelayDirective::is_unmasked_fragment_spread(spread) {
+            return Ok(());
+        }
+        let fragment = self.program.fragment(spread.fragment.item).unwrap();
+        self.visit_fragment(fragment, spread.fragment.location)
+    }
+}
+
+diagnostic_codes! {
+    #[derive(Debug, Error)]
+    enum ValidationMessage {
+        #[error(
+            "Found a cycle of fragments spread with @relay(mask: false): {path}. Unmasked fragments are inlined into their parent and can't reference themselves."
+        )]
+        #[code("RELAY2139")]
+        CircularUnmaskedFragmentReference { path: String },
+    }
+}
@@ -7,15 +7,19 @@
 
 mod deprecated_fields;
 mod disallow_circular_no_inline_fragments;
+mod disallow_circular_unmasked_fragments;
 mod disallow_non_node_id_fields;
 mod disallow_reserved_aliases;
 mod disallow_typename_on_root;
+mod validate_closed_abstract_types;
 mod validate_connections;
+mod validate_custom_scalar_types;
 mod validate_global_variable_names;
 mod validate_global_variables;
 mod validate_module_names;
 mod validate_no_double_underscore_alias;
 mod validate_no_inline_with_raw_response_type;
+mod validate_operation_directories;
 mod validate_relay_directives;
 mod validate_required_arguments;
 mod validate_resolver_fragments;
@@ -27,16 +31,20 @@ mod validate_unused_variables;
 pub use deprecated_fields::deprecated_fields;
 pub use deprecated_fields::deprecated_fields_for_executable_definition;
 pub use disallow_circular_no_inline_fragments::disallow_circular_no_inline_fragments;
+pub use disallow_circular_unmasked_fragments::disallow_circular_unmasked_fragments;
 pub use disallow_non_node_id_fields::disallow_non_node_id_fields;
 pub use disallow_reserved_aliases::disallow_reserved_aliases;
 pub use disallow_typename_on_root::disallow_typename_on_root;
+pub use validate_closed_abstract_types::validate_closed_abstract_types;
 pub use validate_connections::validate_connections;
+pub use validate_custom_scalar_types::validate_custom_scalar_types;
 pub use validate_global_variable_names::validate_global_variable_names;
 pub use validate_global_variables::validate_global_variables;
 pub use validate_module_names::extract_module_name;
 pub use validate_module_names::validate_module_names;
 pub use validate_no_double_underscore_alias::validate_no_double_underscore_alias;
 pub use validate_no_inline_with_raw_response_type::validate_no_inline_fragments_with_raw_response_type;
+pub use validate_operation_directories::validate_operation_directories;
 pub use validate_relay_directives::validate_relay_directives;
 pub use validate_required_arguments::validate_required_arguments;
 pub use validate_resolver_fragments::validate_resolver_fragments;
@@ -0,0 +1,77 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::Diagnostic;
+use common::DiagnosticsResult;
+use common::Location;
+use errors::validate_map;
+use graphql_ir::Program;
+use intern::string_key::StringKey;
+use relay_config::TypegenConfig;
+use schema::Schema;
+use schema::Type;
+
+use crate::errors::ValidationMessage;
+
+/// Validates that the implementing types of the abstract types listed in the
+/// `closedAbstractTypes` typegen config match the schema, since the generated
+/// types of their selections don't account for other implementing types.
+pub fn validate_closed_abstract_types(
+    program: &Program,
+    typegen_config: &TypegenConfig,
+) -> DiagnosticsResult<()> {
+    let schema = &program.schema;
+    validate_map(
+        &typegen_config.closed_abstract_types,
+        |(&type_name, expected_types)| {
+            let (location, object_ids) = match schema.get_type(type_name) {
+                Some(Type::Interface(id)) => {
+                    let interface = schema.interface(id);
+                    (interface.name.location, &interface.implementing_objects)
+                }
+                Some(Type::Union(id)) => {
+                    let union = schema.union(id);
+                    (union.name.location, &union.members)
+                }
+                _ => {
+                    return Err(vec![Diagnostic::error(
+                        ValidationMessage::ClosedAbstractTypeNotAbstract { type_name },
+                        Location::generated(),
+                    )]);
+                }
+            };
+            let mut actual_types: Vec<_> = object_ids
+                .iter()
+                .map(|id| schema.object(*id).name.item.0)
+                .collect();
+            actual_types.sort();
+            let mut expected_types = expected_types.clone();
+            expected_types.sort();
+            expected_types.dedup();
+            if actual_types == expected_types {
+                Ok(())
+            } else {
+                Err(vec![Diagnostic::error(
+                    ValidationMessage::ClosedAbstractTypeImplementationsChanged {
+                        type_name,
+                        expected: join_type_names(&expected_types),
+                        actual: join_type_names(&actual_types),
+                    },
+                    location,
+                )])
+            }
+        },
+    )
+}
+
+fn join_type_names(type_names: &[StringKey]) -> String {
+    type_names
+        .iter()
+        .map(|type_name| type_name.to_string())
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
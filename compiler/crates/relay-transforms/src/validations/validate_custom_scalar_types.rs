@@ -0,0 +1,169 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::Diagnostic;
+use common::DiagnosticsResult;
+use common::DirectiveName;
+use common::Location;
+use common::NamedItem;
+use fnv::FnvHashSet;
+use graphql_ir::Field;
+use graphql_ir::FragmentDefinition;
+use graphql_ir::OperationDefinition;
+use graphql_ir::Program;
+use graphql_ir::ScalarField;
+use graphql_ir::Visitor;
+use indexmap::IndexMap;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use lazy_static::lazy_static;
+use relay_config::TypegenConfig;
+use schema::InputObjectID;
+use schema::SDLSchema;
+use schema::ScalarID;
+use schema::Schema;
+use schema::Type;
+
+use crate::errors::ValidationMessage;
+
+lazy_static! {
+    static ref BUILT_IN_SCALARS: [StringKey; 6] = [
+        "ID".intern(),
+        "String".intern(),
+        "Int".intern(),
+        "Float".intern(),
+        "Boolean".intern(),
+        // Typed from the return type of the resolvers
+        "RelayResolverValue".intern(),
+    ];
+    /// The directive of the scalars of the schema extensions with a type
+    /// defined by `@custom_scalar`.
+    static ref CUSTOM_SCALAR_DIRECTIVE_NAME: DirectiveName =
+        DirectiveName("__RelayCustomScalar".intern());
+}
+
+/// Validates that the custom scalars selected by the documents, or in the
+/// types of the variables of the operations, have a type in the
+/// `customScalarTypes` typegen config when `requireCustomScalarTypes` is set,
+/// instead of silently being typed as `any`.
+pub fn validate_custom_scalar_types(
+    program: &Program,
+    typegen_config: &TypegenConfig,
+) -> DiagnosticsResult<()> {
+    if !typegen_config.require_custom_scalar_types {
+        return Ok(());
+    }
+    let mut validator = ValidateCustomScalarTypes {
+        schema: &program.schema,
+        typegen_config,
+        unmapped_scalars: Default::default(),
+    };
+    validator.visit_program(program);
+
+    let mut unmapped_scalars: Vec<_> = validator.unmapped_scalars.into_iter().collect();
+    unmapped_scalars.sort_by_key(|(scalar_name, _)| *scalar_name);
+    let errors: Vec<_> = unmapped_scalars
+        .into_iter()
+        .map(|(scalar_name, locations)| {
+            locations.iter().skip(1).fold(
+                Diagnostic::error(
+                    ValidationMessage::UnmappedCustomScalar { scalar_name },
+                    locations[0],
+                ),
+                |diagnostic, location| diagnostic.annotate("also used here", *location),
+            )
+        })
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+struct ValidateCustomScalarTypes<'a> {
+    schema: &'a SDLSchema,
+    typegen_config: &'a TypegenConfig,
+    /// The locations where each unmapped scalar is used, in document order.
+    unmapped_scalars: IndexMap<StringKey, Vec<Location>>,
+}
+
+impl<'a> ValidateCustomScalarTypes<'a> {
+    fn is_mapped(&self, scalar_id: ScalarID) -> bool {
+        let scalar = self.schema.scalar(scalar_id);
+        BUILT_IN_SCALARS.contains(&scalar.name.item.0)
+            || self
+                .typegen_config
+                .custom_scalar_types
+                .contains_key(&scalar.name.item)
+            || scalar
+                .directives
+                .named(*CUSTOM_SCALAR_DIRECTIVE_NAME)
+                .is_some()
+    }
+
+    fn check_type(
+        &mut self,
+        type_: Type,
+        location: Location,
+        visited_input_objects: &mut FnvHashSet<InputObjectID>,
+    ) {
+        match type_ {
+            Type::Scalar(scalar_id) => {
+                if !self.is_mapped(scalar_id) {
+                    let locations = self
+                        .unmapped_scalars
+                        .entry(self.schema.scalar(scalar_id).name.item.0)
+                        .or_default();
+                    if !locations.contains(&location) {
+                        locations.push(location);
+                    }
+                }
+            }
+            // The fields of the input objects are typed with the variables
+            Type::InputObject(input_object_id) => {
+                if visited_input_objects.insert(input_object_id) {
+                    let input_object = self.schema.input_object(input_object_id);
+                    for field in input_object.fields.iter() {
+                        self.check_type(field.type_.inner(), location, visited_input_objects);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Visitor for ValidateCustomScalarTypes<'a> {
+    const NAME: &'static str = "ValidateCustomScalarTypes";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_operation(&mut self, operation: &OperationDefinition) {
+        for variable_definition in &operation.variable_definitions {
+            self.check_type(
+                variable_definition.type_.inner(),
+                variable_definition.name.location,
+                &mut Default::default(),
+            );
+        }
+        self.visit_selections(&operation.selections);
+    }
+
+    fn visit_fragment(&mut self, fragment: &FragmentDefinition) {
+        self.visit_selections(&fragment.selections);
+    }
+
+    fn visit_scalar_field(&mut self, field: &ScalarField) {
+        let type_ = self.schema.field(field.definition.item).type_.inner();
+        self.check_type(
+            type_,
+            field.alias_or_name_location(),
+            &mut Default::default(),
+        );
+    }
+}
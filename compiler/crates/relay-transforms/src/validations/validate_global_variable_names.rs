@@ -5,6 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use common::diagnostic_codes;
 use common::Diagnostic;
 use common::DiagnosticsResult;
 use graphql_ir::OperationDefinition;
@@ -58,10 +59,13 @@ impl Validator for ValidateGlobalVariableNames {
     }
 }
 
-#[derive(Clone, Debug, Error, Eq, PartialEq, Ord, PartialOrd, Hash)]
-enum ValidationMessage {
-    #[error(
-        "Invalid name `${0}` for an operation variable. The `__relay_internal` prefix is reserved for relay internal usage."
-    )]
-    InvalidOperationVariablePrefix(VariableName),
+diagnostic_codes! {
+    #[derive(Clone, Debug, Error, Eq, PartialEq, Ord, PartialOrd, Hash)]
+    enum ValidationMessage {
+        #[error(
+            "Invalid name `${0}` for an operation variable. The `__relay_internal` prefix is reserved for relay internal usage."
+        )]
+        #[code("RELAY2140")]
+        InvalidOperationVariablePrefix(VariableName),
+    }
 }
@@ -7,6 +7,7 @@
 
 mod extract_module_name;
 
+use common::diagnostic_codes;
 use common::Diagnostic;
 use common::DiagnosticsResult;
 use graphql_ir::FragmentDefinition;
@@ -16,6 +17,8 @@ use graphql_ir::Validator;
 use graphql_syntax::OperationKind;
 use thiserror::Error;
 
+use crate::unsuffixed_operation_name;
+
 pub fn validate_module_names(program: &Program) -> DiagnosticsResult<()> {
     (ValidateModuleNames {}).validate_program(program)
 }
@@ -30,7 +33,7 @@ impl Validator for ValidateModuleNames {
     const VALIDATE_DIRECTIVES: bool = true;
 
     fn validate_operation(&mut self, operation: &OperationDefinition) -> DiagnosticsResult<()> {
-        let operation_name = operation.name.item.0.to_string();
+        let operation_name = unsuffixed_operation_name(operation).to_string();
         let path = operation.name.location.source_location().path();
         let module_name = extract_module_name(path).expect("Unable to extract module name.");
         let (operation_type_suffix, pluralized_string) = match operation.kind {
@@ -80,23 +83,27 @@ impl Validator for ValidateModuleNames {
     }
 }
 
-#[derive(Debug, Error)]
-pub enum ValidationMessage {
-    #[error(
-        "{pluralized_string} in graphql tags must start with the module name ('{module_name}') and end with '{operation_type_suffix}'. Got '{operation_name}' instead."
-    )]
-    InvalidOperationName {
-        pluralized_string: String,
-        module_name: String,
-        operation_type_suffix: String,
-        operation_name: String,
-    },
+diagnostic_codes! {
+    #[derive(Debug, Error)]
+    pub enum ValidationMessage {
+        #[error(
+            "{pluralized_string} in graphql tags must start with the module name ('{module_name}') and end with '{operation_type_suffix}'. Got '{operation_name}' instead."
+        )]
+        #[code("RELAY2141")]
+        InvalidOperationName {
+            pluralized_string: String,
+            module_name: String,
+            operation_type_suffix: String,
+            operation_name: String,
+        },
 
-    #[error(
-        "Fragments in graphql tags must start with the module name ('{module_name}'). Got '{fragment_name}' instead."
-    )]
-    InvalidFragmentName {
-        module_name: String,
-        fragment_name: String,
-    },
+        #[error(
+            "Fragments in graphql tags must start with the module name ('{module_name}'). Got '{fragment_name}' instead."
+        )]
+        #[code("RELAY2142")]
+        InvalidFragmentName {
+            module_name: String,
+            fragment_name: String,
+        },
+    }
 }
@@ -0,0 +1,51 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::path::Path;
+
+use common::Diagnostic;
+use common::DiagnosticsResult;
+use common::SourceLocationKey;
+use errors::validate_map;
+use graphql_ir::Program;
+use relay_config::ProjectConfig;
+
+use crate::errors::ValidationMessage;
+
+/// Validates that the operations are defined in the `operationDirectories` of
+/// the project, if any, e.g. to only let the route entrypoints own queries.
+pub fn validate_operation_directories(
+    program: &Program,
+    project_config: &ProjectConfig,
+) -> DiagnosticsResult<()> {
+    let operation_directories = match &project_config.operation_directories {
+        Some(operation_directories) => operation_directories,
+        None => return Ok(()),
+    };
+    validate_map(program.operations(), |operation| {
+        let source_location = operation.name.location.source_location();
+        if source_location == SourceLocationKey::Generated
+            || operation_directories
+                .iter()
+                .any(|directory| Path::new(source_location.path()).starts_with(directory))
+        {
+            Ok(())
+        } else {
+            Err(vec![Diagnostic::error(
+                ValidationMessage::OperationOutsideOperationDirectories {
+                    operation_name: operation.name.item.0,
+                    directories: operation_directories
+                        .iter()
+                        .map(|directory| format!("`{}`", directory.display()))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                },
+                operation.name.location,
+            )])
+        }
+    })
+}
@@ -8,6 +8,7 @@
 use std::collections::HashSet;
 
 use common::Diagnostic;
+use common::DiagnosticFix;
 use common::DiagnosticsResult;
 use common::NamedItem;
 use graphql_ir::FragmentDefinition;
@@ -65,13 +66,16 @@ impl Validator for ValidateUnusedFragmentVariables {
                         expect_unused_directive.name.location,
                     ))
                 } else {
-                    Some(Diagnostic::error(
-                        ValidationMessage::UnusedFragmentVariable {
-                            fragment_name: fragment.name.item,
-                            variable_name: variable_definition.name.item,
-                        },
-                        variable_definition.name.location,
-                    ))
+                    Some(
+                        Diagnostic::error(
+                            ValidationMessage::UnusedFragmentVariable {
+                                fragment_name: fragment.name.item,
+                                variable_name: variable_definition.name.item,
+                            },
+                            variable_definition.name.location,
+                        )
+                        .with_safe_fix(DiagnosticFix::RemoveVariableDefinition),
+                    )
                 }
             })
             .collect();
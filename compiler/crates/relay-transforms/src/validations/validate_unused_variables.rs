@@ -6,6 +6,7 @@
  */
 
 use common::Diagnostic;
+use common::DiagnosticFix;
 use common::DiagnosticsResult;
 use common::DirectiveName;
 use common::NamedItem;
@@ -65,6 +66,7 @@ impl Validator for ValidateUnusedVariables<'_> {
                         },
                         unused_variable.name.location,
                     )
+                    .with_safe_fix(DiagnosticFix::RemoveVariableDefinition)
                 })
                 .collect());
         }
@@ -0,0 +1,55 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::build;
+use graphql_ir::Program;
+use graphql_syntax::parse_executable;
+use graphql_text_printer::print_fragment;
+use graphql_text_printer::PrinterOptions;
+use regex::Regex;
+use relay_test_schema::get_test_schema;
+use relay_transforms::apply_configured_no_inline_directives;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let mut parts = fixture.content.split("%no_inline_fragments%");
+    let base = parts.next().expect("Pre-config content required");
+    let no_inline_fragments = parts
+        .next()
+        .expect("Expected a %no_inline_fragments% section")
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| Regex::new(line).unwrap())
+        .collect::<Vec<_>>();
+
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let schema = get_test_schema();
+    let ast = parse_executable(base, source_location).unwrap();
+    let ir = build(&schema, &ast.definitions).unwrap();
+    let mut program = Program::from_definitions(Arc::clone(&schema), ir);
+    let affected_fragments =
+        apply_configured_no_inline_directives(&mut program, &no_inline_fragments);
+
+    let mut printed = program
+        .fragments()
+        .map(|def| print_fragment(&schema, def, PrinterOptions::default()))
+        .collect::<Vec<_>>();
+    printed.sort();
+    printed.push(format!(
+        "# Affected fragments: {}",
+        affected_fragments
+            .iter()
+            .map(|fragment_name| fragment_name.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    ));
+    Ok(printed.join("\n\n"))
+}
@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<76dd3416c34d685cd9d0536891525809>>
+ */
+
+mod apply_configured_no_inline_directives;
+
+use apply_configured_no_inline_directives::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn matching_fragments() {
+    let input = include_str!("apply_configured_no_inline_directives/fixtures/matching-fragments.graphql");
+    let expected = include_str!("apply_configured_no_inline_directives/fixtures/matching-fragments.expected");
+    test_fixture(transform_fixture, "matching-fragments.graphql", "apply_configured_no_inline_directives/fixtures/matching-fragments.expected", input, expected);
+}
+
+#[test]
+fn no_matching_fragments() {
+    let input = include_str!("apply_configured_no_inline_directives/fixtures/no-matching-fragments.graphql");
+    let expected = include_str!("apply_configured_no_inline_directives/fixtures/no-matching-fragments.expected");
+    test_fixture(transform_fixture, "no-matching-fragments.graphql", "apply_configured_no_inline_directives/fixtures/no-matching-fragments.expected", input, expected);
+}
@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<81ac44c67d6b89c742df955302bdd4ff>>
+ */
+
+mod disallow_circular_unmasked_fragments;
+
+use disallow_circular_unmasked_fragments::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn circular_masked_fragments_in_unmasked_chain() {
+    let input = include_str!("disallow_circular_unmasked_fragments/fixtures/circular-masked-fragments-in-unmasked-chain.graphql");
+    let expected = include_str!("disallow_circular_unmasked_fragments/fixtures/circular-masked-fragments-in-unmasked-chain.expected");
+    test_fixture(transform_fixture, "circular-masked-fragments-in-unmasked-chain.graphql", "disallow_circular_unmasked_fragments/fixtures/circular-masked-fragments-in-unmasked-chain.expected", input, expected);
+}
+
+#[test]
+fn circular_unmasked_fragments_invalid() {
+    let input = include_str!("disallow_circular_unmasked_fragments/fixtures/circular-unmasked-fragments.invalid.graphql");
+    let expected = include_str!("disallow_circular_unmasked_fragments/fixtures/circular-unmasked-fragments.invalid.expected");
+    test_fixture(transform_fixture, "circular-unmasked-fragments.invalid.graphql", "disallow_circular_unmasked_fragments/fixtures/circular-unmasked-fragments.invalid.expected", input, expected);
+}
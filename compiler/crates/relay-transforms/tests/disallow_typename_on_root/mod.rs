@@ -7,21 +7,25 @@
 
 use common::SourceLocationKey;
 use fixture_tests::Fixture;
+use fixture_tests::FixtureOutput;
 use graphql_ir::build;
 use graphql_ir::Program;
 use graphql_syntax::parse_executable;
-use graphql_test_helpers::diagnostics_to_sorted_string;
+use graphql_test_helpers::diagnostics_to_fixture_diagnostics;
 use relay_test_schema::get_test_schema;
 use relay_transforms::disallow_typename_on_root;
 
-pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<FixtureOutput, String> {
     let source_location = SourceLocationKey::standalone(fixture.file_name);
     let schema = get_test_schema();
     let ast = parse_executable(fixture.content, source_location).unwrap();
     let ir = build(&schema, &ast.definitions).unwrap();
     let program = Program::from_definitions(schema, ir);
-    disallow_typename_on_root(&program)
-        .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
-
-    Ok("OK".to_owned())
+    Ok(match disallow_typename_on_root(&program) {
+        Ok(()) => FixtureOutput::new("OK"),
+        Err(diagnostics) => FixtureOutput::from_diagnostics(diagnostics_to_fixture_diagnostics(
+            fixture.content,
+            &diagnostics,
+        )),
+    })
 }
@@ -0,0 +1,23 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use fixture_tests::Fixture;
+use graphql_test_helpers::apply_transform_for_test;
+use intern::string_key::Intern;
+use relay_transforms::feature_flag_directive;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let feature_flag_values = [
+        ("enabled_flag".intern(), true),
+        ("disabled_flag".intern(), false),
+    ]
+    .into_iter()
+    .collect();
+    apply_transform_for_test(fixture, |program| {
+        Ok(feature_flag_directive(program, &feature_flag_values))
+    })
+}
@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<4fa67be78a6fee8572c52fdc86334243>>
+ */
+
+mod feature_flag_directive;
+
+use feature_flag_directive::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn feature_flag_directive() {
+    let input = include_str!("feature_flag_directive/fixtures/feature-flag-directive.graphql");
+    let expected = include_str!("feature_flag_directive/fixtures/feature-flag-directive.expected");
+    test_fixture(transform_fixture, "feature-flag-directive.graphql", "feature_flag_directive/fixtures/feature-flag-directive.expected", input, expected);
+}
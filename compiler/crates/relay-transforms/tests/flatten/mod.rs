@@ -18,6 +18,7 @@ use graphql_text_printer::print_operation;
 use graphql_text_printer::PrinterOptions;
 use relay_test_schema::get_test_schema_with_extensions;
 use relay_transforms::flatten;
+use relay_transforms::flatten_readable;
 
 pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
     let source_location = SourceLocationKey::standalone(fixture.file_name);
@@ -30,11 +31,15 @@ directive @serverInlineDirective on INLINE_FRAGMENT"#,
     let ir = build(&schema, &ast.definitions)
         .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
     let mut context = Program::from_definitions(Arc::clone(&schema), ir);
-    flatten(
-        &mut context,
-        !fixture.content.contains("%for_printing%"),
-        false,
-    )
+    if fixture.content.contains("%readable%") {
+        flatten_readable(&mut context)
+    } else {
+        flatten(
+            &mut context,
+            !fixture.content.contains("%for_printing%"),
+            false,
+        )
+    }
     .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
 
     let printer_options = PrinterOptions {
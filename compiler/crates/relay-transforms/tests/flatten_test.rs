@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<fe81a00272bc1a72fb7ed893652fd0b6>>
+ * @generated SignedSource<<eb4f4711ca176afb8a384b8e94cb53aa>>
  */
 
 mod flatten;
@@ -96,6 +96,13 @@ fn match_field_overlap() {
     test_fixture(transform_fixture, "match-field-overlap.graphql", "flatten/fixtures/match-field-overlap.expected", input, expected);
 }
 
+#[test]
+fn readable_keeps_concrete_inline_fragments() {
+    let input = include_str!("flatten/fixtures/readable-keeps-concrete-inline-fragments.graphql");
+    let expected = include_str!("flatten/fixtures/readable-keeps-concrete-inline-fragments.expected");
+    test_fixture(transform_fixture, "readable-keeps-concrete-inline-fragments.graphql", "flatten/fixtures/readable-keeps-concrete-inline-fragments.expected", input, expected);
+}
+
 #[test]
 fn scalar_handle_field() {
     let input = include_str!("flatten/fixtures/scalar-handle-field.graphql");
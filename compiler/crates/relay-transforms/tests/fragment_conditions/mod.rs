@@ -0,0 +1,21 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::FeatureFlag;
+use fixture_tests::Fixture;
+use graphql_test_helpers::apply_transform_for_test;
+use indexmap::IndexMap;
+use intern::string_key::Intern;
+use relay_transforms::fragment_conditions;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let mut condition_defaults = IndexMap::default();
+    condition_defaults.insert("showComments".intern(), false);
+    apply_transform_for_test(fixture, |program| {
+        fragment_conditions(program, &FeatureFlag::Enabled, &condition_defaults)
+    })
+}
@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<ecfdc6c3110dc99a27c84561b46db4d4>>
+ */
+
+mod fragment_conditions;
+
+use fragment_conditions::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn fragment_condition_default() {
+    let input = include_str!("fragment_conditions/fixtures/fragment-condition-default.graphql");
+    let expected = include_str!("fragment_conditions/fixtures/fragment-condition-default.expected");
+    test_fixture(transform_fixture, "fragment-condition-default.graphql", "fragment_conditions/fixtures/fragment-condition-default.expected", input, expected);
+}
+
+#[test]
+fn fragment_condition_on_operation_variable() {
+    let input = include_str!("fragment_conditions/fixtures/fragment-condition-on-operation-variable.graphql");
+    let expected = include_str!("fragment_conditions/fixtures/fragment-condition-on-operation-variable.expected");
+    test_fixture(transform_fixture, "fragment-condition-on-operation-variable.graphql", "fragment_conditions/fixtures/fragment-condition-on-operation-variable.expected", input, expected);
+}
+
+#[test]
+fn fragment_condition_undefined_variable_invalid() {
+    let input = include_str!("fragment_conditions/fixtures/fragment-condition-undefined-variable.invalid.graphql");
+    let expected = include_str!("fragment_conditions/fixtures/fragment-condition-undefined-variable.invalid.expected");
+    test_fixture(transform_fixture, "fragment-condition-undefined-variable.invalid.graphql", "fragment_conditions/fixtures/fragment-condition-undefined-variable.invalid.expected", input, expected);
+}
@@ -14,8 +14,9 @@ use relay_transforms::transform_match;
 pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
     apply_transform_for_test(fixture, |program| {
         let flags = FeatureFlags::default();
-        let program = transform_match(program, &flags, Default::default())?;
-        let program = generate_data_driven_dependency_metadata(&program);
+        let artifact_filename = Default::default();
+        let program = transform_match(program, &flags, Default::default(), &artifact_filename)?;
+        let program = generate_data_driven_dependency_metadata(&program, &artifact_filename);
         Ok(program)
     })
 }
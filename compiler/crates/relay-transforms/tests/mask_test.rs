@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<06cfcd9a6d5345cf8fba25d3382362fa>>
+ * @generated SignedSource<<f7e5723600b26f3e8888b305df65765c>>
  */
 
 mod mask;
@@ -19,6 +19,13 @@ fn mask_mixed_null() {
     test_fixture(transform_fixture, "mask-mixed-null.graphql", "mask/fixtures/mask-mixed-null.expected", input, expected);
 }
 
+#[test]
+fn mask_shared_unmasked_fragments() {
+    let input = include_str!("mask/fixtures/mask-shared-unmasked-fragments.graphql");
+    let expected = include_str!("mask/fixtures/mask-shared-unmasked-fragments.expected");
+    test_fixture(transform_fixture, "mask-shared-unmasked-fragments.graphql", "mask/fixtures/mask-shared-unmasked-fragments.expected", input, expected);
+}
+
 #[test]
 fn relay_mask_transform() {
     let input = include_str!("mask/fixtures/relay-mask-transform.graphql");
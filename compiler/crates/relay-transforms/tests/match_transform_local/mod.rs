@@ -18,6 +18,6 @@ pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
         dynamic_module_provider: Some(DynamicModuleProvider::JSResource),
     };
     apply_transform_for_test(fixture, |program| {
-        transform_match(program, &flags, module_import_config)
+        transform_match(program, &flags, module_import_config, &Default::default())
     })
 }
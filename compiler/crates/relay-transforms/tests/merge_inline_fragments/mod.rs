@@ -0,0 +1,41 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::FeatureFlag;
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::build;
+use graphql_ir::Program;
+use graphql_syntax::parse_executable;
+use graphql_text_printer::print_operation;
+use graphql_text_printer::PrinterOptions;
+use relay_test_schema::get_test_schema;
+use relay_transforms::flatten;
+use relay_transforms::inline_fragments;
+use relay_transforms::merge_inline_fragments;
+use relay_transforms::skip_redundant_nodes;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let schema = get_test_schema();
+    let ast = parse_executable(fixture.content, source_location).unwrap();
+    let ir = build(&schema, &ast.definitions).unwrap();
+    let program = Program::from_definitions(Arc::clone(&schema), ir);
+    let mut program = inline_fragments(&program);
+    flatten(&mut program, true, false).unwrap();
+    let program = skip_redundant_nodes(&program);
+    let next_program = merge_inline_fragments(&program, &FeatureFlag::Enabled);
+
+    let mut printed = next_program
+        .operations()
+        .map(|def| print_operation(&schema, def, PrinterOptions::default()))
+        .collect::<Vec<_>>();
+    printed.sort();
+    Ok(printed.join("\n\n"))
+}
@@ -0,0 +1,48 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<97175888e9cbf2e6709620d3025675e1>>
+ */
+
+mod merge_inline_fragments;
+
+use merge_inline_fragments::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn dont_hoist_fields_not_on_abstract_type() {
+    let input = include_str!("merge_inline_fragments/fixtures/dont-hoist-fields-not-on-abstract-type.graphql");
+    let expected = include_str!("merge_inline_fragments/fixtures/dont-hoist-fields-not-on-abstract-type.expected");
+    test_fixture(transform_fixture, "dont-hoist-fields-not-on-abstract-type.graphql", "merge_inline_fragments/fixtures/dont-hoist-fields-not-on-abstract-type.expected", input, expected);
+}
+
+#[test]
+fn dont_hoist_from_fragments_with_directives() {
+    let input = include_str!("merge_inline_fragments/fixtures/dont-hoist-from-fragments-with-directives.graphql");
+    let expected = include_str!("merge_inline_fragments/fixtures/dont-hoist-from-fragments-with-directives.expected");
+    test_fixture(transform_fixture, "dont-hoist-from-fragments-with-directives.graphql", "merge_inline_fragments/fixtures/dont-hoist-from-fragments-with-directives.expected", input, expected);
+}
+
+#[test]
+fn dont_hoist_partially_covered_fields() {
+    let input = include_str!("merge_inline_fragments/fixtures/dont-hoist-partially-covered-fields.graphql");
+    let expected = include_str!("merge_inline_fragments/fixtures/dont-hoist-partially-covered-fields.expected");
+    test_fixture(transform_fixture, "dont-hoist-partially-covered-fields.graphql", "merge_inline_fragments/fixtures/dont-hoist-partially-covered-fields.expected", input, expected);
+}
+
+#[test]
+fn hoist_common_fields() {
+    let input = include_str!("merge_inline_fragments/fixtures/hoist-common-fields.graphql");
+    let expected = include_str!("merge_inline_fragments/fixtures/hoist-common-fields.expected");
+    test_fixture(transform_fixture, "hoist-common-fields.graphql", "merge_inline_fragments/fixtures/hoist-common-fields.expected", input, expected);
+}
+
+#[test]
+fn merge_identical_fragments() {
+    let input = include_str!("merge_inline_fragments/fixtures/merge-identical-fragments.graphql");
+    let expected = include_str!("merge_inline_fragments/fixtures/merge-identical-fragments.expected");
+    test_fixture(transform_fixture, "merge-identical-fragments.graphql", "merge_inline_fragments/fixtures/merge-identical-fragments.expected", input, expected);
+}
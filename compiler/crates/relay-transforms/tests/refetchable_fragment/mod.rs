@@ -7,14 +7,27 @@
 
 use fixture_tests::Fixture;
 use graphql_test_helpers::apply_transform_for_test;
+use intern::string_key::Intern;
+use relay_config::FetchableTypeConfig;
+use relay_config::SchemaConfig;
 use relay_transforms::transform_connections;
 use relay_transforms::transform_refetchable_fragment;
 use relay_transforms::ConnectionInterface;
 
 pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let mut schema_config = SchemaConfig::default();
+    if fixture.content.contains("%fetchable_types%") {
+        schema_config.fetchable_types.insert(
+            "NonNodeStory".intern(),
+            FetchableTypeConfig {
+                identifying_field: "id".intern(),
+                fetch_field: Some("nonNodeStory".intern()),
+            },
+        );
+    }
     apply_transform_for_test(fixture, |program| {
         let program = transform_connections(program, &ConnectionInterface::default());
         let base_fragments = Default::default();
-        transform_refetchable_fragment(&program, &Default::default(), &base_fragments, false)
+        transform_refetchable_fragment(&program, &schema_config, &base_fragments, false)
     })
 }
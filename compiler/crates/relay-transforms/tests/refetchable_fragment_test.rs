@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<8cfbe364f914c89ce9b2405a88713948>>
+ * @generated SignedSource<<06e1ab8e39de19de080fb5c2e94ade0c>>
  */
 
 mod refetchable_fragment;
@@ -12,6 +12,13 @@ mod refetchable_fragment;
 use refetchable_fragment::transform_fixture;
 use fixture_tests::test_fixture;
 
+#[test]
+fn fragment_on_fetchable_type_from_config() {
+    let input = include_str!("refetchable_fragment/fixtures/fragment-on-fetchable-type-from-config.graphql");
+    let expected = include_str!("refetchable_fragment/fixtures/fragment-on-fetchable-type-from-config.expected");
+    test_fixture(transform_fixture, "fragment-on-fetchable-type-from-config.graphql", "refetchable_fragment/fixtures/fragment-on-fetchable-type-from-config.expected", input, expected);
+}
+
 #[test]
 fn fragment_on_interface_which_implmentations_implement_node() {
     let input = include_str!("refetchable_fragment/fixtures/fragment-on-interface-which-implmentations-implement-node.graphql");
@@ -131,6 +138,13 @@ fn fragment_with_relay_plural_invalid() {
     test_fixture(transform_fixture, "fragment-with-relay-plural.invalid.graphql", "refetchable_fragment/fixtures/fragment-with-relay-plural.invalid.expected", input, expected);
 }
 
+#[test]
+fn refetchable_fragment_spreading_fragment_from_another_file() {
+    let input = include_str!("refetchable_fragment/fixtures/refetchable-fragment-spreading-fragment-from-another-file.graphql");
+    let expected = include_str!("refetchable_fragment/fixtures/refetchable-fragment-spreading-fragment-from-another-file.expected");
+    test_fixture(transform_fixture, "refetchable-fragment-spreading-fragment-from-another-file.graphql", "refetchable_fragment/fixtures/refetchable-fragment-spreading-fragment-from-another-file.expected", input, expected);
+}
+
 #[test]
 fn refetchable_fragment_with_connection() {
     let input = include_str!("refetchable_fragment/fixtures/refetchable-fragment-with-connection.graphql");
@@ -172,3 +186,10 @@ fn refetchable_fragment_with_connection_with_stream() {
     let expected = include_str!("refetchable_fragment/fixtures/refetchable-fragment-with-connection-with-stream.expected");
     test_fixture(transform_fixture, "refetchable-fragment-with-connection-with-stream.graphql", "refetchable_fragment/fixtures/refetchable-fragment-with-connection-with-stream.expected", input, expected);
 }
+
+#[test]
+fn refetchable_fragments_with_same_query_name_in_different_files_invalid() {
+    let input = include_str!("refetchable_fragment/fixtures/refetchable-fragments-with-same-query-name-in-different-files.invalid.graphql");
+    let expected = include_str!("refetchable_fragment/fixtures/refetchable-fragments-with-same-query-name-in-different-files.invalid.expected");
+    test_fixture(transform_fixture, "refetchable-fragments-with-same-query-name-in-different-files.invalid.graphql", "refetchable_fragment/fixtures/refetchable-fragments-with-same-query-name-in-different-files.invalid.expected", input, expected);
+}
@@ -12,5 +12,7 @@ use relay_transforms::relay_client_component;
 
 pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
     let flags = FeatureFlags::default();
-    apply_transform_for_test(fixture, |program| relay_client_component(program, &flags))
+    apply_transform_for_test(fixture, |program| {
+        relay_client_component(program, &flags, &Default::default())
+    })
 }
@@ -10,5 +10,7 @@ use graphql_test_helpers::apply_transform_for_test;
 use relay_transforms::transform_subscriptions;
 
 pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
-    apply_transform_for_test(fixture, |program| transform_subscriptions(program))
+    apply_transform_for_test(fixture, |program| {
+        transform_subscriptions(program, &Default::default())
+    })
 }
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<cbb760c92a070de1f59e662694d57dbb>>
+ * @generated SignedSource<<e00fe10591b4ba2305b7596f3728f285>>
  */
 
 mod updatable_directive;
@@ -124,6 +124,20 @@ fn non_assignable_fragment_spreads_invalid() {
     test_fixture(transform_fixture, "non-assignable-fragment-spreads.invalid.graphql", "updatable_directive/fixtures/non-assignable-fragment-spreads.invalid.expected", input, expected);
 }
 
+#[test]
+fn plural_field_aliased_client_id_invalid() {
+    let input = include_str!("updatable_directive/fixtures/plural-field-aliased-client-id.invalid.graphql");
+    let expected = include_str!("updatable_directive/fixtures/plural-field-aliased-client-id.invalid.expected");
+    test_fixture(transform_fixture, "plural-field-aliased-client-id.invalid.graphql", "updatable_directive/fixtures/plural-field-aliased-client-id.invalid.expected", input, expected);
+}
+
+#[test]
+fn plural_field_client_id() {
+    let input = include_str!("updatable_directive/fixtures/plural-field-client-id.graphql");
+    let expected = include_str!("updatable_directive/fixtures/plural-field-client-id.expected");
+    test_fixture(transform_fixture, "plural-field-client-id.graphql", "updatable_directive/fixtures/plural-field-client-id.expected", input, expected);
+}
+
 #[test]
 fn skip_invalid() {
     let input = include_str!("updatable_directive/fixtures/skip.invalid.graphql");
@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<6b99a835d927864674dc5cfeb79510f6>>
+ */
+
+mod validate_closed_abstract_types;
+
+use validate_closed_abstract_types::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn closed_interface() {
+    let input = include_str!("validate_closed_abstract_types/fixtures/closed-interface.graphql");
+    let expected = include_str!("validate_closed_abstract_types/fixtures/closed-interface.expected");
+    test_fixture(transform_fixture, "closed-interface.graphql", "validate_closed_abstract_types/fixtures/closed-interface.expected", input, expected);
+}
+
+#[test]
+fn closed_interface_with_new_implementation_invalid() {
+    let input = include_str!("validate_closed_abstract_types/fixtures/closed-interface-with-new-implementation.invalid.graphql");
+    let expected = include_str!("validate_closed_abstract_types/fixtures/closed-interface-with-new-implementation.invalid.expected");
+    test_fixture(transform_fixture, "closed-interface-with-new-implementation.invalid.graphql", "validate_closed_abstract_types/fixtures/closed-interface-with-new-implementation.invalid.expected", input, expected);
+}
+
+#[test]
+fn closed_type_not_abstract_invalid() {
+    let input = include_str!("validate_closed_abstract_types/fixtures/closed-type-not-abstract.invalid.graphql");
+    let expected = include_str!("validate_closed_abstract_types/fixtures/closed-type-not-abstract.invalid.expected");
+    test_fixture(transform_fixture, "closed-type-not-abstract.invalid.graphql", "validate_closed_abstract_types/fixtures/closed-type-not-abstract.invalid.expected", input, expected);
+}
@@ -0,0 +1,57 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common::ScalarName;
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::build;
+use graphql_ir::Program;
+use graphql_syntax::parse_executable;
+use graphql_test_helpers::diagnostics_to_sorted_strings_with_sources_map;
+use intern::string_key::Intern;
+use relay_config::CustomScalarType;
+use relay_config::TypegenConfig;
+use relay_test_schema::get_test_schema_with_located_extensions;
+use relay_transforms::validate_custom_scalar_types;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts: Vec<_> = fixture.content.split("%extensions%").collect();
+
+    if let [base, extensions] = parts.as_slice() {
+        let source_location = SourceLocationKey::embedded(fixture.file_name, 0);
+        let extension_location = SourceLocationKey::embedded(fixture.file_name, 1);
+        let mut sources = HashMap::new();
+        sources.insert(source_location, base.to_string());
+        sources.insert(extension_location, extensions.to_string());
+
+        let ast = parse_executable(base, source_location).unwrap();
+        let schema = get_test_schema_with_located_extensions(extensions, extension_location);
+        let ir = build(&schema, &ast.definitions).unwrap();
+        let program = Program::from_definitions(Arc::clone(&schema), ir);
+
+        let typegen_config = TypegenConfig {
+            custom_scalar_types: [(
+                ScalarName("MappedScalar".intern()),
+                CustomScalarType::Name("string".intern()),
+            )]
+            .into_iter()
+            .collect(),
+            require_custom_scalar_types: true,
+            ..Default::default()
+        };
+        validate_custom_scalar_types(&program, &typegen_config).map_err(|diagnostics| {
+            diagnostics_to_sorted_strings_with_sources_map(&sources, &diagnostics)
+        })?;
+
+        Ok("OK".to_owned())
+    } else {
+        panic!("Expected exactly one %extensions% section marker.")
+    }
+}
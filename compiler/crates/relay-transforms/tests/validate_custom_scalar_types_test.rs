@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<761f674cab7b2c21b439ae33162f0d56>>
+ */
+
+mod validate_custom_scalar_types;
+
+use validate_custom_scalar_types::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn custom_scalars() {
+    let input = include_str!("validate_custom_scalar_types/fixtures/custom-scalars.graphql");
+    let expected = include_str!("validate_custom_scalar_types/fixtures/custom-scalars.expected");
+    test_fixture(transform_fixture, "custom-scalars.graphql", "validate_custom_scalar_types/fixtures/custom-scalars.expected", input, expected);
+}
+
+#[test]
+fn unmapped_custom_scalars_invalid() {
+    let input = include_str!("validate_custom_scalar_types/fixtures/unmapped-custom-scalars.invalid.graphql");
+    let expected = include_str!("validate_custom_scalar_types/fixtures/unmapped-custom-scalars.invalid.expected");
+    test_fixture(transform_fixture, "unmapped-custom-scalars.invalid.graphql", "validate_custom_scalar_types/fixtures/unmapped-custom-scalars.invalid.expected", input, expected);
+}
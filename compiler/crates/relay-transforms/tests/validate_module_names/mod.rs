@@ -14,12 +14,16 @@ use graphql_ir::Program;
 use graphql_syntax::parse_executable;
 use graphql_test_helpers::diagnostics_to_sorted_string;
 use relay_test_schema::TEST_SCHEMA;
+use relay_transforms::rename_experiment_variant;
 use relay_transforms::validate_module_names;
 
 pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
     let source_location = SourceLocationKey::standalone(fixture.file_name);
 
-    let ast = parse_executable(fixture.content, source_location).unwrap();
+    let mut ast = parse_executable(fixture.content, source_location).unwrap();
+    for definition in &mut ast.definitions {
+        rename_experiment_variant(definition);
+    }
     let ir = build(&TEST_SCHEMA, &ast.definitions)
         .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
 
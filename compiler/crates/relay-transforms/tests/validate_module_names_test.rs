@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<c52f1e66f39dd95f89add376b2dab414>>
+ * @generated SignedSource<<b91a1150687793dfaab13956d89c31c7>>
  */
 
 mod validate_module_names;
@@ -89,6 +89,13 @@ fn mutationwithvalidname() {
     test_fixture(transform_fixture, "MutationWithValidName.graphql", "validate_module_names/fixtures/MutationWithValidName.expected", input, expected);
 }
 
+#[test]
+fn querywithexperimentvariant() {
+    let input = include_str!("validate_module_names/fixtures/QueryWithExperimentVariant.graphql");
+    let expected = include_str!("validate_module_names/fixtures/QueryWithExperimentVariant.expected");
+    test_fixture(transform_fixture, "QueryWithExperimentVariant.graphql", "validate_module_names/fixtures/QueryWithExperimentVariant.expected", input, expected);
+}
+
 #[test]
 fn querywithinvalidname_invalid() {
     let input = include_str!("validate_module_names/fixtures/QueryWithInvalidName.invalid.graphql");
@@ -0,0 +1,45 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::build;
+use graphql_ir::Program;
+use graphql_syntax::parse_executable;
+use graphql_test_helpers::diagnostics_to_sorted_strings_with_sources_map;
+use relay_config::ProjectConfig;
+use relay_test_schema::TEST_SCHEMA;
+use relay_transforms::validate_operation_directories;
+
+/// Each `# file: <path>` section of the fixture is a source at that path, in
+/// a project whose operations are restricted to `src/routes`.
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let mut sources = HashMap::new();
+    let mut definitions = Vec::new();
+    for section in fixture.content.split("# file: ").skip(1) {
+        let (path, text) = section.split_once('\n').unwrap();
+        let source_location = SourceLocationKey::embedded(path.trim(), 0);
+        definitions.extend(parse_executable(text, source_location).unwrap().definitions);
+        sources.insert(source_location, text.to_string());
+    }
+    let ir = build(&TEST_SCHEMA, &definitions).unwrap();
+    let program = Program::from_definitions(Arc::clone(&TEST_SCHEMA), ir);
+
+    let project_config = ProjectConfig {
+        operation_directories: Some(vec![PathBuf::from("src/routes")]),
+        ..Default::default()
+    };
+    validate_operation_directories(&program, &project_config).map_err(|diagnostics| {
+        diagnostics_to_sorted_strings_with_sources_map(&sources, &diagnostics)
+    })?;
+
+    Ok("OK".to_owned())
+}
@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<25e0e185bdb6dd68eb6be954df8a8367>>
+ */
+
+mod validate_operation_directories;
+
+use validate_operation_directories::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn operation_outside_operation_directories_invalid() {
+    let input = include_str!("validate_operation_directories/fixtures/operation-outside-operation-directories.invalid.graphql");
+    let expected = include_str!("validate_operation_directories/fixtures/operation-outside-operation-directories.invalid.expected");
+    test_fixture(transform_fixture, "operation-outside-operation-directories.invalid.graphql", "validate_operation_directories/fixtures/operation-outside-operation-directories.invalid.expected", input, expected);
+}
+
+#[test]
+fn operations_in_operation_directories() {
+    let input = include_str!("validate_operation_directories/fixtures/operations-in-operation-directories.graphql");
+    let expected = include_str!("validate_operation_directories/fixtures/operations-in-operation-directories.expected");
+    test_fixture(transform_fixture, "operations-in-operation-directories.graphql", "validate_operation_directories/fixtures/operations-in-operation-directories.expected", input, expected);
+}
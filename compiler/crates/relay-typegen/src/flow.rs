@@ -10,7 +10,10 @@ use std::fmt::Write;
 
 use intern::string_key::StringKey;
 use itertools::Itertools;
+use relay_config::TypeNamesConfig;
+use relay_config::TypegenConfig;
 
+use crate::writer::write_doc_comment;
 use crate::writer::FunctionTypeAssertion;
 use crate::writer::KeyValuePairProp;
 use crate::writer::Prop;
@@ -20,6 +23,7 @@ use crate::writer::AST;
 pub struct FlowPrinter {
     result: String,
     indentation: usize,
+    type_names: TypeNamesConfig,
 }
 
 impl Write for FlowPrinter {
@@ -42,6 +46,7 @@ impl Writer for FlowPrinter {
             AST::OtherTypename => self.write_other_string(),
             AST::Number => write!(&mut self.result, "number"),
             AST::Boolean => write!(&mut self.result, "boolean"),
+            AST::BooleanLiteral(value) => write!(&mut self.result, "{}", value),
             AST::Callable(return_type) => self.write_callable(&*return_type),
             AST::Identifier(identifier) => write!(&mut self.result, "{}", identifier),
             AST::RawType(raw) => write!(&mut self.result, "{}", raw),
@@ -56,7 +61,8 @@ impl Writer for FlowPrinter {
             }
             AST::FragmentReference(fragments) => self.write_fragment_references(&***fragments),
             AST::FragmentReferenceType(fragment) => {
-                write!(&mut self.result, "{}$fragmentType", fragment)
+                let fragment_type = self.type_names.fragment_type(fragment);
+                write!(&mut self.result, "{}", fragment_type)
             }
             AST::ReturnTypeOfFunctionWithName(function_name) => {
                 self.write_return_type_of_function_with_name(*function_name)
@@ -151,13 +157,18 @@ impl Writer for FlowPrinter {
     fn write_any_type_definition(&mut self, name: &str) -> FmtResult {
         writeln!(&mut self.result, "type {} = any;", name)
     }
+
+    fn write_doc_comment(&mut self, doc_comment: StringKey) -> FmtResult {
+        write_doc_comment(&mut self.result, self.indentation, doc_comment)
+    }
 }
 
 impl FlowPrinter {
-    pub fn new() -> Self {
+    pub fn new(config: &TypegenConfig) -> Self {
         Self {
             result: String::new(),
             indentation: 0,
+            type_names: config.type_names.clone(),
         }
     }
 
@@ -194,7 +205,8 @@ impl FlowPrinter {
             } else {
                 write!(&mut self.result, " & ")?;
             }
-            write!(&mut self.result, "{}$fragmentType", fragment)?;
+            let fragment_type = self.type_names.fragment_type(fragment);
+            write!(&mut self.result, "{}", fragment_type)?;
         }
         Ok(())
     }
@@ -261,6 +273,9 @@ impl FlowPrinter {
                         )?;
                         self.write_indentation()?;
                     }
+                    if let Some(doc_comment) = key_value_pair.doc_comment {
+                        self.write_doc_comment(doc_comment)?;
+                    }
                     if key_value_pair.read_only {
                         write!(&mut self.result, "+")?;
                     }
@@ -273,6 +288,9 @@ impl FlowPrinter {
                     writeln!(&mut self.result, ",")?;
                 }
                 Prop::GetterSetterPair(getter_setter_pair) => {
+                    if let Some(doc_comment) = getter_setter_pair.doc_comment {
+                        self.write_doc_comment(doc_comment)?;
+                    }
                     // Write the getter
                     write!(&mut self.result, "get ")?;
                     self.write(&AST::Identifier(getter_setter_pair.key))?;
@@ -394,7 +412,7 @@ mod tests {
     use crate::writer::SortedASTList;
 
     fn print_type(ast: &AST) -> String {
-        let mut printer = Box::new(FlowPrinter::new());
+        let mut printer = Box::new(FlowPrinter::new(&Default::default()));
         printer.write(ast).unwrap();
         printer.into_string()
     }
@@ -454,6 +472,7 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
             ],))),
             r"{|
@@ -468,12 +487,14 @@ mod tests {
                     optional: true,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: "bar".intern(),
                     optional: false,
                     read_only: true,
                     value: AST::Number,
+                    doc_comment: None,
                 }),
             ],))),
             r"{|
@@ -498,20 +519,24 @@ mod tests {
                             optional: true,
                             read_only: false,
                             value: AST::String,
+                            doc_comment: None,
                         }),
                         Prop::KeyValuePair(KeyValuePairProp {
                             key: "nested_foo2".intern(),
                             optional: false,
                             read_only: true,
                             value: AST::Number,
+                            doc_comment: None,
                         }),
                     ],)),
+                    doc_comment: None,
                 }),
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: "bar".intern(),
                     optional: false,
                     read_only: true,
                     value: AST::Number,
+                    doc_comment: None,
                 }),
             ],))),
             r"{|
@@ -542,6 +567,7 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
             ]))),
             r"{
@@ -558,12 +584,14 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: "bar".intern(),
                     optional: true,
                     read_only: true,
                     value: AST::Number,
+                    doc_comment: None,
                 })
             ]))),
             r"{
@@ -584,6 +612,7 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::OtherTypename,
+                    doc_comment: None,
                 })
             ],))),
             r#"{|
@@ -597,7 +626,7 @@ mod tests {
 
     #[test]
     fn import_type() {
-        let mut printer = Box::new(FlowPrinter::new());
+        let mut printer = Box::new(FlowPrinter::new(&Default::default()));
         printer.write_import_type(&["A", "B"], "module").unwrap();
         assert_eq!(
             printer.into_string(),
@@ -607,7 +636,7 @@ mod tests {
 
     #[test]
     fn import_module() {
-        let mut printer = Box::new(FlowPrinter::new());
+        let mut printer = Box::new(FlowPrinter::new(&Default::default()));
         printer.write_import_module_default("A", "module").unwrap();
         assert_eq!(printer.into_string(), "import A from \"module\";\n");
     }
@@ -0,0 +1,171 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Flow Enums for the enums of the schema, enabled by
+//! `flowTypegen.useFlowEnums`. Each enum is declared once, in a module of its
+//! own, because Flow Enums are nominal: the artifacts import the enum types
+//! from these modules instead of declaring string literal unions.
+
+use std::fmt::Result as FmtResult;
+use std::fmt::Write;
+
+use ::intern::Lookup;
+use fnv::FnvHashSet;
+use graphql_ir::Selection;
+use graphql_ir::VariableDefinition;
+use indexmap::IndexSet;
+use relay_config::ArtifactFilenameConfig;
+use relay_config::ProjectConfig;
+use relay_config::TypegenConfig;
+use relay_config::TypegenLanguage;
+use schema::EnumID;
+use schema::InputObjectID;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+
+/// Whether the enums are emitted as Flow Enums. Enums imported from the
+/// modules configured by `enumModuleSuffix` take precedence.
+pub fn uses_flow_enums(typegen_config: &TypegenConfig) -> bool {
+    typegen_config.language == TypegenLanguage::Flow
+        && typegen_config.flow_typegen.use_flow_enums
+        && typegen_config.enum_module_suffix.is_none()
+}
+
+/// Name of the module of the Flow Enum of an enum, e.g. `Color.graphql` for
+/// `Color`, imported by the artifacts that use the enum.
+pub fn flow_enum_module_name(
+    enum_name: impl Lookup,
+    artifact_filename: &ArtifactFilenameConfig,
+) -> String {
+    artifact_filename.module_name(enum_name.lookup())
+}
+
+/// The enums of the types of `variable_definitions`, including the fields of
+/// input objects, and of the fields of `selections`, in the order in which
+/// they are first referenced.
+pub fn referenced_enums(
+    schema: &SDLSchema,
+    variable_definitions: &[VariableDefinition],
+    selections: &[Selection],
+) -> IndexSet<EnumID> {
+    let mut collector = EnumsCollector {
+        schema,
+        enums: IndexSet::new(),
+        visited_input_objects: FnvHashSet::default(),
+    };
+    for variable_definition in variable_definitions {
+        collector.visit_input_type(variable_definition.type_.inner());
+    }
+    collector.visit_selections(selections);
+    collector.enums
+}
+
+struct EnumsCollector<'a> {
+    schema: &'a SDLSchema,
+    enums: IndexSet<EnumID>,
+    visited_input_objects: FnvHashSet<InputObjectID>,
+}
+
+impl EnumsCollector<'_> {
+    fn visit_input_type(&mut self, type_: Type) {
+        match type_ {
+            Type::Enum(enum_id) => {
+                self.enums.insert(enum_id);
+            }
+            Type::InputObject(input_object_id) => {
+                if self.visited_input_objects.insert(input_object_id) {
+                    for field in self.schema.input_object(input_object_id).fields.iter() {
+                        self.visit_input_type(field.type_.inner());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_selections(&mut self, selections: &[Selection]) {
+        for selection in selections {
+            match selection {
+                Selection::ScalarField(field) => {
+                    if let Type::Enum(enum_id) =
+                        self.schema.field(field.definition.item).type_.inner()
+                    {
+                        self.enums.insert(enum_id);
+                    }
+                }
+                Selection::LinkedField(field) => self.visit_selections(&field.selections),
+                Selection::InlineFragment(fragment) => self.visit_selections(&fragment.selections),
+                Selection::Condition(condition) => self.visit_selections(&condition.selections),
+                Selection::FragmentSpread(_) => {}
+            }
+        }
+    }
+}
+
+/// Generates the declaration of the Flow Enum of an enum, with a member for
+/// each of its values. Unless `noFutureProofEnums` is set, the enum has
+/// unknown members (`...`), so that a `switch` over it must handle the values
+/// added to the schema later. The values of the data map to the members with
+/// the `cast`, `isValid` and `members` methods of the enum.
+pub fn generate_flow_enum(
+    enum_id: EnumID,
+    schema: &SDLSchema,
+    project_config: &ProjectConfig,
+) -> String {
+    let mut result = String::new();
+    write_flow_enum(&mut result, enum_id, schema, project_config).unwrap();
+    result
+}
+
+fn write_flow_enum(
+    writer: &mut String,
+    enum_id: EnumID,
+    schema: &SDLSchema,
+    project_config: &ProjectConfig,
+) -> FmtResult {
+    let typegen_config = &project_config.typegen_config;
+    let enum_type = schema.enum_(enum_id);
+    let enum_name = enum_type.name.item;
+    let export = if typegen_config.eager_es_modules {
+        "export "
+    } else {
+        ""
+    };
+
+    writeln!(writer, "{}enum {} of string {{", export, enum_name)?;
+    for enum_value in &enum_type.values {
+        writeln!(
+            writer,
+            "  {} = \"{}\",",
+            member_name(enum_value.value.lookup()),
+            enum_value.value
+        )?;
+    }
+    if !typegen_config.flow_typegen.no_future_proof_enums {
+        writeln!(writer, "  ...")?;
+    }
+    writeln!(writer, "}}")?;
+
+    if !typegen_config.eager_es_modules {
+        writeln!(writer)?;
+        writeln!(writer, "module.exports = {{ {} }};", enum_name)?;
+    }
+    Ok(())
+}
+
+/// The members of Flow Enums can't start with a lowercase letter, which the
+/// values of GraphQL enums can.
+fn member_name(value: &str) -> String {
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_lowercase() => {
+            format!("{}{}", first.to_ascii_uppercase(), chars.as_str())
+        }
+        _ => value.to_string(),
+    }
+}
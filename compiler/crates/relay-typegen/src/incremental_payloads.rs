@@ -0,0 +1,297 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Types of the payloads of an operation with `@defer` or `@stream` and
+//! `@raw_response_type`, as the server delivers them incrementally: the
+//! initial payload, without the deferred fragments, and one payload per
+//! deferred fragment or streamed item, with its `label` and `path`.
+
+use std::sync::Arc;
+
+use common::NamedItem;
+use graphql_ir::Argument;
+use graphql_ir::Selection;
+use intern::string_key::Intern;
+use relay_transforms::DeferDirective;
+use relay_transforms::StreamDirective;
+use relay_transforms::DEFER_STREAM_CONSTANTS;
+use schema::Schema;
+use schema::Type;
+use schema::TypeReference;
+
+use crate::typegen_state::EncounteredEnums;
+use crate::typegen_state::EncounteredFragments;
+use crate::typegen_state::ImportedRawResponseTypes;
+use crate::typegen_state::MatchFields;
+use crate::typegen_state::RuntimeImports;
+use crate::visit::hashmap_into_values;
+use crate::visit::raw_response_selections_to_babel;
+use crate::visit::raw_response_visit_selections;
+use crate::visit::selections_to_map;
+use crate::visit::transform_scalar_type;
+use crate::write::CustomScalarsImports;
+use crate::writer::ExactObject;
+use crate::writer::KeyValuePairProp;
+use crate::writer::Prop;
+use crate::writer::SortedASTList;
+use crate::writer::StringLiteral;
+use crate::writer::AST;
+use crate::TypegenContext;
+
+pub(crate) struct IncrementalPayloads {
+    /// The raw response without the deferred fragments.
+    pub(crate) initial: AST,
+    /// The union of the payloads of the deferred fragments and the streamed
+    /// items, discriminated by their `label`.
+    pub(crate) incremental: AST,
+}
+
+/// The incremental payloads of the normalization `selections` of an
+/// operation, if it has `@defer` or `@stream` selections.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn raw_response_incremental_payloads(
+    typegen_context: &'_ TypegenContext<'_>,
+    selections: &[Selection],
+    encountered_enums: &mut EncounteredEnums,
+    match_fields: &mut MatchFields,
+    encountered_fragments: &mut EncounteredFragments,
+    imported_raw_response_types: &mut ImportedRawResponseTypes,
+    runtime_imports: &mut RuntimeImports,
+    custom_scalars: &mut CustomScalarsImports,
+) -> Option<IncrementalPayloads> {
+    let mut builder = IncrementalPayloadsBuilder {
+        typegen_context,
+        encountered_enums,
+        match_fields,
+        encountered_fragments,
+        imported_raw_response_types,
+        runtime_imports,
+        custom_scalars,
+        payloads: Vec::new(),
+    };
+    builder.visit_selections(selections);
+    if builder.payloads.is_empty() {
+        return None;
+    }
+    let initial = builder.raw_response_type(selections, None);
+    Some(IncrementalPayloads {
+        initial,
+        incremental: AST::Union(SortedASTList::new(builder.payloads)),
+    })
+}
+
+struct IncrementalPayloadsBuilder<'a, 'b> {
+    typegen_context: &'a TypegenContext<'b>,
+    encountered_enums: &'a mut EncounteredEnums,
+    match_fields: &'a mut MatchFields,
+    encountered_fragments: &'a mut EncounteredFragments,
+    imported_raw_response_types: &'a mut ImportedRawResponseTypes,
+    runtime_imports: &'a mut RuntimeImports,
+    custom_scalars: &'a mut CustomScalarsImports,
+    payloads: Vec<AST>,
+}
+
+impl IncrementalPayloadsBuilder<'_, '_> {
+    fn visit_selections(&mut self, selections: &[Selection]) {
+        for selection in selections {
+            match selection {
+                Selection::InlineFragment(inline_fragment) => {
+                    if let Some(defer) = inline_fragment
+                        .directives
+                        .named(DEFER_STREAM_CONSTANTS.defer_name)
+                    {
+                        let concrete_type = inline_fragment
+                            .type_condition
+                            .filter(|type_condition| !type_condition.is_abstract_type());
+                        let data =
+                            self.raw_response_type(&inline_fragment.selections, concrete_type);
+                        self.push_payload(DeferDirective::from(defer).label_arg, data);
+                    }
+                    self.visit_selections(&inline_fragment.selections);
+                }
+                Selection::LinkedField(linked_field) => {
+                    if let Some(stream) = linked_field
+                        .directives
+                        .named(DEFER_STREAM_CONSTANTS.stream_name)
+                    {
+                        let field_type = &self
+                            .typegen_context
+                            .schema
+                            .field(linked_field.definition.item)
+                            .type_;
+                        if let Some(item_type) = field_type.list_item_type() {
+                            let data = self.stream_item_type(item_type, &linked_field.selections);
+                            self.push_payload(StreamDirective::from(stream).label_arg, data);
+                        }
+                    }
+                    self.visit_selections(&linked_field.selections);
+                }
+                Selection::ScalarField(scalar_field) => {
+                    if let Some(stream) = scalar_field
+                        .directives
+                        .named(DEFER_STREAM_CONSTANTS.stream_name)
+                    {
+                        let field_type = &self
+                            .typegen_context
+                            .schema
+                            .field(scalar_field.definition.item)
+                            .type_;
+                        if let Some(item_type) = field_type.list_item_type() {
+                            let data = transform_scalar_type(
+                                self.typegen_context,
+                                item_type,
+                                None,
+                                self.encountered_enums,
+                                self.custom_scalars,
+                            );
+                            self.push_payload(StreamDirective::from(stream).label_arg, data);
+                        }
+                    }
+                }
+                Selection::Condition(condition) => self.visit_selections(&condition.selections),
+                Selection::FragmentSpread(_) => {}
+            }
+        }
+    }
+
+    /// The type of an item of a streamed list of objects.
+    fn stream_item_type(
+        &mut self,
+        item_type: &TypeReference<Type>,
+        selections: &[Selection],
+    ) -> AST {
+        let enclosing_concrete_type = if item_type.inner().is_abstract_type() {
+            None
+        } else {
+            Some(item_type.inner())
+        };
+        let concrete_type = if item_type.is_list()
+            || item_type.is_non_null()
+            || item_type.inner().is_abstract_type()
+        {
+            None
+        } else {
+            Some(item_type.inner())
+        };
+        let type_selections = raw_response_visit_selections(
+            self.typegen_context,
+            &without_deferred_fragments(selections),
+            self.encountered_enums,
+            self.match_fields,
+            self.encountered_fragments,
+            self.imported_raw_response_types,
+            self.runtime_imports,
+            self.custom_scalars,
+            enclosing_concrete_type,
+        );
+        let object_props = raw_response_selections_to_babel(
+            self.typegen_context,
+            hashmap_into_values(selections_to_map(type_selections.into_iter(), true)),
+            concrete_type,
+            self.encountered_enums,
+            self.runtime_imports,
+            self.custom_scalars,
+        );
+        transform_scalar_type(
+            self.typegen_context,
+            item_type,
+            Some(object_props),
+            self.encountered_enums,
+            self.custom_scalars,
+        )
+    }
+
+    /// The raw response type of `selections`, without the fragments deferred
+    /// to other payloads.
+    fn raw_response_type(&mut self, selections: &[Selection], concrete_type: Option<Type>) -> AST {
+        let type_selections = raw_response_visit_selections(
+            self.typegen_context,
+            &without_deferred_fragments(selections),
+            self.encountered_enums,
+            self.match_fields,
+            self.encountered_fragments,
+            self.imported_raw_response_types,
+            self.runtime_imports,
+            self.custom_scalars,
+            concrete_type,
+        );
+        raw_response_selections_to_babel(
+            self.typegen_context,
+            type_selections.into_iter(),
+            concrete_type,
+            self.encountered_enums,
+            self.runtime_imports,
+            self.custom_scalars,
+        )
+    }
+
+    fn push_payload(&mut self, label_arg: Option<&Argument>, data: AST) {
+        let label = label_arg
+            .and_then(|label_arg| label_arg.value.item.get_string_literal())
+            .expect("Expected @defer and @stream to have a label after the defer/stream transform");
+        self.payloads.push(AST::ExactObject(ExactObject::new(vec![
+            Prop::KeyValuePair(KeyValuePairProp {
+                key: "data".intern(),
+                value: data,
+                read_only: true,
+                optional: false,
+                doc_comment: None,
+            }),
+            Prop::KeyValuePair(KeyValuePairProp {
+                key: "label".intern(),
+                value: AST::StringLiteral(StringLiteral(label)),
+                read_only: true,
+                optional: false,
+                doc_comment: None,
+            }),
+            Prop::KeyValuePair(KeyValuePairProp {
+                key: "path".intern(),
+                value: AST::ReadOnlyArray(Box::new(AST::Union(SortedASTList::new(vec![
+                    AST::String,
+                    AST::Number,
+                ])))),
+                read_only: true,
+                optional: false,
+                doc_comment: None,
+            }),
+        ])));
+    }
+}
+
+/// `selections` without the fragments with `@defer`, that the server sends in
+/// separate payloads.
+fn without_deferred_fragments(selections: &[Selection]) -> Vec<Selection> {
+    selections
+        .iter()
+        .filter_map(|selection| match selection {
+            Selection::InlineFragment(inline_fragment) => {
+                if inline_fragment
+                    .directives
+                    .named(DEFER_STREAM_CONSTANTS.defer_name)
+                    .is_some()
+                {
+                    return None;
+                }
+                let mut inline_fragment = (**inline_fragment).clone();
+                inline_fragment.selections =
+                    without_deferred_fragments(&inline_fragment.selections);
+                Some(Selection::InlineFragment(Arc::new(inline_fragment)))
+            }
+            Selection::LinkedField(linked_field) => {
+                let mut linked_field = (**linked_field).clone();
+                linked_field.selections = without_deferred_fragments(&linked_field.selections);
+                Some(Selection::LinkedField(Arc::new(linked_field)))
+            }
+            Selection::Condition(condition) => {
+                let mut condition = (**condition).clone();
+                condition.selections = without_deferred_fragments(&condition.selections);
+                Some(Selection::Condition(Arc::new(condition)))
+            }
+            Selection::FragmentSpread(_) | Selection::ScalarField(_) => Some(selection.clone()),
+        })
+        .collect()
+}
@@ -8,6 +8,8 @@
 use std::fmt::Result as FmtResult;
 use std::fmt::Write;
 
+use intern::string_key::StringKey;
+
 use crate::writer::Writer;
 use crate::writer::AST;
 
@@ -79,4 +81,8 @@ impl Writer for JavaScriptPrinter {
     fn write_any_type_definition(&mut self, _name: &str) -> FmtResult {
         Ok(())
     }
+
+    fn write_doc_comment(&mut self, _doc_comment: StringKey) -> FmtResult {
+        Ok(())
+    }
 }
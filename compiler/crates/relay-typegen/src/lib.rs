@@ -10,9 +10,14 @@
 #![deny(clippy::all)]
 
 mod flow;
+mod flow_enums;
+mod incremental_payloads;
 mod javascript;
+mod mock_factory;
+mod operation_registry;
 mod type_selection;
 mod typegen_state;
+mod typename_guards;
 mod typescript;
 mod visit;
 mod write;
@@ -24,15 +29,24 @@ use common::DirectiveName;
 use common::NamedItem;
 use common::ScalarName;
 use common::WithLocation;
+pub use flow_enums::flow_enum_module_name;
+pub use flow_enums::generate_flow_enum;
+pub use flow_enums::referenced_enums;
+pub use flow_enums::uses_flow_enums;
 use graphql_ir::FragmentDefinition;
 use graphql_ir::OperationDefinition;
 use lazy_static::lazy_static;
+pub use mock_factory::generate_mock_factory;
+pub use mock_factory::mock_factory_name;
+pub use operation_registry::generate_operation_registry;
+pub use operation_registry::OPERATION_REGISTRY_TYPE_NAME;
 use relay_config::ProjectConfig;
 pub use relay_config::TypegenConfig;
 pub use relay_config::TypegenLanguage;
 use relay_transforms::UPDATABLE_DIRECTIVE;
 use schema::SDLSchema;
 pub use typegen_state::FragmentLocations;
+pub use typename_guards::generate_typename_guards;
 pub use write::has_raw_response_type_directive;
 use write::write_fragment_type_exports_section;
 use write::write_operation_type_exports_section;
@@ -51,6 +65,7 @@ static LIVE_RESOLVERS_EXPERIMENTAL_STORE_PATH: &str =
     "relay-runtime/store/experimental-live-resolvers/LiveResolverStore";
 
 lazy_static! {
+    static ref CATCH_DIRECTIVE_NAME: DirectiveName = DirectiveName("catch".intern());
     static ref KEY_CLIENTID: StringKey = "__id".intern();
     pub(crate) static ref KEY_DATA: StringKey = "$data".intern();
     static ref KEY_FRAGMENT_SPREADS: StringKey = "$fragmentSpreads".intern();
@@ -63,6 +78,9 @@ lazy_static! {
     static ref KEY_TYPENAME: StringKey = "__typename".intern();
     static ref KEY_NODE: StringKey = "node".intern();
     static ref KEY_NODES: StringKey = "nodes".intern();
+    static ref KEY_OK: StringKey = "ok".intern();
+    static ref KEY_ERRORS: StringKey = "errors".intern();
+    static ref KEY_VALUE: StringKey = "value".intern();
     static ref MODULE_COMPONENT: StringKey = "__module_component".intern();
     static ref RAW_RESPONSE_TYPE_DIRECTIVE_NAME: DirectiveName =
         DirectiveName("raw_response_type".intern());
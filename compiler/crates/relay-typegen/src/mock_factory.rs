@@ -0,0 +1,438 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::fmt::Result as FmtResult;
+use std::fmt::Write;
+
+use ::intern::string_key::Intern;
+use ::intern::string_key::StringKey;
+use ::intern::Lookup;
+use common::DirectiveName;
+use common::NamedItem;
+use graphql_ir::Field;
+use graphql_ir::OperationDefinition;
+use graphql_ir::Selection;
+use indexmap::IndexMap;
+use relay_config::CustomScalarType;
+use relay_config::ProjectConfig;
+use relay_config::TypegenLanguage;
+use relay_schema::CUSTOM_SCALAR_DIRECTIVE_NAME;
+use relay_transforms::ClientEdgeMetadata;
+use relay_transforms::FragmentAliasMetadata;
+use relay_transforms::RelayResolverMetadata;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+use schema::TypeReference;
+
+use crate::KEY_FRAGMENT_SPREADS;
+use crate::TYPE_BOOLEAN;
+use crate::TYPE_FLOAT;
+use crate::TYPE_ID;
+use crate::TYPE_INT;
+use crate::TYPE_STRING;
+
+/// The default value of a selection in a mock factory.
+enum MockValue {
+    Literal(String),
+    List(Box<MockValue>),
+    Object(IndexMap<StringKey, MockValue>),
+    /// Values that can't be derived from the schema, like opaque fragment
+    /// references and custom scalars imported from modules.
+    Any,
+}
+
+/// Name of the mock factory of an operation: `createMock<OperationName>Data`.
+pub fn mock_factory_name(typegen_operation: &OperationDefinition) -> String {
+    format!("createMock{}Data", typegen_operation.name.item.0)
+}
+
+/// Generates a `createMock<OperationName>Data(overrides)` function that returns
+/// the `$data` of the operation with a default value for every selection,
+/// shallowly merged with `overrides`. `operation_import_path` is the module of
+/// the operation artifact, where the `$data` type is imported from.
+pub fn generate_mock_factory(
+    typegen_operation: &OperationDefinition,
+    schema: &SDLSchema,
+    project_config: &ProjectConfig,
+    operation_import_path: &str,
+) -> String {
+    let generator = MockFactoryGenerator {
+        schema,
+        project_config,
+    };
+    let defaults =
+        generator.selections_value(typegen_operation.type_, &typegen_operation.selections, None);
+    let mut result = String::new();
+    generator
+        .write_factory(
+            &mut result,
+            typegen_operation,
+            &defaults,
+            operation_import_path,
+        )
+        .unwrap();
+    result
+}
+
+struct MockFactoryGenerator<'a> {
+    schema: &'a SDLSchema,
+    project_config: &'a ProjectConfig,
+}
+
+impl MockFactoryGenerator<'_> {
+    fn selections_value(
+        &self,
+        parent_type: Type,
+        selections: &[Selection],
+        path: Option<StringKey>,
+    ) -> IndexMap<StringKey, MockValue> {
+        let mut values = IndexMap::new();
+        self.collect_selections(parent_type, selections, path, &mut values);
+        values
+    }
+
+    fn collect_selections(
+        &self,
+        parent_type: Type,
+        selections: &[Selection],
+        path: Option<StringKey>,
+        values: &mut IndexMap<StringKey, MockValue>,
+    ) {
+        for selection in selections {
+            let resolver_metadata = match selection {
+                Selection::Condition(_) => None,
+                _ => RelayResolverMetadata::find(selection.directives()),
+            };
+            if let Some(resolver_metadata) = resolver_metadata {
+                let key = resolver_metadata
+                    .field_alias
+                    .unwrap_or(resolver_metadata.field_name);
+                values.entry(key).or_insert_with(|| {
+                    self.resolver_value(resolver_metadata, next_path(path, key))
+                });
+                continue;
+            }
+            match selection {
+                Selection::ScalarField(field) => {
+                    let key = field.alias_or_name(self.schema);
+                    let value = if field.definition.item == self.schema.typename_field() {
+                        self.typename_value(parent_type)
+                    } else {
+                        let type_ = &self.schema.field(field.definition.item).type_;
+                        self.type_value(type_, next_path(path, key), &|inner, path| {
+                            self.scalar_value(inner, path)
+                        })
+                    };
+                    values.entry(key).or_insert(value);
+                }
+                Selection::LinkedField(field) => {
+                    let key = field.alias_or_name(self.schema);
+                    let type_ = &self.schema.field(field.definition.item).type_;
+                    let value = self.type_value(type_, next_path(path, key), &|inner, path| {
+                        MockValue::Object(self.selections_value(
+                            inner,
+                            &field.selections,
+                            Some(path),
+                        ))
+                    });
+                    values.entry(key).or_insert(value);
+                }
+                Selection::FragmentSpread(spread) => {
+                    if let Some(alias_metadata) = FragmentAliasMetadata::find(&spread.directives) {
+                        values.entry(alias_metadata.alias.item).or_insert_with(|| {
+                            MockValue::Object(IndexMap::from([(
+                                *KEY_FRAGMENT_SPREADS,
+                                MockValue::Any,
+                            )]))
+                        });
+                    } else {
+                        values
+                            .entry(*KEY_FRAGMENT_SPREADS)
+                            .or_insert(MockValue::Any);
+                    }
+                }
+                Selection::InlineFragment(fragment) => {
+                    if let Some(client_edge_metadata) = ClientEdgeMetadata::find(fragment) {
+                        self.collect_selections(
+                            parent_type,
+                            std::slice::from_ref(client_edge_metadata.selections),
+                            path,
+                            values,
+                        );
+                    } else if let Some(alias_metadata) =
+                        FragmentAliasMetadata::find(&fragment.directives)
+                    {
+                        let key = alias_metadata.alias.item;
+                        let value = self.selections_value(
+                            fragment.type_condition.unwrap_or(parent_type),
+                            &fragment.selections,
+                            Some(next_path(path, key)),
+                        );
+                        values.entry(key).or_insert(MockValue::Object(value));
+                    } else if self.matches_type_condition(parent_type, fragment.type_condition) {
+                        self.collect_selections(
+                            fragment.type_condition.unwrap_or(parent_type),
+                            &fragment.selections,
+                            path,
+                            values,
+                        );
+                    }
+                }
+                Selection::Condition(condition) => {
+                    self.collect_selections(parent_type, &condition.selections, path, values);
+                }
+            }
+        }
+    }
+
+    /// Inline fragments on other types of an abstract parent are left out,
+    /// the mock data is typed as the `%other` case of the selection.
+    fn matches_type_condition(&self, parent_type: Type, type_condition: Option<Type>) -> bool {
+        match type_condition {
+            None => true,
+            Some(type_condition) => {
+                type_condition == parent_type
+                    || (!parent_type.is_abstract_type() && type_condition.is_abstract_type())
+            }
+        }
+    }
+
+    fn typename_value(&self, parent_type: Type) -> MockValue {
+        if parent_type.is_abstract_type() {
+            MockValue::Literal(string_literal("%other"))
+        } else {
+            MockValue::Literal(string_literal(
+                self.schema.get_type_name(parent_type).lookup(),
+            ))
+        }
+    }
+
+    /// Lists have a single item, nullable values are not null.
+    fn type_value(
+        &self,
+        type_: &TypeReference<Type>,
+        path: StringKey,
+        inner_value: &dyn Fn(Type, StringKey) -> MockValue,
+    ) -> MockValue {
+        match type_ {
+            TypeReference::Named(inner) => inner_value(*inner, path),
+            TypeReference::NonNull(of) => self.type_value(of, path, inner_value),
+            TypeReference::List(of) => {
+                MockValue::List(Box::new(self.type_value(of, path, inner_value)))
+            }
+        }
+    }
+
+    fn scalar_value(&self, type_: Type, path: StringKey) -> MockValue {
+        match type_ {
+            Type::Enum(enum_id) => self
+                .schema
+                .enum_(enum_id)
+                .values
+                .first()
+                .map_or(MockValue::Any, |value| {
+                    MockValue::Literal(string_literal(value.value.lookup()))
+                }),
+            Type::Scalar(scalar_id) => {
+                let scalar = self.schema.scalar(scalar_id);
+                if scalar
+                    .directives
+                    .named(DirectiveName(*CUSTOM_SCALAR_DIRECTIVE_NAME))
+                    .is_some()
+                {
+                    return MockValue::Any;
+                }
+                let scalar_name = scalar.name.item;
+                if let Some(custom_scalar) = self
+                    .project_config
+                    .typegen_config
+                    .custom_scalar_types
+                    .get(&scalar_name)
+                {
+                    return match custom_scalar {
+                        CustomScalarType::Name(name) => {
+                            match name.lookup().to_lowercase().as_str() {
+                                "string" => MockValue::Literal(string_literal("")),
+                                "number" => MockValue::Literal("0".to_string()),
+                                "boolean" => MockValue::Literal("false".to_string()),
+                                _ => MockValue::Any,
+                            }
+                        }
+                        CustomScalarType::Path(_) => MockValue::Any,
+                    };
+                }
+                if scalar_name == *TYPE_ID {
+                    MockValue::Literal(string_literal(path.lookup()))
+                } else if scalar_name == *TYPE_STRING {
+                    MockValue::Literal(string_literal(""))
+                } else if scalar_name == *TYPE_INT || scalar_name == *TYPE_FLOAT {
+                    MockValue::Literal("0".to_string())
+                } else if scalar_name == *TYPE_BOOLEAN {
+                    MockValue::Literal("false".to_string())
+                } else {
+                    MockValue::Any
+                }
+            }
+            _ => MockValue::Any,
+        }
+    }
+
+    /// Resolvers returning scalars and enums are typed from their schema
+    /// field, client objects are opaque to the factory.
+    fn resolver_value(
+        &self,
+        resolver_metadata: &RelayResolverMetadata,
+        path: StringKey,
+    ) -> MockValue {
+        let field = self
+            .schema
+            .get_type(resolver_metadata.field_parent_type)
+            .and_then(|parent_type| {
+                self.schema
+                    .named_field(parent_type, resolver_metadata.field_name)
+            })
+            .map(|field_id| self.schema.field(field_id));
+        match field {
+            Some(field) if field.type_.inner().is_scalar() || field.type_.inner().is_enum() => self
+                .type_value(&field.type_, path, &|inner, path| {
+                    self.scalar_value(inner, path)
+                }),
+            _ => MockValue::Any,
+        }
+    }
+
+    fn write_factory(
+        &self,
+        writer: &mut String,
+        typegen_operation: &OperationDefinition,
+        defaults: &IndexMap<StringKey, MockValue>,
+        operation_import_path: &str,
+    ) -> FmtResult {
+        let typegen_config = &self.project_config.typegen_config;
+        let language = typegen_config.language;
+        let data_type = typegen_config
+            .type_names
+            .data(typegen_operation.name.item.0);
+        let factory_name = mock_factory_name(typegen_operation);
+        let is_es_module =
+            typegen_config.eager_es_modules || language == TypegenLanguage::TypeScript;
+
+        match language {
+            TypegenLanguage::Flow => {
+                writeln!(writer, "/*::")?;
+                writeln!(
+                    writer,
+                    "import type {{ {} }} from '{}';",
+                    data_type, operation_import_path
+                )?;
+                writeln!(writer, "*/")?;
+                writeln!(writer)?;
+            }
+            TypegenLanguage::TypeScript => {
+                let import = if typegen_config.use_import_type_syntax {
+                    "import type"
+                } else {
+                    "import"
+                };
+                writeln!(
+                    writer,
+                    "{} {{ {} }} from '{}';",
+                    import, data_type, operation_import_path
+                )?;
+                writeln!(writer)?;
+            }
+            TypegenLanguage::JavaScript => {}
+        }
+
+        let export = if is_es_module { "export " } else { "" };
+        match language {
+            TypegenLanguage::Flow => writeln!(
+                writer,
+                "{}function {}(overrides/*: Partial<{}>*/ = {{}})/*: {}*/ {{",
+                export, factory_name, data_type, data_type
+            )?,
+            TypegenLanguage::TypeScript => writeln!(
+                writer,
+                "{}function {}(overrides: Partial<{}> = {{}}): {} {{",
+                export, factory_name, data_type, data_type
+            )?,
+            TypegenLanguage::JavaScript => writeln!(
+                writer,
+                "{}function {}(overrides = {{}}) {{",
+                export, factory_name
+            )?,
+        }
+        writeln!(writer, "  return {{")?;
+        for (key, value) in defaults {
+            write!(writer, "    {}: ", object_key(*key))?;
+            self.write_value(writer, value, 2)?;
+            writeln!(writer, ",")?;
+        }
+        writeln!(writer, "    ...overrides,")?;
+        writeln!(writer, "  }};")?;
+        writeln!(writer, "}}")?;
+
+        if !is_es_module {
+            writeln!(writer)?;
+            writeln!(writer, "module.exports = {{ {} }};", factory_name)?;
+        }
+        Ok(())
+    }
+
+    fn write_value(&self, writer: &mut String, value: &MockValue, indent: usize) -> FmtResult {
+        match value {
+            MockValue::Literal(literal) => write!(writer, "{}", literal),
+            MockValue::Any => match self.project_config.typegen_config.language {
+                TypegenLanguage::Flow => write!(writer, "(null/*: any*/)"),
+                TypegenLanguage::TypeScript => write!(writer, "(null as any)"),
+                TypegenLanguage::JavaScript => write!(writer, "null"),
+            },
+            MockValue::List(item) => {
+                write!(writer, "[")?;
+                self.write_value(writer, item, indent)?;
+                write!(writer, "]")
+            }
+            MockValue::Object(fields) if fields.is_empty() => write!(writer, "{{}}"),
+            MockValue::Object(fields) => {
+                writeln!(writer, "{{")?;
+                for (key, value) in fields {
+                    write!(writer, "{}{}: ", "  ".repeat(indent + 1), object_key(*key))?;
+                    self.write_value(writer, value, indent + 1)?;
+                    writeln!(writer, ",")?;
+                }
+                write!(writer, "{}}}", "  ".repeat(indent))
+            }
+        }
+    }
+}
+
+fn next_path(path: Option<StringKey>, key: StringKey) -> StringKey {
+    match path {
+        None => key,
+        Some(path) => format!("{}.{}", path, key).intern(),
+    }
+}
+
+fn string_literal(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn object_key(key: StringKey) -> String {
+    let key = key.lookup();
+    let is_identifier = matches!(
+        key.chars().next(),
+        Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$'
+    ) && key
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        key.to_string()
+    } else {
+        string_literal(key)
+    }
+}
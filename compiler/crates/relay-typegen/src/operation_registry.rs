@@ -0,0 +1,73 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::fmt::Result as FmtResult;
+
+use ::intern::string_key::Intern;
+use ::intern::string_key::StringKey;
+use relay_config::ProjectConfig;
+
+use crate::writer::new_writer_from_config;
+use crate::writer::ExactObject;
+use crate::writer::KeyValuePairProp;
+use crate::writer::Prop;
+use crate::writer::Writer;
+use crate::writer::AST;
+
+/// Name of the type exported by the operation registry.
+pub const OPERATION_REGISTRY_TYPE_NAME: &str = "RelayOperationRegistry";
+
+/// Generates the `RelayOperationRegistry` type, that maps the name of each of
+/// the `operations` to its `variables` and `response` types, imported from
+/// the module of its artifact. `operations` are pairs of the name of an
+/// operation and the module of its artifact.
+pub fn generate_operation_registry(
+    operations: &[(StringKey, String)],
+    project_config: &ProjectConfig,
+) -> String {
+    let mut writer = new_writer_from_config(&project_config.typegen_config);
+    write_operation_registry(operations, project_config, &mut writer).unwrap();
+    writer.into_string()
+}
+
+fn write_operation_registry(
+    operations: &[(StringKey, String)],
+    project_config: &ProjectConfig,
+    writer: &mut Box<dyn Writer>,
+) -> FmtResult {
+    let type_names = &project_config.typegen_config.type_names;
+    let mut entries = Vec::with_capacity(operations.len());
+    for (operation_name, artifact_module) in operations {
+        let variables_type = type_names.variables(operation_name);
+        let data_type = type_names.data(operation_name);
+        writer.write_import_type(&[&data_type, &variables_type], artifact_module)?;
+        entries.push(Prop::KeyValuePair(KeyValuePairProp {
+            key: *operation_name,
+            value: AST::ExactObject(ExactObject::new(vec![
+                registry_prop("variables", variables_type),
+                registry_prop("response", data_type),
+            ])),
+            read_only: true,
+            optional: false,
+            doc_comment: None,
+        }));
+    }
+    writer.write_export_type(
+        OPERATION_REGISTRY_TYPE_NAME,
+        &AST::ExactObject(ExactObject::new(entries)),
+    )
+}
+
+fn registry_prop(key: &str, type_name: String) -> Prop {
+    Prop::KeyValuePair(KeyValuePairProp {
+        key: key.intern(),
+        value: AST::RawType(type_name.intern()),
+        read_only: true,
+        optional: false,
+        doc_comment: None,
+    })
+}
@@ -137,6 +137,9 @@ pub(crate) struct TypeSelectionLinkedField {
     pub(crate) node_selections: TypeSelectionMap,
     pub(crate) conditional: bool,
     pub(crate) concrete_type: Option<Type>,
+    pub(crate) doc_comment: Option<StringKey>,
+    /// Whether the field has `@catch`, typed as a result of its value or errors.
+    pub(crate) caught: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -146,6 +149,9 @@ pub(crate) struct TypeSelectionScalarField {
     pub(crate) value: AST,
     pub(crate) conditional: bool,
     pub(crate) concrete_type: Option<Type>,
+    pub(crate) doc_comment: Option<StringKey>,
+    /// Whether the field has `@catch`, typed as a result of its value or errors.
+    pub(crate) caught: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,117 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::fmt::Result as FmtResult;
+use std::fmt::Write;
+
+use ::intern::string_key::StringKey;
+use graphql_ir::Selection;
+use indexmap::IndexSet;
+use relay_config::ProjectConfig;
+use relay_config::TypegenLanguage;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+
+/// Generates an `is<TypeName>(data)` type guard for each object type that a
+/// selection on an interface or a union of `selections` refines to with an
+/// inline fragment. The guards compare the `__typename` of the data, to narrow
+/// it to the case of the type in the discriminated union of the selection.
+/// Returns `None` if no selection refines to an object type.
+pub fn generate_typename_guards(
+    parent_type: Type,
+    selections: &[Selection],
+    schema: &SDLSchema,
+    project_config: &ProjectConfig,
+) -> Option<String> {
+    let mut type_names = IndexSet::new();
+    collect_refined_types(schema, parent_type, selections, &mut type_names);
+    if type_names.is_empty() {
+        return None;
+    }
+    let mut result = String::new();
+    write_guards(&mut result, &type_names, project_config).unwrap();
+    Some(result)
+}
+
+fn collect_refined_types(
+    schema: &SDLSchema,
+    parent_type: Type,
+    selections: &[Selection],
+    type_names: &mut IndexSet<StringKey>,
+) {
+    for selection in selections {
+        match selection {
+            Selection::InlineFragment(fragment) => {
+                if let Some(type_condition) = fragment.type_condition {
+                    if parent_type.is_abstract_type() && type_condition.is_object() {
+                        type_names.insert(schema.get_type_name(type_condition));
+                    }
+                }
+                collect_refined_types(
+                    schema,
+                    fragment.type_condition.unwrap_or(parent_type),
+                    &fragment.selections,
+                    type_names,
+                );
+            }
+            Selection::LinkedField(field) => {
+                collect_refined_types(
+                    schema,
+                    schema.field(field.definition.item).type_.inner(),
+                    &field.selections,
+                    type_names,
+                );
+            }
+            Selection::Condition(condition) => {
+                collect_refined_types(schema, parent_type, &condition.selections, type_names);
+            }
+            Selection::FragmentSpread(_) | Selection::ScalarField(_) => {}
+        }
+    }
+}
+
+fn write_guards(
+    writer: &mut String,
+    type_names: &IndexSet<StringKey>,
+    project_config: &ProjectConfig,
+) -> FmtResult {
+    let typegen_config = &project_config.typegen_config;
+    let language = typegen_config.language;
+    let is_es_module = typegen_config.eager_es_modules || language == TypegenLanguage::TypeScript;
+    let export = if is_es_module { "export " } else { "" };
+
+    for type_name in type_names {
+        let guard_name = format!("is{}", type_name);
+        match language {
+            // Flow refines the argument of a `%checks` predicate function at
+            // the call site.
+            TypegenLanguage::Flow => writeln!(
+                writer,
+                "{}function {}(data/*: {{ +__typename: string, ... }}*/)/*: boolean %checks*/ {{",
+                export, guard_name
+            )?,
+            TypegenLanguage::TypeScript => writeln!(
+                writer,
+                "{}function {}<T extends {{ readonly __typename: string }}>(data: T): data is T & {{ readonly __typename: \"{}\" }} {{",
+                export, guard_name, type_name
+            )?,
+            TypegenLanguage::JavaScript => {
+                writeln!(writer, "{}function {}(data) {{", export, guard_name)?
+            }
+        }
+        writeln!(writer, "  return data.__typename === \"{}\";", type_name)?;
+        writeln!(writer, "}}")?;
+    }
+
+    if !is_es_module {
+        for type_name in type_names {
+            writeln!(writer, "module.exports.is{0} = is{0};", type_name)?;
+        }
+    }
+    Ok(())
+}
@@ -13,6 +13,7 @@ use intern::string_key::StringKey;
 use itertools::Itertools;
 use relay_config::TypegenConfig;
 
+use crate::writer::write_doc_comment;
 use crate::writer::Prop;
 use crate::writer::SortedASTList;
 use crate::writer::SortedStringKeyList;
@@ -22,6 +23,7 @@ use crate::writer::AST;
 use crate::KEY_DATA;
 use crate::KEY_FRAGMENT_SPREADS;
 use crate::KEY_FRAGMENT_TYPE;
+use crate::KEY_UPDATABLE_FRAGMENT_SPREADS;
 
 pub struct TypeScriptPrinter {
     result: String,
@@ -53,6 +55,7 @@ impl Writer for TypeScriptPrinter {
             AST::OtherTypename => self.write_other_string(),
             AST::Number => write!(&mut self.result, "number"),
             AST::Boolean => write!(&mut self.result, "boolean"),
+            AST::BooleanLiteral(value) => write!(&mut self.result, "{}", value),
             AST::Callable(return_type) => self.write_callable(&*return_type),
             AST::Identifier(identifier) => write!(&mut self.result, "{}", identifier),
             AST::RawType(raw) => write!(&mut self.result, "{}", raw),
@@ -146,6 +149,10 @@ impl Writer for TypeScriptPrinter {
         Ok(())
     }
 
+    fn write_doc_comment(&mut self, doc_comment: StringKey) -> FmtResult {
+        write_doc_comment(&mut self.result, self.indentation, doc_comment)
+    }
+
     // In TypeScript, we don't export & import fragments. We just use the generic FragmentRefs type instead.
     fn write_import_fragment_type(&mut self, _types: &[&str], _from: &str) -> FmtResult {
         Ok(())
@@ -258,11 +265,15 @@ impl TypeScriptPrinter {
                         )?;
                         self.write_indentation()?;
                     }
+                    if let Some(doc_comment) = key_value_pair.doc_comment {
+                        self.write_doc_comment(doc_comment)?;
+                    }
                     if key_value_pair.read_only {
                         write!(&mut self.result, "readonly ")?;
                     }
                     if key_value_pair.key == *KEY_FRAGMENT_SPREADS
                         || key_value_pair.key == *KEY_FRAGMENT_TYPE
+                        || key_value_pair.key == *KEY_UPDATABLE_FRAGMENT_SPREADS
                         || key_value_pair.key == *KEY_DATA
                     {
                         write!(&mut self.result, "\" {}\"", key_value_pair.key)?;
@@ -276,10 +287,20 @@ impl TypeScriptPrinter {
                     self.write(&key_value_pair.value)?;
                     writeln!(&mut self.result, ";")?;
                 }
-                Prop::GetterSetterPair(_) => {
-                    panic!(
-                        "Getters and setters with different types are not implemented in typescript. See https://github.com/microsoft/TypeScript/issues/43662"
-                    );
+                Prop::GetterSetterPair(getter_setter_pair) => {
+                    // Accessors with unrelated types require TypeScript 5.1
+                    self.write_indentation()?;
+                    if let Some(doc_comment) = getter_setter_pair.doc_comment {
+                        self.write_doc_comment(doc_comment)?;
+                    }
+                    write!(&mut self.result, "get {}(): ", getter_setter_pair.key)?;
+                    self.write(&getter_setter_pair.getter_return_value)?;
+                    writeln!(&mut self.result, ";")?;
+
+                    self.write_indentation()?;
+                    write!(&mut self.result, "set {}(value: ", getter_setter_pair.key)?;
+                    self.write(&getter_setter_pair.setter_parameter)?;
+                    writeln!(&mut self.result, ");")?;
                 }
             }
         }
@@ -412,6 +433,7 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 })
             ]))),
             r"{
@@ -426,12 +448,14 @@ mod tests {
                     optional: true,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: "bar".intern(),
                     optional: false,
                     read_only: true,
                     value: AST::Number,
+                    doc_comment: None,
                 }),
             ]))),
             r"{
@@ -456,20 +480,24 @@ mod tests {
                             optional: true,
                             read_only: false,
                             value: AST::String,
+                            doc_comment: None,
                         }),
                         Prop::KeyValuePair(KeyValuePairProp {
                             key: "nested_foo2".intern(),
                             optional: false,
                             read_only: true,
                             value: AST::Number,
+                            doc_comment: None,
                         }),
                     ])),
+                    doc_comment: None,
                 }),
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: "bar".intern(),
                     optional: false,
                     read_only: true,
                     value: AST::Number,
+                    doc_comment: None,
                 }),
             ]))),
             r"{
@@ -497,6 +525,7 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
             ]))),
             r"{
@@ -512,12 +541,14 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::String,
+                    doc_comment: None,
                 }),
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: "bar".intern(),
                     optional: true,
                     read_only: true,
                     value: AST::Number,
+                    doc_comment: None,
                 })
             ]))),
             r"{
@@ -537,6 +568,7 @@ mod tests {
                     optional: false,
                     read_only: false,
                     value: AST::OtherTypename,
+                    doc_comment: None,
                 }),
             ]))),
             r#"{
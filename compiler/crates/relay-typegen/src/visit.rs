@@ -91,10 +91,14 @@ use crate::writer::StringLiteral;
 use crate::writer::AST;
 use crate::MaskStatus;
 use crate::TypegenContext;
+use crate::CATCH_DIRECTIVE_NAME;
 use crate::FRAGMENT_PROP_NAME;
+use crate::KEY_ERRORS;
 use crate::KEY_FRAGMENT_SPREADS;
 use crate::KEY_FRAGMENT_TYPE;
+use crate::KEY_OK;
 use crate::KEY_UPDATABLE_FRAGMENT_SPREADS;
+use crate::KEY_VALUE;
 use crate::LIVE_STATE_TYPE;
 use crate::MODULE_COMPONENT;
 use crate::RESPONSE;
@@ -287,6 +291,8 @@ fn visit_fragment_spread(
                 node_selections: selections_to_map(vec![spread_selection].into_iter(), true),
                 conditional: false,
                 concrete_type: None,
+                doc_comment: None,
+                caught: false,
             })
         } else {
             spread_selection
@@ -323,12 +329,18 @@ fn generate_resolver_type(
                         key: name,
                         value: AST::PropertyType {
                             type_: Box::new(AST::RawType(
-                                format!("{}$data", fragment_name.item).intern(),
+                                typegen_context
+                                    .project_config
+                                    .typegen_config
+                                    .type_names
+                                    .data(fragment_name.item)
+                                    .intern(),
                             )),
                             property_name: name,
                         },
                         read_only: false,
                         optional: false,
+                        doc_comment: None,
                     });
                 }
             }
@@ -338,9 +350,17 @@ fn generate_resolver_type(
                 .insert(EncounteredFragment::Key(fragment_name));
             resolver_arguments.push(KeyValuePairProp {
                 key: "rootKey".intern(),
-                value: AST::RawType(format!("{}$key", fragment_name).intern()),
+                value: AST::RawType(
+                    typegen_context
+                        .project_config
+                        .typegen_config
+                        .type_names
+                        .fragment_key(fragment_name)
+                        .intern(),
+                ),
                 read_only: false,
                 optional: false,
+                doc_comment: None,
             });
         }
     }
@@ -376,14 +396,43 @@ fn generate_resolver_type(
                 encountered_enums,
                 custom_scalars,
             ),
+            doc_comment: None,
         }));
     }
+    let resolver_context_type = &typegen_context
+        .project_config
+        .typegen_config
+        .resolver_context_type;
     if !args.is_empty() {
         resolver_arguments.push(KeyValuePairProp {
             key: "args".intern(),
             value: AST::ExactObject(ExactObject::new(args)),
             read_only: true,
             optional: false,
+            doc_comment: None,
+        });
+    } else if resolver_context_type.is_some() {
+        // The context is passed after the arguments, which are undefined for
+        // the fields without arguments.
+        resolver_arguments.push(KeyValuePairProp {
+            key: "args".intern(),
+            value: AST::RawType(intern!("void")),
+            read_only: true,
+            optional: false,
+            doc_comment: None,
+        });
+    }
+    if let Some(resolver_context_type) = resolver_context_type {
+        custom_scalars.insert((
+            resolver_context_type.name,
+            resolver_context_type.path.clone(),
+        ));
+        resolver_arguments.push(KeyValuePairProp {
+            key: "context".intern(),
+            value: AST::RawType(resolver_context_type.name),
+            read_only: true,
+            optional: false,
+            doc_comment: None,
         });
     }
     let inner_type = resolver_metadata
@@ -410,9 +459,7 @@ fn generate_resolver_type(
                     Some(normalization_info.normalization_operation.location),
                 );
 
-                let type_ = AST::Nullable(Box::new(AST::RawType(
-                    normalization_info.normalization_operation.item.0,
-                )));
+                let type_ = AST::RawType(normalization_info.normalization_operation.item.0);
 
                 let ast = if let Some(instance_field_name) =
                     normalization_info.weak_object_instance_field
@@ -425,11 +472,15 @@ fn generate_resolver_type(
                     type_
                 };
 
-                if normalization_info.plural {
-                    AST::ReadOnlyArray(Box::new(ast))
-                } else {
-                    ast
-                }
+                // The list and nullability modifiers of the output type apply
+                // to the value returned by the resolver.
+                transform_scalar_type(
+                    typegen_context,
+                    &typegen_context.schema.field(field).type_,
+                    Some(ast),
+                    encountered_enums,
+                    custom_scalars,
+                )
             }
         });
 
@@ -624,6 +675,8 @@ fn visit_relay_resolver(
         value: resolver_type,
         conditional: false,
         concrete_type: None,
+        doc_comment: None,
+        caught: false,
     }));
 }
 
@@ -712,6 +765,8 @@ fn visit_inline_fragment(
             value: AST::Nullable(Box::new(AST::String)),
             conditional: false,
             concrete_type: None,
+            doc_comment: None,
+            caught: false,
         }));
         type_selections.push(TypeSelection::ScalarField(TypeSelectionScalarField {
             field_name_or_alias: *MODULE_COMPONENT,
@@ -719,6 +774,8 @@ fn visit_inline_fragment(
             value: AST::Nullable(Box::new(AST::String)),
             conditional: false,
             concrete_type: None,
+            doc_comment: None,
+            caught: false,
         }));
         type_selections.push(TypeSelection::InlineFragment(TypeSelectionInlineFragment {
             fragment_name: name,
@@ -795,6 +852,8 @@ fn visit_inline_fragment(
                 node_selections: selections_to_map(inline_selections.into_iter(), true),
                 conditional: false,
                 concrete_type: None,
+                doc_comment: None,
+                caught: false,
             })]
         } else {
             // If the inline fragment is on an abstract type, its selections must be
@@ -874,6 +933,7 @@ fn visit_actor_change(
             selections_to_babel(
                 typegen_context,
                 linked_field_selections.into_iter(),
+                Some(field.type_.inner()),
                 MaskStatus::Masked,
                 None,
                 encountered_enums,
@@ -883,6 +943,8 @@ fn visit_actor_change(
         )))),
         conditional: false,
         concrete_type: None,
+        doc_comment: None,
+        caught: false,
     }));
 }
 
@@ -977,6 +1039,11 @@ fn gen_visit_linked_field(
         node_selections: selections_to_map(selections.into_iter(), true),
         conditional: false,
         concrete_type: None,
+        doc_comment: get_field_doc_comment(field),
+        caught: linked_field
+            .directives
+            .named(*CATCH_DIRECTIVE_NAME)
+            .is_some(),
     }));
 }
 
@@ -1022,6 +1089,8 @@ fn visit_scalar_field(
                 )),
                 conditional: false,
                 concrete_type: None,
+                doc_comment: None,
+                caught: false,
             }));
         }
     }
@@ -1038,9 +1107,36 @@ fn visit_scalar_field(
         ),
         conditional: false,
         concrete_type: None,
+        doc_comment: get_field_doc_comment(field),
+        caught: scalar_field
+            .directives
+            .named(*CATCH_DIRECTIVE_NAME)
+            .is_some(),
     }));
 }
 
+/// The description and deprecation reason of a schema field, printed as a doc
+/// comment on the generated property.
+fn get_field_doc_comment(field: &Field) -> Option<StringKey> {
+    let mut lines: Vec<String> = field
+        .description
+        .iter()
+        .flat_map(|description| description.lookup().lines())
+        .map(|line| line.trim_end().to_string())
+        .collect();
+    if let Some(deprecation) = field.deprecated() {
+        lines.push(match deprecation.reason {
+            Some(reason) => format!("@deprecated {}", reason),
+            None => "@deprecated".to_string(),
+        });
+    }
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n").intern())
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn visit_condition(
     typegen_context: &'_ TypegenContext<'_>,
@@ -1079,6 +1175,7 @@ fn visit_condition(
 pub(crate) fn get_data_type(
     typegen_context: &'_ TypegenContext<'_>,
     selections: impl Iterator<Item = TypeSelection>,
+    parent_type: Type,
     mask_status: MaskStatus,
     fragment_type_name: Option<StringKey>,
     emit_optional_type: bool,
@@ -1090,6 +1187,7 @@ pub(crate) fn get_data_type(
     let mut data_type = selections_to_babel(
         typegen_context,
         selections,
+        Some(parent_type),
         mask_status,
         fragment_type_name,
         encountered_enums,
@@ -1105,9 +1203,11 @@ pub(crate) fn get_data_type(
     data_type
 }
 
+#[allow(clippy::too_many_arguments)]
 fn selections_to_babel(
     typegen_context: &'_ TypegenContext<'_>,
     selections: impl Iterator<Item = TypeSelection>,
+    parent_type: Option<Type>,
     mask_status: MaskStatus,
     fragment_type_name: Option<StringKey>,
     encountered_enums: &mut EncounteredEnums,
@@ -1148,6 +1248,7 @@ fn selections_to_babel(
         get_discriminated_union_ast(
             by_concrete_type,
             &base_fields,
+            parent_type,
             typegen_context,
             encountered_enums,
             encountered_fragments,
@@ -1248,6 +1349,7 @@ fn get_merged_object_with_optional_fields(
             optional: false,
             read_only: true,
             value: AST::FragmentReferenceType(fragment_type_name),
+            doc_comment: None,
         }));
     }
 
@@ -1258,9 +1360,11 @@ fn get_merged_object_with_optional_fields(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn get_discriminated_union_ast(
     by_concrete_type: IndexMap<Type, Vec<TypeSelection>>,
     base_fields: &IndexMap<StringKey, TypeSelection>,
+    parent_type: Option<Type>,
     typegen_context: &'_ TypegenContext<'_>,
     encountered_enums: &mut EncounteredEnums,
     encountered_fragments: &mut EncounteredFragments,
@@ -1268,6 +1372,8 @@ fn get_discriminated_union_ast(
     fragment_type_name: Option<StringKey>,
     custom_scalars: &mut CustomScalarsImports,
 ) -> AST {
+    let is_exhaustive = matches!(parent_type, Some(parent_type)
+        if is_exhaustive_selection_of_closed_type(typegen_context, parent_type, &by_concrete_type));
     let mut types: Vec<Vec<Prop>> = Vec::new();
     let mut typename_aliases = IndexSet::new();
     for (concrete_type, selections) in by_concrete_type {
@@ -1293,20 +1399,24 @@ fn get_discriminated_union_ast(
         );
     }
 
-    // Add the __typename: "%other" branch of the discriminated union.
-    types.push(
-        typename_aliases
-            .iter()
-            .map(|typename_alias| {
-                Prop::KeyValuePair(KeyValuePairProp {
-                    key: *typename_alias,
-                    read_only: true,
-                    optional: false,
-                    value: AST::OtherTypename,
+    // Add the __typename: "%other" branch of the discriminated union, unless
+    // every implementing type of a closed abstract type is selected.
+    if !is_exhaustive {
+        types.push(
+            typename_aliases
+                .iter()
+                .map(|typename_alias| {
+                    Prop::KeyValuePair(KeyValuePairProp {
+                        key: *typename_alias,
+                        read_only: true,
+                        optional: false,
+                        value: AST::OtherTypename,
+                        doc_comment: None,
+                    })
                 })
-            })
-            .collect(),
-    );
+                .collect(),
+        );
+    }
     AST::Union(SortedASTList::new(
         types
             .into_iter()
@@ -1319,6 +1429,7 @@ fn get_discriminated_union_ast(
                         optional: false,
                         read_only: true,
                         value: AST::FragmentReferenceType(fragment_type_name),
+                        doc_comment: None,
                     }));
                 }
                 if mask_status == MaskStatus::Unmasked {
@@ -1331,6 +1442,33 @@ fn get_discriminated_union_ast(
     ))
 }
 
+/// Whether `parent_type` is listed in the `closedAbstractTypes` typegen config
+/// and all of its implementing types are selected. The implementing types are
+/// validated against the config, so no other type can be returned.
+fn is_exhaustive_selection_of_closed_type(
+    typegen_context: &'_ TypegenContext<'_>,
+    parent_type: Type,
+    by_concrete_type: &IndexMap<Type, Vec<TypeSelection>>,
+) -> bool {
+    let schema = typegen_context.schema;
+    if !typegen_context
+        .project_config
+        .typegen_config
+        .closed_abstract_types
+        .contains_key(&schema.get_type_name(parent_type))
+    {
+        return false;
+    }
+    let implementing_objects = match parent_type {
+        Type::Interface(id) => &schema.interface(id).implementing_objects,
+        Type::Union(id) => &schema.union(id).members,
+        _ => return false,
+    };
+    implementing_objects
+        .iter()
+        .all(|object_id| by_concrete_type.contains_key(&Type::Object(*object_id)))
+}
+
 /// In the following condition, if base_fields is empty, the .all will return true
 /// but the .any will return false.
 ///
@@ -1523,10 +1661,12 @@ fn make_prop(
 
                 let (just_fragments, no_fragments) =
                     extract_fragments(linked_field.node_selections);
+                let selects_client_id = selects_unaliased_client_id(&no_fragments);
 
                 let getter_object_props = selections_to_babel(
                     typegen_context,
                     no_fragments.into_iter(),
+                    Some(linked_field.node_type.inner()),
                     mask_status,
                     None,
                     encountered_enums,
@@ -1541,54 +1681,62 @@ fn make_prop(
                     custom_scalars,
                 );
 
-                let setter_parameter = if just_fragments.is_empty() {
+                let mut setter_options = just_fragments
+                    .iter()
+                    .map(|fragment_spread| {
+                        let type_condition_info = fragment_spread.type_condition_info.expect(
+                            "Fragment spreads in updatable queries should have TypeConditionInfo",
+                        );
+                        let (key, value) = match type_condition_info {
+                            TypeConditionInfo::Abstract => (
+                                format!("__is{}", fragment_spread.fragment_name).intern(),
+                                AST::String,
+                            ),
+                            TypeConditionInfo::Concrete { concrete_type } => (
+                                "__typename".intern(),
+                                AST::StringLiteral(StringLiteral(concrete_type)),
+                            ),
+                        };
+                        let fragment_spread_or_concrete_type_marker =
+                            Prop::KeyValuePair(KeyValuePairProp {
+                                key,
+                                value,
+                                read_only: true,
+                                optional: false,
+                                doc_comment: None,
+                            });
+                        let assignable_fragment_spread_ref = Prop::KeyValuePair(KeyValuePairProp {
+                            key: *KEY_FRAGMENT_SPREADS,
+                            value: AST::FragmentReference(SortedStringKeyList::new(vec![
+                                fragment_spread.fragment_name.0,
+                            ])),
+                            read_only: true,
+                            optional: false,
+                            doc_comment: None,
+                        });
+
+                        AST::InexactObject(InexactObject::new(vec![
+                            assignable_fragment_spread_ref,
+                            fragment_spread_or_concrete_type_marker,
+                            make_client_id_prop(),
+                        ]))
+                    })
+                    .collect::<Vec<_>>();
+
+                if selects_client_id && linked_field.node_type.is_list() {
+                    setter_options.push(AST::InexactObject(InexactObject::new(vec![
+                        make_client_id_prop(),
+                    ])));
+                }
+
+                let setter_parameter = if setter_options.is_empty() {
                     if linked_field.node_type.is_list() {
                         AST::RawType(intern!("[]"))
                     } else {
                         AST::RawType(intern!("null | void"))
                     }
                 } else {
-                    let setter_parameter = AST::Union(
-                                 SortedASTList::new(
-                                 just_fragments
-                                     .iter()
-                                     .map(|fragment_spread| {
-                                         let type_condition_info =  fragment_spread
-                                             .type_condition_info
-                                             .expect("Fragment spreads in updatable queries should have TypeConditionInfo");
-                                         let (key, value) = match type_condition_info {
-                                             TypeConditionInfo::Abstract => (format!("__is{}", fragment_spread.fragment_name).intern(), AST::String),
-                                             TypeConditionInfo::Concrete { concrete_type } => ("__typename".intern(), AST::StringLiteral(StringLiteral(concrete_type))),
-                                         };
-                                         let fragment_spread_or_concrete_type_marker = Prop::KeyValuePair(KeyValuePairProp {
-                                             key,
-                                             value,
-                                             read_only: true,
-                                             optional: false,
-                                         });
-                                         let assignable_fragment_spread_ref= Prop::KeyValuePair(KeyValuePairProp {
-                                             key: *KEY_FRAGMENT_SPREADS,
-                                             value: AST::FragmentReferenceType(
-                                                 fragment_spread.fragment_name.0,
-                                             ),
-                                             read_only: true,
-                                             optional: false,
-                                         });
-                                         let client_id_field = Prop::KeyValuePair(KeyValuePairProp {
-                                             key: "__id".intern(),
-                                             value: AST::String,
-                                             read_only: true,
-                                             optional: false,
-                                         });
-
-                                         AST::InexactObject(InexactObject::new(vec![
-                                             assignable_fragment_spread_ref,
-                                             fragment_spread_or_concrete_type_marker,
-                                             client_id_field,
-                                         ]))
-                                     })
-                                     .collect(),
-                             ));
+                    let setter_parameter = AST::Union(SortedASTList::new(setter_options));
                     if linked_field.node_type.is_list() {
                         AST::ReadOnlyArray(Box::new(setter_parameter))
                     } else {
@@ -1600,30 +1748,36 @@ fn make_prop(
                     key,
                     getter_return_value,
                     setter_parameter,
+                    doc_comment: linked_field.doc_comment,
                 })
             } else {
                 let object_props = selections_to_babel(
                     typegen_context,
                     hashmap_into_values(linked_field.node_selections),
+                    Some(linked_field.node_type.inner()),
                     mask_status,
                     None,
                     encountered_enums,
                     encountered_fragments,
                     custom_scalars,
                 );
-                let value = transform_scalar_type(
+                let mut value = transform_scalar_type(
                     typegen_context,
                     &linked_field.node_type,
                     Some(object_props),
                     encountered_enums,
                     custom_scalars,
                 );
+                if linked_field.caught {
+                    value = catch_result_type(typegen_context, value, custom_scalars);
+                }
 
                 Prop::KeyValuePair(KeyValuePairProp {
                     key,
                     value,
                     optional,
                     read_only: true,
+                    doc_comment: linked_field.doc_comment,
                 })
             }
         }
@@ -1637,6 +1791,7 @@ fn make_prop(
                         )),
                         optional,
                         read_only: true,
+                        doc_comment: None,
                     })
                 } else {
                     Prop::KeyValuePair(KeyValuePairProp {
@@ -1644,17 +1799,24 @@ fn make_prop(
                         value: scalar_field.value,
                         optional,
                         read_only: true,
+                        doc_comment: None,
                     })
                 }
             } else {
+                let value = if scalar_field.caught {
+                    catch_result_type(typegen_context, scalar_field.value, custom_scalars)
+                } else {
+                    scalar_field.value
+                };
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: scalar_field.field_name_or_alias,
-                    value: scalar_field.value,
+                    value,
                     optional,
                     // all fields outside of updatable operations are read-only, and within updatable operations,
                     // all special fields are read only
                     read_only: !typegen_context.generating_updatable_types
                         || scalar_field.special_field.is_some(),
+                    doc_comment: scalar_field.doc_comment,
                 })
             }
         }
@@ -1665,6 +1827,45 @@ fn make_prop(
     }
 }
 
+/// The type of a field with `@catch`, the result of reading it: either
+/// `{ok: true, value: T}` or `{ok: false, errors: ReadonlyArray<TError>}`.
+/// The type of the errors is `catchErrorType`, or `mixed` (`unknown` in
+/// TypeScript) if it's not configured.
+fn catch_result_type(
+    typegen_context: &'_ TypegenContext<'_>,
+    value: AST,
+    custom_scalars: &mut CustomScalarsImports,
+) -> AST {
+    let typegen_config = &typegen_context.project_config.typegen_config;
+    let error_type = match &typegen_config.catch_error_type {
+        None => AST::Mixed,
+        Some(CustomScalarType::Name(name)) => AST::RawType(*name),
+        Some(CustomScalarType::Path(CustomScalarTypeImport { name, path })) => {
+            custom_scalars.insert((*name, path.clone()));
+            AST::RawType(*name)
+        }
+    };
+    let result_prop = |key: StringKey, value: AST| {
+        Prop::KeyValuePair(KeyValuePairProp {
+            key,
+            value,
+            read_only: true,
+            optional: false,
+            doc_comment: None,
+        })
+    };
+    AST::Union(SortedASTList::new(vec![
+        AST::ExactObject(ExactObject::new(vec![
+            result_prop(*KEY_OK, AST::BooleanLiteral(true)),
+            result_prop(*KEY_VALUE, value),
+        ])),
+        AST::ExactObject(ExactObject::new(vec![
+            result_prop(*KEY_OK, AST::BooleanLiteral(false)),
+            result_prop(*KEY_ERRORS, AST::ReadOnlyArray(Box::new(error_type))),
+        ])),
+    ]))
+}
+
 fn raw_response_make_prop(
     typegen_context: &'_ TypegenContext<'_>,
     type_selection: TypeSelection,
@@ -1677,7 +1878,12 @@ fn raw_response_make_prop(
         !typegen_context.no_optional_fields_in_raw_response_type && type_selection.is_conditional();
     match type_selection {
         TypeSelection::ModuleDirective(module_directive) => Prop::Spread(SpreadProp {
-            value: module_directive.fragment_name.0,
+            value: typegen_context
+                .project_config
+                .typegen_config
+                .type_names
+                .module_raw_response(module_directive.fragment_name)
+                .intern(),
         }),
         TypeSelection::LinkedField(linked_field) => {
             let node_type = linked_field.node_type;
@@ -1708,6 +1914,7 @@ fn raw_response_make_prop(
                 ),
                 read_only: true,
                 optional,
+                doc_comment: None,
             })
         }
         TypeSelection::ScalarField(scalar_field) => {
@@ -1720,6 +1927,7 @@ fn raw_response_make_prop(
                         )),
                         read_only: true,
                         optional,
+                        doc_comment: None,
                     })
                 } else {
                     Prop::KeyValuePair(KeyValuePairProp {
@@ -1727,6 +1935,7 @@ fn raw_response_make_prop(
                         value: scalar_field.value,
                         read_only: true,
                         optional,
+                        doc_comment: None,
                     })
                 }
             } else {
@@ -1735,6 +1944,7 @@ fn raw_response_make_prop(
                     value: scalar_field.value,
                     read_only: true,
                     optional,
+                    doc_comment: None,
                 })
             }
         }
@@ -1746,7 +1956,7 @@ fn raw_response_make_prop(
     }
 }
 
-fn transform_scalar_type(
+pub(crate) fn transform_scalar_type(
     typegen_context: &'_ TypegenContext<'_>,
     type_reference: &TypeReference<Type>,
     object_props: Option<AST>,
@@ -2028,7 +2238,12 @@ fn transform_non_nullable_input_type(
                                             .project_config
                                             .typegen_config
                                             .optional_input_fields
-                                            .contains(&field.name.0),
+                                            .contains(&field.name.0)
+                                        || (field.default_value.is_some()
+                                            && typegen_context
+                                                .project_config
+                                                .typegen_config
+                                                .optional_variables_with_defaults),
                                     value: transform_input_type(
                                         typegen_context,
                                         &field.type_,
@@ -2036,6 +2251,7 @@ fn transform_non_nullable_input_type(
                                         encountered_enums,
                                         custom_scalars,
                                     ),
+                                    doc_comment: None,
                                 })
                             })
                             .collect(),
@@ -2094,7 +2310,12 @@ pub(crate) fn get_input_variables_type<'a>(
                 Prop::KeyValuePair(KeyValuePairProp {
                     key: var_def.name.item.0,
                     read_only: false,
-                    optional: !var_def.type_.is_non_null(),
+                    optional: !var_def.type_.is_non_null()
+                        || (var_def.has_non_null_default_value()
+                            && typegen_context
+                                .project_config
+                                .typegen_config
+                                .optional_variables_with_defaults),
                     value: transform_input_type(
                         typegen_context,
                         &var_def.type_,
@@ -2102,16 +2323,42 @@ pub(crate) fn get_input_variables_type<'a>(
                         encountered_enums,
                         custom_scalars,
                     ),
+                    doc_comment: None,
                 })
             })
             .collect(),
     )
 }
 
-fn hashmap_into_values<K: Hash + Eq, V>(map: IndexMap<K, V>) -> impl Iterator<Item = V> {
+pub(crate) fn hashmap_into_values<K: Hash + Eq, V>(map: IndexMap<K, V>) -> impl Iterator<Item = V> {
     map.into_iter().map(|(_, val)| val)
 }
 
+/// Whether the selections of an updatable linked field include an unaliased
+/// `__id`. The items of a plural linked field that selects it can be passed
+/// back to its setter, e.g. to reorder them, so the setter also accepts
+/// `{ __id: string }`.
+fn selects_unaliased_client_id(selections: &[TypeSelection]) -> bool {
+    selections.iter().any(|selection| {
+        matches!(
+            selection,
+            TypeSelection::ScalarField(scalar_field)
+                if scalar_field.special_field == Some(ScalarFieldSpecialSchemaField::ClientId)
+                    && scalar_field.field_name_or_alias == "__id".intern()
+        )
+    })
+}
+
+fn make_client_id_prop() -> Prop {
+    Prop::KeyValuePair(KeyValuePairProp {
+        key: "__id".intern(),
+        value: AST::String,
+        read_only: true,
+        optional: false,
+        doc_comment: None,
+    })
+}
+
 fn extract_fragments(
     all_selections: IndexMap<TypeSelectionKey, TypeSelection>,
 ) -> (Vec<TypeSelectionFragmentSpread>, Vec<TypeSelection>) {
@@ -2130,7 +2377,7 @@ fn extract_fragments(
     (fragments, non_fragments)
 }
 
-fn selections_to_map(
+pub(crate) fn selections_to_map(
     selections: impl Iterator<Item = TypeSelection>,
     append_type: bool,
 ) -> TypeSelectionMap {
@@ -2253,6 +2500,8 @@ fn group_refs(props: impl Iterator<Item = TypeSelection>) -> impl Iterator<Item
                 special_field: None,
                 conditional: false,
                 concrete_type: None,
+                doc_comment: None,
+                caught: false,
             }));
         }
         if let Some(refs) = updatable_fragment_spreads.take() {
@@ -2263,6 +2512,8 @@ fn group_refs(props: impl Iterator<Item = TypeSelection>) -> impl Iterator<Item
                 special_field: None,
                 conditional: false,
                 concrete_type: None,
+                doc_comment: None,
+                caught: false,
             }));
         }
         None
@@ -2314,12 +2565,14 @@ pub(crate) fn get_operation_type_export(
             read_only: false,
             optional: false,
             value: AST::Identifier(variables_identifier_key),
+            doc_comment: None,
         }),
         Prop::KeyValuePair(KeyValuePairProp {
             key: *RESPONSE,
             read_only: false,
             optional: false,
             value: AST::Identifier(response_identifier_key),
+            doc_comment: None,
         }),
     ];
     if let Some(raw_response_prop) = raw_response_prop {
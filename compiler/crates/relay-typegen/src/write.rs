@@ -23,14 +23,21 @@ use graphql_ir::Selection;
 use indexmap::IndexMap;
 use itertools::Itertools;
 use relay_config::JsModuleFormat;
+use relay_config::TypegenConfig;
 use relay_config::TypegenLanguage;
+use relay_transforms::ModuleMetadata;
 use relay_transforms::RefetchableDerivedFromMetadata;
 use relay_transforms::RefetchableMetadata;
 use relay_transforms::RelayDirective;
 use relay_transforms::ASSIGNABLE_DIRECTIVE;
 use relay_transforms::CHILDREN_CAN_BUBBLE_METADATA_KEY;
 use schema::Schema;
+use schema::Type;
 
+use crate::flow_enums::flow_enum_module_name;
+use crate::flow_enums::uses_flow_enums;
+use crate::incremental_payloads::raw_response_incremental_payloads;
+use crate::incremental_payloads::IncrementalPayloads;
 use crate::typegen_state::ActorChangeStatus;
 use crate::typegen_state::EncounteredEnums;
 use crate::typegen_state::EncounteredFragment;
@@ -64,7 +71,6 @@ use crate::FUTURE_ENUM_VALUE;
 use crate::KEY_CLIENTID;
 use crate::KEY_DATA;
 use crate::KEY_FRAGMENT_SPREADS;
-use crate::KEY_FRAGMENT_TYPE;
 use crate::KEY_RAW_RESPONSE;
 use crate::KEY_TYPENAME;
 use crate::KEY_UPDATABLE_FRAGMENT_SPREADS;
@@ -107,6 +113,7 @@ pub(crate) fn write_operation_type_exports_section(
     let data_type = get_data_type(
         typegen_context,
         type_selections.into_iter(),
+        typegen_operation.type_,
         MaskStatus::Masked, // Queries are never unmasked
         None,
         typegen_operation
@@ -133,17 +140,25 @@ pub(crate) fn write_operation_type_exports_section(
                 &mut custom_scalars,
                 None,
             );
-            Some((
-                raw_response_selections_to_babel(
-                    typegen_context,
-                    raw_response_selections.into_iter(),
-                    None,
-                    &mut encountered_enums,
-                    &mut runtime_imports,
-                    &mut custom_scalars,
-                ),
-                match_fields,
-            ))
+            let raw_response_type = raw_response_selections_to_babel(
+                typegen_context,
+                raw_response_selections.into_iter(),
+                None,
+                &mut encountered_enums,
+                &mut runtime_imports,
+                &mut custom_scalars,
+            );
+            let incremental_payloads = raw_response_incremental_payloads(
+                typegen_context,
+                &normalization_operation.selections,
+                &mut encountered_enums,
+                &mut match_fields,
+                &mut encountered_fragments,
+                &mut imported_raw_response_types,
+                &mut runtime_imports,
+                &mut custom_scalars,
+            );
+            Some((raw_response_type, match_fields, incremental_payloads))
         } else {
             None
         };
@@ -167,7 +182,8 @@ pub(crate) fn write_operation_type_exports_section(
     write_relay_resolver_imports(imported_resolvers, writer)?;
     write_split_raw_response_type_imports(typegen_context, imported_raw_response_types, writer)?;
 
-    let mut input_object_types = IndexMap::default();
+    // The input objects of the variables are declared along with the ones of
+    // the arguments of the Relay Resolvers of the selections.
     let provided_variables_object = generate_provided_variables_type(
         typegen_context,
         normalization_operation,
@@ -189,18 +205,20 @@ pub(crate) fn write_operation_type_exports_section(
 
     write_enum_definitions(typegen_context, encountered_enums, writer)?;
     write_custom_scalar_imports(custom_scalars, writer)?;
-    write_input_object_types(input_object_types, writer)?;
+    write_input_object_types(typegen_context, input_object_types, writer)?;
 
-    let variables_identifier = format!("{}$variables", typegen_operation.name.item.0);
+    let type_names = &typegen_context.project_config.typegen_config.type_names;
+    let variables_identifier = type_names.variables(typegen_operation.name.item.0);
     let variables_identifier_key = variables_identifier.as_str().intern();
 
     writer.write_export_type(&variables_identifier, &input_variables_type.into())?;
 
-    let response_identifier = format!("{}$data", typegen_operation.name.item.0);
+    let response_identifier = type_names.data(typegen_operation.name.item.0);
     let response_identifier_key = response_identifier.as_str().intern();
     writer.write_export_type(&response_identifier, &data_type)?;
 
     let raw_response_prop = write_raw_response_and_get_raw_response_prop(
+        typegen_context,
         raw_response_type_and_match_fields,
         writer,
         typegen_operation,
@@ -211,9 +229,10 @@ pub(crate) fn write_operation_type_exports_section(
         raw_response_prop,
     )?;
     writer.write_export_type(
-        typegen_operation.name.item.0.lookup(),
+        &type_names.operation(typegen_operation.name.item.0),
         &query_wrapper_type.into(),
     )?;
+    write_match_modules_types(typegen_context, &typegen_operation.selections, writer)?;
 
     if let Some(provided_variables) = provided_variables_object {
         writer.write_local_type(PROVIDED_VARIABLE_TYPE, &provided_variables)?;
@@ -223,22 +242,37 @@ pub(crate) fn write_operation_type_exports_section(
 }
 
 fn write_raw_response_and_get_raw_response_prop(
-    raw_response_type_and_match_fields: Option<(AST, MatchFields)>,
+    typegen_context: &'_ TypegenContext<'_>,
+    raw_response_type_and_match_fields: Option<(AST, MatchFields, Option<IncrementalPayloads>)>,
     writer: &mut Box<dyn Writer>,
     typegen_operation: &OperationDefinition,
 ) -> Result<Option<KeyValuePairProp>, std::fmt::Error> {
-    if let Some((raw_response_type, match_fields)) = raw_response_type_and_match_fields {
+    if let Some((raw_response_type, match_fields, incremental_payloads)) =
+        raw_response_type_and_match_fields
+    {
+        let type_names = &typegen_context.project_config.typegen_config.type_names;
         for (key, ast) in match_fields.0 {
-            writer.write_export_type(key.lookup(), &ast)?;
+            writer.write_export_type(&type_names.module_raw_response(key), &ast)?;
         }
-        let raw_response_identifier = format!("{}$rawResponse", typegen_operation.name.item.0);
+        let raw_response_identifier = type_names.raw_response(typegen_operation.name.item.0);
         writer.write_export_type(&raw_response_identifier, &raw_response_type)?;
+        if let Some(incremental_payloads) = incremental_payloads {
+            writer.write_export_type(
+                &type_names.initial_payload(typegen_operation.name.item.0),
+                &incremental_payloads.initial,
+            )?;
+            writer.write_export_type(
+                &type_names.incremental_payload(typegen_operation.name.item.0),
+                &incremental_payloads.incremental,
+            )?;
+        }
 
         Ok(Some(KeyValuePairProp {
             key: *KEY_RAW_RESPONSE,
             read_only: false,
             optional: false,
             value: AST::Identifier(raw_response_identifier.intern()),
+            doc_comment: None,
         }))
     } else {
         Ok(None)
@@ -285,8 +319,9 @@ pub(crate) fn write_split_operation_type_exports_section(
     write_enum_definitions(typegen_context, encountered_enums, writer)?;
     write_custom_scalar_imports(custom_scalars, writer)?;
 
+    let type_names = &typegen_context.project_config.typegen_config.type_names;
     for (key, ast) in match_fields.0 {
-        writer.write_export_type(key.lookup(), &ast)?;
+        writer.write_export_type(&type_names.module_raw_response(key), &ast)?;
     }
 
     writer.write_export_type(typegen_operation.name.item.0.lookup(), &raw_response_type)?;
@@ -342,14 +377,15 @@ pub(crate) fn write_fragment_type_exports_section(
         }
     }
 
-    let data_type = fragment_definition.name.item;
-    let data_type_name = format!("{}$data", data_type);
+    let type_names = &typegen_context.project_config.typegen_config.type_names;
+    let data_type_name = type_names.data(fragment_definition.name.item);
 
     let ref_type_data_property = Prop::KeyValuePair(KeyValuePairProp {
         key: *KEY_DATA,
         optional: true,
         read_only: true,
         value: AST::Identifier(data_type_name.as_str().intern()),
+        doc_comment: None,
     });
     let fragment_name = fragment_definition.name.item.0;
     let ref_type_fragment_spreads_property = Prop::KeyValuePair(KeyValuePairProp {
@@ -361,6 +397,7 @@ pub(crate) fn write_fragment_type_exports_section(
         optional: false,
         read_only: true,
         value: AST::FragmentReference(SortedStringKeyList::new(vec![fragment_name])),
+        doc_comment: None,
     });
     let is_plural_fragment = is_plural(fragment_definition);
     let mut ref_type = AST::InexactObject(InexactObject::new(vec![
@@ -380,6 +417,7 @@ pub(crate) fn write_fragment_type_exports_section(
     let data_type = get_data_type(
         typegen_context,
         type_selections.into_iter(),
+        fragment_definition.type_condition,
         mask_status,
         if mask_status == MaskStatus::Unmasked {
             None
@@ -401,7 +439,7 @@ pub(crate) fn write_fragment_type_exports_section(
         .into_iter()
         .map(|(key, val)| (key, val.unwrap_resolved_type()));
 
-    write_input_object_types(input_object_types, writer)?;
+    write_input_object_types(typegen_context, input_object_types, writer)?;
     write_fragment_imports(
         typegen_context,
         Some(fragment_definition.name.item),
@@ -417,16 +455,19 @@ pub(crate) fn write_fragment_type_exports_section(
     write_relay_resolver_imports(imported_resolvers, writer)?;
 
     let refetchable_metadata = RefetchableMetadata::find(&fragment_definition.directives);
-    let fragment_type_name = format!("{}$fragmentType", fragment_name);
+    let fragment_type_name = type_names.fragment_type(fragment_name);
     writer.write_export_fragment_type(&fragment_type_name)?;
     if let Some(refetchable_metadata) = refetchable_metadata {
-        let variables_name = format!("{}$variables", refetchable_metadata.operation_name);
+        let variables_name = type_names.variables(refetchable_metadata.operation_name);
         match typegen_context.project_config.js_module_format {
             JsModuleFormat::CommonJS => {
                 if typegen_context.has_unified_output {
                     writer.write_import_fragment_type(
                         &[&variables_name],
-                        &format!("./{}.graphql", refetchable_metadata.operation_name),
+                        &typegen_context
+                            .project_config
+                            .artifact_filename
+                            .module_name(format!("./{}", refetchable_metadata.operation_name)),
                     )?;
                 } else {
                     writer.write_any_type_definition(&variables_name)?;
@@ -435,7 +476,10 @@ pub(crate) fn write_fragment_type_exports_section(
             JsModuleFormat::Haste => {
                 writer.write_import_fragment_type(
                     &[&variables_name],
-                    &format!("{}.graphql", refetchable_metadata.operation_name),
+                    &typegen_context
+                        .project_config
+                        .artifact_filename
+                        .module_name(refetchable_metadata.operation_name),
                 )?;
             }
         }
@@ -443,8 +487,12 @@ pub(crate) fn write_fragment_type_exports_section(
 
     if !is_assignable_fragment {
         writer.write_export_type(&data_type_name, &data_type)?;
-        writer.write_export_type(&format!("{}$key", fragment_definition.name.item), &ref_type)?;
+        writer.write_export_type(
+            &type_names.fragment_key(fragment_definition.name.item),
+            &ref_type,
+        )?;
     }
+    write_match_modules_types(typegen_context, &fragment_definition.selections, writer)?;
 
     Ok(())
 }
@@ -455,19 +503,20 @@ fn write_fragment_imports(
     encountered_fragments: EncounteredFragments,
     writer: &mut Box<dyn Writer>,
 ) -> FmtResult {
+    let type_names = &typegen_context.project_config.typegen_config.type_names;
     for current_referenced_fragment in encountered_fragments.0.into_iter().sorted() {
         let (current_referenced_fragment, fragment_type_name) = match current_referenced_fragment {
             EncounteredFragment::Key(current_referenced_fragment) => (
                 current_referenced_fragment,
-                format!("{}$key", current_referenced_fragment),
+                type_names.fragment_key(current_referenced_fragment),
             ),
             EncounteredFragment::Spread(current_referenced_fragment) => (
                 current_referenced_fragment,
-                format!("{}$fragmentType", current_referenced_fragment),
+                type_names.fragment_type(current_referenced_fragment),
             ),
             EncounteredFragment::Data(current_referenced_fragment) => (
                 current_referenced_fragment,
-                format!("{}$data", current_referenced_fragment),
+                type_names.data(current_referenced_fragment),
             ),
         };
 
@@ -485,7 +534,10 @@ fn write_fragment_imports(
                 if typegen_context.has_unified_output {
                     writer.write_import_fragment_type(
                         &[&fragment_type_name],
-                        &format!("./{}.graphql", current_referenced_fragment),
+                        &typegen_context
+                            .project_config
+                            .artifact_filename
+                            .module_name(format!("./{}", current_referenced_fragment)),
                     )?;
                 } else {
                     let fragment_location = typegen_context
@@ -512,14 +564,20 @@ fn write_fragment_imports(
 
                     writer.write_import_fragment_type(
                         &[&fragment_type_name],
-                        &format!("./{}.graphql", fragment_import_path),
+                        &typegen_context
+                            .project_config
+                            .artifact_filename
+                            .module_name(format!("./{}", fragment_import_path)),
                     )?;
                 }
             }
             JsModuleFormat::Haste => {
                 writer.write_import_fragment_type(
                     &[&fragment_type_name],
-                    &format!("{}.graphql", current_referenced_fragment),
+                    &typegen_context
+                        .project_config
+                        .artifact_filename
+                        .module_name(current_referenced_fragment),
                 )?;
             }
         }
@@ -579,7 +637,10 @@ fn write_split_raw_response_type_imports(
                 if typegen_context.has_unified_output {
                     writer.write_import_fragment_type(
                         &[imported_raw_response_type.lookup()],
-                        &format!("./{}.graphql", imported_raw_response_type),
+                        &typegen_context
+                            .project_config
+                            .artifact_filename
+                            .module_name(format!("./{}", imported_raw_response_type)),
                     )?;
                 } else if let Some(imported_raw_response_document_location) =
                     imported_raw_response_document_location
@@ -598,7 +659,10 @@ fn write_split_raw_response_type_imports(
 
                     writer.write_import_fragment_type(
                         &[imported_raw_response_type.lookup()],
-                        &format!("./{}.graphql", artifact_import_path),
+                        &typegen_context
+                            .project_config
+                            .artifact_filename
+                            .module_name(format!("./{}", artifact_import_path)),
                     )?;
                 } else {
                     writer.write_any_type_definition(imported_raw_response_type.lookup())?;
@@ -607,7 +671,10 @@ fn write_split_raw_response_type_imports(
             JsModuleFormat::Haste => {
                 writer.write_import_fragment_type(
                     &[imported_raw_response_type.lookup()],
-                    &format!("{}.graphql", imported_raw_response_type),
+                    &typegen_context
+                        .project_config
+                        .artifact_filename
+                        .module_name(imported_raw_response_type),
                 )?;
             }
         }
@@ -633,6 +700,14 @@ fn write_enum_definitions(
                 &[enum_type.name.item.lookup()],
                 &format!("{}{}", enum_type.name.item, enum_module_suffix),
             )?;
+        } else if uses_flow_enums(&typegen_context.project_config.typegen_config) {
+            writer.write_import_type(
+                &[enum_type.name.item.lookup()],
+                &flow_enum_module_name(
+                    enum_type.name.item,
+                    &typegen_context.project_config.artifact_filename,
+                ),
+            )?;
         } else {
             let mut members: Vec<AST> = enum_type
                 .values
@@ -649,6 +724,9 @@ fn write_enum_definitions(
                 members.push(AST::StringLiteral(StringLiteral(*FUTURE_ENUM_VALUE)));
             }
 
+            if let Some(description) = enum_type.description {
+                writer.write_doc_comment(description)?;
+            }
             writer.write_export_type(
                 enum_type.name.item.lookup(),
                 &AST::Union(SortedASTList::new(members)),
@@ -684,12 +762,14 @@ fn generate_provided_variables_type(
                 read_only: true,
                 optional: false,
                 value: provider_func,
+                doc_comment: None,
             });
             Some(Prop::KeyValuePair(KeyValuePairProp {
                 key: def.name.item.0,
                 read_only: true,
                 optional: false,
                 value: AST::ExactObject(ExactObject::new(vec![provider_module])),
+                doc_comment: None,
             }))
         })
         .collect_vec();
@@ -701,10 +781,16 @@ fn generate_provided_variables_type(
 }
 
 fn write_input_object_types(
+    typegen_context: &'_ TypegenContext<'_>,
     input_object_types: impl Iterator<Item = (InputObjectName, ExactObject)>,
     writer: &mut Box<dyn Writer>,
 ) -> FmtResult {
     for (type_identifier, input_object_type) in input_object_types {
+        if let Some(Type::InputObject(id)) = typegen_context.schema.get_type(type_identifier.0) {
+            if let Some(description) = typegen_context.schema.input_object(id).description {
+                writer.write_doc_comment(description)?;
+            }
+        }
         writer.write_export_type(type_identifier.lookup(), &input_object_type.into())?;
     }
     Ok(())
@@ -739,7 +825,7 @@ pub(crate) fn write_validator_function(
 ) -> FmtResult {
     if fragment_definition.type_condition.is_abstract_type() {
         write_abstract_validator_function(
-            typegen_context.project_config.typegen_config.language,
+            &typegen_context.project_config.typegen_config,
             fragment_definition,
             writer,
         )
@@ -763,7 +849,7 @@ pub(crate) fn write_validator_function(
 ///   return value.__isAssignable_node != null ? (value/*: any*/) : null
 /// };
 fn write_abstract_validator_function(
-    language: TypegenLanguage,
+    typegen_config: &TypegenConfig,
     fragment_definition: &FragmentDefinition,
     writer: &mut Box<dyn Writer>,
 ) -> FmtResult {
@@ -774,24 +860,30 @@ fn write_abstract_validator_function(
         value: AST::String,
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
     let fragment_spread_prop = Prop::KeyValuePair(KeyValuePairProp {
         key: *KEY_FRAGMENT_SPREADS,
-        value: AST::Identifier(format!("{}{}", fragment_name, *KEY_FRAGMENT_TYPE).intern()),
+        value: AST::FragmentReference(SortedStringKeyList::new(vec![
+            fragment_definition.name.item.0,
+        ])),
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
     let parameter_discriminator = Prop::KeyValuePair(KeyValuePairProp {
         key: abstract_fragment_spread_marker,
         value: AST::String,
         read_only: true,
         optional: true,
+        doc_comment: None,
     });
     let return_value_discriminator = Prop::KeyValuePair(KeyValuePairProp {
         key: abstract_fragment_spread_marker,
         value: AST::String,
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
 
     let parameter_type = AST::InexactObject(InexactObject::new(vec![
@@ -808,7 +900,7 @@ fn write_abstract_validator_function(
         AST::RawType(intern!("false")),
     ]));
 
-    let (open_comment, close_comment) = match language {
+    let (open_comment, close_comment) = match typegen_config.language {
         TypegenLanguage::Flow | TypegenLanguage::JavaScript => ("/*", "*/"),
         TypegenLanguage::TypeScript => ("", ""),
     };
@@ -863,24 +955,35 @@ fn write_concrete_validator_function(
         value: AST::String,
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
     let fragment_spread_prop = Prop::KeyValuePair(KeyValuePairProp {
         key: *KEY_FRAGMENT_SPREADS,
-        value: AST::Identifier(format!("{}{}", fragment_name, *KEY_FRAGMENT_TYPE).intern()),
+        value: AST::Identifier(
+            typegen_context
+                .project_config
+                .typegen_config
+                .type_names
+                .fragment_type(fragment_name)
+                .intern(),
+        ),
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
     let parameter_discriminator = Prop::KeyValuePair(KeyValuePairProp {
         key: *KEY_TYPENAME,
         value: AST::String,
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
     let return_value_discriminator = Prop::KeyValuePair(KeyValuePairProp {
         key: *KEY_TYPENAME,
         value: AST::StringLiteral(StringLiteral(concrete_typename)),
         read_only: true,
         optional: false,
+        doc_comment: None,
     });
 
     let parameter_type = AST::InexactObject(InexactObject::new(vec![
@@ -941,6 +1044,103 @@ fn has_fragment_spread(selections: &[Selection]) -> bool {
     })
 }
 
+/// The `{key}$modules` types of the `@match` fields in `selections`, that map
+/// the module of each `@module` fragment to the props of its component, e.g.
+///
+/// export type NameRendererFragment$modules = {|
+///   +"PlainUserNameRenderer.react": {|
+///     +name: {
+///       +$fragmentSpreads: PlainUserNameRenderer_name$fragmentType,
+///       ...
+///     },
+///   |},
+/// |};
+fn write_match_modules_types(
+    typegen_context: &'_ TypegenContext<'_>,
+    selections: &[Selection],
+    writer: &mut Box<dyn Writer>,
+) -> FmtResult {
+    let typegen_config = &typegen_context.project_config.typegen_config;
+    if !typegen_config.generate_match_modules_types {
+        return Ok(());
+    }
+    let mut modules_by_key = Default::default();
+    collect_match_modules(selections, &mut modules_by_key);
+    for (key, modules) in modules_by_key {
+        let props = modules
+            .into_iter()
+            .map(|(module_name, fragment_name)| {
+                // The component of a `FragmentName_propName` fragment gets its
+                // reference as the `propName` prop
+                let fragment_name_str = fragment_name.0.lookup();
+                let prop_name = fragment_name_str
+                    .split_once('_')
+                    .map_or(fragment_name_str, |(_, prop_name)| prop_name);
+                let fragment_ref =
+                    AST::InexactObject(InexactObject::new(vec![Prop::KeyValuePair(
+                        KeyValuePairProp {
+                            key: *KEY_FRAGMENT_SPREADS,
+                            value: AST::FragmentReference(SortedStringKeyList::new(vec![
+                                fragment_name.0,
+                            ])),
+                            read_only: true,
+                            optional: false,
+                            doc_comment: None,
+                        },
+                    )]));
+                Prop::KeyValuePair(KeyValuePairProp {
+                    key: format!("\"{}\"", module_name).intern(),
+                    value: AST::ExactObject(ExactObject::new(vec![Prop::KeyValuePair(
+                        KeyValuePairProp {
+                            key: prop_name.intern(),
+                            value: fragment_ref,
+                            read_only: true,
+                            optional: false,
+                            doc_comment: None,
+                        },
+                    )])),
+                    read_only: true,
+                    optional: false,
+                    doc_comment: None,
+                })
+            })
+            .collect();
+        writer.write_export_type(
+            &typegen_config.type_names.match_modules(key),
+            &AST::ExactObject(ExactObject::new(props)),
+        )?;
+    }
+    Ok(())
+}
+
+/// The `@module` fragments of the `@match` fields in `selections`, by module
+/// name and by key of their `@match`.
+fn collect_match_modules(
+    selections: &[Selection],
+    modules_by_key: &mut IndexMap<StringKey, IndexMap<StringKey, FragmentDefinitionName>>,
+) {
+    for selection in selections {
+        match selection {
+            Selection::InlineFragment(inline_fragment) => {
+                if let Some(module_metadata) = ModuleMetadata::find(&inline_fragment.directives) {
+                    modules_by_key
+                        .entry(module_metadata.key)
+                        .or_default()
+                        .insert(module_metadata.module_name, module_metadata.fragment_name);
+                }
+                collect_match_modules(&inline_fragment.selections, modules_by_key);
+            }
+            Selection::LinkedField(linked_field) => {
+                collect_match_modules(&linked_field.selections, modules_by_key)
+            }
+            Selection::Condition(condition) => {
+                collect_match_modules(&condition.selections, modules_by_key)
+            }
+            Selection::FragmentSpread(_) | Selection::ScalarField(_) => {}
+        }
+    }
+}
+
 pub fn has_raw_response_type_directive(operation: &OperationDefinition) -> bool {
     operation
         .directives
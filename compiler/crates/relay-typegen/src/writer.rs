@@ -41,6 +41,7 @@ pub enum AST {
     InexactObject(InexactObject),
     Number,
     Boolean,
+    BooleanLiteral(bool),
     Callable(Box<AST>),
     Any,
     Mixed,
@@ -258,6 +259,8 @@ pub struct KeyValuePairProp {
     pub value: AST,
     pub read_only: bool,
     pub optional: bool,
+    /// Printed as a doc comment before the property, one line per line.
+    pub doc_comment: Option<StringKey>,
 }
 
 impl Ord for Prop {
@@ -310,6 +313,7 @@ pub struct GetterSetterPairProp {
     pub key: StringKey,
     pub getter_return_value: AST,
     pub setter_parameter: AST,
+    pub doc_comment: Option<StringKey>,
 }
 
 /// A newtype wrapper around StringKey that sorts StringKey's in
@@ -383,6 +387,26 @@ pub trait Writer: Write {
     ) -> FmtResult;
 
     fn write_any_type_definition(&mut self, name: &str) -> FmtResult;
+
+    /// Writes a doc comment for the next property or type definition.
+    fn write_doc_comment(&mut self, doc_comment: StringKey) -> FmtResult;
+}
+
+/// Writes a `/** ... */` comment indented by `indentation` levels and leaves
+/// the cursor indented on the next line, ready for the documented item.
+pub(crate) fn write_doc_comment(
+    result: &mut String,
+    indentation: usize,
+    doc_comment: StringKey,
+) -> FmtResult {
+    let indentation = "  ".repeat(indentation);
+    writeln!(result, "/**")?;
+    for line in doc_comment.lookup().lines() {
+        let line = format!(" * {}", line.replace("*/", "*\\/"));
+        writeln!(result, "{}{}", indentation, line.trim_end())?;
+    }
+    writeln!(result, "{} */", indentation)?;
+    result.write_str(&indentation)
 }
 
 #[cfg(test)]
@@ -434,7 +458,7 @@ mod tests {
 pub(crate) fn new_writer_from_config(config: &TypegenConfig) -> Box<dyn Writer> {
     match config.language {
         TypegenLanguage::JavaScript => Box::new(JavaScriptPrinter::default()),
-        TypegenLanguage::Flow => Box::new(FlowPrinter::new()),
+        TypegenLanguage::Flow => Box::new(FlowPrinter::new(config)),
         TypegenLanguage::TypeScript => Box::new(TypeScriptPrinter::new(config)),
     }
 }
@@ -91,6 +91,12 @@ pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
         typegen_config: TypegenConfig {
             language: TypegenLanguage::Flow,
             custom_scalar_types,
+            closed_abstract_types: [(
+                "NeverNode".intern(),
+                vec!["FakeNode".intern(), "NonNode".intern()],
+            )]
+            .into_iter()
+            .collect(),
             ..Default::default()
         },
         ..Default::default()
@@ -103,6 +109,7 @@ pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
         Arc::new(ConsoleLogger),
         None,
         None,
+        None,
     )
     .map_err(|diagnostics| diagnostics_to_sorted_string(source, &diagnostics))?;
 
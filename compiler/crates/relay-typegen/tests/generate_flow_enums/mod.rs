@@ -0,0 +1,93 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::ConsoleLogger;
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::build;
+use graphql_ir::Program;
+use graphql_syntax::parse_executable;
+use indexmap::IndexSet;
+use intern::string_key::Intern;
+use relay_codegen::JsModuleFormat;
+use relay_config::FlowTypegenConfig;
+use relay_config::ProjectConfig;
+use relay_test_schema::get_test_schema;
+use relay_transforms::apply_transforms;
+use relay_typegen::FragmentLocations;
+use relay_typegen::TypegenConfig;
+use relay_typegen::TypegenLanguage;
+use schema::Schema;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let schema = get_test_schema();
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let ast = parse_executable(fixture.content, source_location).unwrap_or_else(|e| {
+        panic!("Encountered error building AST: {:?}", e);
+    });
+    let ir = build(&schema, &ast.definitions).unwrap_or_else(|e| {
+        panic!("Encountered error building IR {:?}", e);
+    });
+    let program = Program::from_definitions(Arc::clone(&schema), ir);
+
+    let project_config = ProjectConfig {
+        name: "test".intern(),
+        js_module_format: JsModuleFormat::Haste,
+        typegen_config: TypegenConfig {
+            language: TypegenLanguage::Flow,
+            flow_typegen: FlowTypegenConfig {
+                use_flow_enums: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let programs = apply_transforms(
+        &project_config,
+        Arc::new(program),
+        Default::default(),
+        Arc::new(ConsoleLogger),
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    let fragment_locations = FragmentLocations::new(programs.typegen.fragments());
+    let mut fragments: Vec<_> = programs.typegen.fragments().collect();
+    fragments.sort_by_key(|fragment| fragment.name.item);
+    let mut result = Vec::new();
+    let mut enum_ids = IndexSet::new();
+    for fragment in fragments {
+        result.push(relay_typegen::generate_fragment_type_exports_section(
+            fragment,
+            &schema,
+            &project_config,
+            &fragment_locations,
+        ));
+        enum_ids.extend(relay_typegen::referenced_enums(
+            &schema,
+            &fragment.variable_definitions,
+            &fragment.selections,
+        ));
+    }
+    for enum_id in enum_ids {
+        result.push(format!(
+            "// {}\n{}",
+            relay_typegen::flow_enum_module_name(
+                schema.enum_(enum_id).name.item,
+                &project_config.artifact_filename,
+            ),
+            relay_typegen::generate_flow_enum(enum_id, &schema, &project_config)
+        ));
+    }
+    Ok(result
+        .join("-------------------------------------------------------------------------------\n"))
+}
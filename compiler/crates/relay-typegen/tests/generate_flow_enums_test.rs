@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<3194697197b1df493c9410ab9c85d81e>>
+ */
+
+mod generate_flow_enums;
+
+use generate_flow_enums::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn fragment_with_enums() {
+    let input = include_str!("generate_flow_enums/fixtures/fragment-with-enums.graphql");
+    let expected = include_str!("generate_flow_enums/fixtures/fragment-with-enums.expected");
+    test_fixture(transform_fixture, "fragment-with-enums.graphql", "generate_flow_enums/fixtures/fragment-with-enums.expected", input, expected);
+}
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<699083e618ce99dec35d277f6f65cc47>>
+ * @generated SignedSource<<d08054f9f3f477332ebda790dbf6dc09>>
  */
 
 mod generate_flow;
@@ -75,6 +75,20 @@ fn aliased_inline_fragment_spread_without_type_condition_query_root() {
     test_fixture(transform_fixture, "aliased-inline-fragment-spread-without-type-condition-query-root.graphql", "generate_flow/fixtures/aliased-inline-fragment-spread-without-type-condition-query-root.expected", input, expected);
 }
 
+#[test]
+fn catch_field() {
+    let input = include_str!("generate_flow/fixtures/catch-field.graphql");
+    let expected = include_str!("generate_flow/fixtures/catch-field.expected");
+    test_fixture(transform_fixture, "catch-field.graphql", "generate_flow/fixtures/catch-field.expected", input, expected);
+}
+
+#[test]
+fn closed_union() {
+    let input = include_str!("generate_flow/fixtures/closed-union.graphql");
+    let expected = include_str!("generate_flow/fixtures/closed-union.expected");
+    test_fixture(transform_fixture, "closed-union.graphql", "generate_flow/fixtures/closed-union.expected", input, expected);
+}
+
 #[test]
 fn conditional() {
     let input = include_str!("generate_flow/fixtures/conditional.graphql");
@@ -89,6 +103,13 @@ fn custom_scalar_type_import() {
     test_fixture(transform_fixture, "custom-scalar-type-import.graphql", "generate_flow/fixtures/custom-scalar-type-import.expected", input, expected);
 }
 
+#[test]
+fn field_descriptions() {
+    let input = include_str!("generate_flow/fixtures/field-descriptions.graphql");
+    let expected = include_str!("generate_flow/fixtures/field-descriptions.expected");
+    test_fixture(transform_fixture, "field-descriptions.graphql", "generate_flow/fixtures/field-descriptions.expected", input, expected);
+}
+
 #[test]
 fn fragment_spread() {
     let input = include_str!("generate_flow/fixtures/fragment-spread.graphql");
@@ -96,6 +117,13 @@ fn fragment_spread() {
     test_fixture(transform_fixture, "fragment-spread.graphql", "generate_flow/fixtures/fragment-spread.expected", input, expected);
 }
 
+#[test]
+fn inline_data_fragment_with_arguments() {
+    let input = include_str!("generate_flow/fixtures/inline-data-fragment-with-arguments.graphql");
+    let expected = include_str!("generate_flow/fixtures/inline-data-fragment-with-arguments.expected");
+    test_fixture(transform_fixture, "inline-data-fragment-with-arguments.graphql", "generate_flow/fixtures/inline-data-fragment-with-arguments.expected", input, expected);
+}
+
 #[test]
 fn inline_fragment() {
     let input = include_str!("generate_flow/fixtures/inline-fragment.graphql");
@@ -327,6 +355,13 @@ fn relay_live_resolver_with_field_args_no_fragment() {
     test_fixture(transform_fixture, "relay-live-resolver-with-field-args-no-fragment.graphql", "generate_flow/fixtures/relay-live-resolver-with-field-args-no-fragment.expected", input, expected);
 }
 
+#[test]
+fn relay_live_resolver_with_input_object_args() {
+    let input = include_str!("generate_flow/fixtures/relay-live-resolver-with-input-object-args.graphql");
+    let expected = include_str!("generate_flow/fixtures/relay-live-resolver-with-input-object-args.expected");
+    test_fixture(transform_fixture, "relay-live-resolver-with-input-object-args.graphql", "generate_flow/fixtures/relay-live-resolver-with-input-object-args.expected", input, expected);
+}
+
 #[test]
 fn relay_resolver() {
     let input = include_str!("generate_flow/fixtures/relay-resolver.graphql");
@@ -404,6 +439,20 @@ fn relay_resolver_with_output_type() {
     test_fixture(transform_fixture, "relay-resolver-with-output-type.graphql", "generate_flow/fixtures/relay-resolver-with-output-type.expected", input, expected);
 }
 
+#[test]
+fn relay_resolver_with_output_type_custom_scalar() {
+    let input = include_str!("generate_flow/fixtures/relay-resolver-with-output-type-custom-scalar.graphql");
+    let expected = include_str!("generate_flow/fixtures/relay-resolver-with-output-type-custom-scalar.expected");
+    test_fixture(transform_fixture, "relay-resolver-with-output-type-custom-scalar.graphql", "generate_flow/fixtures/relay-resolver-with-output-type-custom-scalar.expected", input, expected);
+}
+
+#[test]
+fn relay_resolver_with_output_type_plural_non_null() {
+    let input = include_str!("generate_flow/fixtures/relay-resolver-with-output-type-plural-non-null.graphql");
+    let expected = include_str!("generate_flow/fixtures/relay-resolver-with-output-type-plural-non-null.expected");
+    test_fixture(transform_fixture, "relay-resolver-with-output-type-plural-non-null.graphql", "generate_flow/fixtures/relay-resolver-with-output-type-plural-non-null.expected", input, expected);
+}
+
 #[test]
 fn relay_resolver_with_output_type_relay_resolver_value() {
     let input = include_str!("generate_flow/fixtures/relay-resolver-with-output-type-relay-resolver-value.graphql");
@@ -614,6 +663,13 @@ fn simple() {
     test_fixture(transform_fixture, "simple.graphql", "generate_flow/fixtures/simple.expected", input, expected);
 }
 
+#[test]
+fn type_descriptions() {
+    let input = include_str!("generate_flow/fixtures/type-descriptions.graphql");
+    let expected = include_str!("generate_flow/fixtures/type-descriptions.expected");
+    test_fixture(transform_fixture, "type-descriptions.graphql", "generate_flow/fixtures/type-descriptions.expected", input, expected);
+}
+
 #[test]
 fn typename_in_union_with_other_fields() {
     let input = include_str!("generate_flow/fixtures/typename-in-union-with-other-fields.graphql");
@@ -698,6 +754,13 @@ fn updatable_operation_assignable_fragments_within_narrowing() {
     test_fixture(transform_fixture, "updatable-operation-assignable-fragments-within-narrowing.graphql", "generate_flow/fixtures/updatable-operation-assignable-fragments-within-narrowing.expected", input, expected);
 }
 
+#[test]
+fn updatable_operation_deprecated_field() {
+    let input = include_str!("generate_flow/fixtures/updatable-operation-deprecated-field.graphql");
+    let expected = include_str!("generate_flow/fixtures/updatable-operation-deprecated-field.expected");
+    test_fixture(transform_fixture, "updatable-operation-deprecated-field.graphql", "generate_flow/fixtures/updatable-operation-deprecated-field.expected", input, expected);
+}
+
 #[test]
 fn updatable_operation_multiple_assignable_fragments() {
     let input = include_str!("generate_flow/fixtures/updatable-operation-multiple-assignable-fragments.graphql");
@@ -705,6 +768,20 @@ fn updatable_operation_multiple_assignable_fragments() {
     test_fixture(transform_fixture, "updatable-operation-multiple-assignable-fragments.graphql", "generate_flow/fixtures/updatable-operation-multiple-assignable-fragments.expected", input, expected);
 }
 
+#[test]
+fn updatable_operation_plural_field_client_id() {
+    let input = include_str!("generate_flow/fixtures/updatable-operation-plural-field-client-id.graphql");
+    let expected = include_str!("generate_flow/fixtures/updatable-operation-plural-field-client-id.expected");
+    test_fixture(transform_fixture, "updatable-operation-plural-field-client-id.graphql", "generate_flow/fixtures/updatable-operation-plural-field-client-id.expected", input, expected);
+}
+
+#[test]
+fn updatable_operation_plural_field_client_id_with_spreads() {
+    let input = include_str!("generate_flow/fixtures/updatable-operation-plural-field-client-id-with-spreads.graphql");
+    let expected = include_str!("generate_flow/fixtures/updatable-operation-plural-field-client-id-with-spreads.expected");
+    test_fixture(transform_fixture, "updatable-operation-plural-field-client-id-with-spreads.graphql", "generate_flow/fixtures/updatable-operation-plural-field-client-id-with-spreads.expected", input, expected);
+}
+
 #[test]
 fn updatable_operation_plural_field_no_spreads() {
     let input = include_str!("generate_flow/fixtures/updatable-operation-plural-field-no-spreads.graphql");
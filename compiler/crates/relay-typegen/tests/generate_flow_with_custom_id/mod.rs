@@ -91,6 +91,7 @@ pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
         Arc::new(ConsoleLogger),
         None,
         None,
+        None,
     )
     .unwrap();
 
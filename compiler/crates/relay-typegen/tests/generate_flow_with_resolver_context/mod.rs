@@ -0,0 +1,105 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::ConsoleLogger;
+use common::FeatureFlags;
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::build_ir_in_relay_mode;
+use graphql_ir::OperationDefinitionName;
+use graphql_ir::Program;
+use graphql_syntax::parse_executable;
+use graphql_test_helpers::diagnostics_to_sorted_string;
+use intern::string_key::Intern;
+use relay_codegen::JsModuleFormat;
+use relay_config::CustomScalarTypeImport;
+use relay_config::ProjectConfig;
+use relay_test_schema::get_test_schema_with_extensions;
+use relay_transforms::apply_transforms;
+use relay_typegen::FragmentLocations;
+use relay_typegen::TypegenConfig;
+use relay_typegen::TypegenLanguage;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts = fixture.content.split("%extensions%").collect::<Vec<_>>();
+    let (source, schema) = match parts.as_slice() {
+        [source, extensions] => (source, get_test_schema_with_extensions(extensions)),
+        _ => panic!("Expected the fixture to define the resolvers in %extensions%"),
+    };
+
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let ast = parse_executable(source, source_location)
+        .map_err(|diagnostics| diagnostics_to_sorted_string(source, &diagnostics))?;
+    let feature_flags = FeatureFlags {
+        enable_relay_resolver_transform: true,
+        ..Default::default()
+    };
+    let ir = build_ir_in_relay_mode(&schema, &ast.definitions)
+        .map_err(|diagnostics| diagnostics_to_sorted_string(source, &diagnostics))?;
+    let program = Program::from_definitions(Arc::clone(&schema), ir);
+
+    let project_config = ProjectConfig {
+        name: "test".intern(),
+        js_module_format: JsModuleFormat::Haste,
+        feature_flags: Arc::new(feature_flags),
+        typegen_config: TypegenConfig {
+            language: TypegenLanguage::Flow,
+            resolver_context_type: Some(CustomScalarTypeImport {
+                name: "TestResolverContext".intern(),
+                path: "TestResolverContext".into(),
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let programs = apply_transforms(
+        &project_config,
+        Arc::new(program),
+        Default::default(),
+        Arc::new(ConsoleLogger),
+        None,
+        None,
+        None,
+    )
+    .map_err(|diagnostics| diagnostics_to_sorted_string(source, &diagnostics))?;
+
+    let fragment_locations = FragmentLocations::new(programs.typegen.fragments());
+    let mut operations: Vec<_> = programs.typegen.operations().collect();
+    operations.sort_by_key(|op| op.name.item.0);
+    let operation_strings = operations.into_iter().map(|typegen_operation| {
+        let normalization_operation = programs
+            .normalization
+            .operation(OperationDefinitionName(typegen_operation.name.item.0))
+            .unwrap();
+        relay_typegen::generate_operation_type_exports_section(
+            typegen_operation,
+            normalization_operation,
+            &schema,
+            &project_config,
+            &fragment_locations,
+        )
+    });
+
+    let mut fragments: Vec<_> = programs.typegen.fragments().collect();
+    fragments.sort_by_key(|frag| frag.name.item);
+    let fragment_strings = fragments.into_iter().map(|frag| {
+        relay_typegen::generate_fragment_type_exports_section(
+            frag,
+            &schema,
+            &project_config,
+            &fragment_locations,
+        )
+    });
+
+    let mut result: Vec<String> = operation_strings.collect();
+    result.extend(fragment_strings);
+    Ok(result
+        .join("-------------------------------------------------------------------------------\n"))
+}
@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<af3db3a2119b0f8fa3b29e8b8e91ed5b>>
+ */
+
+mod generate_flow_with_resolver_context;
+
+use generate_flow_with_resolver_context::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn live_resolver_without_arguments() {
+    let input = include_str!("generate_flow_with_resolver_context/fixtures/live-resolver-without-arguments.graphql");
+    let expected = include_str!("generate_flow_with_resolver_context/fixtures/live-resolver-without-arguments.expected");
+    test_fixture(transform_fixture, "live-resolver-without-arguments.graphql", "generate_flow_with_resolver_context/fixtures/live-resolver-without-arguments.expected", input, expected);
+}
+
+#[test]
+fn resolver_with_arguments() {
+    let input = include_str!("generate_flow_with_resolver_context/fixtures/resolver-with-arguments.graphql");
+    let expected = include_str!("generate_flow_with_resolver_context/fixtures/resolver-with-arguments.expected");
+    test_fixture(transform_fixture, "resolver-with-arguments.graphql", "generate_flow_with_resolver_context/fixtures/resolver-with-arguments.expected", input, expected);
+}
@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<c7be04cc484ac3d647f7617bdb99e6b3>>
+ */
+
+mod generate_match_modules;
+
+use generate_match_modules::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn query_with_match_fields() {
+    let input = include_str!("generate_match_modules/fixtures/query-with-match-fields.graphql");
+    let expected = include_str!("generate_match_modules/fixtures/query-with-match-fields.expected");
+    test_fixture(transform_fixture, "query-with-match-fields.graphql", "generate_match_modules/fixtures/query-with-match-fields.expected", input, expected);
+}
@@ -0,0 +1,107 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::ConsoleLogger;
+use common::FeatureFlags;
+use common::ScalarName;
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use fnv::FnvBuildHasher;
+use graphql_ir::build;
+use graphql_ir::Program;
+use graphql_syntax::parse_executable;
+use indexmap::IndexMap;
+use intern::string_key::Intern;
+use relay_codegen::JsModuleFormat;
+use relay_config::CustomScalarType;
+use relay_config::CustomScalarTypeImport;
+use relay_config::ProjectConfig;
+use relay_test_schema::get_test_schema;
+use relay_test_schema::get_test_schema_with_extensions;
+use relay_transforms::apply_transforms;
+use relay_typegen::TypegenConfig;
+use relay_typegen::TypegenLanguage;
+
+type FnvIndexMap<K, V> = IndexMap<K, V, FnvBuildHasher>;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts = fixture.content.split("%extensions%").collect::<Vec<_>>();
+    let (source, schema) = match parts.as_slice() {
+        [source, extensions] => (source, get_test_schema_with_extensions(extensions)),
+        [source] => (source, get_test_schema()),
+        _ => panic!(),
+    };
+
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let ast = parse_executable(source, source_location).unwrap_or_else(|e| {
+        panic!("Encountered error building AST: {:?}", e);
+    });
+    let ir = build(&schema, &ast.definitions).unwrap_or_else(|e| {
+        panic!("Encountered error building IR {:?}", e);
+    });
+    let program = Program::from_definitions(Arc::clone(&schema), ir);
+    let mut custom_scalar_types = FnvIndexMap::default();
+    custom_scalar_types.insert(
+        ScalarName("JSON".intern()),
+        CustomScalarType::Path(CustomScalarTypeImport {
+            name: "JSON".intern(),
+            path: "TypeDefsFile".into(),
+        }),
+    );
+    custom_scalar_types.insert(
+        ScalarName("Color".intern()),
+        CustomScalarType::Name("string".intern()),
+    );
+
+    let mut result = Vec::new();
+    for language in [TypegenLanguage::Flow, TypegenLanguage::TypeScript] {
+        let project_config = ProjectConfig {
+            name: "test".intern(),
+            js_module_format: JsModuleFormat::Haste,
+            typegen_config: TypegenConfig {
+                language,
+                custom_scalar_types: custom_scalar_types.clone(),
+                generate_mock_factories: true,
+                ..Default::default()
+            },
+            feature_flags: Arc::new(FeatureFlags {
+                enable_relay_resolver_transform: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let programs = apply_transforms(
+            &project_config,
+            Arc::new(program.clone()),
+            Default::default(),
+            Arc::new(ConsoleLogger),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let mut operations: Vec<_> = programs
+            .typegen
+            .operations()
+            .filter(|operation| programs.source.operation(operation.name.item).is_some())
+            .collect();
+        operations.sort_by_key(|operation| operation.name.item.0);
+        for operation in operations {
+            result.push(relay_typegen::generate_mock_factory(
+                operation,
+                &schema,
+                &project_config,
+                &format!("./{}.graphql", operation.name.item.0),
+            ));
+        }
+    }
+    Ok(result
+        .join("-------------------------------------------------------------------------------\n"))
+}
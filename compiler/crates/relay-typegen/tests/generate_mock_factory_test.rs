@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<cf6904f9e326da539a0e286463a52bd8>>
+ */
+
+mod generate_mock_factory;
+
+use generate_mock_factory::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn query_with_fragment_spreads_and_conditions() {
+    let input = include_str!("generate_mock_factory/fixtures/query-with-fragment-spreads-and-conditions.graphql");
+    let expected = include_str!("generate_mock_factory/fixtures/query-with-fragment-spreads-and-conditions.expected");
+    test_fixture(transform_fixture, "query-with-fragment-spreads-and-conditions.graphql", "generate_mock_factory/fixtures/query-with-fragment-spreads-and-conditions.expected", input, expected);
+}
+
+#[test]
+fn query_with_resolvers() {
+    let input = include_str!("generate_mock_factory/fixtures/query-with-resolvers.graphql");
+    let expected = include_str!("generate_mock_factory/fixtures/query-with-resolvers.expected");
+    test_fixture(transform_fixture, "query-with-resolvers.graphql", "generate_mock_factory/fixtures/query-with-resolvers.expected", input, expected);
+}
+
+#[test]
+fn query_with_scalars() {
+    let input = include_str!("generate_mock_factory/fixtures/query-with-scalars.graphql");
+    let expected = include_str!("generate_mock_factory/fixtures/query-with-scalars.expected");
+    test_fixture(transform_fixture, "query-with-scalars.graphql", "generate_mock_factory/fixtures/query-with-scalars.expected", input, expected);
+}
@@ -0,0 +1,55 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_syntax::parse_executable;
+use graphql_syntax::ExecutableDefinition;
+use intern::string_key::Intern;
+use intern::Lookup;
+use relay_codegen::JsModuleFormat;
+use relay_config::ProjectConfig;
+use relay_typegen::TypegenConfig;
+use relay_typegen::TypegenLanguage;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let ast = parse_executable(fixture.content, source_location).unwrap_or_else(|e| {
+        panic!("Encountered error building AST: {:?}", e);
+    });
+    let mut operations: Vec<_> = ast
+        .definitions
+        .iter()
+        .filter_map(|definition| match definition {
+            ExecutableDefinition::Operation(operation) => {
+                let name = operation.name.as_ref().unwrap().value;
+                Some((name, format!("{}.graphql", name)))
+            }
+            ExecutableDefinition::Fragment(_) => None,
+        })
+        .collect();
+    operations.sort_by_key(|(name, _)| name.lookup());
+
+    let mut result = Vec::new();
+    for language in [TypegenLanguage::Flow, TypegenLanguage::TypeScript] {
+        let project_config = ProjectConfig {
+            name: "test".intern(),
+            js_module_format: JsModuleFormat::Haste,
+            typegen_config: TypegenConfig {
+                language,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        result.push(relay_typegen::generate_operation_registry(
+            &operations,
+            &project_config,
+        ));
+    }
+    Ok(result
+        .join("-------------------------------------------------------------------------------\n"))
+}
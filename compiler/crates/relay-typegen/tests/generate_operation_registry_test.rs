@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<cff7de1487c6f5d6f3aebd4df4ef38f7>>
+ */
+
+mod generate_operation_registry;
+
+use generate_operation_registry::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn operations() {
+    let input = include_str!("generate_operation_registry/fixtures/operations.graphql");
+    let expected = include_str!("generate_operation_registry/fixtures/operations.expected");
+    test_fixture(transform_fixture, "operations.graphql", "generate_operation_registry/fixtures/operations.expected", input, expected);
+}
@@ -0,0 +1,85 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::ConsoleLogger;
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::build;
+use graphql_ir::Program;
+use graphql_syntax::parse_executable;
+use intern::string_key::Intern;
+use relay_codegen::JsModuleFormat;
+use relay_config::ProjectConfig;
+use relay_test_schema::get_test_schema;
+use relay_test_schema::get_test_schema_with_extensions;
+use relay_transforms::apply_transforms;
+use relay_typegen::FragmentLocations;
+use relay_typegen::TypegenConfig;
+use relay_typegen::TypegenLanguage;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts = fixture.content.split("%extensions%").collect::<Vec<_>>();
+    let (source, schema) = match parts.as_slice() {
+        [source, extensions] => (source, get_test_schema_with_extensions(extensions)),
+        [source] => (source, get_test_schema()),
+        _ => panic!(),
+    };
+
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let ast = parse_executable(source, source_location).unwrap_or_else(|e| {
+        panic!("Encountered error building AST: {:?}", e);
+    });
+    let ir = build(&schema, &ast.definitions).unwrap_or_else(|e| {
+        panic!("Encountered error building IR {:?}", e);
+    });
+    let program = Program::from_definitions(Arc::clone(&schema), ir);
+
+    let mut result = Vec::new();
+    for language in [TypegenLanguage::Flow, TypegenLanguage::TypeScript] {
+        let project_config = ProjectConfig {
+            name: "test".intern(),
+            js_module_format: JsModuleFormat::Haste,
+            typegen_config: TypegenConfig {
+                language,
+                optional_variables_with_defaults: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let programs = apply_transforms(
+            &project_config,
+            Arc::new(program.clone()),
+            Default::default(),
+            Arc::new(ConsoleLogger),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let fragment_locations = FragmentLocations::new(programs.typegen.fragments());
+        let mut operations: Vec<_> = programs.typegen.operations().collect();
+        operations.sort_by_key(|operation| operation.name.item);
+        for typegen_operation in operations {
+            let normalization_operation = programs
+                .normalization
+                .operation(typegen_operation.name.item)
+                .unwrap();
+            result.push(relay_typegen::generate_operation_type_exports_section(
+                typegen_operation,
+                normalization_operation,
+                &schema,
+                &project_config,
+                &fragment_locations,
+            ));
+        }
+    }
+    Ok(result
+        .join("-------------------------------------------------------------------------------\n"))
+}
@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<c9baf8efc1ee6cecb4026bf0a6728742>>
+ */
+
+mod generate_optional_variables_with_defaults;
+
+use generate_optional_variables_with_defaults::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn variables_with_defaults() {
+    let input = include_str!("generate_optional_variables_with_defaults/fixtures/variables-with-defaults.graphql");
+    let expected = include_str!("generate_optional_variables_with_defaults/fixtures/variables-with-defaults.expected");
+    test_fixture(transform_fixture, "variables-with-defaults.graphql", "generate_optional_variables_with_defaults/fixtures/variables-with-defaults.expected", input, expected);
+}
@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<a0a39c1d5ea0cddcbf316e14298e329f>>
+ */
+
+mod generate_type_names;
+
+use generate_type_names::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn query_with_fragment_spreads() {
+    let input = include_str!("generate_type_names/fixtures/query-with-fragment-spreads.graphql");
+    let expected = include_str!("generate_type_names/fixtures/query-with-fragment-spreads.expected");
+    test_fixture(transform_fixture, "query-with-fragment-spreads.graphql", "generate_type_names/fixtures/query-with-fragment-spreads.expected", input, expected);
+}
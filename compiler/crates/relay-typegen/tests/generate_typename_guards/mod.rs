@@ -0,0 +1,68 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::build;
+use graphql_ir::ExecutableDefinition;
+use graphql_syntax::parse_executable;
+use intern::string_key::Intern;
+use intern::Lookup;
+use relay_codegen::JsModuleFormat;
+use relay_config::ProjectConfig;
+use relay_test_schema::get_test_schema;
+use relay_typegen::TypegenConfig;
+use relay_typegen::TypegenLanguage;
+
+pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let schema = get_test_schema();
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let ast = parse_executable(fixture.content, source_location).unwrap_or_else(|e| {
+        panic!("Encountered error building AST: {:?}", e);
+    });
+    let mut ir = build(&schema, &ast.definitions).unwrap_or_else(|e| {
+        panic!("Encountered error building IR {:?}", e);
+    });
+    ir.sort_by_key(|definition| definition.name_with_location().item.lookup());
+
+    let mut result = Vec::new();
+    for language in [TypegenLanguage::Flow, TypegenLanguage::TypeScript] {
+        let project_config = ProjectConfig {
+            name: "test".intern(),
+            js_module_format: JsModuleFormat::Haste,
+            typegen_config: TypegenConfig {
+                language,
+                generate_typename_guards: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        for definition in &ir {
+            let (parent_type, selections) = match definition {
+                ExecutableDefinition::Operation(operation) => {
+                    (operation.type_, &operation.selections)
+                }
+                ExecutableDefinition::Fragment(fragment) => {
+                    (fragment.type_condition, &fragment.selections)
+                }
+            };
+            let typename_guards = relay_typegen::generate_typename_guards(
+                parent_type,
+                selections,
+                &schema,
+                &project_config,
+            );
+            result.push(format!(
+                "// {}\n{}",
+                definition.name_with_location().item,
+                typename_guards.unwrap_or_else(|| "// No typename guards.\n".to_string())
+            ));
+        }
+    }
+    Ok(result
+        .join("-------------------------------------------------------------------------------\n"))
+}
@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<d1526ad9e26e4b6eeee8c716cd9b93eb>>
+ */
+
+mod generate_typename_guards;
+
+use generate_typename_guards::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[test]
+fn fragment_on_interface() {
+    let input = include_str!("generate_typename_guards/fixtures/fragment-on-interface.graphql");
+    let expected = include_str!("generate_typename_guards/fixtures/fragment-on-interface.expected");
+    test_fixture(transform_fixture, "fragment-on-interface.graphql", "generate_typename_guards/fixtures/fragment-on-interface.expected", input, expected);
+}
+
+#[test]
+fn query_with_union() {
+    let input = include_str!("generate_typename_guards/fixtures/query-with-union.graphql");
+    let expected = include_str!("generate_typename_guards/fixtures/query-with-union.expected");
+    test_fixture(transform_fixture, "query-with-union.graphql", "generate_typename_guards/fixtures/query-with-union.expected", input, expected);
+}
@@ -66,6 +66,12 @@ pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
         typegen_config: TypegenConfig {
             language: TypegenLanguage::TypeScript,
             custom_scalar_types,
+            closed_abstract_types: [(
+                "NeverNode".intern(),
+                vec!["FakeNode".intern(), "NonNode".intern()],
+            )]
+            .into_iter()
+            .collect(),
             ..Default::default()
         },
         feature_flags: Arc::new(FeatureFlags {
@@ -81,6 +87,7 @@ pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
         Arc::new(ConsoleLogger),
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -88,13 +95,25 @@ pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
     let mut operations: Vec<_> = programs.typegen.operations().collect();
     operations.sort_by_key(|op| op.name.item.0);
     let operation_strings = operations.into_iter().map(|typegen_operation| {
-        let normalization_operation = programs
+        // `normalization` ASTs are present unless we are processing an updatable query
+        // In that case, `reader` ASTs are present.
+        let op = programs
             .normalization
             .operation(typegen_operation.name.item)
-            .unwrap();
+            .unwrap_or_else(|| {
+                programs
+                    .reader
+                    .operation(typegen_operation.name.item)
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "Couldn't find normalization or reader operations for {}",
+                            typegen_operation.name.item
+                        )
+                    })
+            });
         relay_typegen::generate_operation_type_exports_section(
             typegen_operation,
-            normalization_operation,
+            op,
             &schema,
             &project_config,
             &fragment_locations,
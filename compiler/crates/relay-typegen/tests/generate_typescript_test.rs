@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<a58c4e9a6c17915f3f6a98c532b7058c>>
+ * @generated SignedSource<<eb73e09d109b4bbeea9f67c686d247f2>>
  */
 
 mod generate_typescript;
@@ -61,6 +61,20 @@ fn aliased_inline_fragment_spread_without_type_condition_query_root() {
     test_fixture(transform_fixture, "aliased-inline-fragment-spread-without-type-condition-query-root.graphql", "generate_typescript/fixtures/aliased-inline-fragment-spread-without-type-condition-query-root.expected", input, expected);
 }
 
+#[test]
+fn catch_field() {
+    let input = include_str!("generate_typescript/fixtures/catch-field.graphql");
+    let expected = include_str!("generate_typescript/fixtures/catch-field.expected");
+    test_fixture(transform_fixture, "catch-field.graphql", "generate_typescript/fixtures/catch-field.expected", input, expected);
+}
+
+#[test]
+fn closed_union() {
+    let input = include_str!("generate_typescript/fixtures/closed-union.graphql");
+    let expected = include_str!("generate_typescript/fixtures/closed-union.expected");
+    test_fixture(transform_fixture, "closed-union.graphql", "generate_typescript/fixtures/closed-union.expected", input, expected);
+}
+
 #[test]
 fn conditional() {
     let input = include_str!("generate_typescript/fixtures/conditional.graphql");
@@ -75,6 +89,13 @@ fn custom_scalar_type_import() {
     test_fixture(transform_fixture, "custom-scalar-type-import.graphql", "generate_typescript/fixtures/custom-scalar-type-import.expected", input, expected);
 }
 
+#[test]
+fn field_descriptions() {
+    let input = include_str!("generate_typescript/fixtures/field-descriptions.graphql");
+    let expected = include_str!("generate_typescript/fixtures/field-descriptions.expected");
+    test_fixture(transform_fixture, "field-descriptions.graphql", "generate_typescript/fixtures/field-descriptions.expected", input, expected);
+}
+
 #[test]
 fn fragment_spread() {
     let input = include_str!("generate_typescript/fixtures/fragment-spread.graphql");
@@ -82,6 +103,13 @@ fn fragment_spread() {
     test_fixture(transform_fixture, "fragment-spread.graphql", "generate_typescript/fixtures/fragment-spread.expected", input, expected);
 }
 
+#[test]
+fn inline_data_fragment_with_arguments() {
+    let input = include_str!("generate_typescript/fixtures/inline-data-fragment-with-arguments.graphql");
+    let expected = include_str!("generate_typescript/fixtures/inline-data-fragment-with-arguments.expected");
+    test_fixture(transform_fixture, "inline-data-fragment-with-arguments.graphql", "generate_typescript/fixtures/inline-data-fragment-with-arguments.expected", input, expected);
+}
+
 #[test]
 fn inline_fragment() {
     let input = include_str!("generate_typescript/fixtures/inline-fragment.graphql");
@@ -355,6 +383,13 @@ fn simple() {
     test_fixture(transform_fixture, "simple.graphql", "generate_typescript/fixtures/simple.expected", input, expected);
 }
 
+#[test]
+fn type_descriptions() {
+    let input = include_str!("generate_typescript/fixtures/type-descriptions.graphql");
+    let expected = include_str!("generate_typescript/fixtures/type-descriptions.expected");
+    test_fixture(transform_fixture, "type-descriptions.graphql", "generate_typescript/fixtures/type-descriptions.expected", input, expected);
+}
+
 #[test]
 fn typename_in_union_with_other_fields() {
     let input = include_str!("generate_typescript/fixtures/typename-in-union-with-other-fields.graphql");
@@ -389,3 +424,24 @@ fn unmasked_fragment_spreads() {
     let expected = include_str!("generate_typescript/fixtures/unmasked-fragment-spreads.expected");
     test_fixture(transform_fixture, "unmasked-fragment-spreads.graphql", "generate_typescript/fixtures/unmasked-fragment-spreads.expected", input, expected);
 }
+
+#[test]
+fn updatable_operation() {
+    let input = include_str!("generate_typescript/fixtures/updatable-operation.graphql");
+    let expected = include_str!("generate_typescript/fixtures/updatable-operation.expected");
+    test_fixture(transform_fixture, "updatable-operation.graphql", "generate_typescript/fixtures/updatable-operation.expected", input, expected);
+}
+
+#[test]
+fn updatable_operation_assignable_fragment() {
+    let input = include_str!("generate_typescript/fixtures/updatable-operation-assignable-fragment.graphql");
+    let expected = include_str!("generate_typescript/fixtures/updatable-operation-assignable-fragment.expected");
+    test_fixture(transform_fixture, "updatable-operation-assignable-fragment.graphql", "generate_typescript/fixtures/updatable-operation-assignable-fragment.expected", input, expected);
+}
+
+#[test]
+fn updatable_operation_type_refinement() {
+    let input = include_str!("generate_typescript/fixtures/updatable-operation-type-refinement.graphql");
+    let expected = include_str!("generate_typescript/fixtures/updatable-operation-type-refinement.expected");
+    test_fixture(transform_fixture, "updatable-operation-type-refinement.graphql", "generate_typescript/fixtures/updatable-operation-type-refinement.expected", input, expected);
+}
@@ -110,6 +110,19 @@ pub fn print_types_directives_as_shards(
     shards
 }
 
+/// Prints only what client schema extensions add on top of the server schema:
+/// client directives and types in full, and `extend` definitions with the
+/// client fields of server types. Directives and types that are also
+/// extensions of `builtin_extensions` are skipped. Unlike the full schema, the
+/// extensions are printed with their descriptions, to document them.
+pub fn print_client_extensions(schema: &SDLSchema, builtin_extensions: &SDLSchema) -> String {
+    let mut result = vec![String::new(); DEAULT_SHARD_COUNT];
+    let mut printer = Printer::new(schema, &mut result);
+    printer.print_descriptions = true;
+    printer.print_client_extensions(builtin_extensions).unwrap();
+    result.into_iter().next().unwrap()
+}
+
 pub fn print_type(schema: &SDLSchema, type_: Type) -> String {
     let mut result = vec![String::new(); DEAULT_SHARD_COUNT];
     write_type(schema, &mut result, type_).unwrap();
@@ -168,6 +181,8 @@ struct Printer<'schema, 'writer> {
     // When sharding individual types, this represents index to
     // specify which shard to wite for a given type and field
     type_writers_index: Option<(StringKey, usize)>,
+    // Whether to print the descriptions of the definitions
+    print_descriptions: bool,
 }
 
 impl<'schema, 'writer, 'curent_writer> Printer<'schema, 'writer> {
@@ -179,6 +194,7 @@ impl<'schema, 'writer, 'curent_writer> Printer<'schema, 'writer> {
             shard_count: DEAULT_SHARD_COUNT,
             type_writers: None,
             type_writers_index: None,
+            print_descriptions: false,
         }
     }
 
@@ -195,6 +211,7 @@ impl<'schema, 'writer, 'curent_writer> Printer<'schema, 'writer> {
             shard_count,
             type_writers,
             type_writers_index: None,
+            print_descriptions: false,
         }
     }
 
@@ -242,6 +259,7 @@ impl<'schema, 'writer, 'curent_writer> Printer<'schema, 'writer> {
     }
 
     fn print_directive(&mut self, directive: &Directive) -> FmtResult {
+        self.print_description(directive.description, "")?;
         write!(self.writer(), "directive @{}", directive.name)?;
         self.print_args(&directive.arguments)?;
         write!(
@@ -260,6 +278,59 @@ impl<'schema, 'writer, 'curent_writer> Printer<'schema, 'writer> {
         Ok(())
     }
 
+    fn print_client_extensions(&mut self, builtin_extensions: &SDLSchema) -> FmtResult {
+        let mut ordered_directives = self
+            .schema
+            .get_directives()
+            .filter(|directive| {
+                directive.is_extension
+                    && !matches!(
+                        builtin_extensions.get_directive(directive.name),
+                        Some(builtin_directive) if builtin_directive.is_extension
+                    )
+            })
+            .collect::<Vec<_>>();
+        ordered_directives.sort_by_key(|dir| dir.name);
+        for directive in ordered_directives {
+            self.print_directive(directive)?;
+        }
+        let ordered_type_map = self.schema.get_type_map().collect::<BTreeMap<_, _>>();
+        for type_ in ordered_type_map.values() {
+            if self.schema.is_extension_type(**type_) {
+                let is_builtin = matches!(
+                    builtin_extensions.get_type(self.schema.get_type_name(**type_)),
+                    Some(builtin_type) if builtin_extensions.is_extension_type(builtin_type)
+                );
+                if !is_builtin {
+                    self.print_type(**type_)?;
+                }
+                continue;
+            }
+            let (keyword, name, fields) = match **type_ {
+                Type::Object(id) => {
+                    let object = self.schema.object(id);
+                    ("type", object.name.item.0, &object.fields)
+                }
+                Type::Interface(id) => {
+                    let interface = self.schema.interface(id);
+                    ("interface", interface.name.item.0, &interface.fields)
+                }
+                _ => continue,
+            };
+            let client_fields = fields
+                .iter()
+                .filter(|field_id| self.schema.field(**field_id).is_extension)
+                .copied()
+                .collect::<Vec<_>>();
+            if !client_fields.is_empty() {
+                write!(self.writer(), "extend {} {} ", keyword, name)?;
+                self.print_fields(&client_fields, name)?;
+                self.print_definition_end()?;
+            }
+        }
+        Ok(())
+    }
+
     fn print_type(&mut self, type_: Type) -> FmtResult {
         self.update_writer_index_for_type_start(&type_);
         match type_ {
@@ -276,6 +347,7 @@ impl<'schema, 'writer, 'curent_writer> Printer<'schema, 'writer> {
 
     fn print_scalar(&mut self, id: ScalarID) -> FmtResult {
         let scalar = self.schema.scalar(id);
+        self.print_description(scalar.description, "")?;
         write!(self.writer(), "scalar {}", scalar.name.item)?;
         self.print_directive_values(&scalar.directives)?;
         self.print_definition_end()
@@ -283,6 +355,7 @@ impl<'schema, 'writer, 'curent_writer> Printer<'schema, 'writer> {
 
     fn print_object(&mut self, id: ObjectID) -> FmtResult {
         let object = self.schema.object(id);
+        self.print_description(object.description, "")?;
         write!(self.writer(), "type {}", object.name.item)?;
         self.print_implementing_interfaces(&object.interfaces)?;
         self.print_directive_values(&object.directives)?;
@@ -293,6 +366,7 @@ impl<'schema, 'writer, 'curent_writer> Printer<'schema, 'writer> {
 
     fn print_interface(&mut self, id: InterfaceID) -> FmtResult {
         let interface = self.schema.interface(id);
+        self.print_description(interface.description, "")?;
         write!(self.writer(), "interface {}", interface.name.item)?;
         self.print_implementing_interfaces(&interface.interfaces)?;
         self.print_directive_values(&interface.directives)?;
@@ -303,6 +377,7 @@ impl<'schema, 'writer, 'curent_writer> Printer<'schema, 'writer> {
 
     fn print_union(&mut self, id: UnionID) -> FmtResult {
         let union_ = self.schema.union(id);
+        self.print_description(union_.description, "")?;
         write!(self.writer(), "union {}", union_.name.item)?;
         self.print_directive_values(&union_.directives)?;
         if !union_.members.is_empty() {
@@ -320,6 +395,7 @@ impl<'schema, 'writer, 'curent_writer> Printer<'schema, 'writer> {
 
     fn print_enum(&mut self, id: EnumID) -> FmtResult {
         let enum_ = self.schema.enum_(id);
+        self.print_description(enum_.description, "")?;
         write!(self.writer(), "enum {}", enum_.name.item)?;
         self.print_directive_values(&enum_.directives)?;
         self.print_space()?;
@@ -329,6 +405,7 @@ impl<'schema, 'writer, 'curent_writer> Printer<'schema, 'writer> {
 
     fn print_input_object(&mut self, id: InputObjectID) -> FmtResult {
         let input_object = self.schema.input_object(id);
+        self.print_description(input_object.description, "")?;
         write!(self.writer(), "input {}", input_object.name.item)?;
         self.print_directive_values(&input_object.directives)?;
         self.print_space()?;
@@ -346,6 +423,7 @@ impl<'schema, 'writer, 'curent_writer> Printer<'schema, 'writer> {
         for field_id in fields {
             let field = &self.schema.field(*field_id);
             self.update_writer_index_for_field_start(field.name.item, typename);
+            self.print_description(field.description, "  ")?;
             self.print_space()?;
             self.print_space()?;
             write!(self.writer(), "{}", field.name.item)?;
@@ -401,6 +479,7 @@ impl<'schema, 'writer, 'curent_writer> Printer<'schema, 'writer> {
         write!(self.writer(), "{{")?;
         self.print_new_line()?;
         for arg in args.iter() {
+            self.print_description(arg.description, "  ")?;
             let type_string = self.schema.get_type_string(&arg.type_);
             write!(self.writer(), "  {}: {}", arg.name, type_string,)?;
             if let Some(default) = &arg.default_value {
@@ -450,6 +529,25 @@ impl<'schema, 'writer, 'curent_writer> Printer<'schema, 'writer> {
         Ok(())
     }
 
+    fn print_description(&mut self, description: Option<StringKey>, indent: &str) -> FmtResult {
+        if !self.print_descriptions {
+            return Ok(());
+        }
+        if let Some(description) = description {
+            writeln!(self.writer(), "{}\"\"\"", indent)?;
+            for line in description.lookup().lines() {
+                writeln!(
+                    self.writer(),
+                    "{}{}",
+                    indent,
+                    line.replace("\"\"\"", "\\\"\"\"")
+                )?;
+            }
+            writeln!(self.writer(), "{}\"\"\"", indent)?;
+        }
+        Ok(())
+    }
+
     fn print_definition_end(&mut self) -> FmtResult {
         self.print_new_line()?;
         self.print_new_line()
@@ -5,13 +5,27 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use common::SourceLocationKey;
 use fixture_tests::Fixture;
 use intern::string_key::Intern;
 use schema::build_schema;
+use schema::build_schema_with_extensions;
+use schema_print::print_client_extensions;
 use schema_print::print_directives;
 use schema_print::print_types_directives_as_shards;
 
 pub fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    if let [base, extensions] = fixture.content.split("# %extensions%").collect::<Vec<_>>()[..] {
+        let schema = build_schema_with_extensions(
+            &[(base, SourceLocationKey::generated())],
+            &[(extensions, SourceLocationKey::generated())],
+        )
+        .unwrap();
+        let builtin_extensions =
+            build_schema_with_extensions::<_, &str>(&[(base, SourceLocationKey::generated())], &[])
+                .unwrap();
+        return Ok(print_client_extensions(&schema, &builtin_extensions));
+    }
     let schema = build_schema(fixture.content).unwrap();
     let mut type_shard_count = fnv::FnvHashMap::default();
     type_shard_count.insert("Query".intern(), 2);
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<deb766629b75c758df128a08ff4fd206>>
+ * @generated SignedSource<<0518c300380ccf7f1eb74600b889f45f>>
  */
 
 mod print_schema;
@@ -12,6 +12,20 @@ mod print_schema;
 use print_schema::transform_fixture;
 use fixture_tests::test_fixture;
 
+#[test]
+fn client_extensions() {
+    let input = include_str!("print_schema/fixtures/client-extensions.graphql");
+    let expected = include_str!("print_schema/fixtures/client-extensions.expected");
+    test_fixture(transform_fixture, "client-extensions.graphql", "print_schema/fixtures/client-extensions.expected", input, expected);
+}
+
+#[test]
+fn client_extensions_with_descriptions() {
+    let input = include_str!("print_schema/fixtures/client-extensions-with-descriptions.graphql");
+    let expected = include_str!("print_schema/fixtures/client-extensions-with-descriptions.expected");
+    test_fixture(transform_fixture, "client-extensions-with-descriptions.graphql", "print_schema/fixtures/client-extensions-with-descriptions.expected", input, expected);
+}
+
 #[test]
 fn kitchen_sink() {
     let input = include_str!("print_schema/fixtures/kitchen-sink.graphql");
@@ -310,6 +310,7 @@ pub struct Object {
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct InputObject {
     pub name: WithLocation<InputObjectName>,
+    pub is_extension: bool,
     pub fields: ArgumentDefinitions,
     pub directives: Vec<DirectiveValue>,
     pub description: Option<StringKey>,
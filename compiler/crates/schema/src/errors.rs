@@ -5,6 +5,8 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use common::diagnostic_codes;
+use graphql_syntax::DirectiveLocation;
 use graphql_syntax::OperationType;
 use intern::string_key::StringKey;
 use thiserror::Error;
@@ -13,44 +15,74 @@ use crate::definitions::Type;
 
 pub type Result<T> = std::result::Result<T, SchemaError>;
 
-#[derive(Debug, Error)]
-pub enum SchemaError {
-    #[error("Duplicate {0:?} type definition, got '{1}' and '{2}'.")]
-    DuplicateOperationDefinition(OperationType, StringKey, StringKey),
+diagnostic_codes! {
+    #[derive(Debug, Error)]
+    pub enum SchemaError {
+        #[error("Duplicate {0:?} type definition, got '{1}' and '{2}'.")]
+        #[code("RELAY6001")]
+        DuplicateOperationDefinition(OperationType, StringKey, StringKey),
 
-    #[error("Duplicate directive definition '{0}'.")]
-    DuplicateDirectiveDefinition(StringKey),
+        #[error("Duplicate directive definition '{0}'.")]
+        #[code("RELAY6002")]
+        DuplicateDirectiveDefinition(StringKey),
 
-    #[error("Cannot extend type '{0}', the type is not defined on the server schema.")]
-    ExtendUndefinedType(StringKey),
+        #[error("Directive '@{0}' lists the location '{1}' more than once.")]
+        #[code("RELAY6003")]
+        DuplicateDirectiveLocation(StringKey, DirectiveLocation),
 
-    #[error("Expected an object type for name '{0}', got '{1:?}'.")]
-    ExpectedObjectReference(StringKey, Type),
+        #[error("Invalid default value for the argument '{1}' of directive '@{0}': {2}")]
+        #[code("RELAY6004")]
+        InvalidDirectiveArgumentDefaultValue(StringKey, StringKey, String),
 
-    #[error("Expected an interface type for name '{0}', got '{1:?}'.")]
-    ExpectedInterfaceReference(StringKey, Type),
+        #[error("Cannot extend type '{0}', the type is not defined on the server schema.")]
+        #[code("RELAY6005")]
+        ExtendUndefinedType(StringKey),
 
-    #[error("Reference to undefined type '{0}'.")]
-    UndefinedType(StringKey),
+        #[error("Expected an object type for name '{0}', got '{1:?}'.")]
+        #[code("RELAY6006")]
+        ExpectedObjectReference(StringKey, Type),
 
-    #[error("Duplicate field definition '{0}' found.")]
-    DuplicateField(StringKey),
+        #[error("Expected an interface type for name '{0}', got '{1:?}'.")]
+        #[code("RELAY6007")]
+        ExpectedInterfaceReference(StringKey, Type),
 
-    #[error("Duplicate definition for type '{0}'.")]
-    DuplicateType(StringKey),
+        #[error("Reference to undefined type '{0}'.")]
+        #[code("RELAY6008")]
+        UndefinedType(StringKey),
 
-    #[error("Invalid ID '{0}' provided for type '{1}'")]
-    UnknownTypeID(usize, String),
+        #[error("Duplicate field definition '{0}' found.")]
+        #[code("RELAY6009")]
+        DuplicateField(StringKey),
 
-    // TODO: These should be replaced with error codes or by unifying the parsers.
-    #[error("Parse Error '{0}' at {1}.\nError line:'{2}'")]
-    Syntax(String, String, String),
+        #[error(
+            "The client schema extension field '{0}.{1}' conflicts with the field '{1}' of the server schema. \
+            Delete the field from the client schema extension to use the server field, \
+            or rename the client field."
+        )]
+        #[code("RELAY6010")]
+        ClientExtensionFieldConflict(StringKey, StringKey),
 
-    #[error(
-        "Expected a field of the input object to be a valid input object, scalar, or enum. Got '{0}'."
-    )]
-    ExpectedInputType(StringKey),
+        #[error("Duplicate definition for type '{0}'.")]
+        #[code("RELAY6011")]
+        DuplicateType(StringKey),
 
-    #[error("Reference to undefined directive '{0}'.")]
-    UndefinedDirective(StringKey),
+        #[error("Invalid ID '{0}' provided for type '{1}'")]
+        #[code("RELAY6012")]
+        UnknownTypeID(usize, String),
+
+        // TODO: These should be replaced with error codes or by unifying the parsers.
+        #[error("Parse Error '{0}' at {1}.\nError line:'{2}'")]
+        #[code("RELAY6013")]
+        Syntax(String, String, String),
+
+        #[error(
+            "Expected a field of the input object to be a valid input object, scalar, or enum. Got '{0}'."
+        )]
+        #[code("RELAY6014")]
+        ExpectedInputType(StringKey),
+
+        #[error("Reference to undefined directive '{0}'.")]
+        #[code("RELAY6015")]
+        UndefinedDirective(StringKey),
+    }
 }
@@ -232,6 +232,7 @@ impl<'fb> FlatBufferSchema<'fb> {
         let name = InputObjectName(input_object.name()?.to_string().intern());
         let parsed_input_object = InputObject {
             name: WithLocation::generated(name),
+            is_extension: false,
             fields: self.parse_arguments(input_object.fields()?)?,
             directives: self.parse_directive_values(input_object.directives()?)?,
             description: None,
@@ -319,7 +319,7 @@ impl Schema for SchemaWrapper {
             Type::Object(id) => self.object(id).is_extension,
             Type::Scalar(id) => self.scalar(id).is_extension,
             Type::Union(id) => self.union(id).is_extension,
-            Type::InputObject(_) => false,
+            Type::InputObject(id) => self.input_object(id).is_extension,
         }
     }
 
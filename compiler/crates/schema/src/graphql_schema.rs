@@ -183,23 +183,16 @@ pub trait Schema {
             a.iter().any(|item| b.contains(item))
         }
 
-        fn overlapping_interfaces(a: &[InterfaceID], b: &[InterfaceID]) -> bool {
-            a.iter().any(|item| b.contains(item))
-        }
-
         if a == b {
             return true;
         };
         match (a, b) {
-            (Type::Interface(a), Type::Interface(b)) => {
-                overlapping_objects(
-                    &self.interface(a).implementing_objects,
-                    &self.interface(b).implementing_objects,
-                ) || overlapping_interfaces(
-                    &self.interface(a).implementing_interfaces,
-                    &self.interface(b).implementing_interfaces,
-                )
-            }
+            // Only objects are possible types, interfaces implementing both
+            // interfaces don't make them overlap if no object implements them.
+            (Type::Interface(a), Type::Interface(b)) => overlapping_objects(
+                &self.interface(a).implementing_objects,
+                &self.interface(b).implementing_objects,
+            ),
 
             (Type::Union(a), Type::Union(b)) => {
                 overlapping_objects(&self.union(a).members, &self.union(b).members)
@@ -226,6 +219,18 @@ pub trait Schema {
         }
     }
 
+    /// The object types that a value of `type_` can have at runtime: the
+    /// object itself, the objects implementing an interface, or the members
+    /// of a union.
+    fn possible_types(&self, type_: Type) -> Vec<ObjectID> {
+        match type_ {
+            Type::Object(id) => vec![id],
+            Type::Interface(id) => self.interface(id).implementing_objects.clone(),
+            Type::Union(id) => self.union(id).members.clone(),
+            _ => vec![],
+        }
+    }
+
     fn write_type_string(&self, writer: &mut String, type_: &TypeReference<Type>) -> FmtResult {
         match type_ {
             TypeReference::Named(inner) => {
@@ -5,6 +5,8 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+mod validate_directive_definitions;
+
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 
@@ -159,7 +161,7 @@ impl Schema for InMemorySchema {
             Type::Object(id) => self.objects[id.as_usize()].is_extension,
             Type::Scalar(id) => self.scalars[id.as_usize()].is_extension,
             Type::Union(id) => self.unions[id.as_usize()].is_extension,
-            Type::InputObject(_) => false,
+            Type::InputObject(id) => self.input_objects[id.as_usize()].is_extension,
         }
     }
 
@@ -763,7 +765,7 @@ impl InMemorySchema {
                 }
                 TypeSystemDefinition::ObjectTypeExtension { .. } => {}
                 TypeSystemDefinition::InterfaceTypeExtension { .. } => {}
-                TypeSystemDefinition::SchemaExtension { .. } => todo!("SchemaExtension"),
+                TypeSystemDefinition::SchemaExtension { .. } => {}
                 TypeSystemDefinition::EnumTypeExtension { .. } => todo!("EnumTypeExtension"),
                 TypeSystemDefinition::UnionTypeExtension { .. } => todo!("UnionTypeExtension"),
                 TypeSystemDefinition::InputObjectTypeExtension { .. } => {
@@ -874,6 +876,11 @@ impl InMemorySchema {
         }
         schema.load_defaults();
 
+        validate_directive_definitions::validate_directive_definitions(
+            &schema,
+            schema_documents.iter().chain(client_schema_documents),
+        )?;
+
         Ok(schema)
     }
 
@@ -1087,55 +1094,14 @@ impl InMemorySchema {
                 operation_types,
                 directives: _directives,
             }) => {
-                for OperationTypeDefinition { operation, type_ } in &operation_types.items {
-                    let operation_id = self.build_object_id(type_.value)?;
-                    match operation {
-                        OperationType::Query => {
-                            if let Some(prev_query_type) = self.query_type {
-                                return Err(vec![Diagnostic::error(
-                                    SchemaError::DuplicateOperationDefinition(
-                                        *operation,
-                                        type_.value,
-                                        expect_object_type_name(&self.type_map, prev_query_type),
-                                    ),
-                                    Location::new(*location_key, type_.span),
-                                )]);
-                            } else {
-                                self.query_type = Some(operation_id);
-                            }
-                        }
-                        OperationType::Mutation => {
-                            if let Some(prev_mutation_type) = self.mutation_type {
-                                return Err(vec![Diagnostic::error(
-                                    SchemaError::DuplicateOperationDefinition(
-                                        *operation,
-                                        type_.value,
-                                        expect_object_type_name(&self.type_map, prev_mutation_type),
-                                    ),
-                                    Location::new(*location_key, type_.span),
-                                )]);
-                            } else {
-                                self.mutation_type = Some(operation_id);
-                            }
-                        }
-                        OperationType::Subscription => {
-                            if let Some(prev_subscription_type) = self.subscription_type {
-                                return Err(vec![Diagnostic::error(
-                                    SchemaError::DuplicateOperationDefinition(
-                                        *operation,
-                                        type_.value,
-                                        expect_object_type_name(
-                                            &self.type_map,
-                                            prev_subscription_type,
-                                        ),
-                                    ),
-                                    Location::new(*location_key, type_.span),
-                                )]);
-                            } else {
-                                self.subscription_type = Some(operation_id);
-                            }
-                        }
-                    }
+                self.add_operation_types(&operation_types.items, location_key)?;
+            }
+            TypeSystemDefinition::SchemaExtension(SchemaExtension {
+                operation_types,
+                directives: _directives,
+            }) => {
+                if let Some(operation_types) = operation_types {
+                    self.add_operation_types(&operation_types.items, location_key)?;
                 }
             }
             TypeSystemDefinition::DirectiveDefinition(DirectiveDefinition {
@@ -1145,7 +1111,20 @@ impl InMemorySchema {
                 locations,
                 description,
             }) => {
-                if self.directives.contains_key(&DirectiveName(name.value)) {
+                let arguments = self.build_arguments(arguments)?;
+                if let Some(existing) = self.directives.get(&DirectiveName(name.value)) {
+                    // Extensions may repeat an identical definition, e.g. when
+                    // they are shared between projects via `sharedDirectiveDefs`.
+                    if is_extension
+                        && is_same_directive_definition(
+                            existing,
+                            &arguments,
+                            locations,
+                            *repeatable,
+                        )
+                    {
+                        return Ok(());
+                    }
                     let str_name = name.value.lookup();
                     if str_name != "skip" && str_name != "include" {
                         // TODO(T63941319) @skip and @include directives are duplicated in our schema
@@ -1155,7 +1134,6 @@ impl InMemorySchema {
                         )]);
                     }
                 }
-                let arguments = self.build_arguments(arguments)?;
                 self.directives.insert(
                     DirectiveName(name.value),
                     Directive {
@@ -1173,6 +1151,7 @@ impl InMemorySchema {
                 interfaces,
                 fields,
                 directives,
+                description,
             }) => {
                 let parent_id = Type::Object(ObjectID(self.objects.len() as u32));
                 let fields = if is_extension {
@@ -1181,6 +1160,7 @@ impl InMemorySchema {
                         &mut HashMap::with_capacity(len_of_option_list(fields)),
                         *location_key,
                         Some(parent_id),
+                        is_extension,
                     )?
                 } else {
                     self.build_fields(fields, *location_key, Some(parent_id))?
@@ -1199,7 +1179,7 @@ impl InMemorySchema {
                     is_extension,
                     interfaces,
                     directives,
-                    description: None,
+                    description: description.as_ref().map(|node| node.value),
                 });
             }
             TypeSystemDefinition::InterfaceTypeDefinition(InterfaceTypeDefinition {
@@ -1207,6 +1187,7 @@ impl InMemorySchema {
                 interfaces,
                 directives,
                 fields,
+                description,
             }) => {
                 let parent_id = Type::Interface(InterfaceID(self.interfaces.len() as u32));
                 let fields = if is_extension {
@@ -1215,6 +1196,7 @@ impl InMemorySchema {
                         &mut HashMap::with_capacity(len_of_option_list(fields)),
                         *location_key,
                         Some(parent_id),
+                        is_extension,
                     )?
                 } else {
                     self.build_fields(fields, *location_key, Some(parent_id))?
@@ -1235,13 +1217,14 @@ impl InMemorySchema {
                     fields,
                     directives,
                     interfaces,
-                    description: None,
+                    description: description.as_ref().map(|node| node.value),
                 });
             }
             TypeSystemDefinition::UnionTypeDefinition(UnionTypeDefinition {
                 name,
                 directives,
                 members,
+                description,
             }) => {
                 let members = members
                     .iter()
@@ -1253,13 +1236,14 @@ impl InMemorySchema {
                     is_extension,
                     members,
                     directives,
-                    description: None,
+                    description: description.as_ref().map(|node| node.value),
                 });
             }
             TypeSystemDefinition::InputObjectTypeDefinition(InputObjectTypeDefinition {
                 name,
                 fields,
                 directives,
+                description,
             }) => {
                 let fields = self.build_arguments(fields)?;
                 let directives = self.build_directive_values(directives);
@@ -1268,16 +1252,17 @@ impl InMemorySchema {
                         Location::new(*location_key, name.span),
                         InputObjectName(name.value),
                     ),
-
+                    is_extension,
                     fields,
                     directives,
-                    description: None,
+                    description: description.as_ref().map(|node| node.value),
                 });
             }
             TypeSystemDefinition::EnumTypeDefinition(EnumTypeDefinition {
                 name,
                 directives,
                 values,
+                description,
             }) => {
                 let directives = self.build_directive_values(directives);
                 let values = if let Some(values) = values {
@@ -1300,12 +1285,13 @@ impl InMemorySchema {
                     is_extension,
                     values,
                     directives,
-                    description: None,
+                    description: description.as_ref().map(|node| node.value),
                 });
             }
             TypeSystemDefinition::ScalarTypeDefinition(ScalarTypeDefinition {
                 name,
                 directives,
+                description,
             }) => {
                 let directives = self.build_directive_values(directives);
                 self.scalars.push(Scalar {
@@ -1315,7 +1301,7 @@ impl InMemorySchema {
                     ),
                     is_extension,
                     directives,
-                    description: None,
+                    description: description.as_ref().map(|node| node.value),
                 })
             }
             TypeSystemDefinition::ObjectTypeExtension(ObjectTypeExtension {
@@ -1337,14 +1323,16 @@ impl InMemorySchema {
                     let mut existing_fields =
                         HashMap::with_capacity(field_ids.len() + len_of_option_list(fields));
                     for field_id in field_ids {
-                        let field_name = self.fields[field_id.as_usize()].name;
-                        existing_fields.insert(field_name.item, field_name.location);
+                        let field = &self.fields[field_id.as_usize()];
+                        existing_fields
+                            .insert(field.name.item, (field.name.location, !field.is_extension));
                     }
                     let client_fields = self.build_extend_fields(
                         fields,
                         &mut existing_fields,
                         *location_key,
                         Some(Type::Object(id)),
+                        is_extension,
                     )?;
 
                     self.objects[index].fields.extend(client_fields);
@@ -1389,14 +1377,16 @@ impl InMemorySchema {
                     let mut existing_fields =
                         HashMap::with_capacity(field_ids.len() + len_of_option_list(fields));
                     for field_id in field_ids {
-                        let field_name = self.fields[field_id.as_usize()].name;
-                        existing_fields.insert(field_name.item, field_name.location);
+                        let field = &self.fields[field_id.as_usize()];
+                        existing_fields
+                            .insert(field.name.item, (field.name.location, !field.is_extension));
                     }
                     let client_fields = self.build_extend_fields(
                         fields,
                         &mut existing_fields,
                         *location_key,
                         Some(Type::Interface(id)),
+                        is_extension,
                     )?;
                     self.interfaces[index].fields.extend(client_fields);
 
@@ -1413,7 +1403,6 @@ impl InMemorySchema {
                     )]);
                 }
             },
-            TypeSystemDefinition::SchemaExtension { .. } => todo!("SchemaExtension"),
             TypeSystemDefinition::EnumTypeExtension { .. } => todo!("EnumTypeExtension"),
             TypeSystemDefinition::UnionTypeExtension { .. } => todo!("UnionTypeExtension"),
             TypeSystemDefinition::InputObjectTypeExtension { .. } => {
@@ -1424,6 +1413,61 @@ impl InMemorySchema {
         Ok(())
     }
 
+    fn add_operation_types(
+        &mut self,
+        operation_types: &[OperationTypeDefinition],
+        location_key: &SourceLocationKey,
+    ) -> DiagnosticsResult<()> {
+        for OperationTypeDefinition { operation, type_ } in operation_types {
+            let operation_id = self.build_object_id(type_.value)?;
+            match operation {
+                OperationType::Query => {
+                    if let Some(prev_query_type) = self.query_type {
+                        return Err(vec![Diagnostic::error(
+                            SchemaError::DuplicateOperationDefinition(
+                                *operation,
+                                type_.value,
+                                expect_object_type_name(&self.type_map, prev_query_type),
+                            ),
+                            Location::new(*location_key, type_.span),
+                        )]);
+                    } else {
+                        self.query_type = Some(operation_id);
+                    }
+                }
+                OperationType::Mutation => {
+                    if let Some(prev_mutation_type) = self.mutation_type {
+                        return Err(vec![Diagnostic::error(
+                            SchemaError::DuplicateOperationDefinition(
+                                *operation,
+                                type_.value,
+                                expect_object_type_name(&self.type_map, prev_mutation_type),
+                            ),
+                            Location::new(*location_key, type_.span),
+                        )]);
+                    } else {
+                        self.mutation_type = Some(operation_id);
+                    }
+                }
+                OperationType::Subscription => {
+                    if let Some(prev_subscription_type) = self.subscription_type {
+                        return Err(vec![Diagnostic::error(
+                            SchemaError::DuplicateOperationDefinition(
+                                *operation,
+                                type_.value,
+                                expect_object_type_name(&self.type_map, prev_subscription_type),
+                            ),
+                            Location::new(*location_key, type_.span),
+                        )]);
+                    } else {
+                        self.subscription_type = Some(operation_id);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn build_object_id(&mut self, name: StringKey) -> DiagnosticsResult<ObjectID> {
         match self.type_map.get(&name) {
             Some(Type::Object(id)) => Ok(*id),
@@ -1495,16 +1539,35 @@ impl InMemorySchema {
     fn build_extend_fields(
         &mut self,
         field_defs: &Option<List<FieldDefinition>>,
-        existing_fields: &mut HashMap<StringKey, Location>,
+        existing_fields: &mut HashMap<StringKey, (Location, bool)>,
         source_location_key: SourceLocationKey,
         parent_type: Option<Type>,
+        is_client_extension: bool,
     ) -> DiagnosticsResult<Vec<FieldID>> {
         if let Some(field_defs) = field_defs {
             let mut field_ids: Vec<FieldID> = Vec::with_capacity(field_defs.items.len());
             for field_def in &field_defs.items {
                 let field_name = field_def.name.value;
                 let field_location = Location::new(source_location_key, field_def.name.span);
-                if let Some(prev_location) = existing_fields.insert(field_name, field_location) {
+                if let Some((prev_location, is_server_field)) =
+                    existing_fields.insert(field_name, (field_location, false))
+                {
+                    // The field was most likely added to the server schema after
+                    // the client extension was written.
+                    if is_client_extension && is_server_field {
+                        if let Some(parent_type) = parent_type {
+                            return Err(vec![
+                                Diagnostic::error(
+                                    SchemaError::ClientExtensionFieldConflict(
+                                        self.get_type_name(parent_type),
+                                        field_name,
+                                    ),
+                                    field_location,
+                                )
+                                .annotate("defined in the server schema here", prev_location),
+                            ]);
+                        }
+                    }
                     return Err(vec![
                         Diagnostic::error(SchemaError::DuplicateField(field_name), field_location)
                             .annotate("previously defined here", prev_location),
@@ -1543,7 +1606,7 @@ impl InMemorySchema {
                         name: ArgumentName(arg_def.name.value),
                         type_: self.build_input_object_reference(&arg_def.type_)?,
                         default_value: arg_def.default_value.clone(),
-                        description: None,
+                        description: arg_def.description.as_ref().map(|node| node.value),
                         directives: self.build_directive_values(&arg_def.directives),
                     })
                 })
@@ -1645,6 +1708,27 @@ fn extend_without_duplicates<T: PartialEq>(
     }
 }
 
+fn is_same_directive_definition(
+    directive: &Directive,
+    arguments: &ArgumentDefinitions,
+    locations: &[DirectiveLocation],
+    repeatable: bool,
+) -> bool {
+    directive.repeatable == repeatable
+        && directive.locations == locations
+        && directive.arguments.0.len() == arguments.0.len()
+        && directive
+            .arguments
+            .iter()
+            .zip(arguments.iter())
+            .all(|(a, b)| {
+                a.name == b.name
+                    && a.type_ == b.type_
+                    && a.default_value.as_ref().map(|value| value.to_string())
+                        == b.default_value.as_ref().map(|value| value.to_string())
+            })
+}
+
 fn len_of_option_list<T>(option_list: &Option<List<T>>) -> usize {
     option_list.as_ref().map_or(0, |list| list.items.len())
 }
@@ -0,0 +1,200 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::collections::HashSet;
+
+use common::ArgumentName;
+use common::Diagnostic;
+use common::DiagnosticsResult;
+use common::DirectiveName;
+use common::Location;
+use common::Span;
+use graphql_syntax::ConstantValue;
+use graphql_syntax::DirectiveDefinition;
+use graphql_syntax::SchemaDocument;
+use graphql_syntax::TypeSystemDefinition;
+use intern::Lookup;
+
+use super::InMemorySchema;
+use crate::definitions::Type;
+use crate::definitions::TypeReference;
+use crate::errors::SchemaError;
+use crate::graphql_schema::Schema;
+
+/// Checks the directive definitions of the SDL documents once all types are
+/// known: locations must be unique and default values of arguments must be
+/// valid values of their types.
+pub(super) fn validate_directive_definitions<'a>(
+    schema: &InMemorySchema,
+    documents: impl Iterator<Item = &'a SchemaDocument>,
+) -> DiagnosticsResult<()> {
+    let mut errors = Vec::new();
+    for document in documents {
+        let location_key = document.location.source_location();
+        for definition in &document.definitions {
+            if let TypeSystemDefinition::DirectiveDefinition(directive_definition) = definition {
+                errors.extend(
+                    validate_directive_definition(schema, directive_definition)
+                        .into_iter()
+                        .map(|(error, span)| {
+                            Diagnostic::error(error, Location::new(location_key, span))
+                        }),
+                );
+            }
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn validate_directive_definition(
+    schema: &InMemorySchema,
+    directive_definition: &DirectiveDefinition,
+) -> Vec<(SchemaError, Span)> {
+    let mut errors = Vec::new();
+    let name = directive_definition.name.value;
+
+    let mut seen_locations = HashSet::new();
+    for location in &directive_definition.locations {
+        if !seen_locations.insert(*location) {
+            errors.push((
+                SchemaError::DuplicateDirectiveLocation(name, *location),
+                directive_definition.name.span,
+            ));
+        }
+    }
+
+    let directive = match schema.get_directive(DirectiveName(name)) {
+        Some(directive) => directive,
+        None => return errors,
+    };
+    for argument_definition in directive_definition
+        .arguments
+        .iter()
+        .flat_map(|list| &list.items)
+    {
+        let argument = match directive
+            .arguments
+            .named(ArgumentName(argument_definition.name.value))
+        {
+            Some(argument) => argument,
+            None => continue,
+        };
+        if let Some(default_value) = &argument_definition.default_value {
+            if let Err((reason, span)) = validate_value(schema, default_value, &argument.type_) {
+                errors.push((
+                    SchemaError::InvalidDirectiveArgumentDefaultValue(
+                        name,
+                        argument_definition.name.value,
+                        reason,
+                    ),
+                    span,
+                ));
+            }
+        }
+    }
+    errors
+}
+
+fn validate_value(
+    schema: &InMemorySchema,
+    value: &ConstantValue,
+    type_: &TypeReference<Type>,
+) -> Result<(), (String, Span)> {
+    let invalid = || {
+        Err((
+            format!(
+                "expected a value of type '{}', got `{}`.",
+                schema.get_type_string(type_),
+                value
+            ),
+            value.span(),
+        ))
+    };
+    match (type_, value) {
+        (TypeReference::NonNull(_), ConstantValue::Null(_)) => invalid(),
+        (_, ConstantValue::Null(_)) => Ok(()),
+        (TypeReference::NonNull(of), _) => validate_value(schema, value, of),
+        (TypeReference::List(of), ConstantValue::List(items)) => items
+            .items
+            .iter()
+            .try_for_each(|item| validate_value(schema, item, of)),
+        // A single value is coerced to a list with one item.
+        (TypeReference::List(of), _) => validate_value(schema, value, of),
+        (TypeReference::Named(named_type), _) => match named_type {
+            Type::Scalar(id) => {
+                let is_valid = match schema.scalar(*id).name.item.0.lookup() {
+                    "Int" => matches!(value, ConstantValue::Int(_)),
+                    "Float" => matches!(value, ConstantValue::Int(_) | ConstantValue::Float(_)),
+                    "String" => matches!(value, ConstantValue::String(_)),
+                    "Boolean" => matches!(value, ConstantValue::Boolean(_)),
+                    "ID" => matches!(value, ConstantValue::Int(_) | ConstantValue::String(_)),
+                    // Custom scalars may be represented by any value.
+                    _ => true,
+                };
+                if is_valid { Ok(()) } else { invalid() }
+            }
+            Type::Enum(id) => match value {
+                ConstantValue::Enum(enum_node)
+                    if schema
+                        .enum_(*id)
+                        .values
+                        .iter()
+                        .any(|enum_value| enum_value.value == enum_node.value) =>
+                {
+                    Ok(())
+                }
+                _ => invalid(),
+            },
+            Type::InputObject(id) => {
+                let input_object = schema.input_object(*id);
+                let fields = match value {
+                    ConstantValue::Object(fields) => fields,
+                    _ => return invalid(),
+                };
+                for field in &fields.items {
+                    match input_object.fields.named(ArgumentName(field.name.value)) {
+                        Some(field_definition) => {
+                            validate_value(schema, &field.value, &field_definition.type_)?
+                        }
+                        None => {
+                            return Err((
+                                format!(
+                                    "unknown field '{}' of input object '{}'.",
+                                    field.name.value, input_object.name.item
+                                ),
+                                field.name.span,
+                            ));
+                        }
+                    }
+                }
+                for field_definition in input_object.fields.iter() {
+                    if field_definition.type_.is_non_null()
+                        && field_definition.default_value.is_none()
+                        && !fields
+                            .items
+                            .iter()
+                            .any(|field| field.name.value == field_definition.name.0)
+                    {
+                        return Err((
+                            format!(
+                                "missing required field '{}' of input object '{}'.",
+                                field_definition.name, input_object.name.item
+                            ),
+                            value.span(),
+                        ));
+                    }
+                }
+                Ok(())
+            }
+            Type::Interface(_) | Type::Object(_) | Type::Union(_) => invalid(),
+        },
+    }
+}
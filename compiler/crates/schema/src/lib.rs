@@ -20,6 +20,8 @@ mod in_memory;
 mod schema;
 pub mod suggestion_list;
 
+use std::collections::HashSet;
+
 use common::DiagnosticsResult;
 use common::SourceLocationKey;
 pub use definitions::Argument;
@@ -51,9 +53,22 @@ use flatbuffer::FlatBufferSchema;
 pub use flatbuffer::SchemaWrapper;
 pub use graphql_schema::Schema;
 pub use graphql_syntax::DirectiveLocation;
+use graphql_syntax::EnumTypeDefinition;
+use graphql_syntax::EnumTypeExtension;
+use graphql_syntax::InputObjectTypeDefinition;
+use graphql_syntax::InputObjectTypeExtension;
+use graphql_syntax::InterfaceTypeDefinition;
+use graphql_syntax::InterfaceTypeExtension;
+use graphql_syntax::List;
+use graphql_syntax::ObjectTypeDefinition;
+use graphql_syntax::ObjectTypeExtension;
 use graphql_syntax::SchemaDocument;
 pub use graphql_syntax::TypeSystemDefinition;
+use graphql_syntax::UnionTypeDefinition;
+use graphql_syntax::UnionTypeExtension;
 pub use in_memory::InMemorySchema;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
 
 pub use crate::schema::SDLSchema;
 
@@ -68,10 +83,34 @@ pub fn build_schema(sdl: &str) -> DiagnosticsResult<SDLSchema> {
 pub fn build_schema_with_extensions<T: AsRef<str>, U: AsRef<str>>(
     server_sdls: &[(T, SourceLocationKey)],
     extension_sdls: &[(U, SourceLocationKey)],
+) -> DiagnosticsResult<SDLSchema> {
+    build_schema_with_extensions_and_options(server_sdls, extension_sdls, Default::default())
+}
+
+/// Options to build a schema from SDL.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BuildSchemaOptions<'a> {
+    /// SDL with type and directive definitions that are added to the server
+    /// schema, unless the server schema already defines them.
+    pub default_server_definitions: Option<&'a str>,
+    /// Extensions of object and interface types that aren't defined in the
+    /// server schema are treated as definitions of these types, e.g. for
+    /// entities owned by another service in Apollo Federation (v1) schemas.
+    pub define_extended_server_types: bool,
+    /// Schema coordinates of the types (`User`), fields (`User.name`) and
+    /// enum values (`Color.RED`) that are removed from the server schema,
+    /// e.g. for a variant of the schema running against an older server.
+    pub removed_coordinates: &'a [String],
+}
+
+pub fn build_schema_with_extensions_and_options<T: AsRef<str>, U: AsRef<str>>(
+    server_sdls: &[(T, SourceLocationKey)],
+    extension_sdls: &[(U, SourceLocationKey)],
+    options: BuildSchemaOptions<'_>,
 ) -> DiagnosticsResult<SDLSchema> {
     let mut server_documents = vec![builtins()?];
 
-    let server_schema_document = match server_sdls {
+    let mut server_schema_document = match server_sdls {
         [(sdl, source_location)] => {
             graphql_syntax::parse_schema_document(sdl.as_ref(), *source_location)?
         }
@@ -91,6 +130,23 @@ pub fn build_schema_with_extensions<T: AsRef<str>, U: AsRef<str>>(
         }
     };
 
+    if options.define_extended_server_types {
+        define_extended_types(&mut server_schema_document);
+    }
+    if !options.removed_coordinates.is_empty() {
+        remove_coordinates(&mut server_schema_document, options.removed_coordinates);
+    }
+    if let Some(default_server_definitions) = options.default_server_definitions {
+        let mut default_document = graphql_syntax::parse_schema_document(
+            default_server_definitions,
+            SourceLocationKey::generated(),
+        )?;
+        let defined_names = defined_names(&server_schema_document);
+        default_document.definitions.retain(|definition| {
+            !matches!(defined_name(definition), Some(name) if defined_names.contains(&name))
+        });
+        server_documents.push(default_document);
+    }
     server_documents.push(server_schema_document);
 
     let mut client_schema_documents = Vec::new();
@@ -112,6 +168,239 @@ pub fn build_schema_from_flat_buffer(bytes: &[u8]) -> DiagnosticsResult<FlatBuff
     Ok(FlatBufferSchema::build(bytes))
 }
 
+/// Names of the types and directives defined (not extended) in the document
+fn defined_names(document: &SchemaDocument) -> HashSet<(bool, StringKey)> {
+    document
+        .definitions
+        .iter()
+        .filter_map(defined_name)
+        .collect()
+}
+
+/// The name of a type or directive definition, with whether it's a directive
+fn defined_name(definition: &TypeSystemDefinition) -> Option<(bool, StringKey)> {
+    match definition {
+        TypeSystemDefinition::DirectiveDefinition(definition) => {
+            Some((true, definition.name.value))
+        }
+        TypeSystemDefinition::ObjectTypeDefinition(definition) => {
+            Some((false, definition.name.value))
+        }
+        TypeSystemDefinition::InterfaceTypeDefinition(definition) => {
+            Some((false, definition.name.value))
+        }
+        TypeSystemDefinition::UnionTypeDefinition(definition) => {
+            Some((false, definition.name.value))
+        }
+        TypeSystemDefinition::InputObjectTypeDefinition(definition) => {
+            Some((false, definition.name.value))
+        }
+        TypeSystemDefinition::EnumTypeDefinition(definition) => {
+            Some((false, definition.name.value))
+        }
+        TypeSystemDefinition::ScalarTypeDefinition(definition) => {
+            Some((false, definition.name.value))
+        }
+        _ => None,
+    }
+}
+
+/// Replaces the first extension of each undefined object or interface type
+/// with a definition of that type.
+fn define_extended_types(document: &mut SchemaDocument) {
+    let mut defined_names = defined_names(document);
+    for definition in document.definitions.iter_mut() {
+        let replacement = match definition {
+            TypeSystemDefinition::ObjectTypeExtension(extension)
+                if defined_names.insert((false, extension.name.value)) =>
+            {
+                TypeSystemDefinition::ObjectTypeDefinition(ObjectTypeDefinition {
+                    name: extension.name.clone(),
+                    interfaces: std::mem::take(&mut extension.interfaces),
+                    directives: std::mem::take(&mut extension.directives),
+                    fields: extension.fields.take(),
+                    description: None,
+                })
+            }
+            TypeSystemDefinition::InterfaceTypeExtension(extension)
+                if defined_names.insert((false, extension.name.value)) =>
+            {
+                TypeSystemDefinition::InterfaceTypeDefinition(InterfaceTypeDefinition {
+                    name: extension.name.clone(),
+                    interfaces: std::mem::take(&mut extension.interfaces),
+                    directives: std::mem::take(&mut extension.directives),
+                    fields: extension.fields.take(),
+                    description: None,
+                })
+            }
+            _ => continue,
+        };
+        *definition = replacement;
+    }
+}
+
+/// Removes the types, fields and enum values named by `coordinates` from the
+/// document, along with the references to the removed types from the members
+/// of unions and the interfaces of types.
+fn remove_coordinates(document: &mut SchemaDocument, coordinates: &[String]) {
+    let mut removed_types = HashSet::new();
+    let mut removed_members = HashSet::new();
+    for coordinate in coordinates {
+        match coordinate.split_once('.') {
+            Some((type_name, member_name)) => {
+                removed_members.insert((type_name.intern(), member_name.intern()));
+            }
+            None => {
+                removed_types.insert(coordinate.intern());
+            }
+        }
+    }
+
+    document.definitions.retain(|definition| {
+        !matches!(defined_name(definition), Some((false, name)) if removed_types.contains(&name))
+    });
+    for definition in document.definitions.iter_mut() {
+        match definition {
+            TypeSystemDefinition::ObjectTypeDefinition(ObjectTypeDefinition {
+                name,
+                interfaces,
+                fields,
+                ..
+            })
+            | TypeSystemDefinition::ObjectTypeExtension(ObjectTypeExtension {
+                name,
+                interfaces,
+                fields,
+                ..
+            })
+            | TypeSystemDefinition::InterfaceTypeDefinition(InterfaceTypeDefinition {
+                name,
+                interfaces,
+                fields,
+                ..
+            })
+            | TypeSystemDefinition::InterfaceTypeExtension(InterfaceTypeExtension {
+                name,
+                interfaces,
+                fields,
+                ..
+            }) => {
+                interfaces.retain(|interface| !removed_types.contains(&interface.value));
+                retain_members(fields, |field| {
+                    !removed_members.contains(&(name.value, field.name.value))
+                });
+            }
+            TypeSystemDefinition::UnionTypeDefinition(UnionTypeDefinition { members, .. })
+            | TypeSystemDefinition::UnionTypeExtension(UnionTypeExtension { members, .. }) => {
+                members.retain(|member| !removed_types.contains(&member.value));
+            }
+            TypeSystemDefinition::InputObjectTypeDefinition(InputObjectTypeDefinition {
+                name,
+                fields,
+                ..
+            })
+            | TypeSystemDefinition::InputObjectTypeExtension(InputObjectTypeExtension {
+                name,
+                fields,
+                ..
+            }) => {
+                retain_members(fields, |field| {
+                    !removed_members.contains(&(name.value, field.name.value))
+                });
+            }
+            TypeSystemDefinition::EnumTypeDefinition(EnumTypeDefinition {
+                name, values, ..
+            })
+            | TypeSystemDefinition::EnumTypeExtension(EnumTypeExtension { name, values, .. }) => {
+                retain_members(values, |value| {
+                    !removed_members.contains(&(name.value, value.name.value))
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+fn retain_members<T>(members: &mut Option<List<T>>, predicate: impl FnMut(&T) -> bool) {
+    if let Some(members) = members {
+        members.items.retain(predicate);
+    }
+}
+
 pub fn builtins() -> DiagnosticsResult<SchemaDocument> {
     graphql_syntax::parse_schema_document(BUILTINS, SourceLocationKey::generated())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SDL: &str = r#"
+        type Query { me: User search: SearchResult }
+        interface Actor { name: String }
+        type User implements Actor { name: String age: Int color: Color }
+        type Page implements Actor { name: String }
+        union SearchResult = User | Page
+        enum Color { RED GREEN }
+    "#;
+
+    fn build_variant_schema(removed_coordinates: &[&str]) -> SDLSchema {
+        let removed_coordinates: Vec<String> = removed_coordinates
+            .iter()
+            .map(|coordinate| coordinate.to_string())
+            .collect();
+        build_schema_with_extensions_and_options::<_, &str>(
+            &[(SDL, SourceLocationKey::generated())],
+            &[(
+                "extend type User { nickname: String }",
+                SourceLocationKey::generated(),
+            )],
+            BuildSchemaOptions {
+                removed_coordinates: &removed_coordinates,
+                ..Default::default()
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn removed_coordinates_test() {
+        let schema = build_variant_schema(&["User.age", "Color.GREEN", "Page"]);
+        let user = schema.get_type("User".intern()).unwrap();
+        assert!(schema.named_field(user, "age".intern()).is_none());
+        assert!(schema.named_field(user, "name".intern()).is_some());
+        // The extensions of the variant apply to the schema without the
+        // removed coordinates
+        assert!(schema.named_field(user, "nickname".intern()).is_some());
+
+        let color = schema
+            .get_type("Color".intern())
+            .unwrap()
+            .get_enum_id()
+            .unwrap();
+        let values: Vec<_> = schema
+            .enum_(color)
+            .values
+            .iter()
+            .map(|value| value.value)
+            .collect();
+        assert_eq!(values, vec!["RED".intern()]);
+
+        // The removed types are removed from the unions
+        assert!(schema.get_type("Page".intern()).is_none());
+        let search_result = schema
+            .get_type("SearchResult".intern())
+            .unwrap()
+            .get_union_id()
+            .unwrap();
+        assert_eq!(schema.union(search_result).members.len(), 1);
+    }
+
+    #[test]
+    fn no_removed_coordinates_test() {
+        let schema = build_variant_schema(&[]);
+        let user = schema.get_type("User".intern()).unwrap();
+        assert!(schema.named_field(user, "age".intern()).is_some());
+        assert!(schema.get_type("Page".intern()).is_some());
+    }
+}
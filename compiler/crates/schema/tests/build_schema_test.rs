@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<8634e6fbc71207f6f4bb9079d4bea17a>>
+ * @generated SignedSource<<cac9ee11407481aa1e9f6c915fc72568>>
  */
 
 mod build_schema;
@@ -12,6 +12,13 @@ mod build_schema;
 use build_schema::transform_fixture;
 use fixture_tests::test_fixture;
 
+#[test]
+fn directive_default_values() {
+    let input = include_str!("build_schema/fixtures/directive-default-values.graphql");
+    let expected = include_str!("build_schema/fixtures/directive-default-values.expected");
+    test_fixture(transform_fixture, "directive-default-values.graphql", "build_schema/fixtures/directive-default-values.expected", input, expected);
+}
+
 #[test]
 fn directive_on_arg_def() {
     let input = include_str!("build_schema/fixtures/directive-on-arg-def.graphql");
@@ -40,6 +47,13 @@ fn extend_object_before_define() {
     test_fixture(transform_fixture, "extend-object-before-define.graphql", "build_schema/fixtures/extend-object-before-define.expected", input, expected);
 }
 
+#[test]
+fn extension_identical_directive_definition() {
+    let input = include_str!("build_schema/fixtures/extension-identical-directive-definition.graphql");
+    let expected = include_str!("build_schema/fixtures/extension-identical-directive-definition.expected");
+    test_fixture(transform_fixture, "extension-identical-directive-definition.graphql", "build_schema/fixtures/extension-identical-directive-definition.expected", input, expected);
+}
+
 #[test]
 fn field_descriptions() {
     let input = include_str!("build_schema/fixtures/field-descriptions.graphql");
@@ -54,6 +68,13 @@ fn interface_implements_interface() {
     test_fixture(transform_fixture, "interface-implements-interface.graphql", "build_schema/fixtures/interface-implements-interface.expected", input, expected);
 }
 
+#[test]
+fn invalid_directive_definitions() {
+    let input = include_str!("build_schema/fixtures/invalid-directive-definitions.graphql");
+    let expected = include_str!("build_schema/fixtures/invalid-directive-definitions.expected");
+    test_fixture(transform_fixture, "invalid-directive-definitions.graphql", "build_schema/fixtures/invalid-directive-definitions.expected", input, expected);
+}
+
 #[test]
 fn invalid_duplicate_directive() {
     let input = include_str!("build_schema/fixtures/invalid-duplicate-directive.graphql");
@@ -68,6 +89,13 @@ fn invalid_duplicate_query_operation() {
     test_fixture(transform_fixture, "invalid-duplicate-query-operation.graphql", "build_schema/fixtures/invalid-duplicate-query-operation.expected", input, expected);
 }
 
+#[test]
+fn invalid_extension_different_directive_definition() {
+    let input = include_str!("build_schema/fixtures/invalid-extension-different-directive-definition.graphql");
+    let expected = include_str!("build_schema/fixtures/invalid-extension-different-directive-definition.expected");
+    test_fixture(transform_fixture, "invalid-extension-different-directive-definition.graphql", "build_schema/fixtures/invalid-extension-different-directive-definition.expected", input, expected);
+}
+
 #[test]
 fn invalid_extension_implements_noninterface() {
     let input = include_str!("build_schema/fixtures/invalid-extension-implements-noninterface.graphql");
@@ -103,6 +131,13 @@ fn invalid_input_type_union() {
     test_fixture(transform_fixture, "invalid-input-type-union.graphql", "build_schema/fixtures/invalid-input-type-union.expected", input, expected);
 }
 
+#[test]
+fn invalid_interface_extension_duplicated_server_field() {
+    let input = include_str!("build_schema/fixtures/invalid-interface-extension-duplicated-server-field.graphql");
+    let expected = include_str!("build_schema/fixtures/invalid-interface-extension-duplicated-server-field.expected");
+    test_fixture(transform_fixture, "invalid-interface-extension-duplicated-server-field.graphql", "build_schema/fixtures/invalid-interface-extension-duplicated-server-field.expected", input, expected);
+}
+
 #[test]
 fn invalid_interface_implements_noninterface() {
     let input = include_str!("build_schema/fixtures/invalid-interface-implements-noninterface.graphql");
@@ -124,6 +159,13 @@ fn invalid_object_extension_local_duplicated_fields() {
     test_fixture(transform_fixture, "invalid-object-extension-local-duplicated-fields.graphql", "build_schema/fixtures/invalid-object-extension-local-duplicated-fields.expected", input, expected);
 }
 
+#[test]
+fn invalid_schema_extension_duplicate_operation() {
+    let input = include_str!("build_schema/fixtures/invalid-schema-extension-duplicate-operation.graphql");
+    let expected = include_str!("build_schema/fixtures/invalid-schema-extension-duplicate-operation.expected");
+    test_fixture(transform_fixture, "invalid-schema-extension-duplicate-operation.graphql", "build_schema/fixtures/invalid-schema-extension-duplicate-operation.expected", input, expected);
+}
+
 #[test]
 fn invalid_sdl() {
     let input = include_str!("build_schema/fixtures/invalid-sdl.graphql");
@@ -144,3 +186,10 @@ fn kitchen_sink() {
     let expected = include_str!("build_schema/fixtures/kitchen-sink.expected");
     test_fixture(transform_fixture, "kitchen-sink.graphql", "build_schema/fixtures/kitchen-sink.expected", input, expected);
 }
+
+#[test]
+fn schema_extension() {
+    let input = include_str!("build_schema/fixtures/schema-extension.graphql");
+    let expected = include_str!("build_schema/fixtures/schema-extension.expected");
+    test_fixture(transform_fixture, "schema-extension.graphql", "build_schema/fixtures/schema-extension.expected", input, expected);
+}
@@ -11,6 +11,8 @@
 'use strict';
 
 const transformerWithOptions = require('./transformerWithOptions');
+const crypto = require('crypto');
+const {parse, print} = require('graphql');
 
 describe('`development` option', () => {
   it('tests the hash when `development` is set', () => {
@@ -50,3 +52,40 @@ describe('`development` option', () => {
     ).toMatchSnapshot();
   });
 });
+
+describe('`@experimentVariant`', () => {
+  const text =
+    'graphql`query TestQuery @experimentVariant(name: "treatment") { __typename }`';
+
+  it('requires the artifact of the variant', () => {
+    const code = transformerWithOptions({})(text);
+    expect(code).toContain(
+      "require('./__generated__/TestQuery_treatment.graphql')",
+    );
+    expect(code).not.toContain("require('./__generated__/TestQuery.graphql')");
+  });
+
+  it('tests the hash of the renamed operation', () => {
+    const hash = crypto
+      .createHash('md5')
+      .update(
+        print(
+          parse(
+            'query TestQuery_treatment @experimentVariant(name: "treatment") { __typename }',
+          ),
+        ),
+        'utf8',
+      )
+      .digest('hex');
+    const code = transformerWithOptions({}, 'development')(text);
+    expect(code).toContain(`_TestQuery_treatment.hash !== '${hash}'`);
+  });
+
+  it('does not rename fragments', () => {
+    expect(
+      transformerWithOptions({})(
+        'graphql`fragment TestFrag on Node @experimentVariant(name: "treatment") { id }`',
+      ),
+    ).toContain("require('./__generated__/TestFrag.graphql')");
+  });
+});
@@ -102,10 +102,16 @@ function createNode(
     isHasteMode: boolean,
   },
 ): Object {
-  const definitionName = graphqlDefinition.name && graphqlDefinition.name.value;
-  if (!definitionName) {
+  const graphqlName = graphqlDefinition.name && graphqlDefinition.name.value;
+  if (!graphqlName) {
     throw new Error('GraphQL operations and fragments must contain names');
   }
+  // The compiler renames the variants of an experiment after their variant.
+  const experimentVariant = getExperimentVariant(graphqlDefinition);
+  const definitionName =
+    experimentVariant != null
+      ? graphqlName + '_' + experimentVariant
+      : graphqlName;
   const requiredFile = definitionName + '.graphql';
   const requiredPath = options.isHasteMode
     ? requiredFile
@@ -115,7 +121,17 @@ function createNode(
 
   const hash = crypto
     .createHash('md5')
-    .update(print(graphqlDefinition), 'utf8')
+    .update(
+      print(
+        experimentVariant != null
+          ? {
+              ...graphqlDefinition,
+              name: {...graphqlDefinition.name, value: definitionName},
+            }
+          : graphqlDefinition,
+      ),
+      'utf8',
+    )
     .digest('hex');
 
   let topScope = path.scope;
@@ -199,6 +215,24 @@ function createNode(
   }
 }
 
+/**
+ * The `name` of the `@experimentVariant` of an operation, if any.
+ */
+function getExperimentVariant(
+  graphqlDefinition: OperationDefinitionNode | FragmentDefinitionNode,
+): ?string {
+  if (graphqlDefinition.kind !== 'OperationDefinition') {
+    return null;
+  }
+  const directive = graphqlDefinition.directives?.find(
+    directive => directive.name.value === 'experimentVariant',
+  );
+  const argument = directive?.arguments?.find(
+    argument => argument.name.value === 'name',
+  );
+  return argument?.value.kind === 'StringValue' ? argument.value.value : null;
+}
+
 function warnNeedsRebuild(
   t: $FlowFixMe,
   definitionName: string,
@@ -261,6 +261,8 @@ export type ReaderRelayResolver = {
   +path: string,
   +resolverModule: ResolverModule,
   +normalizationInfo?: ResolverNormalizationInfo,
+  // Whether the resolver is passed the resolver context after its arguments.
+  +hasContext?: boolean,
 };
 
 export type ReaderRelayLiveResolver = {
@@ -272,6 +274,8 @@ export type ReaderRelayLiveResolver = {
   +path: string,
   +resolverModule: ResolverModule,
   +normalizationInfo?: ResolverNormalizationInfo,
+  // Whether the resolver is passed the resolver context after its arguments.
+  +hasContext?: boolean,
 };
 
 export type ReaderClientEdgeToClientObject = {